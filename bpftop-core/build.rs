@@ -0,0 +1,27 @@
+use std::env;
+use std::ffi::OsStr;
+use std::path::PathBuf;
+
+use libbpf_cargo::SkeletonBuilder;
+
+const SRCS: &[&str] = &["pid_iter", "map_iter"];
+
+fn main() {
+    let bpf_dir = PathBuf::from(
+        env::var_os("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR must be set in build script"),
+    )
+    .join("src")
+    .join("bpf");
+
+    for name in SRCS {
+        let src = bpf_dir.join(format!("{name}.bpf.c"));
+        let out = bpf_dir.join(format!("{name}.skel.rs"));
+
+        SkeletonBuilder::new()
+            .source(&src)
+            .clang_args([OsStr::new("-I")])
+            .build_and_generate(&out)
+            .unwrap();
+        println!("cargo:rerun-if-changed={}", src.display());
+    }
+}
@@ -0,0 +1,66 @@
+#include "vmlinux.h"
+#include "bpf_helpers.h"
+#include "bpf_core_read.h"
+
+char _license[] SEC("license") = "GPL";
+
+extern const void bpf_prog_fops __ksym;
+extern const void bpf_map_fops __ksym;
+extern const void bpf_link_fops __ksym;
+
+struct pid_iter_entry {
+	__u64 cgroup_id;
+	__u32 id;
+	int pid;
+	char comm[16];
+};
+
+/* Number of BPF program, map and link fds held by each process */
+struct {
+	__uint(type, BPF_MAP_TYPE_HASH);
+	__uint(max_entries, 16384);
+	__type(key, int);
+	__type(value, __u32);
+} bpf_fds SEC(".maps");
+
+static __always_inline void count_fd(int pid)
+{
+	__u32 one = 1, *count;
+
+	count = bpf_map_lookup_elem(&bpf_fds, &pid);
+	if (count)
+		__sync_fetch_and_add(count, 1);
+	else
+		bpf_map_update_elem(&bpf_fds, &pid, &one, BPF_NOEXIST);
+}
+
+SEC("iter/task_file")
+int bpftop_iter(struct bpf_iter__task_file *ctx)
+{
+	struct file *file = ctx->file;
+	struct task_struct *task = ctx->task;
+	struct pid_iter_entry e;
+
+	if (!file || !task)
+		return 0;
+
+	if (file->f_op != &bpf_prog_fops) {
+		if (file->f_op == &bpf_map_fops || file->f_op == &bpf_link_fops)
+			count_fd(task->tgid);
+		return 0;
+	}
+	count_fd(task->tgid);
+
+	__builtin_memset(&e, 0, sizeof(e));
+
+	e.pid = task->tgid;
+	/* cgroup v2 ID, the inode number of the cgroup's directory */
+	e.cgroup_id = BPF_CORE_READ(task, cgroups, dfl_cgrp, kn, id);
+	e.id = BPF_CORE_READ((struct bpf_prog *)file->private_data, aux, id);
+
+	bpf_probe_read_kernel_str(&e.comm, sizeof(e.comm),
+				  task->group_leader->comm);
+	bpf_seq_write(ctx->meta->seq, &e, sizeof(e));
+
+	return 0;
+}
\ No newline at end of file
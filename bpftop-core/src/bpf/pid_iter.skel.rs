@@ -0,0 +1,685 @@
+// SPDX-License-Identifier: (LGPL-2.1 OR BSD-2-Clause)
+//
+// THIS FILE IS AUTOGENERATED BY CARGO-LIBBPF-GEN!
+
+pub use self::imp::*;
+
+#[allow(dead_code)]
+#[allow(non_snake_case)]
+#[allow(non_camel_case_types)]
+#[allow(clippy::absolute_paths)]
+#[allow(clippy::upper_case_acronyms)]
+#[allow(clippy::zero_repeat_side_effects)]
+#[warn(single_use_lifetimes)]
+mod imp {
+    #[allow(unused_imports)]
+    use super::*;
+    use libbpf_rs::libbpf_sys;
+    use libbpf_rs::skel::OpenSkel;
+    use libbpf_rs::skel::Skel;
+    use libbpf_rs::skel::SkelBuilder;
+    use libbpf_rs::AsRawLibbpf as _;
+    use libbpf_rs::MapCore as _;
+    fn build_skel_config(
+    ) -> libbpf_rs::Result<libbpf_rs::__internal_skel::ObjectSkeletonConfig<'static>> {
+        let mut builder = libbpf_rs::__internal_skel::ObjectSkeletonConfigBuilder::new(DATA);
+        builder
+            .name("pid_iter_bpf")
+            .map("bpf_fds", false)
+            .prog("bpftop_iter");
+        builder.build()
+    }
+    pub struct OpenPidIterMaps<'obj> {
+        pub bpf_fds: libbpf_rs::OpenMapMut<'obj>,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> OpenPidIterMaps<'obj> {
+        #[allow(unused_variables)]
+        unsafe fn new(
+            config: &libbpf_rs::__internal_skel::ObjectSkeletonConfig<'_>,
+            object: &mut libbpf_rs::OpenObject,
+        ) -> libbpf_rs::Result<Self> {
+            let mut bpf_fds = None;
+            let object = unsafe {
+                std::mem::transmute::<&mut libbpf_rs::OpenObject, &'obj mut libbpf_rs::OpenObject>(
+                    object,
+                )
+            };
+            #[allow(clippy::never_loop)]
+            for map in object.maps_mut() {
+                let name = map.name().to_str().ok_or_else(|| {
+                    libbpf_rs::Error::from(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "map has invalid name",
+                    ))
+                })?;
+                #[allow(clippy::match_single_binding)]
+                match name {
+                    "bpf_fds" => bpf_fds = Some(map),
+                    _ => panic!("encountered unexpected map: `{name}`"),
+                }
+            }
+
+            let slf = Self {
+                bpf_fds: bpf_fds.expect("map `bpf_fds` not present"),
+                _phantom: std::marker::PhantomData,
+            };
+            Ok(slf)
+        }
+    }
+    pub struct PidIterMaps<'obj> {
+        pub bpf_fds: libbpf_rs::MapMut<'obj>,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> PidIterMaps<'obj> {
+        #[allow(unused_variables)]
+        unsafe fn new(
+            config: &libbpf_rs::__internal_skel::ObjectSkeletonConfig<'_>,
+            object: &mut libbpf_rs::Object,
+        ) -> libbpf_rs::Result<Self> {
+            let mut bpf_fds = None;
+            let object = unsafe {
+                std::mem::transmute::<&mut libbpf_rs::Object, &'obj mut libbpf_rs::Object>(object)
+            };
+            #[allow(clippy::never_loop)]
+            for map in object.maps_mut() {
+                let name = map.name().to_str().ok_or_else(|| {
+                    libbpf_rs::Error::from(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "map has invalid name",
+                    ))
+                })?;
+                #[allow(clippy::match_single_binding)]
+                match name {
+                    "bpf_fds" => bpf_fds = Some(map),
+                    _ => panic!("encountered unexpected map: `{name}`"),
+                }
+            }
+
+            let slf = Self {
+                bpf_fds: bpf_fds.expect("map `bpf_fds` not present"),
+                _phantom: std::marker::PhantomData,
+            };
+            Ok(slf)
+        }
+    }
+    pub struct OpenPidIterProgs<'obj> {
+        pub bpftop_iter: libbpf_rs::OpenProgramMut<'obj>,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> OpenPidIterProgs<'obj> {
+        unsafe fn new(object: &mut libbpf_rs::OpenObject) -> libbpf_rs::Result<Self> {
+            let mut bpftop_iter = None;
+            let object = unsafe {
+                std::mem::transmute::<&mut libbpf_rs::OpenObject, &'obj mut libbpf_rs::OpenObject>(
+                    object,
+                )
+            };
+            for prog in object.progs_mut() {
+                let name = prog.name().to_str().ok_or_else(|| {
+                    libbpf_rs::Error::from(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "prog has invalid name",
+                    ))
+                })?;
+                match name {
+                    "bpftop_iter" => bpftop_iter = Some(prog),
+                    _ => panic!("encountered unexpected prog: `{name}`"),
+                }
+            }
+
+            let slf = Self {
+                bpftop_iter: bpftop_iter.expect("prog `bpftop_iter` not present"),
+                _phantom: std::marker::PhantomData,
+            };
+            Ok(slf)
+        }
+    }
+    pub struct PidIterProgs<'obj> {
+        pub bpftop_iter: libbpf_rs::ProgramMut<'obj>,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> PidIterProgs<'obj> {
+        #[allow(unused_variables)]
+        fn new(open_progs: OpenPidIterProgs<'obj>) -> Self {
+            Self {
+                bpftop_iter: unsafe {
+                    libbpf_rs::ProgramMut::new_mut(
+                        open_progs.bpftop_iter.as_libbpf_object().as_mut(),
+                    )
+                },
+                _phantom: std::marker::PhantomData,
+            }
+        }
+    }
+    struct OwnedRef<'obj, O> {
+        object: Option<&'obj mut std::mem::MaybeUninit<O>>,
+    }
+
+    impl<'obj, O> OwnedRef<'obj, O> {
+        /// # Safety
+        /// The object has to be initialized.
+        unsafe fn new(object: &'obj mut std::mem::MaybeUninit<O>) -> Self {
+            Self {
+                object: Some(object),
+            }
+        }
+
+        fn as_ref(&self) -> &O {
+            // SAFETY: As per the contract during construction, the
+            //         object has to be initialized.
+            unsafe { self.object.as_ref().unwrap().assume_init_ref() }
+        }
+
+        fn as_mut(&mut self) -> &mut O {
+            // SAFETY: As per the contract during construction, the
+            //         object has to be initialized.
+            unsafe { self.object.as_mut().unwrap().assume_init_mut() }
+        }
+
+        fn take(mut self) -> &'obj mut std::mem::MaybeUninit<O> {
+            self.object.take().unwrap()
+        }
+    }
+
+    impl<O> Drop for OwnedRef<'_, O> {
+        fn drop(&mut self) {
+            if let Some(object) = &mut self.object {
+                unsafe { object.assume_init_drop() }
+            }
+        }
+    }
+
+    #[derive(Default)]
+    pub struct PidIterSkelBuilder {
+        pub obj_builder: libbpf_rs::ObjectBuilder,
+    }
+
+    impl<'obj> PidIterSkelBuilder {
+        fn open_opts_impl(
+            self,
+            open_opts: *const libbpf_sys::bpf_object_open_opts,
+            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+        ) -> libbpf_rs::Result<OpenPidIterSkel<'obj>> {
+            let skel_config = build_skel_config()?;
+            let skel_ptr = skel_config.as_libbpf_object();
+
+            let ret =
+                unsafe { libbpf_sys::bpf_object__open_skeleton(skel_ptr.as_ptr(), open_opts) };
+            if ret != 0 {
+                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
+            }
+
+            // SAFETY: `skel_ptr` points to a valid object after the
+            //         open call.
+            let obj_ptr = unsafe { *skel_ptr.as_ref().obj };
+            // SANITY: `bpf_object__open_skeleton` should have
+            //         allocated the object.
+            let obj_ptr = std::ptr::NonNull::new(obj_ptr).unwrap();
+            // SAFETY: `obj_ptr` points to an opened object after
+            //         skeleton open.
+            let obj = unsafe { libbpf_rs::OpenObject::from_ptr(obj_ptr) };
+            let _obj = object.write(obj);
+            // SAFETY: We just wrote initialized data to `object`.
+            let mut obj_ref = unsafe { OwnedRef::new(object) };
+
+            #[allow(unused_mut)]
+            let mut skel = OpenPidIterSkel {
+                maps: unsafe { OpenPidIterMaps::new(&skel_config, obj_ref.as_mut())? },
+                progs: unsafe { OpenPidIterProgs::new(obj_ref.as_mut())? },
+                obj: obj_ref,
+                // SAFETY: Our `struct_ops` type contains only pointers,
+                //         which are allowed to be NULL.
+                // TODO: Generate and use a `Default` representation
+                //       instead, to cut down on unsafe code.
+                struct_ops: unsafe { std::mem::zeroed() },
+                skel_config,
+            };
+
+            Ok(skel)
+        }
+    }
+
+    impl<'obj> SkelBuilder<'obj> for PidIterSkelBuilder {
+        type Output = OpenPidIterSkel<'obj>;
+        fn open(
+            self,
+            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+        ) -> libbpf_rs::Result<OpenPidIterSkel<'obj>> {
+            self.open_opts_impl(std::ptr::null(), object)
+        }
+
+        fn open_opts(
+            self,
+            open_opts: libbpf_sys::bpf_object_open_opts,
+            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+        ) -> libbpf_rs::Result<OpenPidIterSkel<'obj>> {
+            self.open_opts_impl(&open_opts, object)
+        }
+
+        fn object_builder(&self) -> &libbpf_rs::ObjectBuilder {
+            &self.obj_builder
+        }
+        fn object_builder_mut(&mut self) -> &mut libbpf_rs::ObjectBuilder {
+            &mut self.obj_builder
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    #[repr(C)]
+    pub struct StructOps {}
+
+    impl StructOps {}
+    pub mod types {
+        #[allow(unused_imports)]
+        use super::*;
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct __anon_1 {
+            pub r#type: *mut [i32; 1],
+            pub max_entries: *mut [i32; 16384],
+            pub key: *mut i32,
+            pub value: *mut u32,
+        }
+        impl Default for __anon_1 {
+            fn default() -> Self {
+                Self {
+                    r#type: std::ptr::null_mut(),
+                    max_entries: std::ptr::null_mut(),
+                    key: std::ptr::null_mut(),
+                    value: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct bpf_iter__task_file {
+            pub meta: *mut bpf_iter_meta,
+            pub task: *mut task_struct,
+            pub fd: u32,
+            pub __pad_20: [u8; 4],
+            pub file: *mut file,
+        }
+        impl Default for bpf_iter__task_file {
+            fn default() -> Self {
+                Self {
+                    meta: std::ptr::null_mut(),
+                    task: std::ptr::null_mut(),
+                    fd: u32::default(),
+                    __pad_20: [u8::default(); 4],
+                    file: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct bpf_iter_meta {
+            pub seq: *mut std::ffi::c_void,
+        }
+        impl Default for bpf_iter_meta {
+            fn default() -> Self {
+                Self {
+                    seq: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct task_struct {
+            pub tgid: i32,
+            pub __pad_4: [u8; 4],
+            pub group_leader: *mut task_struct,
+            pub comm: [i8; 16],
+            pub cgroups: *mut css_set,
+        }
+        impl Default for task_struct {
+            fn default() -> Self {
+                Self {
+                    tgid: i32::default(),
+                    __pad_4: [u8::default(); 4],
+                    group_leader: std::ptr::null_mut(),
+                    comm: [i8::default(); 16],
+                    cgroups: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct file {
+            pub f_op: *mut std::ffi::c_void,
+            pub private_data: *mut std::ffi::c_void,
+        }
+        impl Default for file {
+            fn default() -> Self {
+                Self {
+                    f_op: std::ptr::null_mut(),
+                    private_data: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct css_set {
+            pub dfl_cgrp: *mut cgroup,
+        }
+        impl Default for css_set {
+            fn default() -> Self {
+                Self {
+                    dfl_cgrp: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct cgroup {
+            pub kn: *mut kernfs_node,
+        }
+        impl Default for cgroup {
+            fn default() -> Self {
+                Self {
+                    kn: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Default, Copy, Clone)]
+        #[repr(C)]
+        pub struct kernfs_node {
+            pub id: u64,
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct bpf_prog {
+            pub aux: *mut bpf_prog_aux,
+        }
+        impl Default for bpf_prog {
+            fn default() -> Self {
+                Self {
+                    aux: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Default, Copy, Clone)]
+        #[repr(C)]
+        pub struct bpf_prog_aux {
+            pub id: u32,
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct license {
+            pub _license: [i8; 4],
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct maps {
+            pub bpf_fds: __anon_1,
+        }
+    }
+    pub struct OpenPidIterSkel<'obj> {
+        obj: OwnedRef<'obj, libbpf_rs::OpenObject>,
+        pub maps: OpenPidIterMaps<'obj>,
+        pub progs: OpenPidIterProgs<'obj>,
+        pub struct_ops: StructOps,
+        skel_config: libbpf_rs::__internal_skel::ObjectSkeletonConfig<'obj>,
+    }
+
+    impl<'obj> OpenSkel<'obj> for OpenPidIterSkel<'obj> {
+        type Output = PidIterSkel<'obj>;
+        fn load(self) -> libbpf_rs::Result<PidIterSkel<'obj>> {
+            let skel_ptr = self.skel_config.as_libbpf_object().as_ptr();
+
+            let ret = unsafe { libbpf_sys::bpf_object__load_skeleton(skel_ptr) };
+            if ret != 0 {
+                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
+            }
+
+            let obj_ref = self.obj.take();
+            let open_obj = std::mem::replace(obj_ref, std::mem::MaybeUninit::uninit());
+            // SAFETY: `open_obj` is guaranteed to be properly
+            //         initialized as it came from an `OwnedRef`.
+            let obj_ptr = unsafe { open_obj.assume_init().take_ptr() };
+            // SAFETY: `obj_ptr` points to a loaded object after
+            //         skeleton load.
+            let obj = unsafe { libbpf_rs::Object::from_ptr(obj_ptr) };
+            // SAFETY: `OpenObject` and `Object` are guaranteed to
+            //         have the same memory layout.
+            let obj_ref = unsafe {
+                std::mem::transmute::<
+                    &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+                    &'obj mut std::mem::MaybeUninit<libbpf_rs::Object>,
+                >(obj_ref)
+            };
+            let _obj = obj_ref.write(obj);
+            // SAFETY: We just wrote initialized data to `obj_ref`.
+            let mut obj_ref = unsafe { OwnedRef::new(obj_ref) };
+
+            Ok(PidIterSkel {
+                maps: unsafe { PidIterMaps::new(&self.skel_config, obj_ref.as_mut())? },
+                progs: PidIterProgs::new(self.progs),
+                obj: obj_ref,
+                struct_ops: self.struct_ops,
+                skel_config: self.skel_config,
+                links: PidIterLinks::default(),
+            })
+        }
+
+        fn open_object(&self) -> &libbpf_rs::OpenObject {
+            self.obj.as_ref()
+        }
+
+        fn open_object_mut(&mut self) -> &mut libbpf_rs::OpenObject {
+            self.obj.as_mut()
+        }
+    }
+    #[derive(Default)]
+    pub struct PidIterLinks {
+        pub bpftop_iter: Option<libbpf_rs::Link>,
+    }
+    pub struct PidIterSkel<'obj> {
+        obj: OwnedRef<'obj, libbpf_rs::Object>,
+        pub maps: PidIterMaps<'obj>,
+        pub progs: PidIterProgs<'obj>,
+        struct_ops: StructOps,
+        skel_config: libbpf_rs::__internal_skel::ObjectSkeletonConfig<'obj>,
+        pub links: PidIterLinks,
+    }
+
+    unsafe impl Send for PidIterSkel<'_> {}
+    unsafe impl Sync for PidIterSkel<'_> {}
+
+    impl<'obj> Skel<'obj> for PidIterSkel<'obj> {
+        fn object(&self) -> &libbpf_rs::Object {
+            self.obj.as_ref()
+        }
+
+        fn object_mut(&mut self) -> &mut libbpf_rs::Object {
+            self.obj.as_mut()
+        }
+        fn attach(&mut self) -> libbpf_rs::Result<()> {
+            let skel_ptr = self.skel_config.as_libbpf_object().as_ptr();
+            let ret = unsafe { libbpf_sys::bpf_object__attach_skeleton(skel_ptr) };
+            if ret != 0 {
+                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
+            }
+
+            self.links = PidIterLinks {
+                bpftop_iter: core::ptr::NonNull::new(self.skel_config.prog_link_ptr(0)?)
+                    .map(|ptr| unsafe { libbpf_rs::Link::from_ptr(ptr) }),
+            };
+
+            Ok(())
+        }
+    }
+    impl PidIterSkel<'_> {
+        pub fn struct_ops_raw(&self) -> *const StructOps {
+            &self.struct_ops
+        }
+
+        pub fn struct_ops(&self) -> &StructOps {
+            &self.struct_ops
+        }
+    }
+    const DATA: &[u8] = &[
+        127, 69, 76, 70, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 247, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
+        0, 64, 0, 9, 0, 1, 0, 0, 46, 115, 116, 114, 116, 97, 98, 0, 46, 115, 121, 109, 116, 97, 98,
+        0, 105, 116, 101, 114, 47, 116, 97, 115, 107, 95, 102, 105, 108, 101, 0, 108, 105, 99, 101,
+        110, 115, 101, 0, 46, 109, 97, 112, 115, 0, 112, 105, 100, 95, 105, 116, 101, 114, 46, 108,
+        108, 0, 76, 66, 66, 48, 95, 49, 50, 0, 76, 66, 66, 48, 95, 56, 0, 76, 66, 66, 48, 95, 53,
+        0, 76, 66, 66, 48, 95, 55, 0, 76, 66, 66, 48, 95, 49, 48, 0, 76, 66, 66, 48, 95, 49, 49, 0,
+        98, 112, 102, 116, 111, 112, 95, 105, 116, 101, 114, 0, 98, 112, 102, 95, 112, 114, 111,
+        103, 95, 102, 111, 112, 115, 0, 98, 112, 102, 95, 108, 105, 110, 107, 95, 102, 111, 112,
+        115, 0, 98, 112, 102, 95, 109, 97, 112, 95, 102, 111, 112, 115, 0, 98, 112, 102, 95, 102,
+        100, 115, 0, 95, 108, 105, 99, 101, 110, 115, 101, 0, 46, 114, 101, 108, 105, 116, 101,
+        114, 47, 116, 97, 115, 107, 95, 102, 105, 108, 101, 0, 46, 66, 84, 70, 0, 46, 66, 84, 70,
+        46, 101, 120, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 46, 0, 0, 0, 4, 0, 241, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 3, 0,
+        160, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 3, 0, 208, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 0, 3, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 3, 0, 80, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        87, 0, 0, 0, 0, 0, 3, 0, 32, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 95, 0, 0, 0, 0,
+        0, 3, 0, 112, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 18, 0, 3, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 176, 3, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 143, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156,
+        0, 0, 0, 17, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 17, 0,
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 191, 22, 0, 0, 0, 0, 0, 0, 121, 105,
+        8, 0, 0, 0, 0, 0, 121, 103, 24, 0, 0, 0, 0, 0, 21, 7, 112, 0, 0, 0, 0, 0, 21, 9, 111, 0, 0,
+        0, 0, 0, 97, 152, 0, 0, 0, 0, 0, 0, 121, 113, 0, 0, 0, 0, 0, 0, 24, 2, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 29, 33, 16, 0, 0, 0, 0, 0, 24, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 29, 33, 3, 0, 0, 0, 0, 0, 24, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 33,
+        100, 0, 0, 0, 0, 0, 99, 138, 220, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0,
+        220, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0,
+        0, 21, 0, 83, 0, 0, 0, 0, 0, 180, 1, 0, 0, 1, 0, 0, 0, 195, 16, 0, 0, 0, 0, 0, 0, 5, 0, 90,
+        0, 0, 0, 0, 0, 99, 138, 220, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 220,
+        255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0,
+        21, 0, 3, 0, 0, 0, 0, 0, 180, 1, 0, 0, 1, 0, 0, 0, 195, 16, 0, 0, 0, 0, 0, 0, 5, 0, 10, 0,
+        0, 0, 0, 0, 180, 1, 0, 0, 1, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0,
+        0, 0, 7, 2, 0, 0, 220, 255, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 216, 255,
+        255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 1, 0, 0, 0, 133,
+        0, 0, 0, 2, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 123, 26, 232, 255, 0, 0, 0, 0, 123, 26, 248,
+        255, 0, 0, 0, 0, 123, 26, 240, 255, 0, 0, 0, 0, 123, 26, 224, 255, 0, 0, 0, 0, 99, 138,
+        236, 255, 0, 0, 0, 0, 183, 1, 0, 0, 32, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0,
+        0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 208, 255, 255, 255, 191, 129, 0, 0, 0,
+        0, 0, 0, 180, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0,
+        121, 163, 208, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 180,
+        2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 121, 163, 208,
+        255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 180, 2, 0, 0, 8, 0,
+        0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 121, 163, 208, 255, 0, 0, 0, 0,
+        15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 123,
+        26, 200, 255, 0, 0, 0, 0, 180, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 121, 115,
+        8, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0,
+        0, 0, 180, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 121,
+        163, 208, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0,
+        0, 232, 255, 255, 255, 180, 2, 0, 0, 4, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 121, 147, 8,
+        0, 0, 0, 0, 0, 183, 1, 0, 0, 16, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0,
+        0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 180, 2, 0, 0, 16, 0, 0, 0, 133, 0, 0, 0, 115, 0, 0,
+        0, 121, 97, 0, 0, 0, 0, 0, 0, 121, 17, 0, 0, 0, 0, 0, 0, 121, 162, 200, 255, 0, 0, 0, 0,
+        180, 3, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 127, 0, 0, 0, 5, 0, 10, 0, 0, 0, 0, 0, 180, 1, 0,
+        0, 1, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 220,
+        255, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 216, 255, 255, 255, 24, 1, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 1, 0, 0, 0, 133, 0, 0, 0, 2, 0, 0, 0, 180,
+        0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 71, 80, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0,
+        0, 0, 0, 0, 1, 0, 0, 0, 10, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 11, 0, 0, 0, 104,
+        0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 12, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 13, 0,
+        0, 0, 232, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 13, 0, 0, 0, 80, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+        0, 13, 0, 0, 0, 128, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 13, 0, 0, 0, 159, 235, 1, 0, 24, 0,
+        0, 0, 0, 0, 0, 0, 228, 3, 0, 0, 228, 3, 0, 0, 236, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0,
+        0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+        2, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0,
+        0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 9, 0, 0, 0, 25, 0,
+        0, 0, 0, 0, 0, 8, 10, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0,
+        0, 4, 0, 0, 4, 32, 0, 0, 0, 44, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 5, 0, 0, 0,
+        64, 0, 0, 0, 61, 0, 0, 0, 7, 0, 0, 0, 128, 0, 0, 0, 65, 0, 0, 0, 8, 0, 0, 0, 192, 0, 0, 0,
+        71, 0, 0, 0, 0, 0, 0, 14, 11, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 14, 0, 0, 0, 79,
+        0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 99, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 16,
+        0, 0, 0, 64, 0, 0, 0, 109, 0, 0, 0, 10, 0, 0, 0, 128, 0, 0, 0, 112, 0, 0, 0, 17, 0, 0, 0,
+        192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 20, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 2, 24, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 13, 2, 0, 0, 0, 117, 0, 0, 0, 15, 0,
+        0, 0, 121, 0, 0, 0, 1, 0, 0, 12, 18, 0, 0, 0, 133, 0, 0, 0, 4, 0, 0, 4, 40, 0, 0, 0, 145,
+        0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0, 16, 0, 0, 0, 64, 0, 0, 0, 163, 0, 0, 0, 22,
+        0, 0, 0, 128, 0, 0, 0, 168, 0, 0, 0, 23, 0, 0, 0, 0, 1, 0, 0, 176, 0, 0, 0, 0, 0, 0, 1, 1,
+        0, 0, 0, 8, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 21, 0, 0, 0, 4, 0, 0, 0, 16, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 29, 0, 0, 0, 112, 0, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 181, 0, 0,
+        0, 25, 0, 0, 0, 0, 0, 0, 0, 186, 0, 0, 0, 28, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+        26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 27, 0, 0, 0, 199, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 215, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 223, 0, 0, 0, 30, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 31, 0, 0, 0, 232, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0,
+        0, 239, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 33, 0, 0, 0, 242, 0, 0,
+        0, 1, 0, 0, 4, 8, 0, 0, 0, 254, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 8,
+        35, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 64, 0, 0, 0, 24, 1, 0, 0, 1, 0, 0, 4, 8,
+        0, 0, 0, 33, 1, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 38, 0, 0, 0, 37, 1,
+        0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 254, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 50, 1, 0, 0, 1, 0, 0,
+        4, 8, 0, 0, 0, 64, 1, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 41, 0, 0, 0,
+        68, 1, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 21, 0, 0, 0, 4, 0,
+        0, 0, 4, 0, 0, 0, 77, 1, 0, 0, 0, 0, 0, 14, 42, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        10, 0, 0, 0, 0, 86, 1, 0, 0, 0, 0, 0, 14, 44, 0, 0, 0, 2, 0, 0, 0, 100, 1, 0, 0, 0, 0, 0,
+        14, 44, 0, 0, 0, 2, 0, 0, 0, 113, 1, 0, 0, 0, 0, 0, 14, 44, 0, 0, 0, 2, 0, 0, 0, 200, 1, 0,
+        0, 1, 0, 0, 15, 4, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 208, 1, 0, 0, 1, 0, 0, 15,
+        32, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 214, 1, 0, 0, 3, 0, 0, 15, 0, 0, 0, 0,
+        45, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 47, 0, 0, 0, 0,
+        0, 0, 0, 1, 0, 0, 0, 0, 105, 110, 116, 0, 95, 95, 65, 82, 82, 65, 89, 95, 83, 73, 90, 69,
+        95, 84, 89, 80, 69, 95, 95, 0, 95, 95, 117, 51, 50, 0, 117, 110, 115, 105, 103, 110, 101,
+        100, 32, 105, 110, 116, 0, 116, 121, 112, 101, 0, 109, 97, 120, 95, 101, 110, 116, 114,
+        105, 101, 115, 0, 107, 101, 121, 0, 118, 97, 108, 117, 101, 0, 98, 112, 102, 95, 102, 100,
+        115, 0, 98, 112, 102, 95, 105, 116, 101, 114, 95, 95, 116, 97, 115, 107, 95, 102, 105, 108,
+        101, 0, 109, 101, 116, 97, 0, 116, 97, 115, 107, 0, 102, 100, 0, 102, 105, 108, 101, 0, 99,
+        116, 120, 0, 98, 112, 102, 116, 111, 112, 95, 105, 116, 101, 114, 0, 116, 97, 115, 107, 95,
+        115, 116, 114, 117, 99, 116, 0, 116, 103, 105, 100, 0, 103, 114, 111, 117, 112, 95, 108,
+        101, 97, 100, 101, 114, 0, 99, 111, 109, 109, 0, 99, 103, 114, 111, 117, 112, 115, 0, 99,
+        104, 97, 114, 0, 102, 95, 111, 112, 0, 112, 114, 105, 118, 97, 116, 101, 95, 100, 97, 116,
+        97, 0, 102, 105, 108, 101, 95, 111, 112, 101, 114, 97, 116, 105, 111, 110, 115, 0, 99, 115,
+        115, 95, 115, 101, 116, 0, 100, 102, 108, 95, 99, 103, 114, 112, 0, 99, 103, 114, 111, 117,
+        112, 0, 107, 110, 0, 107, 101, 114, 110, 102, 115, 95, 110, 111, 100, 101, 0, 105, 100, 0,
+        117, 54, 52, 0, 117, 110, 115, 105, 103, 110, 101, 100, 32, 108, 111, 110, 103, 32, 108,
+        111, 110, 103, 0, 98, 112, 102, 95, 112, 114, 111, 103, 0, 97, 117, 120, 0, 98, 112, 102,
+        95, 112, 114, 111, 103, 95, 97, 117, 120, 0, 98, 112, 102, 95, 105, 116, 101, 114, 95, 109,
+        101, 116, 97, 0, 115, 101, 113, 0, 115, 101, 113, 95, 102, 105, 108, 101, 0, 95, 108, 105,
+        99, 101, 110, 115, 101, 0, 98, 112, 102, 95, 112, 114, 111, 103, 95, 102, 111, 112, 115, 0,
+        98, 112, 102, 95, 109, 97, 112, 95, 102, 111, 112, 115, 0, 98, 112, 102, 95, 108, 105, 110,
+        107, 95, 102, 111, 112, 115, 0, 47, 114, 111, 111, 116, 47, 99, 114, 97, 116, 101, 47, 115,
+        114, 99, 47, 98, 112, 102, 47, 112, 105, 100, 95, 105, 116, 101, 114, 46, 98, 112, 102, 46,
+        99, 0, 83, 69, 67, 40, 34, 105, 116, 101, 114, 47, 116, 97, 115, 107, 95, 102, 105, 108,
+        101, 34, 41, 0, 48, 58, 49, 0, 48, 58, 51, 0, 48, 58, 48, 0, 48, 58, 50, 0, 108, 105, 99,
+        101, 110, 115, 101, 0, 46, 109, 97, 112, 115, 0, 46, 107, 115, 121, 109, 115, 0, 105, 116,
+        101, 114, 47, 116, 97, 115, 107, 95, 102, 105, 108, 101, 0, 159, 235, 1, 0, 32, 0, 0, 0, 0,
+        0, 0, 0, 20, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 48, 0, 0, 0, 252, 0, 0, 0, 8, 0, 0, 0, 221,
+        1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 16, 0, 0, 0, 221, 1, 0, 0, 1, 0, 0, 0, 0, 0,
+        0, 0, 127, 1, 0, 0, 162, 1, 0, 0, 0, 148, 0, 0, 16, 0, 0, 0, 221, 1, 0, 0, 15, 0, 0, 0, 8,
+        0, 0, 0, 14, 0, 0, 0, 184, 1, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 14, 0, 0, 0, 188, 1, 0, 0, 0,
+        0, 0, 0, 40, 0, 0, 0, 20, 0, 0, 0, 192, 1, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 24, 0, 0, 0, 192,
+        1, 0, 0, 0, 0, 0, 0, 160, 1, 0, 0, 20, 0, 0, 0, 188, 1, 0, 0, 0, 0, 0, 0, 224, 1, 0, 0, 29,
+        0, 0, 0, 192, 1, 0, 0, 0, 0, 0, 0, 16, 2, 0, 0, 31, 0, 0, 0, 192, 1, 0, 0, 0, 0, 0, 0, 64,
+        2, 0, 0, 33, 0, 0, 0, 192, 1, 0, 0, 0, 0, 0, 0, 128, 2, 0, 0, 24, 0, 0, 0, 184, 1, 0, 0, 0,
+        0, 0, 0, 136, 2, 0, 0, 36, 0, 0, 0, 192, 1, 0, 0, 0, 0, 0, 0, 176, 2, 0, 0, 38, 0, 0, 0,
+        192, 1, 0, 0, 0, 0, 0, 0, 232, 2, 0, 0, 20, 0, 0, 0, 184, 1, 0, 0, 0, 0, 0, 0, 240, 2, 0,
+        0, 20, 0, 0, 0, 196, 1, 0, 0, 0, 0, 0, 0, 32, 3, 0, 0, 14, 0, 0, 0, 192, 1, 0, 0, 0, 0, 0,
+        0, 40, 3, 0, 0, 39, 0, 0, 0, 192, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+        3, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 206,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 9, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 1, 0, 0, 0,
+        0, 0, 0, 104, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 24, 0,
+        0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        120, 2, 0, 0, 0, 0, 0, 0, 176, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 40, 6, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 6, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 173, 0, 0, 0, 9, 0, 0, 0, 64, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 6, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0,
+        0, 2, 0, 0, 0, 3, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0,
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 6, 0, 0, 0, 0, 0, 0, 232,
+        5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 197, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 12, 0, 0,
+        0, 0, 0, 0, 76, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0,
+    ];
+}
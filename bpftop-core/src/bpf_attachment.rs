@@ -0,0 +1,489 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    cgroups::{cgroup_hook, CgroupPrograms},
+    interfaces::{forget_tc_filter, prog_tc_filters},
+    netlink::{NetlinkSocket, TcFilter, TC_EGRESS_PARENT, TC_INGRESS_PARENT},
+};
+use anyhow::{Context, Result};
+use nix::libc;
+use std::{
+    ffi::{c_void, CStr},
+    io,
+    mem::size_of_val,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+};
+
+// Protocol families and hooks from linux/netfilter.h
+const NFPROTO_INET: u32 = 1;
+const NFPROTO_IPV4: u32 = 2;
+const NFPROTO_ARP: u32 = 3;
+const NFPROTO_NETDEV: u32 = 5;
+const NFPROTO_BRIDGE: u32 = 7;
+const NFPROTO_IPV6: u32 = 10;
+
+const BPF_F_NETFILTER_IP_DEFRAG: u32 = 1 << 0;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BpfAttachment {
+    pub link_id: u32,
+    pub link_type: String,
+    // Human readable description of where the link is attached, empty if unknown
+    pub target: String,
+    // Attach type of the link, named after the libbpf section of the programs using it,
+    // empty for link types that only have one
+    pub attach_type: String,
+}
+
+impl BpfAttachment {
+    pub fn describe(&self) -> String {
+        if self.target.is_empty() {
+            format!("{} (link {})", self.link_type, self.link_id)
+        } else {
+            format!("{} {} (link {})", self.link_type, self.target, self.link_id)
+        }
+    }
+}
+
+// An attachment of the program shown in the graph view, which can be detached from there
+#[derive(Clone, Debug, PartialEq)]
+pub enum Attachment {
+    Link(BpfAttachment),
+    // cls_bpf filters attached through the legacy tc API are not links
+    TcFilter(TcFilter),
+}
+
+impl Attachment {
+    pub fn describe(&self) -> String {
+        match self {
+            Attachment::Link(link) => link.describe(),
+            Attachment::TcFilter(filter) => format!(
+                "tc {} {} pref {} (handle {:#x})",
+                describe_ifindex(filter.ifindex),
+                tc_hook(filter.parent),
+                filter.priority(),
+                filter.handle
+            ),
+        }
+    }
+
+    // What has to be typed to confirm detaching it
+    pub fn confirmation(&self) -> String {
+        match self {
+            Attachment::Link(link) => format!("link {}", link.link_id),
+            Attachment::TcFilter(filter) => format!("handle {:#x}", filter.handle),
+        }
+    }
+
+    pub fn detach(&self) -> Result<()> {
+        match self {
+            Attachment::Link(link) => {
+                detach_link(link.link_id).context(format!("Failed to detach link {}", link.link_id))
+            }
+            Attachment::TcFilter(filter) => {
+                NetlinkSocket::open()
+                    .and_then(|mut netlink| netlink.delete_tc_filter(filter))
+                    .context(format!(
+                        "Failed to delete tc filter {:#x} of ifindex {}",
+                        filter.handle, filter.ifindex
+                    ))?;
+                forget_tc_filter(filter);
+                Ok(())
+            }
+        }
+    }
+}
+
+fn tc_hook(parent: u32) -> String {
+    match parent {
+        TC_INGRESS_PARENT => "ingress".to_string(),
+        TC_EGRESS_PARENT => "egress".to_string(),
+        _ => format!("parent {:#x}", parent),
+    }
+}
+
+// Detaches a link from its hook without waiting for its holders to release it. The link
+// stays around, defunct, until they do.
+fn detach_link(link_id: u32) -> io::Result<()> {
+    let fd = unsafe { libbpf_sys::bpf_link_get_fd_by_id(link_id) };
+    if fd < 0 {
+        return Err(io::Error::from_raw_os_error(-fd));
+    }
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+    let ret = unsafe { libbpf_sys::bpf_link_detach(fd.as_raw_fd()) };
+    if ret < 0 {
+        return Err(io::Error::from_raw_os_error(-ret));
+    }
+    Ok(())
+}
+
+fn link_type_to_string(link_type: u32) -> String {
+    match link_type {
+        libbpf_sys::BPF_LINK_TYPE_RAW_TRACEPOINT => "raw_tracepoint",
+        libbpf_sys::BPF_LINK_TYPE_TRACING => "tracing",
+        libbpf_sys::BPF_LINK_TYPE_CGROUP => "cgroup",
+        libbpf_sys::BPF_LINK_TYPE_ITER => "iter",
+        libbpf_sys::BPF_LINK_TYPE_NETNS => "netns",
+        libbpf_sys::BPF_LINK_TYPE_XDP => "xdp",
+        libbpf_sys::BPF_LINK_TYPE_PERF_EVENT => "perf_event",
+        libbpf_sys::BPF_LINK_TYPE_KPROBE_MULTI => "kprobe_multi",
+        libbpf_sys::BPF_LINK_TYPE_STRUCT_OPS => "struct_ops",
+        libbpf_sys::BPF_LINK_TYPE_NETFILTER => "netfilter",
+        libbpf_sys::BPF_LINK_TYPE_TCX => "tcx",
+        libbpf_sys::BPF_LINK_TYPE_UPROBE_MULTI => "uprobe_multi",
+        libbpf_sys::BPF_LINK_TYPE_NETKIT => "netkit",
+        _ => return format!("unknown ({})", link_type),
+    }
+    .to_string()
+}
+
+// How a tracing link hooks its target, named after the libbpf section prefixes
+fn tracing_attach_type(attach_type: u32) -> String {
+    match attach_type {
+        libbpf_sys::BPF_TRACE_RAW_TP => "tp_btf",
+        libbpf_sys::BPF_TRACE_FENTRY => "fentry",
+        libbpf_sys::BPF_TRACE_FEXIT => "fexit",
+        libbpf_sys::BPF_MODIFY_RETURN => "fmod_ret",
+        libbpf_sys::BPF_LSM_MAC => "lsm",
+        libbpf_sys::BPF_LSM_CGROUP => "lsm_cgroup",
+        libbpf_sys::BPF_TRACE_ITER => "iter",
+        // Programs replacing a function of another program have no attach type of their own
+        0 => "freplace",
+        _ => return format!("attach type {}", attach_type),
+    }
+    .to_string()
+}
+
+fn link_attach_type(info: &libbpf_sys::bpf_link_info) -> String {
+    let data = &info.__bindgen_anon_1;
+    let attach_type = unsafe {
+        match info.type_ {
+            libbpf_sys::BPF_LINK_TYPE_TRACING => data.tracing.attach_type,
+            libbpf_sys::BPF_LINK_TYPE_CGROUP => data.cgroup.attach_type,
+            libbpf_sys::BPF_LINK_TYPE_NETNS => data.netns.attach_type,
+            libbpf_sys::BPF_LINK_TYPE_TCX => data.tcx.attach_type,
+            libbpf_sys::BPF_LINK_TYPE_NETKIT => data.netkit.attach_type,
+            _ => return String::new(),
+        }
+    };
+    match (info.type_, attach_type) {
+        (libbpf_sys::BPF_LINK_TYPE_TRACING, _) => tracing_attach_type(attach_type),
+        (libbpf_sys::BPF_LINK_TYPE_CGROUP, _) => cgroup_hook(attach_type),
+        (_, libbpf_sys::BPF_FLOW_DISSECTOR) => "flow_dissector".to_string(),
+        (_, libbpf_sys::BPF_SK_LOOKUP) => "sk_lookup".to_string(),
+        (_, libbpf_sys::BPF_TCX_INGRESS) => "tcx/ingress".to_string(),
+        (_, libbpf_sys::BPF_TCX_EGRESS) => "tcx/egress".to_string(),
+        (_, libbpf_sys::BPF_NETKIT_PRIMARY) => "netkit/primary".to_string(),
+        (_, libbpf_sys::BPF_NETKIT_PEER) => "netkit/peer".to_string(),
+        _ => format!("attach type {}", attach_type),
+    }
+}
+
+// The kernel does not report the expected attach type of programs, which sets apart e.g.
+// bind4 from connect6 cgroup programs, so it is taken from how they are attached. Links
+// report it, and programs attached to cgroups without one are listed per attach type.
+pub fn expected_attach_type(
+    prog_id: u32,
+    links: &[(u32, BpfAttachment)],
+    cgroups: &[CgroupPrograms],
+) -> Option<String> {
+    links
+        .iter()
+        .find(|(link_prog_id, link)| *link_prog_id == prog_id && !link.attach_type.is_empty())
+        .map(|(_, link)| link.attach_type.clone())
+        .or_else(|| {
+            cgroups.iter().find_map(|cgroup| {
+                cgroup
+                    .attached
+                    .iter()
+                    .find(|(_, id)| *id == prog_id)
+                    .map(|(hook, _)| hook.to_string())
+            })
+        })
+}
+
+fn netfilter_family(pf: u32) -> String {
+    match pf {
+        NFPROTO_INET => "inet",
+        NFPROTO_IPV4 => "ipv4",
+        NFPROTO_ARP => "arp",
+        NFPROTO_NETDEV => "netdev",
+        NFPROTO_BRIDGE => "bridge",
+        NFPROTO_IPV6 => "ipv6",
+        _ => return format!("pf {}", pf),
+    }
+    .to_string()
+}
+
+fn netfilter_hook(pf: u32, hooknum: u32) -> String {
+    let hook = match pf {
+        NFPROTO_ARP => match hooknum {
+            0 => Some("in"),
+            1 => Some("out"),
+            2 => Some("forward"),
+            _ => None,
+        },
+        NFPROTO_NETDEV => match hooknum {
+            0 => Some("ingress"),
+            1 => Some("egress"),
+            _ => None,
+        },
+        _ => match hooknum {
+            0 => Some("prerouting"),
+            1 => Some("input"),
+            2 => Some("forward"),
+            3 => Some("output"),
+            4 => Some("postrouting"),
+            _ => None,
+        },
+    };
+    hook.map(str::to_string)
+        .unwrap_or_else(|| format!("hook {}", hooknum))
+}
+
+// Formats a netfilter link the same way `bpftool link` does, e.g. "ipv4 prerouting prio -128"
+fn describe_netfilter(pf: u32, hooknum: u32, priority: i32, flags: u32) -> String {
+    let mut target = format!(
+        "{} {} prio {}",
+        netfilter_family(pf),
+        netfilter_hook(pf, hooknum),
+        priority
+    );
+    if flags & BPF_F_NETFILTER_IP_DEFRAG != 0 {
+        target.push_str(" defrag");
+    }
+    target
+}
+
+// Names the interface a networking link is attached to, e.g. "iface eth0"
+fn describe_ifindex(ifindex: u32) -> String {
+    let mut name = [0; libc::IF_NAMESIZE];
+    let ret = unsafe { libc::if_indextoname(ifindex, name.as_mut_ptr()) };
+    if ret.is_null() {
+        return format!("ifindex {}", ifindex);
+    }
+    let name = unsafe { CStr::from_ptr(name.as_ptr()) };
+    format!("iface {}", name.to_string_lossy())
+}
+
+fn describe_target(info: &libbpf_sys::bpf_link_info) -> String {
+    let data = &info.__bindgen_anon_1;
+    unsafe {
+        match info.type_ {
+            libbpf_sys::BPF_LINK_TYPE_CGROUP => format!("cgroup {}", data.cgroup.cgroup_id),
+            libbpf_sys::BPF_LINK_TYPE_NETNS => format!("netns {}", data.netns.netns_ino),
+            libbpf_sys::BPF_LINK_TYPE_XDP => describe_ifindex(data.xdp.ifindex),
+            libbpf_sys::BPF_LINK_TYPE_TCX => describe_ifindex(data.tcx.ifindex),
+            libbpf_sys::BPF_LINK_TYPE_NETKIT => describe_ifindex(data.netkit.ifindex),
+            libbpf_sys::BPF_LINK_TYPE_STRUCT_OPS => format!("map {}", data.struct_ops.map_id),
+            libbpf_sys::BPF_LINK_TYPE_TRACING => tracing_attach_type(data.tracing.attach_type),
+            libbpf_sys::BPF_LINK_TYPE_NETFILTER => describe_netfilter(
+                data.netfilter.pf,
+                data.netfilter.hooknum,
+                data.netfilter.priority,
+                data.netfilter.flags,
+            ),
+            _ => String::new(),
+        }
+    }
+}
+
+fn link_info(link_id: u32) -> Option<libbpf_sys::bpf_link_info> {
+    let fd = unsafe { libbpf_sys::bpf_link_get_fd_by_id(link_id) };
+    if fd < 0 {
+        return None;
+    }
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    let mut info = libbpf_sys::bpf_link_info::default();
+    let mut len = size_of_val(&info) as u32;
+    let ret = unsafe {
+        libbpf_sys::bpf_obj_get_info_by_fd(
+            fd.as_raw_fd(),
+            &mut info as *mut _ as *mut c_void,
+            &mut len,
+        )
+    };
+    (ret == 0).then_some(info)
+}
+
+// Returns every BPF link on the host along with the ID of the program it attaches. The raw
+// link info is decoded directly since libbpf-rs reports newer link types such as netfilter
+// as unknown.
+pub fn get_links() -> Vec<(u32, BpfAttachment)> {
+    let mut links = vec![];
+    let mut id = 0;
+
+    loop {
+        if unsafe { libbpf_sys::bpf_link_get_next_id(id, &mut id) } != 0 {
+            break;
+        }
+
+        // The link may have been released since its ID was returned
+        let Some(info) = link_info(id) else {
+            continue;
+        };
+
+        links.push((
+            info.prog_id,
+            BpfAttachment {
+                link_id: id,
+                link_type: link_type_to_string(info.type_),
+                target: describe_target(&info),
+                attach_type: link_attach_type(&info),
+            },
+        ));
+    }
+
+    links
+}
+
+// Returns every BPF link the given program is attached through
+pub fn get_prog_attachments(prog_id: u32) -> Vec<BpfAttachment> {
+    get_links()
+        .into_iter()
+        .filter(|(link_prog_id, _)| *link_prog_id == prog_id)
+        .map(|(_, attachment)| attachment)
+        .collect()
+}
+
+// Returns the links and legacy tc filters the given program is attached through
+pub fn get_detachable_attachments(prog_id: u32) -> Vec<Attachment> {
+    let mut attachments: Vec<Attachment> = get_prog_attachments(prog_id)
+        .into_iter()
+        .map(Attachment::Link)
+        .collect();
+    attachments.extend(
+        prog_tc_filters(prog_id)
+            .into_iter()
+            .map(Attachment::TcFilter),
+    );
+    attachments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_netfilter() {
+        assert_eq!(
+            describe_netfilter(NFPROTO_IPV4, 0, -128, 0),
+            "ipv4 prerouting prio -128"
+        );
+        assert_eq!(
+            describe_netfilter(NFPROTO_IPV6, 1, 10, BPF_F_NETFILTER_IP_DEFRAG),
+            "ipv6 input prio 10 defrag"
+        );
+        assert_eq!(describe_netfilter(42, 9, 0, 0), "pf 42 hook 9 prio 0");
+    }
+
+    #[test]
+    fn test_tracing_attach_type() {
+        assert_eq!(tracing_attach_type(libbpf_sys::BPF_TRACE_FENTRY), "fentry");
+        assert_eq!(tracing_attach_type(libbpf_sys::BPF_LSM_MAC), "lsm");
+        assert_eq!(tracing_attach_type(0), "freplace");
+        assert_eq!(tracing_attach_type(99), "attach type 99");
+    }
+
+    #[test]
+    fn test_expected_attach_type() {
+        let links = vec![
+            (
+                1,
+                BpfAttachment {
+                    link_id: 7,
+                    link_type: "xdp".to_string(),
+                    target: "iface lo".to_string(),
+                    attach_type: String::new(),
+                },
+            ),
+            (
+                2,
+                BpfAttachment {
+                    link_id: 8,
+                    link_type: "cgroup".to_string(),
+                    target: "cgroup 42".to_string(),
+                    attach_type: cgroup_hook(libbpf_sys::BPF_CGROUP_INET6_CONNECT),
+                },
+            ),
+        ];
+        let cgroups = vec![CgroupPrograms {
+            id: 42,
+            path: "/system.slice".to_string(),
+            attached: vec![("bind4", 3)],
+            events_per_second: 0,
+            cpu_time_percent: 0.0,
+        }];
+        assert_eq!(expected_attach_type(1, &links, &cgroups), None);
+        assert_eq!(
+            expected_attach_type(2, &links, &cgroups).as_deref(),
+            Some("connect6")
+        );
+        assert_eq!(
+            expected_attach_type(3, &links, &cgroups).as_deref(),
+            Some("bind4")
+        );
+    }
+
+    #[test]
+    fn test_describe_ifindex() {
+        assert_eq!(describe_ifindex(1), "iface lo");
+        assert_eq!(describe_ifindex(u32::MAX), format!("ifindex {}", u32::MAX));
+    }
+
+    #[test]
+    fn test_describe() {
+        let attachment = BpfAttachment {
+            link_id: 7,
+            link_type: link_type_to_string(libbpf_sys::BPF_LINK_TYPE_NETFILTER),
+            target: "ipv4 input prio 0".to_string(),
+            attach_type: String::new(),
+        };
+        assert_eq!(
+            attachment.describe(),
+            "netfilter ipv4 input prio 0 (link 7)"
+        );
+    }
+
+    #[test]
+    fn test_attachment_confirmation() {
+        let link = Attachment::Link(BpfAttachment {
+            link_id: 7,
+            link_type: "xdp".to_string(),
+            target: "iface lo".to_string(),
+            attach_type: String::new(),
+        });
+        assert_eq!(link.describe(), "xdp iface lo (link 7)");
+        assert_eq!(link.confirmation(), "link 7");
+
+        let filter = Attachment::TcFilter(TcFilter {
+            ifindex: 1,
+            parent: TC_INGRESS_PARENT,
+            handle: 1,
+            info: 0xC000_0300,
+            chain: 0,
+            prog_id: 42,
+        });
+        assert_eq!(
+            filter.describe(),
+            "tc iface lo ingress pref 49152 (handle 0x1)"
+        );
+        assert_eq!(filter.confirmation(), "handle 0x1");
+    }
+}
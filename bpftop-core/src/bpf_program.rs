@@ -15,16 +15,28 @@
  *  limitations under the License.
  *
  */
+use crate::container::Container;
+use libbpf_rs::num_possible_cpus;
 use std::{
+    collections::HashSet,
     fmt::{self},
+    sync::OnceLock,
     time::Instant,
 };
 
+// Upper bound of cpu_time_percent, as a program can run on every CPU at once
+fn max_cpu_time_percent() -> f64 {
+    static MAX: OnceLock<f64> = OnceLock::new();
+    *MAX.get_or_init(|| num_possible_cpus().unwrap_or(1) as f64 * 100.0)
+}
+
 #[derive(Clone, Debug)]
 pub struct BpfProgram {
     pub id: u32,
     pub bpf_type: String,
     pub name: String,
+    // Hash of the program's instructions, as shown by bpftool
+    pub tag: String,
     pub prev_runtime_ns: u64,
     pub run_time_ns: u64,
     pub prev_run_cnt: u64,
@@ -39,6 +51,13 @@ pub struct BpfProgram {
 pub struct Process {
     pub pid: i32,
     pub comm: String,
+    pub container: Option<Container>,
+    // cgroup v2 ID, 0 if unknown
+    pub cgroup_id: u64,
+    // Number of BPF program, map and link fds the process holds
+    pub bpf_fds: u32,
+    // Name of the user the process runs as, or its UID if it has no passwd entry
+    pub user: Option<String>,
 }
 
 impl fmt::Display for Process {
@@ -70,12 +89,48 @@ impl BpfProgram {
         self.run_time_ns / self.run_cnt
     }
 
+    // Counters only go backwards if the program was replaced or its stats were reset, in
+    // which case there is no meaningful delta
     pub fn runtime_delta(&self) -> u64 {
-        self.run_time_ns - self.prev_runtime_ns
+        self.run_time_ns.saturating_sub(self.prev_runtime_ns)
     }
 
     pub fn run_cnt_delta(&self) -> u64 {
-        self.run_cnt - self.prev_run_cnt
+        self.run_cnt.saturating_sub(self.prev_run_cnt)
+    }
+
+    // Number of distinct processes holding the program, as a process appears once per fd
+    pub fn holders(&self) -> usize {
+        let mut pids: Vec<i32> = self.processes.iter().map(|process| process.pid).collect();
+        pids.sort_unstable();
+        pids.dedup();
+        pids.len()
+    }
+
+    // Distinct containers of the processes holding the program
+    pub fn containers(&self) -> String {
+        let mut names: Vec<&str> = self
+            .processes
+            .iter()
+            .filter_map(|process| process.container.as_ref())
+            .map(|container| container.display_name())
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        names.join(", ")
+    }
+
+    // Distinct pods of the processes holding the program, as namespace/name
+    pub fn pods(&self) -> String {
+        let mut pods: Vec<String> = self
+            .processes
+            .iter()
+            .filter_map(|process| process.container.as_ref()?.pod.as_ref())
+            .map(|pod| pod.to_string())
+            .collect();
+        pods.sort_unstable();
+        pods.dedup();
+        pods.join(", ")
     }
 
     pub fn events_per_second(&self) -> i64 {
@@ -84,6 +139,10 @@ impl BpfProgram {
         }
         let events_per_second =
             self.run_cnt_delta() as f64 / self.period_ns as f64 * 1_000_000_000.0;
+        if !events_per_second.is_finite() {
+            return 0;
+        }
+        // Saturates at i64::MAX
         events_per_second.round() as i64
     }
 
@@ -91,10 +150,32 @@ impl BpfProgram {
         if self.period_ns == 0 {
             return 0.0;
         }
-        self.runtime_delta() as f64 / self.period_ns as f64 * 100.0
+        let percent = self.runtime_delta() as f64 / self.period_ns as f64 * 100.0;
+        if !percent.is_finite() {
+            return 0.0;
+        }
+        percent.clamp(0.0, max_cpu_time_percent())
     }
 }
 
+// Combined events per second and CPU utilization of the given programs. Programs listed
+// more than once, e.g. because they are attached to several hooks, only count once.
+pub fn combined_stats(
+    programs: &[BpfProgram],
+    prog_ids: impl IntoIterator<Item = u32>,
+) -> (i64, f64) {
+    let prog_ids: HashSet<u32> = prog_ids.into_iter().collect();
+    programs
+        .iter()
+        .filter(|prog| prog_ids.contains(&prog.id))
+        .fold((0, 0.0), |(eps, cpu), prog| {
+            (
+                eps.saturating_add(prog.events_per_second()),
+                cpu + prog.cpu_time_percent(),
+            )
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,6 +186,7 @@ mod tests {
             id: 1,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100,
             run_time_ns: 200,
             prev_run_cnt: 1,
@@ -118,6 +200,7 @@ mod tests {
             id: 2,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100,
             run_time_ns: 200,
             prev_run_cnt: 1,
@@ -137,6 +220,7 @@ mod tests {
             id: 1,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100,
             run_time_ns: 200,
             prev_run_cnt: 1,
@@ -154,6 +238,7 @@ mod tests {
             id: 1,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100,
             run_time_ns: 1000,
             prev_run_cnt: 1,
@@ -171,6 +256,7 @@ mod tests {
             id: 1,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100,
             run_time_ns: 200,
             prev_run_cnt: 1,
@@ -188,6 +274,7 @@ mod tests {
             id: 1,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100,
             run_time_ns: 200,
             prev_run_cnt: 5,
@@ -205,6 +292,7 @@ mod tests {
             id: 1,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100,
             run_time_ns: 200,
             prev_run_cnt: 10,
@@ -222,6 +310,7 @@ mod tests {
             id: 1,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100_000_000,
             run_time_ns: 200_000_000,
             prev_run_cnt: 0,
@@ -234,4 +323,73 @@ mod tests {
         let expected = 10.0;
         assert_eq!(prog.cpu_time_percent(), expected);
     }
+
+    #[test]
+    fn test_counter_reset() {
+        let prog = BpfProgram {
+            id: 1,
+            bpf_type: "test".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: u64::MAX,
+            run_time_ns: 200,
+            prev_run_cnt: 50,
+            run_cnt: 2,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        };
+        assert_eq!(prog.runtime_delta(), 0);
+        assert_eq!(prog.run_cnt_delta(), 0);
+        assert_eq!(prog.period_average_runtime_ns(), 0);
+        assert_eq!(prog.events_per_second(), 0);
+        assert_eq!(prog.cpu_time_percent(), 0.0);
+    }
+
+    #[test]
+    fn test_cpu_time_percent_clamped() {
+        let prog = BpfProgram {
+            id: 1,
+            bpf_type: "test".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: u64::MAX,
+            prev_run_cnt: 0,
+            run_cnt: u64::MAX,
+            instant: Instant::now(),
+            period_ns: 1,
+            processes: vec![],
+        };
+        assert_eq!(prog.cpu_time_percent(), max_cpu_time_percent());
+        assert_eq!(prog.events_per_second(), i64::MAX);
+    }
+
+    #[test]
+    fn test_holders() {
+        let holder = |pid| Process {
+            pid,
+            comm: "test".to_string(),
+            container: None,
+            cgroup_id: 0,
+            bpf_fds: 1,
+            user: None,
+        };
+        let mut prog = BpfProgram {
+            id: 1,
+            bpf_type: "test".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes: vec![],
+        };
+        assert_eq!(prog.holders(), 0);
+        prog.processes = vec![holder(10), holder(20), holder(10)];
+        assert_eq!(prog.holders(), 2);
+    }
 }
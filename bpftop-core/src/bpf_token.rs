@@ -0,0 +1,88 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use anyhow::{anyhow, Context, Result};
+use nix::libc;
+use std::{
+    ffi::CString,
+    fs::File,
+    io,
+    os::{
+        fd::{AsRawFd, FromRawFd, OwnedFd},
+        unix::ffi::OsStrExt,
+    },
+    path::Path,
+};
+
+// Mount options a bpffs needs for its tokens to allow loading and attaching pid_iter
+const DELEGATE_OPTIONS: &str =
+    "delegate_cmds=prog_load:map_create:btf_load:link_create,delegate_progs=tracing,delegate_attachs=any";
+
+fn explain(err: &io::Error) -> &'static str {
+    match err.raw_os_error() {
+        // The command is unknown before 6.9, and so is BPF_TOKEN_CREATE for a non-bpffs path
+        Some(libc::EINVAL) => "needs Linux 6.9 or later and a bpffs mount point",
+        // Nothing was delegated to the mount
+        Some(libc::ENOENT) => "the bpffs mount does not delegate anything",
+        // The mount belongs to another user namespace
+        Some(libc::EPERM) => "the bpffs mount was not created in this user namespace",
+        _ => "unexpected error",
+    }
+}
+
+// Creates a BPF token from a bpffs mounted with delegation options, which lets a process in
+// a user namespace load the BPF programs the mount delegates
+pub fn create(bpffs: &Path) -> Result<OwnedFd> {
+    let dir = File::open(bpffs).context(format!("Failed to open {}", bpffs.display()))?;
+    let fd = unsafe { libbpf_sys::bpf_token_create(dir.as_raw_fd(), std::ptr::null_mut()) };
+    if fd < 0 {
+        let err = io::Error::from_raw_os_error(-fd);
+        return Err(anyhow!(
+            "Failed to create a BPF token from {}: {} ({}). Mount it with -o {}",
+            bpffs.display(),
+            err,
+            explain(&err),
+            DELEGATE_OPTIONS
+        ));
+    }
+    Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+}
+
+// libbpf creates its own token from the path when loading an object
+pub fn token_path(bpffs: &Path) -> Result<CString> {
+    CString::new(bpffs.as_os_str().as_bytes())
+        .map_err(|_| anyhow!("Invalid bpffs path {}", bpffs.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain() {
+        let explain_errno = |errno| explain(&io::Error::from_raw_os_error(errno));
+        assert_eq!(
+            explain_errno(libc::EINVAL),
+            "needs Linux 6.9 or later and a bpffs mount point"
+        );
+        assert_eq!(
+            explain_errno(libc::ENOENT),
+            "the bpffs mount does not delegate anything"
+        );
+        assert_eq!(explain_errno(libc::EIO), "unexpected error");
+    }
+}
@@ -0,0 +1,369 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::bpf_program::{combined_stats, BpfProgram, Process};
+use anyhow::{anyhow, Context, Result};
+use libbpf_sys::{bpf_attach_type, bpf_prog_query};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File},
+    os::{fd::AsRawFd, unix::fs::MetadataExt},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+// How often a cgroup scope looks for new cgroups and attachments in its subtree
+const SCOPE_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+// The kernel allows at most this many programs per cgroup and attach type
+const MAX_PROGS_PER_HOOK: usize = 64;
+
+// Every attach type of the cgroup program types, with the hook name used in section names
+const CGROUP_HOOKS: [(bpf_attach_type, &str); 28] = [
+    (libbpf_sys::BPF_CGROUP_INET_INGRESS, "ingress"),
+    (libbpf_sys::BPF_CGROUP_INET_EGRESS, "egress"),
+    (libbpf_sys::BPF_CGROUP_INET_SOCK_CREATE, "sock_create"),
+    (libbpf_sys::BPF_CGROUP_INET_SOCK_RELEASE, "sock_release"),
+    (libbpf_sys::BPF_CGROUP_INET4_POST_BIND, "post_bind4"),
+    (libbpf_sys::BPF_CGROUP_INET6_POST_BIND, "post_bind6"),
+    (libbpf_sys::BPF_CGROUP_SOCK_OPS, "sock_ops"),
+    (libbpf_sys::BPF_CGROUP_DEVICE, "dev"),
+    (libbpf_sys::BPF_CGROUP_SYSCTL, "sysctl"),
+    (libbpf_sys::BPF_CGROUP_GETSOCKOPT, "getsockopt"),
+    (libbpf_sys::BPF_CGROUP_SETSOCKOPT, "setsockopt"),
+    (libbpf_sys::BPF_CGROUP_INET4_BIND, "bind4"),
+    (libbpf_sys::BPF_CGROUP_INET6_BIND, "bind6"),
+    (libbpf_sys::BPF_CGROUP_INET4_CONNECT, "connect4"),
+    (libbpf_sys::BPF_CGROUP_INET6_CONNECT, "connect6"),
+    (libbpf_sys::BPF_CGROUP_UNIX_CONNECT, "connect_unix"),
+    (libbpf_sys::BPF_CGROUP_UDP4_SENDMSG, "sendmsg4"),
+    (libbpf_sys::BPF_CGROUP_UDP6_SENDMSG, "sendmsg6"),
+    (libbpf_sys::BPF_CGROUP_UNIX_SENDMSG, "sendmsg_unix"),
+    (libbpf_sys::BPF_CGROUP_UDP4_RECVMSG, "recvmsg4"),
+    (libbpf_sys::BPF_CGROUP_UDP6_RECVMSG, "recvmsg6"),
+    (libbpf_sys::BPF_CGROUP_UNIX_RECVMSG, "recvmsg_unix"),
+    (libbpf_sys::BPF_CGROUP_INET4_GETPEERNAME, "getpeername4"),
+    (libbpf_sys::BPF_CGROUP_INET6_GETPEERNAME, "getpeername6"),
+    (libbpf_sys::BPF_CGROUP_UNIX_GETPEERNAME, "getpeername_unix"),
+    (libbpf_sys::BPF_CGROUP_INET4_GETSOCKNAME, "getsockname4"),
+    (libbpf_sys::BPF_CGROUP_INET6_GETSOCKNAME, "getsockname6"),
+    (libbpf_sys::BPF_CGROUP_UNIX_GETSOCKNAME, "getsockname_unix"),
+];
+
+#[derive(Clone, Debug)]
+pub struct CgroupPrograms {
+    // The cgroup ID, which is the inode number of its directory
+    pub id: u64,
+    // Relative to the cgroup2 mount
+    pub path: String,
+    // Hook and ID of every program attached directly to the cgroup
+    pub attached: Vec<(&'static str, u32)>,
+    pub events_per_second: i64,
+    pub cpu_time_percent: f64,
+}
+
+impl PartialEq for CgroupPrograms {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl CgroupPrograms {
+    pub fn programs(&self) -> String {
+        self.attached
+            .iter()
+            .map(|(hook, prog_id)| format!("{}:{}", hook, prog_id))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    // A program attached to several cgroups counts towards each of them
+    pub fn update_stats(&mut self, programs: &[BpfProgram]) {
+        (self.events_per_second, self.cpu_time_percent) =
+            combined_stats(programs, self.attached.iter().map(|(_, prog_id)| *prog_id));
+    }
+}
+
+// Names a cgroup attach type after its hook in section names, e.g. connect6
+pub fn cgroup_hook(attach_type: bpf_attach_type) -> String {
+    CGROUP_HOOKS
+        .iter()
+        .find(|(hook_type, _)| *hook_type == attach_type)
+        .map(|(_, hook)| hook.to_string())
+        .unwrap_or_else(|| format!("attach type {}", attach_type))
+}
+
+// Finds where the unified hierarchy is mounted, which is /sys/fs/cgroup on most hosts and
+// /sys/fs/cgroup/unified on hybrid ones. BPF programs cannot attach to v1 cgroups.
+fn cgroup2_mount(mounts: &str) -> Option<&str> {
+    mounts.lines().find_map(|line| {
+        let mut fields = line.split_whitespace();
+        let mount_point = fields.nth(1)?;
+        (fields.next()? == "cgroup2").then_some(mount_point)
+    })
+}
+
+// Programs attached directly to the cgroup, not the ones it inherits from its ancestors
+fn query_attached(dir: &File) -> Vec<(&'static str, u32)> {
+    let mut attached = vec![];
+    for (attach_type, hook) in CGROUP_HOOKS {
+        let mut prog_ids = [0u32; MAX_PROGS_PER_HOOK];
+        let mut prog_cnt = MAX_PROGS_PER_HOOK as u32;
+        let mut attach_flags = 0;
+        // Fails for hooks the kernel does not know
+        let ret = unsafe {
+            bpf_prog_query(
+                dir.as_raw_fd(),
+                attach_type,
+                0,
+                &mut attach_flags,
+                prog_ids.as_mut_ptr(),
+                &mut prog_cnt,
+            )
+        };
+        if ret != 0 {
+            continue;
+        }
+        let prog_cnt = (prog_cnt as usize).min(MAX_PROGS_PER_HOOK);
+        attached.extend(prog_ids[..prog_cnt].iter().map(|prog_id| (hook, *prog_id)));
+    }
+    attached
+}
+
+fn walk(root: &Path, dir: &Path, cgroups: &mut Vec<CgroupPrograms>) {
+    let Ok(file) = File::open(dir) else {
+        return;
+    };
+    let attached = query_attached(&file);
+    if !attached.is_empty() {
+        let relative = dir.strip_prefix(root).unwrap_or(dir);
+        cgroups.push(CgroupPrograms {
+            id: file.metadata().map(|metadata| metadata.ino()).unwrap_or(0),
+            path: format!("/{}", relative.display()),
+            attached,
+            events_per_second: 0,
+            cpu_time_percent: 0.0,
+        });
+    }
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            walk(root, &entry.path(), cgroups);
+        }
+    }
+}
+
+fn walk_paths(root: &Path, dir: &Path, paths: &mut HashMap<u64, String>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            let path = entry.path();
+            let relative = path.strip_prefix(root).unwrap_or(&path);
+            paths.insert(metadata.ino(), format!("/{}", relative.display()));
+            walk_paths(root, &path, paths);
+        }
+    }
+}
+
+// Maps the ID of every cgroup, which is the inode number of its directory, to its path
+pub fn cgroup_paths() -> HashMap<u64, String> {
+    let mut paths = HashMap::new();
+    let Ok(mounts) = fs::read_to_string("/proc/self/mounts") else {
+        return paths;
+    };
+    if let Some(mount) = cgroup2_mount(&mounts) {
+        walk_paths(Path::new(mount), Path::new(mount), &mut paths);
+    }
+    paths
+}
+
+// Walks the cgroup hierarchy for cgroups with programs attached, through links or the
+// legacy attach API alike. This takes a query per cgroup and hook, so callers should not
+// do it every sample.
+pub fn scan_cgroups() -> Vec<CgroupPrograms> {
+    let mut cgroups = vec![];
+    let Ok(mounts) = fs::read_to_string("/proc/self/mounts") else {
+        return cgroups;
+    };
+    if let Some(mount) = cgroup2_mount(&mounts) {
+        let root = Path::new(mount);
+        walk(root, root, &mut cgroups);
+    }
+    cgroups
+}
+
+// Whether a cgroup path, relative to the cgroup2 mount, is the scope or below it
+fn in_subtree(path: &str, scope: &str) -> bool {
+    let scope = scope.trim_end_matches('/');
+    path == scope || path.starts_with(&format!("{}/", scope))
+}
+
+// The path of the process' cgroup in the unified hierarchy, from /proc/<pid>/cgroup
+fn parse_cgroup2_path(contents: &str) -> Option<&str> {
+    contents.lines().find_map(|line| line.strip_prefix("0::"))
+}
+
+// Restricts programs to a cgroup subtree: the ones attached to a cgroup within it and the
+// ones held by a process inside it
+pub struct CgroupScope {
+    // Relative to the cgroup2 mount, like the paths of CgroupPrograms
+    pub path: String,
+    dir: PathBuf,
+    // IDs of the cgroups in the subtree, for the cgroup IDs reported by pid_iter
+    ids: HashSet<u64>,
+    // Programs attached to a cgroup in the subtree
+    attached: HashSet<u32>,
+    refreshed: Option<Instant>,
+}
+
+impl CgroupScope {
+    pub fn new(dir: &Path) -> Result<Self> {
+        let mounts = fs::read_to_string("/proc/self/mounts")?;
+        let mount = cgroup2_mount(&mounts).ok_or(anyhow!("No cgroup2 hierarchy is mounted"))?;
+        let dir = dir
+            .canonicalize()
+            .with_context(|| format!("Failed to resolve {}", dir.display()))?;
+        let relative = dir.strip_prefix(mount).map_err(|_| {
+            anyhow!(
+                "{} is not in the cgroup2 hierarchy mounted at {}",
+                dir.display(),
+                mount
+            )
+        })?;
+        Ok(CgroupScope {
+            path: format!("/{}", relative.display()),
+            dir,
+            ids: HashSet::new(),
+            attached: HashSet::new(),
+            refreshed: None,
+        })
+    }
+
+    fn refresh(&mut self) {
+        if self
+            .refreshed
+            .is_some_and(|last| last.elapsed() < SCOPE_REFRESH_INTERVAL)
+        {
+            return;
+        }
+        self.refreshed = Some(Instant::now());
+        let mut paths = HashMap::new();
+        walk_paths(&self.dir, &self.dir, &mut paths);
+        self.ids = paths.into_keys().collect();
+        if let Ok(metadata) = fs::metadata(&self.dir) {
+            self.ids.insert(metadata.ino());
+        }
+        let mut cgroups = vec![];
+        walk(&self.dir, &self.dir, &mut cgroups);
+        self.attached = cgroups
+            .iter()
+            .flat_map(|cgroup| cgroup.attached.iter().map(|(_, prog_id)| *prog_id))
+            .collect();
+    }
+
+    fn holds(&self, process: &Process) -> bool {
+        if process.cgroup_id != 0 {
+            return self.ids.contains(&process.cgroup_id);
+        }
+        // Without pid_iter, the cgroup is only known from procfs
+        fs::read_to_string(format!("/proc/{}/cgroup", process.pid)).is_ok_and(|contents| {
+            parse_cgroup2_path(&contents).is_some_and(|path| in_subtree(path, &self.path))
+        })
+    }
+
+    pub fn retain(&mut self, programs: &mut Vec<BpfProgram>) {
+        self.refresh();
+        programs.retain(|prog| {
+            self.attached.contains(&prog.id)
+                || prog.processes.iter().any(|process| self.holds(process))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn program(id: u32, run_time_ns: u64, run_cnt: u64) -> BpfProgram {
+        BpfProgram {
+            id,
+            bpf_type: "CgroupSkb".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns,
+            prev_run_cnt: 0,
+            run_cnt,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_cgroup2_mount() {
+        let mounts = "cgroup /sys/fs/cgroup/memory cgroup rw,memory 0 0\n\
+                      cgroup2 /sys/fs/cgroup/unified cgroup2 rw,nsdelegate 0 0\n";
+        assert_eq!(cgroup2_mount(mounts), Some("/sys/fs/cgroup/unified"));
+        assert_eq!(cgroup2_mount("proc /proc proc rw 0 0\n"), None);
+    }
+
+    #[test]
+    fn test_in_subtree() {
+        let scope = "/kubepods.slice/kubepods-pod1.slice";
+        assert!(in_subtree(scope, scope));
+        assert!(in_subtree(
+            "/kubepods.slice/kubepods-pod1.slice/cri-containerd-abc.scope",
+            scope
+        ));
+        assert!(!in_subtree("/kubepods.slice/kubepods-pod10.slice", scope));
+        assert!(!in_subtree("/kubepods.slice", scope));
+        assert!(in_subtree("/system.slice", "/"));
+        assert_eq!(
+            parse_cgroup2_path("1:name=systemd:/init.scope\n0::/system.slice/nginx.service\n"),
+            Some("/system.slice/nginx.service")
+        );
+    }
+
+    #[test]
+    fn test_update_stats() {
+        let mut cgroup = CgroupPrograms {
+            id: 4242,
+            path: "/system.slice/nginx.service".to_string(),
+            attached: vec![("ingress", 10), ("egress", 11), ("sock_create", 10)],
+            events_per_second: 0,
+            cpu_time_percent: 0.0,
+        };
+        cgroup.update_stats(&[
+            program(10, 10_000_000, 100),
+            program(11, 20_000_000, 50),
+            program(12, 30_000_000, 10),
+        ]);
+        assert_eq!(cgroup.events_per_second, 150);
+        assert!((cgroup.cpu_time_percent - 3.0).abs() < 1e-9);
+        assert_eq!(cgroup.programs(), "ingress:10, egress:11, sock_create:10");
+    }
+}
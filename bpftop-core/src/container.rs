@@ -0,0 +1,247 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+#[cfg(feature = "kubernetes")]
+use crate::kubernetes::PodResolver;
+use crate::{cgroups::cgroup_paths, kubernetes::Pod};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    time::{Duration, Instant},
+};
+
+// Docker keeps each container's name in its config
+const DOCKER_CONTAINERS_DIR: &str = "/var/lib/docker/containers";
+
+// The root cgroup, which is where every process is on hosts without the unified hierarchy
+const ROOT_CGROUP_ID: u64 = 1;
+
+// Cgroups created since the last walk are picked up after at most this long
+const CGROUP_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+const CONTAINER_ID_LEN: usize = 64;
+const SHORT_ID_LEN: usize = 12;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Container {
+    pub runtime: &'static str,
+    pub id: String,
+    pub name: Option<String>,
+    // Only resolved with the kubernetes feature
+    pub pod: Option<Pod>,
+}
+
+impl Container {
+    // Name if known, otherwise the short ID used by docker and crictl
+    pub fn display_name(&self) -> &str {
+        self.name
+            .as_deref()
+            .unwrap_or(&self.id[..SHORT_ID_LEN.min(self.id.len())])
+    }
+}
+
+fn is_container_id(value: &str) -> bool {
+    value.len() == CONTAINER_ID_LEN && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+// Recognizes the cgroup directory of a container, as created by the systemd cgroup driver
+// ("docker-<id>.scope", "cri-containerd-<id>.scope", ...) or the cgroupfs one
+// ("/docker/<id>", "/kubepods/burstable/pod<uid>/<id>")
+fn parse_segment(parent: &str, segment: &str) -> Option<(&'static str, String)> {
+    const SCOPE_PREFIXES: [(&str, &str); 4] = [
+        ("docker-", "docker"),
+        ("cri-containerd-", "containerd"),
+        ("crio-", "cri-o"),
+        ("libpod-", "podman"),
+    ];
+
+    if let Some(scope) = segment.strip_suffix(".scope") {
+        return SCOPE_PREFIXES.iter().find_map(|(prefix, runtime)| {
+            let id = scope.strip_prefix(prefix)?;
+            is_container_id(id).then(|| (*runtime, id.to_string()))
+        });
+    }
+    if !is_container_id(segment) {
+        return None;
+    }
+    let runtime = match parent {
+        "docker" => "docker",
+        parent if parent.starts_with("pod") || parent.starts_with("kubepods") => "cri",
+        _ => return None,
+    };
+    Some((runtime, segment.to_string()))
+}
+
+// Finds the container of a cgroup from its path
+pub fn parse_cgroup_path(path: &str) -> Option<Container> {
+    let segments: Vec<&str> = path.split('/').collect();
+    segments.windows(2).rev().find_map(|pair| {
+        let (runtime, id) = parse_segment(pair[0], pair[1])?;
+        Some(Container {
+            runtime,
+            id,
+            name: None,
+            pod: None,
+        })
+    })
+}
+
+// Finds the container a process runs in from the contents of /proc/<pid>/cgroup
+pub fn parse_cgroup(contents: &str) -> Option<Container> {
+    contents.lines().find_map(|line| {
+        // hierarchy-ID:controller-list:cgroup-path
+        let path = line.splitn(3, ':').nth(2)?;
+        parse_cgroup_path(path)
+    })
+}
+
+fn docker_name(id: &str) -> Option<String> {
+    let config =
+        fs::read_to_string(format!("{}/{}/config.v2.json", DOCKER_CONTAINERS_DIR, id)).ok()?;
+    let config: serde_json::Value = serde_json::from_str(&config).ok()?;
+    let name = config.get("Name")?.as_str()?;
+    Some(name.trim_start_matches('/').to_string())
+}
+
+// Resolves processes to containers, caching the result for as long as the process is seen
+#[derive(Default)]
+pub struct ContainerResolver {
+    processes: HashMap<i32, Option<Container>>,
+    names: HashMap<String, Option<String>>,
+    #[cfg(feature = "kubernetes")]
+    pods: PodResolver,
+    // Paths of the cgroups by ID, for the cgroup IDs reported by pid_iter
+    cgroups: HashMap<u64, String>,
+    cgroups_refreshed: Option<Instant>,
+}
+
+impl ContainerResolver {
+    fn cgroup_path(&mut self, cgroup_id: u64) -> Option<&String> {
+        if !self.cgroups.contains_key(&cgroup_id)
+            && self
+                .cgroups_refreshed
+                .is_none_or(|last| last.elapsed() >= CGROUP_REFRESH_INTERVAL)
+        {
+            self.cgroups = cgroup_paths();
+            self.cgroups_refreshed = Some(Instant::now());
+        }
+        self.cgroups.get(&cgroup_id)
+    }
+
+    // Finds the container from the cgroup ID when pid_iter reported one, and from
+    // /proc/<pid>/cgroup otherwise
+    fn find_container(&mut self, pid: i32, cgroup_id: u64) -> Option<Container> {
+        if cgroup_id != 0 && cgroup_id != ROOT_CGROUP_ID {
+            if let Some(path) = self.cgroup_path(cgroup_id) {
+                return parse_cgroup_path(path);
+            }
+        }
+        fs::read_to_string(format!("/proc/{}/cgroup", pid))
+            .ok()
+            .and_then(|contents| parse_cgroup(&contents))
+    }
+
+    pub fn resolve(&mut self, pid: i32, cgroup_id: u64) -> Option<Container> {
+        if let Some(container) = self.processes.get(&pid) {
+            return container.clone();
+        }
+        let container = self.find_container(pid, cgroup_id).map(|mut container| {
+            if container.runtime == "docker" {
+                container.name = self
+                    .names
+                    .entry(container.id.clone())
+                    .or_insert_with(|| docker_name(&container.id))
+                    .clone();
+            }
+            #[cfg(feature = "kubernetes")]
+            {
+                container.pod = self.pods.lookup(&container.id);
+                if container.name.is_none() {
+                    container.name = container.pod.as_ref().map(|pod| pod.container.clone());
+                }
+            }
+            container
+        });
+        self.processes.insert(pid, container.clone());
+        container
+    }
+
+    // Forgets processes that no longer hold any program
+    pub fn retain(&mut self, pids: &HashSet<i32>) {
+        self.processes.retain(|pid, _| pids.contains(pid));
+        let ids: HashSet<&String> = self
+            .processes
+            .values()
+            .flatten()
+            .map(|container| &container.id)
+            .collect();
+        self.names.retain(|id, _| ids.contains(id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "3b185f0a00ff129c3b185f0a00ff129c3b185f0a00ff129c3b185f0a00ff129c";
+
+    #[test]
+    fn test_parse_cgroup() {
+        let container = |contents: String| parse_cgroup(&contents).map(|c| (c.runtime, c.id));
+
+        assert_eq!(
+            container(format!("0::/system.slice/docker-{}.scope\n", ID)),
+            Some(("docker", ID.to_string()))
+        );
+        assert_eq!(
+            container(format!(
+                "0::/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1234.slice/cri-containerd-{}.scope\n",
+                ID
+            )),
+            Some(("containerd", ID.to_string()))
+        );
+        assert_eq!(
+            container(format!("12:pids:/kubepods/burstable/pod1234/{}\n", ID)),
+            Some(("cri", ID.to_string()))
+        );
+        assert_eq!(
+            container(format!("0::/docker/{}\n", ID)),
+            Some(("docker", ID.to_string()))
+        );
+        assert_eq!(
+            container("0::/user.slice/session-1.scope\n".to_string()),
+            None
+        );
+        assert_eq!(
+            container(format!("0::/machine.slice/libpod-conmon-{}.scope\n", ID)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_display_name() {
+        let mut container = Container {
+            runtime: "docker",
+            id: ID.to_string(),
+            name: None,
+            pod: None,
+        };
+        assert_eq!(container.display_name(), "3b185f0a00ff");
+        container.name = Some("cilium-agent".to_string());
+        assert_eq!(container.display_name(), "cilium-agent");
+    }
+}
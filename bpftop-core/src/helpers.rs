@@ -0,0 +1,234 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use libbpf_rs::{query::Tag, MapType, ProgramType};
+use std::{
+    fs,
+    os::fd::{AsFd, AsRawFd},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| now.as_millis() as u64)
+}
+
+pub fn format_percent(num: f64) -> String {
+    if num < 1.0 {
+        round_to_first_non_zero(num).to_string() + "%"
+    } else {
+        format!("{:.2}%", num)
+    }
+}
+
+pub fn round_to_first_non_zero(num: f64) -> f64 {
+    if num == 0.0 {
+        return 0.0;
+    }
+
+    let mut multiplier = 1.0;
+    while num * multiplier < 1.0 {
+        multiplier *= 10.0;
+    }
+    (num * multiplier).round() / multiplier
+}
+
+// Formats a program tag as hex, the way bpftool and fdinfo print it
+pub fn format_tag(tag: &Tag) -> String {
+    tag.0.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+// Parses a numeric field (e.g. "memlock:\t4096") out of the contents of a
+// /proc/<pid>/fdinfo/<fd> file
+pub fn parse_fdinfo_field(contents: &str, field: &str) -> Option<u64> {
+    contents.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        if key.trim() == field {
+            value.trim().parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+pub fn fdinfo_field(fd: &impl AsFd, field: &str) -> Option<u64> {
+    let path = format!("/proc/self/fdinfo/{}", fd.as_fd().as_raw_fd());
+    let contents = fs::read_to_string(path).ok()?;
+    parse_fdinfo_field(&contents, field)
+}
+
+pub fn program_type_to_string(program_type: ProgramType) -> String {
+    match program_type {
+        ProgramType::Unspec => "Unspec",
+        ProgramType::SocketFilter => "SocketFilter",
+        ProgramType::Kprobe => "Kprobe",
+        ProgramType::SchedCls => "SchedCls",
+        ProgramType::SchedAct => "SchedAct",
+        ProgramType::Tracepoint => "Tracepoint",
+        ProgramType::Xdp => "Xdp",
+        ProgramType::PerfEvent => "PerfEvent",
+        ProgramType::CgroupSkb => "CgroupSkb",
+        ProgramType::CgroupSock => "CgroupSock",
+        ProgramType::LwtIn => "LwtIn",
+        ProgramType::LwtOut => "LwtOut",
+        ProgramType::LwtXmit => "LwtXmit",
+        ProgramType::SockOps => "SockOps",
+        ProgramType::SkSkb => "SkSkb",
+        ProgramType::CgroupDevice => "CgroupDevice",
+        ProgramType::SkMsg => "SkMsg",
+        ProgramType::RawTracepoint => "RawTracepoint",
+        ProgramType::CgroupSockAddr => "CgroupSockAddr",
+        ProgramType::LwtSeg6local => "LwtSeg6local",
+        ProgramType::LircMode2 => "LircMode2",
+        ProgramType::SkReuseport => "SkReuseport",
+        ProgramType::FlowDissector => "FlowDissector",
+        ProgramType::CgroupSysctl => "CgroupSysctl",
+        ProgramType::RawTracepointWritable => "RawTracepointWritable",
+        ProgramType::CgroupSockopt => "CgroupSockopt",
+        ProgramType::Tracing => "Tracing",
+        ProgramType::StructOps => "StructOps",
+        ProgramType::Ext => "Ext",
+        ProgramType::Lsm => "Lsm",
+        ProgramType::SkLookup => "SkLookup",
+        ProgramType::Syscall => "Syscall",
+        _ => "Unknown",
+    }
+    .to_string()
+}
+
+pub fn map_type_to_string(map_type: MapType) -> String {
+    match map_type {
+        MapType::Unspec => "Unspec",
+        MapType::Hash => "Hash",
+        MapType::Array => "Array",
+        MapType::ProgArray => "ProgArray",
+        MapType::PerfEventArray => "PerfEventArray",
+        MapType::PercpuHash => "PercpuHash",
+        MapType::PercpuArray => "PercpuArray",
+        MapType::StackTrace => "StackTrace",
+        MapType::CgroupArray => "CgroupArray",
+        MapType::LruHash => "LruHash",
+        MapType::LruPercpuHash => "LruPercpuHash",
+        MapType::LpmTrie => "LpmTrie",
+        MapType::ArrayOfMaps => "ArrayOfMaps",
+        MapType::HashOfMaps => "HashOfMaps",
+        MapType::Devmap => "Devmap",
+        MapType::Sockmap => "Sockmap",
+        MapType::Cpumap => "Cpumap",
+        MapType::Xskmap => "Xskmap",
+        MapType::Sockhash => "Sockhash",
+        MapType::CgroupStorage => "CgroupStorage",
+        MapType::ReuseportSockarray => "ReuseportSockarray",
+        MapType::PercpuCgroupStorage => "PercpuCgroupStorage",
+        MapType::Queue => "Queue",
+        MapType::Stack => "Stack",
+        MapType::SkStorage => "SkStorage",
+        MapType::DevmapHash => "DevmapHash",
+        MapType::StructOps => "StructOps",
+        MapType::RingBuf => "RingBuf",
+        MapType::InodeStorage => "InodeStorage",
+        MapType::TaskStorage => "TaskStorage",
+        MapType::BloomFilter => "BloomFilter",
+        MapType::UserRingBuf => "UserRingBuf",
+        _ => "Unknown",
+    }
+    .to_string()
+}
+
+// Names a raw map type, including the ones newer than the libbpf-rs MapType, which reports
+// them as unknown
+pub fn map_type_name(map_type: u32) -> String {
+    match map_type {
+        libbpf_sys::BPF_MAP_TYPE_CGRP_STORAGE => "CgrpStorage".to_string(),
+        libbpf_sys::BPF_MAP_TYPE_ARENA => "Arena".to_string(),
+        _ => match MapType::from(map_type) {
+            MapType::Unknown => format!("Unknown ({})", map_type),
+            known => map_type_to_string(known),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_to_first_non_zero() {
+        assert_eq!(round_to_first_non_zero(0.002323), 0.002);
+        assert_eq!(round_to_first_non_zero(0.0000012), 0.000001);
+        assert_eq!(round_to_first_non_zero(0.00321), 0.003);
+    }
+
+    #[test]
+    fn test_program_type_to_string() {
+        let str = program_type_to_string(ProgramType::CgroupSkb);
+        assert_eq!(str, "CgroupSkb");
+    }
+
+    #[test]
+    fn test_map_type_to_string() {
+        let str = map_type_to_string(MapType::LruHash);
+        assert_eq!(str, "LruHash");
+    }
+
+    #[test]
+    fn test_map_type_name() {
+        assert_eq!(map_type_name(libbpf_sys::BPF_MAP_TYPE_LRU_HASH), "LruHash");
+        assert_eq!(map_type_name(libbpf_sys::BPF_MAP_TYPE_ARENA), "Arena");
+        assert_eq!(map_type_name(1000), "Unknown (1000)");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(4096), "4.0 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 + 512 * 1024), "3.5 MiB");
+    }
+
+    #[test]
+    fn test_format_tag() {
+        let tag = Tag([0x3b, 0x18, 0x5f, 0x0a, 0x00, 0xff, 0x12, 0x9c]);
+        assert_eq!(format_tag(&tag), "3b185f0a00ff129c");
+    }
+
+    #[test]
+    fn test_parse_fdinfo_field() {
+        let contents =
+            "pos:\t0\nflags:\t02000002\nmnt_id:\t15\nmap_type:\t1\nmemlock:\t4096\nmap_id:\t42\n";
+        assert_eq!(parse_fdinfo_field(contents, "memlock"), Some(4096));
+        assert_eq!(parse_fdinfo_field(contents, "map_id"), Some(42));
+        assert_eq!(parse_fdinfo_field(contents, "prog_id"), None);
+    }
+}
@@ -0,0 +1,299 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    bpf_program::{combined_stats, BpfProgram},
+    netlink::{NetlinkSocket, TcFilter, TC_EGRESS_PARENT, TC_INGRESS_PARENT},
+};
+use libbpf_sys::{bpf_prog_query_opts, bpf_xdp_query, bpf_xdp_query_opts};
+use std::{
+    fs,
+    mem::size_of,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+use tracing::error;
+
+const SYS_CLASS_NET: &str = "/sys/class/net";
+
+// How long dumped tc filters are served as is. Older ones are still served while they are
+// dumped again in the background, so looking up a program's filters never waits on netlink.
+const TC_FILTERS_TTL: Duration = Duration::from_secs(5);
+
+// The kernel does not cap the number of tcx programs, but more than this is unheard of
+const MAX_TCX_PROGS: usize = 64;
+
+#[derive(Clone, Debug)]
+pub struct InterfacePrograms {
+    pub ifindex: u32,
+    pub name: String,
+    // Hook and ID of every XDP, tcx and tc program attached to the interface
+    pub attached: Vec<(&'static str, u32)>,
+    pub events_per_second: i64,
+    pub cpu_time_percent: f64,
+}
+
+impl PartialEq for InterfacePrograms {
+    fn eq(&self, other: &Self) -> bool {
+        self.ifindex == other.ifindex
+    }
+}
+
+impl InterfacePrograms {
+    pub fn programs(&self) -> String {
+        self.attached
+            .iter()
+            .map(|(hook, prog_id)| format!("{}:{}", hook, prog_id))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    pub fn update_stats(&mut self, programs: &[BpfProgram]) {
+        (self.events_per_second, self.cpu_time_percent) =
+            combined_stats(programs, self.attached.iter().map(|(_, prog_id)| *prog_id));
+    }
+}
+
+// XDP programs in each of the modes they can be attached in, through links or not
+fn xdp_programs(ifindex: u32) -> Vec<(&'static str, u32)> {
+    let mut opts = bpf_xdp_query_opts {
+        sz: size_of::<bpf_xdp_query_opts>() as _,
+        ..Default::default()
+    };
+    if unsafe { bpf_xdp_query(ifindex as i32, 0, &mut opts) } != 0 {
+        return vec![];
+    }
+    [
+        ("xdp", opts.drv_prog_id),
+        ("xdpgeneric", opts.skb_prog_id),
+        ("xdpoffload", opts.hw_prog_id),
+    ]
+    .into_iter()
+    .filter(|(_, prog_id)| *prog_id != 0)
+    .collect()
+}
+
+// Programs attached through tcx, which replaced tc's clsact hooks in 6.6
+fn tcx_programs(ifindex: u32) -> Vec<(&'static str, u32)> {
+    let mut attached = vec![];
+    for (attach_type, hook) in [
+        (libbpf_sys::BPF_TCX_INGRESS, "tcx/ingress"),
+        (libbpf_sys::BPF_TCX_EGRESS, "tcx/egress"),
+    ] {
+        let mut prog_ids = [0u32; MAX_TCX_PROGS];
+        let mut opts = bpf_prog_query_opts {
+            sz: size_of::<bpf_prog_query_opts>() as _,
+            prog_ids: prog_ids.as_mut_ptr(),
+            ..Default::default()
+        };
+        opts.__bindgen_anon_1.prog_cnt = MAX_TCX_PROGS as u32;
+        // Fails on kernels without tcx
+        if unsafe { libbpf_sys::bpf_prog_query_opts(ifindex as i32, attach_type, &mut opts) } != 0 {
+            continue;
+        }
+        let prog_cnt = unsafe { opts.__bindgen_anon_1.prog_cnt } as usize;
+        attached.extend(
+            prog_ids[..prog_cnt.min(MAX_TCX_PROGS)]
+                .iter()
+                .map(|prog_id| (hook, *prog_id)),
+        );
+    }
+    attached
+}
+
+// cls_bpf programs attached to the interface with the legacy tc API
+fn tc_programs(filters: &[TcFilter], ifindex: u32) -> Vec<(&'static str, u32)> {
+    filters
+        .iter()
+        .filter(|filter| filter.ifindex == ifindex)
+        .filter_map(|filter| match filter.parent {
+            TC_INGRESS_PARENT => Some(("tc/ingress", filter.prog_id)),
+            TC_EGRESS_PARENT => Some(("tc/egress", filter.prog_id)),
+            _ => None,
+        })
+        .collect()
+}
+
+// The socket every dump goes through, opened on first use
+static NETLINK: Mutex<Option<NetlinkSocket>> = Mutex::new(None);
+
+// Dumps the cls_bpf filters of every interface
+fn dump_tc_filters() -> Vec<TcFilter> {
+    let mut netlink = NETLINK.lock().unwrap();
+    let socket = match netlink.take() {
+        Some(socket) => Ok(socket),
+        None => NetlinkSocket::open(),
+    };
+    let mut socket = match socket {
+        Ok(socket) => socket,
+        Err(e) => {
+            error!("Failed to open netlink socket: {}", e);
+            return vec![];
+        }
+    };
+    let mut filters = vec![];
+    let mut failed = false;
+    for (ifindex, _) in interfaces() {
+        for parent in [TC_INGRESS_PARENT, TC_EGRESS_PARENT] {
+            match socket.tc_filters(ifindex, parent) {
+                Ok(found) => filters.extend(found),
+                Err(e) => {
+                    error!("Failed to dump tc filters of ifindex {}: {}", ifindex, e);
+                    failed = true;
+                }
+            }
+        }
+    }
+    // Replies of a failed dump may still be queued, so start over with a new socket
+    if !failed {
+        *netlink = Some(socket);
+    }
+    filters
+}
+
+// The filters of the last dump, for looking up the filters of a single program
+struct TcFilterCache {
+    filters: Vec<TcFilter>,
+    dumped_at: Option<Instant>,
+    refreshing: bool,
+}
+
+static TC_FILTERS: Mutex<TcFilterCache> = Mutex::new(TcFilterCache {
+    filters: vec![],
+    dumped_at: None,
+    refreshing: false,
+});
+
+fn store_tc_filters(filters: Vec<TcFilter>) {
+    let mut cache = TC_FILTERS.lock().unwrap();
+    cache.filters = filters;
+    cache.dumped_at = Some(Instant::now());
+    cache.refreshing = false;
+}
+
+// Returns the last dumped tc filters, dumping them first if that never happened
+fn cached_tc_filters() -> Vec<TcFilter> {
+    let mut cache = TC_FILTERS.lock().unwrap();
+    match cache.dumped_at {
+        None => {
+            drop(cache);
+            let filters = dump_tc_filters();
+            store_tc_filters(filters.clone());
+            filters
+        }
+        Some(dumped_at) if dumped_at.elapsed() >= TC_FILTERS_TTL && !cache.refreshing => {
+            cache.refreshing = true;
+            thread::spawn(|| store_tc_filters(dump_tc_filters()));
+            cache.filters.clone()
+        }
+        Some(_) => cache.filters.clone(),
+    }
+}
+
+// Drops a deleted filter from the cache, so it is not shown until the next dump
+pub fn forget_tc_filter(filter: &TcFilter) {
+    TC_FILTERS
+        .lock()
+        .unwrap()
+        .filters
+        .retain(|cached| cached != filter);
+}
+
+fn interfaces() -> Vec<(u32, String)> {
+    let Ok(entries) = fs::read_dir(SYS_CLASS_NET) else {
+        return vec![];
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let ifindex = fs::read_to_string(entry.path().join("ifindex")).ok()?;
+            let ifindex = ifindex.trim().parse().ok()?;
+            Some((ifindex, entry.file_name().to_string_lossy().into_owned()))
+        })
+        .collect()
+}
+
+// Finds the networking programs attached to every interface in bpftop's network namespace.
+// This takes several queries per interface, so callers should not do it every sample.
+pub fn scan_interfaces() -> Vec<InterfacePrograms> {
+    // Keep the dump, so looking up the filters of a program right after is free
+    let tc_filters = dump_tc_filters();
+    store_tc_filters(tc_filters.clone());
+    interfaces()
+        .into_iter()
+        .filter_map(|(ifindex, name)| {
+            let mut attached = xdp_programs(ifindex);
+            attached.extend(tcx_programs(ifindex));
+            attached.extend(tc_programs(&tc_filters, ifindex));
+            (!attached.is_empty()).then_some(InterfacePrograms {
+                ifindex,
+                name,
+                attached,
+                events_per_second: 0,
+                cpu_time_percent: 0.0,
+            })
+        })
+        .collect()
+}
+
+// Finds the cls_bpf filters running the given program on any interface, as of the last
+// dump
+pub fn prog_tc_filters(prog_id: u32) -> Vec<TcFilter> {
+    cached_tc_filters()
+        .into_iter()
+        .filter(|filter| filter.prog_id == prog_id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_programs() {
+        let iface = InterfacePrograms {
+            ifindex: 2,
+            name: "eth0".to_string(),
+            attached: vec![("xdp", 40), ("tcx/ingress", 41), ("tc/egress", 42)],
+            events_per_second: 0,
+            cpu_time_percent: 0.0,
+        };
+        assert_eq!(iface.programs(), "xdp:40, tcx/ingress:41, tc/egress:42");
+    }
+
+    #[test]
+    fn test_tc_programs() {
+        let filter = |ifindex, parent, prog_id| TcFilter {
+            ifindex,
+            parent,
+            handle: 1,
+            info: 0,
+            chain: 0,
+            prog_id,
+        };
+        let filters = [
+            filter(2, TC_INGRESS_PARENT, 40),
+            filter(3, TC_INGRESS_PARENT, 41),
+            filter(2, TC_EGRESS_PARENT, 42),
+        ];
+        assert_eq!(
+            tc_programs(&filters, 2),
+            vec![("tc/ingress", 40), ("tc/egress", 42)]
+        );
+    }
+}
@@ -0,0 +1,373 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use std::{collections::BTreeMap, fmt};
+#[cfg(feature = "kubernetes")]
+use {
+    anyhow::{anyhow, Context, Result},
+    hyper_util::rt::TokioIo,
+    proto::*,
+    std::{
+        collections::{HashMap, HashSet},
+        time::{Duration, Instant},
+    },
+    tokio::{net::UnixStream, runtime},
+    tonic::{
+        client::Grpc,
+        codec::ProstCodec,
+        codegen::http::uri::PathAndQuery,
+        transport::{Channel, Endpoint, Uri},
+        Request,
+    },
+    tower::service_fn,
+    tracing::debug,
+};
+
+// The kubelet's podResources API, which lists the pods running on the node
+#[cfg(feature = "kubernetes")]
+const KUBELET_SOCKET: &str = "/var/lib/kubelet/pod-resources/kubelet.sock";
+
+// CRI sockets of containerd and CRI-O, through one of which the kubelet runs containers
+#[cfg(feature = "kubernetes")]
+const RUNTIME_SOCKETS: [&str; 2] = ["/run/containerd/containerd.sock", "/run/crio/crio.sock"];
+
+// Labels the kubelet adds to pod sandboxes, repeating the pod's name, namespace and UID
+#[cfg(feature = "kubernetes")]
+const KUBELET_LABEL_PREFIX: &str = "io.kubernetes.";
+
+// A hung kubelet or runtime must not stall the collector for long
+#[cfg(feature = "kubernetes")]
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+
+// Pods started since the last listing are picked up after at most this long
+#[cfg(feature = "kubernetes")]
+const REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pod {
+    pub namespace: String,
+    pub name: String,
+    pub container: String,
+    pub labels: BTreeMap<String, String>,
+}
+
+impl fmt::Display for Pod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.namespace, self.name)
+    }
+}
+
+// The fields bpftop reads of the podResources v1 and CRI v1 messages, with their protobuf
+// tags. Fields missing here are skipped when decoding.
+#[cfg(feature = "kubernetes")]
+mod proto {
+    use std::collections::HashMap;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ListPodResourcesRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ListPodResourcesResponse {
+        #[prost(message, repeated, tag = "1")]
+        pub pod_resources: Vec<PodResources>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct PodResources {
+        #[prost(string, tag = "1")]
+        pub name: String,
+        #[prost(string, tag = "2")]
+        pub namespace: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ListContainersRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ListContainersResponse {
+        #[prost(message, repeated, tag = "1")]
+        pub containers: Vec<Container>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Container {
+        #[prost(string, tag = "1")]
+        pub id: String,
+        #[prost(string, tag = "2")]
+        pub pod_sandbox_id: String,
+        #[prost(message, optional, tag = "3")]
+        pub metadata: Option<ContainerMetadata>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ContainerMetadata {
+        #[prost(string, tag = "1")]
+        pub name: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ListPodSandboxRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ListPodSandboxResponse {
+        #[prost(message, repeated, tag = "1")]
+        pub items: Vec<PodSandbox>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct PodSandbox {
+        #[prost(string, tag = "1")]
+        pub id: String,
+        #[prost(message, optional, tag = "2")]
+        pub metadata: Option<PodSandboxMetadata>,
+        #[prost(map = "string, string", tag = "5")]
+        pub labels: HashMap<String, String>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct PodSandboxMetadata {
+        #[prost(string, tag = "1")]
+        pub name: String,
+        #[prost(string, tag = "3")]
+        pub namespace: String,
+    }
+}
+
+#[cfg(feature = "kubernetes")]
+async fn connect(socket: &'static str) -> Result<Channel> {
+    // The URI only fills in the requests' authority, the connector always dials the socket
+    Endpoint::from_static("http://localhost")
+        .connect_with_connector(service_fn(move |_: Uri| async move {
+            Ok::<_, std::io::Error>(TokioIo::new(UnixStream::connect(socket).await?))
+        }))
+        .await
+        .with_context(|| format!("Failed to connect to {}", socket))
+}
+
+#[cfg(feature = "kubernetes")]
+async fn unary<Req, Resp>(channel: Channel, path: &'static str, request: Req) -> Result<Resp>
+where
+    Req: prost::Message + Send + Sync + 'static,
+    Resp: prost::Message + Default + Send + Sync + 'static,
+{
+    let mut grpc = Grpc::new(channel);
+    grpc.ready().await?;
+    let response = grpc
+        .unary(
+            Request::new(request),
+            PathAndQuery::from_static(path),
+            ProstCodec::default(),
+        )
+        .await
+        .with_context(|| format!("{} failed", path))?;
+    Ok(response.into_inner())
+}
+
+// The kubelet's podResources API lists the pods on the node but not their container IDs,
+// which come from the CRI of the container runtime along with the pod labels
+#[cfg(feature = "kubernetes")]
+async fn list_pods() -> Result<HashMap<String, Pod>> {
+    let kubelet = connect(KUBELET_SOCKET).await?;
+    let listed = unary(
+        kubelet,
+        "/v1.PodResourcesLister/List",
+        ListPodResourcesRequest {},
+    )
+    .await?;
+
+    let mut runtime = Err(anyhow!("No container runtime socket"));
+    for socket in RUNTIME_SOCKETS {
+        runtime = connect(socket).await;
+        if runtime.is_ok() {
+            break;
+        }
+    }
+    let runtime = runtime?;
+    let containers = unary(
+        runtime.clone(),
+        "/runtime.v1.RuntimeService/ListContainers",
+        ListContainersRequest {},
+    )
+    .await?;
+    let sandboxes = unary(
+        runtime,
+        "/runtime.v1.RuntimeService/ListPodSandbox",
+        ListPodSandboxRequest {},
+    )
+    .await?;
+    Ok(resolve_pods(&listed, &containers, &sandboxes))
+}
+
+// Maps container IDs to their pods. Sandboxes of pods the kubelet no longer runs linger in
+// the runtime until they are garbage collected, and are left out.
+#[cfg(feature = "kubernetes")]
+fn resolve_pods(
+    listed: &ListPodResourcesResponse,
+    containers: &ListContainersResponse,
+    sandboxes: &ListPodSandboxResponse,
+) -> HashMap<String, Pod> {
+    let running: HashSet<(&str, &str)> = listed
+        .pod_resources
+        .iter()
+        .map(|pod| (pod.namespace.as_str(), pod.name.as_str()))
+        .collect();
+    let sandboxes: HashMap<&str, &PodSandbox> = sandboxes
+        .items
+        .iter()
+        .filter(|sandbox| {
+            sandbox.metadata.as_ref().is_some_and(|metadata| {
+                running.contains(&(metadata.namespace.as_str(), metadata.name.as_str()))
+            })
+        })
+        .map(|sandbox| (sandbox.id.as_str(), sandbox))
+        .collect();
+
+    containers
+        .containers
+        .iter()
+        .filter_map(|container| {
+            let sandbox = sandboxes.get(container.pod_sandbox_id.as_str())?;
+            let metadata = sandbox.metadata.as_ref()?;
+            let pod = Pod {
+                namespace: metadata.namespace.clone(),
+                name: metadata.name.clone(),
+                container: container
+                    .metadata
+                    .as_ref()
+                    .map(|metadata| metadata.name.clone())
+                    .unwrap_or_default(),
+                labels: sandbox
+                    .labels
+                    .iter()
+                    .filter(|(key, _)| !key.starts_with(KUBELET_LABEL_PREFIX))
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect(),
+            };
+            Some((container.id.clone(), pod))
+        })
+        .collect()
+}
+
+// Maps container IDs to the pods they belong to. Off-cluster there is no kubelet socket and
+// nothing resolves.
+#[cfg(feature = "kubernetes")]
+#[derive(Default)]
+pub struct PodResolver {
+    pods: HashMap<String, Pod>,
+    last_refresh: Option<Instant>,
+}
+
+#[cfg(feature = "kubernetes")]
+impl PodResolver {
+    pub fn lookup(&mut self, container_id: &str) -> Option<Pod> {
+        if !self.pods.contains_key(container_id)
+            && self
+                .last_refresh
+                .is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+        {
+            self.refresh();
+        }
+        self.pods.get(container_id).cloned()
+    }
+
+    fn refresh(&mut self) {
+        self.last_refresh = Some(Instant::now());
+        let runtime = match runtime::Builder::new_current_thread().enable_all().build() {
+            Ok(runtime) => runtime,
+            Err(e) => {
+                debug!("Failed to start the pod listing runtime: {}", e);
+                return;
+            }
+        };
+        match runtime.block_on(tokio::time::timeout(REQUEST_TIMEOUT, list_pods())) {
+            Ok(Ok(pods)) => self.pods = pods,
+            Ok(Err(e)) => debug!("Failed to list pods: {:#}", e),
+            Err(_) => debug!("Listing pods timed out after {:?}", REQUEST_TIMEOUT),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "kubernetes"))]
+mod tests {
+    use super::*;
+
+    fn sandbox(id: &str, name: &str) -> PodSandbox {
+        PodSandbox {
+            id: id.to_string(),
+            metadata: Some(PodSandboxMetadata {
+                name: name.to_string(),
+                namespace: "kube-system".to_string(),
+            }),
+            labels: HashMap::from([
+                ("k8s-app".to_string(), "cilium".to_string()),
+                (
+                    "io.kubernetes.pod.name".to_string(),
+                    "cilium-x8k2p".to_string(),
+                ),
+            ]),
+        }
+    }
+
+    fn container(id: &str, sandbox_id: &str) -> Container {
+        Container {
+            id: id.to_string(),
+            pod_sandbox_id: sandbox_id.to_string(),
+            metadata: Some(ContainerMetadata {
+                name: "cilium-agent".to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_resolve_pods() {
+        let listed = ListPodResourcesResponse {
+            pod_resources: vec![PodResources {
+                name: "cilium-x8k2p".to_string(),
+                namespace: "kube-system".to_string(),
+            }],
+        };
+        let containers = ListContainersResponse {
+            containers: vec![container("3b18", "s1"), container("0a00", "s2")],
+        };
+        // s2 belongs to a pod that was deleted
+        let sandboxes = ListPodSandboxResponse {
+            items: vec![sandbox("s1", "cilium-x8k2p"), sandbox("s2", "cilium-old")],
+        };
+        let pods = resolve_pods(&listed, &containers, &sandboxes);
+        assert_eq!(pods.len(), 1);
+        let pod = &pods["3b18"];
+        assert_eq!(pod.to_string(), "kube-system/cilium-x8k2p");
+        assert_eq!(pod.container, "cilium-agent");
+        assert_eq!(
+            pod.labels,
+            BTreeMap::from([("k8s-app".to_string(), "cilium".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        use prost::Message;
+
+        // A kubelet response with fields bpftop does not read, such as the CPU IDs
+        let response = ListPodResourcesResponse::decode(
+            &b"\x0a\x1f\x0a\x0ccilium-x8k2p\x12\x0bkube-system\x1a\x02\x18\x01"[..],
+        )
+        .unwrap();
+        assert_eq!(response.pod_resources[0].name, "cilium-x8k2p");
+        assert_eq!(response.pod_resources[0].namespace, "kube-system");
+    }
+}
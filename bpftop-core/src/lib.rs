@@ -0,0 +1,56 @@
+//! Collection of eBPF program stats, shared by the bpftop frontends.
+//!
+//! [`sampler::Sampler`] samples every loaded program once per call and computes the deltas
+//! against the previous sample. Programs are read from a [`sampler::ProgramStatsSource`]:
+//! [`sampler::LiveSource`] reads them from the kernel along with the processes and
+//! containers holding them, and [`sampler::MockSource`] returns fixed ones for tests. The other modules discover where programs are attached: links, cgroups and
+//! network interfaces.
+//!
+//! With the `metrics` feature, `metrics::record_programs` emits the stats of sampled programs
+//! through the `metrics` crate, to whatever recorder the embedding application installed.
+//!
+//! Runtime stats are only collected by the kernel while `kernel.bpf_stats_enabled` is set,
+//! which is left to the caller.
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+pub mod bpf_attachment;
+pub mod bpf_program;
+pub mod bpf_token;
+pub mod cgroups;
+pub mod container;
+pub mod helpers;
+pub mod interfaces;
+pub mod kubernetes;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod netlink;
+pub mod processes;
+pub mod sampler;
+
+mod pid_iter {
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/bpf/pid_iter.skel.rs"
+    ));
+}
+mod map_iter {
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/bpf/map_iter.skel.rs"
+    ));
+}
@@ -0,0 +1,100 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::bpf_program::BpfProgram;
+use metrics::{counter, gauge};
+
+pub const RUN_TIME_NS_TOTAL: &str = "bpftop_program_run_time_ns_total";
+pub const RUN_COUNT_TOTAL: &str = "bpftop_program_run_count_total";
+pub const EVENTS_PER_SECOND: &str = "bpftop_program_events_per_second";
+pub const CPU_TIME_PERCENT: &str = "bpftop_program_cpu_time_percent";
+pub const AVERAGE_RUNTIME_NS: &str = "bpftop_program_average_runtime_ns";
+
+/// Records every metric of the sampled programs, labelled with their ID, name and type, to
+/// the recorder installed for the `metrics` crate
+pub fn record_programs(programs: &[BpfProgram]) {
+    for prog in programs {
+        let labels = [
+            ("id", prog.id.to_string()),
+            ("name", prog.name.clone()),
+            ("type", prog.bpf_type.clone()),
+        ];
+        // The kernel keeps the totals, so counters are set rather than incremented
+        counter!(RUN_TIME_NS_TOTAL, &labels).absolute(prog.run_time_ns);
+        counter!(RUN_COUNT_TOTAL, &labels).absolute(prog.run_cnt);
+        // Rates need a full period, so they are left out of a program's first sample
+        if prog.period_ns > 0 {
+            gauge!(EVENTS_PER_SECOND, &labels).set(prog.events_per_second() as f64);
+            gauge!(CPU_TIME_PERCENT, &labels).set(prog.cpu_time_percent());
+            gauge!(AVERAGE_RUNTIME_NS, &labels).set(prog.period_average_runtime_ns() as f64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use metrics_util::{
+        debugging::{DebugValue, DebuggingRecorder},
+        MetricKind,
+    };
+    use std::time::Instant;
+
+    #[test]
+    fn test_record_programs() {
+        let mut prog = BpfProgram {
+            id: 1,
+            bpf_type: "Kprobe".to_string(),
+            name: "kprobe_prog".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 1000,
+            prev_run_cnt: 0,
+            run_cnt: 10,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes: vec![],
+        };
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        metrics::with_local_recorder(&recorder, || record_programs(&[prog.clone()]));
+        let recorded = snapshotter.snapshot().into_vec();
+        assert_eq!(recorded.len(), 2);
+        assert!(recorded
+            .iter()
+            .all(|(key, ..)| key.kind() == MetricKind::Counter));
+
+        prog.period_ns = 1_000_000_000;
+        metrics::with_local_recorder(&recorder, || record_programs(&[prog]));
+        let recorded = snapshotter.snapshot().into_vec();
+        let value = |name: &'static str| {
+            recorded
+                .iter()
+                .find(|(key, ..)| key.key().name() == name)
+                .map(|(.., value)| value)
+        };
+        assert_eq!(
+            value(EVENTS_PER_SECOND),
+            Some(&DebugValue::Gauge(10.0.into()))
+        );
+        assert_eq!(
+            value(AVERAGE_RUNTIME_NS),
+            Some(&DebugValue::Gauge(100.0.into()))
+        );
+        assert_eq!(value(RUN_COUNT_TOTAL), Some(&DebugValue::Counter(10)));
+    }
+}
@@ -0,0 +1,307 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use nix::libc;
+use std::{
+    ffi::c_void,
+    io,
+    mem::size_of,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+};
+
+// Parents of the ingress and egress filters of the clsact qdisc, from linux/pkt_sched.h
+pub const TC_INGRESS_PARENT: u32 = 0xFFFF_FFF2;
+pub const TC_EGRESS_PARENT: u32 = 0xFFFF_FFF3;
+
+// Filter attributes, from linux/rtnetlink.h and linux/pkt_cls.h
+const TCA_KIND: u16 = 1;
+const TCA_OPTIONS: u16 = 2;
+const TCA_CHAIN: u16 = 11;
+const TCA_BPF_ID: u16 = 11;
+
+const NLMSG_HDRLEN: usize = size_of::<libc::nlmsghdr>();
+const RECV_BUF_SIZE: usize = 32 * 1024;
+
+#[repr(C)]
+struct TcMsg {
+    family: u8,
+    pad1: u8,
+    pad2: u16,
+    ifindex: i32,
+    handle: u32,
+    parent: u32,
+    info: u32,
+}
+
+// A cls_bpf filter, with everything the kernel needs to find it again to delete it
+#[derive(Clone, Debug, PartialEq)]
+pub struct TcFilter {
+    pub ifindex: u32,
+    pub parent: u32,
+    pub handle: u32,
+    // Priority in the upper 16 bits and protocol in the lower ones
+    pub info: u32,
+    pub chain: u32,
+    pub prog_id: u32,
+}
+
+impl TcFilter {
+    pub fn priority(&self) -> u32 {
+        self.info >> 16
+    }
+}
+
+fn align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+fn as_bytes<T>(value: &T) -> &[u8] {
+    unsafe { std::slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) }
+}
+
+fn attribute(attr_type: u16, payload: &[u8]) -> Vec<u8> {
+    let mut attr = vec![];
+    attr.extend(((4 + payload.len()) as u16).to_ne_bytes());
+    attr.extend(attr_type.to_ne_bytes());
+    attr.extend(payload);
+    attr.resize(align(attr.len()), 0);
+    attr
+}
+
+// Splits a buffer of netlink attributes into their types and payloads
+fn attributes(mut buf: &[u8]) -> impl Iterator<Item = (u16, &[u8])> {
+    std::iter::from_fn(move || {
+        let len = u16::from_ne_bytes(buf.get(0..2)?.try_into().unwrap()) as usize;
+        let attr_type = u16::from_ne_bytes(buf.get(2..4)?.try_into().unwrap());
+        let payload = buf.get(4..len)?;
+        buf = buf.get(align(len)..).unwrap_or_default();
+        // The upper bits flag nested and byte order converted attributes
+        Some((attr_type & 0x3FFF, payload))
+    })
+}
+
+// Parses a cls_bpf filter from the payload of an RTM_NEWTFILTER message
+fn parse_filter(payload: &[u8]) -> Option<TcFilter> {
+    let attrs = payload.get(size_of::<TcMsg>()..)?;
+    let tcmsg: TcMsg = unsafe { std::ptr::read_unaligned(payload.as_ptr() as *const _) };
+    let mut is_bpf = false;
+    let mut chain = 0;
+    let mut prog_id = None;
+    for (attr_type, value) in attributes(attrs) {
+        match attr_type {
+            TCA_KIND => is_bpf = value.strip_suffix(&[0]) == Some(b"bpf"),
+            TCA_CHAIN => {
+                chain = value
+                    .get(0..4)
+                    .map_or(0, |chain| u32::from_ne_bytes(chain.try_into().unwrap()))
+            }
+            TCA_OPTIONS => {
+                prog_id = attributes(value)
+                    .find(|(option, _)| *option == TCA_BPF_ID)
+                    .and_then(|(_, id)| Some(u32::from_ne_bytes(id.get(0..4)?.try_into().ok()?)));
+            }
+            _ => {}
+        }
+    }
+    prog_id.filter(|_| is_bpf).map(|prog_id| TcFilter {
+        ifindex: tcmsg.ifindex as u32,
+        parent: tcmsg.parent,
+        handle: tcmsg.handle,
+        info: tcmsg.info,
+        chain,
+        prog_id,
+    })
+}
+
+// A route netlink socket for dumping and deleting traffic control filters. cls_bpf filters
+// attached through the legacy tc API do not show up as links, so netlink is the only way to
+// find them.
+pub struct NetlinkSocket {
+    fd: OwnedFd,
+    seq: u32,
+}
+
+impl NetlinkSocket {
+    pub fn open() -> io::Result<NetlinkSocket> {
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                libc::NETLINK_ROUTE,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(NetlinkSocket {
+            fd: unsafe { OwnedFd::from_raw_fd(fd) },
+            seq: 0,
+        })
+    }
+
+    fn send(&mut self, msg_type: u16, flags: i32, tcmsg: TcMsg, attrs: &[u8]) -> io::Result<()> {
+        self.seq += 1;
+        let len = NLMSG_HDRLEN + size_of::<TcMsg>() + attrs.len();
+        let header = libc::nlmsghdr {
+            nlmsg_len: len as u32,
+            nlmsg_type: msg_type,
+            nlmsg_flags: (libc::NLM_F_REQUEST | flags) as u16,
+            nlmsg_seq: self.seq,
+            nlmsg_pid: 0,
+        };
+        let mut request: Vec<u8> = Vec::with_capacity(len);
+        request.extend(as_bytes(&header));
+        request.extend(as_bytes(&tcmsg));
+        request.extend(attrs);
+        let ret = unsafe {
+            libc::send(
+                self.fd.as_raw_fd(),
+                request.as_ptr() as *const c_void,
+                request.len(),
+                0,
+            )
+        };
+        if ret < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    // Reads the replies to the last request until the dump is done or the request is
+    // acknowledged, passing the payload of every RTM_NEWTFILTER message to on_filter
+    fn receive(&mut self, mut on_filter: impl FnMut(&[u8])) -> io::Result<()> {
+        let mut buf = vec![0u8; RECV_BUF_SIZE];
+        loop {
+            let len = unsafe {
+                libc::recv(
+                    self.fd.as_raw_fd(),
+                    buf.as_mut_ptr() as *mut c_void,
+                    buf.len(),
+                    0,
+                )
+            };
+            if len < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let mut messages = &buf[..len as usize];
+            while messages.len() >= NLMSG_HDRLEN {
+                let header: libc::nlmsghdr =
+                    unsafe { std::ptr::read_unaligned(messages.as_ptr() as *const _) };
+                let msg_len = header.nlmsg_len as usize;
+                let Some(payload) = messages.get(NLMSG_HDRLEN..msg_len) else {
+                    break;
+                };
+                match header.nlmsg_type as i32 {
+                    libc::NLMSG_DONE => return Ok(()),
+                    libc::NLMSG_ERROR => {
+                        let errno = i32::from_ne_bytes(payload[0..4].try_into().unwrap());
+                        return match -errno {
+                            0 => Ok(()),
+                            errno => Err(io::Error::from_raw_os_error(errno)),
+                        };
+                    }
+                    _ if header.nlmsg_type == libc::RTM_NEWTFILTER => on_filter(payload),
+                    _ => {}
+                }
+                messages = messages.get(align(msg_len)..).unwrap_or_default();
+            }
+        }
+    }
+
+    // Returns the cls_bpf filters under the given parent
+    pub fn tc_filters(&mut self, ifindex: u32, parent: u32) -> io::Result<Vec<TcFilter>> {
+        let tcmsg = TcMsg {
+            family: libc::AF_UNSPEC as u8,
+            pad1: 0,
+            pad2: 0,
+            ifindex: ifindex as i32,
+            handle: 0,
+            parent,
+            info: 0,
+        };
+        self.send(libc::RTM_GETTFILTER, libc::NLM_F_DUMP, tcmsg, &[])?;
+
+        let mut filters = vec![];
+        match self.receive(|payload| filters.extend(parse_filter(payload))) {
+            // Interfaces without a clsact qdisc have no filters to dump
+            Err(e) if !matches!(e.raw_os_error(), Some(libc::EINVAL | libc::ENOENT)) => Err(e),
+            _ => Ok(filters),
+        }
+    }
+
+    // Deletes a single filter, like `tc filter del dev <dev> <parent> prio <prio> handle
+    // <handle> bpf` does
+    pub fn delete_tc_filter(&mut self, filter: &TcFilter) -> io::Result<()> {
+        let tcmsg = TcMsg {
+            family: libc::AF_UNSPEC as u8,
+            pad1: 0,
+            pad2: 0,
+            ifindex: filter.ifindex as i32,
+            handle: filter.handle,
+            parent: filter.parent,
+            info: filter.info,
+        };
+        let mut attrs = attribute(TCA_KIND, b"bpf\0");
+        if filter.chain != 0 {
+            attrs.extend(attribute(TCA_CHAIN, &filter.chain.to_ne_bytes()));
+        }
+        self.send(libc::RTM_DELTFILTER, libc::NLM_F_ACK, tcmsg, &attrs)?;
+        self.receive(|_| {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_filter() {
+        let mut options = attribute(7, b"cil_from_netdev\0");
+        options.extend(attribute(TCA_BPF_ID, &412u32.to_ne_bytes()));
+        let tcmsg = TcMsg {
+            family: 0,
+            pad1: 0,
+            pad2: 0,
+            ifindex: 2,
+            handle: 1,
+            parent: TC_INGRESS_PARENT,
+            info: 0xC000_0003,
+        };
+        let mut payload = as_bytes(&tcmsg).to_vec();
+        payload.extend(attribute(TCA_KIND, b"bpf\0"));
+        // Nested attributes carry the NLA_F_NESTED flag
+        payload.extend(attribute(TCA_OPTIONS | 0x8000, &options));
+        let filter = parse_filter(&payload).unwrap();
+        assert_eq!(
+            filter,
+            TcFilter {
+                ifindex: 2,
+                parent: TC_INGRESS_PARENT,
+                handle: 1,
+                info: 0xC000_0003,
+                chain: 0,
+                prog_id: 412,
+            }
+        );
+        assert_eq!(filter.priority(), 0xC000);
+
+        let mut payload = vec![0u8; size_of::<TcMsg>()];
+        payload.extend(attribute(TCA_KIND, b"u32\0"));
+        payload.extend(attribute(TCA_OPTIONS, &attribute(11, &[1, 0, 0, 0])));
+        assert_eq!(parse_filter(&payload), None);
+    }
+}
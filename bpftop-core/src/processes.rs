@@ -0,0 +1,275 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    bpf_program::{BpfProgram, Process},
+    container::Container,
+};
+use nix::unistd::{Uid, User};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+// Targets of the /proc/<pid>/fd links of BPF program and map fds
+const BPF_PROG_FD: &str = "anon_inode:bpf-prog";
+const BPF_MAP_FD: &str = "anon_inode:bpf-map";
+
+// Targets of the BPF fds counted like pid_iter does
+const BPF_FDS: [&str; 3] = [BPF_PROG_FD, BPF_MAP_FD, "anon_inode:bpf_link"];
+
+// A process holding BPF programs, with the combined stats of the programs it holds
+#[derive(Clone, Debug)]
+pub struct ProcessPrograms {
+    pub pid: i32,
+    pub comm: String,
+    pub container: Option<Container>,
+    pub prog_ids: Vec<u32>,
+    pub events_per_second: i64,
+    pub cpu_time_percent: f64,
+}
+
+impl PartialEq for ProcessPrograms {
+    fn eq(&self, other: &Self) -> bool {
+        self.pid == other.pid
+    }
+}
+
+impl ProcessPrograms {
+    pub fn container_name(&self) -> &str {
+        self.container
+            .as_ref()
+            .map(|container| container.display_name())
+            .unwrap_or_default()
+    }
+}
+
+// Inverts the program to holding processes mapping of pid_iter
+pub fn group_by_process(programs: &[BpfProgram]) -> Vec<ProcessPrograms> {
+    let mut processes: HashMap<i32, ProcessPrograms> = HashMap::new();
+    for prog in programs {
+        for process in &prog.processes {
+            let entry = processes
+                .entry(process.pid)
+                .or_insert_with(|| ProcessPrograms {
+                    pid: process.pid,
+                    comm: process.comm.clone(),
+                    container: process.container.clone(),
+                    prog_ids: vec![],
+                    events_per_second: 0,
+                    cpu_time_percent: 0.0,
+                });
+            // A process holds a program once per file descriptor, only count it once
+            if entry.prog_ids.contains(&prog.id) {
+                continue;
+            }
+            entry.prog_ids.push(prog.id);
+            entry.events_per_second += prog.events_per_second();
+            entry.cpu_time_percent += prog.cpu_time_percent();
+        }
+    }
+    processes.into_values().collect()
+}
+
+// Reads the ID of the program or map behind an fd from its /proc/<pid>/fdinfo entry, where
+// it is the "prog_id:" or "map_id:" field
+fn parse_fdinfo_id(fdinfo: &str, field: &str) -> Option<u32> {
+    fdinfo
+        .lines()
+        .find_map(|line| line.strip_prefix(field))
+        .and_then(|id| id.trim().parse().ok())
+}
+
+// Objects behind the fds of a process with the given link target, and its number of BPF
+// program, map and link fds
+fn process_object_ids(proc_dir: &Path, fd_target: &str, field: &str) -> (Vec<u32>, u32) {
+    let Ok(fds) = fs::read_dir(proc_dir.join("fd")) else {
+        return (vec![], 0);
+    };
+    let mut ids = vec![];
+    let mut bpf_fds = 0;
+    for fd in fds.flatten() {
+        let Ok(target) = fs::read_link(fd.path()) else {
+            continue;
+        };
+        let target = target.to_string_lossy();
+        if BPF_FDS.contains(&target.as_ref()) {
+            bpf_fds += 1;
+        }
+        // Only read the fdinfo of matching fds, which is far cheaper than reading all of them
+        if target != fd_target {
+            continue;
+        }
+        if let Some(id) = fs::read_to_string(proc_dir.join("fdinfo").join(fd.file_name()))
+            .ok()
+            .and_then(|fdinfo| parse_fdinfo_id(&fdinfo, field))
+        {
+            ids.push(id);
+        }
+    }
+    (ids, bpf_fds)
+}
+
+fn scan_holders(fd_target: &str, field: &str) -> HashMap<u32, Vec<Process>> {
+    let mut pid_map: HashMap<u32, Vec<Process>> = HashMap::new();
+    let Ok(entries) = fs::read_dir("/proc") else {
+        return pid_map;
+    };
+    for entry in entries.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|pid| pid.parse().ok()) else {
+            continue;
+        };
+        let (ids, bpf_fds) = process_object_ids(&entry.path(), fd_target, field);
+        if ids.is_empty() {
+            continue;
+        }
+        let comm = fs::read_to_string(entry.path().join("comm")).unwrap_or_default();
+        for id in ids {
+            pid_map.entry(id).or_default().push(Process {
+                pid,
+                comm: comm.trim_end().to_string(),
+                container: None,
+                cgroup_id: 0,
+                bpf_fds,
+                user: None,
+            });
+        }
+    }
+    pid_map
+}
+
+// The real UID of a process, from the contents of /proc/<pid>/status
+fn parse_uid(status: &str) -> Option<u32> {
+    let uids = status.lines().find_map(|line| line.strip_prefix("Uid:"))?;
+    uids.split_whitespace().next()?.parse().ok()
+}
+
+// Users of the processes holding programs, read from procfs once per process and kept for
+// as long as the process is seen
+#[derive(Default)]
+pub struct OwnerResolver {
+    processes: HashMap<i32, Option<String>>,
+    users: HashMap<u32, String>,
+}
+
+impl OwnerResolver {
+    pub fn resolve(&mut self, process: &mut Process) {
+        let users = &mut self.users;
+        let user = self.processes.entry(process.pid).or_insert_with(|| {
+            fs::read_to_string(format!("/proc/{}/status", process.pid))
+                .ok()
+                .and_then(|status| parse_uid(&status))
+                .map(|uid| {
+                    users
+                        .entry(uid)
+                        .or_insert_with(|| match User::from_uid(Uid::from_raw(uid)) {
+                            Ok(Some(user)) => user.name,
+                            _ => uid.to_string(),
+                        })
+                        .clone()
+                })
+        });
+        process.user.clone_from(user);
+    }
+
+    // Forgets processes that no longer hold any program
+    pub fn retain(&mut self, pids: &HashSet<i32>) {
+        self.processes.retain(|pid, _| pids.contains(pid));
+    }
+}
+
+// Builds the same program to holding processes mapping as pid_iter by scanning the fds of
+// every process, for kernels where pid_iter cannot be loaded. Like pid_iter, a process is
+// listed once per fd it holds.
+pub fn scan_procfs() -> HashMap<u32, Vec<Process>> {
+    scan_holders(BPF_PROG_FD, "prog_id:")
+}
+
+// The map to holding processes mapping of map_iter, built the same way
+pub fn scan_procfs_maps() -> HashMap<u32, Vec<Process>> {
+    scan_holders(BPF_MAP_FD, "map_id:")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bpf_program::Process;
+    use std::time::Instant;
+
+    fn program(id: u32, run_cnt: u64, pids: &[i32]) -> BpfProgram {
+        BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: run_cnt * 1000,
+            prev_run_cnt: 0,
+            run_cnt,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: pids
+                .iter()
+                .map(|pid| Process {
+                    pid: *pid,
+                    comm: "agent".to_string(),
+                    container: None,
+                    cgroup_id: 0,
+                    bpf_fds: 1,
+                    user: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_uid() {
+        let status = "Name:\tcontainerd-shim\nUid:\t1000\t0\t0\t0\nGid:\t0\t0\t0\t0\n";
+        assert_eq!(parse_uid(status), Some(1000));
+        assert_eq!(parse_uid("Name:\tkworker\n"), None);
+    }
+
+    #[test]
+    fn test_group_by_process() {
+        let mut processes = group_by_process(&[
+            program(1, 100, &[812, 812]),
+            program(2, 50, &[812, 900]),
+            program(3, 10, &[]),
+        ]);
+        processes.sort_unstable_by_key(|process| process.pid);
+        assert_eq!(processes.len(), 2);
+        assert_eq!(processes[0].pid, 812);
+        assert_eq!(processes[0].comm, "agent");
+        assert_eq!(processes[0].prog_ids, vec![1, 2]);
+        assert_eq!(processes[0].events_per_second, 150);
+        assert_eq!(processes[1].prog_ids, vec![2]);
+        assert_eq!(processes[1].events_per_second, 50);
+        assert_eq!(processes[1].container_name(), "");
+    }
+
+    #[test]
+    fn test_parse_fdinfo_id() {
+        let fdinfo = "pos:\t0\nflags:\t02000002\nmnt_id:\t15\nino:\t1057\nprog_type:\t2\nprog_jited:\t1\nprog_tag:\tb4a2d1d6e2e9a2f1\nmemlock:\t4096\nprog_id:\t812\nrun_time_ns:\t0\nrun_cnt:\t0\n";
+        assert_eq!(parse_fdinfo_id(fdinfo, "prog_id:"), Some(812));
+        assert_eq!(parse_fdinfo_id("pos:\t0\nflags:\t02\n", "prog_id:"), None);
+
+        let fdinfo = "pos:\t0\nflags:\t02000002\nmnt_id:\t15\nino:\t1057\nmap_type:\t1\nkey_size:\t4\nvalue_size:\t8\nmax_entries:\t1024\nmap_flags:\t0x0\nmap_extra:\t0x0\nmemlock:\t86016\nmap_id:\t37\nfrozen:\t0\n";
+        assert_eq!(parse_fdinfo_id(fdinfo, "map_id:"), Some(37));
+        assert_eq!(parse_fdinfo_id(fdinfo, "prog_id:"), None);
+    }
+}
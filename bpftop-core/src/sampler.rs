@@ -0,0 +1,585 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    bpf_program::{BpfProgram, Process},
+    bpf_token,
+    cgroups::CgroupScope,
+    container::ContainerResolver,
+    helpers::{format_tag, now_ms, program_type_to_string},
+    map_iter::MapIterSkelBuilder,
+    pid_iter::PidIterSkelBuilder,
+    processes::{scan_procfs, scan_procfs_maps, OwnerResolver},
+};
+use anyhow::Result;
+use libbpf_rs::{
+    query::{ProgInfoIter, ProgInfoQueryOptions},
+    skel::{OpenSkel, Skel, SkelBuilder},
+    Iter, Link, MapCore, MapFlags, MapHandle,
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    ffi::CStr,
+    io::Read,
+    mem::{self, MaybeUninit},
+    path::Path,
+    ptr, thread,
+    time::Instant,
+};
+use tracing::error;
+
+#[repr(C)]
+pub struct PidIterEntry {
+    cgroup_id: u64,
+    id: u32,
+    pid: i32,
+    comm: [u8; 16],
+}
+
+/// The attached pid_iter program and the map in which it counts the BPF fds of each process
+pub struct PidIter {
+    pub link: Link,
+    pub bpf_fds: MapHandle,
+}
+
+// Takes the fd counts of the last iteration, emptying the map for the next one
+fn take_bpf_fds(bpf_fds: &MapHandle) -> HashMap<i32, u32> {
+    let mut counts = HashMap::new();
+    let keys: Vec<Vec<u8>> = bpf_fds.keys().collect();
+    for key in keys {
+        if let Ok(Some(value)) = bpf_fds.lookup(&key, MapFlags::ANY) {
+            if let (Ok(pid), Ok(count)) = (key[..].try_into(), value[..].try_into()) {
+                counts.insert(i32::from_ne_bytes(pid), u32::from_ne_bytes(count));
+            }
+        }
+        let _ = bpf_fds.delete(&key);
+    }
+    counts
+}
+
+#[repr(C)]
+pub struct MapIterEntry {
+    id: u32,
+    pid: i32,
+    comm: [u8; 16],
+}
+
+// How many entries are read from an iterator at once
+const ENTRIES_PER_READ: usize = 256;
+
+// Reads the fixed-size entries an iterator program writes with bpf_seq_write
+fn read_iter_entries<T>(link: &Link) -> Vec<T> {
+    match Iter::new(link) {
+        Ok(iter) => read_entries(iter),
+        Err(e) => {
+            error!("Failed to create iterator: {}", e);
+            vec![]
+        }
+    }
+}
+
+// Reads many entries per call into a single buffer. A read may stop in the middle of an
+// entry, so its start is moved to the front of the buffer and completed by the next read.
+fn read_entries<T>(mut reader: impl Read) -> Vec<T> {
+    let mut entries = vec![];
+    let struct_size = mem::size_of::<T>();
+    let mut buffer = vec![0u8; struct_size * ENTRIES_PER_READ];
+    let mut filled = 0;
+
+    loop {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break, // No more data to read
+            Ok(n) => {
+                filled += n;
+                let complete = filled - filled % struct_size;
+                entries.extend(
+                    buffer[..complete]
+                        .chunks_exact(struct_size)
+                        .map(|entry| unsafe { ptr::read_unaligned(entry.as_ptr() as *const T) }),
+                );
+                buffer.copy_within(complete..filled, 0);
+                filled -= complete;
+            }
+            Err(e) => {
+                error!("Failed to read from iterator: {}", e);
+                break;
+            }
+        }
+    }
+    if filled != 0 {
+        error!("Expected {} bytes, read {} bytes", struct_size, filled);
+    }
+    entries
+}
+
+// The iterators copy the kernel's comm buffer, which pads shorter names with NULs
+fn comm_to_string(comm: &[u8]) -> String {
+    let len = comm
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(comm.len());
+    String::from_utf8_lossy(&comm[..len]).into_owned()
+}
+
+/// Maps each program ID to the processes holding an fd of it, without their containers
+pub fn get_pid_map(pid_iter: &Option<PidIter>) -> HashMap<u32, Vec<Process>> {
+    let mut pid_map: HashMap<u32, Vec<Process>> = HashMap::new();
+
+    // Without pid_iter, fall back to the slower scan of every process' fds
+    let Some(pid_iter) = pid_iter else {
+        return scan_procfs();
+    };
+
+    for pid_entry in read_iter_entries::<PidIterEntry>(&pid_iter.link) {
+        let process = Process {
+            pid: pid_entry.pid,
+            comm: comm_to_string(&pid_entry.comm),
+            container: None,
+            cgroup_id: pid_entry.cgroup_id,
+            bpf_fds: 0,
+            user: None,
+        };
+
+        pid_map.entry(pid_entry.id).or_default().push(process);
+    }
+
+    let bpf_fds = take_bpf_fds(&pid_iter.bpf_fds);
+    for process in pid_map.values_mut().flatten() {
+        process.bpf_fds = bpf_fds.get(&process.pid).copied().unwrap_or_default();
+    }
+
+    pid_map
+}
+
+/// Maps each map ID to the processes holding an fd of it
+pub fn get_map_owners(map_iter: &Option<Link>) -> HashMap<u32, Vec<Process>> {
+    let Some(link) = map_iter else {
+        return scan_procfs_maps();
+    };
+
+    let mut owners: HashMap<u32, Vec<Process>> = HashMap::new();
+    for entry in read_iter_entries::<MapIterEntry>(link) {
+        owners.entry(entry.id).or_default().push(Process {
+            pid: entry.pid,
+            comm: comm_to_string(&entry.comm),
+            container: None,
+            cgroup_id: 0,
+            bpf_fds: 0,
+            user: None,
+        });
+    }
+    owners
+}
+
+// Open options that make libbpf load the object with a token from the bpffs at token_path
+fn token_open_opts(token_path: &CStr) -> libbpf_sys::bpf_object_open_opts {
+    libbpf_sys::bpf_object_open_opts {
+        sz: mem::size_of::<libbpf_sys::bpf_object_open_opts>() as libbpf_sys::size_t,
+        bpf_token_path: token_path.as_ptr(),
+        ..Default::default()
+    }
+}
+
+/// Loads and attaches the pid_iter program, with a BPF token from the given bpffs if any
+pub fn load_pid_iter(bpf_token: Option<&Path>) -> Result<Option<PidIter>> {
+    let skel_builder = PidIterSkelBuilder::default();
+    let mut open_object = MaybeUninit::uninit();
+    let token_path = bpf_token.map(bpf_token::token_path).transpose()?;
+    let open_skel = match &token_path {
+        Some(path) => skel_builder.open_opts(token_open_opts(path), &mut open_object)?,
+        None => skel_builder.open(&mut open_object)?,
+    };
+    let mut skel = open_skel.load()?;
+    skel.attach()?;
+    let bpf_fds = MapHandle::try_from(&skel.maps.bpf_fds)?;
+    Ok(skel
+        .links
+        .bpftop_iter
+        .take()
+        .map(|link| PidIter { link, bpf_fds }))
+}
+
+/// Loads and attaches the map_iter program, with a BPF token from the given bpffs if any
+pub fn load_map_iter(bpf_token: Option<&Path>) -> Result<Option<Link>> {
+    let skel_builder = MapIterSkelBuilder::default();
+    let mut open_object = MaybeUninit::uninit();
+    let token_path = bpf_token.map(bpf_token::token_path).transpose()?;
+    let open_skel = match &token_path {
+        Some(path) => skel_builder.open_opts(token_open_opts(path), &mut open_object)?,
+        None => skel_builder.open(&mut open_object)?,
+    };
+    let mut skel = open_skel.load()?;
+    skel.attach()?;
+    Ok(skel.links.bpftop_map_iter.take())
+}
+
+/// When a sample was taken, on both clocks. Periods are measured on the monotonic one, and
+/// the wall clock one is what recordings and exports are stamped with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Timestamp {
+    pub instant: Instant,
+    /// Milliseconds since the Unix epoch
+    pub wall_ms: u64,
+}
+
+impl Timestamp {
+    pub fn now() -> Timestamp {
+        Timestamp {
+            instant: Instant::now(),
+            wall_ms: now_ms(),
+        }
+    }
+}
+
+impl Default for Timestamp {
+    fn default() -> Timestamp {
+        Timestamp::now()
+    }
+}
+
+/// Every loaded program as of one call to [`Sampler::sample`]
+#[derive(Clone, Debug, Default)]
+pub struct ProgramSample {
+    /// Taken once at the start of the collection cycle, and shared by every program in it
+    pub timestamp: Timestamp,
+    /// Programs with their holders, and deltas against the previous sample when they were
+    /// in it. Programs without a name are included, with an empty name.
+    pub programs: Vec<BpfProgram>,
+    /// IDs of the programs using each map
+    pub map_prog_ids: HashMap<u32, Vec<u32>>,
+}
+
+/// Where the cumulative stats of programs come from: the running kernel, a recording or a
+/// test. Sources leave the previous stats unset, the [`Sampler`] fills them in.
+pub trait ProgramStatsSource: Send {
+    /// Reads every program once, or returns None once the source has nothing new
+    fn read(&mut self) -> Option<ProgramSample>;
+}
+
+impl<S: ProgramStatsSource + ?Sized> ProgramStatsSource for Box<S> {
+    fn read(&mut self) -> Option<ProgramSample> {
+        (**self).read()
+    }
+}
+
+/// Reads the programs loaded in the kernel, with the processes and containers holding them
+#[derive(Default)]
+pub struct LiveSource {
+    pid_iter: Option<PidIter>,
+    containers: ContainerResolver,
+    owners: OwnerResolver,
+}
+
+impl LiveSource {
+    /// Without pid_iter, holders are found by scanning /proc, which is slower
+    pub fn new(pid_iter: Option<PidIter>) -> LiveSource {
+        LiveSource {
+            pid_iter,
+            ..Default::default()
+        }
+    }
+}
+
+impl LiveSource {
+    // Maps each program ID to the processes holding it, with their containers and users
+    fn holders(&mut self) -> HashMap<u32, Vec<Process>> {
+        let mut pid_map = get_pid_map(&self.pid_iter);
+        let mut pids = HashSet::new();
+        for process in pid_map.values_mut().flatten() {
+            process.container = self.containers.resolve(process.pid, process.cgroup_id);
+            self.owners.resolve(process);
+            pids.insert(process.pid);
+        }
+        self.containers.retain(&pids);
+        self.owners.retain(&pids);
+        pid_map
+    }
+}
+
+// Reads the stats of every program, without the processes holding them
+fn read_programs(timestamp: Timestamp) -> ProgramSample {
+    let mut sample = ProgramSample {
+        timestamp,
+        ..Default::default()
+    };
+    let iter = ProgInfoIter::with_query_opts(ProgInfoQueryOptions::default().include_map_ids(true));
+    for prog in iter {
+        for map_id in &prog.map_ids {
+            sample
+                .map_prog_ids
+                .entry(*map_id)
+                .or_default()
+                .push(prog.id);
+        }
+
+        // Unnamed programs are kept so the allowlist still flags them
+        let prog_name = prog.name.to_string_lossy().into_owned();
+
+        sample.programs.push(BpfProgram {
+            id: prog.id,
+            bpf_type: program_type_to_string(prog.ty),
+            name: prog_name,
+            tag: format_tag(&prog.tag),
+            prev_runtime_ns: 0,
+            run_time_ns: prog.run_time_ns,
+            prev_run_cnt: 0,
+            run_cnt: prog.run_cnt,
+            instant: timestamp.instant,
+            period_ns: 0,
+            processes: vec![],
+        });
+    }
+    sample
+}
+
+impl ProgramStatsSource for LiveSource {
+    // Walking the fds of every process and querying every program both take a while on
+    // big hosts, so they run side by side to keep the sample period accurate
+    fn read(&mut self) -> Option<ProgramSample> {
+        let timestamp = Timestamp::now();
+        let (mut sample, mut pid_map) = thread::scope(|scope| {
+            let holders = scope.spawn(|| self.holders());
+            let sample = read_programs(timestamp);
+            (sample, holders.join().unwrap_or_default())
+        });
+        for prog in sample.programs.iter_mut() {
+            prog.processes = pid_map.remove(&prog.id).unwrap_or_default();
+        }
+        Some(sample)
+    }
+}
+
+/// Reads only the programs of another source that are attached or held within a cgroup
+/// subtree
+pub struct ScopedSource<S> {
+    source: S,
+    scope: CgroupScope,
+}
+
+impl<S> ScopedSource<S> {
+    pub fn new(source: S, scope: CgroupScope) -> ScopedSource<S> {
+        ScopedSource { source, scope }
+    }
+}
+
+impl<S: ProgramStatsSource> ProgramStatsSource for ScopedSource<S> {
+    fn read(&mut self) -> Option<ProgramSample> {
+        let mut sample = self.source.read()?;
+        self.scope.retain(&mut sample.programs);
+        Some(sample)
+    }
+}
+
+/// Returns the given programs, one list per read, for tests of code built on the sampler
+#[derive(Default)]
+pub struct MockSource {
+    samples: VecDeque<Vec<BpfProgram>>,
+}
+
+impl MockSource {
+    pub fn new(samples: impl IntoIterator<Item = Vec<BpfProgram>>) -> MockSource {
+        MockSource {
+            samples: samples.into_iter().collect(),
+        }
+    }
+}
+
+impl ProgramStatsSource for MockSource {
+    fn read(&mut self) -> Option<ProgramSample> {
+        self.samples.pop_front().map(|programs| ProgramSample {
+            programs,
+            ..Default::default()
+        })
+    }
+}
+
+// What a program looked like in the previous sample
+struct PrevStats {
+    tag: String,
+    name: String,
+    run_time_ns: u64,
+    run_cnt: u64,
+    instant: Instant,
+}
+
+impl PrevStats {
+    // The kernel may hand the ID of an unloaded program to a new one, and the counters of
+    // a program restart when stats are turned off and on again
+    fn continues(&self, program: &BpfProgram) -> bool {
+        self.tag == program.tag
+            && self.name == program.name
+            && self.run_time_ns <= program.run_time_ns
+            && self.run_cnt <= program.run_cnt
+    }
+}
+
+/// Samples the stats of every program from a source, remembering them to compute the next
+/// deltas
+pub struct Sampler<S> {
+    source: S,
+    prev_stats: HashMap<u32, PrevStats>,
+    // Repeated once the source has nothing new, e.g. at the end of a recording
+    last: ProgramSample,
+}
+
+impl<S: ProgramStatsSource> Sampler<S> {
+    pub fn new(source: S) -> Sampler<S> {
+        Sampler {
+            source,
+            prev_stats: HashMap::new(),
+            last: ProgramSample::default(),
+        }
+    }
+
+    pub fn sample(&mut self) -> ProgramSample {
+        if let Some(mut sample) = self.source.read() {
+            self.carry_over(&mut sample.programs);
+            self.last = sample;
+        }
+        self.last.clone()
+    }
+
+    // Fills in the previous stats of the programs and remembers their current ones. A
+    // program that is not the one previously seen under its ID starts from a fresh baseline,
+    // like a newly loaded one.
+    fn carry_over(&mut self, programs: &mut [BpfProgram]) {
+        for program in programs.iter_mut() {
+            if let Some(prev) = self
+                .prev_stats
+                .get(&program.id)
+                .filter(|prev| prev.continues(program))
+            {
+                program.prev_runtime_ns = prev.run_time_ns;
+                program.prev_run_cnt = prev.run_cnt;
+                program.period_ns = program.instant.duration_since(prev.instant).as_nanos();
+            }
+        }
+        self.prev_stats = programs
+            .iter()
+            .map(|prog| {
+                let prev = PrevStats {
+                    tag: prog.tag.clone(),
+                    name: prog.name.clone(),
+                    run_time_ns: prog.run_time_ns,
+                    run_cnt: prog.run_cnt,
+                    instant: prog.instant,
+                };
+                (prog.id, prev)
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    // Returns at most a few bytes per read, splitting entries across reads
+    struct ShortReads<'a>(&'a [u8]);
+
+    impl Read for ShortReads<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.0.len()).min(5);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_read_entries() {
+        let ids: Vec<u8> = [7u32, 8, 9]
+            .iter()
+            .flat_map(|id| id.to_ne_bytes())
+            .collect();
+        assert_eq!(read_entries::<u32>(ShortReads(&ids)), vec![7, 8, 9]);
+        assert_eq!(read_entries::<u32>(&ids[..]), vec![7, 8, 9]);
+        // A trailing partial entry is dropped
+        assert_eq!(read_entries::<u32>(&ids[..6]), vec![7]);
+    }
+
+    #[test]
+    fn test_comm_to_string() {
+        assert_eq!(comm_to_string(b"agent\0\0\0"), "agent");
+        assert_eq!(comm_to_string(b"containerd-shim\0"), "containerd-shim");
+        assert_eq!(comm_to_string(b"full-length-comm"), "full-length-comm");
+    }
+
+    fn program(id: u32, run_time_ns: u64, run_cnt: u64, instant: Instant) -> BpfProgram {
+        BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns,
+            prev_run_cnt: 0,
+            run_cnt,
+            instant,
+            period_ns: 0,
+            processes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        let start = Instant::now();
+        let later = start + Duration::from_secs(1);
+        let mut sampler = Sampler::new(MockSource::new([
+            vec![program(1, 100, 1, start), program(2, 50, 5, start)],
+            // Program 2 was unloaded and program 3 is new
+            vec![program(1, 600, 3, later), program(3, 10, 1, later)],
+        ]));
+        let first = sampler.sample().programs;
+        assert_eq!(first[0].period_ns, 0);
+
+        let second = sampler.sample().programs;
+        assert_eq!(second[0].prev_runtime_ns, 100);
+        assert_eq!(second[0].prev_run_cnt, 1);
+        assert_eq!(second[0].period_ns, 1_000_000_000);
+        assert_eq!(second[0].events_per_second(), 2);
+        assert_eq!(second[1].prev_runtime_ns, 0);
+        assert_eq!(second[1].period_ns, 0);
+        assert!(!sampler.prev_stats.contains_key(&2));
+
+        // The last sample is kept once the source runs dry
+        let third = sampler.sample().programs;
+        assert_eq!(third.len(), 2);
+        assert_eq!(third[0].period_ns, 1_000_000_000);
+    }
+
+    #[test]
+    fn test_sample_reused_id() {
+        let start = Instant::now();
+        let later = start + Duration::from_secs(1);
+        let mut replaced = program(1, 20, 1, later);
+        replaced.tag = "0123456789abcdef".to_string();
+        let mut sampler = Sampler::new(MockSource::new([
+            vec![program(1, 100, 5, start), program(2, 100, 5, start)],
+            // Program 1 was replaced under the same ID and the counters of 2 were reset
+            vec![replaced, program(2, 30, 2, later)],
+        ]));
+        sampler.sample();
+
+        for prog in sampler.sample().programs {
+            assert_eq!(prog.prev_runtime_ns, 0);
+            assert_eq!(prog.prev_run_cnt, 0);
+            assert_eq!(prog.period_ns, 0);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+/*
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+#ifndef BPFTOP_H
+#define BPFTOP_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Starts sampling every program each interval_ms milliseconds on a background thread,
+ * enabling BPF stats while it runs. Returns 0, or -1 if the collector is already running
+ * or the interval is 0. Needs the same privileges as bpftop. */
+int bpftop_start(unsigned int interval_ms);
+
+/* Stops the collector and waits for its thread to exit. Returns 0, or -1 if it was not
+ * running. */
+int bpftop_stop(void);
+
+/* Returns the latest sample as a JSON object with a timestamp_ms and the programs, to be
+ * freed with bpftop_free_string. Returns NULL if the collector is not running or has not
+ * taken its first sample yet. Rates are 0 in the first sample. */
+char *bpftop_collect_json(void);
+
+/* Returns why the last failed call failed, to be freed with bpftop_free_string, or NULL if
+ * nothing failed yet. */
+char *bpftop_last_error(void);
+
+/* Frees a string returned by this library. */
+void bpftop_free_string(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* BPFTOP_H */
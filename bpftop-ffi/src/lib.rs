@@ -0,0 +1,288 @@
+//! C interface to the bpftop collector, for agents that are not written in Rust.
+//!
+//! `bpftop_start` samples every program on a background thread, `bpftop_collect_json`
+//! returns the latest sample as JSON and `bpftop_stop` ends the sampling. The declarations
+//! are in `include/bpftop.h`.
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use bpftop_core::{
+    bpf_program::BpfProgram,
+    sampler::{load_pid_iter, LiveSource, Sampler},
+};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    ffi::{c_char, c_int, CString},
+    os::fd::{FromRawFd, OwnedFd},
+    ptr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+// The running collector, if started
+static COLLECTOR: Mutex<Option<Collector>> = Mutex::new(None);
+// Why the last call that returned an error failed
+static LAST_ERROR: Mutex<Option<String>> = Mutex::new(None);
+
+struct Collector {
+    stop: Arc<AtomicBool>,
+    // JSON of the latest sample, empty until the first one is taken
+    latest: Arc<Mutex<String>>,
+    thread: JoinHandle<()>,
+    // Keeps BPF stats enabled until the collector is stopped
+    _stats_fd: Option<OwnedFd>,
+}
+
+#[derive(Serialize)]
+struct PodJson<'a> {
+    namespace: &'a str,
+    name: &'a str,
+    labels: &'a BTreeMap<String, String>,
+}
+
+#[derive(Serialize)]
+struct ProcessJson<'a> {
+    pid: i32,
+    comm: &'a str,
+    container: Option<&'a str>,
+    pod: Option<PodJson<'a>>,
+}
+
+#[derive(Serialize)]
+struct ProgramJson<'a> {
+    id: u32,
+    name: &'a str,
+    bpf_type: &'a str,
+    tag: &'a str,
+    run_time_ns: u64,
+    run_cnt: u64,
+    events_per_second: i64,
+    cpu_time_percent: f64,
+    period_average_runtime_ns: u64,
+    total_average_runtime_ns: u64,
+    processes: Vec<ProcessJson<'a>>,
+}
+
+#[derive(Serialize)]
+struct SampleJson<'a> {
+    timestamp_ms: u64,
+    programs: Vec<ProgramJson<'a>>,
+}
+
+fn sample_json(programs: &[BpfProgram], timestamp_ms: u64) -> String {
+    let sample = SampleJson {
+        timestamp_ms,
+        programs: programs
+            .iter()
+            .map(|prog| ProgramJson {
+                id: prog.id,
+                name: &prog.name,
+                bpf_type: &prog.bpf_type,
+                tag: &prog.tag,
+                run_time_ns: prog.run_time_ns,
+                run_cnt: prog.run_cnt,
+                events_per_second: prog.events_per_second(),
+                cpu_time_percent: prog.cpu_time_percent(),
+                period_average_runtime_ns: prog.period_average_runtime_ns(),
+                total_average_runtime_ns: prog.total_average_runtime_ns(),
+                processes: prog
+                    .processes
+                    .iter()
+                    .map(|process| ProcessJson {
+                        pid: process.pid,
+                        comm: &process.comm,
+                        container: process
+                            .container
+                            .as_ref()
+                            .map(|container| container.display_name()),
+                        pod: process
+                            .container
+                            .as_ref()
+                            .and_then(|container| container.pod.as_ref())
+                            .map(|pod| PodJson {
+                                namespace: &pod.namespace,
+                                name: &pod.name,
+                                labels: &pod.labels,
+                            }),
+                    })
+                    .collect(),
+            })
+            .collect(),
+    };
+    serde_json::to_string(&sample).unwrap_or_default()
+}
+
+fn fail(message: &str) -> c_int {
+    *LAST_ERROR.lock().unwrap() = Some(message.to_string());
+    -1
+}
+
+/// Starts sampling every program each `interval_ms` milliseconds on a background thread,
+/// enabling BPF stats while it runs. Returns 0, or -1 if the collector is already running or
+/// the interval is 0.
+#[no_mangle]
+pub extern "C" fn bpftop_start(interval_ms: u32) -> c_int {
+    let mut collector = COLLECTOR.lock().unwrap();
+    if collector.is_some() {
+        return fail("the collector is already running");
+    }
+    if interval_ms == 0 {
+        return fail("the interval must be at least 1ms");
+    }
+
+    // Without the syscall, stats are only collected if kernel.bpf_stats_enabled is set
+    let fd = unsafe { libbpf_sys::bpf_enable_stats(libbpf_sys::BPF_STATS_RUN_TIME) };
+    let stats_fd = (fd >= 0).then(|| unsafe { OwnedFd::from_raw_fd(fd) });
+    // Without pid_iter, holders are found by scanning /proc
+    let pid_iter = load_pid_iter(None).ok().flatten();
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let latest = Arc::new(Mutex::new(String::new()));
+    let interval = Duration::from_millis(interval_ms.into());
+    let thread = {
+        let stop = Arc::clone(&stop);
+        let latest = Arc::clone(&latest);
+        thread::spawn(move || {
+            let mut sampler = Sampler::new(LiveSource::new(pid_iter));
+            while !stop.load(Ordering::Relaxed) {
+                let loop_start = Instant::now();
+                let sample = sampler.sample();
+                *latest.lock().unwrap() = sample_json(&sample.programs, sample.timestamp.wall_ms);
+                // Unparked early by bpftop_stop
+                thread::park_timeout(interval.saturating_sub(loop_start.elapsed()));
+            }
+        })
+    };
+    *collector = Some(Collector {
+        stop,
+        latest,
+        thread,
+        _stats_fd: stats_fd,
+    });
+    0
+}
+
+/// Stops the collector and waits for its thread to exit. Returns 0, or -1 if it was not
+/// running.
+#[no_mangle]
+pub extern "C" fn bpftop_stop() -> c_int {
+    let Some(collector) = COLLECTOR.lock().unwrap().take() else {
+        return fail("the collector is not running");
+    };
+    collector.stop.store(true, Ordering::Relaxed);
+    collector.thread.thread().unpark();
+    if collector.thread.join().is_err() {
+        return fail("the collector thread panicked");
+    }
+    0
+}
+
+/// Returns the latest sample as a JSON object with a `timestamp_ms` and the `programs`, to
+/// be freed with `bpftop_free_string`. Returns NULL if the collector is not running or has
+/// not taken its first sample yet. Rates are 0 in the first sample.
+#[no_mangle]
+pub extern "C" fn bpftop_collect_json() -> *mut c_char {
+    let collector = COLLECTOR.lock().unwrap();
+    let Some(collector) = collector.as_ref() else {
+        fail("the collector is not running");
+        return ptr::null_mut();
+    };
+    let latest = collector.latest.lock().unwrap();
+    if latest.is_empty() {
+        return ptr::null_mut();
+    }
+    CString::new(latest.as_str()).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Returns why the last failed call failed, to be freed with `bpftop_free_string`, or NULL
+/// if nothing failed yet.
+#[no_mangle]
+pub extern "C" fn bpftop_last_error() -> *mut c_char {
+    LAST_ERROR
+        .lock()
+        .unwrap()
+        .as_deref()
+        .and_then(|message| CString::new(message).ok())
+        .map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Frees a string returned by this library.
+///
+/// # Safety
+///
+/// `s` must be NULL or a string returned by this library that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn bpftop_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bpftop_core::bpf_program::Process;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_sample_json() {
+        let instant = Instant::now();
+        let program = BpfProgram {
+            id: 7,
+            bpf_type: "Xdp".to_string(),
+            name: "xdp_prog".to_string(),
+            tag: "0011223344556677".to_string(),
+            prev_runtime_ns: 100,
+            run_time_ns: 10_000_100,
+            prev_run_cnt: 1,
+            run_cnt: 101,
+            instant,
+            period_ns: 1_000_000_000,
+            processes: vec![Process {
+                pid: 42,
+                comm: "agent".to_string(),
+                container: None,
+                cgroup_id: 0,
+                bpf_fds: 1,
+                user: None,
+            }],
+        };
+        let json: serde_json::Value = serde_json::from_str(&sample_json(&[program], 1000)).unwrap();
+        assert_eq!(json["timestamp_ms"], 1000);
+        assert_eq!(json["programs"][0]["events_per_second"], 100);
+        assert_eq!(json["programs"][0]["cpu_time_percent"], 1.0);
+        assert_eq!(json["programs"][0]["processes"][0]["comm"], "agent");
+    }
+
+    #[test]
+    fn test_not_running() {
+        assert!(bpftop_collect_json().is_null());
+        assert_eq!(bpftop_stop(), -1);
+        assert_eq!(bpftop_start(0), -1);
+        let error = bpftop_last_error();
+        assert_eq!(
+            unsafe { CStr::from_ptr(error) }.to_str().unwrap(),
+            "the interval must be at least 1ms"
+        );
+        unsafe { bpftop_free_string(error) };
+    }
+}
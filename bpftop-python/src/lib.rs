@@ -0,0 +1,266 @@
+//! Python bindings to the bpftop collector, built into the `bpftop` wheel with maturin.
+//!
+//! `bpftop.Sampler` samples every program through [`bpftop_core::sampler::Sampler`], either
+//! once with `sample()` or once per interval by iterating over it. Each sample is returned
+//! as `Sample`, `Program`, `Process` and `Pod` objects.
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use bpftop_core::{
+    bpf_program::BpfProgram,
+    sampler::{self, load_pid_iter, LiveSource, ProgramSample},
+};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::{
+    collections::BTreeMap,
+    os::fd::{FromRawFd, OwnedFd},
+    thread,
+    time::{Duration, Instant},
+};
+
+#[pyclass(frozen, get_all, module = "bpftop")]
+#[derive(Clone)]
+struct Pod {
+    namespace: String,
+    name: String,
+    labels: BTreeMap<String, String>,
+}
+
+#[pyclass(frozen, get_all, module = "bpftop")]
+#[derive(Clone)]
+struct Process {
+    pid: i32,
+    comm: String,
+    container: Option<String>,
+    // Only resolved on Kubernetes nodes, by a module built with the kubernetes feature
+    pod: Option<Pod>,
+}
+
+#[pyclass(frozen, get_all, module = "bpftop")]
+#[derive(Clone)]
+struct Program {
+    id: u32,
+    name: String,
+    bpf_type: String,
+    tag: String,
+    run_time_ns: u64,
+    run_cnt: u64,
+    events_per_second: i64,
+    cpu_time_percent: f64,
+    period_average_runtime_ns: u64,
+    total_average_runtime_ns: u64,
+    processes: Vec<Process>,
+}
+
+#[pyclass(frozen, get_all, module = "bpftop")]
+struct Sample {
+    timestamp_ms: u64,
+    programs: Vec<Program>,
+}
+
+impl From<&BpfProgram> for Program {
+    fn from(prog: &BpfProgram) -> Program {
+        Program {
+            id: prog.id,
+            name: prog.name.clone(),
+            bpf_type: prog.bpf_type.clone(),
+            tag: prog.tag.clone(),
+            run_time_ns: prog.run_time_ns,
+            run_cnt: prog.run_cnt,
+            events_per_second: prog.events_per_second(),
+            cpu_time_percent: prog.cpu_time_percent(),
+            period_average_runtime_ns: prog.period_average_runtime_ns(),
+            total_average_runtime_ns: prog.total_average_runtime_ns(),
+            processes: prog
+                .processes
+                .iter()
+                .map(|process| Process {
+                    pid: process.pid,
+                    comm: process.comm.clone(),
+                    container: process
+                        .container
+                        .as_ref()
+                        .map(|container| container.display_name().to_string()),
+                    pod: process
+                        .container
+                        .as_ref()
+                        .and_then(|container| container.pod.as_ref())
+                        .map(|pod| Pod {
+                            namespace: pod.namespace.clone(),
+                            name: pod.name.clone(),
+                            labels: pod.labels.clone(),
+                        }),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<ProgramSample> for Sample {
+    fn from(sample: ProgramSample) -> Sample {
+        Sample {
+            timestamp_ms: sample.timestamp.wall_ms,
+            programs: sample.programs.iter().map(Program::from).collect(),
+        }
+    }
+}
+
+/// Samples every loaded program, enabling BPF stats until it is closed. Rates are 0 in the
+/// first sample. Needs the same privileges as bpftop.
+#[pyclass(module = "bpftop")]
+struct Sampler {
+    sampler: sampler::Sampler<LiveSource>,
+    interval: Duration,
+    // When iterating takes the next sample
+    next: Option<Instant>,
+    stats_fd: Option<OwnedFd>,
+}
+
+#[pymethods]
+impl Sampler {
+    #[new]
+    #[pyo3(signature = (interval_ms = 1000))]
+    fn new(interval_ms: u64) -> PyResult<Sampler> {
+        if interval_ms == 0 {
+            return Err(PyValueError::new_err("the interval must be at least 1ms"));
+        }
+        // Without the syscall, stats are only collected if kernel.bpf_stats_enabled is set
+        let fd = unsafe { libbpf_sys::bpf_enable_stats(libbpf_sys::BPF_STATS_RUN_TIME) };
+        // Without pid_iter, holders are found by scanning /proc
+        let pid_iter = load_pid_iter(None).ok().flatten();
+        Ok(Sampler {
+            sampler: sampler::Sampler::new(LiveSource::new(pid_iter)),
+            interval: Duration::from_millis(interval_ms),
+            next: None,
+            stats_fd: (fd >= 0).then(|| unsafe { OwnedFd::from_raw_fd(fd) }),
+        })
+    }
+
+    /// Takes a sample now
+    fn sample(&mut self, py: Python<'_>) -> Sample {
+        py.allow_threads(|| self.sampler.sample()).into()
+    }
+
+    /// Stops enabling BPF stats. Samples can still be taken, as long as
+    /// kernel.bpf_stats_enabled is set.
+    fn close(&mut self) {
+        self.stats_fd = None;
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(&mut self, _exc_type: PyObject, _exc: PyObject, _traceback: PyObject) {
+        self.close();
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    // The first sample is taken right away, the next ones once per interval
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Sample> {
+        if let Some(next) = self.next {
+            py.allow_threads(|| thread::sleep(next.saturating_duration_since(Instant::now())));
+            py.check_signals()?;
+        }
+        self.next = Some(Instant::now() + self.interval);
+        Ok(self.sample(py))
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "bpftop")]
+fn bpftop(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<Sampler>()?;
+    module.add_class::<Sample>()?;
+    module.add_class::<Program>()?;
+    module.add_class::<Process>()?;
+    module.add_class::<Pod>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bpftop_core::{bpf_program, container::Container, kubernetes};
+    use pyo3::types::PyDict;
+
+    #[test]
+    fn test_module() {
+        let program = BpfProgram {
+            id: 7,
+            bpf_type: "Xdp".to_string(),
+            name: "xdp_prog".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 100,
+            run_time_ns: 10_000_100,
+            prev_run_cnt: 1,
+            run_cnt: 101,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![bpf_program::Process {
+                pid: 42,
+                comm: "agent".to_string(),
+                container: Some(Container {
+                    runtime: "containerd",
+                    id: "0123456789abcdef".to_string(),
+                    name: Some("agent".to_string()),
+                    pod: Some(kubernetes::Pod {
+                        namespace: "kube-system".to_string(),
+                        name: "agent-x7k2p".to_string(),
+                        container: "agent".to_string(),
+                        labels: BTreeMap::from([("app".to_string(), "agent".to_string())]),
+                    }),
+                }),
+                cgroup_id: 0,
+                bpf_fds: 1,
+                user: None,
+            }],
+        };
+
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = pyo3::wrap_pymodule!(bpftop)(py);
+            py.import_bound("sys")?
+                .getattr("modules")?
+                .set_item("bpftop", module)?;
+            let locals = PyDict::new_bound(py);
+            locals.set_item("prog", Program::from(&program).into_py(py))?;
+            py.run_bound(
+                r#"
+import bpftop
+
+assert isinstance(prog, bpftop.Program)
+assert prog.events_per_second == 100
+assert prog.cpu_time_percent == 1.0
+assert prog.processes[0].comm == "agent"
+assert prog.processes[0].pod.labels == {"app": "agent"}
+try:
+    bpftop.Sampler(interval_ms=0)
+    assert False
+except ValueError:
+    pass
+"#,
+                None,
+                Some(&locals),
+            )
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,38 @@
+#
+#  Copyright 2024 Netflix, Inc.
+#
+#  Licensed under the Apache License, Version 2.0 (the "License");
+#  you may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at
+#
+#  http://www.apache.org/licenses/LICENSE-2.0
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+#
+# Run with `python -m unittest discover tests` after `maturin develop` in bpftop-python
+import unittest
+
+import bpftop
+
+
+class TestBpftop(unittest.TestCase):
+    def test_import(self):
+        for name in ["Sampler", "Sample", "Program", "Process", "Pod"]:
+            self.assertTrue(hasattr(bpftop, name))
+
+    def test_invalid_interval(self):
+        with self.assertRaises(ValueError):
+            bpftop.Sampler(interval_ms=0)
+
+    def test_sample(self):
+        # Without privileges the samples are empty rather than an error
+        with bpftop.Sampler(interval_ms=10) as sampler:
+            first = next(iter(sampler))
+            second = next(sampler)
+            self.assertGreaterEqual(second.timestamp_ms, first.timestamp_ms + 10)
+            for prog in second.programs:
+                self.assertIsInstance(prog, bpftop.Program)
@@ -4,20 +4,25 @@ use std::path::PathBuf;
 
 use libbpf_cargo::SkeletonBuilder;
 
-const SRC: &str = "src/bpf/pid_iter.bpf.c";
+const SRCS: &[&str] = &["profiler", "latency", "audit"];
 
 fn main() {
-    let out = PathBuf::from(
+    let manifest_dir = PathBuf::from(
         env::var_os("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR must be set in build script"),
-    )
-    .join("src")
-    .join("bpf")
-    .join("pid_iter.skel.rs");
+    );
+    let bpf_dir = manifest_dir.join("src").join("bpf");
+    // vmlinux.h and the libbpf headers live with the iterator programs in bpftop-core
+    let include_dir = manifest_dir.join("bpftop-core").join("src").join("bpf");
 
-    SkeletonBuilder::new()
-        .source(SRC)
-        .clang_args([OsStr::new("-I")])
-        .build_and_generate(&out)
-        .unwrap();
-    println!("cargo:rerun-if-changed={SRC}");
+    for name in SRCS {
+        let src = bpf_dir.join(format!("{name}.bpf.c"));
+        let out = bpf_dir.join(format!("{name}.skel.rs"));
+
+        SkeletonBuilder::new()
+            .source(&src)
+            .clang_args([OsStr::new("-I"), include_dir.as_os_str()])
+            .build_and_generate(&out)
+            .unwrap();
+        println!("cargo:rerun-if-changed={}", src.display());
+    }
 }
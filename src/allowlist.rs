@@ -0,0 +1,174 @@
+use anyhow::{anyhow, Context, Result};
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use bpftop_core::bpf_program::BpfProgram;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+use tracing::warn;
+
+// Matches any tag, for programs whose instructions legitimately vary between hosts
+const ANY_TAG: &str = "*";
+
+// Known-good programs, keyed by name with the tags each name may have. The file has one
+// "name tag" pair per line, as printed by
+// `bpftool prog show -j | jq -r '.[] | "\(.name) \(.tag)"'`, with # comments.
+#[derive(Clone, Debug, Default)]
+pub struct Allowlist {
+    entries: HashMap<String, HashSet<String>>,
+}
+
+impl Allowlist {
+    pub fn parse(contents: &str) -> Result<Allowlist> {
+        let mut entries: HashMap<String, HashSet<String>> = HashMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let (Some(name), Some(tag), None) = (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(anyhow!("Line {}: expected \"name tag\"", i + 1));
+            };
+            if tag != ANY_TAG && (tag.len() != 16 || !tag.chars().all(|c| c.is_ascii_hexdigit())) {
+                return Err(anyhow!("Line {}: invalid tag '{}'", i + 1, tag));
+            }
+            entries
+                .entry(name.to_string())
+                .or_default()
+                .insert(tag.to_ascii_lowercase());
+        }
+        Ok(Allowlist { entries })
+    }
+
+    pub fn load(path: &Path) -> Result<Allowlist> {
+        let contents = fs::read_to_string(path)
+            .context(format!("Failed to read allowlist {}", path.display()))?;
+        Allowlist::parse(&contents).context(format!("Invalid allowlist {}", path.display()))
+    }
+
+    // Unnamed programs can not be told apart, so they are never allowed
+    pub fn allows(&self, prog: &BpfProgram) -> bool {
+        !prog.name.is_empty()
+            && self
+                .entries
+                .get(&prog.name)
+                .is_some_and(|tags| tags.contains(ANY_TAG) || tags.contains(&prog.tag))
+    }
+}
+
+// Logs each running program that is not on the allowlist, once per program
+pub struct DriftDetector {
+    allowlist: Allowlist,
+    reported: HashSet<u32>,
+}
+
+impl DriftDetector {
+    pub fn new(allowlist: Allowlist) -> Self {
+        DriftDetector {
+            allowlist,
+            reported: HashSet::new(),
+        }
+    }
+
+    // Returns the programs reported for the first time
+    pub fn check(&mut self, programs: &[BpfProgram]) -> Vec<u32> {
+        let mut unlisted = vec![];
+        for prog in programs {
+            if self.allowlist.allows(prog) || !self.reported.insert(prog.id) {
+                continue;
+            }
+            let processes: Vec<String> = prog.processes.iter().map(|p| p.to_string()).collect();
+            warn!(
+                prog_id = prog.id,
+                prog_name = prog.name.as_str(),
+                prog_tag = prog.tag.as_str(),
+                prog_type = prog.bpf_type.as_str(),
+                processes = processes.join(", ").as_str(),
+                "Program {} ({}) with tag {} is not on the allowlist",
+                prog.name,
+                prog.id,
+                prog.tag
+            );
+            unlisted.push(prog.id);
+        }
+
+        // Forget unloaded programs so a reused ID is checked again
+        let ids: HashSet<u32> = programs.iter().map(|prog| prog.id).collect();
+        self.reported.retain(|id| ids.contains(id));
+        unlisted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn program(id: u32, name: &str, tag: &str) -> BpfProgram {
+        BpfProgram {
+            id,
+            bpf_type: "Xdp".to_string(),
+            name: name.to_string(),
+            tag: tag.to_string(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let allowlist = Allowlist::parse(
+            "# cilium\ncil_from_netdev 3B185F0A00FF129C\n\nxdp_lb *  # rebuilt per host\n",
+        )
+        .unwrap();
+        assert!(allowlist.allows(&program(1, "cil_from_netdev", "3b185f0a00ff129c")));
+        assert!(!allowlist.allows(&program(2, "cil_from_netdev", "0000000000000000")));
+        assert!(allowlist.allows(&program(3, "xdp_lb", "0000000000000000")));
+        assert!(!allowlist.allows(&program(4, "unknown", "3b185f0a00ff129c")));
+
+        assert!(Allowlist::parse("name").is_err());
+        assert!(Allowlist::parse("name tag extra").is_err());
+        assert!(Allowlist::parse("name 3b18").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let allowlist = Allowlist::parse("known 3b185f0a00ff129c").unwrap();
+        let mut detector = DriftDetector::new(allowlist);
+        let programs = vec![
+            program(1, "known", "3b185f0a00ff129c"),
+            program(2, "rogue", "3b185f0a00ff129c"),
+            program(3, "", "3b185f0a00ff129c"),
+        ];
+        assert_eq!(detector.check(&programs), vec![2, 3]);
+        assert!(detector.check(&programs).is_empty());
+
+        // Reported again once the ID is reused after an unload
+        detector.check(&programs[..1]);
+        assert_eq!(detector.check(&programs), vec![2, 3]);
+    }
+}
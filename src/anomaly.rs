@@ -0,0 +1,169 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use bpftop_core::bpf_program::BpfProgram;
+use std::collections::HashMap;
+
+// How many standard deviations from its baseline a program has to move to be flagged,
+// unless configured
+pub const DEFAULT_ANOMALY_SIGMA: f64 = 3.0;
+
+// Periods a baseline is built over before deviations from it are flagged
+const WARMUP_PERIODS: u32 = 30;
+
+// Weight of each period in the rolling baselines, which makes them follow roughly the
+// last minute
+const ALPHA: f64 = 2.0 / 61.0;
+
+// Deviations smaller than these are noise, however steady a program has been
+const MIN_CPU_DEVIATION: f64 = 0.1;
+const MIN_EPS_DEVIATION: f64 = 10.0;
+
+// Exponentially weighted mean and variance of one of a program's stats
+#[derive(Debug, Default)]
+struct Baseline {
+    mean: f64,
+    variance: f64,
+}
+
+impl Baseline {
+    fn deviates(&self, value: f64, sigma: f64, min_deviation: f64) -> bool {
+        (value - self.mean).abs() > (sigma * self.variance.sqrt()).max(min_deviation)
+    }
+
+    fn update(&mut self, value: f64) {
+        let diff = value - self.mean;
+        let increment = ALPHA * diff;
+        self.mean += increment;
+        self.variance = (1.0 - ALPHA) * (self.variance + diff * increment);
+    }
+}
+
+#[derive(Debug, Default)]
+struct ProgramBaseline {
+    periods: u32,
+    cpu_time_percent: Baseline,
+    events_per_second: Baseline,
+    anomalous: bool,
+}
+
+// Learns what is normal for each program and flags the ones whose CPU% or events/sec
+// moved far from it in the last period, so unusual programs stand out and not just
+// the busiest ones
+#[derive(Debug)]
+pub struct AnomalyTracker {
+    sigma: f64,
+    baselines: HashMap<u32, ProgramBaseline>,
+}
+
+impl Default for AnomalyTracker {
+    fn default() -> Self {
+        AnomalyTracker::new(DEFAULT_ANOMALY_SIGMA)
+    }
+}
+
+impl AnomalyTracker {
+    pub fn new(sigma: f64) -> Self {
+        AnomalyTracker {
+            sigma,
+            baselines: HashMap::new(),
+        }
+    }
+
+    // Each period is compared with the baseline before being folded into it, so a lasting
+    // change stops being flagged once it has become the new normal. Programs that are no
+    // longer loaded are forgotten.
+    pub fn observe(&mut self, programs: &[BpfProgram]) {
+        let mut baselines = HashMap::with_capacity(programs.len());
+        for prog in programs.iter().filter(|prog| prog.period_ns > 0) {
+            let mut baseline = self.baselines.remove(&prog.id).unwrap_or_default();
+            let cpu_time_percent = prog.cpu_time_percent();
+            let events_per_second = prog.events_per_second() as f64;
+            // Start from the first period rather than from zero
+            if baseline.periods == 0 {
+                baseline.cpu_time_percent.mean = cpu_time_percent;
+                baseline.events_per_second.mean = events_per_second;
+            }
+            baseline.anomalous = baseline.periods >= WARMUP_PERIODS
+                && (baseline.cpu_time_percent.deviates(
+                    cpu_time_percent,
+                    self.sigma,
+                    MIN_CPU_DEVIATION,
+                ) || baseline.events_per_second.deviates(
+                    events_per_second,
+                    self.sigma,
+                    MIN_EPS_DEVIATION,
+                ));
+            baseline.cpu_time_percent.update(cpu_time_percent);
+            baseline.events_per_second.update(events_per_second);
+            baseline.periods = baseline.periods.saturating_add(1);
+            baselines.insert(prog.id, baseline);
+        }
+        self.baselines = baselines;
+    }
+
+    pub fn anomalous(&self) -> impl Iterator<Item = u32> + '_ {
+        self.baselines
+            .iter()
+            .filter(|(_, baseline)| baseline.anomalous)
+            .map(|(id, _)| *id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    // A program that ran the given number of 1µs events in a one-second period
+    fn program(id: u32, events: u64) -> BpfProgram {
+        BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: events * 1000,
+            prev_run_cnt: 0,
+            run_cnt: events,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_observe() {
+        let mut tracker = AnomalyTracker::new(3.0);
+        let anomalous = |tracker: &AnomalyTracker| tracker.anomalous().collect::<Vec<_>>();
+        for period in 0..WARMUP_PERIODS as u64 {
+            // Some jitter around 1000 events/sec
+            tracker.observe(&[program(1, 1000 + period % 5 * 10), program(2, 500)]);
+            assert!(anomalous(&tracker).is_empty());
+        }
+
+        // A small move within the usual jitter is not flagged
+        tracker.observe(&[program(1, 1030), program(2, 500)]);
+        assert!(anomalous(&tracker).is_empty());
+
+        tracker.observe(&[program(1, 1000), program(2, 50_000)]);
+        assert_eq!(anomalous(&tracker), vec![2]);
+
+        tracker.observe(&[program(1, 1000)]);
+        assert!(anomalous(&tracker).is_empty());
+    }
+}
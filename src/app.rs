@@ -15,48 +15,370 @@
  *  limitations under the License.
  *
  */
-use crate::{bpf_program::{BpfProgram, Process}, helpers::program_type_to_string};
+use crate::{
+    allowlist::Allowlist,
+    anomaly::{AnomalyTracker, DEFAULT_ANOMALY_SIGMA},
+    baseline::{BaselineSnapshot, BASELINE_COLUMNS},
+    bpf_map::{get_maps, BpfMap, FillCounter},
+    bpffs::{
+        bpffs_mounts, pin_program, pinned_objects, pinned_paths, resolve_pins, unpin, Pin,
+        PinnedKind, DEFAULT_BPFFS,
+    },
+    capabilities::{Capabilities, PERFMON_MISSING},
+    cmdline::CmdlineCache,
+    cpu_runtime::{collect_cpu_runtime, CpuBreakdown, CpuRuntimeState},
+    errors::ErrorLog,
+    exporter::Exporters,
+    filter::Filter,
+    histogram::{collect_histogram, Histogram, HistogramState},
+    idle::{IdleTracker, DEFAULT_IDLE_PERIODS},
+    kernel_features::{KernelFeatures, StatsControl, StatsWatcher},
+    loaders::LoaderRegistry,
+    map_inspector::MapInspector,
+    map_ops::MapOpsWorker,
+    memory::{btf_memory, map_memory, program_memory, MemoryUsage},
+    process_info::ProcessInfo,
+    profile::{profile_program, ProfileState, PROFILE_DURATION},
+    prog_flags::ProgFlags,
+    prog_types,
+    ringbuf::RingBufTracker,
+    rules::RuleEngine,
+    script::CustomColumn,
+    session::Session,
+    signals::signal_process,
+    snapshot::{Sample, Snapshot},
+    stars::Stars,
+    status::HostInfo,
+    struct_ops::{get_struct_ops, StructOps},
+    transient::{FastScan, TransientTracker},
+};
+use bpftop_core::{
+    bpf_attachment::{get_detachable_attachments, get_links, Attachment},
+    bpf_program::{BpfProgram, Process},
+    cgroups::{scan_cgroups, CgroupPrograms},
+    interfaces::{scan_interfaces, InterfacePrograms},
+    processes::{group_by_process, ProcessPrograms},
+    sampler::{get_map_owners, ProgramSample, ProgramStatsSource, Sampler, Timestamp},
+};
 use circular_buffer::CircularBuffer;
-use libbpf_rs::{query::ProgInfoIter, Iter, Link};
+use crossterm::event::Event;
+use libbpf_rs::Link;
+use nix::sys::signal::Signal;
 use ratatui::widgets::TableState;
+use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
-    io::Read,
-    ptr,
-    sync::{Arc, Mutex},
-    thread,
+    cmp,
+    collections::{HashMap, HashSet},
+    mem,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
     time::{Duration, Instant},
 };
-use tracing::error;
+use tracing::{error, info, warn};
 use tui_input::Input;
 
+// How long a press of z waits for the second one that suspends BPF stats
+const STATS_CONFIRM_WINDOW: Duration = Duration::from_secs(3);
+
+// How often the collector samples the programs
+pub const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+// How often the cgroup hierarchy and network interfaces are scanned for attached programs
+const ATTACHMENT_SCAN_INTERVAL: Duration = Duration::from_secs(5);
+
+// Attachments of the program in the graph view. They are resolved on a worker thread, as
+// dumping the tc filters of every interface can take a while on hosts with many of them.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttachmentsState {
+    Resolving(u32),
+    Resolved(u32, Vec<Attachment>),
+}
+
+// Flags of the program in the graph view, read on a worker thread as going through kallsyms
+// for the functions the program calls takes a moment
+#[derive(Clone, Debug, PartialEq)]
+pub enum FlagsState {
+    Resolving(u32),
+    Resolved(u32, ProgFlags),
+    Failed(u32, String),
+}
+
+// What wakes the draw loop, which otherwise sleeps until there is something new to show
+pub enum UiEvent {
+    Input(Event),
+    Sample,
+}
+
+// The running collector thread, which owns the pid_iter and map_iter links until it exits
+pub struct Collector {
+    handle: JoinHandle<()>,
+    stop: Sender<()>,
+}
+
+impl Collector {
+    // Stops the collector and waits up to the timeout for it to finish its current cycle,
+    // so its links are detached before bpftop tears down the terminal and exits. Returns
+    // whether it stopped in time.
+    pub fn shutdown(self, timeout: Duration) -> bool {
+        drop(self.stop);
+        let deadline = Instant::now() + timeout;
+        while !self.handle.is_finished() {
+            if Instant::now() >= deadline {
+                warn!("Collector did not stop within {:?}", timeout);
+                return false;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        if self.handle.join().is_err() {
+            error!("Collector panicked");
+        }
+        true
+    }
+}
+
 pub struct App {
     pub mode: Mode,
+    // Where closing the help goes back to
+    pub mode_before_help: Mode,
+    pub view: View,
     pub table_state: TableState,
-    pub header_columns: [String; 7],
+    pub header_columns: [String; 10],
+    // Columns from the config file, shown after the built-in ones
+    pub custom_columns: Vec<CustomColumn>,
+    // Snapshot the programs are compared against in extra columns, with --baseline
+    pub baseline: Option<BaselineSnapshot>,
+    // Cgroup subtree the programs are restricted to, from --cgroup
+    pub cgroup_scope: Option<String>,
     pub items: Arc<Mutex<Vec<BpfProgram>>>,
+    pub map_table_state: TableState,
+    pub map_header_columns: [String; 12],
+    pub maps: Arc<Mutex<Vec<BpfMap>>>,
+    pub struct_ops_table_state: TableState,
+    pub struct_ops_header_columns: [String; 7],
+    pub struct_ops: Arc<Mutex<Vec<StructOps>>>,
+    pub cgroups_table_state: TableState,
+    pub cgroups_header_columns: [String; 5],
+    pub cgroups: Arc<Mutex<Vec<CgroupPrograms>>>,
+    pub pins_table_state: TableState,
+    pub pins_header_columns: [String; 6],
+    pub pins: Arc<Mutex<Vec<Pin>>>,
+    pub interfaces_table_state: TableState,
+    pub interfaces_header_columns: [String; 5],
+    pub interfaces: Arc<Mutex<Vec<InterfacePrograms>>>,
+    pub processes_table_state: TableState,
+    pub processes_header_columns: [String; 6],
+    pub processes: Arc<Mutex<Vec<ProcessPrograms>>>,
+    // PID and comm of the process the program list is narrowed down to
+    pub selected_process: Arc<Mutex<Option<(i32, String)>>>,
+    // Program type family the program list is narrowed down to, picked from the breakdown
+    pub type_filter: Option<String>,
+    pub stars: Stars,
+    // Whether only the starred programs are listed
+    pub starred_only: bool,
+    // Whether the selection follows the program with the highest value of the sorted column
+    pub follow: bool,
     pub data_buf: Arc<Mutex<CircularBuffer<20, PeriodMeasure>>>,
     pub max_cpu: f64,
     pub max_eps: i64,
     pub max_runtime: u64,
-    pub filter_input: Arc<Mutex<Input>>,
+    pub filter: Arc<Mutex<Filter>>,
     pub selected_column: Option<usize>,
     pub graphs_bpf_program: Arc<Mutex<Option<BpfProgram>>>,
+    pub graphs_attachments: Arc<Mutex<Option<AttachmentsState>>>,
+    pub graphs_flags: Arc<Mutex<Option<FlagsState>>>,
+    // Attachment in the graph view that detaching applies to
+    pub selected_attachment: usize,
+    // Process holding the program in the graph view that signalling and details apply to
+    pub selected_holder: usize,
+    // Whether the signal prompt sends SIGKILL rather than SIGTERM
+    pub signal_kill: bool,
+    // Details of the selected process, shown over the graph view
+    pub process_info: Option<ProcessInfo>,
+    pub cmdlines: CmdlineCache,
+    // Typed confirmation or path of the detach, pin and unpin prompts
+    pub prompt_input: Input,
+    // Whether pinning the program from the graph view also pins its maps
+    pub pin_maps: bool,
+    // Outcome of the last detach, pin or unpin, shown in the graph or pins view
+    pub action_status: Option<String>,
+    pub profile: Arc<Mutex<Option<ProfileState>>>,
+    pub histogram: Arc<Mutex<Option<HistogramState>>>,
+    histogram_running: Option<Arc<AtomicBool>>,
+    pub cpu_runtime: Arc<Mutex<Option<CpuRuntimeState>>>,
+    cpu_runtime_running: Option<Arc<AtomicBool>>,
+    pub map_inspector: Option<MapInspector>,
+    pub features: KernelFeatures,
+    pub capabilities: Capabilities,
+    // How BPF stats were enabled. Stats bpftop turned on through the sysctl are turned back
+    // on when something else turns them off, unless suspended; otherwise it only follows
+    // the sysctl.
+    pub stats: Arc<Mutex<StatsControl>>,
+    // When suspending stats was asked for, which takes a second press to confirm
+    stats_suspend_armed: Option<Instant>,
+    runtime_stats: Arc<AtomicBool>,
+    // Programs missing from it are highlighted
+    pub allowlist: Option<Allowlist>,
+    pub loaders: Arc<Mutex<LoaderRegistry>>,
+    pub transients: Arc<Mutex<TransientTracker>>,
+    pub show_transients: bool,
+    // Whether the audit programs report loads and unloads, in which case the fast scan
+    // only runs to fill in the stats of the transient panel while it is shown
+    pub audited: bool,
+    fast_scan: Option<FastScan>,
+    // Whether the lower part of the program list graphs the selected program
+    pub show_split: Arc<AtomicBool>,
+    // Whether the heatmap is shown, which also needs the history of every program
+    show_heatmap: Arc<AtomicBool>,
+    pub history: Arc<Mutex<ProgramHistory>>,
+    // Programs without events for idle_periods periods are left out of the program list
+    pub hide_idle: Arc<AtomicBool>,
+    pub idle_periods: u32,
+    hidden_idle: Arc<AtomicUsize>,
+    // Programs whose last period moved more than anomaly_sigma standard deviations from
+    // their baseline
+    pub anomaly_sigma: f64,
+    pub anomalies: Arc<Mutex<HashSet<u32>>>,
+    // Bumped whenever the collector has published a new cycle of data, so the draw loop can
+    // tell whether a frame would show anything new
+    generation: Arc<AtomicU64>,
+    // Times the --export-json recording was trimmed to stay under its size cap
+    pub recording_trims: Arc<AtomicUsize>,
+    pub memory: Arc<Mutex<MemoryUsage>>,
+    // Shown on the status line of every screen
+    pub host: HostInfo,
+    // Everything collected in the last period, unfiltered, for snapshots
+    pub sample: Arc<Mutex<Sample>>,
+    pub snapshot_dir: PathBuf,
+    // Session restored at startup, applied once the selected program has been sampled
+    pending_session: Option<Session>,
+    watch: Option<WatchTarget>,
+    pub show_memory: bool,
+    // Errors logged since startup, shown on request
+    pub errors: Arc<Mutex<ErrorLog>>,
+    pub show_errors: bool,
+    // Whether a panel shows how events and CPU are spread over the programs
+    pub show_distribution: bool,
+    pub track_map_ops: Arc<AtomicBool>,
     sorted_column: Arc<Mutex<SortColumn>>,
+    map_sorted_column: Arc<Mutex<SortColumn>>,
+    struct_ops_sorted_column: Arc<Mutex<SortColumn>>,
+    cgroups_sorted_column: Arc<Mutex<SortColumn>>,
+    processes_sorted_column: Arc<Mutex<SortColumn>>,
+    interfaces_sorted_column: Arc<Mutex<SortColumn>>,
+    pins_sorted_column: Arc<Mutex<SortColumn>>,
 }
 
+// Program columns computed from the runtime and run count BPF stats
+const STATS_COLUMNS: [usize; 4] = [3, 4, 5, 6];
+
 pub struct PeriodMeasure {
+    // When the period ended
+    pub timestamp: Timestamp,
     pub cpu_time_percent: f64,
     pub events_per_sec: i64,
     pub average_runtime_ns: u64,
 }
 
+// Periods of history kept per program for the split layout and the heatmap
+pub const HISTORY_PERIODS: usize = 60;
+
+// Recent periods of every program, kept while the split layout or the heatmap is shown, so
+// they have history to show without waiting for a program to build it up
+#[derive(Default)]
+pub struct ProgramHistory {
+    periods: HashMap<u32, CircularBuffer<HISTORY_PERIODS, PeriodMeasure>>,
+}
+
+impl ProgramHistory {
+    fn record(&mut self, programs: &[BpfProgram], timestamp: Timestamp) {
+        let ids: HashSet<u32> = programs.iter().map(|prog| prog.id).collect();
+        self.periods.retain(|id, _| ids.contains(id));
+        for prog in programs {
+            self.periods
+                .entry(prog.id)
+                .or_default()
+                .push_back(PeriodMeasure {
+                    timestamp,
+                    cpu_time_percent: prog.cpu_time_percent(),
+                    events_per_sec: prog.events_per_second(),
+                    average_runtime_ns: prog.period_average_runtime_ns(),
+                });
+        }
+    }
+
+    pub fn get(&self, prog_id: u32) -> Option<&CircularBuffer<HISTORY_PERIODS, PeriodMeasure>> {
+        self.periods.get(&prog_id)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum Mode {
     Table,
     Graph,
     Filter,
     Sort,
+    MapInspector,
+    MapSearch,
+    Diagnostics,
+    // CPU % of every program over the last periods
+    Heatmap,
+    // Top programs by CPU %, events per second, runtime and memory
+    Dashboard,
+    // Waiting for the typed confirmation to detach an attachment from the graph view
+    Detach,
+    // Waiting for the typed confirmation to signal a process holding the program in the
+    // graph view
+    Signal,
+    // Details of a process holding the program, over the graph view
+    ProcessInfo,
+    // Waiting for the bpffs path to pin the program in the graph view to
+    Pin,
+    // Waiting for the typed confirmation to remove the selected pin
+    Unpin,
+    // Every key of the program list or graph view, over it
+    Help,
+}
+
+// Program whose graph view is opened once it shows up, with --watch-name or --watch-id
+#[derive(Clone, Debug, PartialEq)]
+pub enum WatchTarget {
+    Id(u32),
+    Name(String),
+}
+
+impl WatchTarget {
+    fn matches(&self, prog: &BpfProgram) -> bool {
+        match self {
+            WatchTarget::Id(id) => prog.id == *id,
+            // The kernel only keeps the first 15 bytes of program names
+            WatchTarget::Name(name) => prog.name == name.get(..15).unwrap_or(name),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            WatchTarget::Id(id) => format!("ID {}", id),
+            WatchTarget::Name(name) => name.clone(),
+        }
+    }
+}
+
+// The top-level screen rendered while in table, filter or sort mode
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum View {
+    #[default]
+    Programs,
+    Maps,
+    StructOps,
+    Cgroups,
+    Processes,
+    Interfaces,
+    Pins,
 }
 
 #[derive(Clone, Copy)]
@@ -66,59 +388,61 @@ pub enum SortColumn {
     Descending(usize),
 }
 
-#[repr(C)]
-pub struct PidIterEntry {
-    id: u32,
-    pid: i32,
-    comm: [u8; 16],
+// Orders percentages with NaN below every number, so a bad reading sinks to the bottom of
+// the default descending sort instead of panicking the collector
+fn cmp_percent(a: f64, b: f64) -> cmp::Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (false, false) => a.total_cmp(&b),
+        (a_nan, b_nan) => b_nan.cmp(&a_nan),
+    }
 }
 
-fn get_pid_map(link: &Option<Link>) -> HashMap<u32, Vec<Process>> {
-    let mut pid_map: HashMap<u32, Vec<Process>> = HashMap::new();
-
-    // Check if there is a link
-    if let Some(actual_link) = link {
-        let mut iter = match Iter::new(actual_link) {
-            Ok(iter) => iter,
-            Err(e) => {
-                error!("Failed to create iterator: {}", e);
-                return pid_map;
+// Sorts programs based on index of the column. Programs with equal values, like the many
+// idle ones at 0%, fall back to their ID so they don't swap places on every refresh.
+fn sort_programs(programs: &mut [BpfProgram], sort_col: SortColumn) {
+    match sort_col {
+        SortColumn::Ascending(col_idx) | SortColumn::Descending(col_idx) => {
+            match col_idx {
+                1 => programs
+                    .sort_unstable_by(|a, b| a.bpf_type.cmp(&b.bpf_type).then(a.id.cmp(&b.id))),
+                2 => programs.sort_unstable_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id))),
+                3 => programs.sort_unstable_by(|a, b| {
+                    a.period_average_runtime_ns()
+                        .cmp(&b.period_average_runtime_ns())
+                        .then(a.id.cmp(&b.id))
+                }),
+                4 => programs.sort_unstable_by(|a, b| {
+                    a.total_average_runtime_ns()
+                        .cmp(&b.total_average_runtime_ns())
+                        .then(a.id.cmp(&b.id))
+                }),
+                5 => programs.sort_unstable_by(|a, b| {
+                    a.events_per_second()
+                        .cmp(&b.events_per_second())
+                        .then(a.id.cmp(&b.id))
+                }),
+                6 => programs.sort_unstable_by(|a, b| {
+                    cmp_percent(a.cpu_time_percent(), b.cpu_time_percent()).then(a.id.cmp(&b.id))
+                }),
+                7 => programs.sort_by_cached_key(|item| (item.containers(), item.id)),
+                8 => programs.sort_by_cached_key(|item| (item.pods(), item.id)),
+                9 => programs.sort_by_cached_key(|item| (item.holders(), item.id)),
+                _ => programs.sort_unstable_by_key(|item| item.id),
             }
-        };
-        let struct_size = std::mem::size_of::<PidIterEntry>();
-
-        loop {
-            let mut buffer = vec![0u8; struct_size];
-            match iter.read(&mut buffer) {
-                Ok(0) => break, // No more data to read
-                Ok(n) => {
-                    if n != struct_size {
-                        error!("Expected {} bytes, read {} bytes", buffer.len(), n);
-                        break;
-                    }
-                    let pid_entry: PidIterEntry = unsafe { ptr::read(buffer.as_ptr() as *const _) };
-                    let process = Process {
-                        pid: pid_entry.pid,
-                        comm: String::from_utf8_lossy(&pid_entry.comm).to_string(),
-                    };
-
-                    pid_map.entry(pid_entry.id).or_default().push(process);
-                }
-                Err(e) => {
-                    error!("Failed to read from iterator: {}", e);
-                    break;
-                }
+            if let SortColumn::Descending(_) = sort_col {
+                programs.reverse();
             }
         }
+        SortColumn::NoOrder => {}
     }
-
-    pid_map
 }
 
 impl App {
     pub fn new() -> App {
         let mut app = App {
             mode: Mode::Table,
+            mode_before_help: Mode::Table,
+            view: View::Programs,
             table_state: TableState::default(),
             header_columns: [
                 String::from("ID"),
@@ -128,205 +452,1558 @@ impl App {
                 String::from("Total Avg Runtime (ns)"),
                 String::from("Events/sec"),
                 String::from("Total CPU %"),
+                String::from("Container"),
+                String::from("Pod"),
+                String::from("Holders"),
             ],
+            custom_columns: vec![],
+            baseline: None,
+            cgroup_scope: None,
             items: Arc::new(Mutex::new(vec![])),
+            map_table_state: TableState::default(),
+            map_header_columns: [
+                String::from("ID"),
+                String::from("Name"),
+                String::from("Type"),
+                String::from("Key Size"),
+                String::from("Value Size"),
+                String::from("Max Entries"),
+                String::from("Fill %"),
+                String::from("Changed keys/s"),
+                String::from("Memlock"),
+                String::from("Pinned"),
+                String::from("Programs"),
+                String::from("Owners"),
+            ],
+            maps: Arc::new(Mutex::new(vec![])),
+            struct_ops_table_state: TableState::default(),
+            struct_ops_header_columns: [
+                String::from("ID"),
+                String::from("Name"),
+                String::from("Struct"),
+                String::from("State"),
+                String::from("Programs"),
+                String::from("Events/sec"),
+                String::from("Total CPU %"),
+            ],
+            struct_ops: Arc::new(Mutex::new(vec![])),
+            cgroups_table_state: TableState::default(),
+            cgroups_header_columns: [
+                String::from("ID"),
+                String::from("Cgroup"),
+                String::from("Programs"),
+                String::from("Events/sec"),
+                String::from("Total CPU %"),
+            ],
+            cgroups: Arc::new(Mutex::new(vec![])),
+            pins_table_state: TableState::default(),
+            pins_header_columns: [
+                String::from("Kind"),
+                String::from("ID"),
+                String::from("Path"),
+                String::from("Name"),
+                String::from("Type"),
+                String::from("Used by"),
+            ],
+            pins: Arc::new(Mutex::new(vec![])),
+            interfaces_table_state: TableState::default(),
+            interfaces_header_columns: [
+                String::from("Ifindex"),
+                String::from("Interface"),
+                String::from("Programs"),
+                String::from("Events/sec"),
+                String::from("Total CPU %"),
+            ],
+            interfaces: Arc::new(Mutex::new(vec![])),
+            processes_table_state: TableState::default(),
+            processes_header_columns: [
+                String::from("PID"),
+                String::from("Comm"),
+                String::from("Container"),
+                String::from("Programs"),
+                String::from("Events/sec"),
+                String::from("Total CPU %"),
+            ],
+            processes: Arc::new(Mutex::new(vec![])),
+            selected_process: Arc::new(Mutex::new(None)),
+            type_filter: None,
+            stars: Stars::default(),
+            starred_only: false,
+            follow: false,
             data_buf: Arc::new(Mutex::new(CircularBuffer::<20, PeriodMeasure>::new())),
             max_cpu: 0.0,
             max_eps: 0,
             max_runtime: 0,
-            filter_input: Arc::new(Mutex::new(Input::default())),
+            filter: Arc::new(Mutex::new(Filter::default())),
             selected_column: None,
             graphs_bpf_program: Arc::new(Mutex::new(None)),
+            graphs_attachments: Arc::new(Mutex::new(None)),
+            graphs_flags: Arc::new(Mutex::new(None)),
+            selected_attachment: 0,
+            selected_holder: 0,
+            signal_kill: false,
+            process_info: None,
+            cmdlines: CmdlineCache::default(),
+            prompt_input: Input::default(),
+            pin_maps: false,
+            action_status: None,
+            profile: Arc::new(Mutex::new(None)),
+            histogram: Arc::new(Mutex::new(None)),
+            histogram_running: None,
+            cpu_runtime: Arc::new(Mutex::new(None)),
+            cpu_runtime_running: None,
+            map_inspector: None,
+            features: KernelFeatures::default(),
+            capabilities: Capabilities::default(),
+            stats: Arc::new(Mutex::new(StatsControl::default())),
+            stats_suspend_armed: None,
+            runtime_stats: Arc::new(AtomicBool::new(true)),
+            allowlist: None,
+            loaders: Arc::new(Mutex::new(LoaderRegistry::default())),
+            transients: Arc::new(Mutex::new(TransientTracker::default())),
+            show_transients: false,
+            audited: false,
+            fast_scan: None,
+            show_split: Arc::new(AtomicBool::new(false)),
+            show_heatmap: Arc::new(AtomicBool::new(false)),
+            history: Arc::new(Mutex::new(ProgramHistory::default())),
+            hide_idle: Arc::new(AtomicBool::new(false)),
+            idle_periods: DEFAULT_IDLE_PERIODS,
+            hidden_idle: Arc::new(AtomicUsize::new(0)),
+            anomaly_sigma: DEFAULT_ANOMALY_SIGMA,
+            anomalies: Arc::new(Mutex::new(HashSet::new())),
+            generation: Arc::new(AtomicU64::new(0)),
+            recording_trims: Arc::new(AtomicUsize::new(0)),
+            memory: Arc::new(Mutex::new(MemoryUsage::default())),
+            host: HostInfo::default(),
+            sample: Arc::new(Mutex::new(Sample::default())),
+            snapshot_dir: PathBuf::from("."),
+            pending_session: None,
+            watch: None,
+            show_memory: false,
+            errors: Arc::new(Mutex::new(ErrorLog::default())),
+            show_errors: false,
+            show_distribution: false,
+            track_map_ops: Arc::new(AtomicBool::new(false)),
             sorted_column: Arc::new(Mutex::new(SortColumn::NoOrder)),
+            map_sorted_column: Arc::new(Mutex::new(SortColumn::NoOrder)),
+            struct_ops_sorted_column: Arc::new(Mutex::new(SortColumn::NoOrder)),
+            cgroups_sorted_column: Arc::new(Mutex::new(SortColumn::NoOrder)),
+            processes_sorted_column: Arc::new(Mutex::new(SortColumn::NoOrder)),
+            interfaces_sorted_column: Arc::new(Mutex::new(SortColumn::NoOrder)),
+            pins_sorted_column: Arc::new(Mutex::new(SortColumn::NoOrder)),
         };
         // Default sort column is Total CPU % in descending order
         app.sort_column(SortColumn::Descending(6));
+        // Default map sort column is Memlock in descending order
+        update_sort_column(
+            &mut app.map_header_columns,
+            &app.map_sorted_column,
+            SortColumn::Descending(8),
+        );
+        // Default struct_ops sort column is Total CPU % in descending order
+        update_sort_column(
+            &mut app.struct_ops_header_columns,
+            &app.struct_ops_sorted_column,
+            SortColumn::Descending(6),
+        );
+        // Default cgroup sort column is Total CPU % in descending order
+        update_sort_column(
+            &mut app.cgroups_header_columns,
+            &app.cgroups_sorted_column,
+            SortColumn::Descending(4),
+        );
+        // Default process sort column is Total CPU % in descending order
+        update_sort_column(
+            &mut app.processes_header_columns,
+            &app.processes_sorted_column,
+            SortColumn::Descending(5),
+        );
+        // Default interface sort column is Total CPU % in descending order
+        update_sort_column(
+            &mut app.interfaces_header_columns,
+            &app.interfaces_sorted_column,
+            SortColumn::Descending(4),
+        );
+        // Default pin sort column is Path in ascending order
+        update_sort_column(
+            &mut app.pins_header_columns,
+            &app.pins_sorted_column,
+            SortColumn::Ascending(2),
+        );
         app
     }
 
-    pub fn start_background_thread(&self, iter_link: Option<Link>) {
+    pub fn start_background_thread<S: ProgramStatsSource + 'static>(
+        &self,
+        source: S,
+        map_iter: Option<Link>,
+        mut rules: RuleEngine,
+        mut exporters: Exporters,
+        ui_events: Sender<UiEvent>,
+    ) -> Collector {
+        let (stop, stopped) = mpsc::channel();
         let items = Arc::clone(&self.items);
         let data_buf = Arc::clone(&self.data_buf);
-        let filter = Arc::clone(&self.filter_input);
+        let filter = Arc::clone(&self.filter);
         let sort_col = Arc::clone(&self.sorted_column);
+        let maps = Arc::clone(&self.maps);
+        let map_sort_col = Arc::clone(&self.map_sorted_column);
+        let track_map_ops = Arc::clone(&self.track_map_ops);
+        let struct_ops = Arc::clone(&self.struct_ops);
+        let struct_ops_sort_col = Arc::clone(&self.struct_ops_sorted_column);
+        let cgroups = Arc::clone(&self.cgroups);
+        let cgroups_sort_col = Arc::clone(&self.cgroups_sorted_column);
+        let pins = Arc::clone(&self.pins);
+        let pins_sort_col = Arc::clone(&self.pins_sorted_column);
+        let interfaces = Arc::clone(&self.interfaces);
+        let interfaces_sort_col = Arc::clone(&self.interfaces_sorted_column);
+        let processes = Arc::clone(&self.processes);
+        let processes_sort_col = Arc::clone(&self.processes_sorted_column);
+        let selected_process = Arc::clone(&self.selected_process);
         let graphs_bpf_program = Arc::clone(&self.graphs_bpf_program);
+        let loaders = Arc::clone(&self.loaders);
+        let memory = Arc::clone(&self.memory);
+        let mut stats_watcher = StatsWatcher::new(Arc::clone(&self.stats));
+        let runtime_stats = Arc::clone(&self.runtime_stats);
+        let sample = Arc::clone(&self.sample);
+        let hide_idle = Arc::clone(&self.hide_idle);
+        let hidden_idle = Arc::clone(&self.hidden_idle);
+        let generation = Arc::clone(&self.generation);
+        let idle_periods = self.idle_periods;
+        let anomaly_sigma = self.anomaly_sigma;
+        let anomalies = Arc::clone(&self.anomalies);
+        let show_split = Arc::clone(&self.show_split);
+        let show_heatmap = Arc::clone(&self.show_heatmap);
+        let history = Arc::clone(&self.history);
+        let net_admin = self.capabilities.net_admin;
+
+        let handle = thread::spawn(move || {
+            let mut sampler = Sampler::new(source);
+            let fill_counter = FillCounter::start();
+            let ops_worker = MapOpsWorker::start(track_map_ops);
+            let mut ringbuf_tracker = RingBufTracker::default();
+            let mut last_attachment_scan: Option<Instant> = None;
+            let mut cgroup_attachments = vec![];
+            let mut interface_attachments = vec![];
+            let mut idle = IdleTracker::new(idle_periods);
+            let mut anomaly = AnomalyTracker::new(anomaly_sigma);
+            loop {
+                let loop_start = Instant::now();
+
+                // Stats may be turned on or off by whatever manages the sysctl at any time,
+                // even when bpftop turned them on
+                runtime_stats.store(stats_watcher.check(), Ordering::Relaxed);
+
+                if last_attachment_scan
+                    .is_none_or(|last| last.elapsed() >= ATTACHMENT_SCAN_INTERVAL)
+                {
+                    last_attachment_scan = Some(Instant::now());
+                    // Querying the attachments of cgroups and interfaces needs CAP_NET_ADMIN
+                    if net_admin {
+                        cgroup_attachments = scan_cgroups();
+                        interface_attachments = scan_interfaces();
+                    }
+                }
 
-        thread::spawn(move || loop {
-            let loop_start = Instant::now();
+                // Built privately and swapped in once sorted, so the UI never waits on a
+                // whole collection cycle to draw
+                let mut new_items = vec![];
+
+                let ProgramSample {
+                    timestamp,
+                    programs,
+                    map_prog_ids,
+                } = sampler.sample();
+
+                let alerts = rules.evaluate(&programs);
+                loaders.lock().unwrap().observe(&programs);
+                idle.observe(&programs);
+                anomaly.observe(&programs);
+                *anomalies.lock().unwrap() = anomaly.anomalous().collect();
+                if show_split.load(Ordering::Relaxed) || show_heatmap.load(Ordering::Relaxed) {
+                    history.lock().unwrap().record(&programs, timestamp);
+                } else {
+                    *history.lock().unwrap() = ProgramHistory::default();
+                }
+                // Without stats every program looks idle, so nothing is hidden then
+                let hide_idle =
+                    hide_idle.load(Ordering::Relaxed) && runtime_stats.load(Ordering::Relaxed);
+                let mut hidden = 0;
+                let mut new_cgroups = cgroup_attachments.clone();
+                for cgroup in new_cgroups.iter_mut() {
+                    cgroup.update_stats(&programs);
+                }
+                let mut new_interfaces = interface_attachments.clone();
+                for iface in new_interfaces.iter_mut() {
+                    iface.update_stats(&programs);
+                }
+                let mut new_processes = group_by_process(&programs);
+                let mut memory_consumers = program_memory(&programs);
+                let selected_pid = selected_process
+                    .lock()
+                    .unwrap()
+                    .as_ref()
+                    .map(|(pid, _)| *pid);
+                // Keep a copy of all programs, including the ones not held by the selected
+                // process, to aggregate struct_ops stats and resolve pins once maps are known
+                let all_programs = programs.clone();
+
+                for bpf_program in programs {
+                    // Skip bpf program if it is not held by the selected process
+                    if selected_pid.is_some_and(|pid| {
+                        !bpf_program
+                            .processes
+                            .iter()
+                            .any(|process| process.pid == pid)
+                    }) {
+                        continue;
+                    }
 
-            let mut items = items.lock().unwrap();
-            let map: HashMap<u32, BpfProgram> =
-                items.drain(..).map(|prog| (prog.id, prog)).collect();
+                    if let Some(graphs_bpf_program) = graphs_bpf_program.lock().unwrap().as_ref() {
+                        if bpf_program.id == graphs_bpf_program.id {
+                            let mut data_buf = data_buf.lock().unwrap();
+                            data_buf.push_back(PeriodMeasure {
+                                timestamp,
+                                cpu_time_percent: bpf_program.cpu_time_percent(),
+                                events_per_sec: bpf_program.events_per_second(),
+                                average_runtime_ns: bpf_program.period_average_runtime_ns(),
+                            });
+                        }
+                    }
 
-            let filter = filter.lock().unwrap();
-            let filter_str = filter.value().to_lowercase();
-            drop(filter);
+                    // Skip bpf program if it has not run for a while and idle ones are hidden,
+                    // after sampling it in case it is shown in the graph view
+                    if hide_idle && idle.is_idle(bpf_program.id) {
+                        hidden += 1;
+                        continue;
+                    }
 
-            let pid_map = get_pid_map(&iter_link);
-            let iter = ProgInfoIter::default();
-            for prog in iter {
-                let instant = Instant::now();
+                    new_items.push(bpf_program);
+                }
+                hidden_idle.store(hidden, Ordering::Relaxed);
+
+                sort_programs(&mut new_items, *sort_col.lock().unwrap());
+
+                *items.lock().unwrap() = new_items;
+
+                let filter = filter.lock().unwrap().clone();
+
+                let pinned = pinned_objects();
+                let mut new_maps = get_maps(
+                    &map_prog_ids,
+                    &pinned_paths(&pinned, PinnedKind::Map),
+                    &get_map_owners(&map_iter),
+                    &fill_counter.counts(),
+                    &ops_worker.ops(),
+                    &mut ringbuf_tracker,
+                );
+
+                memory_consumers.extend(map_memory(&new_maps));
+                memory_consumers.extend(btf_memory());
+                *memory.lock().unwrap() = MemoryUsage::new(memory_consumers);
+
+                let mut new_struct_ops = get_struct_ops(&new_maps, &all_programs);
+
+                let mut new_pins = if pinned.is_empty() {
+                    vec![]
+                } else {
+                    let mut attached: HashSet<u32> = cgroup_attachments
+                        .iter()
+                        .flat_map(|cgroup| cgroup.attached.iter())
+                        .chain(
+                            interface_attachments
+                                .iter()
+                                .flat_map(|iface| iface.attached.iter()),
+                        )
+                        .map(|(_, prog_id)| *prog_id)
+                        .collect();
+                    attached.extend(
+                        new_struct_ops
+                            .iter()
+                            .flat_map(|ops| ops.callbacks.iter().map(|(_, prog_id)| *prog_id)),
+                    );
+                    resolve_pins(&pinned, &all_programs, &new_maps, &get_links(), &attached)
+                };
 
-                let prog_name = match prog.name.to_str() {
-                    Ok(name) => name.to_string(),
-                    Err(_) => continue,
+                let new_sample = Sample {
+                    timestamp,
+                    programs: all_programs.clone(),
+                    maps: new_maps.clone(),
+                    cgroups: new_cgroups.clone(),
+                    interfaces: new_interfaces.clone(),
+                    pins: new_pins.clone(),
+                    alerts,
                 };
+                exporters.export(&new_sample);
+                *sample.lock().unwrap() = new_sample;
 
-                if prog_name.is_empty() {
-                    continue;
+                // Skip bpf maps that do not match filter
+                if !filter.is_empty() {
+                    new_maps
+                        .retain(|map| filter.matches(&map.map_type) || filter.matches(&map.name));
                 }
 
-                // Skip bpf program if it does not match filter
-                let bpf_type = program_type_to_string(prog.ty);
-                if !filter_str.is_empty()
-                    && !bpf_type.to_lowercase().contains(&filter_str)
-                    && !prog_name.to_lowercase().contains(&filter_str)
-                {
-                    continue;
+                // Sort maps based on index of the column
+                let map_sort_col = *map_sort_col.lock().unwrap();
+                match map_sort_col {
+                    SortColumn::Ascending(col_idx) | SortColumn::Descending(col_idx) => {
+                        match col_idx {
+                            1 => new_maps.sort_unstable_by(|a, b| a.name.cmp(&b.name)),
+                            2 => new_maps.sort_unstable_by(|a, b| a.map_type.cmp(&b.map_type)),
+                            3 => new_maps.sort_unstable_by_key(|map| map.key_size),
+                            4 => new_maps.sort_unstable_by_key(|map| map.value_size),
+                            5 => new_maps.sort_unstable_by_key(|map| map.max_entries),
+                            6 => new_maps.sort_unstable_by(|a, b| {
+                                a.fill_percent()
+                                    .unwrap_or(-1.0)
+                                    .total_cmp(&b.fill_percent().unwrap_or(-1.0))
+                            }),
+                            7 => new_maps.sort_unstable_by_key(|map| map.changed_keys_per_second()),
+                            8 => new_maps.sort_unstable_by_key(|map| map.memlock),
+                            9 => new_maps
+                                .sort_unstable_by(|a, b| a.pinned_paths.cmp(&b.pinned_paths)),
+                            10 => new_maps.sort_unstable_by_key(|map| map.prog_ids.len()),
+                            11 => new_maps.sort_unstable_by_key(|map| map.owners.len()),
+                            _ => new_maps.sort_unstable_by_key(|map| map.id),
+                        }
+                        if let SortColumn::Descending(_) = map_sort_col {
+                            new_maps.reverse();
+                        }
+                    }
+                    SortColumn::NoOrder => {}
                 }
+                *maps.lock().unwrap() = new_maps;
 
-                let processes = pid_map.get(&prog.id).cloned().unwrap_or_default();
-
-                let mut bpf_program = BpfProgram {
-                    id: prog.id,
-                    bpf_type,
-                    name: prog_name,
-                    prev_runtime_ns: 0,
-                    run_time_ns: prog.run_time_ns,
-                    prev_run_cnt: 0,
-                    run_cnt: prog.run_cnt,
-                    instant,
-                    period_ns: 0,
-                    processes,
-                };
+                // Skip struct_ops that do not match filter
+                if !filter.is_empty() {
+                    new_struct_ops.retain(|ops| {
+                        filter.matches(&ops.struct_name) || filter.matches(&ops.name)
+                    });
+                }
 
-                if let Some(prev_bpf_program) = map.get(&bpf_program.id) {
-                    bpf_program.prev_runtime_ns = prev_bpf_program.run_time_ns;
-                    bpf_program.prev_run_cnt = prev_bpf_program.run_cnt;
-                    bpf_program.period_ns = prev_bpf_program.instant.elapsed().as_nanos();
+                // Sort struct_ops based on index of the column
+                let struct_ops_sort_col = *struct_ops_sort_col.lock().unwrap();
+                match struct_ops_sort_col {
+                    SortColumn::Ascending(col_idx) | SortColumn::Descending(col_idx) => {
+                        match col_idx {
+                            1 => new_struct_ops.sort_unstable_by(|a, b| a.name.cmp(&b.name)),
+                            2 => new_struct_ops
+                                .sort_unstable_by(|a, b| a.struct_name.cmp(&b.struct_name)),
+                            3 => new_struct_ops.sort_unstable_by(|a, b| a.state.cmp(&b.state)),
+                            4 => new_struct_ops.sort_unstable_by_key(|ops| ops.callbacks.len()),
+                            5 => new_struct_ops.sort_unstable_by_key(|ops| ops.events_per_second),
+                            6 => new_struct_ops.sort_unstable_by(|a, b| {
+                                a.cpu_time_percent.total_cmp(&b.cpu_time_percent)
+                            }),
+                            _ => new_struct_ops.sort_unstable_by_key(|ops| ops.map_id),
+                        }
+                        if let SortColumn::Descending(_) = struct_ops_sort_col {
+                            new_struct_ops.reverse();
+                        }
+                    }
+                    SortColumn::NoOrder => {}
                 }
+                *struct_ops.lock().unwrap() = new_struct_ops;
 
-                if let Some(graphs_bpf_program) = graphs_bpf_program.lock().unwrap().as_ref() {
-                    if bpf_program.id == graphs_bpf_program.id {
-                        let mut data_buf = data_buf.lock().unwrap();
-                        data_buf.push_back(PeriodMeasure {
-                            cpu_time_percent: bpf_program.cpu_time_percent(),
-                            events_per_sec: bpf_program.events_per_second(),
-                            average_runtime_ns: bpf_program.period_average_runtime_ns(),
-                        });
+                // Skip cgroups that do not match filter
+                if !filter.is_empty() {
+                    new_cgroups.retain(|cgroup| filter.matches(&cgroup.path));
+                }
+
+                // Sort cgroups based on index of the column
+                let cgroups_sort_col = *cgroups_sort_col.lock().unwrap();
+                match cgroups_sort_col {
+                    SortColumn::Ascending(col_idx) | SortColumn::Descending(col_idx) => {
+                        match col_idx {
+                            1 => new_cgroups.sort_unstable_by(|a, b| a.path.cmp(&b.path)),
+                            2 => new_cgroups.sort_unstable_by_key(|cgroup| cgroup.attached.len()),
+                            3 => {
+                                new_cgroups.sort_unstable_by_key(|cgroup| cgroup.events_per_second)
+                            }
+                            4 => new_cgroups.sort_unstable_by(|a, b| {
+                                a.cpu_time_percent.total_cmp(&b.cpu_time_percent)
+                            }),
+                            _ => new_cgroups.sort_unstable_by_key(|cgroup| cgroup.id),
+                        }
+                        if let SortColumn::Descending(_) = cgroups_sort_col {
+                            new_cgroups.reverse();
+                        }
                     }
+                    SortColumn::NoOrder => {}
                 }
+                *cgroups.lock().unwrap() = new_cgroups;
 
-                items.push(bpf_program);
-            }
+                // Skip pins that do not match filter
+                if !filter.is_empty() {
+                    new_pins.retain(|pin| filter.matches(&pin.path) || filter.matches(&pin.name));
+                }
+
+                // Sort pins based on index of the column
+                let pins_sort_col = *pins_sort_col.lock().unwrap();
+                match pins_sort_col {
+                    SortColumn::Ascending(col_idx) | SortColumn::Descending(col_idx) => {
+                        match col_idx {
+                            1 => new_pins.sort_unstable_by_key(|pin| pin.id),
+                            2 => new_pins.sort_unstable_by(|a, b| a.path.cmp(&b.path)),
+                            3 => new_pins.sort_unstable_by(|a, b| a.name.cmp(&b.name)),
+                            4 => {
+                                new_pins.sort_unstable_by(|a, b| a.object_type.cmp(&b.object_type))
+                            }
+                            5 => new_pins.sort_unstable_by_key(|pin| pin.users.len()),
+                            _ => new_pins.sort_unstable_by_key(|pin| pin.kind.name()),
+                        }
+                        if let SortColumn::Descending(_) = pins_sort_col {
+                            new_pins.reverse();
+                        }
+                    }
+                    SortColumn::NoOrder => {}
+                }
+                *pins.lock().unwrap() = new_pins;
+
+                // Skip interfaces that do not match filter
+                if !filter.is_empty() {
+                    new_interfaces.retain(|iface| filter.matches(&iface.name));
+                }
 
-            // Sort items based on index of the column
-            let sort_col = sort_col.lock().unwrap();
-            match *sort_col {
-                SortColumn::Ascending(col_idx) | SortColumn::Descending(col_idx) => {
-                    match col_idx {
-                        1 => items.sort_unstable_by(|a, b| a.bpf_type.cmp(&b.bpf_type)),
-                        2 => items.sort_unstable_by(|a, b| a.name.cmp(&b.name)),
-                        3 => items.sort_unstable_by(|a, b| {
-                            a.period_average_runtime_ns()
-                                .cmp(&b.period_average_runtime_ns())
-                        }),
-                        4 => items.sort_unstable_by(|a, b| {
-                            a.total_average_runtime_ns()
-                                .cmp(&b.total_average_runtime_ns())
-                        }),
-                        5 => items.sort_unstable_by(|a, b| {
-                            a.events_per_second().cmp(&b.events_per_second())
-                        }),
-                        6 => items.sort_unstable_by(|a, b| {
-                            a.cpu_time_percent()
-                                .partial_cmp(&b.cpu_time_percent())
-                                .unwrap()
-                        }),
-                        _ => items.sort_unstable_by_key(|item| item.id),
+                // Sort interfaces based on index of the column
+                let interfaces_sort_col = *interfaces_sort_col.lock().unwrap();
+                match interfaces_sort_col {
+                    SortColumn::Ascending(col_idx) | SortColumn::Descending(col_idx) => {
+                        match col_idx {
+                            1 => new_interfaces.sort_unstable_by(|a, b| a.name.cmp(&b.name)),
+                            2 => new_interfaces.sort_unstable_by_key(|iface| iface.attached.len()),
+                            3 => {
+                                new_interfaces.sort_unstable_by_key(|iface| iface.events_per_second)
+                            }
+                            4 => new_interfaces.sort_unstable_by(|a, b| {
+                                a.cpu_time_percent.total_cmp(&b.cpu_time_percent)
+                            }),
+                            _ => new_interfaces.sort_unstable_by_key(|iface| iface.ifindex),
+                        }
+                        if let SortColumn::Descending(_) = interfaces_sort_col {
+                            new_interfaces.reverse();
+                        }
                     }
-                    if let SortColumn::Descending(_) = *sort_col {
-                        items.reverse();
+                    SortColumn::NoOrder => {}
+                }
+                *interfaces.lock().unwrap() = new_interfaces;
+
+                // Skip processes that do not match filter
+                if !filter.is_empty() {
+                    new_processes.retain(|process| {
+                        filter.matches(&process.comm)
+                            || filter.matches(&process.pid.to_string())
+                            || filter.matches(process.container_name())
+                    });
+                }
+
+                // Sort processes based on index of the column
+                let processes_sort_col = *processes_sort_col.lock().unwrap();
+                match processes_sort_col {
+                    SortColumn::Ascending(col_idx) | SortColumn::Descending(col_idx) => {
+                        match col_idx {
+                            1 => new_processes.sort_unstable_by(|a, b| a.comm.cmp(&b.comm)),
+                            2 => new_processes.sort_unstable_by(|a, b| {
+                                a.container_name().cmp(b.container_name())
+                            }),
+                            3 => {
+                                new_processes.sort_unstable_by_key(|process| process.prog_ids.len())
+                            }
+                            4 => new_processes
+                                .sort_unstable_by_key(|process| process.events_per_second),
+                            5 => new_processes.sort_unstable_by(|a, b| {
+                                a.cpu_time_percent.total_cmp(&b.cpu_time_percent)
+                            }),
+                            _ => new_processes.sort_unstable_by_key(|process| process.pid),
+                        }
+                        if let SortColumn::Descending(_) = processes_sort_col {
+                            new_processes.reverse();
+                        }
                     }
+                    SortColumn::NoOrder => {}
                 }
-                SortColumn::NoOrder => {}
+                *processes.lock().unwrap() = new_processes;
+                generation.fetch_add(1, Ordering::Release);
+
+                // Stop collecting once the draw loop has exited
+                if ui_events.send(UiEvent::Sample).is_err() {
+                    return;
+                }
+
+                // Adjust sleep duration to maintain the sample period, accounting for loop processing time.
+                let elapsed = loop_start.elapsed();
+                let sleep = if elapsed > SAMPLE_INTERVAL {
+                    SAMPLE_INTERVAL
+                } else {
+                    SAMPLE_INTERVAL - elapsed
+                };
+                // Woken early when shutdown drops the sender
+                if stopped.recv_timeout(sleep) != Err(RecvTimeoutError::Timeout) {
+                    return;
+                }
+            }
+        });
+        Collector { handle, stop }
+    }
+
+    pub fn show_graphs(&mut self) {
+        self.data_buf.lock().unwrap().clear();
+        self.max_cpu = 0.0;
+        self.max_eps = 0;
+        self.max_runtime = 0;
+        self.mode = Mode::Graph;
+        let selected_program = self.selected_program();
+        match &selected_program {
+            Some(prog) => {
+                self.resolve_attachments(prog.id);
+                self.resolve_flags(prog.id);
+            }
+            None => {
+                *self.graphs_attachments.lock().unwrap() = None;
+                *self.graphs_flags.lock().unwrap() = None;
+            }
+        }
+        self.selected_attachment = 0;
+        self.selected_holder = 0;
+        self.action_status = None;
+        self.graphs_bpf_program
+            .lock()
+            .unwrap()
+            .clone_from(&selected_program);
+    }
+
+    pub fn show_table(&mut self) {
+        self.mode = Mode::Table;
+        self.data_buf.lock().unwrap().clear();
+        self.max_cpu = 0.0;
+        self.max_eps = 0;
+        self.max_runtime = 0;
+        *self.graphs_bpf_program.lock().unwrap() = None;
+        *self.graphs_attachments.lock().unwrap() = None;
+        *self.graphs_flags.lock().unwrap() = None;
+        self.action_status = None;
+        *self.profile.lock().unwrap() = None;
+        self.stop_histogram();
+        self.stop_cpu_runtime();
+    }
+
+    fn resolve_attachments(&self, prog_id: u32) {
+        *self.graphs_attachments.lock().unwrap() = Some(AttachmentsState::Resolving(prog_id));
+        let state = Arc::clone(&self.graphs_attachments);
+        thread::spawn(move || {
+            let attachments = get_detachable_attachments(prog_id);
+            let mut state = state.lock().unwrap();
+            // Drop the result if the graph view was closed or moved on in the meantime
+            if *state == Some(AttachmentsState::Resolving(prog_id)) {
+                *state = Some(AttachmentsState::Resolved(prog_id, attachments));
+            }
+        });
+    }
+
+    fn resolve_flags(&self, prog_id: u32) {
+        *self.graphs_flags.lock().unwrap() = Some(FlagsState::Resolving(prog_id));
+        let state = Arc::clone(&self.graphs_flags);
+        let struct_ops = self.struct_ops.lock().unwrap().clone();
+        let cgroups = self.cgroups.lock().unwrap().clone();
+        thread::spawn(move || {
+            let flags = match ProgFlags::read(prog_id, &struct_ops, &cgroups) {
+                Ok(flags) => FlagsState::Resolved(prog_id, flags),
+                Err(e) => FlagsState::Failed(prog_id, e.to_string()),
+            };
+            let mut state = state.lock().unwrap();
+            if *state == Some(FlagsState::Resolving(prog_id)) {
+                *state = Some(flags);
+            }
+        });
+    }
+
+    // The attachments of the program in the graph view, empty until they are resolved
+    pub fn attachments(&self) -> Vec<Attachment> {
+        match &*self.graphs_attachments.lock().unwrap() {
+            Some(AttachmentsState::Resolved(_, attachments)) => attachments.clone(),
+            _ => vec![],
+        }
+    }
+
+    pub fn next_attachment(&mut self) {
+        let len = self.attachments().len();
+        if len > 0 {
+            self.selected_attachment = (self.selected_attachment + 1) % len;
+        }
+    }
+
+    pub fn previous_attachment(&mut self) {
+        let len = self.attachments().len();
+        if len > 0 {
+            self.selected_attachment = self.selected_attachment.checked_sub(1).unwrap_or(len - 1);
+        }
+    }
+
+    pub fn attachment_to_detach(&self) -> Option<Attachment> {
+        self.attachments().get(self.selected_attachment).cloned()
+    }
+
+    // Asks for the confirmation to detach the selected attachment
+    pub fn start_detach(&mut self) {
+        if self.attachment_to_detach().is_some() {
+            self.prompt_input.reset();
+            self.mode = Mode::Detach;
+        }
+    }
+
+    fn holders(&self) -> Vec<Process> {
+        self.graphs_bpf_program
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|prog| prog.processes.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn next_holder(&mut self) {
+        let len = self.holders().len();
+        if len > 0 {
+            self.selected_holder = (self.selected_holder + 1) % len;
+        }
+    }
+
+    pub fn previous_holder(&mut self) {
+        let len = self.holders().len();
+        if len > 0 {
+            self.selected_holder = self.selected_holder.checked_sub(1).unwrap_or(len - 1);
+        }
+    }
+
+    pub fn holder_to_signal(&self) -> Option<Process> {
+        self.holders().get(self.selected_holder).cloned()
+    }
+
+    // Shows the details of the selected process, or goes back to the program list if the
+    // program is not held by any
+    pub fn show_process_info(&mut self) {
+        let Some(holder) = self.holder_to_signal() else {
+            self.show_table();
+            return;
+        };
+        match ProcessInfo::read(&holder) {
+            Ok(info) => {
+                self.process_info = Some(info);
+                self.mode = Mode::ProcessInfo;
+            }
+            Err(e) => self.action_status = Some(format!("{:#}", e)),
+        }
+    }
+
+    pub fn hide_process_info(&mut self) {
+        self.process_info = None;
+        self.mode = Mode::Graph;
+    }
+
+    pub fn signal(&self) -> Signal {
+        if self.signal_kill {
+            Signal::SIGKILL
+        } else {
+            Signal::SIGTERM
+        }
+    }
+
+    // Asks for the confirmation to signal the selected process, SIGTERM unless changed
+    pub fn start_signal(&mut self) {
+        if self.holder_to_signal().is_some() {
+            self.prompt_input.reset();
+            self.signal_kill = false;
+            self.mode = Mode::Signal;
+        }
+    }
+
+    pub fn toggle_signal_kill(&mut self) {
+        self.signal_kill = !self.signal_kill;
+    }
+
+    // Signals the selected process if its PID was typed to confirm, and logs it
+    pub fn confirm_signal(&mut self) {
+        self.mode = Mode::Graph;
+        let Some(holder) = self.holder_to_signal() else {
+            return;
+        };
+        if self.prompt_input.value().trim() != holder.pid.to_string() {
+            self.action_status = Some("Confirmation did not match, nothing sent".to_string());
+            return;
+        }
+        let signal = self.signal();
+        let comm = &holder.comm;
+        self.action_status = Some(match signal_process(&holder, signal) {
+            Ok(()) => {
+                let program = self
+                    .graphs_bpf_program
+                    .lock()
+                    .unwrap()
+                    .as_ref()
+                    .map(|prog| format!("{} ({})", prog.name, prog.id))
+                    .unwrap_or_default();
+                info!(
+                    "Sent {} to {} ({}) holding program {} on operator request",
+                    signal, comm, holder.pid, program
+                );
+                format!("Sent {} to {} ({})", signal, comm, holder.pid)
             }
+            Err(e) => {
+                error!("{:#}", e);
+                format!("{:#}", e)
+            }
+        });
+    }
+
+    // Leaves the detach, pin or unpin prompt without doing anything
+    pub fn cancel_prompt(&mut self) {
+        self.mode = match self.mode {
+            Mode::Unpin => Mode::Table,
+            _ => Mode::Graph,
+        };
+    }
+
+    // Detaches the selected attachment if the typed confirmation matches, and logs it
+    pub fn confirm_detach(&mut self) {
+        self.mode = Mode::Graph;
+        let Some(attachment) = self.attachment_to_detach() else {
+            return;
+        };
+        if self.prompt_input.value().trim() != attachment.confirmation() {
+            self.action_status = Some("Confirmation did not match, nothing detached".to_string());
+            return;
+        }
+        let program = self
+            .graphs_bpf_program
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|prog| format!("{} ({})", prog.name, prog.id))
+            .unwrap_or_default();
+        self.action_status = Some(match attachment.detach() {
+            Ok(()) => {
+                info!(
+                    "Detached {} of program {} on operator request",
+                    attachment.describe(),
+                    program
+                );
+                format!("Detached {}", attachment.describe())
+            }
+            Err(e) => {
+                error!("{:#}", e);
+                format!("{:#}", e)
+            }
+        });
+        let prog_id = self
+            .graphs_bpf_program
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|prog| prog.id);
+        if let Some(prog_id) = prog_id {
+            self.resolve_attachments(prog_id);
+        }
+        self.selected_attachment = 0;
+    }
+
+    // Asks for the path to pin the program in the graph view to, below the first bpffs mount
+    pub fn start_pin(&mut self) {
+        let Some(prog) = self.graphs_bpf_program.lock().unwrap().clone() else {
+            return;
+        };
+        let mount = bpffs_mounts()
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_BPFFS));
+        let name = if prog.name.is_empty() {
+            format!("prog_{}", prog.id)
+        } else {
+            prog.name.clone()
+        };
+        self.prompt_input = Input::new(mount.join(name).display().to_string());
+        self.mode = Mode::Pin;
+    }
+
+    pub fn toggle_pin_maps(&mut self) {
+        self.pin_maps = !self.pin_maps;
+    }
+
+    pub fn confirm_pin(&mut self) {
+        self.mode = Mode::Graph;
+        let Some(prog) = self.graphs_bpf_program.lock().unwrap().clone() else {
+            return;
+        };
+        let path = PathBuf::from(self.prompt_input.value().trim());
+        self.action_status = Some(match pin_program(prog.id, &path, self.pin_maps) {
+            Ok(pinned) => {
+                info!(
+                    "Pinned program {} ({}) to {} on operator request",
+                    prog.name,
+                    prog.id,
+                    path.display()
+                );
+                match pinned.len() - 1 {
+                    0 => format!("Pinned to {}", path.display()),
+                    maps => format!("Pinned to {} with {} maps", path.display(), maps),
+                }
+            }
+            Err(e) => {
+                error!("{:#}", e);
+                format!("{:#}", e)
+            }
+        });
+    }
+
+    pub fn pin_to_unpin(&self) -> Option<Pin> {
+        let pins = self.pins.lock().unwrap();
+        self.pins_table_state
+            .selected()
+            .and_then(|i| pins.get(i).cloned())
+    }
+
+    // Asks for the confirmation to remove the selected pin
+    pub fn start_unpin(&mut self) {
+        if self.pin_to_unpin().is_some() {
+            self.prompt_input.reset();
+            self.mode = Mode::Unpin;
+        }
+    }
+
+    // Removes the selected pin if the typed confirmation matches, and logs it
+    pub fn confirm_unpin(&mut self) {
+        self.mode = Mode::Table;
+        let Some(pin) = self.pin_to_unpin() else {
+            return;
+        };
+        if self.prompt_input.value().trim() != pin.confirmation() {
+            self.action_status = Some("Confirmation did not match, nothing unpinned".to_string());
+            return;
+        }
+        self.action_status = Some(match unpin(Path::new(&pin.path)) {
+            Ok(()) => {
+                info!(
+                    "Unpinned {} {} from {} on operator request",
+                    pin.kind.name(),
+                    pin.id,
+                    pin.path
+                );
+                format!("Unpinned {}", pin.path)
+            }
+            Err(e) => {
+                error!("{:#}", e);
+                format!("{:#}", e)
+            }
+        });
+    }
+
+    // Starts collecting a runtime histogram for the program shown in the graph view,
+    // or stops it when one is already shown
+    pub fn toggle_histogram(&mut self) {
+        if self.histogram_running.is_some() {
+            self.stop_histogram();
+            return;
+        }
+        let Some(prog_id) = self
+            .graphs_bpf_program
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|prog| prog.id)
+        else {
+            return;
+        };
+        if !self.capabilities.perfmon {
+            *self.histogram.lock().unwrap() =
+                Some(HistogramState::Failed(prog_id, PERFMON_MISSING.to_string()));
+            return;
+        }
+        *self.histogram.lock().unwrap() =
+            Some(HistogramState::Collecting(prog_id, Histogram::default()));
+
+        let running = Arc::new(AtomicBool::new(true));
+        self.histogram_running = Some(Arc::clone(&running));
+        let histogram = Arc::clone(&self.histogram);
+        thread::spawn(move || collect_histogram(prog_id, running, histogram));
+    }
+
+    fn stop_histogram(&mut self) {
+        // Clear the flag before the state so the collector cannot write it back
+        if let Some(running) = self.histogram_running.take() {
+            running.store(false, Ordering::Relaxed);
+        }
+        *self.histogram.lock().unwrap() = None;
+    }
+
+    // Starts attributing the runtime of the program shown in the graph view to the CPUs
+    // it ran on, or stops it when the breakdown is already shown
+    pub fn toggle_cpu_runtime(&mut self) {
+        if self.cpu_runtime_running.is_some() {
+            self.stop_cpu_runtime();
+            return;
+        }
+        let Some(prog_id) = self
+            .graphs_bpf_program
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|prog| prog.id)
+        else {
+            return;
+        };
+        if !self.capabilities.perfmon {
+            *self.cpu_runtime.lock().unwrap() = Some(CpuRuntimeState::Failed(
+                prog_id,
+                PERFMON_MISSING.to_string(),
+            ));
+            return;
+        }
+        *self.cpu_runtime.lock().unwrap() = Some(CpuRuntimeState::Collecting(
+            prog_id,
+            CpuBreakdown::default(),
+        ));
+
+        let running = Arc::new(AtomicBool::new(true));
+        self.cpu_runtime_running = Some(Arc::clone(&running));
+        let cpu_runtime = Arc::clone(&self.cpu_runtime);
+        thread::spawn(move || collect_cpu_runtime(prog_id, running, cpu_runtime));
+    }
+
+    fn stop_cpu_runtime(&mut self) {
+        if let Some(running) = self.cpu_runtime_running.take() {
+            running.store(false, Ordering::Relaxed);
+        }
+        *self.cpu_runtime.lock().unwrap() = None;
+    }
+
+    // Profiles the program shown in the graph view on a worker thread, since attaching
+    // the profiler blocks for the whole sampling window
+    pub fn start_profile(&mut self) {
+        let Some(prog_id) = self
+            .graphs_bpf_program
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|prog| prog.id)
+        else {
+            return;
+        };
+        let mut profile = self.profile.lock().unwrap();
+        if let Some(ProfileState::Running(_)) = *profile {
+            return;
+        }
+        if !self.capabilities.perfmon {
+            *profile = Some(ProfileState::Failed(prog_id, PERFMON_MISSING.to_string()));
+            return;
+        }
+        *profile = Some(ProfileState::Running(prog_id));
+        drop(profile);
+
+        let profile = Arc::clone(&self.profile);
+        thread::spawn(move || {
+            let result = profile_program(prog_id, PROFILE_DURATION);
+            let mut profile = profile.lock().unwrap();
+            // Drop the result if the graph view was closed in the meantime
+            if *profile != Some(ProfileState::Running(prog_id)) {
+                return;
+            }
+            *profile = Some(match result {
+                Ok(report) => ProfileState::Done(prog_id, report),
+                Err(e) => ProfileState::Failed(prog_id, e.to_string()),
+            });
+        });
+    }
+
+    pub fn runtime_stats(&self) -> bool {
+        self.runtime_stats.load(Ordering::Relaxed)
+    }
+
+    // Indices of the program columns to show, leaving out the ones computed from BPF stats
+    // while they are disabled
+    pub fn program_columns(&self) -> Vec<usize> {
+        let runtime_stats = self.runtime_stats();
+        // The baseline columns come last and compare stats too
+        let baseline_start = self.header_columns.len() + self.custom_columns.len();
+        let baseline_columns = match self.baseline {
+            Some(_) => BASELINE_COLUMNS.len(),
+            None => 0,
+        };
+        (0..baseline_start + baseline_columns)
+            .filter(|i| runtime_stats || !(STATS_COLUMNS.contains(i) || *i >= baseline_start))
+            .collect()
+    }
+
+    // The programs listed in the table. Every program is collected, so that graphs and
+    // totals stay right, and the filter only decides which of them are shown.
+    pub fn shown_programs<'a>(&self, items: &'a [BpfProgram]) -> Vec<&'a BpfProgram> {
+        let filter = self.filter.lock().unwrap();
+        items
+            .iter()
+            .filter(|prog| matches_filter(prog, &filter))
+            .filter(|prog| {
+                self.type_filter
+                    .as_ref()
+                    .is_none_or(|family| prog_types::family(&prog.bpf_type) == *family)
+            })
+            .filter(|prog| !self.starred_only || self.stars.contains(prog))
+            .collect()
+    }
+
+    // Moves the type filter along the breakdown, passing through no filter after the last
+    // family
+    pub fn next_type_filter(&mut self) {
+        self.cycle_type_filter(1);
+    }
+
+    pub fn previous_type_filter(&mut self) {
+        self.cycle_type_filter(-1);
+    }
+
+    pub fn toggle_star(&mut self) {
+        if let Some(prog) = self.selected_program() {
+            self.stars.toggle(&prog);
+        }
+    }
+
+    pub fn toggle_starred_only(&mut self) {
+        self.starred_only = !self.starred_only;
+        if self.starred_only && self.stars.is_empty() {
+            self.action_status = Some("No programs starred yet, press * on one".to_string());
+        }
+    }
+
+    // Filters on the family bound to a number key, or clears the filter for 0
+    pub fn quick_type_filter(&mut self, key: usize) {
+        self.type_filter = key
+            .checked_sub(1)
+            .and_then(|i| prog_types::QUICK_FILTERS.get(i))
+            .map(|family| family.to_string());
+    }
+
+    fn cycle_type_filter(&mut self, step: isize) {
+        let mut choices = vec![None];
+        choices.extend(
+            prog_types::counts(self.items.lock().unwrap().iter())
+                .into_iter()
+                .map(|(family, _)| Some(family)),
+        );
+        // A family whose programs have all gone is left as if from no filter
+        let position = choices
+            .iter()
+            .position(|choice| *choice == self.type_filter)
+            .unwrap_or(0);
+        let next = (position as isize + step).rem_euclid(choices.len() as isize);
+        self.type_filter = choices.swap_remove(next as usize);
+    }
+
+    pub fn selected_program(&self) -> Option<BpfProgram> {
+        let items = self.items.lock().unwrap();
+
+        self.table_state
+            .selected()
+            .and_then(|i| self.shown_programs(&items).get(i).copied().cloned())
+    }
+
+    pub fn next_program(&mut self) {
+        self.follow = false;
+        let len = self.shown_programs(&self.items.lock().unwrap()).len();
+        select_next(&mut self.table_state, len);
+    }
+
+    pub fn previous_program(&mut self) {
+        self.follow = false;
+        let len = self.shown_programs(&self.items.lock().unwrap()).len();
+        select_previous(&mut self.table_state, len);
+    }
+
+    pub fn toggle_follow(&mut self) {
+        self.follow = !self.follow;
+        self.follow_top_program();
+    }
+
+    // Moves the selection onto the program with the highest value of the sorted column, which
+    // the collector has already put first, or last in ascending order. Moving the selection
+    // by hand stops following.
+    pub fn follow_top_program(&mut self) {
+        if !self.follow {
+            return;
+        }
+        let index = {
+            let items = self.items.lock().unwrap();
+            let shown = self.shown_programs(&items);
+            if shown.is_empty() {
+                return;
+            }
+            match *self.sorted_column.lock().unwrap() {
+                SortColumn::Descending(_) => 0,
+                SortColumn::Ascending(_) => shown.len() - 1,
+                SortColumn::NoOrder => shown
+                    .iter()
+                    .enumerate()
+                    .max_by(|(_, a), (_, b)| {
+                        cmp_percent(a.cpu_time_percent(), b.cpu_time_percent())
+                    })
+                    .map_or(0, |(i, _)| i),
+            }
+        };
+        self.table_state.select(Some(index));
+    }
+
+    pub fn selected_map(&self) -> Option<BpfMap> {
+        let maps = self.maps.lock().unwrap();
+
+        self.map_table_state
+            .selected()
+            .and_then(|i| maps.get(i).cloned())
+    }
+
+    pub fn show_map_inspector(&mut self) {
+        if let Some(map) = self.selected_map() {
+            self.map_inspector = Some(MapInspector::new(map));
+            self.mode = Mode::MapInspector;
+        }
+    }
+
+    pub fn hide_map_inspector(&mut self) {
+        self.map_inspector = None;
+        self.mode = Mode::Table;
+    }
+
+    pub fn toggle_map_search(&mut self) {
+        self.mode = match &self.mode {
+            Mode::MapInspector => Mode::MapSearch,
+            _ => Mode::MapInspector,
+        }
+    }
+
+    pub fn next_map(&mut self) {
+        let len = self.maps.lock().unwrap().len();
+        select_next(&mut self.map_table_state, len);
+    }
+
+    pub fn previous_map(&mut self) {
+        let len = self.maps.lock().unwrap().len();
+        select_previous(&mut self.map_table_state, len);
+    }
+
+    pub fn next_struct_ops(&mut self) {
+        let len = self.struct_ops.lock().unwrap().len();
+        select_next(&mut self.struct_ops_table_state, len);
+    }
+
+    pub fn previous_struct_ops(&mut self) {
+        let len = self.struct_ops.lock().unwrap().len();
+        select_previous(&mut self.struct_ops_table_state, len);
+    }
+
+    pub fn next_cgroup(&mut self) {
+        let len = self.cgroups.lock().unwrap().len();
+        select_next(&mut self.cgroups_table_state, len);
+    }
+
+    pub fn previous_cgroup(&mut self) {
+        let len = self.cgroups.lock().unwrap().len();
+        select_previous(&mut self.cgroups_table_state, len);
+    }
+
+    pub fn next_pin(&mut self) {
+        let len = self.pins.lock().unwrap().len();
+        select_next(&mut self.pins_table_state, len);
+    }
+
+    pub fn previous_pin(&mut self) {
+        let len = self.pins.lock().unwrap().len();
+        select_previous(&mut self.pins_table_state, len);
+    }
+
+    pub fn next_interface(&mut self) {
+        let len = self.interfaces.lock().unwrap().len();
+        select_next(&mut self.interfaces_table_state, len);
+    }
+
+    pub fn previous_interface(&mut self) {
+        let len = self.interfaces.lock().unwrap().len();
+        select_previous(&mut self.interfaces_table_state, len);
+    }
+
+    pub fn next_process(&mut self) {
+        let len = self.processes.lock().unwrap().len();
+        select_next(&mut self.processes_table_state, len);
+    }
+
+    pub fn previous_process(&mut self) {
+        let len = self.processes.lock().unwrap().len();
+        select_previous(&mut self.processes_table_state, len);
+    }
+
+    // Narrows the program list down to the programs held by the selected process
+    pub fn show_process_programs(&mut self) {
+        let processes = self.processes.lock().unwrap();
+        let Some(process) = self
+            .processes_table_state
+            .selected()
+            .and_then(|i| processes.get(i))
+        else {
+            return;
+        };
+        *self.selected_process.lock().unwrap() = Some((process.pid, process.comm.clone()));
+        drop(processes);
+        self.table_state.select(None);
+        self.view = View::Programs;
+    }
+
+    pub fn toggle_hide_idle(&mut self) {
+        self.hide_idle.fetch_xor(true, Ordering::Relaxed);
+    }
+
+    // Number of programs left out of the program list for being idle
+    pub fn hidden_idle(&self) -> usize {
+        self.hidden_idle.load(Ordering::Relaxed)
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    pub fn toggle_transients(&mut self) {
+        self.show_transients = !self.show_transients;
+        self.update_fast_scan();
+    }
+
+    // Starts or stops the fast scan, which without the audit programs is the only way to
+    // see transient programs
+    pub fn update_fast_scan(&mut self) {
+        let wanted = self.show_transients || !self.audited;
+        if wanted && self.fast_scan.is_none() {
+            self.fast_scan = Some(FastScan::start(Arc::clone(&self.transients)));
+        } else if !wanted {
+            self.fast_scan = None;
+        }
+    }
+
+    pub fn toggle_split(&mut self) {
+        self.show_split.fetch_xor(true, Ordering::Relaxed);
+    }
+
+    pub fn split(&self) -> bool {
+        self.show_split.load(Ordering::Relaxed)
+    }
+
+    fn table_state_mut(&mut self) -> &mut TableState {
+        match self.view {
+            View::Programs => &mut self.table_state,
+            View::Maps => &mut self.map_table_state,
+            View::StructOps => &mut self.struct_ops_table_state,
+            View::Cgroups => &mut self.cgroups_table_state,
+            View::Processes => &mut self.processes_table_state,
+            View::Interfaces => &mut self.interfaces_table_state,
+            View::Pins => &mut self.pins_table_state,
+        }
+    }
+
+    // The view, selection and scroll position to resume from on the next start
+    pub fn session(&mut self) -> Session {
+        // Not applied yet, so still the one to resume from
+        if let Some(session) = &self.pending_session {
+            return Session {
+                stars: self.stars.clone(),
+                ..session.clone()
+            };
+        }
+        let graphs_program = self
+            .graphs_bpf_program
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|prog| prog.id);
+        let program_id = graphs_program.or_else(|| {
+            (self.view == View::Programs)
+                .then(|| self.selected_program().map(|prog| prog.id))
+                .flatten()
+        });
+        let view = self.view;
+        let table_state = self.table_state_mut();
+        Session {
+            view,
+            program_id,
+            selected: table_state.selected(),
+            offset: table_state.offset(),
+            graphs: graphs_program.is_some(),
+            stars: self.stars.clone(),
+        }
+    }
+
+    pub fn restore_session(&mut self, session: Session) {
+        self.view = session.view;
+        self.stars = session.stars.clone();
+        let table_state = self.table_state_mut();
+        table_state.select(session.selected);
+        *table_state.offset_mut() = session.offset;
+        if session.program_id.is_some() {
+            self.pending_session = Some(session);
+        }
+    }
+
+    // Selects the program of the restored session, and opens its graphs, once it shows up.
+    // Programs that were unloaded in between are forgotten after the first sample.
+    pub fn apply_pending_session(&mut self) {
+        if self.pending_session.is_none() || self.items.lock().unwrap().is_empty() {
+            return;
+        }
+        let Some(session) = self.pending_session.take() else {
+            return;
+        };
+        let index = self
+            .shown_programs(&self.items.lock().unwrap())
+            .iter()
+            .position(|prog| Some(prog.id) == session.program_id);
+        let Some(index) = index else {
+            return;
+        };
+        self.view = View::Programs;
+        self.table_state.select(Some(index));
+        if session.graphs {
+            self.show_graphs();
+        }
+    }
+
+    // The watched program is opened instead of the program of the restored session, whose
+    // stars are kept
+    pub fn watch(&mut self, target: WatchTarget) {
+        self.pending_session = None;
+        self.action_status = Some(format!(
+            "Waiting for program {} to be loaded",
+            target.describe()
+        ));
+        self.watch = Some(target);
+    }
+
+    // Opens the graph view of the watched program as soon as it is listed, however long that
+    // takes
+    pub fn apply_watch(&mut self) {
+        let Some(target) = &self.watch else {
+            return;
+        };
+        let index = self
+            .shown_programs(&self.items.lock().unwrap())
+            .iter()
+            .position(|prog| target.matches(prog));
+        let Some(index) = index else {
+            return;
+        };
+        self.watch = None;
+        self.view = View::Programs;
+        self.table_state.select(Some(index));
+        self.show_graphs();
+    }
+
+    // Writes every program, map, link and attachment of the last period to a JSON file
+    pub fn write_snapshot(&mut self) {
+        let snapshot = Snapshot::new(
+            &self.sample.lock().unwrap(),
+            &get_links(),
+            &self.loaders.lock().unwrap(),
+        );
+        self.action_status = Some(match snapshot.write(&self.snapshot_dir) {
+            Ok(path) => {
+                info!("Wrote snapshot to {}", path.display());
+                format!("Wrote snapshot to {}", path.display())
+            }
+            Err(e) => {
+                error!("{:#}", e);
+                format!("{:#}", e)
+            }
+        });
+    }
 
-            // Explicitly drop the remaining MutexGuards
-            drop(items);
-            drop(sort_col);
+    pub fn toggle_memory(&mut self) {
+        self.show_memory = !self.show_memory;
+    }
 
-            // Adjust sleep duration to maintain a 1-second sample period, accounting for loop processing time.
-            let elapsed = loop_start.elapsed();
-            let sleep = if elapsed > Duration::from_secs(1) {
-                Duration::from_secs(1)
-            } else {
-                Duration::from_secs(1) - elapsed
-            };
-            thread::sleep(sleep);
-        });
+    pub fn toggle_errors(&mut self) {
+        self.show_errors = !self.show_errors;
     }
 
-    pub fn show_graphs(&mut self) {
-        self.data_buf.lock().unwrap().clear();
-        self.max_cpu = 0.0;
-        self.max_eps = 0;
-        self.max_runtime = 0;
-        self.mode = Mode::Graph;
-        self.graphs_bpf_program
-            .lock()
-            .unwrap()
-            .clone_from(&self.selected_program());
+    pub fn toggle_distribution(&mut self) {
+        self.show_distribution = !self.show_distribution;
     }
 
-    pub fn show_table(&mut self) {
-        self.mode = Mode::Table;
-        self.data_buf.lock().unwrap().clear();
-        self.max_cpu = 0.0;
-        self.max_eps = 0;
-        self.max_runtime = 0;
-        *self.graphs_bpf_program.lock().unwrap() = None;
+    pub fn toggle_map_ops_tracking(&mut self) {
+        self.track_map_ops.fetch_xor(true, Ordering::Relaxed);
     }
 
-    pub fn selected_program(&self) -> Option<BpfProgram> {
-        let items = self.items.lock().unwrap();
+    // Switches to the given view, or back to the program list if it is already shown
+    pub fn toggle_view(&mut self, view: View) {
+        // Picking another process starts from the full program list again
+        if view == View::Processes {
+            *self.selected_process.lock().unwrap() = None;
+        }
+        self.action_status = None;
+        self.view = if self.view == view {
+            View::Programs
+        } else {
+            view
+        }
+    }
 
-        self.table_state
-            .selected()
-            .and_then(|i| items.get(i).cloned())
+    pub fn toggle_diagnostics(&mut self) {
+        self.mode = match &self.mode {
+            Mode::Table => Mode::Diagnostics,
+            _ => Mode::Table,
+        }
     }
 
-    pub fn next_program(&mut self) {
-        let items = self.items.lock().unwrap();
-        if items.len() > 0 {
-            let i = match self.table_state.selected() {
-                Some(i) => {
-                    if i >= items.len() - 1 {
-                        0
-                    } else {
-                        i + 1
-                    }
-                }
-                None => 0,
-            };
-            self.table_state.select(Some(i));
+    pub fn toggle_help(&mut self) {
+        if self.mode == Mode::Help {
+            self.mode = mem::replace(&mut self.mode_before_help, Mode::Table);
+        } else {
+            self.mode_before_help = mem::replace(&mut self.mode, Mode::Help);
         }
     }
 
-    pub fn previous_program(&mut self) {
-        let items = self.items.lock().unwrap();
-        if items.len() > 0 {
-            let i = match self.table_state.selected() {
-                Some(i) => {
-                    if i == 0 {
-                        items.len() - 1
-                    } else {
-                        i - 1
-                    }
-                }
-                None => items.len() - 1,
-            };
-            self.table_state.select(Some(i));
+    pub fn toggle_heatmap(&mut self) {
+        self.mode = match &self.mode {
+            Mode::Table => Mode::Heatmap,
+            _ => Mode::Table,
+        };
+        self.show_heatmap
+            .store(self.mode == Mode::Heatmap, Ordering::Relaxed);
+    }
+
+    // Suspending stats takes a second press, as every runtime column goes blank until they
+    // are resumed
+    pub fn toggle_stats(&mut self) {
+        let mut stats = self.stats.lock().unwrap();
+        let status = if stats.is_suspended() {
+            stats.resume().map(|()| "BPF stats resumed".to_string())
+        } else if !stats.can_suspend() {
+            Ok(format!(
+                "BPF stats are {}, so bpftop leaves them alone",
+                stats.mechanism().describe()
+            ))
+        } else if self
+            .stats_suspend_armed
+            .take()
+            .is_some_and(|armed| armed.elapsed() < STATS_CONFIRM_WINDOW)
+        {
+            stats.suspend()
+        } else {
+            self.stats_suspend_armed = Some(Instant::now());
+            Ok("Press z again to suspend BPF stats".to_string())
+        };
+        self.action_status = Some(status.unwrap_or_else(|e| {
+            error!("{:#}", e);
+            format!("{:#}", e)
+        }));
+    }
+
+    pub fn stats_suspended(&self) -> bool {
+        self.stats.lock().unwrap().is_suspended()
+    }
+
+    pub fn toggle_dashboard(&mut self) {
+        self.mode = match &self.mode {
+            Mode::Table => Mode::Dashboard,
+            _ => Mode::Table,
         }
     }
 
@@ -343,14 +2020,13 @@ impl App {
                 self.mode = Mode::Sort;
 
                 // Pickup where last selected column left off from
-                let sorted_column = self.sorted_column.lock().unwrap();
-                self.selected_column = match *sorted_column {
+                let sorted_column = *self.active_sorted_column().lock().unwrap();
+                self.selected_column = match sorted_column {
                     SortColumn::Descending(col_idx) | SortColumn::Ascending(col_idx) => {
                         Some(col_idx)
                     }
                     SortColumn::NoOrder => Some(0),
                 };
-                drop(sorted_column);
             }
             _ => {
                 self.mode = Mode::Table;
@@ -359,9 +2035,33 @@ impl App {
         }
     }
 
+    pub fn active_header_columns(&self) -> &[String] {
+        match self.view {
+            View::Programs => &self.header_columns,
+            View::Maps => &self.map_header_columns,
+            View::StructOps => &self.struct_ops_header_columns,
+            View::Cgroups => &self.cgroups_header_columns,
+            View::Processes => &self.processes_header_columns,
+            View::Interfaces => &self.interfaces_header_columns,
+            View::Pins => &self.pins_header_columns,
+        }
+    }
+
+    fn active_sorted_column(&self) -> &Arc<Mutex<SortColumn>> {
+        match self.view {
+            View::Programs => &self.sorted_column,
+            View::Maps => &self.map_sorted_column,
+            View::StructOps => &self.struct_ops_sorted_column,
+            View::Cgroups => &self.cgroups_sorted_column,
+            View::Processes => &self.processes_sorted_column,
+            View::Interfaces => &self.interfaces_sorted_column,
+            View::Pins => &self.pins_sorted_column,
+        }
+    }
+
     pub fn next_column(&mut self) {
+        let num_cols = self.active_header_columns().len();
         if let Some(selected) = self.selected_column.as_mut() {
-            let num_cols = self.header_columns.len();
             *selected = (*selected + 1) % num_cols;
         } else {
             self.selected_column = Some(0);
@@ -369,8 +2069,8 @@ impl App {
     }
 
     pub fn previous_column(&mut self) {
+        let num_cols = self.active_header_columns().len();
         if let Some(selected) = self.selected_column.as_mut() {
-            let num_cols = self.header_columns.len();
             *selected = (*selected + num_cols - 1) % num_cols;
         } else {
             self.selected_column = Some(0);
@@ -378,32 +2078,45 @@ impl App {
     }
 
     pub fn sort_column(&mut self, sort_input: SortColumn) {
-        let mut sorted_column = self.sorted_column.lock().unwrap();
-
-        // Clear sort symbol of the currently sorted column
-        match *sorted_column {
-            SortColumn::Ascending(col_idx) | SortColumn::Descending(col_idx) => {
-                self.header_columns[col_idx].pop();
-            }
-            SortColumn::NoOrder => {}
-        };
-
-        // Update selected column with new sort
-        match sort_input {
-            SortColumn::Ascending(col_idx) => {
-                self.header_columns[col_idx].push('↑');
-            }
-            SortColumn::Descending(col_idx) => {
-                self.header_columns[col_idx].push('↓');
+        match self.view {
+            View::Programs => {
+                update_sort_column(&mut self.header_columns, &self.sorted_column, sort_input)
             }
-            SortColumn::NoOrder => {}
+            View::Maps => update_sort_column(
+                &mut self.map_header_columns,
+                &self.map_sorted_column,
+                sort_input,
+            ),
+            View::StructOps => update_sort_column(
+                &mut self.struct_ops_header_columns,
+                &self.struct_ops_sorted_column,
+                sort_input,
+            ),
+            View::Cgroups => update_sort_column(
+                &mut self.cgroups_header_columns,
+                &self.cgroups_sorted_column,
+                sort_input,
+            ),
+            View::Processes => update_sort_column(
+                &mut self.processes_header_columns,
+                &self.processes_sorted_column,
+                sort_input,
+            ),
+            View::Interfaces => update_sort_column(
+                &mut self.interfaces_header_columns,
+                &self.interfaces_sorted_column,
+                sort_input,
+            ),
+            View::Pins => update_sort_column(
+                &mut self.pins_header_columns,
+                &self.pins_sorted_column,
+                sort_input,
+            ),
         }
-        *sorted_column = sort_input;
-        drop(sorted_column);
     }
 
     pub fn cycle_sort_exit(&mut self) {
-        let sorted_column = self.sorted_column.lock().unwrap();
+        let sorted_column = self.active_sorted_column().lock().unwrap();
         let sorted_col = *sorted_column;
         drop(sorted_column);
 
@@ -423,9 +2136,69 @@ impl App {
     }
 }
 
+fn update_sort_column(
+    header_columns: &mut [String],
+    sorted_column: &Mutex<SortColumn>,
+    sort_input: SortColumn,
+) {
+    let mut sorted_column = sorted_column.lock().unwrap();
+
+    // Clear sort symbol of the currently sorted column
+    match *sorted_column {
+        SortColumn::Ascending(col_idx) | SortColumn::Descending(col_idx) => {
+            header_columns[col_idx].pop();
+        }
+        SortColumn::NoOrder => {}
+    };
+
+    // Update selected column with new sort
+    match sort_input {
+        SortColumn::Ascending(col_idx) => {
+            header_columns[col_idx].push('↑');
+        }
+        SortColumn::Descending(col_idx) => {
+            header_columns[col_idx].push('↓');
+        }
+        SortColumn::NoOrder => {}
+    }
+    *sorted_column = sort_input;
+}
+
+// Matches the type, name, containers and pods of a program against the filter
+fn matches_filter(program: &BpfProgram, filter: &Filter) -> bool {
+    filter.is_empty()
+        || filter.matches(&program.bpf_type)
+        || filter.matches(&program.name)
+        || filter.matches(&program.containers())
+        || filter.matches(&program.pods())
+}
+
+fn select_next(table_state: &mut TableState, len: usize) {
+    if len > 0 {
+        let i = match table_state.selected() {
+            Some(i) if i < len - 1 => i + 1,
+            _ => 0,
+        };
+        table_state.select(Some(i));
+    }
+}
+
+fn select_previous(table_state: &mut TableState, len: usize) {
+    if len > 0 {
+        let i = match table_state.selected() {
+            Some(i) if i > 0 && i < len => i - 1,
+            _ => len - 1,
+        };
+        table_state.select(Some(i));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::kernel_features::StatsMechanism;
+    use bpftop_core::bpf_attachment::BpfAttachment;
+    use crossterm::event::KeyCode;
 
     #[test]
     fn test_next_program_with_empty() {
@@ -446,6 +2219,7 @@ mod tests {
             id: 1,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100,
             run_time_ns: 200,
             prev_run_cnt: 1,
@@ -459,6 +2233,7 @@ mod tests {
             id: 2,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100,
             run_time_ns: 200,
             prev_run_cnt: 1,
@@ -507,6 +2282,7 @@ mod tests {
             id: 1,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100,
             run_time_ns: 200,
             prev_run_cnt: 1,
@@ -520,6 +2296,7 @@ mod tests {
             id: 2,
             bpf_type: "test".to_string(),
             name: "test".to_string(),
+            tag: String::new(),
             prev_runtime_ns: 100,
             run_time_ns: 200,
             prev_run_cnt: 1,
@@ -565,6 +2342,7 @@ mod tests {
         app.max_eps = 5;
         app.max_runtime = 100;
         app.data_buf.lock().unwrap().push_back(PeriodMeasure {
+            timestamp: Timestamp::now(),
             cpu_time_percent: 10.0,
             events_per_sec: 5,
             average_runtime_ns: 100,
@@ -582,4 +2360,631 @@ mod tests {
         // and data_buf should be empty again
         assert!(app.data_buf.lock().unwrap().is_empty());
     }
+
+    #[test]
+    fn test_toggle_help() {
+        let mut app = App::new();
+        app.toggle_help();
+        assert_eq!(app.mode, Mode::Help);
+        app.toggle_help();
+        assert_eq!(app.mode, Mode::Table);
+
+        // Closing the help over the graph view goes back to it
+        app.show_graphs();
+        app.toggle_help();
+        assert_eq!(app.mode, Mode::Help);
+        app.toggle_help();
+        assert_eq!(app.mode, Mode::Graph);
+    }
+
+    #[test]
+    fn test_next_map() {
+        let mut app = App::new();
+        let map_1 = BpfMap {
+            id: 1,
+            name: "test".to_string(),
+            map_type: "Hash".to_string(),
+            key_size: 4,
+            value_size: 8,
+            max_entries: 1024,
+            memlock: 4096,
+            map_extra: 0,
+            entries: None,
+            ops: None,
+            ringbuf: None,
+            pinned_paths: vec![],
+            prog_ids: vec![],
+            owners: vec![],
+        };
+        let map_2 = BpfMap {
+            id: 2,
+            ..map_1.clone()
+        };
+
+        app.maps.lock().unwrap().push(map_1);
+        app.maps.lock().unwrap().push(map_2);
+
+        // Navigating maps should not affect the program selection
+        app.next_map();
+        assert_eq!(app.map_table_state.selected(), Some(0));
+        app.next_map();
+        assert_eq!(app.map_table_state.selected(), Some(1));
+        app.next_map();
+        assert_eq!(app.map_table_state.selected(), Some(0));
+        app.previous_map();
+        assert_eq!(app.map_table_state.selected(), Some(1));
+        assert_eq!(app.table_state.selected(), None);
+    }
+
+    #[test]
+    fn test_sort_maps_view() {
+        let mut app = App::new();
+
+        // Default sort is Total CPU % for programs and Memlock for maps
+        assert_eq!(app.header_columns[6], "Total CPU %↓");
+        assert_eq!(app.map_header_columns[8], "Memlock↓");
+
+        app.toggle_view(View::Maps);
+        assert_eq!(app.view, View::Maps);
+
+        // Sorting in the maps view only changes the map columns
+        app.toggle_sort();
+        assert_eq!(app.selected_column, Some(8));
+        app.next_column();
+        app.next_column();
+        app.next_column();
+        app.next_column();
+        assert_eq!(app.selected_column, Some(0));
+        app.cycle_sort_exit();
+        assert_eq!(app.mode, Mode::Table);
+        assert_eq!(app.map_header_columns[0], "ID↓");
+        assert_eq!(app.map_header_columns[8], "Memlock");
+        assert_eq!(app.header_columns[6], "Total CPU %↓");
+
+        app.toggle_view(View::Maps);
+        assert_eq!(app.view, View::Programs);
+    }
+
+    #[test]
+    fn test_toggle_struct_ops_view() {
+        let mut app = App::new();
+        assert_eq!(app.struct_ops_header_columns[6], "Total CPU %↓");
+
+        app.toggle_view(View::StructOps);
+        assert_eq!(app.view, View::StructOps);
+
+        // Switching between views does not go through the program list
+        app.toggle_view(View::Maps);
+        assert_eq!(app.view, View::Maps);
+        app.toggle_view(View::StructOps);
+        app.toggle_view(View::StructOps);
+        assert_eq!(app.view, View::Programs);
+
+        // Navigating an empty list selects nothing
+        app.toggle_view(View::StructOps);
+        app.next_struct_ops();
+        assert_eq!(app.struct_ops_table_state.selected(), None);
+    }
+
+    #[test]
+    fn test_sort_cgroups_view() {
+        let mut app = App::new();
+        assert_eq!(app.cgroups_header_columns[4], "Total CPU %↓");
+
+        app.toggle_view(View::Cgroups);
+        assert_eq!(app.view, View::Cgroups);
+        app.toggle_sort();
+        assert_eq!(app.selected_column, Some(4));
+        app.next_column();
+        assert_eq!(app.selected_column, Some(0));
+        app.next_column();
+        app.cycle_sort_exit();
+        assert_eq!(app.cgroups_header_columns[1], "Cgroup↓");
+        assert_eq!(app.cgroups_header_columns[4], "Total CPU %");
+        assert_eq!(app.struct_ops_header_columns[6], "Total CPU %↓");
+
+        app.next_cgroup();
+        assert_eq!(app.cgroups_table_state.selected(), None);
+    }
+
+    #[test]
+    fn test_toggle_interfaces_view() {
+        let mut app = App::new();
+        assert_eq!(app.interfaces_header_columns[4], "Total CPU %↓");
+
+        app.toggle_view(View::Interfaces);
+        assert_eq!(app.view, View::Interfaces);
+        app.toggle_sort();
+        app.previous_column();
+        app.cycle_sort_exit();
+        assert_eq!(app.interfaces_header_columns[3], "Events/sec↓");
+        assert_eq!(app.cgroups_header_columns[4], "Total CPU %↓");
+
+        app.next_interface();
+        assert_eq!(app.interfaces_table_state.selected(), None);
+        app.toggle_view(View::Interfaces);
+        assert_eq!(app.view, View::Programs);
+    }
+
+    #[test]
+    fn test_detach_confirmation() {
+        let mut app = App::new();
+        app.show_graphs();
+
+        // Nothing to detach without attachments
+        app.start_detach();
+        assert_eq!(app.mode, Mode::Graph);
+
+        let link = |link_id| {
+            Attachment::Link(BpfAttachment {
+                link_id,
+                link_type: "xdp".to_string(),
+                target: String::new(),
+                attach_type: String::new(),
+            })
+        };
+        *app.graphs_attachments.lock().unwrap() = Some(AttachmentsState::Resolved(
+            0,
+            vec![link(u32::MAX - 1), link(u32::MAX)],
+        ));
+        app.previous_attachment();
+        assert_eq!(app.attachment_to_detach(), Some(link(u32::MAX)));
+
+        app.start_detach();
+        assert_eq!(app.mode, Mode::Detach);
+        app.prompt_input = Input::new(format!("link {}", u32::MAX - 1));
+        app.confirm_detach();
+        assert_eq!(app.mode, Mode::Graph);
+        assert_eq!(
+            app.action_status.as_deref(),
+            Some("Confirmation did not match, nothing detached")
+        );
+        assert_eq!(app.attachments().len(), 2);
+
+        // The link does not exist, so detaching it fails
+        app.start_detach();
+        app.prompt_input = Input::new(format!("link {}", u32::MAX));
+        app.confirm_detach();
+        assert!(app
+            .action_status
+            .as_deref()
+            .is_some_and(|status| status.starts_with("Failed to detach link")));
+    }
+
+    #[test]
+    fn test_restore_session() {
+        let mut app = App::new();
+        app.restore_session(Session {
+            view: View::Programs,
+            program_id: Some(2),
+            selected: Some(0),
+            offset: 0,
+            graphs: true,
+            stars: Stars::default(),
+        });
+        // Nothing sampled yet
+        app.apply_pending_session();
+        assert_eq!(app.mode, Mode::Table);
+
+        let prog = |id| BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes: vec![],
+        };
+        *app.items.lock().unwrap() = vec![prog(1), prog(2)];
+        app.apply_pending_session();
+        assert_eq!(app.table_state.selected(), Some(1));
+        assert_eq!(app.mode, Mode::Graph);
+        assert_eq!(app.session().program_id, Some(2));
+        assert!(app.session().graphs);
+    }
+
+    #[test]
+    fn test_filter_shown_programs() {
+        let mut app = App::new();
+        let prog = |id, name: &str| BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: name.to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes: vec![],
+        };
+        *app.items.lock().unwrap() = vec![prog(1, "tcp_connect"), prog(2, "udp_sendmsg")];
+        for c in "UDP".chars() {
+            app.filter
+                .lock()
+                .unwrap()
+                .handle_event(&Event::Key(KeyCode::Char(c).into()));
+        }
+
+        // Selection follows the shown rows, while every program is still collected
+        app.next_program();
+        assert_eq!(app.selected_program().map(|prog| prog.id), Some(2));
+        app.next_program();
+        assert_eq!(app.table_state.selected(), Some(0));
+        assert_eq!(app.items.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_type_filter() {
+        let mut app = App::new();
+        let prog = |id, bpf_type: &str| BpfProgram {
+            id,
+            bpf_type: bpf_type.to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes: vec![],
+        };
+        *app.items.lock().unwrap() = vec![prog(1, "Kprobe"), prog(2, "Xdp"), prog(3, "Kprobe")];
+        app.next_type_filter();
+        assert_eq!(app.type_filter.as_deref(), Some("kprobe"));
+        assert_eq!(app.shown_programs(&app.items.lock().unwrap()).len(), 2);
+        app.next_type_filter();
+        assert_eq!(app.type_filter.as_deref(), Some("xdp"));
+        app.next_type_filter();
+        assert_eq!(app.type_filter, None);
+        app.previous_type_filter();
+        assert_eq!(app.type_filter.as_deref(), Some("xdp"));
+        assert_eq!(app.shown_programs(&app.items.lock().unwrap()).len(), 1);
+
+        app.quick_type_filter(1);
+        assert_eq!(app.type_filter.as_deref(), Some("kprobe"));
+        app.quick_type_filter(3);
+        assert_eq!(app.shown_programs(&app.items.lock().unwrap()).len(), 1);
+        app.quick_type_filter(0);
+        assert_eq!(app.type_filter, None);
+    }
+
+    #[test]
+    fn test_starred_only() {
+        let mut app = App::new();
+        let prog = |id, name: &str| BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: name.to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes: vec![],
+        };
+        *app.items.lock().unwrap() = vec![prog(1, "tcp_connect"), prog(2, "udp_sendmsg")];
+        app.table_state.select(Some(1));
+        app.toggle_star();
+        app.toggle_starred_only();
+        let items = app.items.lock().unwrap().clone();
+        let shown = app.shown_programs(&items);
+        assert_eq!(shown.len(), 1);
+        assert_eq!(shown[0].name, "udp_sendmsg");
+        assert_eq!(app.session().stars, app.stars);
+    }
+
+    #[test]
+    fn test_follow() {
+        let mut app = App::new();
+        let prog = |id, run_time_ns| BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns,
+            prev_run_cnt: 0,
+            run_cnt: 1,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        };
+        *app.items.lock().unwrap() = vec![prog(1, 100), prog(2, 300), prog(3, 200)];
+        *app.sorted_column.lock().unwrap() = SortColumn::Ascending(6);
+        app.toggle_follow();
+        assert_eq!(app.table_state.selected(), Some(2));
+
+        *app.sorted_column.lock().unwrap() = SortColumn::NoOrder;
+        app.follow_top_program();
+        assert_eq!(app.selected_program().unwrap().id, 2);
+
+        app.previous_program();
+        assert!(!app.follow);
+    }
+
+    #[test]
+    fn test_watch() {
+        let mut app = App::new();
+        let prog = |id, name: &str| BpfProgram {
+            id,
+            bpf_type: "Xdp".to_string(),
+            name: name.to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes: vec![],
+        };
+        let mut stars = Stars::default();
+        stars.toggle(&prog(1, "xdp_lb"));
+        app.restore_session(Session {
+            view: View::Programs,
+            program_id: Some(1),
+            selected: Some(0),
+            offset: 0,
+            graphs: true,
+            stars,
+        });
+        app.watch(WatchTarget::Name("cilium_xdp_entry_point".to_string()));
+        *app.items.lock().unwrap() = vec![prog(1, "xdp_lb")];
+        app.apply_pending_session();
+        app.apply_watch();
+        assert_eq!(app.mode, Mode::Table);
+        assert_eq!(app.session().stars.len(), 1);
+
+        app.items.lock().unwrap().push(prog(2, "cilium_xdp_entr"));
+        app.apply_watch();
+        assert_eq!(app.mode, Mode::Graph);
+        assert_eq!(
+            app.graphs_bpf_program.lock().unwrap().as_ref().unwrap().id,
+            2
+        );
+        assert!(app.action_status.is_none());
+    }
+
+    #[test]
+    fn test_signal_confirmation() {
+        let mut app = App::new();
+        app.show_graphs();
+        // Nothing to signal without a program
+        app.start_signal();
+        assert_eq!(app.mode, Mode::Graph);
+
+        let holder = |pid| Process {
+            pid,
+            comm: "profiler".to_string(),
+            container: None,
+            cgroup_id: 0,
+            bpf_fds: 1,
+            user: None,
+        };
+        *app.graphs_bpf_program.lock().unwrap() = Some(BpfProgram {
+            id: 1,
+            bpf_type: "PerfEvent".to_string(),
+            name: "on_cpu".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes: vec![holder(100), holder(200)],
+        });
+        app.previous_holder();
+        assert_eq!(app.holder_to_signal().unwrap().pid, 200);
+
+        app.start_signal();
+        assert_eq!(app.mode, Mode::Signal);
+        app.toggle_signal_kill();
+        assert_eq!(app.signal(), Signal::SIGKILL);
+        app.prompt_input = Input::new("100".to_string());
+        app.confirm_signal();
+        assert_eq!(app.mode, Mode::Graph);
+        assert_eq!(
+            app.action_status.as_deref(),
+            Some("Confirmation did not match, nothing sent")
+        );
+    }
+
+    #[test]
+    fn test_toggle_stats() {
+        let mut app = App::new();
+        app.toggle_stats();
+        assert!(app
+            .action_status
+            .as_ref()
+            .unwrap()
+            .contains("leaves them alone"));
+        assert!(!app.stats_suspended());
+
+        *app.stats.lock().unwrap() = StatsControl::new(StatsMechanism::Syscall, None);
+        app.toggle_stats();
+        assert_eq!(
+            app.action_status.as_deref(),
+            Some("Press z again to suspend BPF stats")
+        );
+        assert!(!app.stats_suspended());
+        app.toggle_stats();
+        assert!(app.stats_suspended());
+    }
+
+    #[test]
+    fn test_program_history() {
+        let prog = |id, run_time_ns| BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns,
+            prev_run_cnt: 0,
+            run_cnt: 10,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        };
+        let timestamp = Timestamp {
+            instant: Instant::now(),
+            wall_ms: 0,
+        };
+        let mut history = ProgramHistory::default();
+        history.record(&[prog(1, 10_000_000), prog(2, 0)], timestamp);
+        history.record(&[prog(1, 20_000_000)], timestamp);
+
+        let periods = history.get(1).unwrap();
+        assert_eq!(periods.len(), 2);
+        assert_eq!(periods.back().unwrap().cpu_time_percent, 2.0);
+        assert_eq!(periods.back().unwrap().events_per_sec, 10);
+        // Unloaded programs are forgotten
+        assert!(history.get(2).is_none());
+    }
+
+    #[test]
+    fn test_sort_programs_ties() {
+        let prog = |id, run_time_ns| BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns,
+            prev_run_cnt: 0,
+            run_cnt: 1,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        };
+        let ids = |programs: &[BpfProgram]| programs.iter().map(|prog| prog.id).collect::<Vec<_>>();
+        let mut programs = vec![
+            prog(4, 0),
+            prog(2, 0),
+            prog(9, 1000),
+            prog(3, 0),
+            prog(1, 0),
+        ];
+
+        // Idle programs keep the same order however they were collected
+        sort_programs(&mut programs, SortColumn::Ascending(6));
+        assert_eq!(ids(&programs), vec![1, 2, 3, 4, 9]);
+        programs.reverse();
+        sort_programs(&mut programs, SortColumn::Descending(6));
+        assert_eq!(ids(&programs), vec![9, 4, 3, 2, 1]);
+        programs.swap(1, 3);
+        sort_programs(&mut programs, SortColumn::Descending(2));
+        assert_eq!(ids(&programs), vec![9, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_cmp_percent_nan() {
+        let mut percents = [12.5, f64::NAN, 0.0, -f64::NAN, 100.0];
+        percents.sort_unstable_by(|a, b| cmp_percent(*a, *b));
+        assert!(percents[..2].iter().all(|percent| percent.is_nan()));
+        assert_eq!(percents[2..], [0.0, 12.5, 100.0]);
+        assert_eq!(cmp_percent(f64::NAN, f64::NAN), cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_program_columns() {
+        let mut app = App::new();
+        assert_eq!(app.program_columns(), (0..10).collect::<Vec<_>>());
+        app.baseline = Some(BaselineSnapshot::default());
+        assert_eq!(app.program_columns(), (0..12).collect::<Vec<_>>());
+        app.runtime_stats.store(false, Ordering::Relaxed);
+        assert_eq!(app.program_columns(), vec![0, 1, 2, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_unpin_confirmation() {
+        let mut app = App::new();
+        app.toggle_view(View::Pins);
+
+        // Nothing to unpin without a selected pin
+        app.start_unpin();
+        assert_eq!(app.mode, Mode::Table);
+
+        *app.pins.lock().unwrap() = vec![Pin {
+            path: "/tmp/not-bpffs".to_string(),
+            kind: PinnedKind::Map,
+            id: 37,
+            name: "map".to_string(),
+            object_type: "Hash".to_string(),
+            users: vec![],
+        }];
+        app.next_pin();
+        app.start_unpin();
+        assert_eq!(app.mode, Mode::Unpin);
+        app.prompt_input = Input::new("map 38".to_string());
+        app.confirm_unpin();
+        assert_eq!(app.mode, Mode::Table);
+        assert_eq!(
+            app.action_status.as_deref(),
+            Some("Confirmation did not match, nothing unpinned")
+        );
+
+        // Paths outside bpffs are never removed
+        app.start_unpin();
+        app.prompt_input = Input::new("map 37".to_string());
+        app.confirm_unpin();
+        assert_eq!(
+            app.action_status.as_deref(),
+            Some("/tmp/not-bpffs is not below a bpffs mount")
+        );
+    }
+
+    #[test]
+    fn test_show_process_programs() {
+        let mut app = App::new();
+        app.toggle_view(View::Processes);
+
+        // Nothing to drill into without a selected process
+        app.show_process_programs();
+        assert_eq!(app.view, View::Processes);
+
+        app.processes.lock().unwrap().push(ProcessPrograms {
+            pid: 812,
+            comm: "agent".to_string(),
+            container: None,
+            prog_ids: vec![1, 2],
+            events_per_second: 0,
+            cpu_time_percent: 0.0,
+        });
+        app.next_process();
+        app.table_state.select(Some(3));
+        app.show_process_programs();
+        assert_eq!(app.view, View::Programs);
+        assert_eq!(app.table_state.selected(), None);
+        assert_eq!(
+            *app.selected_process.lock().unwrap(),
+            Some((812, "agent".to_string()))
+        );
+
+        // Going back to the process list shows all programs again
+        app.toggle_view(View::Processes);
+        assert_eq!(app.view, View::Processes);
+        assert!(app.selected_process.lock().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_map_inspector_without_selection() {
+        let mut app = App::new();
+        app.toggle_view(View::Maps);
+
+        // Nothing to inspect without a selected map
+        app.show_map_inspector();
+        assert_eq!(app.mode, Mode::Table);
+        assert!(app.map_inspector.is_none());
+    }
 }
@@ -0,0 +1,143 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    btf::{object_info, vmlinux_btf, Btf},
+    struct_ops::StructOps,
+};
+const LSM_HOOK_PREFIX: &str = "bpf_lsm_";
+
+// What a program loaded against a BTF type hooks, e.g. "fentry → tcp_sendmsg"
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttachTarget {
+    // fentry, fexit, lsm, struct_ops...
+    pub kind: String,
+    // Function, or struct_ops callback, the program hooks
+    pub name: String,
+}
+
+impl AttachTarget {
+    // Programs only report the BTF type they were loaded against, so how they hook it comes
+    // from the tracing link they are attached through, if any, or else from their type
+    pub fn resolve(
+        prog_id: u32,
+        prog_type: u32,
+        btf_obj_id: u32,
+        btf_type_id: u32,
+        link_kind: Option<String>,
+        struct_ops: &[StructOps],
+    ) -> AttachTarget {
+        let kind = link_kind.unwrap_or_else(|| {
+            match prog_type {
+                libbpf_sys::BPF_PROG_TYPE_LSM => "lsm",
+                libbpf_sys::BPF_PROG_TYPE_EXT => "freplace",
+                libbpf_sys::BPF_PROG_TYPE_STRUCT_OPS => "struct_ops",
+                _ => "tracing",
+            }
+            .to_string()
+        });
+        // struct_ops programs are loaded against the struct, the callback they implement
+        // is found in the struct_ops maps using them
+        let callback = struct_ops.iter().find_map(|ops| {
+            ops.callbacks
+                .iter()
+                .find(|(_, id)| *id == prog_id)
+                .map(|(member, _)| format!("{}.{}", ops.struct_name, member))
+        });
+        let name = callback.unwrap_or_else(|| {
+            btf_type_name(btf_obj_id, btf_type_id)
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| format!("BTF type {}", btf_type_id))
+        });
+        AttachTarget { kind, name }
+    }
+
+    // LSM programs read like their libbpf section, e.g. "lsm/bprm_check_security", as the
+    // functions they attach to only wrap the hook with a bpf_lsm_ prefix
+    pub fn describe(&self) -> String {
+        match self.kind.as_str() {
+            "lsm" | "lsm_cgroup" => format!(
+                "{}/{}",
+                self.kind,
+                self.name
+                    .strip_prefix(LSM_HOOK_PREFIX)
+                    .unwrap_or(&self.name)
+            ),
+            _ => format!("{} → {}", self.kind, self.name),
+        }
+    }
+}
+
+// Name of a type in the kernel's BTF, a module's, or, for programs replacing a function of
+// another program, that program's
+fn btf_type_name(btf_obj_id: u32, btf_type_id: u32) -> Option<String> {
+    match object_info(btf_obj_id)? {
+        (name, true) if name == "vmlinux" => {
+            vmlinux_btf().map(|btf| btf.type_name(btf_type_id).to_string())
+        }
+        _ => Btf::from_kernel_by_id(btf_obj_id)
+            .ok()
+            .map(|btf| btf.type_name(btf_type_id).to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let struct_ops = vec![StructOps {
+            map_id: 3,
+            name: "bpf_cubic".to_string(),
+            struct_name: "tcp_congestion_ops".to_string(),
+            state: "inuse".to_string(),
+            callbacks: vec![("ssthresh".to_string(), 41), ("cong_avoid".to_string(), 42)],
+            events_per_second: 0,
+            cpu_time_percent: 0.0,
+        }];
+        let target = AttachTarget::resolve(
+            42,
+            libbpf_sys::BPF_PROG_TYPE_STRUCT_OPS,
+            0,
+            0,
+            None,
+            &struct_ops,
+        );
+        assert_eq!(
+            target.describe(),
+            "struct_ops → tcp_congestion_ops.cong_avoid"
+        );
+
+        // The BTF object does not exist, so only the type ID is known
+        let target = AttachTarget::resolve(
+            7,
+            libbpf_sys::BPF_PROG_TYPE_TRACING,
+            u32::MAX,
+            1234,
+            Some("fexit".to_string()),
+            &struct_ops,
+        );
+        assert_eq!(target.describe(), "fexit → BTF type 1234");
+
+        let target = AttachTarget {
+            kind: "lsm".to_string(),
+            name: "bpf_lsm_bprm_check_security".to_string(),
+        };
+        assert_eq!(target.describe(), "lsm/bprm_check_security");
+    }
+}
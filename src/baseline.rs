@@ -0,0 +1,162 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use anyhow::{Context, Result};
+use bpftop_core::{bpf_program::BpfProgram, helpers::format_percent};
+use serde::Deserialize;
+use std::{collections::HashMap, fs, path::Path};
+
+// Headers of the columns added to the program list when comparing against a baseline
+pub const BASELINE_COLUMNS: [&str; 2] = ["ΔCPU %", "ΔEvents/s"];
+
+// The fields of a snapshot's programs that comparing against it needs
+#[derive(Debug, Deserialize)]
+struct SnapshotProgram {
+    name: String,
+    bpf_type: String,
+    events_per_second: i64,
+    cpu_time_percent: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct SnapshotFile {
+    programs: Vec<SnapshotProgram>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct BaselineStats {
+    cpu_time_percent: f64,
+    events_per_second: f64,
+}
+
+// The program stats of a snapshot written with the w key, to compare the running programs
+// against, e.g. before and after upgrading an agent. Program IDs change whenever a program
+// is reloaded, so programs are matched by type and name, and programs sharing both are
+// compared with their average.
+#[derive(Debug, Default)]
+pub struct BaselineSnapshot {
+    programs: HashMap<(String, String), BaselineStats>,
+}
+
+impl BaselineSnapshot {
+    pub fn parse(contents: &str) -> Result<BaselineSnapshot> {
+        let snapshot: SnapshotFile = serde_json::from_str(contents)?;
+        let mut grouped: HashMap<(String, String), Vec<SnapshotProgram>> = HashMap::new();
+        for prog in snapshot.programs {
+            grouped
+                .entry((prog.bpf_type.clone(), prog.name.clone()))
+                .or_default()
+                .push(prog);
+        }
+        let programs = grouped
+            .into_iter()
+            .map(|(key, progs)| {
+                let count = progs.len() as f64;
+                let stats = BaselineStats {
+                    cpu_time_percent: progs.iter().map(|prog| prog.cpu_time_percent).sum::<f64>()
+                        / count,
+                    events_per_second: progs
+                        .iter()
+                        .map(|prog| prog.events_per_second as f64)
+                        .sum::<f64>()
+                        / count,
+                };
+                (key, stats)
+            })
+            .collect();
+        Ok(BaselineSnapshot { programs })
+    }
+
+    pub fn load(path: &Path) -> Result<BaselineSnapshot> {
+        let contents = fs::read_to_string(path)
+            .context(format!("Failed to read baseline {}", path.display()))?;
+        BaselineSnapshot::parse(&contents)
+            .context(format!("Invalid baseline snapshot {}", path.display()))
+    }
+
+    fn get(&self, prog: &BpfProgram) -> Option<&BaselineStats> {
+        self.programs
+            .get(&(prog.bpf_type.clone(), prog.name.clone()))
+    }
+
+    // Cells of the baseline columns for a program, or "new" for programs missing from the
+    // baseline
+    pub fn deltas(&self, prog: &BpfProgram) -> [String; 2] {
+        let Some(baseline) = self.get(prog) else {
+            return ["new".to_string(), "new".to_string()];
+        };
+        let cpu_delta = prog.cpu_time_percent() - baseline.cpu_time_percent;
+        let eps_delta = prog.events_per_second() as f64 - baseline.events_per_second;
+        [
+            format!(
+                "{}{}",
+                if cpu_delta < 0.0 { "-" } else { "+" },
+                format_percent(cpu_delta.abs())
+            ),
+            format!("{:+}", eps_delta.round() as i64),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn test_deltas() {
+        let baseline = BaselineSnapshot::parse(
+            r#"{
+                "timestamp_ms": 0,
+                "programs": [
+                    {"id": 1, "name": "xdp_lb", "bpf_type": "Xdp", "events_per_second": 1000, "cpu_time_percent": 2.0},
+                    {"id": 2, "name": "trace_exec", "bpf_type": "Kprobe", "events_per_second": 10, "cpu_time_percent": 0.1},
+                    {"id": 3, "name": "trace_exec", "bpf_type": "Kprobe", "events_per_second": 30, "cpu_time_percent": 0.3}
+                ]
+            }"#,
+        )
+        .unwrap();
+        // Programs are matched by type and name, whatever their ID is now
+        let prog = |id, bpf_type: &str, name: &str, run_cnt, run_time_ns| BpfProgram {
+            id,
+            bpf_type: bpf_type.to_string(),
+            name: name.to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns,
+            prev_run_cnt: 0,
+            run_cnt,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        };
+        assert_eq!(
+            baseline.deltas(&prog(7, "Xdp", "xdp_lb", 1500, 25_000_000)),
+            ["+0.5%", "+500"]
+        );
+        assert_eq!(
+            baseline.deltas(&prog(8, "Kprobe", "trace_exec", 5, 1_000_000)),
+            ["-0.1%", "-15"]
+        );
+        assert_eq!(
+            baseline.deltas(&prog(9, "Kprobe", "trace_open", 5, 0)),
+            ["new", "new"]
+        );
+
+        assert!(BaselineSnapshot::parse("{}").is_err());
+    }
+}
@@ -0,0 +1,64 @@
+#include "vmlinux.h"
+#include "bpf_helpers.h"
+#include "bpf_core_read.h"
+
+char _license[] SEC("license") = "GPL";
+
+#define AUDIT_PROG_LOAD 1
+#define AUDIT_PROG_UNLOAD 2
+
+struct audit_event {
+	__u32 event;
+	__u32 prog_id;
+	__u32 prog_type;
+	__u32 pid;
+	__u32 uid;
+	char comm[16];
+	char prog_name[16];
+};
+
+struct {
+	__uint(type, BPF_MAP_TYPE_RINGBUF);
+	__uint(max_entries, 256 * 1024);
+} events SEC(".maps");
+
+static __always_inline void emit(struct bpf_prog *prog, __u32 event)
+{
+	struct audit_event e;
+
+	__builtin_memset(&e, 0, sizeof(e));
+
+	e.event = event;
+	e.prog_id = BPF_CORE_READ(prog, aux, id);
+	e.prog_type = BPF_CORE_READ(prog, type);
+	e.pid = bpf_get_current_pid_tgid() >> 32;
+	e.uid = bpf_get_current_uid_gid();
+	bpf_get_current_comm(&e.comm, sizeof(e.comm));
+	BPF_CORE_READ_STR_INTO(&e.prog_name, prog, aux, name);
+
+	bpf_ringbuf_output(&events, &e, sizeof(e), 0);
+}
+
+/* Called for every program load once its ID is allocated, from the loading process */
+SEC("fentry/perf_event_bpf_event")
+int audit_prog_load(__u64 *ctx)
+{
+	struct bpf_prog *prog = (void *)ctx[0];
+	enum perf_bpf_event_type type = ctx[1];
+
+	if (type == PERF_BPF_EVENT_PROG_LOAD)
+		emit(prog, AUDIT_PROG_LOAD);
+
+	return 0;
+}
+
+/* Called when the last reference is dropped, while the ID is still assigned */
+SEC("fentry/bpf_prog_free_id")
+int audit_prog_unload(__u64 *ctx)
+{
+	struct bpf_prog *prog = (void *)ctx[0];
+
+	emit(prog, AUDIT_PROG_UNLOAD);
+
+	return 0;
+}
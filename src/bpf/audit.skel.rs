@@ -0,0 +1,584 @@
+// SPDX-License-Identifier: (LGPL-2.1 OR BSD-2-Clause)
+//
+// THIS FILE IS AUTOGENERATED BY CARGO-LIBBPF-GEN!
+
+pub use self::imp::*;
+
+#[allow(dead_code)]
+#[allow(non_snake_case)]
+#[allow(non_camel_case_types)]
+#[allow(clippy::absolute_paths)]
+#[allow(clippy::upper_case_acronyms)]
+#[allow(clippy::zero_repeat_side_effects)]
+#[warn(single_use_lifetimes)]
+mod imp {
+    #[allow(unused_imports)]
+    use super::*;
+    use libbpf_rs::libbpf_sys;
+    use libbpf_rs::skel::OpenSkel;
+    use libbpf_rs::skel::Skel;
+    use libbpf_rs::skel::SkelBuilder;
+    use libbpf_rs::AsRawLibbpf as _;
+    use libbpf_rs::MapCore as _;
+    fn build_skel_config(
+    ) -> libbpf_rs::Result<libbpf_rs::__internal_skel::ObjectSkeletonConfig<'static>> {
+        let mut builder = libbpf_rs::__internal_skel::ObjectSkeletonConfigBuilder::new(DATA);
+        builder
+            .name("audit_bpf")
+            .map("events", false)
+            .prog("audit_prog_load")
+            .prog("audit_prog_unload");
+        builder.build()
+    }
+    pub struct OpenAuditMaps<'obj> {
+        pub events: libbpf_rs::OpenMapMut<'obj>,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> OpenAuditMaps<'obj> {
+        #[allow(unused_variables)]
+        unsafe fn new(
+            config: &libbpf_rs::__internal_skel::ObjectSkeletonConfig<'_>,
+            object: &mut libbpf_rs::OpenObject,
+        ) -> libbpf_rs::Result<Self> {
+            let mut events = None;
+            let object = unsafe {
+                std::mem::transmute::<&mut libbpf_rs::OpenObject, &'obj mut libbpf_rs::OpenObject>(
+                    object,
+                )
+            };
+            #[allow(clippy::never_loop)]
+            for map in object.maps_mut() {
+                let name = map.name().to_str().ok_or_else(|| {
+                    libbpf_rs::Error::from(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "map has invalid name",
+                    ))
+                })?;
+                #[allow(clippy::match_single_binding)]
+                match name {
+                    "events" => events = Some(map),
+                    _ => panic!("encountered unexpected map: `{name}`"),
+                }
+            }
+
+            let slf = Self {
+                events: events.expect("map `events` not present"),
+                _phantom: std::marker::PhantomData,
+            };
+            Ok(slf)
+        }
+    }
+    pub struct AuditMaps<'obj> {
+        pub events: libbpf_rs::MapMut<'obj>,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> AuditMaps<'obj> {
+        #[allow(unused_variables)]
+        unsafe fn new(
+            config: &libbpf_rs::__internal_skel::ObjectSkeletonConfig<'_>,
+            object: &mut libbpf_rs::Object,
+        ) -> libbpf_rs::Result<Self> {
+            let mut events = None;
+            let object = unsafe {
+                std::mem::transmute::<&mut libbpf_rs::Object, &'obj mut libbpf_rs::Object>(object)
+            };
+            #[allow(clippy::never_loop)]
+            for map in object.maps_mut() {
+                let name = map.name().to_str().ok_or_else(|| {
+                    libbpf_rs::Error::from(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "map has invalid name",
+                    ))
+                })?;
+                #[allow(clippy::match_single_binding)]
+                match name {
+                    "events" => events = Some(map),
+                    _ => panic!("encountered unexpected map: `{name}`"),
+                }
+            }
+
+            let slf = Self {
+                events: events.expect("map `events` not present"),
+                _phantom: std::marker::PhantomData,
+            };
+            Ok(slf)
+        }
+    }
+    pub struct OpenAuditProgs<'obj> {
+        pub audit_prog_load: libbpf_rs::OpenProgramMut<'obj>,
+        pub audit_prog_unload: libbpf_rs::OpenProgramMut<'obj>,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> OpenAuditProgs<'obj> {
+        unsafe fn new(object: &mut libbpf_rs::OpenObject) -> libbpf_rs::Result<Self> {
+            let mut audit_prog_load = None;
+            let mut audit_prog_unload = None;
+            let object = unsafe {
+                std::mem::transmute::<&mut libbpf_rs::OpenObject, &'obj mut libbpf_rs::OpenObject>(
+                    object,
+                )
+            };
+            for prog in object.progs_mut() {
+                let name = prog.name().to_str().ok_or_else(|| {
+                    libbpf_rs::Error::from(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "prog has invalid name",
+                    ))
+                })?;
+                match name {
+                    "audit_prog_load" => audit_prog_load = Some(prog),
+                    "audit_prog_unload" => audit_prog_unload = Some(prog),
+                    _ => panic!("encountered unexpected prog: `{name}`"),
+                }
+            }
+
+            let slf = Self {
+                audit_prog_load: audit_prog_load.expect("prog `audit_prog_load` not present"),
+                audit_prog_unload: audit_prog_unload.expect("prog `audit_prog_unload` not present"),
+                _phantom: std::marker::PhantomData,
+            };
+            Ok(slf)
+        }
+    }
+    pub struct AuditProgs<'obj> {
+        pub audit_prog_load: libbpf_rs::ProgramMut<'obj>,
+        pub audit_prog_unload: libbpf_rs::ProgramMut<'obj>,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> AuditProgs<'obj> {
+        #[allow(unused_variables)]
+        fn new(open_progs: OpenAuditProgs<'obj>) -> Self {
+            Self {
+                audit_prog_load: unsafe {
+                    libbpf_rs::ProgramMut::new_mut(
+                        open_progs.audit_prog_load.as_libbpf_object().as_mut(),
+                    )
+                },
+                audit_prog_unload: unsafe {
+                    libbpf_rs::ProgramMut::new_mut(
+                        open_progs.audit_prog_unload.as_libbpf_object().as_mut(),
+                    )
+                },
+                _phantom: std::marker::PhantomData,
+            }
+        }
+    }
+    struct OwnedRef<'obj, O> {
+        object: Option<&'obj mut std::mem::MaybeUninit<O>>,
+    }
+
+    impl<'obj, O> OwnedRef<'obj, O> {
+        /// # Safety
+        /// The object has to be initialized.
+        unsafe fn new(object: &'obj mut std::mem::MaybeUninit<O>) -> Self {
+            Self {
+                object: Some(object),
+            }
+        }
+
+        fn as_ref(&self) -> &O {
+            // SAFETY: As per the contract during construction, the
+            //         object has to be initialized.
+            unsafe { self.object.as_ref().unwrap().assume_init_ref() }
+        }
+
+        fn as_mut(&mut self) -> &mut O {
+            // SAFETY: As per the contract during construction, the
+            //         object has to be initialized.
+            unsafe { self.object.as_mut().unwrap().assume_init_mut() }
+        }
+
+        fn take(mut self) -> &'obj mut std::mem::MaybeUninit<O> {
+            self.object.take().unwrap()
+        }
+    }
+
+    impl<O> Drop for OwnedRef<'_, O> {
+        fn drop(&mut self) {
+            if let Some(object) = &mut self.object {
+                unsafe { object.assume_init_drop() }
+            }
+        }
+    }
+
+    #[derive(Default)]
+    pub struct AuditSkelBuilder {
+        pub obj_builder: libbpf_rs::ObjectBuilder,
+    }
+
+    impl<'obj> AuditSkelBuilder {
+        fn open_opts_impl(
+            self,
+            open_opts: *const libbpf_sys::bpf_object_open_opts,
+            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+        ) -> libbpf_rs::Result<OpenAuditSkel<'obj>> {
+            let skel_config = build_skel_config()?;
+            let skel_ptr = skel_config.as_libbpf_object();
+
+            let ret =
+                unsafe { libbpf_sys::bpf_object__open_skeleton(skel_ptr.as_ptr(), open_opts) };
+            if ret != 0 {
+                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
+            }
+
+            // SAFETY: `skel_ptr` points to a valid object after the
+            //         open call.
+            let obj_ptr = unsafe { *skel_ptr.as_ref().obj };
+            // SANITY: `bpf_object__open_skeleton` should have
+            //         allocated the object.
+            let obj_ptr = std::ptr::NonNull::new(obj_ptr).unwrap();
+            // SAFETY: `obj_ptr` points to an opened object after
+            //         skeleton open.
+            let obj = unsafe { libbpf_rs::OpenObject::from_ptr(obj_ptr) };
+            let _obj = object.write(obj);
+            // SAFETY: We just wrote initialized data to `object`.
+            let mut obj_ref = unsafe { OwnedRef::new(object) };
+
+            #[allow(unused_mut)]
+            let mut skel = OpenAuditSkel {
+                maps: unsafe { OpenAuditMaps::new(&skel_config, obj_ref.as_mut())? },
+                progs: unsafe { OpenAuditProgs::new(obj_ref.as_mut())? },
+                obj: obj_ref,
+                // SAFETY: Our `struct_ops` type contains only pointers,
+                //         which are allowed to be NULL.
+                // TODO: Generate and use a `Default` representation
+                //       instead, to cut down on unsafe code.
+                struct_ops: unsafe { std::mem::zeroed() },
+                skel_config,
+            };
+
+            Ok(skel)
+        }
+    }
+
+    impl<'obj> SkelBuilder<'obj> for AuditSkelBuilder {
+        type Output = OpenAuditSkel<'obj>;
+        fn open(
+            self,
+            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+        ) -> libbpf_rs::Result<OpenAuditSkel<'obj>> {
+            self.open_opts_impl(std::ptr::null(), object)
+        }
+
+        fn open_opts(
+            self,
+            open_opts: libbpf_sys::bpf_object_open_opts,
+            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+        ) -> libbpf_rs::Result<OpenAuditSkel<'obj>> {
+            self.open_opts_impl(&open_opts, object)
+        }
+
+        fn object_builder(&self) -> &libbpf_rs::ObjectBuilder {
+            &self.obj_builder
+        }
+        fn object_builder_mut(&mut self) -> &mut libbpf_rs::ObjectBuilder {
+            &mut self.obj_builder
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    #[repr(C)]
+    pub struct StructOps {}
+
+    impl StructOps {}
+    pub mod types {
+        #[allow(unused_imports)]
+        use super::*;
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct __anon_1 {
+            pub r#type: *mut [i32; 27],
+            pub max_entries: *mut [i32; 262144],
+        }
+        impl Default for __anon_1 {
+            fn default() -> Self {
+                Self {
+                    r#type: std::ptr::null_mut(),
+                    max_entries: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct bpf_prog {
+            pub r#type: std::mem::MaybeUninit<bpf_prog_type>,
+            pub __pad_4: [u8; 4],
+            pub aux: *mut bpf_prog_aux,
+        }
+        impl Default for bpf_prog {
+            fn default() -> Self {
+                Self {
+                    r#type: std::mem::MaybeUninit::new(bpf_prog_type::default()),
+                    __pad_4: [u8::default(); 4],
+                    aux: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+        #[repr(u32)]
+        pub enum bpf_prog_type {
+            #[default]
+            BPF_PROG_TYPE_UNSPEC = 0,
+        }
+        #[derive(Debug, Default, Copy, Clone)]
+        #[repr(C)]
+        pub struct bpf_prog_aux {
+            pub id: u32,
+            pub name: [i8; 16],
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct license {
+            pub _license: [i8; 4],
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct maps {
+            pub events: __anon_1,
+        }
+    }
+    pub struct OpenAuditSkel<'obj> {
+        obj: OwnedRef<'obj, libbpf_rs::OpenObject>,
+        pub maps: OpenAuditMaps<'obj>,
+        pub progs: OpenAuditProgs<'obj>,
+        pub struct_ops: StructOps,
+        skel_config: libbpf_rs::__internal_skel::ObjectSkeletonConfig<'obj>,
+    }
+
+    impl<'obj> OpenSkel<'obj> for OpenAuditSkel<'obj> {
+        type Output = AuditSkel<'obj>;
+        fn load(self) -> libbpf_rs::Result<AuditSkel<'obj>> {
+            let skel_ptr = self.skel_config.as_libbpf_object().as_ptr();
+
+            let ret = unsafe { libbpf_sys::bpf_object__load_skeleton(skel_ptr) };
+            if ret != 0 {
+                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
+            }
+
+            let obj_ref = self.obj.take();
+            let open_obj = std::mem::replace(obj_ref, std::mem::MaybeUninit::uninit());
+            // SAFETY: `open_obj` is guaranteed to be properly
+            //         initialized as it came from an `OwnedRef`.
+            let obj_ptr = unsafe { open_obj.assume_init().take_ptr() };
+            // SAFETY: `obj_ptr` points to a loaded object after
+            //         skeleton load.
+            let obj = unsafe { libbpf_rs::Object::from_ptr(obj_ptr) };
+            // SAFETY: `OpenObject` and `Object` are guaranteed to
+            //         have the same memory layout.
+            let obj_ref = unsafe {
+                std::mem::transmute::<
+                    &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+                    &'obj mut std::mem::MaybeUninit<libbpf_rs::Object>,
+                >(obj_ref)
+            };
+            let _obj = obj_ref.write(obj);
+            // SAFETY: We just wrote initialized data to `obj_ref`.
+            let mut obj_ref = unsafe { OwnedRef::new(obj_ref) };
+
+            Ok(AuditSkel {
+                maps: unsafe { AuditMaps::new(&self.skel_config, obj_ref.as_mut())? },
+                progs: AuditProgs::new(self.progs),
+                obj: obj_ref,
+                struct_ops: self.struct_ops,
+                skel_config: self.skel_config,
+                links: AuditLinks::default(),
+            })
+        }
+
+        fn open_object(&self) -> &libbpf_rs::OpenObject {
+            self.obj.as_ref()
+        }
+
+        fn open_object_mut(&mut self) -> &mut libbpf_rs::OpenObject {
+            self.obj.as_mut()
+        }
+    }
+    #[derive(Default)]
+    pub struct AuditLinks {
+        pub audit_prog_load: Option<libbpf_rs::Link>,
+        pub audit_prog_unload: Option<libbpf_rs::Link>,
+    }
+    pub struct AuditSkel<'obj> {
+        obj: OwnedRef<'obj, libbpf_rs::Object>,
+        pub maps: AuditMaps<'obj>,
+        pub progs: AuditProgs<'obj>,
+        struct_ops: StructOps,
+        skel_config: libbpf_rs::__internal_skel::ObjectSkeletonConfig<'obj>,
+        pub links: AuditLinks,
+    }
+
+    unsafe impl Send for AuditSkel<'_> {}
+    unsafe impl Sync for AuditSkel<'_> {}
+
+    impl<'obj> Skel<'obj> for AuditSkel<'obj> {
+        fn object(&self) -> &libbpf_rs::Object {
+            self.obj.as_ref()
+        }
+
+        fn object_mut(&mut self) -> &mut libbpf_rs::Object {
+            self.obj.as_mut()
+        }
+        fn attach(&mut self) -> libbpf_rs::Result<()> {
+            let skel_ptr = self.skel_config.as_libbpf_object().as_ptr();
+            let ret = unsafe { libbpf_sys::bpf_object__attach_skeleton(skel_ptr) };
+            if ret != 0 {
+                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
+            }
+
+            self.links = AuditLinks {
+                audit_prog_load: core::ptr::NonNull::new(self.skel_config.prog_link_ptr(0)?)
+                    .map(|ptr| unsafe { libbpf_rs::Link::from_ptr(ptr) }),
+                audit_prog_unload: core::ptr::NonNull::new(self.skel_config.prog_link_ptr(1)?)
+                    .map(|ptr| unsafe { libbpf_rs::Link::from_ptr(ptr) }),
+            };
+
+            Ok(())
+        }
+    }
+    impl AuditSkel<'_> {
+        pub fn struct_ops_raw(&self) -> *const StructOps {
+            &self.struct_ops
+        }
+
+        pub fn struct_ops(&self) -> &StructOps {
+            &self.struct_ops
+        }
+    }
+    const DATA: &[u8] = &[
+        127, 69, 76, 70, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 247, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
+        0, 64, 0, 11, 0, 1, 0, 0, 46, 115, 116, 114, 116, 97, 98, 0, 46, 115, 121, 109, 116, 97,
+        98, 0, 102, 101, 110, 116, 114, 121, 47, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116,
+        95, 98, 112, 102, 95, 101, 118, 101, 110, 116, 0, 102, 101, 110, 116, 114, 121, 47, 98,
+        112, 102, 95, 112, 114, 111, 103, 95, 102, 114, 101, 101, 95, 105, 100, 0, 108, 105, 99,
+        101, 110, 115, 101, 0, 46, 109, 97, 112, 115, 0, 97, 117, 100, 105, 116, 46, 108, 108, 0,
+        76, 66, 66, 48, 95, 50, 0, 97, 117, 100, 105, 116, 95, 112, 114, 111, 103, 95, 108, 111,
+        97, 100, 0, 101, 118, 101, 110, 116, 115, 0, 97, 117, 100, 105, 116, 95, 112, 114, 111,
+        103, 95, 117, 110, 108, 111, 97, 100, 0, 95, 108, 105, 99, 101, 110, 115, 101, 0, 46, 114,
+        101, 108, 102, 101, 110, 116, 114, 121, 47, 112, 101, 114, 102, 95, 101, 118, 101, 110,
+        116, 95, 98, 112, 102, 95, 101, 118, 101, 110, 116, 0, 46, 114, 101, 108, 102, 101, 110,
+        116, 114, 121, 47, 98, 112, 102, 95, 112, 114, 111, 103, 95, 102, 114, 101, 101, 95, 105,
+        100, 0, 46, 66, 84, 70, 0, 46, 66, 84, 70, 46, 101, 120, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 4, 0, 241, 255, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 3, 0, 232, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 18, 0,
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 1, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 17, 0, 6, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 18, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        232, 1, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 17, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+        0, 0, 0, 121, 18, 8, 0, 0, 0, 0, 0, 86, 2, 59, 0, 1, 0, 0, 0, 121, 22, 0, 0, 0, 0, 0, 0,
+        183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 248, 255, 0, 0, 0, 0, 99, 26, 244, 255, 0, 0, 0, 0, 99,
+        26, 240, 255, 0, 0, 0, 0, 99, 26, 236, 255, 0, 0, 0, 0, 99, 26, 232, 255, 0, 0, 0, 0, 99,
+        26, 228, 255, 0, 0, 0, 0, 99, 26, 224, 255, 0, 0, 0, 0, 99, 26, 220, 255, 0, 0, 0, 0, 99,
+        26, 216, 255, 0, 0, 0, 0, 99, 26, 212, 255, 0, 0, 0, 0, 99, 26, 208, 255, 0, 0, 0, 0, 183,
+        1, 0, 0, 1, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0, 183, 1, 0, 0, 8, 0, 0, 0, 191, 99, 0,
+        0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 192, 255,
+        255, 255, 180, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0,
+        121, 163, 192, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1,
+        0, 0, 204, 255, 255, 255, 180, 2, 0, 0, 4, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 183, 1, 0,
+        0, 0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 208, 255,
+        255, 255, 180, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0,
+        133, 0, 0, 0, 14, 0, 0, 0, 119, 0, 0, 0, 32, 0, 0, 0, 99, 10, 212, 255, 0, 0, 0, 0, 133, 0,
+        0, 0, 15, 0, 0, 0, 99, 10, 216, 255, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0,
+        220, 255, 255, 255, 180, 2, 0, 0, 16, 0, 0, 0, 133, 0, 0, 0, 16, 0, 0, 0, 183, 1, 0, 0, 4,
+        0, 0, 0, 121, 163, 192, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0,
+        0, 7, 1, 0, 0, 236, 255, 255, 255, 180, 2, 0, 0, 16, 0, 0, 0, 133, 0, 0, 0, 115, 0, 0, 0,
+        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 200, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 183, 3, 0, 0, 52, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 130,
+        0, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 121, 22, 0, 0, 0, 0, 0, 0,
+        183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 248, 255, 0, 0, 0, 0, 99, 26, 244, 255, 0, 0, 0, 0, 99,
+        26, 240, 255, 0, 0, 0, 0, 99, 26, 236, 255, 0, 0, 0, 0, 99, 26, 232, 255, 0, 0, 0, 0, 99,
+        26, 228, 255, 0, 0, 0, 0, 99, 26, 224, 255, 0, 0, 0, 0, 99, 26, 220, 255, 0, 0, 0, 0, 99,
+        26, 216, 255, 0, 0, 0, 0, 99, 26, 212, 255, 0, 0, 0, 0, 99, 26, 208, 255, 0, 0, 0, 0, 183,
+        1, 0, 0, 2, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0, 183, 1, 0, 0, 8, 0, 0, 0, 191, 99, 0,
+        0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 192, 255,
+        255, 255, 180, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0,
+        121, 163, 192, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1,
+        0, 0, 204, 255, 255, 255, 180, 2, 0, 0, 4, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 183, 1, 0,
+        0, 0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 208, 255,
+        255, 255, 180, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0,
+        133, 0, 0, 0, 14, 0, 0, 0, 119, 0, 0, 0, 32, 0, 0, 0, 99, 10, 212, 255, 0, 0, 0, 0, 133, 0,
+        0, 0, 15, 0, 0, 0, 99, 10, 216, 255, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0,
+        220, 255, 255, 255, 180, 2, 0, 0, 16, 0, 0, 0, 133, 0, 0, 0, 16, 0, 0, 0, 183, 1, 0, 0, 4,
+        0, 0, 0, 121, 163, 192, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0,
+        0, 7, 1, 0, 0, 236, 255, 255, 255, 180, 2, 0, 0, 16, 0, 0, 0, 133, 0, 0, 0, 115, 0, 0, 0,
+        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 200, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 183, 3, 0, 0, 52, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 130,
+        0, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 71, 80, 76, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0,
+        0, 176, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 159, 235, 1, 0, 24, 0, 0, 0, 0, 0, 0,
+        0, 220, 1, 0, 0, 220, 1, 0, 0, 115, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 1, 0, 0,
+        0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4,
+        0, 0, 0, 27, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 0, 0, 4, 0, 0,
+        0, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 25, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 5, 0,
+        0, 0, 64, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 14, 7, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 10, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 13,
+        2, 0, 0, 0, 68, 0, 0, 0, 9, 0, 0, 0, 72, 0, 0, 0, 1, 0, 0, 12, 11, 0, 0, 0, 88, 0, 0, 0, 2,
+        0, 0, 4, 16, 0, 0, 0, 25, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 15, 0, 0, 0, 64,
+        0, 0, 0, 101, 0, 0, 0, 1, 0, 0, 6, 4, 0, 0, 0, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 2, 16, 0, 0, 0, 136, 0, 0, 0, 2, 0, 0, 4, 20, 0, 0, 0, 149, 0, 0, 0, 17, 0, 0, 0, 0, 0,
+        0, 0, 152, 0, 0, 0, 19, 0, 0, 0, 32, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0,
+        0, 0, 170, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 8, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+        18, 0, 0, 0, 4, 0, 0, 0, 16, 0, 0, 0, 175, 0, 0, 0, 1, 0, 0, 12, 11, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 3, 0, 0, 0, 0, 18, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 193, 0, 0, 0, 0, 0, 0, 14, 21,
+        0, 0, 0, 1, 0, 0, 0, 49, 1, 0, 0, 1, 0, 0, 15, 4, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 4, 0,
+        0, 0, 57, 1, 0, 0, 1, 0, 0, 15, 16, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 105,
+        110, 116, 0, 95, 95, 65, 82, 82, 65, 89, 95, 83, 73, 90, 69, 95, 84, 89, 80, 69, 95, 95, 0,
+        116, 121, 112, 101, 0, 109, 97, 120, 95, 101, 110, 116, 114, 105, 101, 115, 0, 101, 118,
+        101, 110, 116, 115, 0, 117, 110, 115, 105, 103, 110, 101, 100, 32, 108, 111, 110, 103, 32,
+        108, 111, 110, 103, 0, 99, 116, 120, 0, 97, 117, 100, 105, 116, 95, 112, 114, 111, 103, 95,
+        108, 111, 97, 100, 0, 98, 112, 102, 95, 112, 114, 111, 103, 0, 97, 117, 120, 0, 98, 112,
+        102, 95, 112, 114, 111, 103, 95, 116, 121, 112, 101, 0, 66, 80, 70, 95, 80, 82, 79, 71, 95,
+        84, 89, 80, 69, 95, 85, 78, 83, 80, 69, 67, 0, 98, 112, 102, 95, 112, 114, 111, 103, 95,
+        97, 117, 120, 0, 105, 100, 0, 110, 97, 109, 101, 0, 117, 110, 115, 105, 103, 110, 101, 100,
+        32, 105, 110, 116, 0, 99, 104, 97, 114, 0, 97, 117, 100, 105, 116, 95, 112, 114, 111, 103,
+        95, 117, 110, 108, 111, 97, 100, 0, 95, 108, 105, 99, 101, 110, 115, 101, 0, 47, 114, 111,
+        111, 116, 47, 99, 114, 97, 116, 101, 47, 115, 114, 99, 47, 98, 112, 102, 47, 97, 117, 100,
+        105, 116, 46, 98, 112, 102, 46, 99, 0, 105, 110, 116, 32, 97, 117, 100, 105, 116, 95, 112,
+        114, 111, 103, 95, 108, 111, 97, 100, 40, 95, 95, 117, 54, 52, 32, 42, 99, 116, 120, 41, 0,
+        83, 69, 67, 40, 34, 102, 101, 110, 116, 114, 121, 47, 98, 112, 102, 95, 112, 114, 111, 103,
+        95, 102, 114, 101, 101, 95, 105, 100, 34, 41, 0, 48, 58, 49, 0, 48, 58, 48, 0, 108, 105,
+        99, 101, 110, 115, 101, 0, 46, 109, 97, 112, 115, 0, 102, 101, 110, 116, 114, 121, 47, 112,
+        101, 114, 102, 95, 101, 118, 101, 110, 116, 95, 98, 112, 102, 95, 101, 118, 101, 110, 116,
+        0, 102, 101, 110, 116, 114, 121, 47, 98, 112, 102, 95, 112, 114, 111, 103, 95, 102, 114,
+        101, 101, 95, 105, 100, 0, 0, 159, 235, 1, 0, 32, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 36, 0,
+        0, 0, 52, 0, 0, 0, 88, 0, 0, 0, 148, 0, 0, 0, 8, 0, 0, 0, 63, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+        0, 12, 0, 0, 0, 91, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 16, 0, 0, 0, 63, 1, 0, 0,
+        1, 0, 0, 0, 0, 0, 0, 0, 202, 0, 0, 0, 234, 0, 0, 0, 0, 176, 0, 0, 91, 1, 0, 0, 1, 0, 0, 0,
+        0, 0, 0, 0, 202, 0, 0, 0, 10, 1, 0, 0, 0, 224, 0, 0, 16, 0, 0, 0, 63, 1, 0, 0, 4, 0, 0, 0,
+        136, 0, 0, 0, 13, 0, 0, 0, 41, 1, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 16, 0, 0, 0, 45, 1, 0, 0,
+        0, 0, 0, 0, 248, 0, 0, 0, 13, 0, 0, 0, 45, 1, 0, 0, 0, 0, 0, 0, 120, 1, 0, 0, 16, 0, 0, 0,
+        41, 1, 0, 0, 0, 0, 0, 0, 91, 1, 0, 0, 4, 0, 0, 0, 120, 0, 0, 0, 13, 0, 0, 0, 41, 1, 0, 0,
+        0, 0, 0, 0, 176, 0, 0, 0, 16, 0, 0, 0, 45, 1, 0, 0, 0, 0, 0, 0, 232, 0, 0, 0, 13, 0, 0, 0,
+        45, 1, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0, 16, 0, 0, 0, 41, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0,
+        0, 0, 0, 0, 0, 223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 32, 1, 0, 0, 0, 0, 0, 0, 216, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0,
+        0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 248, 1, 0, 0, 0, 0, 0, 0, 248, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 3, 0, 0, 0, 0, 0, 0, 232, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 1, 0, 0, 0,
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 216, 5, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0,
+        1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 224, 5, 0, 0, 0, 0, 0, 0, 16,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 149, 0, 0, 0, 9, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 5, 0, 0,
+        0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 16, 0,
+        0, 0, 0, 0, 0, 0, 181, 0, 0, 0, 9, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 6, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 8, 0, 0, 0, 0,
+        0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 209, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 16, 6, 0, 0, 0, 0, 0, 0, 103, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 214, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 9, 0, 0, 0, 0, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+}
@@ -0,0 +1,102 @@
+#include "vmlinux.h"
+#include "bpf_helpers.h"
+
+char _license[] SEC("license") = "GPL";
+
+#define MAX_SLOTS 64
+
+/* Entry timestamp of the current run of the measured program on this CPU */
+struct {
+	__uint(type, BPF_MAP_TYPE_PERCPU_ARRAY);
+	__uint(key_size, sizeof(__u32));
+	__uint(value_size, sizeof(__u64));
+	__uint(max_entries, 1);
+} start SEC(".maps");
+
+/* Number of runs per log2(runtime in ns) bucket */
+struct {
+	__uint(type, BPF_MAP_TYPE_PERCPU_ARRAY);
+	__uint(key_size, sizeof(__u32));
+	__uint(value_size, sizeof(__u64));
+	__uint(max_entries, MAX_SLOTS);
+} hist SEC(".maps");
+
+struct runtime {
+	__u64 run_time_ns;
+	__u64 run_cnt;
+};
+
+/* Runtime of the measured program accumulated on each CPU */
+struct {
+	__uint(type, BPF_MAP_TYPE_PERCPU_ARRAY);
+	__uint(key_size, sizeof(__u32));
+	__uint(value_size, sizeof(struct runtime));
+	__uint(max_entries, 1);
+} cpu_runtime SEC(".maps");
+
+static __always_inline __u32 log2(__u32 v)
+{
+	__u32 shift, r;
+
+	r = (v > 0xFFFF) << 4; v >>= r;
+	shift = (v > 0xFF) << 3; v >>= shift; r |= shift;
+	shift = (v > 0xF) << 2; v >>= shift; r |= shift;
+	shift = (v > 0x3) << 1; v >>= shift; r |= shift;
+	r |= (v >> 1);
+	return r;
+}
+
+static __always_inline __u32 log2l(__u64 v)
+{
+	__u32 hi = v >> 32;
+
+	if (hi)
+		return log2(hi) + 32;
+	return log2(v);
+}
+
+/* The attach target is set at runtime to the program being measured */
+SEC("fentry/XXX")
+int fentry_XXX(void *ctx)
+{
+	__u32 zero = 0;
+	__u64 *ts;
+
+	ts = bpf_map_lookup_elem(&start, &zero);
+	if (ts)
+		*ts = bpf_ktime_get_ns();
+
+	return 0;
+}
+
+SEC("fexit/XXX")
+int fexit_XXX(void *ctx)
+{
+	__u32 zero = 0, slot;
+	struct runtime *runtime;
+	__u64 *ts, *count, delta;
+
+	ts = bpf_map_lookup_elem(&start, &zero);
+	/* Skip runs that were already in flight when we attached */
+	if (!ts || !*ts)
+		return 0;
+
+	delta = bpf_ktime_get_ns() - *ts;
+	*ts = 0;
+
+	runtime = bpf_map_lookup_elem(&cpu_runtime, &zero);
+	if (runtime) {
+		runtime->run_time_ns += delta;
+		runtime->run_cnt += 1;
+	}
+
+	slot = log2l(delta);
+	if (slot >= MAX_SLOTS)
+		slot = MAX_SLOTS - 1;
+
+	count = bpf_map_lookup_elem(&hist, &slot);
+	if (count)
+		*count += 1;
+
+	return 0;
+}
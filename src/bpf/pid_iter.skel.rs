@@ -1,4972 +0,0 @@
-// SPDX-License-Identifier: (LGPL-2.1 OR BSD-2-Clause)
-//
-// THIS FILE IS AUTOGENERATED BY CARGO-LIBBPF-GEN!
-
-pub use self::imp::*;
-
-#[allow(dead_code)]
-#[allow(non_snake_case)]
-#[allow(non_camel_case_types)]
-#[allow(clippy::absolute_paths)]
-#[allow(clippy::upper_case_acronyms)]
-#[allow(clippy::zero_repeat_side_effects)]
-#[warn(single_use_lifetimes)]
-mod imp {
-    #[allow(unused_imports)]
-    use super::*;
-    use libbpf_rs::libbpf_sys;
-    use libbpf_rs::skel::OpenSkel;
-    use libbpf_rs::skel::Skel;
-    use libbpf_rs::skel::SkelBuilder;
-    use libbpf_rs::AsRawLibbpf as _;
-    use libbpf_rs::MapCore as _;
-    fn build_skel_config(
-    ) -> libbpf_rs::Result<libbpf_rs::__internal_skel::ObjectSkeletonConfig<'static>> {
-        let mut builder = libbpf_rs::__internal_skel::ObjectSkeletonConfigBuilder::new(DATA);
-        builder.name("pid_iter_bpf").prog("bpftop_iter");
-        builder.build()
-    }
-    pub struct OpenPidIterMaps<'obj> {
-        _phantom: std::marker::PhantomData<&'obj ()>,
-    }
-
-    impl<'obj> OpenPidIterMaps<'obj> {
-        #[allow(unused_variables)]
-        unsafe fn new(
-            config: &libbpf_rs::__internal_skel::ObjectSkeletonConfig<'_>,
-            object: &mut libbpf_rs::OpenObject,
-        ) -> libbpf_rs::Result<Self> {
-            let object = unsafe {
-                std::mem::transmute::<&mut libbpf_rs::OpenObject, &'obj mut libbpf_rs::OpenObject>(
-                    object,
-                )
-            };
-            #[allow(clippy::never_loop)]
-            for map in object.maps_mut() {
-                let name = map.name().to_str().ok_or_else(|| {
-                    libbpf_rs::Error::from(std::io::Error::new(
-                        std::io::ErrorKind::InvalidData,
-                        "map has invalid name",
-                    ))
-                })?;
-                #[allow(clippy::match_single_binding)]
-                match name {
-                    _ => panic!("encountered unexpected map: `{name}`"),
-                }
-            }
-
-            let slf = Self {
-                _phantom: std::marker::PhantomData,
-            };
-            Ok(slf)
-        }
-    }
-    pub struct PidIterMaps<'obj> {
-        _phantom: std::marker::PhantomData<&'obj ()>,
-    }
-
-    impl<'obj> PidIterMaps<'obj> {
-        #[allow(unused_variables)]
-        unsafe fn new(
-            config: &libbpf_rs::__internal_skel::ObjectSkeletonConfig<'_>,
-            object: &mut libbpf_rs::Object,
-        ) -> libbpf_rs::Result<Self> {
-            let object = unsafe {
-                std::mem::transmute::<&mut libbpf_rs::Object, &'obj mut libbpf_rs::Object>(object)
-            };
-            #[allow(clippy::never_loop)]
-            for map in object.maps_mut() {
-                let name = map.name().to_str().ok_or_else(|| {
-                    libbpf_rs::Error::from(std::io::Error::new(
-                        std::io::ErrorKind::InvalidData,
-                        "map has invalid name",
-                    ))
-                })?;
-                #[allow(clippy::match_single_binding)]
-                match name {
-                    _ => panic!("encountered unexpected map: `{name}`"),
-                }
-            }
-
-            let slf = Self {
-                _phantom: std::marker::PhantomData,
-            };
-            Ok(slf)
-        }
-    }
-    pub struct OpenPidIterProgs<'obj> {
-        pub bpftop_iter: libbpf_rs::OpenProgramMut<'obj>,
-        _phantom: std::marker::PhantomData<&'obj ()>,
-    }
-
-    impl<'obj> OpenPidIterProgs<'obj> {
-        unsafe fn new(object: &mut libbpf_rs::OpenObject) -> libbpf_rs::Result<Self> {
-            let mut bpftop_iter = None;
-            let object = unsafe {
-                std::mem::transmute::<&mut libbpf_rs::OpenObject, &'obj mut libbpf_rs::OpenObject>(
-                    object,
-                )
-            };
-            for prog in object.progs_mut() {
-                let name = prog.name().to_str().ok_or_else(|| {
-                    libbpf_rs::Error::from(std::io::Error::new(
-                        std::io::ErrorKind::InvalidData,
-                        "prog has invalid name",
-                    ))
-                })?;
-                match name {
-                    "bpftop_iter" => bpftop_iter = Some(prog),
-                    _ => panic!("encountered unexpected prog: `{name}`"),
-                }
-            }
-
-            let slf = Self {
-                bpftop_iter: bpftop_iter.expect("prog `bpftop_iter` not present"),
-                _phantom: std::marker::PhantomData,
-            };
-            Ok(slf)
-        }
-    }
-    pub struct PidIterProgs<'obj> {
-        pub bpftop_iter: libbpf_rs::ProgramMut<'obj>,
-        _phantom: std::marker::PhantomData<&'obj ()>,
-    }
-
-    impl<'obj> PidIterProgs<'obj> {
-        #[allow(unused_variables)]
-        fn new(open_progs: OpenPidIterProgs<'obj>) -> Self {
-            Self {
-                bpftop_iter: unsafe {
-                    libbpf_rs::ProgramMut::new_mut(
-                        open_progs.bpftop_iter.as_libbpf_object().as_mut(),
-                    )
-                },
-                _phantom: std::marker::PhantomData,
-            }
-        }
-    }
-    struct OwnedRef<'obj, O> {
-        object: Option<&'obj mut std::mem::MaybeUninit<O>>,
-    }
-
-    impl<'obj, O> OwnedRef<'obj, O> {
-        /// # Safety
-        /// The object has to be initialized.
-        unsafe fn new(object: &'obj mut std::mem::MaybeUninit<O>) -> Self {
-            Self {
-                object: Some(object),
-            }
-        }
-
-        fn as_ref(&self) -> &O {
-            // SAFETY: As per the contract during construction, the
-            //         object has to be initialized.
-            unsafe { self.object.as_ref().unwrap().assume_init_ref() }
-        }
-
-        fn as_mut(&mut self) -> &mut O {
-            // SAFETY: As per the contract during construction, the
-            //         object has to be initialized.
-            unsafe { self.object.as_mut().unwrap().assume_init_mut() }
-        }
-
-        fn take(mut self) -> &'obj mut std::mem::MaybeUninit<O> {
-            self.object.take().unwrap()
-        }
-    }
-
-    impl<O> Drop for OwnedRef<'_, O> {
-        fn drop(&mut self) {
-            if let Some(object) = &mut self.object {
-                unsafe { object.assume_init_drop() }
-            }
-        }
-    }
-
-    #[derive(Default)]
-    pub struct PidIterSkelBuilder {
-        pub obj_builder: libbpf_rs::ObjectBuilder,
-    }
-
-    impl<'obj> PidIterSkelBuilder {
-        fn open_opts_impl(
-            self,
-            open_opts: *const libbpf_sys::bpf_object_open_opts,
-            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
-        ) -> libbpf_rs::Result<OpenPidIterSkel<'obj>> {
-            let skel_config = build_skel_config()?;
-            let skel_ptr = skel_config.as_libbpf_object();
-
-            let ret =
-                unsafe { libbpf_sys::bpf_object__open_skeleton(skel_ptr.as_ptr(), open_opts) };
-            if ret != 0 {
-                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
-            }
-
-            // SAFETY: `skel_ptr` points to a valid object after the
-            //         open call.
-            let obj_ptr = unsafe { *skel_ptr.as_ref().obj };
-            // SANITY: `bpf_object__open_skeleton` should have
-            //         allocated the object.
-            let obj_ptr = std::ptr::NonNull::new(obj_ptr).unwrap();
-            // SAFETY: `obj_ptr` points to an opened object after
-            //         skeleton open.
-            let obj = unsafe { libbpf_rs::OpenObject::from_ptr(obj_ptr) };
-            let _obj = object.write(obj);
-            // SAFETY: We just wrote initialized data to `object`.
-            let mut obj_ref = unsafe { OwnedRef::new(object) };
-
-            #[allow(unused_mut)]
-            let mut skel = OpenPidIterSkel {
-                maps: unsafe { OpenPidIterMaps::new(&skel_config, obj_ref.as_mut())? },
-                progs: unsafe { OpenPidIterProgs::new(obj_ref.as_mut())? },
-                obj: obj_ref,
-                // SAFETY: Our `struct_ops` type contains only pointers,
-                //         which are allowed to be NULL.
-                // TODO: Generate and use a `Default` representation
-                //       instead, to cut down on unsafe code.
-                struct_ops: unsafe { std::mem::zeroed() },
-                skel_config,
-            };
-
-            Ok(skel)
-        }
-    }
-
-    impl<'obj> SkelBuilder<'obj> for PidIterSkelBuilder {
-        type Output = OpenPidIterSkel<'obj>;
-        fn open(
-            self,
-            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
-        ) -> libbpf_rs::Result<OpenPidIterSkel<'obj>> {
-            self.open_opts_impl(std::ptr::null(), object)
-        }
-
-        fn open_opts(
-            self,
-            open_opts: libbpf_sys::bpf_object_open_opts,
-            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
-        ) -> libbpf_rs::Result<OpenPidIterSkel<'obj>> {
-            self.open_opts_impl(&open_opts, object)
-        }
-
-        fn object_builder(&self) -> &libbpf_rs::ObjectBuilder {
-            &self.obj_builder
-        }
-        fn object_builder_mut(&mut self) -> &mut libbpf_rs::ObjectBuilder {
-            &mut self.obj_builder
-        }
-    }
-
-    #[derive(Debug, Clone)]
-    #[repr(C)]
-    pub struct StructOps {}
-
-    impl StructOps {}
-    pub mod types {
-        #[allow(unused_imports)]
-        use super::*;
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct bpf_iter__task_file {
-            pub __anon_1: __anon_1,
-            pub __anon_2: __anon_2,
-            pub fd: u32,
-            pub __pad_20: [u8; 4],
-            pub __anon_3: __anon_3,
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_1 {
-            pub meta: *mut bpf_iter_meta,
-        }
-        impl std::fmt::Debug for __anon_1 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_1 {
-            fn default() -> Self {
-                Self {
-                    meta: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_2 {
-            pub task: *mut task_struct,
-        }
-        impl std::fmt::Debug for __anon_2 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_2 {
-            fn default() -> Self {
-                Self {
-                    task: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_3 {
-            pub file: *mut file,
-        }
-        impl std::fmt::Debug for __anon_3 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_3 {
-            fn default() -> Self {
-                Self {
-                    file: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct bpf_iter_meta {
-            pub __anon_4: __anon_4,
-            pub session_id: u64,
-            pub seq_num: u64,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct task_struct {
-            pub thread_info: thread_info,
-            pub state: i64,
-            pub stack: *mut std::ffi::c_void,
-            pub usage: refcount_struct,
-            pub flags: u32,
-            pub ptrace: u32,
-            pub on_cpu: i32,
-            pub wake_entry: __call_single_node,
-            pub cpu: u32,
-            pub wakee_flips: u32,
-            pub wakee_flip_decay_ts: u64,
-            pub last_wakee: *mut task_struct,
-            pub recent_used_cpu: i32,
-            pub wake_cpu: i32,
-            pub on_rq: i32,
-            pub prio: i32,
-            pub static_prio: i32,
-            pub normal_prio: i32,
-            pub rt_priority: u32,
-            pub __pad_116: [u8; 4],
-            pub sched_class: *mut std::ffi::c_void,
-            pub se: sched_entity,
-            pub rt: sched_rt_entity,
-            pub sched_task_group: *mut std::ffi::c_void,
-            pub dl: sched_dl_entity,
-            pub uclamp_req: [uclamp_se; 2],
-            pub uclamp: [uclamp_se; 2],
-            pub preempt_notifiers: hlist_head,
-            pub btrace_seq: u32,
-            pub policy: u32,
-            pub nr_cpus_allowed: i32,
-            pub __pad_884: [u8; 4],
-            pub cpus_ptr: *mut cpumask,
-            pub cpus_mask: cpumask,
-            pub rcu_read_lock_nesting: i32,
-            pub rcu_read_unlock_special: rcu_special,
-            pub rcu_node_entry: list_head,
-            pub rcu_blocked_node: *mut std::ffi::c_void,
-            pub rcu_tasks_nvcsw: u64,
-            pub rcu_tasks_holdout: u8,
-            pub rcu_tasks_idx: u8,
-            pub rcu_tasks_idle_cpu: i32,
-            pub rcu_tasks_holdout_list: list_head,
-            pub sched_info: sched_info,
-            pub tasks: list_head,
-            pub pushable_tasks: plist_node,
-            pub pushable_dl_tasks: rb_node,
-            pub mm: *mut std::ffi::c_void,
-            pub active_mm: *mut std::ffi::c_void,
-            pub vmacache: vmacache,
-            pub rss_stat: task_rss_stat,
-            pub exit_state: i32,
-            pub exit_code: i32,
-            pub exit_signal: i32,
-            pub pdeath_signal: i32,
-            pub __pad_1204: [u8; 4],
-            pub jobctl: u64,
-            pub personality: u32,
-            pub __pad_1220: [u8; 12],
-            pub atomic_flags: u64,
-            pub restart_block: restart_block,
-            pub pid: i32,
-            pub tgid: i32,
-            pub stack_canary: u64,
-            pub real_parent: *mut task_struct,
-            pub parent: *mut task_struct,
-            pub children: list_head,
-            pub sibling: list_head,
-            pub group_leader: *mut task_struct,
-            pub ptraced: list_head,
-            pub ptrace_entry: list_head,
-            pub thread_pid: *mut std::ffi::c_void,
-            pub pid_links: [hlist_node; 4],
-            pub thread_group: list_head,
-            pub thread_node: list_head,
-            pub vfork_done: *mut std::ffi::c_void,
-            pub set_child_tid: *mut i32,
-            pub clear_child_tid: *mut i32,
-            pub utime: u64,
-            pub stime: u64,
-            pub gtime: u64,
-            pub prev_cputime: prev_cputime,
-            pub nvcsw: u64,
-            pub nivcsw: u64,
-            pub start_time: u64,
-            pub start_boottime: u64,
-            pub min_flt: u64,
-            pub maj_flt: u64,
-            pub posix_cputimers: posix_cputimers,
-            pub ptracer_cred: *mut cred,
-            pub real_cred: *mut cred,
-            pub cred: *mut cred,
-            pub cached_requested_key: *mut std::ffi::c_void,
-            pub comm: [i8; 16],
-            pub nameidata: *mut std::ffi::c_void,
-            pub sysvsem: sysv_sem,
-            pub sysvshm: sysv_shm,
-            pub last_switch_count: u64,
-            pub last_switch_time: u64,
-            pub fs: *mut std::ffi::c_void,
-            pub files: *mut std::ffi::c_void,
-            pub nsproxy: *mut std::ffi::c_void,
-            pub signal: *mut std::ffi::c_void,
-            pub sighand: *mut std::ffi::c_void,
-            pub blocked: __anon_5,
-            pub real_blocked: __anon_5,
-            pub saved_sigmask: __anon_5,
-            pub pending: sigpending,
-            pub sas_ss_sp: u64,
-            pub sas_ss_size: u64,
-            pub sas_ss_flags: u32,
-            pub __pad_1900: [u8; 4],
-            pub task_works: *mut callback_head,
-            pub audit_context: *mut std::ffi::c_void,
-            pub loginuid: __anon_6,
-            pub sessionid: u32,
-            pub seccomp: seccomp,
-            pub parent_exec_id: u64,
-            pub self_exec_id: u64,
-            pub alloc_lock: spinlock,
-            pub pi_lock: raw_spinlock,
-            pub wake_q: wake_q_node,
-            pub pi_waiters: rb_root_cached,
-            pub pi_top_task: *mut task_struct,
-            pub pi_blocked_on: *mut std::ffi::c_void,
-            pub journal_info: *mut std::ffi::c_void,
-            pub bio_list: *mut std::ffi::c_void,
-            pub plug: *mut std::ffi::c_void,
-            pub reclaim_state: *mut std::ffi::c_void,
-            pub backing_dev_info: *mut std::ffi::c_void,
-            pub io_context: *mut std::ffi::c_void,
-            pub capture_control: *mut std::ffi::c_void,
-            pub ptrace_message: u64,
-            pub last_siginfo: *mut std::ffi::c_void,
-            pub ioac: task_io_accounting,
-            pub psi_flags: u32,
-            pub __pad_2140: [u8; 4],
-            pub acct_rss_mem1: u64,
-            pub acct_vm_mem1: u64,
-            pub acct_timexpd: u64,
-            pub mems_allowed: __anon_7,
-            pub mems_allowed_seq: seqcount,
-            pub cpuset_mem_spread_rotor: i32,
-            pub cpuset_slab_spread_rotor: i32,
-            pub __pad_2188: [u8; 4],
-            pub cgroups: *mut std::ffi::c_void,
-            pub cg_list: list_head,
-            pub closid: u32,
-            pub rmid: u32,
-            pub robust_list: *mut std::ffi::c_void,
-            pub compat_robust_list: *mut std::ffi::c_void,
-            pub pi_state_list: list_head,
-            pub pi_state_cache: *mut std::ffi::c_void,
-            pub futex_exit_mutex: mutex,
-            pub futex_state: u32,
-            pub __pad_2300: [u8; 4],
-            pub perf_event_ctxp: [*mut perf_event_context; 2],
-            pub perf_event_mutex: mutex,
-            pub perf_event_list: list_head,
-            pub preempt_disable_ip: u64,
-            pub mempolicy: *mut std::ffi::c_void,
-            pub il_prev: i16,
-            pub pref_node_fork: i16,
-            pub numa_scan_seq: i32,
-            pub numa_scan_period: u32,
-            pub numa_scan_period_max: u32,
-            pub numa_preferred_nid: i32,
-            pub __pad_2404: [u8; 4],
-            pub numa_migrate_retry: u64,
-            pub node_stamp: u64,
-            pub last_task_numa_placement: u64,
-            pub last_sum_exec_runtime: u64,
-            pub numa_work: callback_head,
-            pub numa_group: *mut std::ffi::c_void,
-            pub numa_faults: *mut u64,
-            pub total_numa_faults: u64,
-            pub numa_faults_locality: [u64; 3],
-            pub numa_pages_migrated: u64,
-            pub rseq: *mut std::ffi::c_void,
-            pub rseq_sig: u32,
-            pub __pad_2524: [u8; 4],
-            pub rseq_event_mask: u64,
-            pub tlb_ubc: tlbflush_unmap_batch,
-            pub __anon_8: __anon_8,
-            pub splice_pipe: *mut std::ffi::c_void,
-            pub task_frag: page_frag,
-            pub delays: *mut std::ffi::c_void,
-            pub nr_dirtied: i32,
-            pub nr_dirtied_pause: i32,
-            pub dirty_paused_when: u64,
-            pub latency_record_count: i32,
-            pub __pad_2652: [u8; 4],
-            pub latency_record: [latency_record; 32],
-            pub timer_slack_ns: u64,
-            pub default_timer_slack_ns: u64,
-            pub curr_ret_stack: i32,
-            pub curr_ret_depth: i32,
-            pub ret_stack: *mut std::ffi::c_void,
-            pub ftrace_timestamp: u64,
-            pub trace_overrun: __anon_9,
-            pub tracing_graph_pause: __anon_9,
-            pub trace: u64,
-            pub trace_recursion: u64,
-            pub memcg_in_oom: *mut std::ffi::c_void,
-            pub memcg_oom_gfp_mask: u32,
-            pub memcg_oom_order: i32,
-            pub memcg_nr_pages_over_high: u32,
-            pub __pad_6580: [u8; 4],
-            pub active_memcg: *mut std::ffi::c_void,
-            pub throttle_queue: *mut std::ffi::c_void,
-            pub utask: *mut std::ffi::c_void,
-            pub sequential_io: u32,
-            pub sequential_io_avg: u32,
-            pub pagefault_disabled: i32,
-            pub __pad_6620: [u8; 4],
-            pub oom_reaper_list: *mut task_struct,
-            pub stack_vm_area: *mut std::ffi::c_void,
-            pub stack_refcount: refcount_struct,
-            pub __pad_6644: [u8; 4],
-            pub security: *mut std::ffi::c_void,
-            pub mce_addr: u64,
-            pub __pad_6664: [u8; 8],
-            pub mce_kill_me: callback_head,
-            pub __pad_6688: [u8; 32],
-            pub thread: thread_struct,
-        }
-        impl Default for task_struct {
-            fn default() -> Self {
-                Self {
-                    thread_info: thread_info::default(),
-                    state: i64::default(),
-                    stack: std::ptr::null_mut(),
-                    usage: refcount_struct::default(),
-                    flags: u32::default(),
-                    ptrace: u32::default(),
-                    on_cpu: i32::default(),
-                    wake_entry: __call_single_node::default(),
-                    cpu: u32::default(),
-                    wakee_flips: u32::default(),
-                    wakee_flip_decay_ts: u64::default(),
-                    last_wakee: std::ptr::null_mut(),
-                    recent_used_cpu: i32::default(),
-                    wake_cpu: i32::default(),
-                    on_rq: i32::default(),
-                    prio: i32::default(),
-                    static_prio: i32::default(),
-                    normal_prio: i32::default(),
-                    rt_priority: u32::default(),
-                    __pad_116: [u8::default(); 4],
-                    sched_class: std::ptr::null_mut(),
-                    se: sched_entity::default(),
-                    rt: sched_rt_entity::default(),
-                    sched_task_group: std::ptr::null_mut(),
-                    dl: sched_dl_entity::default(),
-                    uclamp_req: [uclamp_se::default(); 2],
-                    uclamp: [uclamp_se::default(); 2],
-                    preempt_notifiers: hlist_head::default(),
-                    btrace_seq: u32::default(),
-                    policy: u32::default(),
-                    nr_cpus_allowed: i32::default(),
-                    __pad_884: [u8::default(); 4],
-                    cpus_ptr: std::ptr::null_mut(),
-                    cpus_mask: cpumask::default(),
-                    rcu_read_lock_nesting: i32::default(),
-                    rcu_read_unlock_special: rcu_special::default(),
-                    rcu_node_entry: list_head::default(),
-                    rcu_blocked_node: std::ptr::null_mut(),
-                    rcu_tasks_nvcsw: u64::default(),
-                    rcu_tasks_holdout: u8::default(),
-                    rcu_tasks_idx: u8::default(),
-                    rcu_tasks_idle_cpu: i32::default(),
-                    rcu_tasks_holdout_list: list_head::default(),
-                    sched_info: sched_info::default(),
-                    tasks: list_head::default(),
-                    pushable_tasks: plist_node::default(),
-                    pushable_dl_tasks: rb_node::default(),
-                    mm: std::ptr::null_mut(),
-                    active_mm: std::ptr::null_mut(),
-                    vmacache: vmacache::default(),
-                    rss_stat: task_rss_stat::default(),
-                    exit_state: i32::default(),
-                    exit_code: i32::default(),
-                    exit_signal: i32::default(),
-                    pdeath_signal: i32::default(),
-                    __pad_1204: [u8::default(); 4],
-                    jobctl: u64::default(),
-                    personality: u32::default(),
-                    __pad_1220: [u8::default(); 12],
-                    atomic_flags: u64::default(),
-                    restart_block: restart_block::default(),
-                    pid: i32::default(),
-                    tgid: i32::default(),
-                    stack_canary: u64::default(),
-                    real_parent: std::ptr::null_mut(),
-                    parent: std::ptr::null_mut(),
-                    children: list_head::default(),
-                    sibling: list_head::default(),
-                    group_leader: std::ptr::null_mut(),
-                    ptraced: list_head::default(),
-                    ptrace_entry: list_head::default(),
-                    thread_pid: std::ptr::null_mut(),
-                    pid_links: [hlist_node::default(); 4],
-                    thread_group: list_head::default(),
-                    thread_node: list_head::default(),
-                    vfork_done: std::ptr::null_mut(),
-                    set_child_tid: std::ptr::null_mut(),
-                    clear_child_tid: std::ptr::null_mut(),
-                    utime: u64::default(),
-                    stime: u64::default(),
-                    gtime: u64::default(),
-                    prev_cputime: prev_cputime::default(),
-                    nvcsw: u64::default(),
-                    nivcsw: u64::default(),
-                    start_time: u64::default(),
-                    start_boottime: u64::default(),
-                    min_flt: u64::default(),
-                    maj_flt: u64::default(),
-                    posix_cputimers: posix_cputimers::default(),
-                    ptracer_cred: std::ptr::null_mut(),
-                    real_cred: std::ptr::null_mut(),
-                    cred: std::ptr::null_mut(),
-                    cached_requested_key: std::ptr::null_mut(),
-                    comm: [i8::default(); 16],
-                    nameidata: std::ptr::null_mut(),
-                    sysvsem: sysv_sem::default(),
-                    sysvshm: sysv_shm::default(),
-                    last_switch_count: u64::default(),
-                    last_switch_time: u64::default(),
-                    fs: std::ptr::null_mut(),
-                    files: std::ptr::null_mut(),
-                    nsproxy: std::ptr::null_mut(),
-                    signal: std::ptr::null_mut(),
-                    sighand: std::ptr::null_mut(),
-                    blocked: __anon_5::default(),
-                    real_blocked: __anon_5::default(),
-                    saved_sigmask: __anon_5::default(),
-                    pending: sigpending::default(),
-                    sas_ss_sp: u64::default(),
-                    sas_ss_size: u64::default(),
-                    sas_ss_flags: u32::default(),
-                    __pad_1900: [u8::default(); 4],
-                    task_works: std::ptr::null_mut(),
-                    audit_context: std::ptr::null_mut(),
-                    loginuid: __anon_6::default(),
-                    sessionid: u32::default(),
-                    seccomp: seccomp::default(),
-                    parent_exec_id: u64::default(),
-                    self_exec_id: u64::default(),
-                    alloc_lock: spinlock::default(),
-                    pi_lock: raw_spinlock::default(),
-                    wake_q: wake_q_node::default(),
-                    pi_waiters: rb_root_cached::default(),
-                    pi_top_task: std::ptr::null_mut(),
-                    pi_blocked_on: std::ptr::null_mut(),
-                    journal_info: std::ptr::null_mut(),
-                    bio_list: std::ptr::null_mut(),
-                    plug: std::ptr::null_mut(),
-                    reclaim_state: std::ptr::null_mut(),
-                    backing_dev_info: std::ptr::null_mut(),
-                    io_context: std::ptr::null_mut(),
-                    capture_control: std::ptr::null_mut(),
-                    ptrace_message: u64::default(),
-                    last_siginfo: std::ptr::null_mut(),
-                    ioac: task_io_accounting::default(),
-                    psi_flags: u32::default(),
-                    __pad_2140: [u8::default(); 4],
-                    acct_rss_mem1: u64::default(),
-                    acct_vm_mem1: u64::default(),
-                    acct_timexpd: u64::default(),
-                    mems_allowed: __anon_7::default(),
-                    mems_allowed_seq: seqcount::default(),
-                    cpuset_mem_spread_rotor: i32::default(),
-                    cpuset_slab_spread_rotor: i32::default(),
-                    __pad_2188: [u8::default(); 4],
-                    cgroups: std::ptr::null_mut(),
-                    cg_list: list_head::default(),
-                    closid: u32::default(),
-                    rmid: u32::default(),
-                    robust_list: std::ptr::null_mut(),
-                    compat_robust_list: std::ptr::null_mut(),
-                    pi_state_list: list_head::default(),
-                    pi_state_cache: std::ptr::null_mut(),
-                    futex_exit_mutex: mutex::default(),
-                    futex_state: u32::default(),
-                    __pad_2300: [u8::default(); 4],
-                    perf_event_ctxp: [std::ptr::null_mut(); 2],
-                    perf_event_mutex: mutex::default(),
-                    perf_event_list: list_head::default(),
-                    preempt_disable_ip: u64::default(),
-                    mempolicy: std::ptr::null_mut(),
-                    il_prev: i16::default(),
-                    pref_node_fork: i16::default(),
-                    numa_scan_seq: i32::default(),
-                    numa_scan_period: u32::default(),
-                    numa_scan_period_max: u32::default(),
-                    numa_preferred_nid: i32::default(),
-                    __pad_2404: [u8::default(); 4],
-                    numa_migrate_retry: u64::default(),
-                    node_stamp: u64::default(),
-                    last_task_numa_placement: u64::default(),
-                    last_sum_exec_runtime: u64::default(),
-                    numa_work: callback_head::default(),
-                    numa_group: std::ptr::null_mut(),
-                    numa_faults: std::ptr::null_mut(),
-                    total_numa_faults: u64::default(),
-                    numa_faults_locality: [u64::default(); 3],
-                    numa_pages_migrated: u64::default(),
-                    rseq: std::ptr::null_mut(),
-                    rseq_sig: u32::default(),
-                    __pad_2524: [u8::default(); 4],
-                    rseq_event_mask: u64::default(),
-                    tlb_ubc: tlbflush_unmap_batch::default(),
-                    __anon_8: __anon_8::default(),
-                    splice_pipe: std::ptr::null_mut(),
-                    task_frag: page_frag::default(),
-                    delays: std::ptr::null_mut(),
-                    nr_dirtied: i32::default(),
-                    nr_dirtied_pause: i32::default(),
-                    dirty_paused_when: u64::default(),
-                    latency_record_count: i32::default(),
-                    __pad_2652: [u8::default(); 4],
-                    latency_record: [latency_record::default(); 32],
-                    timer_slack_ns: u64::default(),
-                    default_timer_slack_ns: u64::default(),
-                    curr_ret_stack: i32::default(),
-                    curr_ret_depth: i32::default(),
-                    ret_stack: std::ptr::null_mut(),
-                    ftrace_timestamp: u64::default(),
-                    trace_overrun: __anon_9::default(),
-                    tracing_graph_pause: __anon_9::default(),
-                    trace: u64::default(),
-                    trace_recursion: u64::default(),
-                    memcg_in_oom: std::ptr::null_mut(),
-                    memcg_oom_gfp_mask: u32::default(),
-                    memcg_oom_order: i32::default(),
-                    memcg_nr_pages_over_high: u32::default(),
-                    __pad_6580: [u8::default(); 4],
-                    active_memcg: std::ptr::null_mut(),
-                    throttle_queue: std::ptr::null_mut(),
-                    utask: std::ptr::null_mut(),
-                    sequential_io: u32::default(),
-                    sequential_io_avg: u32::default(),
-                    pagefault_disabled: i32::default(),
-                    __pad_6620: [u8::default(); 4],
-                    oom_reaper_list: std::ptr::null_mut(),
-                    stack_vm_area: std::ptr::null_mut(),
-                    stack_refcount: refcount_struct::default(),
-                    __pad_6644: [u8::default(); 4],
-                    security: std::ptr::null_mut(),
-                    mce_addr: u64::default(),
-                    __pad_6664: [u8::default(); 8],
-                    mce_kill_me: callback_head::default(),
-                    __pad_6688: [u8::default(); 32],
-                    thread: thread_struct::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct file {
-            pub f_u: __anon_10,
-            pub f_path: path,
-            pub f_inode: *mut std::ffi::c_void,
-            pub f_op: *mut std::ffi::c_void,
-            pub f_lock: spinlock,
-            pub f_write_hint: std::mem::MaybeUninit<rw_hint>,
-            pub f_count: __anon_11,
-            pub f_flags: u32,
-            pub f_mode: u32,
-            pub f_pos_lock: mutex,
-            pub f_pos: i64,
-            pub f_owner: fown_struct,
-            pub f_cred: *mut cred,
-            pub f_ra: file_ra_state,
-            pub f_version: u64,
-            pub f_security: *mut std::ffi::c_void,
-            pub private_data: *mut std::ffi::c_void,
-            pub f_ep_links: list_head,
-            pub f_tfile_llink: list_head,
-            pub f_mapping: *mut std::ffi::c_void,
-            pub f_wb_err: u32,
-            pub f_sb_err: u32,
-        }
-        impl Default for file {
-            fn default() -> Self {
-                Self {
-                    f_u: __anon_10::default(),
-                    f_path: path::default(),
-                    f_inode: std::ptr::null_mut(),
-                    f_op: std::ptr::null_mut(),
-                    f_lock: spinlock::default(),
-                    f_write_hint: std::mem::MaybeUninit::new(rw_hint::default()),
-                    f_count: __anon_11::default(),
-                    f_flags: u32::default(),
-                    f_mode: u32::default(),
-                    f_pos_lock: mutex::default(),
-                    f_pos: i64::default(),
-                    f_owner: fown_struct::default(),
-                    f_cred: std::ptr::null_mut(),
-                    f_ra: file_ra_state::default(),
-                    f_version: u64::default(),
-                    f_security: std::ptr::null_mut(),
-                    private_data: std::ptr::null_mut(),
-                    f_ep_links: list_head::default(),
-                    f_tfile_llink: list_head::default(),
-                    f_mapping: std::ptr::null_mut(),
-                    f_wb_err: u32::default(),
-                    f_sb_err: u32::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_4 {
-            pub seq: *mut std::ffi::c_void,
-        }
-        impl std::fmt::Debug for __anon_4 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_4 {
-            fn default() -> Self {
-                Self {
-                    seq: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct thread_info {
-            pub flags: u64,
-            pub status: u32,
-            pub __pad_12: [u8; 4],
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct refcount_struct {
-            pub refs: __anon_9,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __call_single_node {
-            pub llist: llist_node,
-            pub __anon_12: __anon_12,
-            pub __pad_12: [u8; 4],
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct sched_entity {
-            pub load: load_weight,
-            pub run_node: rb_node,
-            pub group_node: list_head,
-            pub on_rq: u32,
-            pub __pad_60: [u8; 4],
-            pub exec_start: u64,
-            pub sum_exec_runtime: u64,
-            pub vruntime: u64,
-            pub prev_sum_exec_runtime: u64,
-            pub nr_migrations: u64,
-            pub statistics: sched_statistics,
-            pub depth: i32,
-            pub __pad_324: [u8; 4],
-            pub parent: *mut sched_entity,
-            pub cfs_rq: *mut std::ffi::c_void,
-            pub my_q: *mut std::ffi::c_void,
-            pub runnable_weight: u64,
-            pub __pad_360: [u8; 24],
-            pub avg: sched_avg,
-        }
-        impl Default for sched_entity {
-            fn default() -> Self {
-                Self {
-                    load: load_weight::default(),
-                    run_node: rb_node::default(),
-                    group_node: list_head::default(),
-                    on_rq: u32::default(),
-                    __pad_60: [u8::default(); 4],
-                    exec_start: u64::default(),
-                    sum_exec_runtime: u64::default(),
-                    vruntime: u64::default(),
-                    prev_sum_exec_runtime: u64::default(),
-                    nr_migrations: u64::default(),
-                    statistics: sched_statistics::default(),
-                    depth: i32::default(),
-                    __pad_324: [u8::default(); 4],
-                    parent: std::ptr::null_mut(),
-                    cfs_rq: std::ptr::null_mut(),
-                    my_q: std::ptr::null_mut(),
-                    runnable_weight: u64::default(),
-                    __pad_360: [u8::default(); 24],
-                    avg: sched_avg::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct sched_rt_entity {
-            pub run_list: list_head,
-            pub timeout: u64,
-            pub watchdog_stamp: u64,
-            pub time_slice: u32,
-            pub on_rq: u16,
-            pub on_list: u16,
-            pub back: *mut sched_rt_entity,
-        }
-        impl Default for sched_rt_entity {
-            fn default() -> Self {
-                Self {
-                    run_list: list_head::default(),
-                    timeout: u64::default(),
-                    watchdog_stamp: u64::default(),
-                    time_slice: u32::default(),
-                    on_rq: u16::default(),
-                    on_list: u16::default(),
-                    back: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct sched_dl_entity {
-            pub rb_node: rb_node,
-            pub dl_runtime: u64,
-            pub dl_deadline: u64,
-            pub dl_period: u64,
-            pub dl_bw: u64,
-            pub dl_density: u64,
-            pub runtime: i64,
-            pub deadline: u64,
-            pub flags: u32,
-            pub __pad_84: [u8; 4],
-            pub dl_timer: hrtimer,
-            pub inactive_timer: hrtimer,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct uclamp_se {
-            pub __pad_0: [u8; 4],
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct hlist_head {
-            pub first: *mut hlist_node,
-        }
-        impl Default for hlist_head {
-            fn default() -> Self {
-                Self {
-                    first: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct cpumask {
-            pub bits: [u64; 5],
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union rcu_special {
-            pub b: __anon_13,
-            pub s: u32,
-        }
-        impl std::fmt::Debug for rcu_special {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for rcu_special {
-            fn default() -> Self {
-                Self {
-                    b: __anon_13::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct list_head {
-            pub next: *mut list_head,
-            pub prev: *mut list_head,
-        }
-        impl Default for list_head {
-            fn default() -> Self {
-                Self {
-                    next: std::ptr::null_mut(),
-                    prev: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct sched_info {
-            pub pcount: u64,
-            pub run_delay: u64,
-            pub last_arrival: u64,
-            pub last_queued: u64,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct plist_node {
-            pub prio: i32,
-            pub __pad_4: [u8; 4],
-            pub prio_list: list_head,
-            pub node_list: list_head,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct rb_node {
-            pub __rb_parent_color: u64,
-            pub rb_right: *mut rb_node,
-            pub rb_left: *mut rb_node,
-        }
-        impl Default for rb_node {
-            fn default() -> Self {
-                Self {
-                    __rb_parent_color: u64::default(),
-                    rb_right: std::ptr::null_mut(),
-                    rb_left: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct vmacache {
-            pub seqnum: u64,
-            pub vmas: [*mut vm_area_struct; 4],
-        }
-        impl Default for vmacache {
-            fn default() -> Self {
-                Self {
-                    seqnum: u64::default(),
-                    vmas: [std::ptr::null_mut(); 4],
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct task_rss_stat {
-            pub events: i32,
-            pub count: [i32; 4],
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct restart_block {
-            pub r#fn: *mut std::ffi::c_void,
-            pub __anon_14: __anon_14,
-        }
-        impl Default for restart_block {
-            fn default() -> Self {
-                Self {
-                    r#fn: std::ptr::null_mut(),
-                    __anon_14: __anon_14::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct hlist_node {
-            pub next: *mut hlist_node,
-            pub pprev: *mut *mut hlist_node,
-        }
-        impl Default for hlist_node {
-            fn default() -> Self {
-                Self {
-                    next: std::ptr::null_mut(),
-                    pprev: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct prev_cputime {
-            pub utime: u64,
-            pub stime: u64,
-            pub lock: raw_spinlock,
-            pub __pad_20: [u8; 4],
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct posix_cputimers {
-            pub bases: [posix_cputimer_base; 3],
-            pub timers_active: u32,
-            pub expiry_active: u32,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct cred {
-            pub usage: __anon_9,
-            pub uid: __anon_6,
-            pub gid: __anon_15,
-            pub suid: __anon_6,
-            pub sgid: __anon_15,
-            pub euid: __anon_6,
-            pub egid: __anon_15,
-            pub fsuid: __anon_6,
-            pub fsgid: __anon_15,
-            pub securebits: u32,
-            pub cap_inheritable: kernel_cap_struct,
-            pub cap_permitted: kernel_cap_struct,
-            pub cap_effective: kernel_cap_struct,
-            pub cap_bset: kernel_cap_struct,
-            pub cap_ambient: kernel_cap_struct,
-            pub jit_keyring: u8,
-            pub __pad_81: [u8; 7],
-            pub session_keyring: *mut std::ffi::c_void,
-            pub process_keyring: *mut std::ffi::c_void,
-            pub thread_keyring: *mut std::ffi::c_void,
-            pub request_key_auth: *mut std::ffi::c_void,
-            pub security: *mut std::ffi::c_void,
-            pub user: *mut std::ffi::c_void,
-            pub user_ns: *mut std::ffi::c_void,
-            pub group_info: *mut std::ffi::c_void,
-            pub __anon_16: __anon_16,
-        }
-        impl Default for cred {
-            fn default() -> Self {
-                Self {
-                    usage: __anon_9::default(),
-                    uid: __anon_6::default(),
-                    gid: __anon_15::default(),
-                    suid: __anon_6::default(),
-                    sgid: __anon_15::default(),
-                    euid: __anon_6::default(),
-                    egid: __anon_15::default(),
-                    fsuid: __anon_6::default(),
-                    fsgid: __anon_15::default(),
-                    securebits: u32::default(),
-                    cap_inheritable: kernel_cap_struct::default(),
-                    cap_permitted: kernel_cap_struct::default(),
-                    cap_effective: kernel_cap_struct::default(),
-                    cap_bset: kernel_cap_struct::default(),
-                    cap_ambient: kernel_cap_struct::default(),
-                    jit_keyring: u8::default(),
-                    __pad_81: [u8::default(); 7],
-                    session_keyring: std::ptr::null_mut(),
-                    process_keyring: std::ptr::null_mut(),
-                    thread_keyring: std::ptr::null_mut(),
-                    request_key_auth: std::ptr::null_mut(),
-                    security: std::ptr::null_mut(),
-                    user: std::ptr::null_mut(),
-                    user_ns: std::ptr::null_mut(),
-                    group_info: std::ptr::null_mut(),
-                    __anon_16: __anon_16::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct sysv_sem {
-            pub undo_list: *mut std::ffi::c_void,
-        }
-        impl Default for sysv_sem {
-            fn default() -> Self {
-                Self {
-                    undo_list: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct sysv_shm {
-            pub shm_clist: list_head,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_5 {
-            pub sig: [u64; 1],
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct sigpending {
-            pub list: list_head,
-            pub signal: __anon_5,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct callback_head {
-            pub next: *mut callback_head,
-            pub func: *mut std::ffi::c_void,
-        }
-        impl Default for callback_head {
-            fn default() -> Self {
-                Self {
-                    next: std::ptr::null_mut(),
-                    func: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_6 {
-            pub val: u32,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct seccomp {
-            pub mode: i32,
-            pub __pad_4: [u8; 4],
-            pub filter: *mut std::ffi::c_void,
-        }
-        impl Default for seccomp {
-            fn default() -> Self {
-                Self {
-                    mode: i32::default(),
-                    __pad_4: [u8::default(); 4],
-                    filter: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct spinlock {
-            pub __anon_17: __anon_17,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct raw_spinlock {
-            pub raw_lock: qspinlock,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct wake_q_node {
-            pub next: *mut wake_q_node,
-        }
-        impl Default for wake_q_node {
-            fn default() -> Self {
-                Self {
-                    next: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct rb_root_cached {
-            pub rb_root: rb_root,
-            pub rb_leftmost: *mut rb_node,
-        }
-        impl Default for rb_root_cached {
-            fn default() -> Self {
-                Self {
-                    rb_root: rb_root::default(),
-                    rb_leftmost: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct task_io_accounting {
-            pub rchar: u64,
-            pub wchar: u64,
-            pub syscr: u64,
-            pub syscw: u64,
-            pub read_bytes: u64,
-            pub write_bytes: u64,
-            pub cancelled_write_bytes: u64,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_7 {
-            pub bits: [u64; 1],
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct seqcount {
-            pub sequence: u32,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct mutex {
-            pub owner: __anon_11,
-            pub wait_lock: spinlock,
-            pub osq: optimistic_spin_queue,
-            pub wait_list: list_head,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct perf_event_context {
-            pub pmu: *mut std::ffi::c_void,
-            pub lock: raw_spinlock,
-            pub __pad_12: [u8; 4],
-            pub mutex: mutex,
-            pub active_ctx_list: list_head,
-            pub pinned_groups: perf_event_groups,
-            pub flexible_groups: perf_event_groups,
-            pub event_list: list_head,
-            pub pinned_active: list_head,
-            pub flexible_active: list_head,
-            pub nr_events: i32,
-            pub nr_active: i32,
-            pub is_active: i32,
-            pub nr_stat: i32,
-            pub nr_freq: i32,
-            pub rotate_disable: i32,
-            pub rotate_necessary: i32,
-            pub refcount: refcount_struct,
-            pub task: *mut task_struct,
-            pub time: u64,
-            pub timestamp: u64,
-            pub parent_ctx: *mut perf_event_context,
-            pub parent_gen: u64,
-            pub generation: u64,
-            pub pin_count: i32,
-            pub nr_cgroups: i32,
-            pub task_ctx_data: *mut std::ffi::c_void,
-            pub callback_head: callback_head,
-        }
-        impl Default for perf_event_context {
-            fn default() -> Self {
-                Self {
-                    pmu: std::ptr::null_mut(),
-                    lock: raw_spinlock::default(),
-                    __pad_12: [u8::default(); 4],
-                    mutex: mutex::default(),
-                    active_ctx_list: list_head::default(),
-                    pinned_groups: perf_event_groups::default(),
-                    flexible_groups: perf_event_groups::default(),
-                    event_list: list_head::default(),
-                    pinned_active: list_head::default(),
-                    flexible_active: list_head::default(),
-                    nr_events: i32::default(),
-                    nr_active: i32::default(),
-                    is_active: i32::default(),
-                    nr_stat: i32::default(),
-                    nr_freq: i32::default(),
-                    rotate_disable: i32::default(),
-                    rotate_necessary: i32::default(),
-                    refcount: refcount_struct::default(),
-                    task: std::ptr::null_mut(),
-                    time: u64::default(),
-                    timestamp: u64::default(),
-                    parent_ctx: std::ptr::null_mut(),
-                    parent_gen: u64::default(),
-                    generation: u64::default(),
-                    pin_count: i32::default(),
-                    nr_cgroups: i32::default(),
-                    task_ctx_data: std::ptr::null_mut(),
-                    callback_head: callback_head::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct tlbflush_unmap_batch {
-            pub arch: arch_tlbflush_unmap_batch,
-            pub flush_required: std::mem::MaybeUninit<bool>,
-            pub writable: std::mem::MaybeUninit<bool>,
-            pub __pad_42: [u8; 6],
-        }
-        impl Default for tlbflush_unmap_batch {
-            fn default() -> Self {
-                Self {
-                    arch: arch_tlbflush_unmap_batch::default(),
-                    flush_required: std::mem::MaybeUninit::new(bool::default()),
-                    writable: std::mem::MaybeUninit::new(bool::default()),
-                    __pad_42: [u8::default(); 6],
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_8 {
-            pub rcu_users: refcount_struct,
-            pub rcu: callback_head,
-        }
-        impl std::fmt::Debug for __anon_8 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_8 {
-            fn default() -> Self {
-                Self {
-                    rcu_users: refcount_struct::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct page_frag {
-            pub page: *mut std::ffi::c_void,
-            pub offset: u32,
-            pub size: u32,
-        }
-        impl Default for page_frag {
-            fn default() -> Self {
-                Self {
-                    page: std::ptr::null_mut(),
-                    offset: u32::default(),
-                    size: u32::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct latency_record {
-            pub backtrace: [u64; 12],
-            pub count: u32,
-            pub __pad_100: [u8; 4],
-            pub time: u64,
-            pub max: u64,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_9 {
-            pub counter: i32,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct thread_struct {
-            pub tls_array: [desc_struct; 3],
-            pub sp: u64,
-            pub es: u16,
-            pub ds: u16,
-            pub fsindex: u16,
-            pub gsindex: u16,
-            pub fsbase: u64,
-            pub gsbase: u64,
-            pub ptrace_bps: [*mut perf_event; 4],
-            pub debugreg6: u64,
-            pub ptrace_dr7: u64,
-            pub cr2: u64,
-            pub trap_nr: u64,
-            pub error_code: u64,
-            pub io_bitmap: *mut std::ffi::c_void,
-            pub iopl_emul: u64,
-            pub addr_limit: __anon_18,
-            pub __pad_152: [u8; 40],
-            pub fpu: fpu,
-        }
-        impl Default for thread_struct {
-            fn default() -> Self {
-                Self {
-                    tls_array: [desc_struct::default(); 3],
-                    sp: u64::default(),
-                    es: u16::default(),
-                    ds: u16::default(),
-                    fsindex: u16::default(),
-                    gsindex: u16::default(),
-                    fsbase: u64::default(),
-                    gsbase: u64::default(),
-                    ptrace_bps: [std::ptr::null_mut(); 4],
-                    debugreg6: u64::default(),
-                    ptrace_dr7: u64::default(),
-                    cr2: u64::default(),
-                    trap_nr: u64::default(),
-                    error_code: u64::default(),
-                    io_bitmap: std::ptr::null_mut(),
-                    iopl_emul: u64::default(),
-                    addr_limit: __anon_18::default(),
-                    __pad_152: [u8::default(); 40],
-                    fpu: fpu::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_10 {
-            pub fu_llist: llist_node,
-            pub fu_rcuhead: callback_head,
-        }
-        impl std::fmt::Debug for __anon_10 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_10 {
-            fn default() -> Self {
-                Self {
-                    fu_llist: llist_node::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct path {
-            pub mnt: *mut std::ffi::c_void,
-            pub dentry: *mut std::ffi::c_void,
-        }
-        impl Default for path {
-            fn default() -> Self {
-                Self {
-                    mnt: std::ptr::null_mut(),
-                    dentry: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
-        #[repr(u32)]
-        pub enum rw_hint {
-            #[default]
-            WRITE_LIFE_NOT_SET = 0,
-            WRITE_LIFE_NONE = 1,
-            WRITE_LIFE_SHORT = 2,
-            WRITE_LIFE_MEDIUM = 3,
-            WRITE_LIFE_LONG = 4,
-            WRITE_LIFE_EXTREME = 5,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_11 {
-            pub counter: i64,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct fown_struct {
-            pub lock: __anon_19,
-            pub pid: *mut std::ffi::c_void,
-            pub pid_type: std::mem::MaybeUninit<pid_type>,
-            pub uid: __anon_6,
-            pub euid: __anon_6,
-            pub signum: i32,
-        }
-        impl Default for fown_struct {
-            fn default() -> Self {
-                Self {
-                    lock: __anon_19::default(),
-                    pid: std::ptr::null_mut(),
-                    pid_type: std::mem::MaybeUninit::new(pid_type::default()),
-                    uid: __anon_6::default(),
-                    euid: __anon_6::default(),
-                    signum: i32::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct file_ra_state {
-            pub start: u64,
-            pub size: u32,
-            pub async_size: u32,
-            pub ra_pages: u32,
-            pub mmap_miss: u32,
-            pub prev_pos: i64,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct llist_node {
-            pub next: *mut llist_node,
-        }
-        impl Default for llist_node {
-            fn default() -> Self {
-                Self {
-                    next: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_12 {
-            pub u_flags: u32,
-            pub a_flags: __anon_9,
-        }
-        impl std::fmt::Debug for __anon_12 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_12 {
-            fn default() -> Self {
-                Self {
-                    u_flags: u32::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct load_weight {
-            pub weight: u64,
-            pub inv_weight: u32,
-            pub __pad_12: [u8; 4],
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct sched_statistics {
-            pub wait_start: u64,
-            pub wait_max: u64,
-            pub wait_count: u64,
-            pub wait_sum: u64,
-            pub iowait_count: u64,
-            pub iowait_sum: u64,
-            pub sleep_start: u64,
-            pub sleep_max: u64,
-            pub sum_sleep_runtime: i64,
-            pub block_start: u64,
-            pub block_max: u64,
-            pub exec_max: u64,
-            pub slice_max: u64,
-            pub nr_migrations_cold: u64,
-            pub nr_failed_migrations_affine: u64,
-            pub nr_failed_migrations_running: u64,
-            pub nr_failed_migrations_hot: u64,
-            pub nr_forced_migrations: u64,
-            pub nr_wakeups: u64,
-            pub nr_wakeups_sync: u64,
-            pub nr_wakeups_migrate: u64,
-            pub nr_wakeups_local: u64,
-            pub nr_wakeups_remote: u64,
-            pub nr_wakeups_affine: u64,
-            pub nr_wakeups_affine_attempts: u64,
-            pub nr_wakeups_passive: u64,
-            pub nr_wakeups_idle: u64,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct sched_avg {
-            pub last_update_time: u64,
-            pub load_sum: u64,
-            pub runnable_sum: u64,
-            pub util_sum: u32,
-            pub period_contrib: u32,
-            pub load_avg: u64,
-            pub runnable_avg: u64,
-            pub util_avg: u64,
-            pub util_est: util_est,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct hrtimer {
-            pub node: timerqueue_node,
-            pub _softexpires: i64,
-            pub function: *mut std::ffi::c_void,
-            pub base: *mut std::ffi::c_void,
-            pub state: u8,
-            pub is_rel: u8,
-            pub is_soft: u8,
-            pub is_hard: u8,
-            pub __pad_60: [u8; 4],
-        }
-        impl Default for hrtimer {
-            fn default() -> Self {
-                Self {
-                    node: timerqueue_node::default(),
-                    _softexpires: i64::default(),
-                    function: std::ptr::null_mut(),
-                    base: std::ptr::null_mut(),
-                    state: u8::default(),
-                    is_rel: u8::default(),
-                    is_soft: u8::default(),
-                    is_hard: u8::default(),
-                    __pad_60: [u8::default(); 4],
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_13 {
-            pub blocked: u8,
-            pub need_qs: u8,
-            pub exp_hint: u8,
-            pub need_mb: u8,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct vm_area_struct {
-            pub vm_start: u64,
-            pub vm_end: u64,
-            pub vm_next: *mut vm_area_struct,
-            pub vm_prev: *mut vm_area_struct,
-            pub vm_rb: rb_node,
-            pub rb_subtree_gap: u64,
-            pub vm_mm: *mut std::ffi::c_void,
-            pub vm_page_prot: pgprot,
-            pub vm_flags: u64,
-            pub shared: __anon_20,
-            pub anon_vma_chain: list_head,
-            pub anon_vma: *mut std::ffi::c_void,
-            pub vm_ops: *mut std::ffi::c_void,
-            pub vm_pgoff: u64,
-            pub vm_file: *mut file,
-            pub vm_private_data: *mut std::ffi::c_void,
-            pub swap_readahead_info: __anon_11,
-            pub vm_policy: *mut std::ffi::c_void,
-            pub vm_userfaultfd_ctx: vm_userfaultfd_ctx,
-        }
-        impl Default for vm_area_struct {
-            fn default() -> Self {
-                Self {
-                    vm_start: u64::default(),
-                    vm_end: u64::default(),
-                    vm_next: std::ptr::null_mut(),
-                    vm_prev: std::ptr::null_mut(),
-                    vm_rb: rb_node::default(),
-                    rb_subtree_gap: u64::default(),
-                    vm_mm: std::ptr::null_mut(),
-                    vm_page_prot: pgprot::default(),
-                    vm_flags: u64::default(),
-                    shared: __anon_20::default(),
-                    anon_vma_chain: list_head::default(),
-                    anon_vma: std::ptr::null_mut(),
-                    vm_ops: std::ptr::null_mut(),
-                    vm_pgoff: u64::default(),
-                    vm_file: std::ptr::null_mut(),
-                    vm_private_data: std::ptr::null_mut(),
-                    swap_readahead_info: __anon_11::default(),
-                    vm_policy: std::ptr::null_mut(),
-                    vm_userfaultfd_ctx: vm_userfaultfd_ctx::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_14 {
-            pub futex: __anon_21,
-            pub nanosleep: __anon_22,
-            pub poll: __anon_23,
-        }
-        impl std::fmt::Debug for __anon_14 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_14 {
-            fn default() -> Self {
-                Self {
-                    futex: __anon_21::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct posix_cputimer_base {
-            pub nextevt: u64,
-            pub tqhead: timerqueue_head,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_15 {
-            pub val: u32,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct kernel_cap_struct {
-            pub cap: [u32; 2],
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_16 {
-            pub non_rcu: i32,
-            pub rcu: callback_head,
-        }
-        impl std::fmt::Debug for __anon_16 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_16 {
-            fn default() -> Self {
-                Self {
-                    non_rcu: i32::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_17 {
-            pub rlock: raw_spinlock,
-        }
-        impl std::fmt::Debug for __anon_17 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_17 {
-            fn default() -> Self {
-                Self {
-                    rlock: raw_spinlock::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct qspinlock {
-            pub __anon_24: __anon_24,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct rb_root {
-            pub rb_node: *mut rb_node,
-        }
-        impl Default for rb_root {
-            fn default() -> Self {
-                Self {
-                    rb_node: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct optimistic_spin_queue {
-            pub tail: __anon_9,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct perf_event_groups {
-            pub tree: rb_root,
-            pub index: u64,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct arch_tlbflush_unmap_batch {
-            pub cpumask: cpumask,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct desc_struct {
-            pub limit0: u16,
-            pub base0: u16,
-            pub __pad_4: [u8; 4],
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct perf_event {
-            pub event_entry: list_head,
-            pub sibling_list: list_head,
-            pub active_list: list_head,
-            pub group_node: rb_node,
-            pub group_index: u64,
-            pub migrate_entry: list_head,
-            pub hlist_entry: hlist_node,
-            pub active_entry: list_head,
-            pub nr_siblings: i32,
-            pub event_caps: i32,
-            pub group_caps: i32,
-            pub __pad_140: [u8; 4],
-            pub group_leader: *mut perf_event,
-            pub pmu: *mut std::ffi::c_void,
-            pub pmu_private: *mut std::ffi::c_void,
-            pub state: std::mem::MaybeUninit<perf_event_state>,
-            pub attach_state: u32,
-            pub count: __anon_25,
-            pub child_count: __anon_11,
-            pub total_time_enabled: u64,
-            pub total_time_running: u64,
-            pub tstamp: u64,
-            pub shadow_ctx_time: u64,
-            pub attr: perf_event_attr,
-            pub header_size: u16,
-            pub id_header_size: u16,
-            pub read_size: u16,
-            pub hw: hw_perf_event,
-            pub ctx: *mut perf_event_context,
-            pub refcount: __anon_11,
-            pub child_total_time_enabled: __anon_11,
-            pub child_total_time_running: __anon_11,
-            pub child_mutex: mutex,
-            pub child_list: list_head,
-            pub parent: *mut perf_event,
-            pub oncpu: i32,
-            pub cpu: i32,
-            pub owner_entry: list_head,
-            pub owner: *mut task_struct,
-            pub mmap_mutex: mutex,
-            pub mmap_count: __anon_9,
-            pub __pad_700: [u8; 4],
-            pub rb: *mut std::ffi::c_void,
-            pub rb_entry: list_head,
-            pub rcu_batches: u64,
-            pub rcu_pending: i32,
-            pub __pad_740: [u8; 4],
-            pub waitq: wait_queue_head,
-            pub fasync: *mut std::ffi::c_void,
-            pub pending_wakeup: i32,
-            pub pending_kill: i32,
-            pub pending_disable: i32,
-            pub __pad_788: [u8; 4],
-            pub pending: irq_work,
-            pub event_limit: __anon_9,
-            pub __pad_820: [u8; 4],
-            pub addr_filters: perf_addr_filters_head,
-            pub addr_filter_ranges: *mut std::ffi::c_void,
-            pub addr_filters_gen: u64,
-            pub aux_event: *mut perf_event,
-            pub destroy: *mut std::ffi::c_void,
-            pub callback_head: callback_head,
-            pub ns: *mut std::ffi::c_void,
-            pub id: u64,
-            pub clock: *mut std::ffi::c_void,
-            pub overflow_handler: *mut std::ffi::c_void,
-            pub overflow_handler_context: *mut std::ffi::c_void,
-            pub orig_overflow_handler: *mut std::ffi::c_void,
-            pub prog: *mut bpf_prog,
-            pub tp_event: *mut std::ffi::c_void,
-            pub filter: *mut std::ffi::c_void,
-            pub ftrace_ops: ftrace_ops,
-            pub cgrp: *mut std::ffi::c_void,
-            pub security: *mut std::ffi::c_void,
-            pub sb_list: list_head,
-        }
-        impl Default for perf_event {
-            fn default() -> Self {
-                Self {
-                    event_entry: list_head::default(),
-                    sibling_list: list_head::default(),
-                    active_list: list_head::default(),
-                    group_node: rb_node::default(),
-                    group_index: u64::default(),
-                    migrate_entry: list_head::default(),
-                    hlist_entry: hlist_node::default(),
-                    active_entry: list_head::default(),
-                    nr_siblings: i32::default(),
-                    event_caps: i32::default(),
-                    group_caps: i32::default(),
-                    __pad_140: [u8::default(); 4],
-                    group_leader: std::ptr::null_mut(),
-                    pmu: std::ptr::null_mut(),
-                    pmu_private: std::ptr::null_mut(),
-                    state: std::mem::MaybeUninit::new(perf_event_state::default()),
-                    attach_state: u32::default(),
-                    count: __anon_25::default(),
-                    child_count: __anon_11::default(),
-                    total_time_enabled: u64::default(),
-                    total_time_running: u64::default(),
-                    tstamp: u64::default(),
-                    shadow_ctx_time: u64::default(),
-                    attr: perf_event_attr::default(),
-                    header_size: u16::default(),
-                    id_header_size: u16::default(),
-                    read_size: u16::default(),
-                    hw: hw_perf_event::default(),
-                    ctx: std::ptr::null_mut(),
-                    refcount: __anon_11::default(),
-                    child_total_time_enabled: __anon_11::default(),
-                    child_total_time_running: __anon_11::default(),
-                    child_mutex: mutex::default(),
-                    child_list: list_head::default(),
-                    parent: std::ptr::null_mut(),
-                    oncpu: i32::default(),
-                    cpu: i32::default(),
-                    owner_entry: list_head::default(),
-                    owner: std::ptr::null_mut(),
-                    mmap_mutex: mutex::default(),
-                    mmap_count: __anon_9::default(),
-                    __pad_700: [u8::default(); 4],
-                    rb: std::ptr::null_mut(),
-                    rb_entry: list_head::default(),
-                    rcu_batches: u64::default(),
-                    rcu_pending: i32::default(),
-                    __pad_740: [u8::default(); 4],
-                    waitq: wait_queue_head::default(),
-                    fasync: std::ptr::null_mut(),
-                    pending_wakeup: i32::default(),
-                    pending_kill: i32::default(),
-                    pending_disable: i32::default(),
-                    __pad_788: [u8::default(); 4],
-                    pending: irq_work::default(),
-                    event_limit: __anon_9::default(),
-                    __pad_820: [u8::default(); 4],
-                    addr_filters: perf_addr_filters_head::default(),
-                    addr_filter_ranges: std::ptr::null_mut(),
-                    addr_filters_gen: u64::default(),
-                    aux_event: std::ptr::null_mut(),
-                    destroy: std::ptr::null_mut(),
-                    callback_head: callback_head::default(),
-                    ns: std::ptr::null_mut(),
-                    id: u64::default(),
-                    clock: std::ptr::null_mut(),
-                    overflow_handler: std::ptr::null_mut(),
-                    overflow_handler_context: std::ptr::null_mut(),
-                    orig_overflow_handler: std::ptr::null_mut(),
-                    prog: std::ptr::null_mut(),
-                    tp_event: std::ptr::null_mut(),
-                    filter: std::ptr::null_mut(),
-                    ftrace_ops: ftrace_ops::default(),
-                    cgrp: std::ptr::null_mut(),
-                    security: std::ptr::null_mut(),
-                    sb_list: list_head::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_18 {
-            pub seg: u64,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct fpu {
-            pub last_cpu: u32,
-            pub __pad_4: [u8; 4],
-            pub avx512_timestamp: u64,
-            pub __pad_16: [u8; 48],
-            pub state: fpregs_state,
-        }
-        impl Default for fpu {
-            fn default() -> Self {
-                Self {
-                    last_cpu: u32::default(),
-                    __pad_4: [u8::default(); 4],
-                    avx512_timestamp: u64::default(),
-                    __pad_16: [u8::default(); 48],
-                    state: fpregs_state::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_19 {
-            pub raw_lock: qrwlock,
-        }
-        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
-        #[repr(u32)]
-        pub enum pid_type {
-            #[default]
-            PIDTYPE_PID = 0,
-            PIDTYPE_TGID = 1,
-            PIDTYPE_PGID = 2,
-            PIDTYPE_SID = 3,
-            PIDTYPE_MAX = 4,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct util_est {
-            pub enqueued: u32,
-            pub ewma: u32,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct timerqueue_node {
-            pub node: rb_node,
-            pub expires: i64,
-        }
-        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
-        #[repr(u32)]
-        pub enum hrtimer_restart {
-            #[default]
-            HRTIMER_NORESTART = 0,
-            HRTIMER_RESTART = 1,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct pgprot {
-            pub pgprot: u64,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_20 {
-            pub rb: rb_node,
-            pub rb_subtree_last: u64,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct vm_userfaultfd_ctx {
-            pub ctx: *mut std::ffi::c_void,
-        }
-        impl Default for vm_userfaultfd_ctx {
-            fn default() -> Self {
-                Self {
-                    ctx: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_21 {
-            pub uaddr: *mut u32,
-            pub val: u32,
-            pub flags: u32,
-            pub bitset: u32,
-            pub __pad_20: [u8; 4],
-            pub time: u64,
-            pub uaddr2: *mut u32,
-        }
-        impl Default for __anon_21 {
-            fn default() -> Self {
-                Self {
-                    uaddr: std::ptr::null_mut(),
-                    val: u32::default(),
-                    flags: u32::default(),
-                    bitset: u32::default(),
-                    __pad_20: [u8::default(); 4],
-                    time: u64::default(),
-                    uaddr2: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_22 {
-            pub clockid: i32,
-            pub r#type: std::mem::MaybeUninit<timespec_type>,
-            pub __anon_26: __anon_26,
-            pub expires: u64,
-        }
-        impl Default for __anon_22 {
-            fn default() -> Self {
-                Self {
-                    clockid: i32::default(),
-                    r#type: std::mem::MaybeUninit::new(timespec_type::default()),
-                    __anon_26: __anon_26::default(),
-                    expires: u64::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_23 {
-            pub ufds: *mut std::ffi::c_void,
-            pub nfds: i32,
-            pub has_timeout: i32,
-            pub tv_sec: u64,
-            pub tv_nsec: u64,
-        }
-        impl Default for __anon_23 {
-            fn default() -> Self {
-                Self {
-                    ufds: std::ptr::null_mut(),
-                    nfds: i32::default(),
-                    has_timeout: i32::default(),
-                    tv_sec: u64::default(),
-                    tv_nsec: u64::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct timerqueue_head {
-            pub rb_root: rb_root_cached,
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_24 {
-            pub val: __anon_9,
-            pub __anon_27: __anon_27,
-            pub __anon_28: __anon_28,
-        }
-        impl std::fmt::Debug for __anon_24 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_24 {
-            fn default() -> Self {
-                Self {
-                    val: __anon_9::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
-        #[repr(i32)]
-        pub enum perf_event_state {
-            #[default]
-            PERF_EVENT_STATE_DEAD = -4,
-            PERF_EVENT_STATE_EXIT = -3,
-            PERF_EVENT_STATE_ERROR = -2,
-            PERF_EVENT_STATE_OFF = -1,
-            PERF_EVENT_STATE_INACTIVE = 0,
-            PERF_EVENT_STATE_ACTIVE = 1,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_25 {
-            pub a: __anon_29,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct perf_event_attr {
-            pub r#type: u32,
-            pub size: u32,
-            pub config: u64,
-            pub __anon_30: __anon_30,
-            pub sample_type: u64,
-            pub read_format: u64,
-            pub __pad_40: [u8; 8],
-            pub __anon_31: __anon_31,
-            pub bp_type: u32,
-            pub __anon_32: __anon_32,
-            pub __anon_33: __anon_33,
-            pub branch_sample_type: u64,
-            pub sample_regs_user: u64,
-            pub sample_stack_user: u32,
-            pub clockid: i32,
-            pub sample_regs_intr: u64,
-            pub aux_watermark: u32,
-            pub sample_max_stack: u16,
-            pub __reserved_2: u16,
-            pub aux_sample_size: u32,
-            pub __reserved_3: u32,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct hw_perf_event {
-            pub __anon_34: __anon_34,
-            pub target: *mut task_struct,
-            pub addr_filters: *mut std::ffi::c_void,
-            pub addr_filters_gen: u64,
-            pub state: i32,
-            pub __pad_124: [u8; 4],
-            pub prev_count: __anon_25,
-            pub sample_period: u64,
-            pub last_period: u64,
-            pub period_left: __anon_25,
-            pub interrupts_seq: u64,
-            pub interrupts: u64,
-            pub freq_time_stamp: u64,
-            pub freq_count_stamp: u64,
-        }
-        impl Default for hw_perf_event {
-            fn default() -> Self {
-                Self {
-                    __anon_34: __anon_34::default(),
-                    target: std::ptr::null_mut(),
-                    addr_filters: std::ptr::null_mut(),
-                    addr_filters_gen: u64::default(),
-                    state: i32::default(),
-                    __pad_124: [u8::default(); 4],
-                    prev_count: __anon_25::default(),
-                    sample_period: u64::default(),
-                    last_period: u64::default(),
-                    period_left: __anon_25::default(),
-                    interrupts_seq: u64::default(),
-                    interrupts: u64::default(),
-                    freq_time_stamp: u64::default(),
-                    freq_count_stamp: u64::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct wait_queue_head {
-            pub lock: spinlock,
-            pub __pad_4: [u8; 4],
-            pub head: list_head,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct irq_work {
-            pub __anon_35: __anon_35,
-            pub func: *mut std::ffi::c_void,
-        }
-        impl Default for irq_work {
-            fn default() -> Self {
-                Self {
-                    __anon_35: __anon_35::default(),
-                    func: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct perf_addr_filters_head {
-            pub list: list_head,
-            pub lock: raw_spinlock,
-            pub nr_file_filters: u32,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct bpf_prog {
-            pub pages: u16,
-            pub r#type: std::mem::MaybeUninit<bpf_prog_type>,
-            pub expected_attach_type: std::mem::MaybeUninit<bpf_attach_type>,
-            pub len: u32,
-            pub jited_len: u32,
-            pub tag: [u8; 8],
-            pub __pad_28: [u8; 4],
-            pub aux: *mut bpf_prog_aux,
-            pub orig_prog: *mut std::ffi::c_void,
-            pub bpf_func: *mut std::ffi::c_void,
-            pub insns: [sock_filter; 0],
-            pub insnsi: [bpf_insn; 0],
-        }
-        impl Default for bpf_prog {
-            fn default() -> Self {
-                Self {
-                    pages: u16::default(),
-                    r#type: std::mem::MaybeUninit::new(bpf_prog_type::default()),
-                    expected_attach_type: std::mem::MaybeUninit::new(bpf_attach_type::default()),
-                    len: u32::default(),
-                    jited_len: u32::default(),
-                    tag: [u8::default(); 8],
-                    __pad_28: [u8::default(); 4],
-                    aux: std::ptr::null_mut(),
-                    orig_prog: std::ptr::null_mut(),
-                    bpf_func: std::ptr::null_mut(),
-                    insns: [sock_filter::default(); 0],
-                    insnsi: [bpf_insn::default(); 0],
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct ftrace_ops {
-            pub func: *mut std::ffi::c_void,
-            pub next: *mut ftrace_ops,
-            pub flags: u64,
-            pub private: *mut std::ffi::c_void,
-            pub saved_func: *mut std::ffi::c_void,
-            pub local_hash: ftrace_ops_hash,
-            pub func_hash: *mut ftrace_ops_hash,
-            pub old_hash: ftrace_ops_hash,
-            pub trampoline: u64,
-            pub trampoline_size: u64,
-        }
-        impl Default for ftrace_ops {
-            fn default() -> Self {
-                Self {
-                    func: std::ptr::null_mut(),
-                    next: std::ptr::null_mut(),
-                    flags: u64::default(),
-                    private: std::ptr::null_mut(),
-                    saved_func: std::ptr::null_mut(),
-                    local_hash: ftrace_ops_hash::default(),
-                    func_hash: std::ptr::null_mut(),
-                    old_hash: ftrace_ops_hash::default(),
-                    trampoline: u64::default(),
-                    trampoline_size: u64::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union fpregs_state {
-            pub fsave: fregs_state,
-            pub fxsave: fxregs_state,
-            pub soft: swregs_state,
-            pub xsave: xregs_state,
-            pub __padding: [u8; 4096],
-        }
-        impl std::fmt::Debug for fpregs_state {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for fpregs_state {
-            fn default() -> Self {
-                Self {
-                    fsave: fregs_state::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct qrwlock {
-            pub __anon_36: __anon_36,
-            pub wait_lock: qspinlock,
-        }
-        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
-        #[repr(u32)]
-        pub enum timespec_type {
-            #[default]
-            TT_NONE = 0,
-            TT_NATIVE = 1,
-            TT_COMPAT = 2,
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_26 {
-            pub rmtp: *mut std::ffi::c_void,
-            pub compat_rmtp: *mut std::ffi::c_void,
-        }
-        impl std::fmt::Debug for __anon_26 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_26 {
-            fn default() -> Self {
-                Self {
-                    rmtp: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_27 {
-            pub locked: u8,
-            pub pending: u8,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_28 {
-            pub locked_pending: u16,
-            pub tail: u16,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_29 {
-            pub a: __anon_11,
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_30 {
-            pub sample_period: u64,
-            pub sample_freq: u64,
-        }
-        impl std::fmt::Debug for __anon_30 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_30 {
-            fn default() -> Self {
-                Self {
-                    sample_period: u64::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_31 {
-            pub wakeup_events: u32,
-            pub wakeup_watermark: u32,
-        }
-        impl std::fmt::Debug for __anon_31 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_31 {
-            fn default() -> Self {
-                Self {
-                    wakeup_events: u32::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_32 {
-            pub bp_addr: u64,
-            pub kprobe_func: u64,
-            pub uprobe_path: u64,
-            pub config1: u64,
-        }
-        impl std::fmt::Debug for __anon_32 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_32 {
-            fn default() -> Self {
-                Self {
-                    bp_addr: u64::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_33 {
-            pub bp_len: u64,
-            pub kprobe_addr: u64,
-            pub probe_offset: u64,
-            pub config2: u64,
-        }
-        impl std::fmt::Debug for __anon_33 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_33 {
-            fn default() -> Self {
-                Self {
-                    bp_len: u64::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_34 {
-            pub __anon_37: __anon_37,
-            pub __anon_38: __anon_38,
-            pub __anon_39: __anon_39,
-            pub __anon_40: __anon_40,
-            pub __anon_41: __anon_41,
-            pub __anon_42: __anon_42,
-        }
-        impl std::fmt::Debug for __anon_34 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_34 {
-            fn default() -> Self {
-                Self {
-                    __anon_37: __anon_37::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_35 {
-            pub node: __call_single_node,
-            pub __anon_43: __anon_43,
-        }
-        impl std::fmt::Debug for __anon_35 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_35 {
-            fn default() -> Self {
-                Self {
-                    node: __call_single_node::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
-        #[repr(u32)]
-        pub enum bpf_prog_type {
-            #[default]
-            BPF_PROG_TYPE_UNSPEC = 0,
-            BPF_PROG_TYPE_SOCKET_FILTER = 1,
-            BPF_PROG_TYPE_KPROBE = 2,
-            BPF_PROG_TYPE_SCHED_CLS = 3,
-            BPF_PROG_TYPE_SCHED_ACT = 4,
-            BPF_PROG_TYPE_TRACEPOINT = 5,
-            BPF_PROG_TYPE_XDP = 6,
-            BPF_PROG_TYPE_PERF_EVENT = 7,
-            BPF_PROG_TYPE_CGROUP_SKB = 8,
-            BPF_PROG_TYPE_CGROUP_SOCK = 9,
-            BPF_PROG_TYPE_LWT_IN = 10,
-            BPF_PROG_TYPE_LWT_OUT = 11,
-            BPF_PROG_TYPE_LWT_XMIT = 12,
-            BPF_PROG_TYPE_SOCK_OPS = 13,
-            BPF_PROG_TYPE_SK_SKB = 14,
-            BPF_PROG_TYPE_CGROUP_DEVICE = 15,
-            BPF_PROG_TYPE_SK_MSG = 16,
-            BPF_PROG_TYPE_RAW_TRACEPOINT = 17,
-            BPF_PROG_TYPE_CGROUP_SOCK_ADDR = 18,
-            BPF_PROG_TYPE_LWT_SEG6LOCAL = 19,
-            BPF_PROG_TYPE_LIRC_MODE2 = 20,
-            BPF_PROG_TYPE_SK_REUSEPORT = 21,
-            BPF_PROG_TYPE_FLOW_DISSECTOR = 22,
-            BPF_PROG_TYPE_CGROUP_SYSCTL = 23,
-            BPF_PROG_TYPE_RAW_TRACEPOINT_WRITABLE = 24,
-            BPF_PROG_TYPE_CGROUP_SOCKOPT = 25,
-            BPF_PROG_TYPE_TRACING = 26,
-            BPF_PROG_TYPE_STRUCT_OPS = 27,
-            BPF_PROG_TYPE_EXT = 28,
-            BPF_PROG_TYPE_LSM = 29,
-        }
-        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
-        #[repr(u32)]
-        pub enum bpf_attach_type {
-            #[default]
-            BPF_CGROUP_INET_INGRESS = 0,
-            BPF_CGROUP_INET_EGRESS = 1,
-            BPF_CGROUP_INET_SOCK_CREATE = 2,
-            BPF_CGROUP_SOCK_OPS = 3,
-            BPF_SK_SKB_STREAM_PARSER = 4,
-            BPF_SK_SKB_STREAM_VERDICT = 5,
-            BPF_CGROUP_DEVICE = 6,
-            BPF_SK_MSG_VERDICT = 7,
-            BPF_CGROUP_INET4_BIND = 8,
-            BPF_CGROUP_INET6_BIND = 9,
-            BPF_CGROUP_INET4_CONNECT = 10,
-            BPF_CGROUP_INET6_CONNECT = 11,
-            BPF_CGROUP_INET4_POST_BIND = 12,
-            BPF_CGROUP_INET6_POST_BIND = 13,
-            BPF_CGROUP_UDP4_SENDMSG = 14,
-            BPF_CGROUP_UDP6_SENDMSG = 15,
-            BPF_LIRC_MODE2 = 16,
-            BPF_FLOW_DISSECTOR = 17,
-            BPF_CGROUP_SYSCTL = 18,
-            BPF_CGROUP_UDP4_RECVMSG = 19,
-            BPF_CGROUP_UDP6_RECVMSG = 20,
-            BPF_CGROUP_GETSOCKOPT = 21,
-            BPF_CGROUP_SETSOCKOPT = 22,
-            BPF_TRACE_RAW_TP = 23,
-            BPF_TRACE_FENTRY = 24,
-            BPF_TRACE_FEXIT = 25,
-            BPF_MODIFY_RETURN = 26,
-            BPF_LSM_MAC = 27,
-            BPF_TRACE_ITER = 28,
-            BPF_CGROUP_INET4_GETPEERNAME = 29,
-            BPF_CGROUP_INET6_GETPEERNAME = 30,
-            BPF_CGROUP_INET4_GETSOCKNAME = 31,
-            BPF_CGROUP_INET6_GETSOCKNAME = 32,
-            BPF_XDP_DEVMAP = 33,
-            __MAX_BPF_ATTACH_TYPE = 34,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct bpf_prog_aux {
-            pub refcnt: __anon_11,
-            pub used_map_cnt: u32,
-            pub max_ctx_offset: u32,
-            pub max_pkt_offset: u32,
-            pub max_tp_access: u32,
-            pub stack_depth: u32,
-            pub id: u32,
-            pub func_cnt: u32,
-            pub func_idx: u32,
-            pub attach_btf_id: u32,
-            pub ctx_arg_info_size: u32,
-            pub ctx_arg_info: *mut std::ffi::c_void,
-            pub linked_prog: *mut bpf_prog,
-            pub verifier_zext: std::mem::MaybeUninit<bool>,
-            pub offload_requested: std::mem::MaybeUninit<bool>,
-            pub attach_btf_trace: std::mem::MaybeUninit<bool>,
-            pub func_proto_unreliable: std::mem::MaybeUninit<bool>,
-            pub trampoline_prog_type: std::mem::MaybeUninit<bpf_tramp_prog_type>,
-            pub trampoline: *mut std::ffi::c_void,
-            pub tramp_hlist: hlist_node,
-            pub attach_func_proto: *mut std::ffi::c_void,
-            pub attach_func_name: *mut i8,
-            pub func: *mut *mut bpf_prog,
-            pub jit_data: *mut std::ffi::c_void,
-            pub poke_tab: *mut std::ffi::c_void,
-            pub size_poke_tab: u32,
-            pub __pad_140: [u8; 4],
-            pub ksym: bpf_ksym,
-            pub ops: *mut std::ffi::c_void,
-            pub used_maps: *mut *mut bpf_map,
-            pub prog: *mut bpf_prog,
-            pub user: *mut std::ffi::c_void,
-            pub load_time: u64,
-            pub cgroup_storage: [*mut bpf_map; 2],
-            pub name: [i8; 16],
-            pub security: *mut std::ffi::c_void,
-            pub offload: *mut std::ffi::c_void,
-            pub btf: *mut std::ffi::c_void,
-            pub func_info: *mut std::ffi::c_void,
-            pub func_info_aux: *mut std::ffi::c_void,
-            pub linfo: *mut std::ffi::c_void,
-            pub jited_linfo: *mut *mut std::ffi::c_void,
-            pub func_info_cnt: u32,
-            pub nr_linfo: u32,
-            pub linfo_idx: u32,
-            pub num_exentries: u32,
-            pub extable: *mut std::ffi::c_void,
-            pub stats: *mut std::ffi::c_void,
-            pub __anon_44: __anon_44,
-        }
-        impl Default for bpf_prog_aux {
-            fn default() -> Self {
-                Self {
-                    refcnt: __anon_11::default(),
-                    used_map_cnt: u32::default(),
-                    max_ctx_offset: u32::default(),
-                    max_pkt_offset: u32::default(),
-                    max_tp_access: u32::default(),
-                    stack_depth: u32::default(),
-                    id: u32::default(),
-                    func_cnt: u32::default(),
-                    func_idx: u32::default(),
-                    attach_btf_id: u32::default(),
-                    ctx_arg_info_size: u32::default(),
-                    ctx_arg_info: std::ptr::null_mut(),
-                    linked_prog: std::ptr::null_mut(),
-                    verifier_zext: std::mem::MaybeUninit::new(bool::default()),
-                    offload_requested: std::mem::MaybeUninit::new(bool::default()),
-                    attach_btf_trace: std::mem::MaybeUninit::new(bool::default()),
-                    func_proto_unreliable: std::mem::MaybeUninit::new(bool::default()),
-                    trampoline_prog_type: std::mem::MaybeUninit::new(bpf_tramp_prog_type::default()),
-                    trampoline: std::ptr::null_mut(),
-                    tramp_hlist: hlist_node::default(),
-                    attach_func_proto: std::ptr::null_mut(),
-                    attach_func_name: std::ptr::null_mut(),
-                    func: std::ptr::null_mut(),
-                    jit_data: std::ptr::null_mut(),
-                    poke_tab: std::ptr::null_mut(),
-                    size_poke_tab: u32::default(),
-                    __pad_140: [u8::default(); 4],
-                    ksym: bpf_ksym::default(),
-                    ops: std::ptr::null_mut(),
-                    used_maps: std::ptr::null_mut(),
-                    prog: std::ptr::null_mut(),
-                    user: std::ptr::null_mut(),
-                    load_time: u64::default(),
-                    cgroup_storage: [std::ptr::null_mut(); 2],
-                    name: [i8::default(); 16],
-                    security: std::ptr::null_mut(),
-                    offload: std::ptr::null_mut(),
-                    btf: std::ptr::null_mut(),
-                    func_info: std::ptr::null_mut(),
-                    func_info_aux: std::ptr::null_mut(),
-                    linfo: std::ptr::null_mut(),
-                    jited_linfo: std::ptr::null_mut(),
-                    func_info_cnt: u32::default(),
-                    nr_linfo: u32::default(),
-                    linfo_idx: u32::default(),
-                    num_exentries: u32::default(),
-                    extable: std::ptr::null_mut(),
-                    stats: std::ptr::null_mut(),
-                    __anon_44: __anon_44::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct sock_filter {
-            pub code: u16,
-            pub jt: u8,
-            pub jf: u8,
-            pub k: u32,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct bpf_insn {
-            pub code: u8,
-            pub off: i16,
-            pub imm: i32,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct ftrace_ops_hash {
-            pub notrace_hash: *mut std::ffi::c_void,
-            pub filter_hash: *mut std::ffi::c_void,
-            pub regex_lock: mutex,
-        }
-        impl Default for ftrace_ops_hash {
-            fn default() -> Self {
-                Self {
-                    notrace_hash: std::ptr::null_mut(),
-                    filter_hash: std::ptr::null_mut(),
-                    regex_lock: mutex::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct fregs_state {
-            pub cwd: u32,
-            pub swd: u32,
-            pub twd: u32,
-            pub fip: u32,
-            pub fcs: u32,
-            pub foo: u32,
-            pub fos: u32,
-            pub st_space: [u32; 20],
-            pub status: u32,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct fxregs_state {
-            pub cwd: u16,
-            pub swd: u16,
-            pub twd: u16,
-            pub fop: u16,
-            pub __anon_45: __anon_45,
-            pub mxcsr: u32,
-            pub mxcsr_mask: u32,
-            pub st_space: [u32; 32],
-            pub xmm_space: [u32; 64],
-            pub padding: [u32; 12],
-            pub __anon_46: __anon_46,
-        }
-        impl Default for fxregs_state {
-            fn default() -> Self {
-                Self {
-                    cwd: u16::default(),
-                    swd: u16::default(),
-                    twd: u16::default(),
-                    fop: u16::default(),
-                    __anon_45: __anon_45::default(),
-                    mxcsr: u32::default(),
-                    mxcsr_mask: u32::default(),
-                    st_space: [u32::default(); 32],
-                    xmm_space: [u32::default(); 64],
-                    padding: [u32::default(); 12],
-                    __anon_46: __anon_46::default(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct swregs_state {
-            pub cwd: u32,
-            pub swd: u32,
-            pub twd: u32,
-            pub fip: u32,
-            pub fcs: u32,
-            pub foo: u32,
-            pub fos: u32,
-            pub st_space: [u32; 20],
-            pub ftop: u8,
-            pub changed: u8,
-            pub lookahead: u8,
-            pub no_update: u8,
-            pub rm: u8,
-            pub alimit: u8,
-            pub __pad_114: [u8; 6],
-            pub info: *mut std::ffi::c_void,
-            pub entry_eip: u32,
-            pub __pad_132: [u8; 4],
-        }
-        impl Default for swregs_state {
-            fn default() -> Self {
-                Self {
-                    cwd: u32::default(),
-                    swd: u32::default(),
-                    twd: u32::default(),
-                    fip: u32::default(),
-                    fcs: u32::default(),
-                    foo: u32::default(),
-                    fos: u32::default(),
-                    st_space: [u32::default(); 20],
-                    ftop: u8::default(),
-                    changed: u8::default(),
-                    lookahead: u8::default(),
-                    no_update: u8::default(),
-                    rm: u8::default(),
-                    alimit: u8::default(),
-                    __pad_114: [u8::default(); 6],
-                    info: std::ptr::null_mut(),
-                    entry_eip: u32::default(),
-                    __pad_132: [u8::default(); 4],
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct xregs_state {
-            pub i387: fxregs_state,
-            pub header: xstate_header,
-            pub extended_state_area: [u8; 0],
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_36 {
-            pub cnts: __anon_9,
-            pub __anon_47: __anon_47,
-        }
-        impl std::fmt::Debug for __anon_36 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_36 {
-            fn default() -> Self {
-                Self {
-                    cnts: __anon_9::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_37 {
-            pub config: u64,
-            pub last_tag: u64,
-            pub config_base: u64,
-            pub event_base: u64,
-            pub event_base_rdpmc: i32,
-            pub idx: i32,
-            pub last_cpu: i32,
-            pub flags: i32,
-            pub extra_reg: hw_perf_event_extra,
-            pub branch_reg: hw_perf_event_extra,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_38 {
-            pub hrtimer: hrtimer,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_39 {
-            pub tp_list: list_head,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_40 {
-            pub pwr_acc: u64,
-            pub ptsc: u64,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_41 {
-            pub info: arch_hw_breakpoint,
-            pub bp_list: list_head,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_42 {
-            pub iommu_bank: u8,
-            pub iommu_cntr: u8,
-            pub padding: u16,
-            pub __pad_4: [u8; 4],
-            pub conf: u64,
-            pub conf1: u64,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_43 {
-            pub llnode: llist_node,
-            pub flags: __anon_9,
-            pub __pad_12: [u8; 4],
-        }
-        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
-        #[repr(u32)]
-        pub enum bpf_tramp_prog_type {
-            #[default]
-            BPF_TRAMP_FENTRY = 0,
-            BPF_TRAMP_FEXIT = 1,
-            BPF_TRAMP_MODIFY_RETURN = 2,
-            BPF_TRAMP_MAX = 3,
-            BPF_TRAMP_REPLACE = 4,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct bpf_ksym {
-            pub start: u64,
-            pub end: u64,
-            pub name: [i8; 128],
-            pub lnode: list_head,
-            pub tnode: latch_tree_node,
-            pub prog: std::mem::MaybeUninit<bool>,
-            pub __pad_209: [u8; 7],
-        }
-        impl Default for bpf_ksym {
-            fn default() -> Self {
-                Self {
-                    start: u64::default(),
-                    end: u64::default(),
-                    name: [i8::default(); 128],
-                    lnode: list_head::default(),
-                    tnode: latch_tree_node::default(),
-                    prog: std::mem::MaybeUninit::new(bool::default()),
-                    __pad_209: [u8::default(); 7],
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct bpf_map {
-            pub ops: *mut std::ffi::c_void,
-            pub inner_map_meta: *mut bpf_map,
-            pub security: *mut std::ffi::c_void,
-            pub map_type: std::mem::MaybeUninit<bpf_map_type>,
-            pub key_size: u32,
-            pub value_size: u32,
-            pub max_entries: u32,
-            pub map_flags: u32,
-            pub spin_lock_off: i32,
-            pub id: u32,
-            pub numa_node: i32,
-            pub btf_key_type_id: u32,
-            pub btf_value_type_id: u32,
-            pub btf: *mut std::ffi::c_void,
-            pub memory: bpf_map_memory,
-            pub name: [i8; 16],
-            pub btf_vmlinux_value_type_id: u32,
-            pub bypass_spec_v1: std::mem::MaybeUninit<bool>,
-            pub frozen: std::mem::MaybeUninit<bool>,
-            pub __pad_110: [u8; 18],
-            pub refcnt: __anon_11,
-            pub usercnt: __anon_11,
-            pub work: work_struct,
-            pub freeze_mutex: mutex,
-            pub writecnt: u64,
-            pub __pad_216: [u8; 40],
-        }
-        impl Default for bpf_map {
-            fn default() -> Self {
-                Self {
-                    ops: std::ptr::null_mut(),
-                    inner_map_meta: std::ptr::null_mut(),
-                    security: std::ptr::null_mut(),
-                    map_type: std::mem::MaybeUninit::new(bpf_map_type::default()),
-                    key_size: u32::default(),
-                    value_size: u32::default(),
-                    max_entries: u32::default(),
-                    map_flags: u32::default(),
-                    spin_lock_off: i32::default(),
-                    id: u32::default(),
-                    numa_node: i32::default(),
-                    btf_key_type_id: u32::default(),
-                    btf_value_type_id: u32::default(),
-                    btf: std::ptr::null_mut(),
-                    memory: bpf_map_memory::default(),
-                    name: [i8::default(); 16],
-                    btf_vmlinux_value_type_id: u32::default(),
-                    bypass_spec_v1: std::mem::MaybeUninit::new(bool::default()),
-                    frozen: std::mem::MaybeUninit::new(bool::default()),
-                    __pad_110: [u8::default(); 18],
-                    refcnt: __anon_11::default(),
-                    usercnt: __anon_11::default(),
-                    work: work_struct::default(),
-                    freeze_mutex: mutex::default(),
-                    writecnt: u64::default(),
-                    __pad_216: [u8::default(); 40],
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_44 {
-            pub work: work_struct,
-            pub rcu: callback_head,
-        }
-        impl std::fmt::Debug for __anon_44 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_44 {
-            fn default() -> Self {
-                Self {
-                    work: work_struct::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_45 {
-            pub __anon_48: __anon_48,
-            pub __anon_49: __anon_49,
-        }
-        impl std::fmt::Debug for __anon_45 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_45 {
-            fn default() -> Self {
-                Self {
-                    __anon_48: __anon_48::default(),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union __anon_46 {
-            pub padding1: [u32; 12],
-            pub sw_reserved: [u32; 12],
-        }
-        impl std::fmt::Debug for __anon_46 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for __anon_46 {
-            fn default() -> Self {
-                Self {
-                    padding1: [u32::default(); 12],
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct xstate_header {
-            pub xfeatures: u64,
-            pub xcomp_bv: u64,
-            pub reserved: [u64; 6],
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_47 {
-            pub wlocked: u8,
-            pub __lstate: [u8; 3],
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct hw_perf_event_extra {
-            pub config: u64,
-            pub reg: u32,
-            pub alloc: i32,
-            pub idx: i32,
-            pub __pad_20: [u8; 4],
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct arch_hw_breakpoint {
-            pub address: u64,
-            pub mask: u64,
-            pub len: u8,
-            pub r#type: u8,
-            pub __pad_18: [u8; 6],
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct latch_tree_node {
-            pub node: [rb_node; 2],
-        }
-        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
-        #[repr(u32)]
-        pub enum bpf_map_type {
-            #[default]
-            BPF_MAP_TYPE_UNSPEC = 0,
-            BPF_MAP_TYPE_HASH = 1,
-            BPF_MAP_TYPE_ARRAY = 2,
-            BPF_MAP_TYPE_PROG_ARRAY = 3,
-            BPF_MAP_TYPE_PERF_EVENT_ARRAY = 4,
-            BPF_MAP_TYPE_PERCPU_HASH = 5,
-            BPF_MAP_TYPE_PERCPU_ARRAY = 6,
-            BPF_MAP_TYPE_STACK_TRACE = 7,
-            BPF_MAP_TYPE_CGROUP_ARRAY = 8,
-            BPF_MAP_TYPE_LRU_HASH = 9,
-            BPF_MAP_TYPE_LRU_PERCPU_HASH = 10,
-            BPF_MAP_TYPE_LPM_TRIE = 11,
-            BPF_MAP_TYPE_ARRAY_OF_MAPS = 12,
-            BPF_MAP_TYPE_HASH_OF_MAPS = 13,
-            BPF_MAP_TYPE_DEVMAP = 14,
-            BPF_MAP_TYPE_SOCKMAP = 15,
-            BPF_MAP_TYPE_CPUMAP = 16,
-            BPF_MAP_TYPE_XSKMAP = 17,
-            BPF_MAP_TYPE_SOCKHASH = 18,
-            BPF_MAP_TYPE_CGROUP_STORAGE = 19,
-            BPF_MAP_TYPE_REUSEPORT_SOCKARRAY = 20,
-            BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE = 21,
-            BPF_MAP_TYPE_QUEUE = 22,
-            BPF_MAP_TYPE_STACK = 23,
-            BPF_MAP_TYPE_SK_STORAGE = 24,
-            BPF_MAP_TYPE_DEVMAP_HASH = 25,
-            BPF_MAP_TYPE_STRUCT_OPS = 26,
-            BPF_MAP_TYPE_RINGBUF = 27,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct bpf_map_memory {
-            pub pages: u32,
-            pub __pad_4: [u8; 4],
-            pub user: *mut std::ffi::c_void,
-        }
-        impl Default for bpf_map_memory {
-            fn default() -> Self {
-                Self {
-                    pages: u32::default(),
-                    __pad_4: [u8::default(); 4],
-                    user: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct work_struct {
-            pub data: __anon_11,
-            pub entry: list_head,
-            pub func: *mut std::ffi::c_void,
-        }
-        impl Default for work_struct {
-            fn default() -> Self {
-                Self {
-                    data: __anon_11::default(),
-                    entry: list_head::default(),
-                    func: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_48 {
-            pub rip: u64,
-            pub rdp: u64,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_49 {
-            pub fip: u32,
-            pub fcs: u32,
-            pub foo: u32,
-            pub fos: u32,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct perf_sample_data {
-            pub addr: u64,
-            pub raw: *mut std::ffi::c_void,
-            pub br_stack: *mut std::ffi::c_void,
-            pub period: u64,
-            pub weight: u64,
-            pub txn: u64,
-            pub data_src: perf_mem_data_src,
-            pub r#type: u64,
-            pub ip: u64,
-            pub tid_entry: __anon_50,
-            pub time: u64,
-            pub id: u64,
-            pub stream_id: u64,
-            pub cpu_entry: __anon_51,
-            pub callchain: *mut std::ffi::c_void,
-            pub aux_size: u64,
-            pub regs_user: perf_regs,
-            pub regs_user_copy: pt_regs,
-            pub regs_intr: perf_regs,
-            pub stack_user_size: u64,
-            pub phys_addr: u64,
-            pub cgroup: u64,
-            pub __pad_352: [u8; 32],
-        }
-        impl Default for perf_sample_data {
-            fn default() -> Self {
-                Self {
-                    addr: u64::default(),
-                    raw: std::ptr::null_mut(),
-                    br_stack: std::ptr::null_mut(),
-                    period: u64::default(),
-                    weight: u64::default(),
-                    txn: u64::default(),
-                    data_src: perf_mem_data_src::default(),
-                    r#type: u64::default(),
-                    ip: u64::default(),
-                    tid_entry: __anon_50::default(),
-                    time: u64::default(),
-                    id: u64::default(),
-                    stream_id: u64::default(),
-                    cpu_entry: __anon_51::default(),
-                    callchain: std::ptr::null_mut(),
-                    aux_size: u64::default(),
-                    regs_user: perf_regs::default(),
-                    regs_user_copy: pt_regs::default(),
-                    regs_intr: perf_regs::default(),
-                    stack_user_size: u64::default(),
-                    phys_addr: u64::default(),
-                    cgroup: u64::default(),
-                    __pad_352: [u8::default(); 32],
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        pub union perf_mem_data_src {
-            pub val: u64,
-            pub __anon_52: __anon_52,
-        }
-        impl std::fmt::Debug for perf_mem_data_src {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "(???)")
-            }
-        }
-        impl Default for perf_mem_data_src {
-            fn default() -> Self {
-                Self {
-                    val: u64::default(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_50 {
-            pub pid: u32,
-            pub tid: u32,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_51 {
-            pub cpu: u32,
-            pub reserved: u32,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct perf_regs {
-            pub abi: u64,
-            pub regs: *mut pt_regs,
-        }
-        impl Default for perf_regs {
-            fn default() -> Self {
-                Self {
-                    abi: u64::default(),
-                    regs: std::ptr::null_mut(),
-                }
-            }
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct pt_regs {
-            pub r15: u64,
-            pub r14: u64,
-            pub r13: u64,
-            pub r12: u64,
-            pub bp: u64,
-            pub bx: u64,
-            pub r11: u64,
-            pub r10: u64,
-            pub r9: u64,
-            pub r8: u64,
-            pub ax: u64,
-            pub cx: u64,
-            pub dx: u64,
-            pub si: u64,
-            pub di: u64,
-            pub orig_ax: u64,
-            pub ip: u64,
-            pub cs: u64,
-            pub flags: u64,
-            pub sp: u64,
-            pub ss: u64,
-        }
-        #[derive(Debug, Default, Copy, Clone)]
-        #[repr(C)]
-        pub struct __anon_52 {
-            pub __pad_0: [u8; 8],
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct license {
-            pub _license: [i8; 4],
-        }
-    }
-    pub struct OpenPidIterSkel<'obj> {
-        obj: OwnedRef<'obj, libbpf_rs::OpenObject>,
-        pub maps: OpenPidIterMaps<'obj>,
-        pub progs: OpenPidIterProgs<'obj>,
-        pub struct_ops: StructOps,
-        skel_config: libbpf_rs::__internal_skel::ObjectSkeletonConfig<'obj>,
-    }
-
-    impl<'obj> OpenSkel<'obj> for OpenPidIterSkel<'obj> {
-        type Output = PidIterSkel<'obj>;
-        fn load(self) -> libbpf_rs::Result<PidIterSkel<'obj>> {
-            let skel_ptr = self.skel_config.as_libbpf_object().as_ptr();
-
-            let ret = unsafe { libbpf_sys::bpf_object__load_skeleton(skel_ptr) };
-            if ret != 0 {
-                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
-            }
-
-            let obj_ref = self.obj.take();
-            let open_obj = std::mem::replace(obj_ref, std::mem::MaybeUninit::uninit());
-            // SAFETY: `open_obj` is guaranteed to be properly
-            //         initialized as it came from an `OwnedRef`.
-            let obj_ptr = unsafe { open_obj.assume_init().take_ptr() };
-            // SAFETY: `obj_ptr` points to a loaded object after
-            //         skeleton load.
-            let obj = unsafe { libbpf_rs::Object::from_ptr(obj_ptr) };
-            // SAFETY: `OpenObject` and `Object` are guaranteed to
-            //         have the same memory layout.
-            let obj_ref = unsafe {
-                std::mem::transmute::<
-                    &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
-                    &'obj mut std::mem::MaybeUninit<libbpf_rs::Object>,
-                >(obj_ref)
-            };
-            let _obj = obj_ref.write(obj);
-            // SAFETY: We just wrote initialized data to `obj_ref`.
-            let mut obj_ref = unsafe { OwnedRef::new(obj_ref) };
-
-            Ok(PidIterSkel {
-                maps: unsafe { PidIterMaps::new(&self.skel_config, obj_ref.as_mut())? },
-                progs: PidIterProgs::new(self.progs),
-                obj: obj_ref,
-                struct_ops: self.struct_ops,
-                skel_config: self.skel_config,
-                links: PidIterLinks::default(),
-            })
-        }
-
-        fn open_object(&self) -> &libbpf_rs::OpenObject {
-            self.obj.as_ref()
-        }
-
-        fn open_object_mut(&mut self) -> &mut libbpf_rs::OpenObject {
-            self.obj.as_mut()
-        }
-    }
-    #[derive(Default)]
-    pub struct PidIterLinks {
-        pub bpftop_iter: Option<libbpf_rs::Link>,
-    }
-    pub struct PidIterSkel<'obj> {
-        obj: OwnedRef<'obj, libbpf_rs::Object>,
-        pub maps: PidIterMaps<'obj>,
-        pub progs: PidIterProgs<'obj>,
-        struct_ops: StructOps,
-        skel_config: libbpf_rs::__internal_skel::ObjectSkeletonConfig<'obj>,
-        pub links: PidIterLinks,
-    }
-
-    unsafe impl Send for PidIterSkel<'_> {}
-    unsafe impl Sync for PidIterSkel<'_> {}
-
-    impl<'obj> Skel<'obj> for PidIterSkel<'obj> {
-        fn object(&self) -> &libbpf_rs::Object {
-            self.obj.as_ref()
-        }
-
-        fn object_mut(&mut self) -> &mut libbpf_rs::Object {
-            self.obj.as_mut()
-        }
-        fn attach(&mut self) -> libbpf_rs::Result<()> {
-            let skel_ptr = self.skel_config.as_libbpf_object().as_ptr();
-            let ret = unsafe { libbpf_sys::bpf_object__attach_skeleton(skel_ptr) };
-            if ret != 0 {
-                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
-            }
-
-            self.links = PidIterLinks {
-                bpftop_iter: core::ptr::NonNull::new(self.skel_config.prog_link_ptr(0)?)
-                    .map(|ptr| unsafe { libbpf_rs::Link::from_ptr(ptr) }),
-            };
-
-            Ok(())
-        }
-    }
-    impl PidIterSkel<'_> {}
-    const DATA: &[u8] = &[
-        127, 69, 76, 70, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 247, 0, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 232, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
-        0, 64, 0, 8, 0, 1, 0, 0, 46, 115, 116, 114, 116, 97, 98, 0, 46, 115, 121, 109, 116, 97, 98,
-        0, 105, 116, 101, 114, 47, 116, 97, 115, 107, 95, 102, 105, 108, 101, 0, 108, 105, 99, 101,
-        110, 115, 101, 0, 112, 105, 100, 95, 105, 116, 101, 114, 46, 98, 112, 102, 46, 99, 0, 76,
-        66, 66, 48, 95, 52, 0, 98, 112, 102, 116, 111, 112, 95, 105, 116, 101, 114, 0, 98, 112,
-        102, 95, 112, 114, 111, 103, 95, 102, 111, 112, 115, 0, 95, 108, 105, 99, 101, 110, 115,
-        101, 0, 46, 114, 101, 108, 105, 116, 101, 114, 47, 116, 97, 115, 107, 95, 102, 105, 108,
-        101, 0, 46, 66, 84, 70, 0, 46, 66, 84, 70, 46, 101, 120, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 4, 0, 241, 255,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 3, 0, 88, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 62, 0, 0, 0, 18, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0, 0, 0, 0, 0, 74,
-        0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 17, 0,
-        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 121, 23, 8, 0, 0, 0, 0, 0, 121, 18,
-        24, 0, 0, 0, 0, 0, 21, 2, 40, 0, 0, 0, 0, 0, 21, 7, 39, 0, 0, 0, 0, 0, 121, 35, 40, 0, 0,
-        0, 0, 0, 24, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 67, 35, 0, 0, 0, 0, 0, 183,
-        3, 0, 0, 0, 0, 0, 0, 123, 58, 248, 255, 0, 0, 0, 0, 123, 58, 240, 255, 0, 0, 0, 0, 123, 58,
-        232, 255, 0, 0, 0, 0, 97, 115, 12, 5, 0, 0, 0, 0, 99, 58, 236, 255, 0, 0, 0, 0, 121, 35,
-        200, 0, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 15, 35, 0, 0, 0, 0, 0, 0, 191, 162, 0, 0, 0,
-        0, 0, 0, 7, 2, 0, 0, 224, 255, 255, 255, 191, 24, 0, 0, 0, 0, 0, 0, 191, 33, 0, 0, 0, 0, 0,
-        0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 183, 1, 0, 0, 28, 0, 0, 0, 121,
-        163, 224, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 166, 0, 0, 0, 0, 0, 0, 7, 6, 0,
-        0, 232, 255, 255, 255, 191, 97, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 133, 0, 0, 0,
-        113, 0, 0, 0, 121, 115, 72, 5, 0, 0, 0, 0, 183, 1, 0, 0, 192, 6, 0, 0, 15, 19, 0, 0, 0, 0,
-        0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 16, 0, 0,
-        0, 133, 0, 0, 0, 115, 0, 0, 0, 121, 129, 0, 0, 0, 0, 0, 0, 121, 17, 0, 0, 0, 0, 0, 0, 191,
-        98, 0, 0, 0, 0, 0, 0, 183, 3, 0, 0, 24, 0, 0, 0, 133, 0, 0, 0, 127, 0, 0, 0, 183, 0, 0, 0,
-        0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 71, 80, 76, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 5, 0, 0, 0, 159, 235, 1, 0, 24, 0, 0, 0, 0, 0, 0, 0, 192, 78, 0, 0, 192, 78, 0,
-        0, 202, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0,
-        0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 64, 0, 0, 0, 21, 0, 0, 0, 7, 0,
-        0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 5, 8, 0, 0,
-        0, 24, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 153, 1, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 5, 8, 0, 0, 0, 29, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 85, 0,
-        0, 0, 34, 0, 0, 0, 0, 0, 0, 8, 8, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 8, 9, 0, 0, 0, 44, 0, 0,
-        0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 5, 8, 0, 0, 0, 57, 0, 0, 0,
-        11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 15, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 13, 13,
-        0, 0, 0, 62, 0, 0, 0, 1, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 1, 70, 0,
-        0, 0, 1, 0, 0, 12, 12, 0, 0, 0, 57, 0, 0, 0, 22, 0, 0, 4, 0, 1, 0, 0, 82, 0, 0, 0, 16, 0,
-        0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 23, 0, 0, 0, 128, 0, 0, 0, 93, 0, 0, 0, 26, 0, 0, 0, 0, 1,
-        0, 0, 101, 0, 0, 0, 27, 0, 0, 0, 64, 1, 0, 0, 106, 0, 0, 0, 29, 0, 0, 0, 128, 1, 0, 0, 113,
-        0, 0, 0, 46, 0, 0, 0, 160, 1, 0, 0, 126, 0, 0, 0, 47, 0, 0, 0, 192, 1, 0, 0, 134, 0, 0, 0,
-        9, 0, 0, 0, 0, 2, 0, 0, 142, 0, 0, 0, 53, 0, 0, 0, 32, 2, 0, 0, 149, 0, 0, 0, 54, 0, 0, 0,
-        64, 2, 0, 0, 160, 0, 0, 0, 58, 0, 0, 0, 64, 3, 0, 0, 166, 0, 0, 0, 60, 0, 0, 0, 128, 3, 0,
-        0, 174, 0, 0, 0, 75, 0, 0, 0, 128, 4, 0, 0, 181, 0, 0, 0, 77, 0, 0, 0, 192, 4, 0, 0, 186,
-        0, 0, 0, 79, 0, 0, 0, 192, 5, 0, 0, 196, 0, 0, 0, 82, 0, 0, 0, 0, 6, 0, 0, 207, 0, 0, 0,
-        82, 0, 0, 0, 64, 6, 0, 0, 220, 0, 0, 0, 56, 0, 0, 0, 128, 6, 0, 0, 231, 0, 0, 0, 56, 0, 0,
-        0, 0, 7, 0, 0, 245, 0, 0, 0, 83, 0, 0, 0, 128, 7, 0, 0, 255, 0, 0, 0, 84, 0, 0, 0, 192, 7,
-        0, 0, 8, 1, 0, 0, 84, 0, 0, 0, 224, 7, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 16, 0, 0, 0, 17, 1, 0,
-        0, 17, 0, 0, 0, 0, 0, 0, 0, 26, 1, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 37, 1, 0, 0, 1, 0, 0, 4,
-        8, 0, 0, 0, 48, 1, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 17, 0, 0, 0, 53,
-        1, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 48, 1, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 67, 1, 0, 0, 21, 0,
-        0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 22, 0, 0,
-        0, 0, 0, 0, 0, 1, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 72, 1, 0, 0, 2, 0, 0, 4,
-        16, 0, 0, 0, 77, 1, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 81, 1, 0, 0, 25, 0, 0, 0, 64, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 161, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 162, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 2, 160, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 174,
-        1, 0, 0, 88, 1, 0, 0, 0, 0, 0, 8, 30, 0, 0, 0, 99, 1, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 0, 0,
-        0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 5, 4, 0, 0, 0, 108, 1, 0, 0, 32, 0, 0,
-        0, 0, 0, 0, 0, 114, 1, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 127, 1, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0,
-        136, 1, 0, 0, 0, 0, 0, 8, 34, 0, 0, 0, 152, 1, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 0,
-        35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 5, 4, 0, 0, 0, 162, 1, 0, 0, 36, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 166, 1,
-        0, 0, 0, 0, 0, 8, 37, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 175, 1, 0, 0, 13, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 2, 0, 0, 0, 183, 1, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0,
-        190, 1, 0, 0, 39, 0, 0, 0, 8, 0, 0, 0, 198, 1, 0, 0, 0, 0, 0, 8, 40, 0, 0, 0, 201, 1, 0, 0,
-        0, 0, 0, 8, 41, 0, 0, 0, 206, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 2,
-        0, 0, 4, 4, 0, 0, 0, 220, 1, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 235, 1, 0, 0, 43, 0, 0, 0, 16,
-        0, 0, 0, 240, 1, 0, 0, 0, 0, 0, 8, 44, 0, 0, 0, 244, 1, 0, 0, 0, 0, 0, 8, 45, 0, 0, 0, 250,
-        1, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 16, 0, 0, 0, 9, 2, 0, 0, 6, 0, 0, 6, 4, 0, 0, 0, 17, 2, 0,
-        0, 0, 0, 0, 0, 36, 2, 0, 0, 1, 0, 0, 0, 52, 2, 0, 0, 2, 0, 0, 0, 69, 2, 0, 0, 3, 0, 0, 0,
-        87, 2, 0, 0, 4, 0, 0, 0, 103, 2, 0, 0, 5, 0, 0, 0, 122, 2, 0, 0, 0, 0, 0, 8, 48, 0, 0, 0,
-        136, 2, 0, 0, 0, 0, 0, 8, 49, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 175, 1, 0, 0,
-        50, 0, 0, 0, 0, 0, 0, 0, 147, 2, 0, 0, 0, 0, 0, 8, 51, 0, 0, 0, 151, 2, 0, 0, 0, 0, 0, 8,
-        52, 0, 0, 0, 157, 2, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 64, 0, 0, 1, 167, 2, 0, 0, 0, 0, 0, 8,
-        9, 0, 0, 0, 175, 2, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 181, 2, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0,
-        187, 2, 0, 0, 29, 0, 0, 0, 64, 0, 0, 0, 197, 2, 0, 0, 55, 0, 0, 0, 96, 0, 0, 0, 201, 2, 0,
-        0, 56, 0, 0, 0, 128, 0, 0, 0, 211, 2, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 235, 1, 0, 0, 36, 0, 0,
-        0, 0, 0, 0, 0, 233, 2, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 48, 1, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0,
-        243, 2, 0, 0, 57, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 56, 0, 0, 0, 248, 2, 0, 0,
-        0, 0, 0, 8, 59, 0, 0, 0, 255, 2, 0, 0, 0, 0, 0, 8, 52, 0, 0, 0, 15, 3, 0, 0, 6, 0, 0, 4,
-        32, 0, 0, 0, 27, 3, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 32, 3, 0, 0, 69, 0, 0, 0, 64, 0, 0, 0,
-        36, 3, 0, 0, 70, 0, 0, 0, 128, 0, 0, 0, 45, 3, 0, 0, 71, 0, 0, 0, 160, 0, 0, 0, 49, 3, 0,
-        0, 71, 0, 0, 0, 192, 0, 0, 0, 54, 3, 0, 0, 13, 0, 0, 0, 224, 0, 0, 0, 61, 3, 0, 0, 0, 0, 0,
-        8, 62, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 127, 1, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0,
-        70, 3, 0, 0, 0, 0, 0, 8, 64, 0, 0, 0, 84, 3, 0, 0, 2, 0, 0, 4, 8, 0, 0, 0, 0, 0, 0, 0, 65,
-        0, 0, 0, 0, 0, 0, 0, 187, 2, 0, 0, 33, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 4, 0,
-        0, 0, 92, 3, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 2, 0, 0, 4, 4, 0, 0, 0, 97, 3, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 105, 3, 0, 0, 67, 0, 0, 0,
-        8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 39, 0, 0, 0, 68, 0, 0, 0, 3, 0, 0, 0, 114,
-        3, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 186, 1, 0, 0, 36, 3,
-        0, 0, 5, 0, 0, 6, 4, 0, 0, 0, 134, 3, 0, 0, 0, 0, 0, 0, 146, 3, 0, 0, 1, 0, 0, 0, 159, 3,
-        0, 0, 2, 0, 0, 0, 172, 3, 0, 0, 3, 0, 0, 0, 184, 3, 0, 0, 4, 0, 0, 0, 196, 3, 0, 0, 0, 0,
-        0, 8, 72, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 162, 1, 0, 0, 73, 0, 0, 0, 0, 0, 0,
-        0, 203, 3, 0, 0, 0, 0, 0, 8, 74, 0, 0, 0, 209, 3, 0, 0, 0, 0, 0, 8, 9, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 178, 0, 0, 0, 226, 3, 0, 0, 6, 0, 0, 4,
-        32, 0, 0, 0, 240, 3, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 246, 3, 0, 0, 9, 0, 0, 0, 64, 0, 0, 0,
-        251, 3, 0, 0, 9, 0, 0, 0, 96, 0, 0, 0, 6, 4, 0, 0, 9, 0, 0, 0, 128, 0, 0, 0, 15, 4, 0, 0,
-        9, 0, 0, 0, 160, 0, 0, 0, 25, 4, 0, 0, 58, 0, 0, 0, 192, 0, 0, 0, 34, 4, 0, 0, 0, 0, 0, 1,
-        8, 0, 0, 0, 64, 0, 0, 0, 48, 4, 0, 0, 0, 0, 0, 8, 80, 0, 0, 0, 52, 4, 0, 0, 0, 0, 0, 8, 81,
-        0, 0, 0, 58, 4, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 2, 173, 1, 0, 0, 77, 4, 0, 0, 0, 0, 0, 8, 7, 0, 0, 0, 86, 4, 0, 0,
-        225, 0, 0, 132, 64, 43, 0, 0, 98, 4, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 110, 4, 0, 0, 87, 0, 0,
-        0, 128, 0, 0, 0, 116, 4, 0, 0, 82, 0, 0, 0, 192, 0, 0, 0, 122, 4, 0, 0, 89, 0, 0, 0, 0, 1,
-        0, 0, 128, 4, 0, 0, 9, 0, 0, 0, 32, 1, 0, 0, 134, 4, 0, 0, 9, 0, 0, 0, 64, 1, 0, 0, 141, 4,
-        0, 0, 13, 0, 0, 0, 96, 1, 0, 0, 148, 4, 0, 0, 91, 0, 0, 0, 128, 1, 0, 0, 159, 4, 0, 0, 9,
-        0, 0, 0, 0, 2, 0, 0, 163, 4, 0, 0, 9, 0, 0, 0, 32, 2, 0, 0, 175, 4, 0, 0, 78, 0, 0, 0, 64,
-        2, 0, 0, 195, 4, 0, 0, 6, 0, 0, 0, 128, 2, 0, 0, 206, 4, 0, 0, 13, 0, 0, 0, 192, 2, 0, 0,
-        222, 4, 0, 0, 13, 0, 0, 0, 224, 2, 0, 0, 231, 4, 0, 0, 13, 0, 0, 0, 0, 3, 0, 0, 237, 4, 0,
-        0, 13, 0, 0, 0, 32, 3, 0, 0, 242, 4, 0, 0, 13, 0, 0, 0, 64, 3, 0, 0, 254, 4, 0, 0, 13, 0,
-        0, 0, 96, 3, 0, 0, 10, 5, 0, 0, 9, 0, 0, 0, 128, 3, 0, 0, 22, 5, 0, 0, 93, 0, 0, 0, 192, 3,
-        0, 0, 34, 5, 0, 0, 95, 0, 0, 0, 0, 4, 0, 0, 37, 5, 0, 0, 104, 0, 0, 0, 0, 18, 0, 0, 40, 5,
-        0, 0, 106, 0, 0, 0, 128, 19, 0, 0, 57, 5, 0, 0, 107, 0, 0, 0, 192, 19, 0, 0, 60, 5, 0, 0,
-        117, 0, 0, 0, 128, 26, 0, 0, 71, 5, 0, 0, 117, 0, 0, 0, 192, 26, 0, 0, 78, 5, 0, 0, 118, 0,
-        0, 0, 0, 27, 0, 0, 96, 5, 0, 0, 9, 0, 0, 0, 64, 27, 0, 0, 107, 5, 0, 0, 9, 0, 0, 0, 96, 27,
-        0, 0, 114, 5, 0, 0, 13, 0, 0, 0, 128, 27, 0, 0, 130, 5, 0, 0, 120, 0, 0, 0, 192, 27, 0, 0,
-        139, 5, 0, 0, 122, 0, 0, 0, 0, 28, 0, 0, 149, 5, 0, 0, 13, 0, 0, 0, 64, 29, 0, 0, 171, 5,
-        0, 0, 125, 0, 0, 0, 96, 29, 0, 0, 195, 5, 0, 0, 56, 0, 0, 0, 128, 29, 0, 0, 210, 5, 0, 0,
-        127, 0, 0, 0, 0, 30, 0, 0, 227, 5, 0, 0, 78, 0, 0, 0, 64, 30, 0, 0, 243, 5, 0, 0, 39, 0, 0,
-        0, 128, 30, 0, 0, 5, 6, 0, 0, 39, 0, 0, 0, 136, 30, 0, 0, 19, 6, 0, 0, 13, 0, 0, 0, 160,
-        30, 0, 0, 38, 6, 0, 0, 56, 0, 0, 0, 192, 30, 0, 0, 61, 6, 0, 0, 128, 0, 0, 0, 64, 31, 0, 0,
-        72, 6, 0, 0, 56, 0, 0, 0, 64, 32, 0, 0, 78, 6, 0, 0, 129, 0, 0, 0, 192, 32, 0, 0, 93, 6, 0,
-        0, 97, 0, 0, 0, 0, 34, 0, 0, 111, 6, 0, 0, 130, 0, 0, 0, 192, 34, 0, 0, 114, 6, 0, 0, 130,
-        0, 0, 0, 0, 35, 0, 0, 124, 6, 0, 0, 131, 0, 0, 0, 64, 35, 0, 0, 133, 6, 0, 0, 145, 0, 0, 0,
-        128, 36, 0, 0, 142, 6, 0, 0, 13, 0, 0, 0, 32, 37, 0, 0, 153, 6, 0, 0, 13, 0, 0, 0, 64, 37,
-        0, 0, 163, 6, 0, 0, 13, 0, 0, 0, 96, 37, 0, 0, 175, 6, 0, 0, 13, 0, 0, 0, 128, 37, 0, 0,
-        189, 6, 0, 0, 78, 0, 0, 0, 192, 37, 0, 0, 196, 6, 0, 0, 9, 0, 0, 0, 0, 38, 0, 0, 208, 6, 0,
-        0, 9, 0, 0, 0, 32, 38, 0, 1, 228, 6, 0, 0, 9, 0, 0, 0, 33, 38, 0, 1, 254, 6, 0, 0, 9, 0, 0,
-        0, 34, 38, 0, 1, 13, 7, 0, 0, 9, 0, 0, 0, 35, 38, 0, 1, 33, 7, 0, 0, 9, 0, 0, 0, 36, 38, 0,
-        1, 56, 7, 0, 0, 9, 0, 0, 0, 64, 38, 0, 1, 66, 7, 0, 0, 9, 0, 0, 0, 65, 38, 0, 1, 76, 7, 0,
-        0, 9, 0, 0, 0, 66, 38, 0, 1, 92, 7, 0, 0, 9, 0, 0, 0, 67, 38, 0, 1, 106, 7, 0, 0, 9, 0, 0,
-        0, 68, 38, 0, 1, 126, 7, 0, 0, 9, 0, 0, 0, 69, 38, 0, 1, 133, 7, 0, 0, 9, 0, 0, 0, 70, 38,
-        0, 1, 146, 7, 0, 0, 9, 0, 0, 0, 71, 38, 0, 1, 158, 7, 0, 0, 78, 0, 0, 0, 128, 38, 0, 0,
-        171, 7, 0, 0, 147, 0, 0, 0, 192, 38, 0, 0, 32, 3, 0, 0, 163, 0, 0, 0, 64, 40, 0, 0, 185, 7,
-        0, 0, 163, 0, 0, 0, 96, 40, 0, 0, 190, 7, 0, 0, 78, 0, 0, 0, 128, 40, 0, 0, 203, 7, 0, 0,
-        6, 0, 0, 0, 192, 40, 0, 0, 215, 7, 0, 0, 6, 0, 0, 0, 0, 41, 0, 0, 222, 7, 0, 0, 56, 0, 0,
-        0, 64, 41, 0, 0, 231, 7, 0, 0, 56, 0, 0, 0, 192, 41, 0, 0, 239, 7, 0, 0, 6, 0, 0, 0, 64,
-        42, 0, 0, 252, 7, 0, 0, 56, 0, 0, 0, 128, 42, 0, 0, 4, 8, 0, 0, 56, 0, 0, 0, 0, 43, 0, 0,
-        17, 8, 0, 0, 69, 0, 0, 0, 128, 43, 0, 0, 28, 8, 0, 0, 167, 0, 0, 0, 192, 43, 0, 0, 38, 8,
-        0, 0, 56, 0, 0, 0, 192, 45, 0, 0, 51, 8, 0, 0, 56, 0, 0, 0, 64, 46, 0, 0, 63, 8, 0, 0, 168,
-        0, 0, 0, 192, 46, 0, 0, 74, 8, 0, 0, 169, 0, 0, 0, 0, 47, 0, 0, 88, 8, 0, 0, 169, 0, 0, 0,
-        64, 47, 0, 0, 104, 8, 0, 0, 79, 0, 0, 0, 128, 47, 0, 0, 110, 8, 0, 0, 79, 0, 0, 0, 192, 47,
-        0, 0, 116, 8, 0, 0, 79, 0, 0, 0, 0, 48, 0, 0, 122, 8, 0, 0, 170, 0, 0, 0, 64, 48, 0, 0,
-        135, 8, 0, 0, 78, 0, 0, 0, 0, 49, 0, 0, 141, 8, 0, 0, 78, 0, 0, 0, 64, 49, 0, 0, 148, 8, 0,
-        0, 79, 0, 0, 0, 128, 49, 0, 0, 159, 8, 0, 0, 79, 0, 0, 0, 192, 49, 0, 0, 174, 8, 0, 0, 78,
-        0, 0, 0, 0, 50, 0, 0, 182, 8, 0, 0, 78, 0, 0, 0, 64, 50, 0, 0, 190, 8, 0, 0, 172, 0, 0, 0,
-        128, 50, 0, 0, 206, 8, 0, 0, 75, 0, 0, 0, 0, 53, 0, 0, 219, 8, 0, 0, 75, 0, 0, 0, 64, 53,
-        0, 0, 229, 8, 0, 0, 75, 0, 0, 0, 128, 53, 0, 0, 234, 8, 0, 0, 186, 0, 0, 0, 192, 53, 0, 0,
-        255, 8, 0, 0, 192, 0, 0, 0, 0, 54, 0, 0, 4, 9, 0, 0, 193, 0, 0, 0, 128, 54, 0, 0, 14, 9, 0,
-        0, 194, 0, 0, 0, 192, 54, 0, 0, 22, 9, 0, 0, 196, 0, 0, 0, 0, 55, 0, 0, 30, 9, 0, 0, 78, 0,
-        0, 0, 128, 55, 0, 0, 48, 9, 0, 0, 78, 0, 0, 0, 192, 55, 0, 0, 65, 9, 0, 0, 197, 0, 0, 0, 0,
-        56, 0, 0, 68, 9, 0, 0, 198, 0, 0, 0, 64, 56, 0, 0, 74, 9, 0, 0, 199, 0, 0, 0, 128, 56, 0,
-        0, 82, 9, 0, 0, 200, 0, 0, 0, 192, 56, 0, 0, 89, 9, 0, 0, 201, 0, 0, 0, 0, 57, 0, 0, 97, 9,
-        0, 0, 202, 0, 0, 0, 64, 57, 0, 0, 105, 9, 0, 0, 202, 0, 0, 0, 128, 57, 0, 0, 118, 9, 0, 0,
-        202, 0, 0, 0, 192, 57, 0, 0, 190, 1, 0, 0, 205, 0, 0, 0, 0, 58, 0, 0, 132, 9, 0, 0, 78, 0,
-        0, 0, 192, 58, 0, 0, 142, 9, 0, 0, 206, 0, 0, 0, 0, 59, 0, 0, 154, 9, 0, 0, 9, 0, 0, 0, 64,
-        59, 0, 0, 167, 9, 0, 0, 20, 0, 0, 0, 128, 59, 0, 0, 178, 9, 0, 0, 209, 0, 0, 0, 192, 59, 0,
-        0, 192, 9, 0, 0, 71, 0, 0, 0, 0, 60, 0, 0, 201, 9, 0, 0, 9, 0, 0, 0, 32, 60, 0, 0, 211, 9,
-        0, 0, 210, 0, 0, 0, 64, 60, 0, 0, 219, 9, 0, 0, 79, 0, 0, 0, 192, 60, 0, 0, 234, 9, 0, 0,
-        79, 0, 0, 0, 0, 61, 0, 0, 247, 9, 0, 0, 29, 0, 0, 0, 64, 61, 0, 0, 2, 10, 0, 0, 171, 0, 0,
-        0, 96, 61, 0, 0, 10, 10, 0, 0, 212, 0, 0, 0, 128, 61, 0, 0, 17, 10, 0, 0, 175, 0, 0, 0,
-        192, 61, 0, 0, 28, 10, 0, 0, 6, 0, 0, 0, 64, 62, 0, 0, 40, 10, 0, 0, 214, 0, 0, 0, 128, 62,
-        0, 0, 54, 10, 0, 0, 82, 0, 0, 0, 192, 62, 0, 0, 67, 10, 0, 0, 215, 0, 0, 0, 0, 63, 0, 0,
-        76, 10, 0, 0, 216, 0, 0, 0, 64, 63, 0, 0, 81, 10, 0, 0, 217, 0, 0, 0, 128, 63, 0, 0, 95,
-        10, 0, 0, 218, 0, 0, 0, 192, 63, 0, 0, 112, 10, 0, 0, 219, 0, 0, 0, 0, 64, 0, 0, 123, 10,
-        0, 0, 220, 0, 0, 0, 64, 64, 0, 0, 139, 10, 0, 0, 78, 0, 0, 0, 128, 64, 0, 0, 154, 10, 0, 0,
-        221, 0, 0, 0, 192, 64, 0, 0, 167, 10, 0, 0, 223, 0, 0, 0, 0, 65, 0, 0, 172, 10, 0, 0, 9, 0,
-        0, 0, 192, 66, 0, 0, 182, 10, 0, 0, 79, 0, 0, 0, 0, 67, 0, 0, 196, 10, 0, 0, 79, 0, 0, 0,
-        64, 67, 0, 0, 209, 10, 0, 0, 79, 0, 0, 0, 128, 67, 0, 0, 222, 10, 0, 0, 224, 0, 0, 0, 192,
-        67, 0, 0, 235, 10, 0, 0, 226, 0, 0, 0, 0, 68, 0, 0, 252, 10, 0, 0, 13, 0, 0, 0, 32, 68, 0,
-        0, 20, 11, 0, 0, 13, 0, 0, 0, 64, 68, 0, 0, 45, 11, 0, 0, 228, 0, 0, 0, 128, 68, 0, 0, 53,
-        11, 0, 0, 56, 0, 0, 0, 192, 68, 0, 0, 61, 11, 0, 0, 7, 0, 0, 0, 64, 69, 0, 0, 68, 11, 0, 0,
-        7, 0, 0, 0, 96, 69, 0, 0, 73, 11, 0, 0, 229, 0, 0, 0, 128, 69, 0, 0, 85, 11, 0, 0, 230, 0,
-        0, 0, 192, 69, 0, 0, 104, 11, 0, 0, 56, 0, 0, 0, 0, 70, 0, 0, 118, 11, 0, 0, 231, 0, 0, 0,
-        128, 70, 0, 0, 133, 11, 0, 0, 54, 0, 0, 0, 192, 70, 0, 0, 150, 11, 0, 0, 9, 0, 0, 0, 192,
-        71, 0, 0, 162, 11, 0, 0, 236, 0, 0, 0, 0, 72, 0, 0, 178, 11, 0, 0, 54, 0, 0, 0, 128, 72, 0,
-        0, 195, 11, 0, 0, 56, 0, 0, 0, 128, 73, 0, 0, 211, 11, 0, 0, 78, 0, 0, 0, 0, 74, 0, 0, 230,
-        11, 0, 0, 141, 0, 0, 0, 64, 74, 0, 0, 240, 11, 0, 0, 237, 0, 0, 0, 128, 74, 0, 0, 248, 11,
-        0, 0, 237, 0, 0, 0, 144, 74, 0, 0, 7, 12, 0, 0, 13, 0, 0, 0, 160, 74, 0, 0, 21, 12, 0, 0,
-        9, 0, 0, 0, 192, 74, 0, 0, 38, 12, 0, 0, 9, 0, 0, 0, 224, 74, 0, 0, 59, 12, 0, 0, 13, 0, 0,
-        0, 0, 75, 0, 0, 78, 12, 0, 0, 78, 0, 0, 0, 64, 75, 0, 0, 97, 12, 0, 0, 79, 0, 0, 0, 128,
-        75, 0, 0, 108, 12, 0, 0, 79, 0, 0, 0, 192, 75, 0, 0, 133, 12, 0, 0, 79, 0, 0, 0, 0, 76, 0,
-        0, 155, 12, 0, 0, 19, 0, 0, 0, 64, 76, 0, 0, 165, 12, 0, 0, 238, 0, 0, 0, 192, 76, 0, 0,
-        176, 12, 0, 0, 239, 0, 0, 0, 0, 77, 0, 0, 188, 12, 0, 0, 78, 0, 0, 0, 64, 77, 0, 0, 206,
-        12, 0, 0, 240, 0, 0, 0, 128, 77, 0, 0, 227, 12, 0, 0, 78, 0, 0, 0, 64, 78, 0, 0, 247, 12,
-        0, 0, 241, 0, 0, 0, 128, 78, 0, 0, 252, 12, 0, 0, 7, 0, 0, 0, 192, 78, 0, 0, 5, 13, 0, 0,
-        78, 0, 0, 0, 0, 79, 0, 0, 21, 13, 0, 0, 242, 0, 0, 0, 64, 79, 0, 0, 0, 0, 0, 0, 246, 0, 0,
-        0, 192, 80, 0, 0, 29, 13, 0, 0, 247, 0, 0, 0, 64, 81, 0, 0, 41, 13, 0, 0, 248, 0, 0, 0,
-        128, 81, 0, 0, 51, 13, 0, 0, 250, 0, 0, 0, 0, 82, 0, 0, 58, 13, 0, 0, 13, 0, 0, 0, 64, 82,
-        0, 0, 69, 13, 0, 0, 13, 0, 0, 0, 96, 82, 0, 0, 86, 13, 0, 0, 78, 0, 0, 0, 128, 82, 0, 0,
-        104, 13, 0, 0, 13, 0, 0, 0, 192, 82, 0, 0, 125, 13, 0, 0, 253, 0, 0, 0, 0, 83, 0, 0, 140,
-        13, 0, 0, 79, 0, 0, 0, 0, 203, 0, 0, 155, 13, 0, 0, 79, 0, 0, 0, 64, 203, 0, 0, 178, 13, 0,
-        0, 13, 0, 0, 0, 128, 203, 0, 0, 193, 13, 0, 0, 13, 0, 0, 0, 160, 203, 0, 0, 208, 13, 0, 0,
-        254, 0, 0, 0, 192, 203, 0, 0, 218, 13, 0, 0, 81, 0, 0, 0, 0, 204, 0, 0, 235, 13, 0, 0, 36,
-        0, 0, 0, 64, 204, 0, 0, 249, 13, 0, 0, 36, 0, 0, 0, 96, 204, 0, 0, 13, 14, 0, 0, 78, 0, 0,
-        0, 128, 204, 0, 0, 19, 14, 0, 0, 78, 0, 0, 0, 192, 204, 0, 0, 35, 14, 0, 0, 255, 0, 0, 0,
-        0, 205, 0, 0, 48, 14, 0, 0, 0, 1, 0, 0, 64, 205, 0, 0, 67, 14, 0, 0, 13, 0, 0, 0, 96, 205,
-        0, 0, 83, 14, 0, 0, 9, 0, 0, 0, 128, 205, 0, 0, 108, 14, 0, 0, 255, 0, 0, 0, 192, 205, 0,
-        0, 121, 14, 0, 0, 1, 1, 0, 0, 0, 206, 0, 0, 136, 14, 0, 0, 2, 1, 0, 0, 64, 206, 0, 0, 142,
-        14, 0, 0, 9, 0, 0, 0, 128, 206, 0, 0, 156, 14, 0, 0, 9, 0, 0, 0, 160, 206, 0, 0, 174, 14,
-        0, 0, 13, 0, 0, 0, 192, 206, 0, 0, 193, 14, 0, 0, 6, 0, 0, 0, 0, 207, 0, 0, 209, 14, 0, 0,
-        3, 1, 0, 0, 64, 207, 0, 0, 223, 14, 0, 0, 89, 0, 0, 0, 128, 207, 0, 0, 238, 14, 0, 0, 82,
-        0, 0, 0, 192, 207, 0, 0, 247, 14, 0, 0, 79, 0, 0, 0, 0, 208, 0, 0, 0, 15, 0, 0, 80, 0, 0,
-        0, 64, 208, 0, 1, 9, 15, 0, 0, 80, 0, 0, 0, 65, 208, 0, 1, 24, 15, 0, 0, 80, 0, 0, 0, 66,
-        208, 0, 62, 39, 15, 0, 0, 19, 0, 0, 0, 128, 208, 0, 0, 51, 15, 0, 0, 4, 1, 0, 0, 0, 210, 0,
-        0, 98, 4, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 128, 4, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 58, 15, 0,
-        0, 7, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 88, 0, 0, 0, 65, 15, 0, 0, 0, 0, 0, 1,
-        8, 0, 0, 0, 64, 0, 0, 1, 70, 15, 0, 0, 0, 0, 0, 8, 90, 0, 0, 0, 81, 15, 0, 0, 1, 0, 0, 4,
-        4, 0, 0, 0, 97, 15, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 102, 15, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0,
-        121, 15, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0,
-        2, 0, 0, 5, 4, 0, 0, 0, 127, 15, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 135, 15, 0, 0, 36, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 164, 1, 0, 0,
-        143, 15, 0, 0, 16, 0, 0, 4, 192, 1, 0, 0, 156, 15, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 161, 15,
-        0, 0, 97, 0, 0, 0, 128, 0, 0, 0, 170, 15, 0, 0, 56, 0, 0, 0, 64, 1, 0, 0, 231, 4, 0, 0, 9,
-        0, 0, 0, 192, 1, 0, 0, 181, 15, 0, 0, 79, 0, 0, 0, 0, 2, 0, 0, 192, 15, 0, 0, 79, 0, 0, 0,
-        64, 2, 0, 0, 209, 15, 0, 0, 79, 0, 0, 0, 128, 2, 0, 0, 218, 15, 0, 0, 79, 0, 0, 0, 192, 2,
-        0, 0, 240, 15, 0, 0, 79, 0, 0, 0, 0, 3, 0, 0, 254, 15, 0, 0, 99, 0, 0, 0, 64, 3, 0, 0, 9,
-        16, 0, 0, 13, 0, 0, 0, 0, 10, 0, 0, 215, 7, 0, 0, 100, 0, 0, 0, 64, 10, 0, 0, 15, 16, 0, 0,
-        101, 0, 0, 0, 128, 10, 0, 0, 22, 16, 0, 0, 101, 0, 0, 0, 192, 10, 0, 0, 27, 16, 0, 0, 78,
-        0, 0, 0, 0, 11, 0, 0, 43, 16, 0, 0, 102, 0, 0, 0, 0, 12, 0, 0, 47, 16, 0, 0, 2, 0, 0, 4,
-        16, 0, 0, 0, 59, 16, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 66, 16, 0, 0, 7, 0, 0, 0, 64, 0, 0, 0,
-        77, 16, 0, 0, 3, 0, 0, 4, 24, 0, 0, 0, 85, 16, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 103, 16, 0,
-        0, 98, 0, 0, 0, 64, 0, 0, 0, 112, 16, 0, 0, 98, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        2, 97, 0, 0, 0, 120, 16, 0, 0, 27, 0, 0, 4, 216, 0, 0, 0, 137, 16, 0, 0, 79, 0, 0, 0, 0, 0,
-        0, 0, 148, 16, 0, 0, 79, 0, 0, 0, 64, 0, 0, 0, 157, 16, 0, 0, 79, 0, 0, 0, 128, 0, 0, 0,
-        168, 16, 0, 0, 79, 0, 0, 0, 192, 0, 0, 0, 177, 16, 0, 0, 79, 0, 0, 0, 0, 1, 0, 0, 190, 16,
-        0, 0, 79, 0, 0, 0, 64, 1, 0, 0, 201, 16, 0, 0, 79, 0, 0, 0, 128, 1, 0, 0, 213, 16, 0, 0,
-        79, 0, 0, 0, 192, 1, 0, 0, 223, 16, 0, 0, 50, 0, 0, 0, 0, 2, 0, 0, 241, 16, 0, 0, 79, 0, 0,
-        0, 64, 2, 0, 0, 253, 16, 0, 0, 79, 0, 0, 0, 128, 2, 0, 0, 7, 17, 0, 0, 79, 0, 0, 0, 192, 2,
-        0, 0, 16, 17, 0, 0, 79, 0, 0, 0, 0, 3, 0, 0, 26, 17, 0, 0, 79, 0, 0, 0, 64, 3, 0, 0, 45,
-        17, 0, 0, 79, 0, 0, 0, 128, 3, 0, 0, 73, 17, 0, 0, 79, 0, 0, 0, 192, 3, 0, 0, 102, 17, 0,
-        0, 79, 0, 0, 0, 0, 4, 0, 0, 127, 17, 0, 0, 79, 0, 0, 0, 64, 4, 0, 0, 148, 17, 0, 0, 79, 0,
-        0, 0, 128, 4, 0, 0, 159, 17, 0, 0, 79, 0, 0, 0, 192, 4, 0, 0, 175, 17, 0, 0, 79, 0, 0, 0,
-        0, 5, 0, 0, 194, 17, 0, 0, 79, 0, 0, 0, 64, 5, 0, 0, 211, 17, 0, 0, 79, 0, 0, 0, 128, 5, 0,
-        0, 229, 17, 0, 0, 79, 0, 0, 0, 192, 5, 0, 0, 247, 17, 0, 0, 79, 0, 0, 0, 0, 6, 0, 0, 18,
-        18, 0, 0, 79, 0, 0, 0, 64, 6, 0, 0, 37, 18, 0, 0, 79, 0, 0, 0, 128, 6, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 2, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 166, 1, 0, 0, 53, 18, 0, 0, 9, 0, 0, 4, 64,
-        0, 0, 0, 63, 18, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 80, 18, 0, 0, 79, 0, 0, 0, 64, 0, 0, 0, 89,
-        18, 0, 0, 79, 0, 0, 0, 128, 0, 0, 0, 102, 18, 0, 0, 7, 0, 0, 0, 192, 0, 0, 0, 111, 18, 0,
-        0, 7, 0, 0, 0, 224, 0, 0, 0, 126, 18, 0, 0, 78, 0, 0, 0, 0, 1, 0, 0, 135, 18, 0, 0, 78, 0,
-        0, 0, 64, 1, 0, 0, 148, 18, 0, 0, 78, 0, 0, 0, 128, 1, 0, 0, 157, 18, 0, 0, 103, 0, 0, 0,
-        192, 1, 0, 0, 157, 18, 0, 0, 2, 0, 0, 4, 8, 0, 0, 0, 166, 18, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0,
-        175, 18, 0, 0, 9, 0, 0, 0, 32, 0, 0, 0, 180, 18, 0, 0, 7, 0, 0, 4, 48, 0, 0, 0, 196, 18, 0,
-        0, 56, 0, 0, 0, 0, 0, 0, 0, 205, 18, 0, 0, 78, 0, 0, 0, 128, 0, 0, 0, 213, 18, 0, 0, 78, 0,
-        0, 0, 192, 0, 0, 0, 228, 18, 0, 0, 9, 0, 0, 0, 0, 1, 0, 0, 231, 4, 0, 0, 45, 0, 0, 0, 32,
-        1, 0, 0, 239, 18, 0, 0, 45, 0, 0, 0, 48, 1, 0, 0, 247, 18, 0, 0, 105, 0, 0, 0, 64, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 169, 1, 0, 0, 252, 18, 0, 0,
-        16, 0, 0, 132, 216, 0, 0, 0, 77, 16, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 12, 19, 0, 0, 79, 0, 0,
-        0, 192, 0, 0, 0, 23, 19, 0, 0, 79, 0, 0, 0, 0, 1, 0, 0, 35, 19, 0, 0, 79, 0, 0, 0, 64, 1,
-        0, 0, 45, 19, 0, 0, 79, 0, 0, 0, 128, 1, 0, 0, 51, 19, 0, 0, 79, 0, 0, 0, 192, 1, 0, 0, 62,
-        19, 0, 0, 50, 0, 0, 0, 0, 2, 0, 0, 70, 19, 0, 0, 79, 0, 0, 0, 64, 2, 0, 0, 128, 4, 0, 0, 9,
-        0, 0, 0, 128, 2, 0, 0, 79, 19, 0, 0, 9, 0, 0, 0, 160, 2, 0, 1, 92, 19, 0, 0, 9, 0, 0, 0,
-        161, 2, 0, 1, 103, 19, 0, 0, 9, 0, 0, 0, 162, 2, 0, 1, 114, 19, 0, 0, 9, 0, 0, 0, 163, 2,
-        0, 1, 132, 19, 0, 0, 9, 0, 0, 0, 164, 2, 0, 1, 143, 19, 0, 0, 108, 0, 0, 0, 192, 2, 0, 0,
-        152, 19, 0, 0, 108, 0, 0, 0, 192, 4, 0, 0, 167, 19, 0, 0, 8, 0, 0, 4, 64, 0, 0, 0, 175, 19,
-        0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 180, 19, 0, 0, 110, 0, 0, 0, 0, 1, 0, 0, 193, 19, 0, 0,
-        111, 0, 0, 0, 64, 1, 0, 0, 202, 19, 0, 0, 115, 0, 0, 0, 128, 1, 0, 0, 110, 4, 0, 0, 39, 0,
-        0, 0, 192, 1, 0, 0, 207, 19, 0, 0, 39, 0, 0, 0, 200, 1, 0, 0, 214, 19, 0, 0, 39, 0, 0, 0,
-        208, 1, 0, 0, 222, 19, 0, 0, 39, 0, 0, 0, 216, 1, 0, 0, 230, 19, 0, 0, 2, 0, 0, 4, 32, 0,
-        0, 0, 175, 19, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 246, 19, 0, 0, 110, 0, 0, 0, 192, 0, 0, 0,
-        254, 19, 0, 0, 0, 0, 0, 8, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 112, 0, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 13, 113, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 6, 20, 0, 0, 2, 0, 0, 6, 4, 0, 0, 0,
-        22, 20, 0, 0, 0, 0, 0, 0, 40, 20, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 108, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 177, 1, 0, 0, 56, 20, 0, 0, 4, 0, 0, 132, 4, 0, 0, 0, 66, 20, 0, 0,
-        9, 0, 0, 0, 0, 0, 0, 11, 72, 20, 0, 0, 9, 0, 0, 0, 11, 0, 0, 3, 82, 20, 0, 0, 9, 0, 0, 0,
-        14, 0, 0, 1, 89, 20, 0, 0, 9, 0, 0, 0, 15, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
-        116, 0, 0, 0, 68, 0, 0, 0, 2, 0, 0, 0, 102, 20, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 113, 20, 0,
-        0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 122, 0, 0, 0, 119, 20, 0, 0, 0, 0, 0, 8, 123, 0, 0,
-        0, 129, 20, 0, 0, 1, 0, 0, 4, 40, 0, 0, 0, 137, 20, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 78, 0, 0, 0, 68, 0, 0, 0, 5, 0, 0, 0, 142, 20, 0, 0, 2, 0, 0,
-        5, 4, 0, 0, 0, 154, 20, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 156, 20, 0, 0, 7, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 4, 0, 0, 4, 4, 0, 0, 0, 97, 9, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 158, 20, 0, 0,
-        39, 0, 0, 0, 8, 0, 0, 0, 166, 20, 0, 0, 39, 0, 0, 0, 16, 0, 0, 0, 175, 20, 0, 0, 39, 0, 0,
-        0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 219, 1, 0, 0, 61, 6, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0,
-        183, 20, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 190, 20, 0, 0, 81, 0, 0, 0, 64, 0, 0, 0, 200, 20,
-        0, 0, 81, 0, 0, 0, 128, 0, 0, 0, 213, 20, 0, 0, 81, 0, 0, 0, 192, 0, 0, 0, 225, 20, 0, 0,
-        3, 0, 0, 4, 40, 0, 0, 0, 237, 4, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 236, 20, 0, 0, 56, 0, 0, 0,
-        64, 0, 0, 0, 246, 20, 0, 0, 56, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 171, 1, 0,
-        0, 124, 6, 0, 0, 2, 0, 0, 4, 40, 0, 0, 0, 0, 21, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 7, 21, 0,
-        0, 144, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 133, 0, 0, 0, 12, 21, 0, 0, 19, 0, 0,
-        4, 200, 0, 0, 0, 27, 21, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 36, 21, 0, 0, 78, 0, 0, 0, 64, 0,
-        0, 0, 43, 21, 0, 0, 132, 0, 0, 0, 128, 0, 0, 0, 51, 21, 0, 0, 132, 0, 0, 0, 192, 0, 0, 0,
-        59, 21, 0, 0, 97, 0, 0, 0, 0, 1, 0, 0, 65, 21, 0, 0, 78, 0, 0, 0, 192, 1, 0, 0, 80, 21, 0,
-        0, 130, 0, 0, 0, 0, 2, 0, 0, 86, 21, 0, 0, 134, 0, 0, 0, 64, 2, 0, 0, 99, 21, 0, 0, 78, 0,
-        0, 0, 128, 2, 0, 0, 108, 21, 0, 0, 137, 0, 0, 0, 192, 2, 0, 0, 115, 21, 0, 0, 56, 0, 0, 0,
-        192, 3, 0, 0, 130, 21, 0, 0, 138, 0, 0, 0, 64, 4, 0, 0, 139, 21, 0, 0, 139, 0, 0, 0, 128,
-        4, 0, 0, 146, 21, 0, 0, 78, 0, 0, 0, 192, 4, 0, 0, 155, 21, 0, 0, 11, 0, 0, 0, 0, 5, 0, 0,
-        163, 21, 0, 0, 82, 0, 0, 0, 64, 5, 0, 0, 179, 21, 0, 0, 47, 0, 0, 0, 128, 5, 0, 0, 199, 21,
-        0, 0, 141, 0, 0, 0, 192, 5, 0, 0, 209, 21, 0, 0, 142, 0, 0, 0, 0, 6, 0, 0, 228, 21, 0, 0,
-        0, 0, 0, 8, 135, 0, 0, 0, 237, 21, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 237, 21, 0, 0, 136, 0, 0,
-        0, 0, 0, 0, 0, 244, 21, 0, 0, 0, 0, 0, 8, 78, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 32, 0, 0, 0,
-        0, 22, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 3, 22, 0, 0, 78, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 188, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10,
-        170, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 189, 1, 0, 0, 209, 21, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0,
-        62, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 190, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 3, 0, 0, 0, 0, 132, 0, 0, 0, 68, 0, 0, 0, 4, 0, 0, 0, 19, 22, 0, 0, 2, 0, 0, 4, 20,
-        0, 0, 0, 33, 22, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 40, 22, 0, 0, 146, 0, 0, 0, 32, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 13, 0, 0, 0, 68, 0, 0, 0, 4, 0, 0, 0, 171, 7, 0, 0, 2, 0,
-        0, 4, 48, 0, 0, 0, 46, 22, 0, 0, 148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 0, 0, 0, 64, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 149, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 13, 88, 0, 0, 0, 0, 0, 0,
-        0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 147, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 5, 40, 0, 0, 0,
-        49, 22, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 55, 22, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 65, 22, 0,
-        0, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 4, 40, 0, 0, 0, 70, 22, 0, 0, 153, 0, 0,
-        0, 0, 0, 0, 0, 162, 1, 0, 0, 7, 0, 0, 0, 64, 0, 0, 0, 128, 4, 0, 0, 7, 0, 0, 0, 96, 0, 0,
-        0, 76, 22, 0, 0, 7, 0, 0, 0, 128, 0, 0, 0, 83, 22, 0, 0, 79, 0, 0, 0, 192, 0, 0, 0, 88, 22,
-        0, 0, 153, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 7, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4,
-        24, 0, 0, 0, 95, 22, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 103, 22, 0, 0, 157, 0, 0, 0, 32, 0, 0,
-        0, 0, 0, 0, 0, 158, 0, 0, 0, 64, 0, 0, 0, 246, 19, 0, 0, 79, 0, 0, 0, 128, 0, 0, 0, 108,
-        22, 0, 0, 0, 0, 0, 8, 156, 0, 0, 0, 118, 22, 0, 0, 0, 0, 0, 8, 13, 0, 0, 0, 137, 22, 0, 0,
-        3, 0, 0, 6, 4, 0, 0, 0, 151, 22, 0, 0, 0, 0, 0, 0, 159, 22, 0, 0, 1, 0, 0, 0, 169, 22, 0,
-        0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 8, 0, 0, 0, 179, 22, 0, 0, 159, 0, 0, 0, 0, 0, 0, 0,
-        184, 22, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 182, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 183, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 4, 32, 0, 0, 0, 196, 22, 0, 0, 162, 0, 0, 0,
-        0, 0, 0, 0, 201, 22, 0, 0, 13, 0, 0, 0, 64, 0, 0, 0, 206, 22, 0, 0, 13, 0, 0, 0, 96, 0, 0,
-        0, 218, 22, 0, 0, 78, 0, 0, 0, 128, 0, 0, 0, 225, 22, 0, 0, 78, 0, 0, 0, 192, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 2, 220, 1, 0, 0, 233, 22, 0, 0, 0, 0, 0, 8, 164, 0, 0, 0, 239, 22, 0, 0,
-        0, 0, 0, 8, 13, 0, 0, 0, 254, 22, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 48, 1, 0, 0, 119, 0, 0, 0,
-        0, 0, 0, 0, 9, 23, 0, 0, 166, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 119, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 165, 0, 0, 0, 68, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 2, 176, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 13, 0, 0, 0, 122, 8, 0, 0, 3, 0, 0, 4, 24,
-        0, 0, 0, 104, 8, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 110, 8, 0, 0, 79, 0, 0, 0, 64, 0, 0, 0, 27,
-        3, 0, 0, 171, 0, 0, 0, 128, 0, 0, 0, 15, 23, 0, 0, 0, 0, 0, 8, 32, 0, 0, 0, 190, 8, 0, 0,
-        3, 0, 0, 4, 80, 0, 0, 0, 30, 23, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 36, 23, 0, 0, 9, 0, 0, 0,
-        64, 2, 0, 0, 50, 23, 0, 0, 9, 0, 0, 0, 96, 2, 0, 0, 64, 23, 0, 0, 2, 0, 0, 4, 24, 0, 0, 0,
-        84, 23, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 92, 23, 0, 0, 174, 0, 0, 0, 64, 0, 0, 0, 99, 23, 0,
-        0, 1, 0, 0, 4, 16, 0, 0, 0, 115, 23, 0, 0, 175, 0, 0, 0, 0, 0, 0, 0, 123, 23, 0, 0, 2, 0,
-        0, 4, 16, 0, 0, 0, 115, 23, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 138, 23, 0, 0, 98, 0, 0, 0, 64,
-        0, 0, 0, 115, 23, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 77, 16, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 173, 0, 0, 0, 68, 0, 0, 0, 3, 0, 0, 0, 229, 8, 0, 0, 25,
-        0, 0, 4, 168, 0, 0, 0, 122, 4, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 45, 3, 0, 0, 71, 0, 0, 0, 32,
-        0, 0, 0, 150, 23, 0, 0, 179, 0, 0, 0, 64, 0, 0, 0, 154, 23, 0, 0, 71, 0, 0, 0, 96, 0, 0, 0,
-        159, 23, 0, 0, 179, 0, 0, 0, 128, 0, 0, 0, 49, 3, 0, 0, 71, 0, 0, 0, 160, 0, 0, 0, 164, 23,
-        0, 0, 179, 0, 0, 0, 192, 0, 0, 0, 169, 23, 0, 0, 71, 0, 0, 0, 224, 0, 0, 0, 175, 23, 0, 0,
-        179, 0, 0, 0, 0, 1, 0, 0, 181, 23, 0, 0, 9, 0, 0, 0, 32, 1, 0, 0, 192, 23, 0, 0, 183, 0, 0,
-        0, 64, 1, 0, 0, 208, 23, 0, 0, 183, 0, 0, 0, 128, 1, 0, 0, 222, 23, 0, 0, 183, 0, 0, 0,
-        192, 1, 0, 0, 236, 23, 0, 0, 183, 0, 0, 0, 0, 2, 0, 0, 245, 23, 0, 0, 183, 0, 0, 0, 64, 2,
-        0, 0, 1, 24, 0, 0, 41, 0, 0, 0, 128, 2, 0, 0, 13, 24, 0, 0, 186, 0, 0, 0, 192, 2, 0, 0, 29,
-        24, 0, 0, 186, 0, 0, 0, 0, 3, 0, 0, 45, 24, 0, 0, 186, 0, 0, 0, 64, 3, 0, 0, 60, 24, 0, 0,
-        186, 0, 0, 0, 128, 3, 0, 0, 238, 14, 0, 0, 82, 0, 0, 0, 192, 3, 0, 0, 77, 24, 0, 0, 187, 0,
-        0, 0, 0, 4, 0, 0, 82, 24, 0, 0, 188, 0, 0, 0, 64, 4, 0, 0, 90, 24, 0, 0, 189, 0, 0, 0, 128,
-        4, 0, 0, 0, 0, 0, 0, 190, 0, 0, 0, 192, 4, 0, 0, 101, 24, 0, 0, 0, 0, 0, 8, 180, 0, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 162, 1, 0, 0, 181, 0, 0, 0, 0, 0, 0, 0, 108, 24, 0, 0,
-        0, 0, 0, 8, 182, 0, 0, 0, 114, 24, 0, 0, 0, 0, 0, 8, 9, 0, 0, 0, 131, 24, 0, 0, 0, 0, 0, 8,
-        184, 0, 0, 0, 144, 24, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 162, 24, 0, 0, 185, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 8, 0, 0, 0, 68, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 2, 206, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 181, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 159,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 207, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 16, 0, 0, 0, 166,
-        24, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 174, 24, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 178, 24, 0, 0,
-        0, 0, 0, 1, 1, 0, 0, 0, 8, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 0, 0, 0, 68,
-        0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 221, 1, 0, 0, 183, 24, 0, 0, 1, 0, 0, 4, 8,
-        0, 0, 0, 192, 24, 0, 0, 195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 222, 1, 0, 0,
-        202, 24, 0, 0, 1, 0, 0, 4, 16, 0, 0, 0, 211, 24, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 223, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 224, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        225, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 226, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 227, 1, 0, 0,
-        221, 24, 0, 0, 0, 0, 0, 8, 203, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 230, 24, 0, 0,
-        204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 78, 0, 0, 0, 68, 0, 0, 0, 1,
-        0, 0, 0, 234, 24, 0, 0, 2, 0, 0, 4, 24, 0, 0, 0, 245, 24, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0,
-        82, 9, 0, 0, 202, 0, 0, 0, 128, 0, 0, 0, 250, 24, 0, 0, 0, 0, 0, 8, 207, 0, 0, 0, 1, 25, 0,
-        0, 0, 0, 0, 8, 208, 0, 0, 0, 17, 25, 0, 0, 0, 0, 0, 8, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        228, 1, 0, 0, 211, 9, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 34, 25, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0,
-        39, 25, 0, 0, 211, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 229, 1, 0, 0, 46, 25, 0,
-        0, 1, 0, 0, 4, 8, 0, 0, 0, 48, 1, 0, 0, 213, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 230, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 179, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 231, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 184, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 2, 167, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 178, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 185,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 222, 0, 0, 0, 58, 25, 0, 0, 0, 0, 0, 8, 218, 1, 0, 0, 75,
-        25, 0, 0, 7, 0, 0, 4, 56, 0, 0, 0, 94, 25, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 100, 25, 0, 0,
-        79, 0, 0, 0, 64, 0, 0, 0, 106, 25, 0, 0, 79, 0, 0, 0, 128, 0, 0, 0, 112, 25, 0, 0, 79, 0,
-        0, 0, 192, 0, 0, 0, 118, 25, 0, 0, 79, 0, 0, 0, 0, 1, 0, 0, 129, 25, 0, 0, 79, 0, 0, 0, 64,
-        1, 0, 0, 141, 25, 0, 0, 79, 0, 0, 0, 128, 1, 0, 0, 163, 25, 0, 0, 0, 0, 0, 8, 225, 0, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 137, 20, 0, 0, 204, 0, 0, 0, 0, 0, 0, 0, 174, 25, 0, 0,
-        0, 0, 0, 8, 227, 0, 0, 0, 185, 25, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 194, 25, 0, 0, 9, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 191, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 232, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 2, 233, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 234, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 2, 233, 0, 0, 0, 203, 25, 0, 0, 27, 0, 0, 4, 0, 1, 0, 0, 222, 25, 0, 0, 234, 0, 0, 0, 0,
-        0, 0, 0, 27, 3, 0, 0, 171, 0, 0, 0, 64, 0, 0, 0, 175, 2, 0, 0, 54, 0, 0, 0, 128, 0, 0, 0,
-        226, 25, 0, 0, 56, 0, 0, 0, 128, 1, 0, 0, 242, 25, 0, 0, 235, 0, 0, 0, 0, 2, 0, 0, 0, 26,
-        0, 0, 235, 0, 0, 0, 128, 2, 0, 0, 16, 26, 0, 0, 56, 0, 0, 0, 0, 3, 0, 0, 27, 26, 0, 0, 56,
-        0, 0, 0, 128, 3, 0, 0, 41, 26, 0, 0, 56, 0, 0, 0, 0, 4, 0, 0, 57, 26, 0, 0, 13, 0, 0, 0,
-        128, 4, 0, 0, 67, 26, 0, 0, 13, 0, 0, 0, 160, 4, 0, 0, 77, 26, 0, 0, 13, 0, 0, 0, 192, 4,
-        0, 0, 87, 26, 0, 0, 13, 0, 0, 0, 224, 4, 0, 0, 95, 26, 0, 0, 13, 0, 0, 0, 0, 5, 0, 0, 103,
-        26, 0, 0, 13, 0, 0, 0, 32, 5, 0, 0, 118, 26, 0, 0, 13, 0, 0, 0, 64, 5, 0, 0, 135, 26, 0, 0,
-        89, 0, 0, 0, 96, 5, 0, 0, 29, 0, 0, 0, 6, 0, 0, 0, 128, 5, 0, 0, 83, 22, 0, 0, 79, 0, 0, 0,
-        192, 5, 0, 0, 144, 26, 0, 0, 79, 0, 0, 0, 0, 6, 0, 0, 154, 26, 0, 0, 232, 0, 0, 0, 64, 6,
-        0, 0, 165, 26, 0, 0, 79, 0, 0, 0, 128, 6, 0, 0, 176, 26, 0, 0, 79, 0, 0, 0, 192, 6, 0, 0,
-        187, 26, 0, 0, 13, 0, 0, 0, 0, 7, 0, 0, 197, 26, 0, 0, 13, 0, 0, 0, 32, 7, 0, 0, 208, 26,
-        0, 0, 82, 0, 0, 0, 64, 7, 0, 0, 53, 1, 0, 0, 19, 0, 0, 0, 128, 7, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 2, 211, 1, 0, 0, 222, 26, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 240, 26, 0, 0, 176, 0, 0, 0, 0,
-        0, 0, 0, 245, 26, 0, 0, 79, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 232,
-        0, 0, 0, 68, 0, 0, 0, 2, 0, 0, 0, 251, 26, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 16, 0, 0, 1, 0, 0,
-        0, 0, 0, 0, 0, 2, 235, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        3, 0, 0, 0, 0, 78, 0, 0, 0, 68, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 236, 1, 0, 0,
-        1, 27, 0, 0, 3, 0, 0, 4, 48, 0, 0, 0, 22, 27, 0, 0, 243, 0, 0, 0, 0, 0, 0, 0, 27, 27, 0, 0,
-        244, 0, 0, 0, 64, 1, 0, 0, 42, 27, 0, 0, 244, 0, 0, 0, 72, 1, 0, 0, 51, 27, 0, 0, 1, 0, 0,
-        4, 40, 0, 0, 0, 129, 20, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 77, 27, 0, 0, 0, 0, 0, 8, 245, 0,
-        0, 0, 82, 27, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 8, 0, 0, 4, 0, 0, 0, 0, 2, 0, 0, 5, 16, 0, 0,
-        0, 88, 27, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 174, 24, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 2, 168, 1, 0, 0, 98, 27, 0, 0, 3, 0, 0, 4, 16, 0, 0, 0, 108, 27, 0, 0, 249, 0,
-        0, 0, 0, 0, 0, 0, 113, 27, 0, 0, 8, 0, 0, 0, 64, 0, 0, 0, 246, 3, 0, 0, 8, 0, 0, 0, 96, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 172, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 237, 1, 0, 0, 125, 13,
-        0, 0, 4, 0, 0, 4, 120, 0, 0, 0, 120, 27, 0, 0, 252, 0, 0, 0, 0, 0, 0, 0, 40, 22, 0, 0, 9,
-        0, 0, 0, 0, 3, 0, 0, 83, 22, 0, 0, 78, 0, 0, 0, 64, 3, 0, 0, 130, 27, 0, 0, 78, 0, 0, 0,
-        128, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 78, 0, 0, 0, 68, 0, 0, 0, 12, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 251, 0, 0, 0, 68, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 2, 238, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 180, 1, 0, 0, 134, 27, 0, 0, 0, 0, 0, 8, 9, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 165, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 239, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 2, 240, 1, 0, 0, 140, 27, 0, 0, 19, 0, 0, 132, 0, 17, 0, 0, 154, 27, 0, 0, 6,
-        1, 0, 0, 0, 0, 0, 0, 164, 27, 0, 0, 78, 0, 0, 0, 192, 0, 0, 0, 167, 27, 0, 0, 45, 0, 0, 0,
-        0, 1, 0, 0, 170, 27, 0, 0, 45, 0, 0, 0, 16, 1, 0, 0, 173, 27, 0, 0, 45, 0, 0, 0, 32, 1, 0,
-        0, 181, 27, 0, 0, 45, 0, 0, 0, 48, 1, 0, 0, 189, 27, 0, 0, 78, 0, 0, 0, 64, 1, 0, 0, 196,
-        27, 0, 0, 78, 0, 0, 0, 128, 1, 0, 0, 203, 27, 0, 0, 74, 1, 0, 0, 192, 1, 0, 0, 214, 27, 0,
-        0, 78, 0, 0, 0, 192, 2, 0, 0, 224, 27, 0, 0, 78, 0, 0, 0, 0, 3, 0, 0, 235, 27, 0, 0, 78, 0,
-        0, 0, 64, 3, 0, 0, 239, 27, 0, 0, 78, 0, 0, 0, 128, 3, 0, 0, 247, 27, 0, 0, 78, 0, 0, 0,
-        192, 3, 0, 0, 2, 28, 0, 0, 75, 1, 0, 0, 0, 4, 0, 0, 12, 28, 0, 0, 78, 0, 0, 0, 64, 4, 0, 0,
-        22, 28, 0, 0, 76, 1, 0, 0, 128, 4, 0, 0, 33, 28, 0, 0, 9, 0, 0, 0, 192, 4, 0, 1, 52, 28, 0,
-        0, 78, 1, 0, 0, 0, 6, 0, 0, 56, 28, 0, 0, 13, 0, 0, 132, 8, 0, 0, 0, 68, 28, 0, 0, 43, 0,
-        0, 0, 0, 0, 0, 0, 75, 28, 0, 0, 43, 0, 0, 0, 16, 0, 0, 0, 81, 28, 0, 0, 43, 0, 0, 0, 32, 0,
-        0, 8, 103, 22, 0, 0, 43, 0, 0, 0, 40, 0, 0, 4, 156, 20, 0, 0, 43, 0, 0, 0, 44, 0, 0, 1, 87,
-        28, 0, 0, 43, 0, 0, 0, 45, 0, 0, 2, 91, 28, 0, 0, 43, 0, 0, 0, 47, 0, 0, 1, 93, 28, 0, 0,
-        43, 0, 0, 0, 48, 0, 0, 4, 100, 28, 0, 0, 43, 0, 0, 0, 52, 0, 0, 1, 104, 28, 0, 0, 43, 0, 0,
-        0, 53, 0, 0, 1, 106, 28, 0, 0, 43, 0, 0, 0, 54, 0, 0, 1, 108, 28, 0, 0, 43, 0, 0, 0, 55, 0,
-        0, 1, 110, 28, 0, 0, 43, 0, 0, 0, 56, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 5, 1, 0,
-        0, 68, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 8, 1, 0, 0, 116, 28, 0, 0, 70, 0, 0, 4,
-        136, 4, 0, 0, 127, 28, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 139, 28, 0, 0, 56, 0, 0, 0, 128, 0,
-        0, 0, 152, 28, 0, 0, 56, 0, 0, 0, 0, 1, 0, 0, 170, 15, 0, 0, 97, 0, 0, 0, 128, 1, 0, 0,
-        164, 28, 0, 0, 79, 0, 0, 0, 64, 2, 0, 0, 176, 28, 0, 0, 56, 0, 0, 0, 128, 2, 0, 0, 190, 28,
-        0, 0, 165, 0, 0, 0, 0, 3, 0, 0, 202, 28, 0, 0, 56, 0, 0, 0, 128, 3, 0, 0, 215, 28, 0, 0,
-        13, 0, 0, 0, 0, 4, 0, 0, 227, 28, 0, 0, 13, 0, 0, 0, 32, 4, 0, 0, 238, 28, 0, 0, 13, 0, 0,
-        0, 64, 4, 0, 0, 239, 7, 0, 0, 7, 1, 0, 0, 128, 4, 0, 0, 222, 25, 0, 0, 234, 0, 0, 0, 192,
-        4, 0, 0, 249, 28, 0, 0, 82, 0, 0, 0, 0, 5, 0, 0, 110, 4, 0, 0, 9, 1, 0, 0, 64, 5, 0, 0, 5,
-        29, 0, 0, 9, 0, 0, 0, 96, 5, 0, 0, 40, 22, 0, 0, 10, 1, 0, 0, 128, 5, 0, 0, 18, 29, 0, 0,
-        48, 0, 0, 0, 192, 5, 0, 0, 30, 29, 0, 0, 79, 0, 0, 0, 0, 6, 0, 0, 49, 29, 0, 0, 79, 0, 0,
-        0, 64, 6, 0, 0, 68, 29, 0, 0, 79, 0, 0, 0, 128, 6, 0, 0, 75, 29, 0, 0, 79, 0, 0, 0, 192, 6,
-        0, 0, 91, 29, 0, 0, 14, 1, 0, 0, 0, 7, 0, 0, 96, 29, 0, 0, 43, 0, 0, 0, 192, 10, 0, 0, 108,
-        29, 0, 0, 43, 0, 0, 0, 208, 10, 0, 0, 123, 29, 0, 0, 43, 0, 0, 0, 224, 10, 0, 0, 133, 29,
-        0, 0, 20, 1, 0, 0, 0, 11, 0, 0, 62, 0, 0, 0, 232, 0, 0, 0, 0, 17, 0, 0, 135, 26, 0, 0, 47,
-        0, 0, 0, 64, 17, 0, 0, 136, 29, 0, 0, 48, 0, 0, 0, 128, 17, 0, 0, 161, 29, 0, 0, 48, 0, 0,
-        0, 192, 17, 0, 0, 186, 29, 0, 0, 54, 0, 0, 0, 0, 18, 0, 0, 198, 29, 0, 0, 56, 0, 0, 0, 0,
-        19, 0, 0, 215, 7, 0, 0, 7, 1, 0, 0, 128, 19, 0, 0, 209, 29, 0, 0, 13, 0, 0, 0, 192, 19, 0,
-        0, 159, 4, 0, 0, 13, 0, 0, 0, 224, 19, 0, 0, 215, 29, 0, 0, 56, 0, 0, 0, 0, 20, 0, 0, 181,
-        2, 0, 0, 6, 0, 0, 0, 128, 20, 0, 0, 227, 29, 0, 0, 54, 0, 0, 0, 192, 20, 0, 0, 238, 29, 0,
-        0, 36, 0, 0, 0, 192, 21, 0, 0, 0, 22, 0, 0, 30, 1, 0, 0, 0, 22, 0, 0, 249, 29, 0, 0, 56, 0,
-        0, 0, 64, 22, 0, 0, 2, 30, 0, 0, 78, 0, 0, 0, 192, 22, 0, 0, 14, 30, 0, 0, 13, 0, 0, 0, 0,
-        23, 0, 0, 26, 30, 0, 0, 31, 1, 0, 0, 64, 23, 0, 0, 32, 30, 0, 0, 33, 1, 0, 0, 0, 24, 0, 0,
-        39, 30, 0, 0, 13, 0, 0, 0, 64, 24, 0, 0, 54, 30, 0, 0, 13, 0, 0, 0, 96, 24, 0, 0, 67, 30,
-        0, 0, 13, 0, 0, 0, 128, 24, 0, 0, 190, 1, 0, 0, 34, 1, 0, 0, 192, 24, 0, 0, 83, 30, 0, 0,
-        36, 0, 0, 0, 128, 25, 0, 0, 95, 30, 0, 0, 40, 1, 0, 0, 192, 25, 0, 0, 108, 30, 0, 0, 41, 1,
-        0, 0, 128, 26, 0, 0, 127, 30, 0, 0, 78, 0, 0, 0, 192, 26, 0, 0, 144, 30, 0, 0, 7, 1, 0, 0,
-        0, 27, 0, 0, 154, 30, 0, 0, 42, 1, 0, 0, 64, 27, 0, 0, 53, 1, 0, 0, 19, 0, 0, 0, 128, 27,
-        0, 0, 162, 30, 0, 0, 44, 1, 0, 0, 0, 28, 0, 0, 165, 30, 0, 0, 79, 0, 0, 0, 64, 28, 0, 0,
-        168, 30, 0, 0, 45, 1, 0, 0, 128, 28, 0, 0, 174, 30, 0, 0, 47, 1, 0, 0, 192, 28, 0, 0, 191,
-        30, 0, 0, 82, 0, 0, 0, 0, 29, 0, 0, 216, 30, 0, 0, 47, 1, 0, 0, 64, 29, 0, 0, 238, 30, 0,
-        0, 62, 1, 0, 0, 128, 29, 0, 0, 243, 30, 0, 0, 63, 1, 0, 0, 192, 29, 0, 0, 39, 25, 0, 0, 64,
-        1, 0, 0, 0, 30, 0, 0, 252, 30, 0, 0, 65, 1, 0, 0, 64, 30, 0, 0, 7, 31, 0, 0, 73, 1, 0, 0,
-        64, 35, 0, 0, 238, 14, 0, 0, 82, 0, 0, 0, 128, 35, 0, 0, 12, 31, 0, 0, 56, 0, 0, 0, 192,
-        35, 0, 0, 20, 31, 0, 0, 6, 0, 0, 6, 4, 0, 0, 0, 37, 31, 0, 0, 252, 255, 255, 255, 59, 31,
-        0, 0, 253, 255, 255, 255, 81, 31, 0, 0, 254, 255, 255, 255, 104, 31, 0, 0, 255, 255, 255,
-        255, 125, 31, 0, 0, 0, 0, 0, 0, 151, 31, 0, 0, 1, 0, 0, 0, 175, 31, 0, 0, 0, 0, 0, 8, 11,
-        1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 185, 31, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 187,
-        31, 0, 0, 0, 0, 0, 8, 13, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 185, 31, 0, 0, 47,
-        0, 0, 0, 0, 0, 0, 0, 195, 31, 0, 0, 53, 0, 0, 132, 120, 0, 0, 0, 103, 22, 0, 0, 8, 0, 0, 0,
-        0, 0, 0, 0, 246, 3, 0, 0, 8, 0, 0, 0, 32, 0, 0, 0, 211, 31, 0, 0, 80, 0, 0, 0, 64, 0, 0, 0,
-        0, 0, 0, 0, 15, 1, 0, 0, 128, 0, 0, 0, 218, 31, 0, 0, 80, 0, 0, 0, 192, 0, 0, 0, 230, 31,
-        0, 0, 80, 0, 0, 0, 0, 1, 0, 0, 242, 31, 0, 0, 80, 0, 0, 0, 64, 1, 0, 1, 251, 31, 0, 0, 80,
-        0, 0, 0, 65, 1, 0, 1, 3, 32, 0, 0, 80, 0, 0, 0, 66, 1, 0, 1, 10, 32, 0, 0, 80, 0, 0, 0, 67,
-        1, 0, 1, 20, 32, 0, 0, 80, 0, 0, 0, 68, 1, 0, 1, 33, 32, 0, 0, 80, 0, 0, 0, 69, 1, 0, 1,
-        48, 32, 0, 0, 80, 0, 0, 0, 70, 1, 0, 1, 59, 32, 0, 0, 80, 0, 0, 0, 71, 1, 0, 1, 72, 32, 0,
-        0, 80, 0, 0, 0, 72, 1, 0, 1, 255, 8, 0, 0, 80, 0, 0, 0, 73, 1, 0, 1, 77, 32, 0, 0, 80, 0,
-        0, 0, 74, 1, 0, 1, 82, 32, 0, 0, 80, 0, 0, 0, 75, 1, 0, 1, 95, 32, 0, 0, 80, 0, 0, 0, 76,
-        1, 0, 1, 29, 0, 0, 0, 80, 0, 0, 0, 77, 1, 0, 1, 110, 32, 0, 0, 80, 0, 0, 0, 78, 1, 0, 1,
-        120, 32, 0, 0, 80, 0, 0, 0, 79, 1, 0, 2, 131, 32, 0, 0, 80, 0, 0, 0, 81, 1, 0, 1, 141, 32,
-        0, 0, 80, 0, 0, 0, 82, 1, 0, 1, 155, 32, 0, 0, 80, 0, 0, 0, 83, 1, 0, 1, 168, 32, 0, 0, 80,
-        0, 0, 0, 84, 1, 0, 1, 182, 32, 0, 0, 80, 0, 0, 0, 85, 1, 0, 1, 207, 32, 0, 0, 80, 0, 0, 0,
-        86, 1, 0, 1, 230, 32, 0, 0, 80, 0, 0, 0, 87, 1, 0, 1, 236, 32, 0, 0, 80, 0, 0, 0, 88, 1, 0,
-        1, 246, 32, 0, 0, 80, 0, 0, 0, 89, 1, 0, 1, 2, 33, 0, 0, 80, 0, 0, 0, 90, 1, 0, 1, 17, 33,
-        0, 0, 80, 0, 0, 0, 91, 1, 0, 1, 32, 33, 0, 0, 80, 0, 0, 0, 92, 1, 0, 1, 43, 33, 0, 0, 80,
-        0, 0, 0, 93, 1, 0, 1, 51, 33, 0, 0, 80, 0, 0, 0, 94, 1, 0, 1, 61, 33, 0, 0, 80, 0, 0, 0,
-        95, 1, 0, 1, 72, 33, 0, 0, 80, 0, 0, 0, 96, 1, 0, 1, 79, 33, 0, 0, 80, 0, 0, 0, 97, 1, 0,
-        31, 0, 0, 0, 0, 16, 1, 0, 0, 128, 1, 0, 0, 92, 33, 0, 0, 8, 0, 0, 0, 160, 1, 0, 0, 0, 0, 0,
-        0, 17, 1, 0, 0, 192, 1, 0, 0, 0, 0, 0, 0, 18, 1, 0, 0, 0, 2, 0, 0, 100, 33, 0, 0, 80, 0, 0,
-        0, 64, 2, 0, 0, 119, 33, 0, 0, 80, 0, 0, 0, 128, 2, 0, 0, 136, 33, 0, 0, 8, 0, 0, 0, 192,
-        2, 0, 0, 95, 22, 0, 0, 19, 1, 0, 0, 224, 2, 0, 0, 154, 33, 0, 0, 80, 0, 0, 0, 0, 3, 0, 0,
-        171, 33, 0, 0, 8, 0, 0, 0, 64, 3, 0, 0, 185, 33, 0, 0, 44, 0, 0, 0, 96, 3, 0, 0, 202, 33,
-        0, 0, 44, 0, 0, 0, 112, 3, 0, 0, 215, 33, 0, 0, 8, 0, 0, 0, 128, 3, 0, 0, 231, 33, 0, 0, 8,
-        0, 0, 0, 160, 3, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 8, 0, 0, 0, 244, 33, 0, 0, 80, 0, 0, 0, 0,
-        0, 0, 0, 2, 34, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 4, 0, 0, 0, 14, 34,
-        0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 28, 34, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 5,
-        8, 0, 0, 0, 45, 34, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 53, 34, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0,
-        65, 34, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 77, 34, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        4, 0, 0, 5, 8, 0, 0, 0, 85, 34, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 92, 34, 0, 0, 80, 0, 0, 0,
-        0, 0, 0, 0, 104, 34, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 117, 34, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0,
-        125, 34, 0, 0, 0, 0, 0, 8, 13, 0, 0, 0, 131, 34, 0, 0, 13, 0, 0, 4, 192, 0, 0, 0, 0, 0, 0,
-        0, 21, 1, 0, 0, 0, 0, 0, 0, 145, 34, 0, 0, 6, 0, 0, 0, 0, 3, 0, 0, 95, 30, 0, 0, 82, 0, 0,
-        0, 64, 3, 0, 0, 127, 30, 0, 0, 78, 0, 0, 0, 128, 3, 0, 0, 110, 4, 0, 0, 13, 0, 0, 0, 192,
-        3, 0, 0, 152, 34, 0, 0, 10, 1, 0, 0, 0, 4, 0, 0, 244, 33, 0, 0, 79, 0, 0, 0, 64, 4, 0, 0,
-        163, 34, 0, 0, 79, 0, 0, 0, 128, 4, 0, 0, 175, 34, 0, 0, 10, 1, 0, 0, 192, 4, 0, 0, 187,
-        34, 0, 0, 79, 0, 0, 0, 0, 5, 0, 0, 202, 34, 0, 0, 79, 0, 0, 0, 64, 5, 0, 0, 213, 34, 0, 0,
-        79, 0, 0, 0, 128, 5, 0, 0, 229, 34, 0, 0, 79, 0, 0, 0, 192, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0,
-        5, 96, 0, 0, 0, 0, 0, 0, 0, 22, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 25, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 4, 96, 0,
-        0, 0, 211, 31, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 246, 34, 0, 0, 79, 0, 0, 0, 64, 0, 0, 0, 255,
-        34, 0, 0, 78, 0, 0, 0, 128, 0, 0, 0, 11, 35, 0, 0, 78, 0, 0, 0, 192, 0, 0, 0, 22, 35, 0, 0,
-        13, 0, 0, 0, 0, 1, 0, 0, 39, 35, 0, 0, 13, 0, 0, 0, 32, 1, 0, 0, 43, 35, 0, 0, 13, 0, 0, 0,
-        64, 1, 0, 0, 128, 4, 0, 0, 13, 0, 0, 0, 96, 1, 0, 0, 52, 35, 0, 0, 23, 1, 0, 0, 128, 1, 0,
-        0, 62, 35, 0, 0, 23, 1, 0, 0, 64, 2, 0, 0, 73, 35, 0, 0, 4, 0, 0, 4, 24, 0, 0, 0, 211, 31,
-        0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 93, 35, 0, 0, 9, 0, 0, 0, 64, 0, 0, 0, 97, 35, 0, 0, 13, 0,
-        0, 0, 96, 0, 0, 0, 39, 35, 0, 0, 13, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 64, 0,
-        0, 0, 167, 19, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 16, 0, 0, 0, 103,
-        35, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 111, 35, 0, 0, 79,
-        0, 0, 0, 0, 0, 0, 0, 119, 35, 0, 0, 79, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 40,
-        0, 0, 0, 124, 35, 0, 0, 28, 1, 0, 0, 0, 0, 0, 0, 129, 35, 0, 0, 56, 0, 0, 0, 192, 0, 0, 0,
-        137, 35, 0, 0, 4, 0, 0, 4, 24, 0, 0, 0, 156, 35, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 164, 35, 0,
-        0, 78, 0, 0, 0, 64, 0, 0, 0, 169, 35, 0, 0, 39, 0, 0, 0, 128, 0, 0, 0, 103, 22, 0, 0, 39,
-        0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 4, 24, 0, 0, 0, 173, 35, 0, 0, 39, 0, 0, 0, 0,
-        0, 0, 0, 184, 35, 0, 0, 39, 0, 0, 0, 8, 0, 0, 0, 195, 35, 0, 0, 43, 0, 0, 0, 16, 0, 0, 0,
-        203, 35, 0, 0, 79, 0, 0, 0, 64, 0, 0, 0, 208, 35, 0, 0, 79, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 2, 213, 1, 0, 0, 214, 35, 0, 0, 0, 0, 0, 8, 32, 1, 0, 0, 232, 35, 0, 0, 2, 0,
-        0, 4, 24, 0, 0, 0, 27, 3, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 248, 35, 0, 0, 56, 0, 0, 0, 64, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 175, 1, 0, 0, 253, 35, 0, 0, 2, 0, 0, 4, 24, 0, 0, 0, 0, 0,
-        0, 0, 35, 1, 0, 0, 0, 0, 0, 0, 67, 1, 0, 0, 37, 1, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
-        5, 16, 0, 0, 0, 175, 19, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 6, 36, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 128, 4, 0, 0,
-        36, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 38, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 13, 0,
-        0, 0, 0, 0, 0, 0, 0, 39, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 34, 1, 0, 0, 13, 36, 0, 0, 3, 0,
-        0, 4, 24, 0, 0, 0, 245, 24, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 27, 3, 0, 0, 171, 0, 0, 0, 128,
-        0, 0, 0, 36, 36, 0, 0, 9, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 214, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 2, 43, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 2, 187, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 46, 1, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 13, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 36, 0, 0, 0, 0, 0, 8, 48, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 2, 49, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0,
-        0, 0, 0, 0, 0, 50, 1, 0, 0, 0, 0, 0, 0, 60, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 51, 1, 0, 0,
-        76, 36, 0, 0, 22, 0, 0, 4, 128, 1, 0, 0, 93, 36, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 98, 36, 0,
-        0, 52, 1, 0, 0, 64, 0, 0, 0, 102, 36, 0, 0, 53, 1, 0, 0, 128, 0, 0, 0, 111, 36, 0, 0, 79,
-        0, 0, 0, 192, 0, 0, 0, 59, 16, 0, 0, 79, 0, 0, 0, 0, 1, 0, 0, 118, 36, 0, 0, 79, 0, 0, 0,
-        64, 1, 0, 0, 122, 36, 0, 0, 54, 1, 0, 0, 128, 1, 0, 0, 103, 22, 0, 0, 79, 0, 0, 0, 192, 1,
-        0, 0, 131, 36, 0, 0, 79, 0, 0, 0, 0, 2, 0, 0, 134, 36, 0, 0, 56, 1, 0, 0, 64, 2, 0, 0, 83,
-        22, 0, 0, 79, 0, 0, 0, 128, 2, 0, 0, 165, 30, 0, 0, 79, 0, 0, 0, 192, 2, 0, 0, 144, 36, 0,
-        0, 79, 0, 0, 0, 0, 3, 0, 0, 154, 36, 0, 0, 57, 1, 0, 0, 64, 3, 0, 0, 164, 36, 0, 0, 58, 1,
-        0, 0, 128, 3, 0, 0, 174, 36, 0, 0, 79, 0, 0, 0, 192, 3, 0, 0, 183, 36, 0, 0, 59, 1, 0, 0,
-        0, 4, 0, 0, 193, 36, 0, 0, 61, 1, 0, 0, 128, 4, 0, 0, 208, 36, 0, 0, 59, 1, 0, 0, 192, 9,
-        0, 0, 218, 36, 0, 0, 79, 0, 0, 0, 64, 10, 0, 0, 234, 36, 0, 0, 79, 0, 0, 0, 128, 10, 0, 0,
-        72, 33, 0, 0, 79, 0, 0, 0, 192, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 215, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 216, 1, 0, 0, 244, 36, 0, 0, 2, 0, 0, 5, 8, 0, 0, 0, 162, 1, 0, 0, 80, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 55, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 132, 8, 0, 0, 0, 6,
-        37, 0, 0, 80, 0, 0, 0, 0, 0, 0, 5, 13, 37, 0, 0, 80, 0, 0, 0, 5, 0, 0, 14, 21, 37, 0, 0,
-        80, 0, 0, 0, 19, 0, 0, 5, 31, 37, 0, 0, 80, 0, 0, 0, 24, 0, 0, 2, 40, 37, 0, 0, 80, 0, 0,
-        0, 26, 0, 0, 7, 49, 37, 0, 0, 80, 0, 0, 0, 33, 0, 0, 4, 61, 37, 0, 0, 80, 0, 0, 0, 37, 0,
-        0, 1, 72, 37, 0, 0, 80, 0, 0, 0, 38, 0, 0, 2, 83, 37, 0, 0, 80, 0, 0, 0, 40, 0, 0, 24, 0,
-        0, 0, 0, 2, 0, 0, 4, 8, 0, 0, 0, 32, 3, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 92, 37, 0, 0, 7, 0,
-        0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 8, 0, 0, 0, 159, 4, 0, 0, 7, 0, 0, 0, 0, 0, 0,
-        0, 96, 37, 0, 0, 7, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 217, 1, 0, 0, 105, 37, 0,
-        0, 2, 0, 0, 4, 16, 0, 0, 0, 115, 37, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 119, 37, 0, 0, 60, 1,
-        0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 61, 1, 0, 0, 124, 37, 0, 0, 21, 0, 0, 4, 168, 0,
-        0, 0, 132, 37, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 136, 37, 0, 0, 78, 0, 0, 0, 64, 0, 0, 0, 140,
-        37, 0, 0, 78, 0, 0, 0, 128, 0, 0, 0, 144, 37, 0, 0, 78, 0, 0, 0, 192, 0, 0, 0, 148, 37, 0,
-        0, 78, 0, 0, 0, 0, 1, 0, 0, 151, 37, 0, 0, 78, 0, 0, 0, 64, 1, 0, 0, 154, 37, 0, 0, 78, 0,
-        0, 0, 128, 1, 0, 0, 158, 37, 0, 0, 78, 0, 0, 0, 192, 1, 0, 0, 162, 37, 0, 0, 78, 0, 0, 0,
-        0, 2, 0, 0, 165, 37, 0, 0, 78, 0, 0, 0, 64, 2, 0, 0, 168, 37, 0, 0, 78, 0, 0, 0, 128, 2, 0,
-        0, 171, 37, 0, 0, 78, 0, 0, 0, 192, 2, 0, 0, 174, 37, 0, 0, 78, 0, 0, 0, 0, 3, 0, 0, 177,
-        37, 0, 0, 78, 0, 0, 0, 64, 3, 0, 0, 180, 37, 0, 0, 78, 0, 0, 0, 128, 3, 0, 0, 183, 37, 0,
-        0, 78, 0, 0, 0, 192, 3, 0, 0, 131, 36, 0, 0, 78, 0, 0, 0, 0, 4, 0, 0, 191, 37, 0, 0, 78, 0,
-        0, 0, 64, 4, 0, 0, 128, 4, 0, 0, 78, 0, 0, 0, 128, 4, 0, 0, 164, 27, 0, 0, 78, 0, 0, 0,
-        192, 4, 0, 0, 194, 37, 0, 0, 78, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 97, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 208, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 209, 1, 0, 0, 252, 30, 0, 0,
-        10, 0, 0, 4, 160, 0, 0, 0, 67, 1, 0, 0, 66, 1, 0, 0, 0, 0, 0, 0, 48, 1, 0, 0, 69, 1, 0, 0,
-        64, 0, 0, 0, 128, 4, 0, 0, 78, 0, 0, 0, 128, 0, 0, 0, 197, 37, 0, 0, 82, 0, 0, 0, 192, 0,
-        0, 0, 205, 37, 0, 0, 66, 1, 0, 0, 0, 1, 0, 0, 216, 37, 0, 0, 70, 1, 0, 0, 64, 1, 0, 0, 227,
-        37, 0, 0, 72, 1, 0, 0, 192, 2, 0, 0, 237, 37, 0, 0, 70, 1, 0, 0, 0, 3, 0, 0, 246, 37, 0, 0,
-        78, 0, 0, 0, 128, 4, 0, 0, 1, 38, 0, 0, 78, 0, 0, 0, 192, 4, 0, 0, 17, 38, 0, 0, 0, 0, 0,
-        8, 67, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 68, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 13, 0, 0, 0, 0,
-        0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 69, 1, 0, 0, 0, 0, 0, 0, 60,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 65, 1, 0, 0, 31, 38, 0, 0, 3, 0, 0, 4, 48, 0, 0, 0, 47,
-        38, 0, 0, 71, 1, 0, 0, 0, 0, 0, 0, 60, 38, 0, 0, 71, 1, 0, 0, 64, 0, 0, 0, 72, 38, 0, 0,
-        54, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 210, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        70, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 212, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 7,
-        1, 0, 0, 68, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 241, 1, 0, 0, 83, 38, 0, 0, 0, 0,
-        0, 8, 77, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 96, 38, 0, 0, 78, 0, 0, 0, 0, 0, 0,
-        0, 52, 28, 0, 0, 3, 0, 0, 4, 64, 16, 0, 0, 43, 35, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 100, 38,
-        0, 0, 78, 0, 0, 0, 64, 0, 0, 0, 110, 4, 0, 0, 79, 1, 0, 0, 0, 2, 0, 0, 117, 38, 0, 0, 5, 0,
-        0, 5, 0, 16, 0, 0, 130, 38, 0, 0, 80, 1, 0, 0, 0, 0, 0, 0, 136, 38, 0, 0, 82, 1, 0, 0, 0,
-        0, 0, 0, 143, 38, 0, 0, 90, 1, 0, 0, 0, 0, 0, 0, 148, 38, 0, 0, 92, 1, 0, 0, 0, 0, 0, 0,
-        154, 38, 0, 0, 96, 1, 0, 0, 0, 0, 0, 0, 164, 38, 0, 0, 9, 0, 0, 4, 112, 0, 0, 0, 176, 38,
-        0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 180, 38, 0, 0, 7, 0, 0, 0, 32, 0, 0, 0, 184, 38, 0, 0, 7, 0,
-        0, 0, 64, 0, 0, 0, 188, 38, 0, 0, 7, 0, 0, 0, 96, 0, 0, 0, 192, 38, 0, 0, 7, 0, 0, 0, 128,
-        0, 0, 0, 196, 38, 0, 0, 7, 0, 0, 0, 160, 0, 0, 0, 200, 38, 0, 0, 7, 0, 0, 0, 192, 0, 0, 0,
-        204, 38, 0, 0, 81, 1, 0, 0, 224, 0, 0, 0, 58, 15, 0, 0, 7, 0, 0, 0, 96, 3, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 3, 0, 0, 0, 0, 7, 0, 0, 0, 68, 0, 0, 0, 20, 0, 0, 0, 213, 38, 0, 0, 11, 0, 0,
-        4, 0, 2, 0, 0, 176, 38, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 180, 38, 0, 0, 43, 0, 0, 0, 16, 0,
-        0, 0, 184, 38, 0, 0, 43, 0, 0, 0, 32, 0, 0, 0, 226, 38, 0, 0, 43, 0, 0, 0, 48, 0, 0, 0, 0,
-        0, 0, 0, 83, 1, 0, 0, 64, 0, 0, 0, 230, 38, 0, 0, 7, 0, 0, 0, 192, 0, 0, 0, 236, 38, 0, 0,
-        7, 0, 0, 0, 224, 0, 0, 0, 204, 38, 0, 0, 86, 1, 0, 0, 0, 1, 0, 0, 247, 38, 0, 0, 87, 1, 0,
-        0, 0, 5, 0, 0, 195, 35, 0, 0, 88, 1, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 89, 1, 0, 0, 128, 14,
-        0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 16, 0, 0, 0, 0, 0, 0, 0, 84, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        85, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 1, 39, 0, 0, 79, 0, 0, 0, 0,
-        0, 0, 0, 5, 39, 0, 0, 79, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 16, 0, 0, 0, 188,
-        38, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 192, 38, 0, 0, 7, 0, 0, 0, 32, 0, 0, 0, 196, 38, 0, 0, 7,
-        0, 0, 0, 64, 0, 0, 0, 200, 38, 0, 0, 7, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
-        0, 0, 7, 0, 0, 0, 68, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 7, 0, 0, 0,
-        68, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 7, 0, 0, 0, 68, 0, 0, 0, 12,
-        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 48, 0, 0, 0, 9, 39, 0, 0, 88, 1, 0, 0, 0, 0, 0, 0, 18, 39,
-        0, 0, 88, 1, 0, 0, 0, 0, 0, 0, 30, 39, 0, 0, 16, 0, 0, 4, 136, 0, 0, 0, 176, 38, 0, 0, 7,
-        0, 0, 0, 0, 0, 0, 0, 180, 38, 0, 0, 7, 0, 0, 0, 32, 0, 0, 0, 184, 38, 0, 0, 7, 0, 0, 0, 64,
-        0, 0, 0, 188, 38, 0, 0, 7, 0, 0, 0, 96, 0, 0, 0, 192, 38, 0, 0, 7, 0, 0, 0, 128, 0, 0, 0,
-        196, 38, 0, 0, 7, 0, 0, 0, 160, 0, 0, 0, 200, 38, 0, 0, 7, 0, 0, 0, 192, 0, 0, 0, 204, 38,
-        0, 0, 81, 1, 0, 0, 224, 0, 0, 0, 43, 39, 0, 0, 39, 0, 0, 0, 96, 3, 0, 0, 48, 39, 0, 0, 39,
-        0, 0, 0, 104, 3, 0, 0, 56, 39, 0, 0, 39, 0, 0, 0, 112, 3, 0, 0, 66, 39, 0, 0, 39, 0, 0, 0,
-        120, 3, 0, 0, 76, 39, 0, 0, 39, 0, 0, 0, 128, 3, 0, 0, 79, 39, 0, 0, 39, 0, 0, 0, 136, 3,
-        0, 0, 124, 35, 0, 0, 91, 1, 0, 0, 192, 3, 0, 0, 86, 39, 0, 0, 7, 0, 0, 0, 0, 4, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 2, 242, 1, 0, 0, 96, 39, 0, 0, 3, 0, 0, 4, 64, 2, 0, 0, 108, 39, 0, 0, 82,
-        1, 0, 0, 0, 0, 0, 0, 113, 39, 0, 0, 93, 1, 0, 0, 0, 16, 0, 0, 120, 39, 0, 0, 95, 1, 0, 0,
-        0, 18, 0, 0, 140, 39, 0, 0, 3, 0, 0, 4, 64, 0, 0, 0, 154, 39, 0, 0, 79, 0, 0, 0, 0, 0, 0,
-        0, 164, 39, 0, 0, 79, 0, 0, 0, 64, 0, 0, 0, 96, 37, 0, 0, 94, 1, 0, 0, 128, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 79, 0, 0, 0, 68, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
-        0, 0, 0, 0, 39, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 39,
-        0, 0, 0, 68, 0, 0, 0, 0, 16, 0, 0, 173, 39, 0, 0, 21, 0, 0, 132, 56, 0, 0, 0, 182, 39, 0,
-        0, 43, 0, 0, 0, 0, 0, 0, 0, 188, 39, 0, 0, 43, 0, 0, 0, 16, 0, 0, 1, 194, 39, 0, 0, 43, 0,
-        0, 0, 17, 0, 0, 1, 208, 39, 0, 0, 43, 0, 0, 0, 18, 0, 0, 1, 223, 39, 0, 0, 43, 0, 0, 0, 19,
-        0, 0, 1, 233, 39, 0, 0, 43, 0, 0, 0, 20, 0, 0, 1, 244, 39, 0, 0, 43, 0, 0, 0, 21, 0, 0, 1,
-        252, 39, 0, 0, 43, 0, 0, 0, 22, 0, 0, 1, 4, 40, 0, 0, 43, 0, 0, 0, 23, 0, 0, 1, 20, 40, 0,
-        0, 43, 0, 0, 0, 24, 0, 0, 1, 38, 40, 0, 0, 43, 0, 0, 0, 25, 0, 0, 1, 103, 22, 0, 0, 98, 1,
-        0, 0, 32, 0, 0, 0, 67, 40, 0, 0, 99, 1, 0, 0, 64, 0, 0, 0, 169, 35, 0, 0, 7, 0, 0, 0, 96,
-        0, 0, 0, 88, 40, 0, 0, 7, 0, 0, 0, 128, 0, 0, 0, 98, 40, 0, 0, 100, 1, 0, 0, 160, 0, 0, 0,
-        102, 40, 0, 0, 101, 1, 0, 0, 0, 1, 0, 0, 106, 40, 0, 0, 102, 1, 0, 0, 64, 1, 0, 0, 116, 40,
-        0, 0, 103, 1, 0, 0, 128, 1, 0, 0, 125, 40, 0, 0, 112, 1, 0, 0, 192, 1, 0, 0, 131, 40, 0, 0,
-        113, 1, 0, 0, 192, 1, 0, 0, 138, 40, 0, 0, 30, 0, 0, 6, 4, 0, 0, 0, 152, 40, 0, 0, 0, 0, 0,
-        0, 173, 40, 0, 0, 1, 0, 0, 0, 201, 40, 0, 0, 2, 0, 0, 0, 222, 40, 0, 0, 3, 0, 0, 0, 246,
-        40, 0, 0, 4, 0, 0, 0, 14, 41, 0, 0, 5, 0, 0, 0, 39, 41, 0, 0, 6, 0, 0, 0, 57, 41, 0, 0, 7,
-        0, 0, 0, 82, 41, 0, 0, 8, 0, 0, 0, 107, 41, 0, 0, 9, 0, 0, 0, 133, 41, 0, 0, 10, 0, 0, 0,
-        154, 41, 0, 0, 11, 0, 0, 0, 176, 41, 0, 0, 12, 0, 0, 0, 199, 41, 0, 0, 13, 0, 0, 0, 222,
-        41, 0, 0, 14, 0, 0, 0, 243, 41, 0, 0, 15, 0, 0, 0, 15, 42, 0, 0, 16, 0, 0, 0, 36, 42, 0, 0,
-        17, 0, 0, 0, 65, 42, 0, 0, 18, 0, 0, 0, 96, 42, 0, 0, 19, 0, 0, 0, 124, 42, 0, 0, 20, 0, 0,
-        0, 149, 42, 0, 0, 21, 0, 0, 0, 176, 42, 0, 0, 22, 0, 0, 0, 205, 42, 0, 0, 23, 0, 0, 0, 233,
-        42, 0, 0, 24, 0, 0, 0, 15, 43, 0, 0, 25, 0, 0, 0, 44, 43, 0, 0, 26, 0, 0, 0, 66, 43, 0, 0,
-        27, 0, 0, 0, 91, 43, 0, 0, 28, 0, 0, 0, 109, 43, 0, 0, 29, 0, 0, 0, 127, 43, 0, 0, 35, 0,
-        0, 6, 4, 0, 0, 0, 143, 43, 0, 0, 0, 0, 0, 0, 167, 43, 0, 0, 1, 0, 0, 0, 190, 43, 0, 0, 2,
-        0, 0, 0, 218, 43, 0, 0, 3, 0, 0, 0, 238, 43, 0, 0, 4, 0, 0, 0, 7, 44, 0, 0, 5, 0, 0, 0, 33,
-        44, 0, 0, 6, 0, 0, 0, 51, 44, 0, 0, 7, 0, 0, 0, 70, 44, 0, 0, 8, 0, 0, 0, 92, 44, 0, 0, 9,
-        0, 0, 0, 114, 44, 0, 0, 10, 0, 0, 0, 139, 44, 0, 0, 11, 0, 0, 0, 164, 44, 0, 0, 12, 0, 0,
-        0, 191, 44, 0, 0, 13, 0, 0, 0, 218, 44, 0, 0, 14, 0, 0, 0, 242, 44, 0, 0, 15, 0, 0, 0, 10,
-        45, 0, 0, 16, 0, 0, 0, 25, 45, 0, 0, 17, 0, 0, 0, 44, 45, 0, 0, 18, 0, 0, 0, 62, 45, 0, 0,
-        19, 0, 0, 0, 86, 45, 0, 0, 20, 0, 0, 0, 110, 45, 0, 0, 21, 0, 0, 0, 132, 45, 0, 0, 22, 0,
-        0, 0, 154, 45, 0, 0, 23, 0, 0, 0, 171, 45, 0, 0, 24, 0, 0, 0, 188, 45, 0, 0, 25, 0, 0, 0,
-        204, 45, 0, 0, 26, 0, 0, 0, 222, 45, 0, 0, 27, 0, 0, 0, 234, 45, 0, 0, 28, 0, 0, 0, 249,
-        45, 0, 0, 29, 0, 0, 0, 22, 46, 0, 0, 30, 0, 0, 0, 51, 46, 0, 0, 31, 0, 0, 0, 80, 46, 0, 0,
-        32, 0, 0, 0, 109, 46, 0, 0, 33, 0, 0, 0, 124, 46, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        3, 0, 0, 0, 0, 39, 0, 0, 0, 68, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 114, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 199, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 104, 1, 0, 0, 0, 0, 0, 0, 2,
-        0, 0, 13, 9, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 0, 0, 0, 107, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 2, 106, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 108, 1, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 10, 109, 1, 0, 0, 146, 46, 0, 0, 5, 0, 0, 132, 8, 0, 0, 0, 155, 46,
-        0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 160, 46, 0, 0, 40, 0, 0, 0, 8, 0, 0, 4, 168, 46, 0, 0, 40,
-        0, 0, 0, 12, 0, 0, 4, 176, 46, 0, 0, 110, 1, 0, 0, 16, 0, 0, 0, 180, 46, 0, 0, 19, 1, 0, 0,
-        32, 0, 0, 0, 184, 46, 0, 0, 0, 0, 0, 8, 237, 0, 0, 0, 190, 46, 0, 0, 4, 0, 0, 4, 8, 0, 0,
-        0, 155, 46, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 202, 46, 0, 0, 40, 0, 0, 0, 16, 0, 0, 0, 205,
-        46, 0, 0, 40, 0, 0, 0, 24, 0, 0, 0, 208, 46, 0, 0, 8, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 3, 0, 0, 0, 0, 111, 1, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0,
-        0, 0, 109, 1, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 210, 46, 0, 0, 48, 0, 0, 4, 40, 2, 0, 0, 223,
-        46, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 230, 46, 0, 0, 7, 0, 0, 0, 64, 0, 0, 0, 243, 46, 0, 0,
-        7, 0, 0, 0, 96, 0, 0, 0, 2, 47, 0, 0, 7, 0, 0, 0, 128, 0, 0, 0, 17, 47, 0, 0, 7, 0, 0, 0,
-        160, 0, 0, 0, 31, 47, 0, 0, 7, 0, 0, 0, 192, 0, 0, 0, 165, 30, 0, 0, 7, 0, 0, 0, 224, 0, 0,
-        0, 43, 47, 0, 0, 7, 0, 0, 0, 0, 1, 0, 0, 52, 47, 0, 0, 7, 0, 0, 0, 32, 1, 0, 0, 61, 47, 0,
-        0, 7, 0, 0, 0, 64, 1, 0, 0, 75, 47, 0, 0, 7, 0, 0, 0, 96, 1, 0, 0, 93, 47, 0, 0, 115, 1, 0,
-        0, 128, 1, 0, 0, 106, 47, 0, 0, 62, 1, 0, 0, 192, 1, 0, 0, 118, 47, 0, 0, 244, 0, 0, 0, 0,
-        2, 0, 0, 132, 47, 0, 0, 244, 0, 0, 0, 8, 2, 0, 0, 150, 47, 0, 0, 244, 0, 0, 0, 16, 2, 0, 0,
-        167, 47, 0, 0, 244, 0, 0, 0, 24, 2, 0, 0, 189, 47, 0, 0, 117, 1, 0, 0, 32, 2, 0, 0, 246,
-        37, 0, 0, 118, 1, 0, 0, 64, 2, 0, 0, 210, 47, 0, 0, 165, 0, 0, 0, 128, 2, 0, 0, 222, 47, 0,
-        0, 119, 1, 0, 0, 0, 3, 0, 0, 240, 47, 0, 0, 121, 1, 0, 0, 64, 3, 0, 0, 67, 1, 0, 0, 123, 1,
-        0, 0, 128, 3, 0, 0, 1, 48, 0, 0, 82, 0, 0, 0, 192, 3, 0, 0, 10, 48, 0, 0, 124, 1, 0, 0, 0,
-        4, 0, 0, 19, 48, 0, 0, 7, 0, 0, 0, 64, 4, 0, 0, 33, 48, 0, 0, 125, 1, 0, 0, 128, 4, 0, 0,
-        38, 48, 0, 0, 129, 1, 0, 0, 64, 11, 0, 0, 42, 48, 0, 0, 131, 1, 0, 0, 128, 11, 0, 0, 238,
-        30, 0, 0, 62, 1, 0, 0, 192, 11, 0, 0, 77, 24, 0, 0, 187, 0, 0, 0, 0, 12, 0, 0, 52, 48, 0,
-        0, 79, 0, 0, 0, 64, 12, 0, 0, 62, 48, 0, 0, 144, 1, 0, 0, 128, 12, 0, 0, 77, 48, 0, 0, 192,
-        0, 0, 0, 0, 13, 0, 0, 238, 14, 0, 0, 82, 0, 0, 0, 128, 13, 0, 0, 82, 48, 0, 0, 145, 1, 0,
-        0, 192, 13, 0, 0, 90, 48, 0, 0, 137, 1, 0, 0, 0, 14, 0, 0, 94, 48, 0, 0, 146, 1, 0, 0, 64,
-        14, 0, 0, 104, 48, 0, 0, 147, 1, 0, 0, 128, 14, 0, 0, 118, 48, 0, 0, 148, 1, 0, 0, 192, 14,
-        0, 0, 124, 48, 0, 0, 149, 1, 0, 0, 0, 15, 0, 0, 136, 48, 0, 0, 7, 0, 0, 0, 64, 15, 0, 0,
-        150, 48, 0, 0, 7, 0, 0, 0, 96, 15, 0, 0, 159, 48, 0, 0, 7, 0, 0, 0, 128, 15, 0, 0, 169, 48,
-        0, 0, 7, 0, 0, 0, 160, 15, 0, 0, 183, 48, 0, 0, 150, 1, 0, 0, 192, 15, 0, 0, 191, 48, 0, 0,
-        151, 1, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 152, 1, 0, 0, 64, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        116, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 192, 1, 0, 0, 197, 48, 0, 0, 5, 0, 0, 6, 4, 0, 0, 0,
-        217, 48, 0, 0, 0, 0, 0, 0, 234, 48, 0, 0, 1, 0, 0, 0, 250, 48, 0, 0, 2, 0, 0, 0, 18, 49, 0,
-        0, 3, 0, 0, 0, 32, 49, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 193, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 120, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 194, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        122, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 62, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 195, 1, 0, 0, 50, 49, 0, 0, 6, 0, 0, 4, 216, 0, 0, 0, 240, 3, 0, 0,
-        78, 0, 0, 0, 0, 0, 0, 0, 59, 49, 0, 0, 78, 0, 0, 0, 64, 0, 0, 0, 77, 48, 0, 0, 126, 1, 0,
-        0, 128, 0, 0, 0, 63, 49, 0, 0, 56, 0, 0, 0, 128, 4, 0, 0, 69, 49, 0, 0, 127, 1, 0, 0, 0, 5,
-        0, 0, 238, 30, 0, 0, 244, 0, 0, 0, 128, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191,
-        0, 0, 0, 68, 0, 0, 0, 128, 0, 0, 0, 75, 49, 0, 0, 1, 0, 0, 4, 48, 0, 0, 0, 175, 19, 0, 0,
-        128, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 97, 0, 0, 0, 68, 0, 0, 0, 2,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 130, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 198, 1, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 2, 132, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 133, 1, 0, 0, 91, 49, 0, 0, 24, 0,
-        0, 4, 0, 1, 0, 0, 38, 48, 0, 0, 134, 1, 0, 0, 0, 0, 0, 0, 99, 49, 0, 0, 132, 1, 0, 0, 64,
-        0, 0, 0, 238, 14, 0, 0, 82, 0, 0, 0, 128, 0, 0, 0, 114, 49, 0, 0, 136, 1, 0, 0, 192, 0, 0,
-        0, 123, 49, 0, 0, 7, 0, 0, 0, 224, 0, 0, 0, 132, 49, 0, 0, 7, 0, 0, 0, 0, 1, 0, 0, 143, 49,
-        0, 0, 7, 0, 0, 0, 32, 1, 0, 0, 155, 49, 0, 0, 7, 0, 0, 0, 64, 1, 0, 0, 165, 49, 0, 0, 13,
-        0, 0, 0, 96, 1, 0, 0, 165, 30, 0, 0, 7, 0, 0, 0, 128, 1, 0, 0, 179, 49, 0, 0, 13, 0, 0, 0,
-        160, 1, 0, 0, 189, 49, 0, 0, 7, 0, 0, 0, 192, 1, 0, 0, 205, 49, 0, 0, 7, 0, 0, 0, 224, 1,
-        0, 0, 90, 48, 0, 0, 137, 1, 0, 0, 0, 2, 0, 0, 223, 49, 0, 0, 138, 1, 0, 0, 64, 2, 0, 0, 77,
-        48, 0, 0, 192, 0, 0, 0, 192, 2, 0, 0, 230, 49, 0, 0, 7, 0, 0, 0, 64, 3, 0, 0, 0, 50, 0, 0,
-        244, 0, 0, 0, 96, 3, 0, 0, 126, 7, 0, 0, 244, 0, 0, 0, 104, 3, 0, 0, 223, 46, 0, 0, 48, 0,
-        0, 0, 0, 4, 0, 0, 15, 50, 0, 0, 48, 0, 0, 0, 64, 4, 0, 0, 23, 50, 0, 0, 139, 1, 0, 0, 128,
-        4, 0, 0, 28, 50, 0, 0, 54, 0, 0, 0, 128, 5, 0, 0, 41, 50, 0, 0, 79, 0, 0, 0, 128, 6, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 135, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 196, 1, 0, 0, 50, 50, 0, 0,
-        28, 0, 0, 6, 4, 0, 0, 0, 63, 50, 0, 0, 0, 0, 0, 0, 83, 50, 0, 0, 1, 0, 0, 0, 101, 50, 0, 0,
-        2, 0, 0, 0, 120, 50, 0, 0, 3, 0, 0, 0, 144, 50, 0, 0, 4, 0, 0, 0, 174, 50, 0, 0, 5, 0, 0,
-        0, 199, 50, 0, 0, 6, 0, 0, 0, 225, 50, 0, 0, 7, 0, 0, 0, 250, 50, 0, 0, 8, 0, 0, 0, 20, 51,
-        0, 0, 9, 0, 0, 0, 42, 51, 0, 0, 10, 0, 0, 0, 71, 51, 0, 0, 11, 0, 0, 0, 93, 51, 0, 0, 12,
-        0, 0, 0, 120, 51, 0, 0, 13, 0, 0, 0, 146, 51, 0, 0, 14, 0, 0, 0, 166, 51, 0, 0, 15, 0, 0,
-        0, 187, 51, 0, 0, 16, 0, 0, 0, 207, 51, 0, 0, 17, 0, 0, 0, 227, 51, 0, 0, 18, 0, 0, 0, 249,
-        51, 0, 0, 19, 0, 0, 0, 21, 52, 0, 0, 20, 0, 0, 0, 54, 52, 0, 0, 21, 0, 0, 0, 89, 52, 0, 0,
-        22, 0, 0, 0, 108, 52, 0, 0, 23, 0, 0, 0, 127, 52, 0, 0, 24, 0, 0, 0, 151, 52, 0, 0, 25, 0,
-        0, 0, 176, 52, 0, 0, 26, 0, 0, 0, 200, 52, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 197,
-        1, 0, 0, 221, 52, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 182, 39, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 77,
-        24, 0, 0, 187, 0, 0, 0, 64, 0, 0, 0, 236, 52, 0, 0, 3, 0, 0, 4, 32, 0, 0, 0, 248, 52, 0, 0,
-        47, 0, 0, 0, 0, 0, 0, 0, 253, 52, 0, 0, 56, 0, 0, 0, 64, 0, 0, 0, 67, 1, 0, 0, 140, 1, 0,
-        0, 192, 0, 0, 0, 3, 53, 0, 0, 0, 0, 0, 8, 141, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 142, 1, 0,
-        0, 0, 0, 0, 0, 1, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 143, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        139, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 132, 1, 0, 0, 68, 0, 0, 0, 2, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 2, 200, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 202, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 2, 203, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 201, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 82, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 2, 204, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 205, 1, 0, 0, 0, 0, 0, 0,
-        2, 0, 0, 5, 32, 0, 0, 0, 23, 50, 0, 0, 139, 1, 0, 0, 0, 0, 0, 0, 174, 24, 0, 0, 19, 0, 0,
-        0, 0, 0, 0, 0, 15, 53, 0, 0, 3, 0, 0, 4, 24, 0, 0, 0, 0, 0, 0, 0, 154, 1, 0, 0, 0, 0, 0, 0,
-        29, 53, 0, 0, 79, 0, 0, 0, 64, 0, 0, 0, 40, 53, 0, 0, 79, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0,
-        0, 1, 0, 0, 5, 8, 0, 0, 0, 48, 53, 0, 0, 155, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        163, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 0, 0, 0, 68, 0, 0, 0, 4, 0, 0, 0,
-        52, 53, 0, 0, 0, 0, 0, 14, 156, 1, 0, 0, 1, 0, 0, 0, 61, 53, 0, 0, 0, 0, 0, 14, 106, 1, 0,
-        0, 2, 0, 0, 0, 75, 53, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 90, 53, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
-        96, 53, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 81, 1, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 105, 53, 0, 0,
-        0, 0, 0, 7, 0, 0, 0, 0, 22, 5, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 114, 53, 0, 0, 0, 0, 0, 7, 0,
-        0, 0, 0, 15, 16, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 95, 10, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 128,
-        53, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 144, 53, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 155, 53, 0, 0, 0,
-        0, 0, 7, 0, 0, 0, 0, 176, 53, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 108, 27, 0, 0, 0, 0, 0, 7, 0,
-        0, 0, 0, 186, 53, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 200, 53, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 216,
-        53, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 230, 53, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 241, 53, 0, 0, 0,
-        0, 0, 7, 0, 0, 0, 0, 112, 10, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 67, 10, 0, 0, 0, 0, 0, 7, 0, 0,
-        0, 0, 4, 54, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 15, 54, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 27, 54, 0,
-        0, 0, 0, 0, 7, 0, 0, 0, 0, 45, 54, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 81, 10, 0, 0, 0, 0, 0, 7,
-        0, 0, 0, 0, 123, 10, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 32, 3, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 60,
-        54, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 130, 21, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 230, 11, 0, 0, 0,
-        0, 0, 7, 0, 0, 0, 0, 74, 54, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 90, 54, 0, 0, 0, 0, 0, 7, 0, 0,
-        0, 0, 98, 54, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 114, 54, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 129, 54,
-        0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 138, 54, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 162, 54, 0, 0, 0, 0,
-        0, 7, 0, 0, 0, 0, 90, 48, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 174, 54, 0, 0, 0, 0, 0, 7, 0, 0, 0,
-        0, 187, 54, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 203, 54, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 220, 54,
-        0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 234, 54, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 248, 54, 0, 0, 0, 0,
-        0, 7, 0, 0, 0, 0, 10, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 32, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0,
-        0, 47, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 90, 24, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 51, 55, 0,
-        0, 0, 0, 0, 7, 0, 0, 0, 0, 68, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 81, 55, 0, 0, 0, 0, 0, 7,
-        0, 0, 0, 0, 222, 25, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 93, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
-        105, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 117, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 140, 55, 0,
-        0, 0, 0, 0, 7, 0, 0, 0, 0, 156, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 174, 55, 0, 0, 0, 0, 0,
-        7, 0, 0, 0, 0, 195, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 210, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0,
-        0, 219, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 4, 9, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 226, 55, 0,
-        0, 0, 0, 0, 7, 0, 0, 0, 0, 240, 55, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 250, 55, 0, 0, 0, 0, 0,
-        7, 0, 0, 0, 0, 74, 9, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 7, 56, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
-        21, 56, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 178, 9, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 36, 56, 0, 0,
-        0, 0, 0, 7, 0, 0, 0, 0, 51, 56, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 67, 56, 0, 0, 0, 0, 0, 7, 0,
-        0, 0, 0, 76, 56, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 93, 56, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 117,
-        56, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 165, 12, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 247, 12, 0, 0, 0,
-        0, 0, 7, 0, 0, 0, 0, 132, 56, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 148, 56, 0, 0, 0, 0, 0, 7, 0,
-        0, 0, 0, 165, 56, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 177, 56, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 2,
-        28, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 187, 56, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 172, 58, 0, 0, 1,
-        0, 0, 15, 4, 0, 0, 0, 157, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 180, 58, 0, 0, 1, 0, 0, 15, 0,
-        0, 0, 0, 158, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 98, 112, 102, 95, 105, 116, 101, 114, 95,
-        95, 116, 97, 115, 107, 95, 102, 105, 108, 101, 0, 102, 100, 0, 109, 101, 116, 97, 0, 116,
-        97, 115, 107, 0, 117, 51, 50, 0, 95, 95, 117, 51, 50, 0, 117, 110, 115, 105, 103, 110, 101,
-        100, 32, 105, 110, 116, 0, 102, 105, 108, 101, 0, 99, 116, 120, 0, 105, 110, 116, 0, 98,
-        112, 102, 116, 111, 112, 95, 105, 116, 101, 114, 0, 102, 95, 117, 0, 102, 95, 112, 97, 116,
-        104, 0, 102, 95, 105, 110, 111, 100, 101, 0, 102, 95, 111, 112, 0, 102, 95, 108, 111, 99,
-        107, 0, 102, 95, 119, 114, 105, 116, 101, 95, 104, 105, 110, 116, 0, 102, 95, 99, 111, 117,
-        110, 116, 0, 102, 95, 102, 108, 97, 103, 115, 0, 102, 95, 109, 111, 100, 101, 0, 102, 95,
-        112, 111, 115, 95, 108, 111, 99, 107, 0, 102, 95, 112, 111, 115, 0, 102, 95, 111, 119, 110,
-        101, 114, 0, 102, 95, 99, 114, 101, 100, 0, 102, 95, 114, 97, 0, 102, 95, 118, 101, 114,
-        115, 105, 111, 110, 0, 102, 95, 115, 101, 99, 117, 114, 105, 116, 121, 0, 112, 114, 105,
-        118, 97, 116, 101, 95, 100, 97, 116, 97, 0, 102, 95, 101, 112, 95, 108, 105, 110, 107, 115,
-        0, 102, 95, 116, 102, 105, 108, 101, 95, 108, 108, 105, 110, 107, 0, 102, 95, 109, 97, 112,
-        112, 105, 110, 103, 0, 102, 95, 119, 98, 95, 101, 114, 114, 0, 102, 95, 115, 98, 95, 101,
-        114, 114, 0, 102, 117, 95, 108, 108, 105, 115, 116, 0, 102, 117, 95, 114, 99, 117, 104,
-        101, 97, 100, 0, 108, 108, 105, 115, 116, 95, 110, 111, 100, 101, 0, 110, 101, 120, 116, 0,
-        99, 97, 108, 108, 98, 97, 99, 107, 95, 104, 101, 97, 100, 0, 102, 117, 110, 99, 0, 112, 97,
-        116, 104, 0, 109, 110, 116, 0, 100, 101, 110, 116, 114, 121, 0, 115, 112, 105, 110, 108,
-        111, 99, 107, 95, 116, 0, 115, 112, 105, 110, 108, 111, 99, 107, 0, 114, 108, 111, 99, 107,
-        0, 114, 97, 119, 95, 115, 112, 105, 110, 108, 111, 99, 107, 0, 114, 97, 119, 95, 108, 111,
-        99, 107, 0, 97, 114, 99, 104, 95, 115, 112, 105, 110, 108, 111, 99, 107, 95, 116, 0, 113,
-        115, 112, 105, 110, 108, 111, 99, 107, 0, 118, 97, 108, 0, 97, 116, 111, 109, 105, 99, 95,
-        116, 0, 99, 111, 117, 110, 116, 101, 114, 0, 108, 111, 99, 107, 101, 100, 0, 112, 101, 110,
-        100, 105, 110, 103, 0, 117, 56, 0, 95, 95, 117, 56, 0, 117, 110, 115, 105, 103, 110, 101,
-        100, 32, 99, 104, 97, 114, 0, 108, 111, 99, 107, 101, 100, 95, 112, 101, 110, 100, 105,
-        110, 103, 0, 116, 97, 105, 108, 0, 117, 49, 54, 0, 95, 95, 117, 49, 54, 0, 117, 110, 115,
-        105, 103, 110, 101, 100, 32, 115, 104, 111, 114, 116, 0, 114, 119, 95, 104, 105, 110, 116,
-        0, 87, 82, 73, 84, 69, 95, 76, 73, 70, 69, 95, 78, 79, 84, 95, 83, 69, 84, 0, 87, 82, 73,
-        84, 69, 95, 76, 73, 70, 69, 95, 78, 79, 78, 69, 0, 87, 82, 73, 84, 69, 95, 76, 73, 70, 69,
-        95, 83, 72, 79, 82, 84, 0, 87, 82, 73, 84, 69, 95, 76, 73, 70, 69, 95, 77, 69, 68, 73, 85,
-        77, 0, 87, 82, 73, 84, 69, 95, 76, 73, 70, 69, 95, 76, 79, 78, 71, 0, 87, 82, 73, 84, 69,
-        95, 76, 73, 70, 69, 95, 69, 88, 84, 82, 69, 77, 69, 0, 97, 116, 111, 109, 105, 99, 95, 108,
-        111, 110, 103, 95, 116, 0, 97, 116, 111, 109, 105, 99, 54, 52, 95, 116, 0, 115, 54, 52, 0,
-        95, 95, 115, 54, 52, 0, 108, 111, 110, 103, 32, 108, 111, 110, 103, 0, 102, 109, 111, 100,
-        101, 95, 116, 0, 109, 117, 116, 101, 120, 0, 111, 119, 110, 101, 114, 0, 119, 97, 105, 116,
-        95, 108, 111, 99, 107, 0, 111, 115, 113, 0, 119, 97, 105, 116, 95, 108, 105, 115, 116, 0,
-        111, 112, 116, 105, 109, 105, 115, 116, 105, 99, 95, 115, 112, 105, 110, 95, 113, 117, 101,
-        117, 101, 0, 108, 105, 115, 116, 95, 104, 101, 97, 100, 0, 112, 114, 101, 118, 0, 108, 111,
-        102, 102, 95, 116, 0, 95, 95, 107, 101, 114, 110, 101, 108, 95, 108, 111, 102, 102, 95,
-        116, 0, 102, 111, 119, 110, 95, 115, 116, 114, 117, 99, 116, 0, 108, 111, 99, 107, 0, 112,
-        105, 100, 0, 112, 105, 100, 95, 116, 121, 112, 101, 0, 117, 105, 100, 0, 101, 117, 105,
-        100, 0, 115, 105, 103, 110, 117, 109, 0, 114, 119, 108, 111, 99, 107, 95, 116, 0, 97, 114,
-        99, 104, 95, 114, 119, 108, 111, 99, 107, 95, 116, 0, 113, 114, 119, 108, 111, 99, 107, 0,
-        99, 110, 116, 115, 0, 119, 108, 111, 99, 107, 101, 100, 0, 95, 95, 108, 115, 116, 97, 116,
-        101, 0, 95, 95, 65, 82, 82, 65, 89, 95, 83, 73, 90, 69, 95, 84, 89, 80, 69, 95, 95, 0, 80,
-        73, 68, 84, 89, 80, 69, 95, 80, 73, 68, 0, 80, 73, 68, 84, 89, 80, 69, 95, 84, 71, 73, 68,
-        0, 80, 73, 68, 84, 89, 80, 69, 95, 80, 71, 73, 68, 0, 80, 73, 68, 84, 89, 80, 69, 95, 83,
-        73, 68, 0, 80, 73, 68, 84, 89, 80, 69, 95, 77, 65, 88, 0, 107, 117, 105, 100, 95, 116, 0,
-        117, 105, 100, 95, 116, 0, 95, 95, 107, 101, 114, 110, 101, 108, 95, 117, 105, 100, 51, 50,
-        95, 116, 0, 102, 105, 108, 101, 95, 114, 97, 95, 115, 116, 97, 116, 101, 0, 115, 116, 97,
-        114, 116, 0, 115, 105, 122, 101, 0, 97, 115, 121, 110, 99, 95, 115, 105, 122, 101, 0, 114,
-        97, 95, 112, 97, 103, 101, 115, 0, 109, 109, 97, 112, 95, 109, 105, 115, 115, 0, 112, 114,
-        101, 118, 95, 112, 111, 115, 0, 117, 110, 115, 105, 103, 110, 101, 100, 32, 108, 111, 110,
-        103, 0, 117, 54, 52, 0, 95, 95, 117, 54, 52, 0, 117, 110, 115, 105, 103, 110, 101, 100, 32,
-        108, 111, 110, 103, 32, 108, 111, 110, 103, 0, 101, 114, 114, 115, 101, 113, 95, 116, 0,
-        116, 97, 115, 107, 95, 115, 116, 114, 117, 99, 116, 0, 116, 104, 114, 101, 97, 100, 95,
-        105, 110, 102, 111, 0, 115, 116, 97, 116, 101, 0, 115, 116, 97, 99, 107, 0, 117, 115, 97,
-        103, 101, 0, 102, 108, 97, 103, 115, 0, 112, 116, 114, 97, 99, 101, 0, 111, 110, 95, 99,
-        112, 117, 0, 119, 97, 107, 101, 95, 101, 110, 116, 114, 121, 0, 99, 112, 117, 0, 119, 97,
-        107, 101, 101, 95, 102, 108, 105, 112, 115, 0, 119, 97, 107, 101, 101, 95, 102, 108, 105,
-        112, 95, 100, 101, 99, 97, 121, 95, 116, 115, 0, 108, 97, 115, 116, 95, 119, 97, 107, 101,
-        101, 0, 114, 101, 99, 101, 110, 116, 95, 117, 115, 101, 100, 95, 99, 112, 117, 0, 119, 97,
-        107, 101, 95, 99, 112, 117, 0, 111, 110, 95, 114, 113, 0, 112, 114, 105, 111, 0, 115, 116,
-        97, 116, 105, 99, 95, 112, 114, 105, 111, 0, 110, 111, 114, 109, 97, 108, 95, 112, 114,
-        105, 111, 0, 114, 116, 95, 112, 114, 105, 111, 114, 105, 116, 121, 0, 115, 99, 104, 101,
-        100, 95, 99, 108, 97, 115, 115, 0, 115, 101, 0, 114, 116, 0, 115, 99, 104, 101, 100, 95,
-        116, 97, 115, 107, 95, 103, 114, 111, 117, 112, 0, 100, 108, 0, 117, 99, 108, 97, 109, 112,
-        95, 114, 101, 113, 0, 117, 99, 108, 97, 109, 112, 0, 112, 114, 101, 101, 109, 112, 116, 95,
-        110, 111, 116, 105, 102, 105, 101, 114, 115, 0, 98, 116, 114, 97, 99, 101, 95, 115, 101,
-        113, 0, 112, 111, 108, 105, 99, 121, 0, 110, 114, 95, 99, 112, 117, 115, 95, 97, 108, 108,
-        111, 119, 101, 100, 0, 99, 112, 117, 115, 95, 112, 116, 114, 0, 99, 112, 117, 115, 95, 109,
-        97, 115, 107, 0, 114, 99, 117, 95, 114, 101, 97, 100, 95, 108, 111, 99, 107, 95, 110, 101,
-        115, 116, 105, 110, 103, 0, 114, 99, 117, 95, 114, 101, 97, 100, 95, 117, 110, 108, 111,
-        99, 107, 95, 115, 112, 101, 99, 105, 97, 108, 0, 114, 99, 117, 95, 110, 111, 100, 101, 95,
-        101, 110, 116, 114, 121, 0, 114, 99, 117, 95, 98, 108, 111, 99, 107, 101, 100, 95, 110,
-        111, 100, 101, 0, 114, 99, 117, 95, 116, 97, 115, 107, 115, 95, 110, 118, 99, 115, 119, 0,
-        114, 99, 117, 95, 116, 97, 115, 107, 115, 95, 104, 111, 108, 100, 111, 117, 116, 0, 114,
-        99, 117, 95, 116, 97, 115, 107, 115, 95, 105, 100, 120, 0, 114, 99, 117, 95, 116, 97, 115,
-        107, 115, 95, 105, 100, 108, 101, 95, 99, 112, 117, 0, 114, 99, 117, 95, 116, 97, 115, 107,
-        115, 95, 104, 111, 108, 100, 111, 117, 116, 95, 108, 105, 115, 116, 0, 115, 99, 104, 101,
-        100, 95, 105, 110, 102, 111, 0, 116, 97, 115, 107, 115, 0, 112, 117, 115, 104, 97, 98, 108,
-        101, 95, 116, 97, 115, 107, 115, 0, 112, 117, 115, 104, 97, 98, 108, 101, 95, 100, 108, 95,
-        116, 97, 115, 107, 115, 0, 109, 109, 0, 97, 99, 116, 105, 118, 101, 95, 109, 109, 0, 118,
-        109, 97, 99, 97, 99, 104, 101, 0, 114, 115, 115, 95, 115, 116, 97, 116, 0, 101, 120, 105,
-        116, 95, 115, 116, 97, 116, 101, 0, 101, 120, 105, 116, 95, 99, 111, 100, 101, 0, 101, 120,
-        105, 116, 95, 115, 105, 103, 110, 97, 108, 0, 112, 100, 101, 97, 116, 104, 95, 115, 105,
-        103, 110, 97, 108, 0, 106, 111, 98, 99, 116, 108, 0, 112, 101, 114, 115, 111, 110, 97, 108,
-        105, 116, 121, 0, 115, 99, 104, 101, 100, 95, 114, 101, 115, 101, 116, 95, 111, 110, 95,
-        102, 111, 114, 107, 0, 115, 99, 104, 101, 100, 95, 99, 111, 110, 116, 114, 105, 98, 117,
-        116, 101, 115, 95, 116, 111, 95, 108, 111, 97, 100, 0, 115, 99, 104, 101, 100, 95, 109,
-        105, 103, 114, 97, 116, 101, 100, 0, 115, 99, 104, 101, 100, 95, 114, 101, 109, 111, 116,
-        101, 95, 119, 97, 107, 101, 117, 112, 0, 115, 99, 104, 101, 100, 95, 112, 115, 105, 95,
-        119, 97, 107, 101, 95, 114, 101, 113, 117, 101, 117, 101, 0, 105, 110, 95, 101, 120, 101,
-        99, 118, 101, 0, 105, 110, 95, 105, 111, 119, 97, 105, 116, 0, 114, 101, 115, 116, 111,
-        114, 101, 95, 115, 105, 103, 109, 97, 115, 107, 0, 105, 110, 95, 117, 115, 101, 114, 95,
-        102, 97, 117, 108, 116, 0, 110, 111, 95, 99, 103, 114, 111, 117, 112, 95, 109, 105, 103,
-        114, 97, 116, 105, 111, 110, 0, 102, 114, 111, 122, 101, 110, 0, 117, 115, 101, 95, 109,
-        101, 109, 100, 101, 108, 97, 121, 0, 105, 110, 95, 109, 101, 109, 115, 116, 97, 108, 108,
-        0, 97, 116, 111, 109, 105, 99, 95, 102, 108, 97, 103, 115, 0, 114, 101, 115, 116, 97, 114,
-        116, 95, 98, 108, 111, 99, 107, 0, 116, 103, 105, 100, 0, 115, 116, 97, 99, 107, 95, 99,
-        97, 110, 97, 114, 121, 0, 114, 101, 97, 108, 95, 112, 97, 114, 101, 110, 116, 0, 112, 97,
-        114, 101, 110, 116, 0, 99, 104, 105, 108, 100, 114, 101, 110, 0, 115, 105, 98, 108, 105,
-        110, 103, 0, 103, 114, 111, 117, 112, 95, 108, 101, 97, 100, 101, 114, 0, 112, 116, 114,
-        97, 99, 101, 100, 0, 112, 116, 114, 97, 99, 101, 95, 101, 110, 116, 114, 121, 0, 116, 104,
-        114, 101, 97, 100, 95, 112, 105, 100, 0, 112, 105, 100, 95, 108, 105, 110, 107, 115, 0,
-        116, 104, 114, 101, 97, 100, 95, 103, 114, 111, 117, 112, 0, 116, 104, 114, 101, 97, 100,
-        95, 110, 111, 100, 101, 0, 118, 102, 111, 114, 107, 95, 100, 111, 110, 101, 0, 115, 101,
-        116, 95, 99, 104, 105, 108, 100, 95, 116, 105, 100, 0, 99, 108, 101, 97, 114, 95, 99, 104,
-        105, 108, 100, 95, 116, 105, 100, 0, 117, 116, 105, 109, 101, 0, 115, 116, 105, 109, 101,
-        0, 103, 116, 105, 109, 101, 0, 112, 114, 101, 118, 95, 99, 112, 117, 116, 105, 109, 101, 0,
-        110, 118, 99, 115, 119, 0, 110, 105, 118, 99, 115, 119, 0, 115, 116, 97, 114, 116, 95, 116,
-        105, 109, 101, 0, 115, 116, 97, 114, 116, 95, 98, 111, 111, 116, 116, 105, 109, 101, 0,
-        109, 105, 110, 95, 102, 108, 116, 0, 109, 97, 106, 95, 102, 108, 116, 0, 112, 111, 115,
-        105, 120, 95, 99, 112, 117, 116, 105, 109, 101, 114, 115, 0, 112, 116, 114, 97, 99, 101,
-        114, 95, 99, 114, 101, 100, 0, 114, 101, 97, 108, 95, 99, 114, 101, 100, 0, 99, 114, 101,
-        100, 0, 99, 97, 99, 104, 101, 100, 95, 114, 101, 113, 117, 101, 115, 116, 101, 100, 95,
-        107, 101, 121, 0, 99, 111, 109, 109, 0, 110, 97, 109, 101, 105, 100, 97, 116, 97, 0, 115,
-        121, 115, 118, 115, 101, 109, 0, 115, 121, 115, 118, 115, 104, 109, 0, 108, 97, 115, 116,
-        95, 115, 119, 105, 116, 99, 104, 95, 99, 111, 117, 110, 116, 0, 108, 97, 115, 116, 95, 115,
-        119, 105, 116, 99, 104, 95, 116, 105, 109, 101, 0, 102, 115, 0, 102, 105, 108, 101, 115, 0,
-        110, 115, 112, 114, 111, 120, 121, 0, 115, 105, 103, 110, 97, 108, 0, 115, 105, 103, 104,
-        97, 110, 100, 0, 98, 108, 111, 99, 107, 101, 100, 0, 114, 101, 97, 108, 95, 98, 108, 111,
-        99, 107, 101, 100, 0, 115, 97, 118, 101, 100, 95, 115, 105, 103, 109, 97, 115, 107, 0, 115,
-        97, 115, 95, 115, 115, 95, 115, 112, 0, 115, 97, 115, 95, 115, 115, 95, 115, 105, 122, 101,
-        0, 115, 97, 115, 95, 115, 115, 95, 102, 108, 97, 103, 115, 0, 116, 97, 115, 107, 95, 119,
-        111, 114, 107, 115, 0, 97, 117, 100, 105, 116, 95, 99, 111, 110, 116, 101, 120, 116, 0,
-        108, 111, 103, 105, 110, 117, 105, 100, 0, 115, 101, 115, 115, 105, 111, 110, 105, 100, 0,
-        115, 101, 99, 99, 111, 109, 112, 0, 112, 97, 114, 101, 110, 116, 95, 101, 120, 101, 99, 95,
-        105, 100, 0, 115, 101, 108, 102, 95, 101, 120, 101, 99, 95, 105, 100, 0, 97, 108, 108, 111,
-        99, 95, 108, 111, 99, 107, 0, 112, 105, 95, 108, 111, 99, 107, 0, 119, 97, 107, 101, 95,
-        113, 0, 112, 105, 95, 119, 97, 105, 116, 101, 114, 115, 0, 112, 105, 95, 116, 111, 112, 95,
-        116, 97, 115, 107, 0, 112, 105, 95, 98, 108, 111, 99, 107, 101, 100, 95, 111, 110, 0, 106,
-        111, 117, 114, 110, 97, 108, 95, 105, 110, 102, 111, 0, 98, 105, 111, 95, 108, 105, 115,
-        116, 0, 112, 108, 117, 103, 0, 114, 101, 99, 108, 97, 105, 109, 95, 115, 116, 97, 116, 101,
-        0, 98, 97, 99, 107, 105, 110, 103, 95, 100, 101, 118, 95, 105, 110, 102, 111, 0, 105, 111,
-        95, 99, 111, 110, 116, 101, 120, 116, 0, 99, 97, 112, 116, 117, 114, 101, 95, 99, 111, 110,
-        116, 114, 111, 108, 0, 112, 116, 114, 97, 99, 101, 95, 109, 101, 115, 115, 97, 103, 101, 0,
-        108, 97, 115, 116, 95, 115, 105, 103, 105, 110, 102, 111, 0, 105, 111, 97, 99, 0, 112, 115,
-        105, 95, 102, 108, 97, 103, 115, 0, 97, 99, 99, 116, 95, 114, 115, 115, 95, 109, 101, 109,
-        49, 0, 97, 99, 99, 116, 95, 118, 109, 95, 109, 101, 109, 49, 0, 97, 99, 99, 116, 95, 116,
-        105, 109, 101, 120, 112, 100, 0, 109, 101, 109, 115, 95, 97, 108, 108, 111, 119, 101, 100,
-        0, 109, 101, 109, 115, 95, 97, 108, 108, 111, 119, 101, 100, 95, 115, 101, 113, 0, 99, 112,
-        117, 115, 101, 116, 95, 109, 101, 109, 95, 115, 112, 114, 101, 97, 100, 95, 114, 111, 116,
-        111, 114, 0, 99, 112, 117, 115, 101, 116, 95, 115, 108, 97, 98, 95, 115, 112, 114, 101, 97,
-        100, 95, 114, 111, 116, 111, 114, 0, 99, 103, 114, 111, 117, 112, 115, 0, 99, 103, 95, 108,
-        105, 115, 116, 0, 99, 108, 111, 115, 105, 100, 0, 114, 109, 105, 100, 0, 114, 111, 98, 117,
-        115, 116, 95, 108, 105, 115, 116, 0, 99, 111, 109, 112, 97, 116, 95, 114, 111, 98, 117,
-        115, 116, 95, 108, 105, 115, 116, 0, 112, 105, 95, 115, 116, 97, 116, 101, 95, 108, 105,
-        115, 116, 0, 112, 105, 95, 115, 116, 97, 116, 101, 95, 99, 97, 99, 104, 101, 0, 102, 117,
-        116, 101, 120, 95, 101, 120, 105, 116, 95, 109, 117, 116, 101, 120, 0, 102, 117, 116, 101,
-        120, 95, 115, 116, 97, 116, 101, 0, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116, 95,
-        99, 116, 120, 112, 0, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116, 95, 109, 117, 116,
-        101, 120, 0, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116, 95, 108, 105, 115, 116, 0,
-        112, 114, 101, 101, 109, 112, 116, 95, 100, 105, 115, 97, 98, 108, 101, 95, 105, 112, 0,
-        109, 101, 109, 112, 111, 108, 105, 99, 121, 0, 105, 108, 95, 112, 114, 101, 118, 0, 112,
-        114, 101, 102, 95, 110, 111, 100, 101, 95, 102, 111, 114, 107, 0, 110, 117, 109, 97, 95,
-        115, 99, 97, 110, 95, 115, 101, 113, 0, 110, 117, 109, 97, 95, 115, 99, 97, 110, 95, 112,
-        101, 114, 105, 111, 100, 0, 110, 117, 109, 97, 95, 115, 99, 97, 110, 95, 112, 101, 114,
-        105, 111, 100, 95, 109, 97, 120, 0, 110, 117, 109, 97, 95, 112, 114, 101, 102, 101, 114,
-        114, 101, 100, 95, 110, 105, 100, 0, 110, 117, 109, 97, 95, 109, 105, 103, 114, 97, 116,
-        101, 95, 114, 101, 116, 114, 121, 0, 110, 111, 100, 101, 95, 115, 116, 97, 109, 112, 0,
-        108, 97, 115, 116, 95, 116, 97, 115, 107, 95, 110, 117, 109, 97, 95, 112, 108, 97, 99, 101,
-        109, 101, 110, 116, 0, 108, 97, 115, 116, 95, 115, 117, 109, 95, 101, 120, 101, 99, 95,
-        114, 117, 110, 116, 105, 109, 101, 0, 110, 117, 109, 97, 95, 119, 111, 114, 107, 0, 110,
-        117, 109, 97, 95, 103, 114, 111, 117, 112, 0, 110, 117, 109, 97, 95, 102, 97, 117, 108,
-        116, 115, 0, 116, 111, 116, 97, 108, 95, 110, 117, 109, 97, 95, 102, 97, 117, 108, 116,
-        115, 0, 110, 117, 109, 97, 95, 102, 97, 117, 108, 116, 115, 95, 108, 111, 99, 97, 108, 105,
-        116, 121, 0, 110, 117, 109, 97, 95, 112, 97, 103, 101, 115, 95, 109, 105, 103, 114, 97,
-        116, 101, 100, 0, 114, 115, 101, 113, 0, 114, 115, 101, 113, 95, 115, 105, 103, 0, 114,
-        115, 101, 113, 95, 101, 118, 101, 110, 116, 95, 109, 97, 115, 107, 0, 116, 108, 98, 95,
-        117, 98, 99, 0, 115, 112, 108, 105, 99, 101, 95, 112, 105, 112, 101, 0, 116, 97, 115, 107,
-        95, 102, 114, 97, 103, 0, 100, 101, 108, 97, 121, 115, 0, 110, 114, 95, 100, 105, 114, 116,
-        105, 101, 100, 0, 110, 114, 95, 100, 105, 114, 116, 105, 101, 100, 95, 112, 97, 117, 115,
-        101, 0, 100, 105, 114, 116, 121, 95, 112, 97, 117, 115, 101, 100, 95, 119, 104, 101, 110,
-        0, 108, 97, 116, 101, 110, 99, 121, 95, 114, 101, 99, 111, 114, 100, 95, 99, 111, 117, 110,
-        116, 0, 108, 97, 116, 101, 110, 99, 121, 95, 114, 101, 99, 111, 114, 100, 0, 116, 105, 109,
-        101, 114, 95, 115, 108, 97, 99, 107, 95, 110, 115, 0, 100, 101, 102, 97, 117, 108, 116, 95,
-        116, 105, 109, 101, 114, 95, 115, 108, 97, 99, 107, 95, 110, 115, 0, 99, 117, 114, 114, 95,
-        114, 101, 116, 95, 115, 116, 97, 99, 107, 0, 99, 117, 114, 114, 95, 114, 101, 116, 95, 100,
-        101, 112, 116, 104, 0, 114, 101, 116, 95, 115, 116, 97, 99, 107, 0, 102, 116, 114, 97, 99,
-        101, 95, 116, 105, 109, 101, 115, 116, 97, 109, 112, 0, 116, 114, 97, 99, 101, 95, 111,
-        118, 101, 114, 114, 117, 110, 0, 116, 114, 97, 99, 105, 110, 103, 95, 103, 114, 97, 112,
-        104, 95, 112, 97, 117, 115, 101, 0, 116, 114, 97, 99, 101, 0, 116, 114, 97, 99, 101, 95,
-        114, 101, 99, 117, 114, 115, 105, 111, 110, 0, 109, 101, 109, 99, 103, 95, 105, 110, 95,
-        111, 111, 109, 0, 109, 101, 109, 99, 103, 95, 111, 111, 109, 95, 103, 102, 112, 95, 109,
-        97, 115, 107, 0, 109, 101, 109, 99, 103, 95, 111, 111, 109, 95, 111, 114, 100, 101, 114, 0,
-        109, 101, 109, 99, 103, 95, 110, 114, 95, 112, 97, 103, 101, 115, 95, 111, 118, 101, 114,
-        95, 104, 105, 103, 104, 0, 97, 99, 116, 105, 118, 101, 95, 109, 101, 109, 99, 103, 0, 116,
-        104, 114, 111, 116, 116, 108, 101, 95, 113, 117, 101, 117, 101, 0, 117, 116, 97, 115, 107,
-        0, 115, 101, 113, 117, 101, 110, 116, 105, 97, 108, 95, 105, 111, 0, 115, 101, 113, 117,
-        101, 110, 116, 105, 97, 108, 95, 105, 111, 95, 97, 118, 103, 0, 112, 97, 103, 101, 102, 97,
-        117, 108, 116, 95, 100, 105, 115, 97, 98, 108, 101, 100, 0, 111, 111, 109, 95, 114, 101,
-        97, 112, 101, 114, 95, 108, 105, 115, 116, 0, 115, 116, 97, 99, 107, 95, 118, 109, 95, 97,
-        114, 101, 97, 0, 115, 116, 97, 99, 107, 95, 114, 101, 102, 99, 111, 117, 110, 116, 0, 115,
-        101, 99, 117, 114, 105, 116, 121, 0, 109, 99, 101, 95, 97, 100, 100, 114, 0, 109, 99, 101,
-        95, 114, 105, 112, 118, 0, 109, 99, 101, 95, 119, 104, 111, 108, 101, 95, 112, 97, 103,
-        101, 0, 95, 95, 109, 99, 101, 95, 114, 101, 115, 101, 114, 118, 101, 100, 0, 109, 99, 101,
-        95, 107, 105, 108, 108, 95, 109, 101, 0, 116, 104, 114, 101, 97, 100, 0, 115, 116, 97, 116,
-        117, 115, 0, 108, 111, 110, 103, 0, 114, 101, 102, 99, 111, 117, 110, 116, 95, 116, 0, 114,
-        101, 102, 99, 111, 117, 110, 116, 95, 115, 116, 114, 117, 99, 116, 0, 114, 101, 102, 115,
-        0, 95, 95, 99, 97, 108, 108, 95, 115, 105, 110, 103, 108, 101, 95, 110, 111, 100, 101, 0,
-        108, 108, 105, 115, 116, 0, 117, 95, 102, 108, 97, 103, 115, 0, 97, 95, 102, 108, 97, 103,
-        115, 0, 115, 99, 104, 101, 100, 95, 101, 110, 116, 105, 116, 121, 0, 108, 111, 97, 100, 0,
-        114, 117, 110, 95, 110, 111, 100, 101, 0, 103, 114, 111, 117, 112, 95, 110, 111, 100, 101,
-        0, 101, 120, 101, 99, 95, 115, 116, 97, 114, 116, 0, 115, 117, 109, 95, 101, 120, 101, 99,
-        95, 114, 117, 110, 116, 105, 109, 101, 0, 118, 114, 117, 110, 116, 105, 109, 101, 0, 112,
-        114, 101, 118, 95, 115, 117, 109, 95, 101, 120, 101, 99, 95, 114, 117, 110, 116, 105, 109,
-        101, 0, 110, 114, 95, 109, 105, 103, 114, 97, 116, 105, 111, 110, 115, 0, 115, 116, 97,
-        116, 105, 115, 116, 105, 99, 115, 0, 100, 101, 112, 116, 104, 0, 99, 102, 115, 95, 114,
-        113, 0, 109, 121, 95, 113, 0, 114, 117, 110, 110, 97, 98, 108, 101, 95, 119, 101, 105, 103,
-        104, 116, 0, 97, 118, 103, 0, 108, 111, 97, 100, 95, 119, 101, 105, 103, 104, 116, 0, 119,
-        101, 105, 103, 104, 116, 0, 105, 110, 118, 95, 119, 101, 105, 103, 104, 116, 0, 114, 98,
-        95, 110, 111, 100, 101, 0, 95, 95, 114, 98, 95, 112, 97, 114, 101, 110, 116, 95, 99, 111,
-        108, 111, 114, 0, 114, 98, 95, 114, 105, 103, 104, 116, 0, 114, 98, 95, 108, 101, 102, 116,
-        0, 115, 99, 104, 101, 100, 95, 115, 116, 97, 116, 105, 115, 116, 105, 99, 115, 0, 119, 97,
-        105, 116, 95, 115, 116, 97, 114, 116, 0, 119, 97, 105, 116, 95, 109, 97, 120, 0, 119, 97,
-        105, 116, 95, 99, 111, 117, 110, 116, 0, 119, 97, 105, 116, 95, 115, 117, 109, 0, 105, 111,
-        119, 97, 105, 116, 95, 99, 111, 117, 110, 116, 0, 105, 111, 119, 97, 105, 116, 95, 115,
-        117, 109, 0, 115, 108, 101, 101, 112, 95, 115, 116, 97, 114, 116, 0, 115, 108, 101, 101,
-        112, 95, 109, 97, 120, 0, 115, 117, 109, 95, 115, 108, 101, 101, 112, 95, 114, 117, 110,
-        116, 105, 109, 101, 0, 98, 108, 111, 99, 107, 95, 115, 116, 97, 114, 116, 0, 98, 108, 111,
-        99, 107, 95, 109, 97, 120, 0, 101, 120, 101, 99, 95, 109, 97, 120, 0, 115, 108, 105, 99,
-        101, 95, 109, 97, 120, 0, 110, 114, 95, 109, 105, 103, 114, 97, 116, 105, 111, 110, 115,
-        95, 99, 111, 108, 100, 0, 110, 114, 95, 102, 97, 105, 108, 101, 100, 95, 109, 105, 103,
-        114, 97, 116, 105, 111, 110, 115, 95, 97, 102, 102, 105, 110, 101, 0, 110, 114, 95, 102,
-        97, 105, 108, 101, 100, 95, 109, 105, 103, 114, 97, 116, 105, 111, 110, 115, 95, 114, 117,
-        110, 110, 105, 110, 103, 0, 110, 114, 95, 102, 97, 105, 108, 101, 100, 95, 109, 105, 103,
-        114, 97, 116, 105, 111, 110, 115, 95, 104, 111, 116, 0, 110, 114, 95, 102, 111, 114, 99,
-        101, 100, 95, 109, 105, 103, 114, 97, 116, 105, 111, 110, 115, 0, 110, 114, 95, 119, 97,
-        107, 101, 117, 112, 115, 0, 110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 115, 121,
-        110, 99, 0, 110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 109, 105, 103, 114, 97,
-        116, 101, 0, 110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 108, 111, 99, 97, 108, 0,
-        110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 114, 101, 109, 111, 116, 101, 0, 110,
-        114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 97, 102, 102, 105, 110, 101, 0, 110, 114,
-        95, 119, 97, 107, 101, 117, 112, 115, 95, 97, 102, 102, 105, 110, 101, 95, 97, 116, 116,
-        101, 109, 112, 116, 115, 0, 110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 112, 97,
-        115, 115, 105, 118, 101, 0, 110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 105, 100,
-        108, 101, 0, 115, 99, 104, 101, 100, 95, 97, 118, 103, 0, 108, 97, 115, 116, 95, 117, 112,
-        100, 97, 116, 101, 95, 116, 105, 109, 101, 0, 108, 111, 97, 100, 95, 115, 117, 109, 0, 114,
-        117, 110, 110, 97, 98, 108, 101, 95, 115, 117, 109, 0, 117, 116, 105, 108, 95, 115, 117,
-        109, 0, 112, 101, 114, 105, 111, 100, 95, 99, 111, 110, 116, 114, 105, 98, 0, 108, 111, 97,
-        100, 95, 97, 118, 103, 0, 114, 117, 110, 110, 97, 98, 108, 101, 95, 97, 118, 103, 0, 117,
-        116, 105, 108, 95, 97, 118, 103, 0, 117, 116, 105, 108, 95, 101, 115, 116, 0, 101, 110,
-        113, 117, 101, 117, 101, 100, 0, 101, 119, 109, 97, 0, 115, 99, 104, 101, 100, 95, 114,
-        116, 95, 101, 110, 116, 105, 116, 121, 0, 114, 117, 110, 95, 108, 105, 115, 116, 0, 116,
-        105, 109, 101, 111, 117, 116, 0, 119, 97, 116, 99, 104, 100, 111, 103, 95, 115, 116, 97,
-        109, 112, 0, 116, 105, 109, 101, 95, 115, 108, 105, 99, 101, 0, 111, 110, 95, 108, 105,
-        115, 116, 0, 98, 97, 99, 107, 0, 115, 99, 104, 101, 100, 95, 100, 108, 95, 101, 110, 116,
-        105, 116, 121, 0, 100, 108, 95, 114, 117, 110, 116, 105, 109, 101, 0, 100, 108, 95, 100,
-        101, 97, 100, 108, 105, 110, 101, 0, 100, 108, 95, 112, 101, 114, 105, 111, 100, 0, 100,
-        108, 95, 98, 119, 0, 100, 108, 95, 100, 101, 110, 115, 105, 116, 121, 0, 114, 117, 110,
-        116, 105, 109, 101, 0, 100, 101, 97, 100, 108, 105, 110, 101, 0, 100, 108, 95, 116, 104,
-        114, 111, 116, 116, 108, 101, 100, 0, 100, 108, 95, 98, 111, 111, 115, 116, 101, 100, 0,
-        100, 108, 95, 121, 105, 101, 108, 100, 101, 100, 0, 100, 108, 95, 110, 111, 110, 95, 99,
-        111, 110, 116, 101, 110, 100, 105, 110, 103, 0, 100, 108, 95, 111, 118, 101, 114, 114, 117,
-        110, 0, 100, 108, 95, 116, 105, 109, 101, 114, 0, 105, 110, 97, 99, 116, 105, 118, 101, 95,
-        116, 105, 109, 101, 114, 0, 104, 114, 116, 105, 109, 101, 114, 0, 110, 111, 100, 101, 0,
-        95, 115, 111, 102, 116, 101, 120, 112, 105, 114, 101, 115, 0, 102, 117, 110, 99, 116, 105,
-        111, 110, 0, 98, 97, 115, 101, 0, 105, 115, 95, 114, 101, 108, 0, 105, 115, 95, 115, 111,
-        102, 116, 0, 105, 115, 95, 104, 97, 114, 100, 0, 116, 105, 109, 101, 114, 113, 117, 101,
-        117, 101, 95, 110, 111, 100, 101, 0, 101, 120, 112, 105, 114, 101, 115, 0, 107, 116, 105,
-        109, 101, 95, 116, 0, 104, 114, 116, 105, 109, 101, 114, 95, 114, 101, 115, 116, 97, 114,
-        116, 0, 72, 82, 84, 73, 77, 69, 82, 95, 78, 79, 82, 69, 83, 84, 65, 82, 84, 0, 72, 82, 84,
-        73, 77, 69, 82, 95, 82, 69, 83, 84, 65, 82, 84, 0, 117, 99, 108, 97, 109, 112, 95, 115,
-        101, 0, 118, 97, 108, 117, 101, 0, 98, 117, 99, 107, 101, 116, 95, 105, 100, 0, 97, 99,
-        116, 105, 118, 101, 0, 117, 115, 101, 114, 95, 100, 101, 102, 105, 110, 101, 100, 0, 104,
-        108, 105, 115, 116, 95, 104, 101, 97, 100, 0, 102, 105, 114, 115, 116, 0, 99, 112, 117,
-        109, 97, 115, 107, 95, 116, 0, 99, 112, 117, 109, 97, 115, 107, 0, 98, 105, 116, 115, 0,
-        114, 99, 117, 95, 115, 112, 101, 99, 105, 97, 108, 0, 98, 0, 115, 0, 110, 101, 101, 100,
-        95, 113, 115, 0, 101, 120, 112, 95, 104, 105, 110, 116, 0, 110, 101, 101, 100, 95, 109, 98,
-        0, 112, 99, 111, 117, 110, 116, 0, 114, 117, 110, 95, 100, 101, 108, 97, 121, 0, 108, 97,
-        115, 116, 95, 97, 114, 114, 105, 118, 97, 108, 0, 108, 97, 115, 116, 95, 113, 117, 101,
-        117, 101, 100, 0, 112, 108, 105, 115, 116, 95, 110, 111, 100, 101, 0, 112, 114, 105, 111,
-        95, 108, 105, 115, 116, 0, 110, 111, 100, 101, 95, 108, 105, 115, 116, 0, 115, 101, 113,
-        110, 117, 109, 0, 118, 109, 97, 115, 0, 118, 109, 95, 97, 114, 101, 97, 95, 115, 116, 114,
-        117, 99, 116, 0, 118, 109, 95, 115, 116, 97, 114, 116, 0, 118, 109, 95, 101, 110, 100, 0,
-        118, 109, 95, 110, 101, 120, 116, 0, 118, 109, 95, 112, 114, 101, 118, 0, 118, 109, 95,
-        114, 98, 0, 114, 98, 95, 115, 117, 98, 116, 114, 101, 101, 95, 103, 97, 112, 0, 118, 109,
-        95, 109, 109, 0, 118, 109, 95, 112, 97, 103, 101, 95, 112, 114, 111, 116, 0, 118, 109, 95,
-        102, 108, 97, 103, 115, 0, 115, 104, 97, 114, 101, 100, 0, 97, 110, 111, 110, 95, 118, 109,
-        97, 95, 99, 104, 97, 105, 110, 0, 97, 110, 111, 110, 95, 118, 109, 97, 0, 118, 109, 95,
-        111, 112, 115, 0, 118, 109, 95, 112, 103, 111, 102, 102, 0, 118, 109, 95, 102, 105, 108,
-        101, 0, 118, 109, 95, 112, 114, 105, 118, 97, 116, 101, 95, 100, 97, 116, 97, 0, 115, 119,
-        97, 112, 95, 114, 101, 97, 100, 97, 104, 101, 97, 100, 95, 105, 110, 102, 111, 0, 118, 109,
-        95, 112, 111, 108, 105, 99, 121, 0, 118, 109, 95, 117, 115, 101, 114, 102, 97, 117, 108,
-        116, 102, 100, 95, 99, 116, 120, 0, 112, 103, 112, 114, 111, 116, 95, 116, 0, 112, 103,
-        112, 114, 111, 116, 0, 112, 103, 112, 114, 111, 116, 118, 97, 108, 95, 116, 0, 114, 98, 0,
-        114, 98, 95, 115, 117, 98, 116, 114, 101, 101, 95, 108, 97, 115, 116, 0, 116, 97, 115, 107,
-        95, 114, 115, 115, 95, 115, 116, 97, 116, 0, 101, 118, 101, 110, 116, 115, 0, 99, 111, 117,
-        110, 116, 0, 102, 110, 0, 102, 117, 116, 101, 120, 0, 110, 97, 110, 111, 115, 108, 101,
-        101, 112, 0, 112, 111, 108, 108, 0, 117, 97, 100, 100, 114, 0, 98, 105, 116, 115, 101, 116,
-        0, 116, 105, 109, 101, 0, 117, 97, 100, 100, 114, 50, 0, 99, 108, 111, 99, 107, 105, 100,
-        0, 116, 121, 112, 101, 0, 99, 108, 111, 99, 107, 105, 100, 95, 116, 0, 95, 95, 107, 101,
-        114, 110, 101, 108, 95, 99, 108, 111, 99, 107, 105, 100, 95, 116, 0, 116, 105, 109, 101,
-        115, 112, 101, 99, 95, 116, 121, 112, 101, 0, 84, 84, 95, 78, 79, 78, 69, 0, 84, 84, 95,
-        78, 65, 84, 73, 86, 69, 0, 84, 84, 95, 67, 79, 77, 80, 65, 84, 0, 114, 109, 116, 112, 0,
-        99, 111, 109, 112, 97, 116, 95, 114, 109, 116, 112, 0, 117, 102, 100, 115, 0, 110, 102,
-        100, 115, 0, 104, 97, 115, 95, 116, 105, 109, 101, 111, 117, 116, 0, 116, 118, 95, 115,
-        101, 99, 0, 116, 118, 95, 110, 115, 101, 99, 0, 112, 105, 100, 95, 116, 0, 95, 95, 107,
-        101, 114, 110, 101, 108, 95, 112, 105, 100, 95, 116, 0, 104, 108, 105, 115, 116, 95, 110,
-        111, 100, 101, 0, 112, 112, 114, 101, 118, 0, 114, 97, 119, 95, 115, 112, 105, 110, 108,
-        111, 99, 107, 95, 116, 0, 98, 97, 115, 101, 115, 0, 116, 105, 109, 101, 114, 115, 95, 97,
-        99, 116, 105, 118, 101, 0, 101, 120, 112, 105, 114, 121, 95, 97, 99, 116, 105, 118, 101, 0,
-        112, 111, 115, 105, 120, 95, 99, 112, 117, 116, 105, 109, 101, 114, 95, 98, 97, 115, 101,
-        0, 110, 101, 120, 116, 101, 118, 116, 0, 116, 113, 104, 101, 97, 100, 0, 116, 105, 109,
-        101, 114, 113, 117, 101, 117, 101, 95, 104, 101, 97, 100, 0, 114, 98, 95, 114, 111, 111,
-        116, 0, 114, 98, 95, 114, 111, 111, 116, 95, 99, 97, 99, 104, 101, 100, 0, 114, 98, 95,
-        108, 101, 102, 116, 109, 111, 115, 116, 0, 103, 105, 100, 0, 115, 117, 105, 100, 0, 115,
-        103, 105, 100, 0, 101, 103, 105, 100, 0, 102, 115, 117, 105, 100, 0, 102, 115, 103, 105,
-        100, 0, 115, 101, 99, 117, 114, 101, 98, 105, 116, 115, 0, 99, 97, 112, 95, 105, 110, 104,
-        101, 114, 105, 116, 97, 98, 108, 101, 0, 99, 97, 112, 95, 112, 101, 114, 109, 105, 116,
-        116, 101, 100, 0, 99, 97, 112, 95, 101, 102, 102, 101, 99, 116, 105, 118, 101, 0, 99, 97,
-        112, 95, 98, 115, 101, 116, 0, 99, 97, 112, 95, 97, 109, 98, 105, 101, 110, 116, 0, 106,
-        105, 116, 95, 107, 101, 121, 114, 105, 110, 103, 0, 115, 101, 115, 115, 105, 111, 110, 95,
-        107, 101, 121, 114, 105, 110, 103, 0, 112, 114, 111, 99, 101, 115, 115, 95, 107, 101, 121,
-        114, 105, 110, 103, 0, 116, 104, 114, 101, 97, 100, 95, 107, 101, 121, 114, 105, 110, 103,
-        0, 114, 101, 113, 117, 101, 115, 116, 95, 107, 101, 121, 95, 97, 117, 116, 104, 0, 117,
-        115, 101, 114, 0, 117, 115, 101, 114, 95, 110, 115, 0, 103, 114, 111, 117, 112, 95, 105,
-        110, 102, 111, 0, 107, 103, 105, 100, 95, 116, 0, 103, 105, 100, 95, 116, 0, 95, 95, 107,
-        101, 114, 110, 101, 108, 95, 103, 105, 100, 51, 50, 95, 116, 0, 107, 101, 114, 110, 101,
-        108, 95, 99, 97, 112, 95, 116, 0, 107, 101, 114, 110, 101, 108, 95, 99, 97, 112, 95, 115,
-        116, 114, 117, 99, 116, 0, 99, 97, 112, 0, 110, 111, 110, 95, 114, 99, 117, 0, 114, 99,
-        117, 0, 99, 104, 97, 114, 0, 115, 121, 115, 118, 95, 115, 101, 109, 0, 117, 110, 100, 111,
-        95, 108, 105, 115, 116, 0, 115, 121, 115, 118, 95, 115, 104, 109, 0, 115, 104, 109, 95, 99,
-        108, 105, 115, 116, 0, 115, 105, 103, 115, 101, 116, 95, 116, 0, 115, 105, 103, 0, 115,
-        105, 103, 112, 101, 110, 100, 105, 110, 103, 0, 108, 105, 115, 116, 0, 115, 105, 122, 101,
-        95, 116, 0, 95, 95, 107, 101, 114, 110, 101, 108, 95, 115, 105, 122, 101, 95, 116, 0, 95,
-        95, 107, 101, 114, 110, 101, 108, 95, 117, 108, 111, 110, 103, 95, 116, 0, 109, 111, 100,
-        101, 0, 102, 105, 108, 116, 101, 114, 0, 119, 97, 107, 101, 95, 113, 95, 110, 111, 100,
-        101, 0, 107, 101, 114, 110, 101, 108, 95, 115, 105, 103, 105, 110, 102, 111, 95, 116, 0,
-        116, 97, 115, 107, 95, 105, 111, 95, 97, 99, 99, 111, 117, 110, 116, 105, 110, 103, 0, 114,
-        99, 104, 97, 114, 0, 119, 99, 104, 97, 114, 0, 115, 121, 115, 99, 114, 0, 115, 121, 115,
-        99, 119, 0, 114, 101, 97, 100, 95, 98, 121, 116, 101, 115, 0, 119, 114, 105, 116, 101, 95,
-        98, 121, 116, 101, 115, 0, 99, 97, 110, 99, 101, 108, 108, 101, 100, 95, 119, 114, 105,
-        116, 101, 95, 98, 121, 116, 101, 115, 0, 110, 111, 100, 101, 109, 97, 115, 107, 95, 116, 0,
-        115, 101, 113, 99, 111, 117, 110, 116, 95, 116, 0, 115, 101, 113, 99, 111, 117, 110, 116,
-        0, 115, 101, 113, 117, 101, 110, 99, 101, 0, 112, 101, 114, 102, 95, 101, 118, 101, 110,
-        116, 95, 99, 111, 110, 116, 101, 120, 116, 0, 112, 109, 117, 0, 97, 99, 116, 105, 118, 101,
-        95, 99, 116, 120, 95, 108, 105, 115, 116, 0, 112, 105, 110, 110, 101, 100, 95, 103, 114,
-        111, 117, 112, 115, 0, 102, 108, 101, 120, 105, 98, 108, 101, 95, 103, 114, 111, 117, 112,
-        115, 0, 101, 118, 101, 110, 116, 95, 108, 105, 115, 116, 0, 112, 105, 110, 110, 101, 100,
-        95, 97, 99, 116, 105, 118, 101, 0, 102, 108, 101, 120, 105, 98, 108, 101, 95, 97, 99, 116,
-        105, 118, 101, 0, 110, 114, 95, 101, 118, 101, 110, 116, 115, 0, 110, 114, 95, 97, 99, 116,
-        105, 118, 101, 0, 105, 115, 95, 97, 99, 116, 105, 118, 101, 0, 110, 114, 95, 115, 116, 97,
-        116, 0, 110, 114, 95, 102, 114, 101, 113, 0, 114, 111, 116, 97, 116, 101, 95, 100, 105,
-        115, 97, 98, 108, 101, 0, 114, 111, 116, 97, 116, 101, 95, 110, 101, 99, 101, 115, 115, 97,
-        114, 121, 0, 114, 101, 102, 99, 111, 117, 110, 116, 0, 116, 105, 109, 101, 115, 116, 97,
-        109, 112, 0, 112, 97, 114, 101, 110, 116, 95, 99, 116, 120, 0, 112, 97, 114, 101, 110, 116,
-        95, 103, 101, 110, 0, 103, 101, 110, 101, 114, 97, 116, 105, 111, 110, 0, 112, 105, 110,
-        95, 99, 111, 117, 110, 116, 0, 110, 114, 95, 99, 103, 114, 111, 117, 112, 115, 0, 116, 97,
-        115, 107, 95, 99, 116, 120, 95, 100, 97, 116, 97, 0, 112, 101, 114, 102, 95, 101, 118, 101,
-        110, 116, 95, 103, 114, 111, 117, 112, 115, 0, 116, 114, 101, 101, 0, 105, 110, 100, 101,
-        120, 0, 115, 104, 111, 114, 116, 0, 116, 108, 98, 102, 108, 117, 115, 104, 95, 117, 110,
-        109, 97, 112, 95, 98, 97, 116, 99, 104, 0, 97, 114, 99, 104, 0, 102, 108, 117, 115, 104,
-        95, 114, 101, 113, 117, 105, 114, 101, 100, 0, 119, 114, 105, 116, 97, 98, 108, 101, 0, 97,
-        114, 99, 104, 95, 116, 108, 98, 102, 108, 117, 115, 104, 95, 117, 110, 109, 97, 112, 95,
-        98, 97, 116, 99, 104, 0, 98, 111, 111, 108, 0, 95, 66, 111, 111, 108, 0, 114, 99, 117, 95,
-        117, 115, 101, 114, 115, 0, 112, 97, 103, 101, 95, 102, 114, 97, 103, 0, 112, 97, 103, 101,
-        0, 111, 102, 102, 115, 101, 116, 0, 98, 97, 99, 107, 116, 114, 97, 99, 101, 0, 109, 97,
-        120, 0, 103, 102, 112, 95, 116, 0, 116, 104, 114, 101, 97, 100, 95, 115, 116, 114, 117, 99,
-        116, 0, 116, 108, 115, 95, 97, 114, 114, 97, 121, 0, 115, 112, 0, 101, 115, 0, 100, 115, 0,
-        102, 115, 105, 110, 100, 101, 120, 0, 103, 115, 105, 110, 100, 101, 120, 0, 102, 115, 98,
-        97, 115, 101, 0, 103, 115, 98, 97, 115, 101, 0, 112, 116, 114, 97, 99, 101, 95, 98, 112,
-        115, 0, 100, 101, 98, 117, 103, 114, 101, 103, 54, 0, 112, 116, 114, 97, 99, 101, 95, 100,
-        114, 55, 0, 99, 114, 50, 0, 116, 114, 97, 112, 95, 110, 114, 0, 101, 114, 114, 111, 114,
-        95, 99, 111, 100, 101, 0, 105, 111, 95, 98, 105, 116, 109, 97, 112, 0, 105, 111, 112, 108,
-        95, 101, 109, 117, 108, 0, 97, 100, 100, 114, 95, 108, 105, 109, 105, 116, 0, 115, 105,
-        103, 95, 111, 110, 95, 117, 97, 99, 99, 101, 115, 115, 95, 101, 114, 114, 0, 102, 112, 117,
-        0, 100, 101, 115, 99, 95, 115, 116, 114, 117, 99, 116, 0, 108, 105, 109, 105, 116, 48, 0,
-        98, 97, 115, 101, 48, 0, 98, 97, 115, 101, 49, 0, 100, 112, 108, 0, 112, 0, 108, 105, 109,
-        105, 116, 49, 0, 97, 118, 108, 0, 108, 0, 100, 0, 103, 0, 98, 97, 115, 101, 50, 0, 112,
-        101, 114, 102, 95, 101, 118, 101, 110, 116, 0, 101, 118, 101, 110, 116, 95, 101, 110, 116,
-        114, 121, 0, 115, 105, 98, 108, 105, 110, 103, 95, 108, 105, 115, 116, 0, 97, 99, 116, 105,
-        118, 101, 95, 108, 105, 115, 116, 0, 103, 114, 111, 117, 112, 95, 105, 110, 100, 101, 120,
-        0, 109, 105, 103, 114, 97, 116, 101, 95, 101, 110, 116, 114, 121, 0, 104, 108, 105, 115,
-        116, 95, 101, 110, 116, 114, 121, 0, 97, 99, 116, 105, 118, 101, 95, 101, 110, 116, 114,
-        121, 0, 110, 114, 95, 115, 105, 98, 108, 105, 110, 103, 115, 0, 101, 118, 101, 110, 116,
-        95, 99, 97, 112, 115, 0, 103, 114, 111, 117, 112, 95, 99, 97, 112, 115, 0, 112, 109, 117,
-        95, 112, 114, 105, 118, 97, 116, 101, 0, 97, 116, 116, 97, 99, 104, 95, 115, 116, 97, 116,
-        101, 0, 99, 104, 105, 108, 100, 95, 99, 111, 117, 110, 116, 0, 116, 111, 116, 97, 108, 95,
-        116, 105, 109, 101, 95, 101, 110, 97, 98, 108, 101, 100, 0, 116, 111, 116, 97, 108, 95,
-        116, 105, 109, 101, 95, 114, 117, 110, 110, 105, 110, 103, 0, 116, 115, 116, 97, 109, 112,
-        0, 115, 104, 97, 100, 111, 119, 95, 99, 116, 120, 95, 116, 105, 109, 101, 0, 97, 116, 116,
-        114, 0, 104, 101, 97, 100, 101, 114, 95, 115, 105, 122, 101, 0, 105, 100, 95, 104, 101, 97,
-        100, 101, 114, 95, 115, 105, 122, 101, 0, 114, 101, 97, 100, 95, 115, 105, 122, 101, 0,
-        104, 119, 0, 99, 104, 105, 108, 100, 95, 116, 111, 116, 97, 108, 95, 116, 105, 109, 101,
-        95, 101, 110, 97, 98, 108, 101, 100, 0, 99, 104, 105, 108, 100, 95, 116, 111, 116, 97, 108,
-        95, 116, 105, 109, 101, 95, 114, 117, 110, 110, 105, 110, 103, 0, 99, 104, 105, 108, 100,
-        95, 109, 117, 116, 101, 120, 0, 99, 104, 105, 108, 100, 95, 108, 105, 115, 116, 0, 111,
-        110, 99, 112, 117, 0, 111, 119, 110, 101, 114, 95, 101, 110, 116, 114, 121, 0, 109, 109,
-        97, 112, 95, 109, 117, 116, 101, 120, 0, 109, 109, 97, 112, 95, 99, 111, 117, 110, 116, 0,
-        114, 98, 95, 101, 110, 116, 114, 121, 0, 114, 99, 117, 95, 98, 97, 116, 99, 104, 101, 115,
-        0, 114, 99, 117, 95, 112, 101, 110, 100, 105, 110, 103, 0, 119, 97, 105, 116, 113, 0, 102,
-        97, 115, 121, 110, 99, 0, 112, 101, 110, 100, 105, 110, 103, 95, 119, 97, 107, 101, 117,
-        112, 0, 112, 101, 110, 100, 105, 110, 103, 95, 107, 105, 108, 108, 0, 112, 101, 110, 100,
-        105, 110, 103, 95, 100, 105, 115, 97, 98, 108, 101, 0, 101, 118, 101, 110, 116, 95, 108,
-        105, 109, 105, 116, 0, 97, 100, 100, 114, 95, 102, 105, 108, 116, 101, 114, 115, 0, 97,
-        100, 100, 114, 95, 102, 105, 108, 116, 101, 114, 95, 114, 97, 110, 103, 101, 115, 0, 97,
-        100, 100, 114, 95, 102, 105, 108, 116, 101, 114, 115, 95, 103, 101, 110, 0, 97, 117, 120,
-        95, 101, 118, 101, 110, 116, 0, 100, 101, 115, 116, 114, 111, 121, 0, 110, 115, 0, 105,
-        100, 0, 99, 108, 111, 99, 107, 0, 111, 118, 101, 114, 102, 108, 111, 119, 95, 104, 97, 110,
-        100, 108, 101, 114, 0, 111, 118, 101, 114, 102, 108, 111, 119, 95, 104, 97, 110, 100, 108,
-        101, 114, 95, 99, 111, 110, 116, 101, 120, 116, 0, 111, 114, 105, 103, 95, 111, 118, 101,
-        114, 102, 108, 111, 119, 95, 104, 97, 110, 100, 108, 101, 114, 0, 112, 114, 111, 103, 0,
-        116, 112, 95, 101, 118, 101, 110, 116, 0, 102, 116, 114, 97, 99, 101, 95, 111, 112, 115, 0,
-        99, 103, 114, 112, 0, 115, 98, 95, 108, 105, 115, 116, 0, 112, 101, 114, 102, 95, 101, 118,
-        101, 110, 116, 95, 115, 116, 97, 116, 101, 0, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95,
-        83, 84, 65, 84, 69, 95, 68, 69, 65, 68, 0, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95, 83,
-        84, 65, 84, 69, 95, 69, 88, 73, 84, 0, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95, 83, 84,
-        65, 84, 69, 95, 69, 82, 82, 79, 82, 0, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95, 83, 84,
-        65, 84, 69, 95, 79, 70, 70, 0, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95, 83, 84, 65, 84,
-        69, 95, 73, 78, 65, 67, 84, 73, 86, 69, 0, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95, 83,
-        84, 65, 84, 69, 95, 65, 67, 84, 73, 86, 69, 0, 108, 111, 99, 97, 108, 54, 52, 95, 116, 0,
-        97, 0, 108, 111, 99, 97, 108, 95, 116, 0, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116,
-        95, 97, 116, 116, 114, 0, 99, 111, 110, 102, 105, 103, 0, 115, 97, 109, 112, 108, 101, 95,
-        116, 121, 112, 101, 0, 114, 101, 97, 100, 95, 102, 111, 114, 109, 97, 116, 0, 100, 105,
-        115, 97, 98, 108, 101, 100, 0, 105, 110, 104, 101, 114, 105, 116, 0, 112, 105, 110, 110,
-        101, 100, 0, 101, 120, 99, 108, 117, 115, 105, 118, 101, 0, 101, 120, 99, 108, 117, 100,
-        101, 95, 117, 115, 101, 114, 0, 101, 120, 99, 108, 117, 100, 101, 95, 107, 101, 114, 110,
-        101, 108, 0, 101, 120, 99, 108, 117, 100, 101, 95, 104, 118, 0, 101, 120, 99, 108, 117,
-        100, 101, 95, 105, 100, 108, 101, 0, 109, 109, 97, 112, 0, 102, 114, 101, 113, 0, 105, 110,
-        104, 101, 114, 105, 116, 95, 115, 116, 97, 116, 0, 101, 110, 97, 98, 108, 101, 95, 111,
-        110, 95, 101, 120, 101, 99, 0, 119, 97, 116, 101, 114, 109, 97, 114, 107, 0, 112, 114, 101,
-        99, 105, 115, 101, 95, 105, 112, 0, 109, 109, 97, 112, 95, 100, 97, 116, 97, 0, 115, 97,
-        109, 112, 108, 101, 95, 105, 100, 95, 97, 108, 108, 0, 101, 120, 99, 108, 117, 100, 101,
-        95, 104, 111, 115, 116, 0, 101, 120, 99, 108, 117, 100, 101, 95, 103, 117, 101, 115, 116,
-        0, 101, 120, 99, 108, 117, 100, 101, 95, 99, 97, 108, 108, 99, 104, 97, 105, 110, 95, 107,
-        101, 114, 110, 101, 108, 0, 101, 120, 99, 108, 117, 100, 101, 95, 99, 97, 108, 108, 99,
-        104, 97, 105, 110, 95, 117, 115, 101, 114, 0, 109, 109, 97, 112, 50, 0, 99, 111, 109, 109,
-        95, 101, 120, 101, 99, 0, 117, 115, 101, 95, 99, 108, 111, 99, 107, 105, 100, 0, 99, 111,
-        110, 116, 101, 120, 116, 95, 115, 119, 105, 116, 99, 104, 0, 119, 114, 105, 116, 101, 95,
-        98, 97, 99, 107, 119, 97, 114, 100, 0, 110, 97, 109, 101, 115, 112, 97, 99, 101, 115, 0,
-        107, 115, 121, 109, 98, 111, 108, 0, 98, 112, 102, 95, 101, 118, 101, 110, 116, 0, 97, 117,
-        120, 95, 111, 117, 116, 112, 117, 116, 0, 99, 103, 114, 111, 117, 112, 0, 95, 95, 114, 101,
-        115, 101, 114, 118, 101, 100, 95, 49, 0, 98, 112, 95, 116, 121, 112, 101, 0, 98, 114, 97,
-        110, 99, 104, 95, 115, 97, 109, 112, 108, 101, 95, 116, 121, 112, 101, 0, 115, 97, 109,
-        112, 108, 101, 95, 114, 101, 103, 115, 95, 117, 115, 101, 114, 0, 115, 97, 109, 112, 108,
-        101, 95, 115, 116, 97, 99, 107, 95, 117, 115, 101, 114, 0, 115, 97, 109, 112, 108, 101, 95,
-        114, 101, 103, 115, 95, 105, 110, 116, 114, 0, 97, 117, 120, 95, 119, 97, 116, 101, 114,
-        109, 97, 114, 107, 0, 115, 97, 109, 112, 108, 101, 95, 109, 97, 120, 95, 115, 116, 97, 99,
-        107, 0, 95, 95, 114, 101, 115, 101, 114, 118, 101, 100, 95, 50, 0, 97, 117, 120, 95, 115,
-        97, 109, 112, 108, 101, 95, 115, 105, 122, 101, 0, 95, 95, 114, 101, 115, 101, 114, 118,
-        101, 100, 95, 51, 0, 115, 97, 109, 112, 108, 101, 95, 112, 101, 114, 105, 111, 100, 0, 115,
-        97, 109, 112, 108, 101, 95, 102, 114, 101, 113, 0, 119, 97, 107, 101, 117, 112, 95, 101,
-        118, 101, 110, 116, 115, 0, 119, 97, 107, 101, 117, 112, 95, 119, 97, 116, 101, 114, 109,
-        97, 114, 107, 0, 98, 112, 95, 97, 100, 100, 114, 0, 107, 112, 114, 111, 98, 101, 95, 102,
-        117, 110, 99, 0, 117, 112, 114, 111, 98, 101, 95, 112, 97, 116, 104, 0, 99, 111, 110, 102,
-        105, 103, 49, 0, 98, 112, 95, 108, 101, 110, 0, 107, 112, 114, 111, 98, 101, 95, 97, 100,
-        100, 114, 0, 112, 114, 111, 98, 101, 95, 111, 102, 102, 115, 101, 116, 0, 99, 111, 110,
-        102, 105, 103, 50, 0, 95, 95, 115, 51, 50, 0, 104, 119, 95, 112, 101, 114, 102, 95, 101,
-        118, 101, 110, 116, 0, 116, 97, 114, 103, 101, 116, 0, 112, 114, 101, 118, 95, 99, 111,
-        117, 110, 116, 0, 108, 97, 115, 116, 95, 112, 101, 114, 105, 111, 100, 0, 112, 101, 114,
-        105, 111, 100, 95, 108, 101, 102, 116, 0, 105, 110, 116, 101, 114, 114, 117, 112, 116, 115,
-        95, 115, 101, 113, 0, 105, 110, 116, 101, 114, 114, 117, 112, 116, 115, 0, 102, 114, 101,
-        113, 95, 116, 105, 109, 101, 95, 115, 116, 97, 109, 112, 0, 102, 114, 101, 113, 95, 99,
-        111, 117, 110, 116, 95, 115, 116, 97, 109, 112, 0, 108, 97, 115, 116, 95, 116, 97, 103, 0,
-        99, 111, 110, 102, 105, 103, 95, 98, 97, 115, 101, 0, 101, 118, 101, 110, 116, 95, 98, 97,
-        115, 101, 0, 101, 118, 101, 110, 116, 95, 98, 97, 115, 101, 95, 114, 100, 112, 109, 99, 0,
-        105, 100, 120, 0, 108, 97, 115, 116, 95, 99, 112, 117, 0, 101, 120, 116, 114, 97, 95, 114,
-        101, 103, 0, 98, 114, 97, 110, 99, 104, 95, 114, 101, 103, 0, 104, 119, 95, 112, 101, 114,
-        102, 95, 101, 118, 101, 110, 116, 95, 101, 120, 116, 114, 97, 0, 114, 101, 103, 0, 97, 108,
-        108, 111, 99, 0, 116, 112, 95, 108, 105, 115, 116, 0, 112, 119, 114, 95, 97, 99, 99, 0,
-        112, 116, 115, 99, 0, 105, 110, 102, 111, 0, 98, 112, 95, 108, 105, 115, 116, 0, 97, 114,
-        99, 104, 95, 104, 119, 95, 98, 114, 101, 97, 107, 112, 111, 105, 110, 116, 0, 97, 100, 100,
-        114, 101, 115, 115, 0, 109, 97, 115, 107, 0, 108, 101, 110, 0, 105, 111, 109, 109, 117, 95,
-        98, 97, 110, 107, 0, 105, 111, 109, 109, 117, 95, 99, 110, 116, 114, 0, 112, 97, 100, 100,
-        105, 110, 103, 0, 99, 111, 110, 102, 0, 99, 111, 110, 102, 49, 0, 119, 97, 105, 116, 95,
-        113, 117, 101, 117, 101, 95, 104, 101, 97, 100, 95, 116, 0, 119, 97, 105, 116, 95, 113,
-        117, 101, 117, 101, 95, 104, 101, 97, 100, 0, 104, 101, 97, 100, 0, 105, 114, 113, 95, 119,
-        111, 114, 107, 0, 108, 108, 110, 111, 100, 101, 0, 112, 101, 114, 102, 95, 97, 100, 100,
-        114, 95, 102, 105, 108, 116, 101, 114, 115, 95, 104, 101, 97, 100, 0, 110, 114, 95, 102,
-        105, 108, 101, 95, 102, 105, 108, 116, 101, 114, 115, 0, 112, 101, 114, 102, 95, 111, 118,
-        101, 114, 102, 108, 111, 119, 95, 104, 97, 110, 100, 108, 101, 114, 95, 116, 0, 112, 101,
-        114, 102, 95, 115, 97, 109, 112, 108, 101, 95, 100, 97, 116, 97, 0, 97, 100, 100, 114, 0,
-        114, 97, 119, 0, 98, 114, 95, 115, 116, 97, 99, 107, 0, 112, 101, 114, 105, 111, 100, 0,
-        116, 120, 110, 0, 100, 97, 116, 97, 95, 115, 114, 99, 0, 105, 112, 0, 116, 105, 100, 95,
-        101, 110, 116, 114, 121, 0, 115, 116, 114, 101, 97, 109, 95, 105, 100, 0, 99, 112, 117, 95,
-        101, 110, 116, 114, 121, 0, 99, 97, 108, 108, 99, 104, 97, 105, 110, 0, 97, 117, 120, 95,
-        115, 105, 122, 101, 0, 114, 101, 103, 115, 95, 117, 115, 101, 114, 0, 114, 101, 103, 115,
-        95, 117, 115, 101, 114, 95, 99, 111, 112, 121, 0, 114, 101, 103, 115, 95, 105, 110, 116,
-        114, 0, 115, 116, 97, 99, 107, 95, 117, 115, 101, 114, 95, 115, 105, 122, 101, 0, 112, 104,
-        121, 115, 95, 97, 100, 100, 114, 0, 112, 101, 114, 102, 95, 109, 101, 109, 95, 100, 97,
-        116, 97, 95, 115, 114, 99, 0, 109, 101, 109, 95, 111, 112, 0, 109, 101, 109, 95, 108, 118,
-        108, 0, 109, 101, 109, 95, 115, 110, 111, 111, 112, 0, 109, 101, 109, 95, 108, 111, 99,
-        107, 0, 109, 101, 109, 95, 100, 116, 108, 98, 0, 109, 101, 109, 95, 108, 118, 108, 95, 110,
-        117, 109, 0, 109, 101, 109, 95, 114, 101, 109, 111, 116, 101, 0, 109, 101, 109, 95, 115,
-        110, 111, 111, 112, 120, 0, 109, 101, 109, 95, 114, 115, 118, 100, 0, 116, 105, 100, 0,
-        114, 101, 115, 101, 114, 118, 101, 100, 0, 112, 101, 114, 102, 95, 114, 101, 103, 115, 0,
-        97, 98, 105, 0, 114, 101, 103, 115, 0, 112, 116, 95, 114, 101, 103, 115, 0, 114, 49, 53, 0,
-        114, 49, 52, 0, 114, 49, 51, 0, 114, 49, 50, 0, 98, 112, 0, 98, 120, 0, 114, 49, 49, 0,
-        114, 49, 48, 0, 114, 57, 0, 114, 56, 0, 97, 120, 0, 99, 120, 0, 100, 120, 0, 115, 105, 0,
-        100, 105, 0, 111, 114, 105, 103, 95, 97, 120, 0, 99, 115, 0, 115, 115, 0, 112, 114, 105,
-        118, 97, 116, 101, 0, 115, 97, 118, 101, 100, 95, 102, 117, 110, 99, 0, 108, 111, 99, 97,
-        108, 95, 104, 97, 115, 104, 0, 102, 117, 110, 99, 95, 104, 97, 115, 104, 0, 111, 108, 100,
-        95, 104, 97, 115, 104, 0, 116, 114, 97, 109, 112, 111, 108, 105, 110, 101, 0, 116, 114, 97,
-        109, 112, 111, 108, 105, 110, 101, 95, 115, 105, 122, 101, 0, 102, 116, 114, 97, 99, 101,
-        95, 102, 117, 110, 99, 95, 116, 0, 102, 116, 114, 97, 99, 101, 95, 111, 112, 115, 95, 104,
-        97, 115, 104, 0, 110, 111, 116, 114, 97, 99, 101, 95, 104, 97, 115, 104, 0, 102, 105, 108,
-        116, 101, 114, 95, 104, 97, 115, 104, 0, 114, 101, 103, 101, 120, 95, 108, 111, 99, 107, 0,
-        109, 109, 95, 115, 101, 103, 109, 101, 110, 116, 95, 116, 0, 115, 101, 103, 0, 97, 118,
-        120, 53, 49, 50, 95, 116, 105, 109, 101, 115, 116, 97, 109, 112, 0, 102, 112, 114, 101,
-        103, 115, 95, 115, 116, 97, 116, 101, 0, 102, 115, 97, 118, 101, 0, 102, 120, 115, 97, 118,
-        101, 0, 115, 111, 102, 116, 0, 120, 115, 97, 118, 101, 0, 95, 95, 112, 97, 100, 100, 105,
-        110, 103, 0, 102, 114, 101, 103, 115, 95, 115, 116, 97, 116, 101, 0, 99, 119, 100, 0, 115,
-        119, 100, 0, 116, 119, 100, 0, 102, 105, 112, 0, 102, 99, 115, 0, 102, 111, 111, 0, 102,
-        111, 115, 0, 115, 116, 95, 115, 112, 97, 99, 101, 0, 102, 120, 114, 101, 103, 115, 95, 115,
-        116, 97, 116, 101, 0, 102, 111, 112, 0, 109, 120, 99, 115, 114, 0, 109, 120, 99, 115, 114,
-        95, 109, 97, 115, 107, 0, 120, 109, 109, 95, 115, 112, 97, 99, 101, 0, 114, 105, 112, 0,
-        114, 100, 112, 0, 112, 97, 100, 100, 105, 110, 103, 49, 0, 115, 119, 95, 114, 101, 115,
-        101, 114, 118, 101, 100, 0, 115, 119, 114, 101, 103, 115, 95, 115, 116, 97, 116, 101, 0,
-        102, 116, 111, 112, 0, 99, 104, 97, 110, 103, 101, 100, 0, 108, 111, 111, 107, 97, 104,
-        101, 97, 100, 0, 110, 111, 95, 117, 112, 100, 97, 116, 101, 0, 114, 109, 0, 97, 108, 105,
-        109, 105, 116, 0, 101, 110, 116, 114, 121, 95, 101, 105, 112, 0, 120, 114, 101, 103, 115,
-        95, 115, 116, 97, 116, 101, 0, 105, 51, 56, 55, 0, 104, 101, 97, 100, 101, 114, 0, 101,
-        120, 116, 101, 110, 100, 101, 100, 95, 115, 116, 97, 116, 101, 95, 97, 114, 101, 97, 0,
-        120, 115, 116, 97, 116, 101, 95, 104, 101, 97, 100, 101, 114, 0, 120, 102, 101, 97, 116,
-        117, 114, 101, 115, 0, 120, 99, 111, 109, 112, 95, 98, 118, 0, 98, 112, 102, 95, 112, 114,
-        111, 103, 0, 112, 97, 103, 101, 115, 0, 106, 105, 116, 101, 100, 0, 106, 105, 116, 95, 114,
-        101, 113, 117, 101, 115, 116, 101, 100, 0, 103, 112, 108, 95, 99, 111, 109, 112, 97, 116,
-        105, 98, 108, 101, 0, 99, 98, 95, 97, 99, 99, 101, 115, 115, 0, 100, 115, 116, 95, 110,
-        101, 101, 100, 101, 100, 0, 98, 108, 105, 110, 100, 101, 100, 0, 105, 115, 95, 102, 117,
-        110, 99, 0, 107, 112, 114, 111, 98, 101, 95, 111, 118, 101, 114, 114, 105, 100, 101, 0,
-        104, 97, 115, 95, 99, 97, 108, 108, 99, 104, 97, 105, 110, 95, 98, 117, 102, 0, 101, 110,
-        102, 111, 114, 99, 101, 95, 101, 120, 112, 101, 99, 116, 101, 100, 95, 97, 116, 116, 97,
-        99, 104, 95, 116, 121, 112, 101, 0, 101, 120, 112, 101, 99, 116, 101, 100, 95, 97, 116,
-        116, 97, 99, 104, 95, 116, 121, 112, 101, 0, 106, 105, 116, 101, 100, 95, 108, 101, 110, 0,
-        116, 97, 103, 0, 97, 117, 120, 0, 111, 114, 105, 103, 95, 112, 114, 111, 103, 0, 98, 112,
-        102, 95, 102, 117, 110, 99, 0, 105, 110, 115, 110, 115, 0, 105, 110, 115, 110, 115, 105, 0,
-        98, 112, 102, 95, 112, 114, 111, 103, 95, 116, 121, 112, 101, 0, 66, 80, 70, 95, 80, 82,
-        79, 71, 95, 84, 89, 80, 69, 95, 85, 78, 83, 80, 69, 67, 0, 66, 80, 70, 95, 80, 82, 79, 71,
-        95, 84, 89, 80, 69, 95, 83, 79, 67, 75, 69, 84, 95, 70, 73, 76, 84, 69, 82, 0, 66, 80, 70,
-        95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 75, 80, 82, 79, 66, 69, 0, 66, 80, 70, 95, 80,
-        82, 79, 71, 95, 84, 89, 80, 69, 95, 83, 67, 72, 69, 68, 95, 67, 76, 83, 0, 66, 80, 70, 95,
-        80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 83, 67, 72, 69, 68, 95, 65, 67, 84, 0, 66, 80, 70,
-        95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 84, 82, 65, 67, 69, 80, 79, 73, 78, 84, 0, 66,
-        80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 88, 68, 80, 0, 66, 80, 70, 95, 80, 82,
-        79, 71, 95, 84, 89, 80, 69, 95, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 0, 66, 80, 70, 95,
-        80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 67, 71, 82, 79, 85, 80, 95, 83, 75, 66, 0, 66, 80,
-        70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 67, 71, 82, 79, 85, 80, 95, 83, 79, 67, 75,
-        0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 76, 87, 84, 95, 73, 78, 0, 66,
-        80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 76, 87, 84, 95, 79, 85, 84, 0, 66, 80,
-        70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 76, 87, 84, 95, 88, 77, 73, 84, 0, 66, 80,
-        70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 83, 79, 67, 75, 95, 79, 80, 83, 0, 66, 80,
-        70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 83, 75, 95, 83, 75, 66, 0, 66, 80, 70, 95,
-        80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 67, 71, 82, 79, 85, 80, 95, 68, 69, 86, 73, 67, 69,
-        0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 83, 75, 95, 77, 83, 71, 0, 66,
-        80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 82, 65, 87, 95, 84, 82, 65, 67, 69, 80,
-        79, 73, 78, 84, 0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 67, 71, 82, 79,
-        85, 80, 95, 83, 79, 67, 75, 95, 65, 68, 68, 82, 0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84,
-        89, 80, 69, 95, 76, 87, 84, 95, 83, 69, 71, 54, 76, 79, 67, 65, 76, 0, 66, 80, 70, 95, 80,
-        82, 79, 71, 95, 84, 89, 80, 69, 95, 76, 73, 82, 67, 95, 77, 79, 68, 69, 50, 0, 66, 80, 70,
-        95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 83, 75, 95, 82, 69, 85, 83, 69, 80, 79, 82, 84,
-        0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 70, 76, 79, 87, 95, 68, 73, 83,
-        83, 69, 67, 84, 79, 82, 0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 67, 71,
-        82, 79, 85, 80, 95, 83, 89, 83, 67, 84, 76, 0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84, 89,
-        80, 69, 95, 82, 65, 87, 95, 84, 82, 65, 67, 69, 80, 79, 73, 78, 84, 95, 87, 82, 73, 84, 65,
-        66, 76, 69, 0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 67, 71, 82, 79, 85,
-        80, 95, 83, 79, 67, 75, 79, 80, 84, 0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69,
-        95, 84, 82, 65, 67, 73, 78, 71, 0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95,
-        83, 84, 82, 85, 67, 84, 95, 79, 80, 83, 0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80,
-        69, 95, 69, 88, 84, 0, 66, 80, 70, 95, 80, 82, 79, 71, 95, 84, 89, 80, 69, 95, 76, 83, 77,
-        0, 98, 112, 102, 95, 97, 116, 116, 97, 99, 104, 95, 116, 121, 112, 101, 0, 66, 80, 70, 95,
-        67, 71, 82, 79, 85, 80, 95, 73, 78, 69, 84, 95, 73, 78, 71, 82, 69, 83, 83, 0, 66, 80, 70,
-        95, 67, 71, 82, 79, 85, 80, 95, 73, 78, 69, 84, 95, 69, 71, 82, 69, 83, 83, 0, 66, 80, 70,
-        95, 67, 71, 82, 79, 85, 80, 95, 73, 78, 69, 84, 95, 83, 79, 67, 75, 95, 67, 82, 69, 65, 84,
-        69, 0, 66, 80, 70, 95, 67, 71, 82, 79, 85, 80, 95, 83, 79, 67, 75, 95, 79, 80, 83, 0, 66,
-        80, 70, 95, 83, 75, 95, 83, 75, 66, 95, 83, 84, 82, 69, 65, 77, 95, 80, 65, 82, 83, 69, 82,
-        0, 66, 80, 70, 95, 83, 75, 95, 83, 75, 66, 95, 83, 84, 82, 69, 65, 77, 95, 86, 69, 82, 68,
-        73, 67, 84, 0, 66, 80, 70, 95, 67, 71, 82, 79, 85, 80, 95, 68, 69, 86, 73, 67, 69, 0, 66,
-        80, 70, 95, 83, 75, 95, 77, 83, 71, 95, 86, 69, 82, 68, 73, 67, 84, 0, 66, 80, 70, 95, 67,
-        71, 82, 79, 85, 80, 95, 73, 78, 69, 84, 52, 95, 66, 73, 78, 68, 0, 66, 80, 70, 95, 67, 71,
-        82, 79, 85, 80, 95, 73, 78, 69, 84, 54, 95, 66, 73, 78, 68, 0, 66, 80, 70, 95, 67, 71, 82,
-        79, 85, 80, 95, 73, 78, 69, 84, 52, 95, 67, 79, 78, 78, 69, 67, 84, 0, 66, 80, 70, 95, 67,
-        71, 82, 79, 85, 80, 95, 73, 78, 69, 84, 54, 95, 67, 79, 78, 78, 69, 67, 84, 0, 66, 80, 70,
-        95, 67, 71, 82, 79, 85, 80, 95, 73, 78, 69, 84, 52, 95, 80, 79, 83, 84, 95, 66, 73, 78, 68,
-        0, 66, 80, 70, 95, 67, 71, 82, 79, 85, 80, 95, 73, 78, 69, 84, 54, 95, 80, 79, 83, 84, 95,
-        66, 73, 78, 68, 0, 66, 80, 70, 95, 67, 71, 82, 79, 85, 80, 95, 85, 68, 80, 52, 95, 83, 69,
-        78, 68, 77, 83, 71, 0, 66, 80, 70, 95, 67, 71, 82, 79, 85, 80, 95, 85, 68, 80, 54, 95, 83,
-        69, 78, 68, 77, 83, 71, 0, 66, 80, 70, 95, 76, 73, 82, 67, 95, 77, 79, 68, 69, 50, 0, 66,
-        80, 70, 95, 70, 76, 79, 87, 95, 68, 73, 83, 83, 69, 67, 84, 79, 82, 0, 66, 80, 70, 95, 67,
-        71, 82, 79, 85, 80, 95, 83, 89, 83, 67, 84, 76, 0, 66, 80, 70, 95, 67, 71, 82, 79, 85, 80,
-        95, 85, 68, 80, 52, 95, 82, 69, 67, 86, 77, 83, 71, 0, 66, 80, 70, 95, 67, 71, 82, 79, 85,
-        80, 95, 85, 68, 80, 54, 95, 82, 69, 67, 86, 77, 83, 71, 0, 66, 80, 70, 95, 67, 71, 82, 79,
-        85, 80, 95, 71, 69, 84, 83, 79, 67, 75, 79, 80, 84, 0, 66, 80, 70, 95, 67, 71, 82, 79, 85,
-        80, 95, 83, 69, 84, 83, 79, 67, 75, 79, 80, 84, 0, 66, 80, 70, 95, 84, 82, 65, 67, 69, 95,
-        82, 65, 87, 95, 84, 80, 0, 66, 80, 70, 95, 84, 82, 65, 67, 69, 95, 70, 69, 78, 84, 82, 89,
-        0, 66, 80, 70, 95, 84, 82, 65, 67, 69, 95, 70, 69, 88, 73, 84, 0, 66, 80, 70, 95, 77, 79,
-        68, 73, 70, 89, 95, 82, 69, 84, 85, 82, 78, 0, 66, 80, 70, 95, 76, 83, 77, 95, 77, 65, 67,
-        0, 66, 80, 70, 95, 84, 82, 65, 67, 69, 95, 73, 84, 69, 82, 0, 66, 80, 70, 95, 67, 71, 82,
-        79, 85, 80, 95, 73, 78, 69, 84, 52, 95, 71, 69, 84, 80, 69, 69, 82, 78, 65, 77, 69, 0, 66,
-        80, 70, 95, 67, 71, 82, 79, 85, 80, 95, 73, 78, 69, 84, 54, 95, 71, 69, 84, 80, 69, 69, 82,
-        78, 65, 77, 69, 0, 66, 80, 70, 95, 67, 71, 82, 79, 85, 80, 95, 73, 78, 69, 84, 52, 95, 71,
-        69, 84, 83, 79, 67, 75, 78, 65, 77, 69, 0, 66, 80, 70, 95, 67, 71, 82, 79, 85, 80, 95, 73,
-        78, 69, 84, 54, 95, 71, 69, 84, 83, 79, 67, 75, 78, 65, 77, 69, 0, 66, 80, 70, 95, 88, 68,
-        80, 95, 68, 69, 86, 77, 65, 80, 0, 95, 95, 77, 65, 88, 95, 66, 80, 70, 95, 65, 84, 84, 65,
-        67, 72, 95, 84, 89, 80, 69, 0, 98, 112, 102, 95, 105, 110, 115, 110, 0, 99, 111, 100, 101,
-        0, 100, 115, 116, 95, 114, 101, 103, 0, 115, 114, 99, 95, 114, 101, 103, 0, 111, 102, 102,
-        0, 105, 109, 109, 0, 95, 95, 115, 49, 54, 0, 115, 111, 99, 107, 95, 102, 105, 108, 116,
-        101, 114, 0, 106, 116, 0, 106, 102, 0, 107, 0, 98, 112, 102, 95, 112, 114, 111, 103, 95,
-        97, 117, 120, 0, 114, 101, 102, 99, 110, 116, 0, 117, 115, 101, 100, 95, 109, 97, 112, 95,
-        99, 110, 116, 0, 109, 97, 120, 95, 99, 116, 120, 95, 111, 102, 102, 115, 101, 116, 0, 109,
-        97, 120, 95, 112, 107, 116, 95, 111, 102, 102, 115, 101, 116, 0, 109, 97, 120, 95, 116,
-        112, 95, 97, 99, 99, 101, 115, 115, 0, 115, 116, 97, 99, 107, 95, 100, 101, 112, 116, 104,
-        0, 102, 117, 110, 99, 95, 99, 110, 116, 0, 102, 117, 110, 99, 95, 105, 100, 120, 0, 97,
-        116, 116, 97, 99, 104, 95, 98, 116, 102, 95, 105, 100, 0, 99, 116, 120, 95, 97, 114, 103,
-        95, 105, 110, 102, 111, 95, 115, 105, 122, 101, 0, 99, 116, 120, 95, 97, 114, 103, 95, 105,
-        110, 102, 111, 0, 108, 105, 110, 107, 101, 100, 95, 112, 114, 111, 103, 0, 118, 101, 114,
-        105, 102, 105, 101, 114, 95, 122, 101, 120, 116, 0, 111, 102, 102, 108, 111, 97, 100, 95,
-        114, 101, 113, 117, 101, 115, 116, 101, 100, 0, 97, 116, 116, 97, 99, 104, 95, 98, 116,
-        102, 95, 116, 114, 97, 99, 101, 0, 102, 117, 110, 99, 95, 112, 114, 111, 116, 111, 95, 117,
-        110, 114, 101, 108, 105, 97, 98, 108, 101, 0, 116, 114, 97, 109, 112, 111, 108, 105, 110,
-        101, 95, 112, 114, 111, 103, 95, 116, 121, 112, 101, 0, 116, 114, 97, 109, 112, 95, 104,
-        108, 105, 115, 116, 0, 97, 116, 116, 97, 99, 104, 95, 102, 117, 110, 99, 95, 112, 114, 111,
-        116, 111, 0, 97, 116, 116, 97, 99, 104, 95, 102, 117, 110, 99, 95, 110, 97, 109, 101, 0,
-        106, 105, 116, 95, 100, 97, 116, 97, 0, 112, 111, 107, 101, 95, 116, 97, 98, 0, 115, 105,
-        122, 101, 95, 112, 111, 107, 101, 95, 116, 97, 98, 0, 107, 115, 121, 109, 0, 111, 112, 115,
-        0, 117, 115, 101, 100, 95, 109, 97, 112, 115, 0, 108, 111, 97, 100, 95, 116, 105, 109, 101,
-        0, 99, 103, 114, 111, 117, 112, 95, 115, 116, 111, 114, 97, 103, 101, 0, 110, 97, 109, 101,
-        0, 111, 102, 102, 108, 111, 97, 100, 0, 98, 116, 102, 0, 102, 117, 110, 99, 95, 105, 110,
-        102, 111, 0, 102, 117, 110, 99, 95, 105, 110, 102, 111, 95, 97, 117, 120, 0, 108, 105, 110,
-        102, 111, 0, 106, 105, 116, 101, 100, 95, 108, 105, 110, 102, 111, 0, 102, 117, 110, 99,
-        95, 105, 110, 102, 111, 95, 99, 110, 116, 0, 110, 114, 95, 108, 105, 110, 102, 111, 0, 108,
-        105, 110, 102, 111, 95, 105, 100, 120, 0, 110, 117, 109, 95, 101, 120, 101, 110, 116, 114,
-        105, 101, 115, 0, 101, 120, 116, 97, 98, 108, 101, 0, 115, 116, 97, 116, 115, 0, 98, 112,
-        102, 95, 116, 114, 97, 109, 112, 95, 112, 114, 111, 103, 95, 116, 121, 112, 101, 0, 66, 80,
-        70, 95, 84, 82, 65, 77, 80, 95, 70, 69, 78, 84, 82, 89, 0, 66, 80, 70, 95, 84, 82, 65, 77,
-        80, 95, 70, 69, 88, 73, 84, 0, 66, 80, 70, 95, 84, 82, 65, 77, 80, 95, 77, 79, 68, 73, 70,
-        89, 95, 82, 69, 84, 85, 82, 78, 0, 66, 80, 70, 95, 84, 82, 65, 77, 80, 95, 77, 65, 88, 0,
-        66, 80, 70, 95, 84, 82, 65, 77, 80, 95, 82, 69, 80, 76, 65, 67, 69, 0, 98, 112, 102, 95,
-        107, 115, 121, 109, 0, 101, 110, 100, 0, 108, 110, 111, 100, 101, 0, 116, 110, 111, 100,
-        101, 0, 108, 97, 116, 99, 104, 95, 116, 114, 101, 101, 95, 110, 111, 100, 101, 0, 98, 112,
-        102, 95, 109, 97, 112, 0, 105, 110, 110, 101, 114, 95, 109, 97, 112, 95, 109, 101, 116, 97,
-        0, 109, 97, 112, 95, 116, 121, 112, 101, 0, 107, 101, 121, 95, 115, 105, 122, 101, 0, 118,
-        97, 108, 117, 101, 95, 115, 105, 122, 101, 0, 109, 97, 120, 95, 101, 110, 116, 114, 105,
-        101, 115, 0, 109, 97, 112, 95, 102, 108, 97, 103, 115, 0, 115, 112, 105, 110, 95, 108, 111,
-        99, 107, 95, 111, 102, 102, 0, 110, 117, 109, 97, 95, 110, 111, 100, 101, 0, 98, 116, 102,
-        95, 107, 101, 121, 95, 116, 121, 112, 101, 95, 105, 100, 0, 98, 116, 102, 95, 118, 97, 108,
-        117, 101, 95, 116, 121, 112, 101, 95, 105, 100, 0, 109, 101, 109, 111, 114, 121, 0, 98,
-        116, 102, 95, 118, 109, 108, 105, 110, 117, 120, 95, 118, 97, 108, 117, 101, 95, 116, 121,
-        112, 101, 95, 105, 100, 0, 98, 121, 112, 97, 115, 115, 95, 115, 112, 101, 99, 95, 118, 49,
-        0, 117, 115, 101, 114, 99, 110, 116, 0, 119, 111, 114, 107, 0, 102, 114, 101, 101, 122,
-        101, 95, 109, 117, 116, 101, 120, 0, 119, 114, 105, 116, 101, 99, 110, 116, 0, 98, 112,
-        102, 95, 109, 97, 112, 95, 116, 121, 112, 101, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89,
-        80, 69, 95, 85, 78, 83, 80, 69, 67, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95,
-        72, 65, 83, 72, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 65, 82, 82, 65, 89,
-        0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 80, 82, 79, 71, 95, 65, 82, 82, 65,
-        89, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 80, 69, 82, 70, 95, 69, 86, 69,
-        78, 84, 95, 65, 82, 82, 65, 89, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 80,
-        69, 82, 67, 80, 85, 95, 72, 65, 83, 72, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69,
-        95, 80, 69, 82, 67, 80, 85, 95, 65, 82, 82, 65, 89, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84,
-        89, 80, 69, 95, 83, 84, 65, 67, 75, 95, 84, 82, 65, 67, 69, 0, 66, 80, 70, 95, 77, 65, 80,
-        95, 84, 89, 80, 69, 95, 67, 71, 82, 79, 85, 80, 95, 65, 82, 82, 65, 89, 0, 66, 80, 70, 95,
-        77, 65, 80, 95, 84, 89, 80, 69, 95, 76, 82, 85, 95, 72, 65, 83, 72, 0, 66, 80, 70, 95, 77,
-        65, 80, 95, 84, 89, 80, 69, 95, 76, 82, 85, 95, 80, 69, 82, 67, 80, 85, 95, 72, 65, 83, 72,
-        0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 76, 80, 77, 95, 84, 82, 73, 69, 0,
-        66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 65, 82, 82, 65, 89, 95, 79, 70, 95, 77,
-        65, 80, 83, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 72, 65, 83, 72, 95, 79,
-        70, 95, 77, 65, 80, 83, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 68, 69, 86,
-        77, 65, 80, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 83, 79, 67, 75, 77, 65,
-        80, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 67, 80, 85, 77, 65, 80, 0, 66,
-        80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 88, 83, 75, 77, 65, 80, 0, 66, 80, 70, 95,
-        77, 65, 80, 95, 84, 89, 80, 69, 95, 83, 79, 67, 75, 72, 65, 83, 72, 0, 66, 80, 70, 95, 77,
-        65, 80, 95, 84, 89, 80, 69, 95, 67, 71, 82, 79, 85, 80, 95, 83, 84, 79, 82, 65, 71, 69, 0,
-        66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 82, 69, 85, 83, 69, 80, 79, 82, 84, 95,
-        83, 79, 67, 75, 65, 82, 82, 65, 89, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95,
-        80, 69, 82, 67, 80, 85, 95, 67, 71, 82, 79, 85, 80, 95, 83, 84, 79, 82, 65, 71, 69, 0, 66,
-        80, 70, 95, 77, 65, 80, 95, 84, 89, 80, 69, 95, 81, 85, 69, 85, 69, 0, 66, 80, 70, 95, 77,
-        65, 80, 95, 84, 89, 80, 69, 95, 83, 84, 65, 67, 75, 0, 66, 80, 70, 95, 77, 65, 80, 95, 84,
-        89, 80, 69, 95, 83, 75, 95, 83, 84, 79, 82, 65, 71, 69, 0, 66, 80, 70, 95, 77, 65, 80, 95,
-        84, 89, 80, 69, 95, 68, 69, 86, 77, 65, 80, 95, 72, 65, 83, 72, 0, 66, 80, 70, 95, 77, 65,
-        80, 95, 84, 89, 80, 69, 95, 83, 84, 82, 85, 67, 84, 95, 79, 80, 83, 0, 66, 80, 70, 95, 77,
-        65, 80, 95, 84, 89, 80, 69, 95, 82, 73, 78, 71, 66, 85, 70, 0, 98, 112, 102, 95, 109, 97,
-        112, 95, 109, 101, 109, 111, 114, 121, 0, 119, 111, 114, 107, 95, 115, 116, 114, 117, 99,
-        116, 0, 100, 97, 116, 97, 0, 101, 110, 116, 114, 121, 0, 119, 111, 114, 107, 95, 102, 117,
-        110, 99, 95, 116, 0, 98, 112, 102, 95, 105, 116, 101, 114, 95, 109, 101, 116, 97, 0, 115,
-        101, 115, 115, 105, 111, 110, 95, 105, 100, 0, 115, 101, 113, 95, 110, 117, 109, 0, 115,
-        101, 113, 0, 95, 108, 105, 99, 101, 110, 115, 101, 0, 98, 112, 102, 95, 112, 114, 111, 103,
-        95, 102, 111, 112, 115, 0, 117, 115, 101, 114, 95, 110, 97, 109, 101, 115, 112, 97, 99,
-        101, 0, 105, 110, 111, 100, 101, 0, 118, 102, 115, 109, 111, 117, 110, 116, 0, 115, 101,
-        113, 95, 102, 105, 108, 101, 0, 114, 101, 113, 117, 101, 115, 116, 95, 113, 117, 101, 117,
-        101, 0, 112, 105, 112, 101, 95, 105, 110, 111, 100, 101, 95, 105, 110, 102, 111, 0, 116,
-        97, 115, 107, 95, 103, 114, 111, 117, 112, 0, 118, 109, 95, 111, 112, 101, 114, 97, 116,
-        105, 111, 110, 115, 95, 115, 116, 114, 117, 99, 116, 0, 109, 109, 95, 115, 116, 114, 117,
-        99, 116, 0, 97, 100, 100, 114, 101, 115, 115, 95, 115, 112, 97, 99, 101, 0, 102, 105, 108,
-        101, 95, 111, 112, 101, 114, 97, 116, 105, 111, 110, 115, 0, 102, 97, 115, 121, 110, 99,
-        95, 115, 116, 114, 117, 99, 116, 0, 99, 111, 109, 112, 108, 101, 116, 105, 111, 110, 0,
-        104, 114, 116, 105, 109, 101, 114, 95, 99, 108, 111, 99, 107, 95, 98, 97, 115, 101, 0, 109,
-        101, 109, 95, 99, 103, 114, 111, 117, 112, 0, 117, 115, 101, 114, 95, 115, 116, 114, 117,
-        99, 116, 0, 95, 95, 107, 101, 114, 110, 101, 108, 95, 116, 105, 109, 101, 115, 112, 101,
-        99, 0, 111, 108, 100, 95, 116, 105, 109, 101, 115, 112, 101, 99, 51, 50, 0, 112, 105, 100,
-        95, 110, 97, 109, 101, 115, 112, 97, 99, 101, 0, 117, 115, 101, 114, 102, 97, 117, 108,
-        116, 102, 100, 95, 99, 116, 120, 0, 99, 115, 115, 95, 115, 101, 116, 0, 98, 112, 102, 95,
-        99, 116, 120, 95, 97, 114, 103, 95, 97, 117, 120, 0, 98, 112, 102, 95, 116, 114, 97, 109,
-        112, 111, 108, 105, 110, 101, 0, 98, 116, 102, 95, 116, 121, 112, 101, 0, 98, 112, 102, 95,
-        106, 105, 116, 95, 112, 111, 107, 101, 95, 100, 101, 115, 99, 114, 105, 112, 116, 111, 114,
-        0, 98, 112, 102, 95, 109, 97, 112, 95, 111, 112, 115, 0, 98, 112, 102, 95, 112, 114, 111,
-        103, 95, 111, 112, 115, 0, 115, 111, 99, 107, 95, 102, 112, 114, 111, 103, 95, 107, 101,
-        114, 110, 0, 98, 112, 102, 95, 112, 114, 111, 103, 95, 111, 102, 102, 108, 111, 97, 100, 0,
-        98, 112, 102, 95, 108, 105, 110, 101, 95, 105, 110, 102, 111, 0, 98, 112, 102, 95, 102,
-        117, 110, 99, 95, 105, 110, 102, 111, 0, 98, 112, 102, 95, 102, 117, 110, 99, 95, 105, 110,
-        102, 111, 95, 97, 117, 120, 0, 101, 120, 99, 101, 112, 116, 105, 111, 110, 95, 116, 97, 98,
-        108, 101, 95, 101, 110, 116, 114, 121, 0, 98, 112, 102, 95, 112, 114, 111, 103, 95, 115,
-        116, 97, 116, 115, 0, 107, 101, 121, 0, 116, 114, 97, 99, 101, 95, 101, 118, 101, 110, 116,
-        95, 99, 97, 108, 108, 0, 101, 118, 101, 110, 116, 95, 102, 105, 108, 116, 101, 114, 0, 102,
-        116, 114, 97, 99, 101, 95, 104, 97, 115, 104, 0, 112, 101, 114, 102, 95, 99, 103, 114, 111,
-        117, 112, 0, 112, 101, 114, 102, 95, 98, 117, 102, 102, 101, 114, 0, 112, 101, 114, 102,
-        95, 97, 100, 100, 114, 95, 102, 105, 108, 116, 101, 114, 95, 114, 97, 110, 103, 101, 0,
-        112, 101, 114, 102, 95, 114, 97, 119, 95, 114, 101, 99, 111, 114, 100, 0, 112, 101, 114,
-        102, 95, 98, 114, 97, 110, 99, 104, 95, 115, 116, 97, 99, 107, 0, 112, 101, 114, 102, 95,
-        99, 97, 108, 108, 99, 104, 97, 105, 110, 95, 101, 110, 116, 114, 121, 0, 107, 101, 114,
-        110, 101, 108, 95, 115, 105, 103, 105, 110, 102, 111, 0, 114, 99, 117, 95, 110, 111, 100,
-        101, 0, 112, 111, 108, 108, 102, 100, 0, 115, 101, 109, 95, 117, 110, 100, 111, 95, 108,
-        105, 115, 116, 0, 102, 115, 95, 115, 116, 114, 117, 99, 116, 0, 102, 105, 108, 101, 115,
-        95, 115, 116, 114, 117, 99, 116, 0, 115, 105, 103, 110, 97, 108, 95, 115, 116, 114, 117,
-        99, 116, 0, 115, 105, 103, 104, 97, 110, 100, 95, 115, 116, 114, 117, 99, 116, 0, 115, 101,
-        99, 99, 111, 109, 112, 95, 102, 105, 108, 116, 101, 114, 0, 114, 116, 95, 109, 117, 116,
-        101, 120, 95, 119, 97, 105, 116, 101, 114, 0, 98, 108, 107, 95, 112, 108, 117, 103, 0, 114,
-        111, 98, 117, 115, 116, 95, 108, 105, 115, 116, 95, 104, 101, 97, 100, 0, 99, 111, 109,
-        112, 97, 116, 95, 114, 111, 98, 117, 115, 116, 95, 108, 105, 115, 116, 95, 104, 101, 97,
-        100, 0, 102, 117, 116, 101, 120, 95, 112, 105, 95, 115, 116, 97, 116, 101, 0, 116, 97, 115,
-        107, 95, 100, 101, 108, 97, 121, 95, 105, 110, 102, 111, 0, 102, 116, 114, 97, 99, 101, 95,
-        114, 101, 116, 95, 115, 116, 97, 99, 107, 0, 117, 112, 114, 111, 98, 101, 95, 116, 97, 115,
-        107, 0, 118, 109, 95, 115, 116, 114, 117, 99, 116, 0, 109, 97, 116, 104, 95, 101, 109, 117,
-        95, 105, 110, 102, 111, 0, 47, 104, 111, 109, 101, 47, 106, 111, 115, 101, 47, 67, 111,
-        100, 101, 47, 98, 112, 102, 116, 111, 112, 47, 115, 114, 99, 47, 98, 112, 102, 47, 112,
-        105, 100, 95, 105, 116, 101, 114, 46, 98, 112, 102, 46, 99, 0, 9, 115, 116, 114, 117, 99,
-        116, 32, 116, 97, 115, 107, 95, 115, 116, 114, 117, 99, 116, 32, 42, 116, 97, 115, 107, 32,
-        61, 32, 99, 116, 120, 45, 62, 116, 97, 115, 107, 59, 0, 9, 115, 116, 114, 117, 99, 116, 32,
-        102, 105, 108, 101, 32, 42, 102, 105, 108, 101, 32, 61, 32, 99, 116, 120, 45, 62, 102, 105,
-        108, 101, 59, 0, 9, 105, 102, 32, 40, 33, 102, 105, 108, 101, 32, 124, 124, 32, 33, 116,
-        97, 115, 107, 41, 0, 9, 105, 102, 32, 40, 102, 105, 108, 101, 45, 62, 102, 95, 111, 112,
-        32, 33, 61, 32, 38, 98, 112, 102, 95, 112, 114, 111, 103, 95, 102, 111, 112, 115, 41, 0, 9,
-        95, 95, 98, 117, 105, 108, 116, 105, 110, 95, 109, 101, 109, 115, 101, 116, 40, 38, 101,
-        44, 32, 48, 44, 32, 115, 105, 122, 101, 111, 102, 40, 101, 41, 41, 59, 0, 9, 101, 46, 112,
-        105, 100, 32, 61, 32, 116, 97, 115, 107, 45, 62, 116, 103, 105, 100, 59, 0, 9, 101, 46,
-        105, 100, 32, 61, 32, 66, 80, 70, 95, 67, 79, 82, 69, 95, 82, 69, 65, 68, 40, 40, 115, 116,
-        114, 117, 99, 116, 32, 98, 112, 102, 95, 112, 114, 111, 103, 32, 42, 41, 102, 105, 108,
-        101, 45, 62, 112, 114, 105, 118, 97, 116, 101, 95, 100, 97, 116, 97, 44, 32, 97, 117, 120,
-        44, 32, 105, 100, 41, 59, 0, 9, 9, 9, 9, 32, 32, 116, 97, 115, 107, 45, 62, 103, 114, 111,
-        117, 112, 95, 108, 101, 97, 100, 101, 114, 45, 62, 99, 111, 109, 109, 41, 59, 0, 9, 98,
-        112, 102, 95, 112, 114, 111, 98, 101, 95, 114, 101, 97, 100, 95, 107, 101, 114, 110, 101,
-        108, 95, 115, 116, 114, 40, 38, 101, 46, 99, 111, 109, 109, 44, 32, 115, 105, 122, 101,
-        111, 102, 40, 101, 46, 99, 111, 109, 109, 41, 44, 0, 9, 98, 112, 102, 95, 115, 101, 113,
-        95, 119, 114, 105, 116, 101, 40, 99, 116, 120, 45, 62, 109, 101, 116, 97, 45, 62, 115, 101,
-        113, 44, 32, 38, 101, 44, 32, 115, 105, 122, 101, 111, 102, 40, 101, 41, 41, 59, 0, 125, 0,
-        48, 58, 49, 58, 48, 0, 48, 58, 51, 58, 48, 0, 48, 58, 51, 0, 48, 58, 55, 49, 0, 48, 58, 49,
-        54, 0, 48, 58, 54, 0, 48, 58, 55, 55, 0, 48, 58, 49, 48, 50, 0, 48, 58, 48, 58, 48, 0, 108,
-        105, 99, 101, 110, 115, 101, 0, 46, 107, 115, 121, 109, 115, 0, 105, 116, 101, 114, 47,
-        116, 97, 115, 107, 95, 102, 105, 108, 101, 0, 0, 0, 0, 0, 0, 0, 159, 235, 1, 0, 32, 0, 0,
-        0, 0, 0, 0, 0, 20, 0, 0, 0, 20, 0, 0, 0, 92, 1, 0, 0, 112, 1, 0, 0, 188, 0, 0, 0, 8, 0, 0,
-        0, 187, 58, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 16, 0, 0, 0, 187, 58, 0, 0, 21, 0,
-        0, 0, 0, 0, 0, 0, 201, 56, 0, 0, 247, 56, 0, 0, 34, 76, 0, 0, 8, 0, 0, 0, 201, 56, 0, 0,
-        30, 57, 0, 0, 27, 72, 0, 0, 16, 0, 0, 0, 201, 56, 0, 0, 62, 57, 0, 0, 12, 88, 0, 0, 32, 0,
-        0, 0, 201, 56, 0, 0, 83, 57, 0, 0, 12, 100, 0, 0, 40, 0, 0, 0, 201, 56, 0, 0, 83, 57, 0, 0,
-        17, 100, 0, 0, 56, 0, 0, 0, 201, 56, 0, 0, 83, 57, 0, 0, 6, 100, 0, 0, 72, 0, 0, 0, 201,
-        56, 0, 0, 118, 57, 0, 0, 2, 112, 0, 0, 96, 0, 0, 0, 201, 56, 0, 0, 155, 57, 0, 0, 16, 120,
-        0, 0, 104, 0, 0, 0, 201, 56, 0, 0, 155, 57, 0, 0, 8, 120, 0, 0, 112, 0, 0, 0, 201, 56, 0,
-        0, 176, 57, 0, 0, 9, 124, 0, 0, 144, 0, 0, 0, 201, 56, 0, 0, 118, 57, 0, 0, 2, 112, 0, 0,
-        160, 0, 0, 0, 201, 56, 0, 0, 176, 57, 0, 0, 9, 124, 0, 0, 216, 0, 0, 0, 201, 56, 0, 0, 118,
-        57, 0, 0, 2, 112, 0, 0, 224, 0, 0, 0, 201, 56, 0, 0, 176, 57, 0, 0, 9, 124, 0, 0, 248, 0,
-        0, 0, 201, 56, 0, 0, 247, 57, 0, 0, 13, 136, 0, 0, 16, 1, 0, 0, 201, 56, 0, 0, 118, 57, 0,
-        0, 2, 112, 0, 0, 32, 1, 0, 0, 201, 56, 0, 0, 24, 58, 0, 0, 2, 132, 0, 0, 48, 1, 0, 0, 201,
-        56, 0, 0, 76, 58, 0, 0, 21, 140, 0, 0, 56, 1, 0, 0, 201, 56, 0, 0, 76, 58, 0, 0, 27, 140,
-        0, 0, 64, 1, 0, 0, 201, 56, 0, 0, 76, 58, 0, 0, 2, 140, 0, 0, 88, 1, 0, 0, 201, 56, 0, 0,
-        123, 58, 0, 0, 1, 152, 0, 0, 16, 0, 0, 0, 187, 58, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0,
-        0, 125, 58, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 2, 0, 0, 0, 131, 58, 0, 0, 0, 0, 0, 0, 32, 0, 0,
-        0, 15, 0, 0, 0, 137, 58, 0, 0, 0, 0, 0, 0, 96, 0, 0, 0, 85, 0, 0, 0, 141, 58, 0, 0, 0, 0,
-        0, 0, 112, 0, 0, 0, 15, 0, 0, 0, 146, 58, 0, 0, 0, 0, 0, 0, 120, 0, 0, 0, 97, 1, 0, 0, 146,
-        58, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 114, 1, 0, 0, 151, 58, 0, 0, 0, 0, 0, 0, 248, 0, 0, 0,
-        85, 0, 0, 0, 155, 58, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 85, 0, 0, 0, 160, 58, 0, 0, 0, 0, 0, 0,
-        48, 1, 0, 0, 2, 0, 0, 0, 166, 58, 0, 0, 0, 0, 0, 0, 56, 1, 0, 0, 153, 1, 0, 0, 166, 58, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 1, 0, 0,
-        0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 1, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0,
-        0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 216, 2, 0, 0, 0, 0, 0, 0,
-        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 97, 0, 0, 0, 9, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 224, 2, 0,
-        0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 16,
-        0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 240, 2, 0, 0, 0, 0, 0, 0, 162, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 152, 140, 0, 0, 0, 0, 0, 0, 76, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-}
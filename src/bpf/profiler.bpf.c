@@ -0,0 +1,106 @@
+#include "vmlinux.h"
+#include "bpf_helpers.h"
+
+char _license[] SEC("license") = "GPL";
+
+#define MAX_NUM_METRICS 4
+
+/* One perf event per metric and CPU, indexed by cpu + metric * num_cpu */
+struct {
+	__uint(type, BPF_MAP_TYPE_PERF_EVENT_ARRAY);
+	__uint(key_size, sizeof(__u32));
+	__uint(value_size, sizeof(int));
+} events SEC(".maps");
+
+/* Counter readings taken when the profiled program is entered */
+struct {
+	__uint(type, BPF_MAP_TYPE_PERCPU_ARRAY);
+	__uint(key_size, sizeof(__u32));
+	__uint(value_size, sizeof(struct bpf_perf_event_value));
+	__uint(max_entries, MAX_NUM_METRICS);
+} fentry_readings SEC(".maps");
+
+/* Counter deltas accumulated over every run of the profiled program */
+struct {
+	__uint(type, BPF_MAP_TYPE_PERCPU_ARRAY);
+	__uint(key_size, sizeof(__u32));
+	__uint(value_size, sizeof(struct bpf_perf_event_value));
+	__uint(max_entries, MAX_NUM_METRICS);
+} accum_readings SEC(".maps");
+
+struct {
+	__uint(type, BPF_MAP_TYPE_PERCPU_ARRAY);
+	__uint(key_size, sizeof(__u32));
+	__uint(value_size, sizeof(__u64));
+	__uint(max_entries, 1);
+} counts SEC(".maps");
+
+const volatile __u32 num_cpu = 1;
+const volatile __u32 num_metric = 1;
+
+/* The attach target is set at runtime to the program being profiled */
+SEC("fentry/XXX")
+int fentry_XXX(void *ctx)
+{
+	__u32 key = bpf_get_smp_processor_id();
+	__u32 i;
+
+	for (i = 0; i < num_metric && i < MAX_NUM_METRICS; i++) {
+		struct bpf_perf_event_value *reading;
+		__u32 idx = i;
+
+		reading = bpf_map_lookup_elem(&fentry_readings, &idx);
+		if (!reading)
+			return 0;
+
+		if (bpf_perf_event_read_value(&events, key, reading,
+					      sizeof(*reading)))
+			return 0;
+
+		key += num_cpu;
+	}
+
+	return 0;
+}
+
+static __always_inline void
+fexit_update_maps(__u32 id, struct bpf_perf_event_value *after)
+{
+	struct bpf_perf_event_value *before, *accum;
+
+	before = bpf_map_lookup_elem(&fentry_readings, &id);
+	/* Only account runs with a valid fentry reading */
+	if (!before || !before->counter)
+		return;
+
+	accum = bpf_map_lookup_elem(&accum_readings, &id);
+	if (!accum)
+		return;
+
+	accum->counter += after->counter - before->counter;
+	accum->enabled += after->enabled - before->enabled;
+	accum->running += after->running - before->running;
+}
+
+SEC("fexit/XXX")
+int fexit_XXX(void *ctx)
+{
+	struct bpf_perf_event_value reading;
+	__u32 cpu = bpf_get_smp_processor_id();
+	__u32 i, zero = 0;
+	__u64 *count;
+
+	count = bpf_map_lookup_elem(&counts, &zero);
+	if (count)
+		*count += 1;
+
+	for (i = 0; i < num_metric && i < MAX_NUM_METRICS; i++) {
+		if (bpf_perf_event_read_value(&events, cpu + i * num_cpu,
+					      &reading, sizeof(reading)))
+			return 0;
+
+		fexit_update_maps(i, &reading);
+	}
+
+	return 0;
+}
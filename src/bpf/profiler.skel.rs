@@ -0,0 +1,669 @@
+// SPDX-License-Identifier: (LGPL-2.1 OR BSD-2-Clause)
+//
+// THIS FILE IS AUTOGENERATED BY CARGO-LIBBPF-GEN!
+
+pub use self::imp::*;
+
+#[allow(dead_code)]
+#[allow(non_snake_case)]
+#[allow(non_camel_case_types)]
+#[allow(clippy::absolute_paths)]
+#[allow(clippy::upper_case_acronyms)]
+#[allow(clippy::zero_repeat_side_effects)]
+#[warn(single_use_lifetimes)]
+mod imp {
+    #[allow(unused_imports)]
+    use super::*;
+    use libbpf_rs::libbpf_sys;
+    use libbpf_rs::skel::OpenSkel;
+    use libbpf_rs::skel::Skel;
+    use libbpf_rs::skel::SkelBuilder;
+    use libbpf_rs::AsRawLibbpf as _;
+    use libbpf_rs::MapCore as _;
+    fn build_skel_config(
+    ) -> libbpf_rs::Result<libbpf_rs::__internal_skel::ObjectSkeletonConfig<'static>> {
+        let mut builder = libbpf_rs::__internal_skel::ObjectSkeletonConfigBuilder::new(DATA);
+        builder
+            .name("profiler_bpf")
+            .map("events", false)
+            .map("fentry_readings", false)
+            .map("accum_readings", false)
+            .map("counts", false)
+            .map("profiler.rodata", true)
+            .prog("fentry_XXX")
+            .prog("fexit_XXX");
+        builder.build()
+    }
+    pub struct OpenProfilerMaps<'obj> {
+        pub events: libbpf_rs::OpenMapMut<'obj>,
+        pub fentry_readings: libbpf_rs::OpenMapMut<'obj>,
+        pub accum_readings: libbpf_rs::OpenMapMut<'obj>,
+        pub counts: libbpf_rs::OpenMapMut<'obj>,
+        pub rodata: libbpf_rs::OpenMapMut<'obj>,
+        pub rodata_data: &'obj mut types::rodata,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> OpenProfilerMaps<'obj> {
+        #[allow(unused_variables)]
+        unsafe fn new(
+            config: &libbpf_rs::__internal_skel::ObjectSkeletonConfig<'_>,
+            object: &mut libbpf_rs::OpenObject,
+        ) -> libbpf_rs::Result<Self> {
+            let mut events = None;
+            let mut fentry_readings = None;
+            let mut accum_readings = None;
+            let mut counts = None;
+            let mut rodata = None;
+            let object = unsafe {
+                std::mem::transmute::<&mut libbpf_rs::OpenObject, &'obj mut libbpf_rs::OpenObject>(
+                    object,
+                )
+            };
+            #[allow(clippy::never_loop)]
+            for map in object.maps_mut() {
+                let name = map.name().to_str().ok_or_else(|| {
+                    libbpf_rs::Error::from(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "map has invalid name",
+                    ))
+                })?;
+                #[allow(clippy::match_single_binding)]
+                match name {
+                    "events" => events = Some(map),
+                    "fentry_readings" => fentry_readings = Some(map),
+                    "accum_readings" => accum_readings = Some(map),
+                    "counts" => counts = Some(map),
+                    "profiler.rodata" => rodata = Some(map),
+                    _ => panic!("encountered unexpected map: `{name}`"),
+                }
+            }
+
+            let slf = Self {
+                events: events.expect("map `events` not present"),
+                fentry_readings: fentry_readings.expect("map `fentry_readings` not present"),
+                accum_readings: accum_readings.expect("map `accum_readings` not present"),
+                counts: counts.expect("map `counts` not present"),
+                rodata: rodata.expect("map `rodata` not present"),
+                rodata_data: unsafe {
+                    config
+                        .map_mmap_ptr(4)
+                        .expect("BPF map `rodata` does not have mmap pointer")
+                        .cast::<types::rodata>()
+                        .as_mut()
+                        .expect("BPF map `rodata` mmap pointer is NULL")
+                },
+                _phantom: std::marker::PhantomData,
+            };
+            Ok(slf)
+        }
+    }
+    pub struct ProfilerMaps<'obj> {
+        pub events: libbpf_rs::MapMut<'obj>,
+        pub fentry_readings: libbpf_rs::MapMut<'obj>,
+        pub accum_readings: libbpf_rs::MapMut<'obj>,
+        pub counts: libbpf_rs::MapMut<'obj>,
+        pub rodata: libbpf_rs::MapMut<'obj>,
+        pub rodata_data: &'obj types::rodata,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> ProfilerMaps<'obj> {
+        #[allow(unused_variables)]
+        unsafe fn new(
+            config: &libbpf_rs::__internal_skel::ObjectSkeletonConfig<'_>,
+            object: &mut libbpf_rs::Object,
+        ) -> libbpf_rs::Result<Self> {
+            let mut events = None;
+            let mut fentry_readings = None;
+            let mut accum_readings = None;
+            let mut counts = None;
+            let mut rodata = None;
+            let object = unsafe {
+                std::mem::transmute::<&mut libbpf_rs::Object, &'obj mut libbpf_rs::Object>(object)
+            };
+            #[allow(clippy::never_loop)]
+            for map in object.maps_mut() {
+                let name = map.name().to_str().ok_or_else(|| {
+                    libbpf_rs::Error::from(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "map has invalid name",
+                    ))
+                })?;
+                #[allow(clippy::match_single_binding)]
+                match name {
+                    "events" => events = Some(map),
+                    "fentry_readings" => fentry_readings = Some(map),
+                    "accum_readings" => accum_readings = Some(map),
+                    "counts" => counts = Some(map),
+                    "profiler.rodata" => rodata = Some(map),
+                    _ => panic!("encountered unexpected map: `{name}`"),
+                }
+            }
+
+            let slf = Self {
+                events: events.expect("map `events` not present"),
+                fentry_readings: fentry_readings.expect("map `fentry_readings` not present"),
+                accum_readings: accum_readings.expect("map `accum_readings` not present"),
+                counts: counts.expect("map `counts` not present"),
+                rodata: rodata.expect("map `rodata` not present"),
+                rodata_data: unsafe {
+                    config
+                        .map_mmap_ptr(4)
+                        .expect("BPF map `rodata` does not have mmap pointer")
+                        .cast::<types::rodata>()
+                        .as_ref()
+                        .expect("BPF map `rodata` mmap pointer is NULL")
+                },
+                _phantom: std::marker::PhantomData,
+            };
+            Ok(slf)
+        }
+    }
+    pub struct OpenProfilerProgs<'obj> {
+        pub fentry_XXX: libbpf_rs::OpenProgramMut<'obj>,
+        pub fexit_XXX: libbpf_rs::OpenProgramMut<'obj>,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> OpenProfilerProgs<'obj> {
+        unsafe fn new(object: &mut libbpf_rs::OpenObject) -> libbpf_rs::Result<Self> {
+            let mut fentry_XXX = None;
+            let mut fexit_XXX = None;
+            let object = unsafe {
+                std::mem::transmute::<&mut libbpf_rs::OpenObject, &'obj mut libbpf_rs::OpenObject>(
+                    object,
+                )
+            };
+            for prog in object.progs_mut() {
+                let name = prog.name().to_str().ok_or_else(|| {
+                    libbpf_rs::Error::from(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "prog has invalid name",
+                    ))
+                })?;
+                match name {
+                    "fentry_XXX" => fentry_XXX = Some(prog),
+                    "fexit_XXX" => fexit_XXX = Some(prog),
+                    _ => panic!("encountered unexpected prog: `{name}`"),
+                }
+            }
+
+            let slf = Self {
+                fentry_XXX: fentry_XXX.expect("prog `fentry_XXX` not present"),
+                fexit_XXX: fexit_XXX.expect("prog `fexit_XXX` not present"),
+                _phantom: std::marker::PhantomData,
+            };
+            Ok(slf)
+        }
+    }
+    pub struct ProfilerProgs<'obj> {
+        pub fentry_XXX: libbpf_rs::ProgramMut<'obj>,
+        pub fexit_XXX: libbpf_rs::ProgramMut<'obj>,
+        _phantom: std::marker::PhantomData<&'obj ()>,
+    }
+
+    impl<'obj> ProfilerProgs<'obj> {
+        #[allow(unused_variables)]
+        fn new(open_progs: OpenProfilerProgs<'obj>) -> Self {
+            Self {
+                fentry_XXX: unsafe {
+                    libbpf_rs::ProgramMut::new_mut(
+                        open_progs.fentry_XXX.as_libbpf_object().as_mut(),
+                    )
+                },
+                fexit_XXX: unsafe {
+                    libbpf_rs::ProgramMut::new_mut(open_progs.fexit_XXX.as_libbpf_object().as_mut())
+                },
+                _phantom: std::marker::PhantomData,
+            }
+        }
+    }
+    struct OwnedRef<'obj, O> {
+        object: Option<&'obj mut std::mem::MaybeUninit<O>>,
+    }
+
+    impl<'obj, O> OwnedRef<'obj, O> {
+        /// # Safety
+        /// The object has to be initialized.
+        unsafe fn new(object: &'obj mut std::mem::MaybeUninit<O>) -> Self {
+            Self {
+                object: Some(object),
+            }
+        }
+
+        fn as_ref(&self) -> &O {
+            // SAFETY: As per the contract during construction, the
+            //         object has to be initialized.
+            unsafe { self.object.as_ref().unwrap().assume_init_ref() }
+        }
+
+        fn as_mut(&mut self) -> &mut O {
+            // SAFETY: As per the contract during construction, the
+            //         object has to be initialized.
+            unsafe { self.object.as_mut().unwrap().assume_init_mut() }
+        }
+
+        fn take(mut self) -> &'obj mut std::mem::MaybeUninit<O> {
+            self.object.take().unwrap()
+        }
+    }
+
+    impl<O> Drop for OwnedRef<'_, O> {
+        fn drop(&mut self) {
+            if let Some(object) = &mut self.object {
+                unsafe { object.assume_init_drop() }
+            }
+        }
+    }
+
+    #[derive(Default)]
+    pub struct ProfilerSkelBuilder {
+        pub obj_builder: libbpf_rs::ObjectBuilder,
+    }
+
+    impl<'obj> ProfilerSkelBuilder {
+        fn open_opts_impl(
+            self,
+            open_opts: *const libbpf_sys::bpf_object_open_opts,
+            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+        ) -> libbpf_rs::Result<OpenProfilerSkel<'obj>> {
+            let skel_config = build_skel_config()?;
+            let skel_ptr = skel_config.as_libbpf_object();
+
+            let ret =
+                unsafe { libbpf_sys::bpf_object__open_skeleton(skel_ptr.as_ptr(), open_opts) };
+            if ret != 0 {
+                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
+            }
+
+            // SAFETY: `skel_ptr` points to a valid object after the
+            //         open call.
+            let obj_ptr = unsafe { *skel_ptr.as_ref().obj };
+            // SANITY: `bpf_object__open_skeleton` should have
+            //         allocated the object.
+            let obj_ptr = std::ptr::NonNull::new(obj_ptr).unwrap();
+            // SAFETY: `obj_ptr` points to an opened object after
+            //         skeleton open.
+            let obj = unsafe { libbpf_rs::OpenObject::from_ptr(obj_ptr) };
+            let _obj = object.write(obj);
+            // SAFETY: We just wrote initialized data to `object`.
+            let mut obj_ref = unsafe { OwnedRef::new(object) };
+
+            #[allow(unused_mut)]
+            let mut skel = OpenProfilerSkel {
+                maps: unsafe { OpenProfilerMaps::new(&skel_config, obj_ref.as_mut())? },
+                progs: unsafe { OpenProfilerProgs::new(obj_ref.as_mut())? },
+                obj: obj_ref,
+                // SAFETY: Our `struct_ops` type contains only pointers,
+                //         which are allowed to be NULL.
+                // TODO: Generate and use a `Default` representation
+                //       instead, to cut down on unsafe code.
+                struct_ops: unsafe { std::mem::zeroed() },
+                skel_config,
+            };
+
+            Ok(skel)
+        }
+    }
+
+    impl<'obj> SkelBuilder<'obj> for ProfilerSkelBuilder {
+        type Output = OpenProfilerSkel<'obj>;
+        fn open(
+            self,
+            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+        ) -> libbpf_rs::Result<OpenProfilerSkel<'obj>> {
+            self.open_opts_impl(std::ptr::null(), object)
+        }
+
+        fn open_opts(
+            self,
+            open_opts: libbpf_sys::bpf_object_open_opts,
+            object: &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+        ) -> libbpf_rs::Result<OpenProfilerSkel<'obj>> {
+            self.open_opts_impl(&open_opts, object)
+        }
+
+        fn object_builder(&self) -> &libbpf_rs::ObjectBuilder {
+            &self.obj_builder
+        }
+        fn object_builder_mut(&mut self) -> &mut libbpf_rs::ObjectBuilder {
+            &mut self.obj_builder
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    #[repr(C)]
+    pub struct StructOps {}
+
+    impl StructOps {}
+    pub mod types {
+        #[allow(unused_imports)]
+        use super::*;
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct __anon_1 {
+            pub r#type: *mut [i32; 4],
+            pub key_size: *mut [i32; 4],
+            pub value_size: *mut [i32; 4],
+        }
+        impl Default for __anon_1 {
+            fn default() -> Self {
+                Self {
+                    r#type: std::ptr::null_mut(),
+                    key_size: std::ptr::null_mut(),
+                    value_size: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct __anon_2 {
+            pub r#type: *mut [i32; 6],
+            pub key_size: *mut [i32; 4],
+            pub value_size: *mut [i32; 24],
+            pub max_entries: *mut [i32; 4],
+        }
+        impl Default for __anon_2 {
+            fn default() -> Self {
+                Self {
+                    r#type: std::ptr::null_mut(),
+                    key_size: std::ptr::null_mut(),
+                    value_size: std::ptr::null_mut(),
+                    max_entries: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct __anon_3 {
+            pub r#type: *mut [i32; 6],
+            pub key_size: *mut [i32; 4],
+            pub value_size: *mut [i32; 8],
+            pub max_entries: *mut [i32; 1],
+        }
+        impl Default for __anon_3 {
+            fn default() -> Self {
+                Self {
+                    r#type: std::ptr::null_mut(),
+                    key_size: std::ptr::null_mut(),
+                    value_size: std::ptr::null_mut(),
+                    max_entries: std::ptr::null_mut(),
+                }
+            }
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct license {
+            pub _license: [i8; 4],
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct maps {
+            pub events: __anon_1,
+            pub fentry_readings: __anon_2,
+            pub accum_readings: __anon_2,
+            pub counts: __anon_3,
+        }
+        #[derive(Debug, Copy, Clone)]
+        #[repr(C)]
+        pub struct rodata {
+            pub num_cpu: u32,
+            pub num_metric: u32,
+        }
+    }
+    pub struct OpenProfilerSkel<'obj> {
+        obj: OwnedRef<'obj, libbpf_rs::OpenObject>,
+        pub maps: OpenProfilerMaps<'obj>,
+        pub progs: OpenProfilerProgs<'obj>,
+        pub struct_ops: StructOps,
+        skel_config: libbpf_rs::__internal_skel::ObjectSkeletonConfig<'obj>,
+    }
+
+    impl<'obj> OpenSkel<'obj> for OpenProfilerSkel<'obj> {
+        type Output = ProfilerSkel<'obj>;
+        fn load(self) -> libbpf_rs::Result<ProfilerSkel<'obj>> {
+            let skel_ptr = self.skel_config.as_libbpf_object().as_ptr();
+
+            let ret = unsafe { libbpf_sys::bpf_object__load_skeleton(skel_ptr) };
+            if ret != 0 {
+                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
+            }
+
+            let obj_ref = self.obj.take();
+            let open_obj = std::mem::replace(obj_ref, std::mem::MaybeUninit::uninit());
+            // SAFETY: `open_obj` is guaranteed to be properly
+            //         initialized as it came from an `OwnedRef`.
+            let obj_ptr = unsafe { open_obj.assume_init().take_ptr() };
+            // SAFETY: `obj_ptr` points to a loaded object after
+            //         skeleton load.
+            let obj = unsafe { libbpf_rs::Object::from_ptr(obj_ptr) };
+            // SAFETY: `OpenObject` and `Object` are guaranteed to
+            //         have the same memory layout.
+            let obj_ref = unsafe {
+                std::mem::transmute::<
+                    &'obj mut std::mem::MaybeUninit<libbpf_rs::OpenObject>,
+                    &'obj mut std::mem::MaybeUninit<libbpf_rs::Object>,
+                >(obj_ref)
+            };
+            let _obj = obj_ref.write(obj);
+            // SAFETY: We just wrote initialized data to `obj_ref`.
+            let mut obj_ref = unsafe { OwnedRef::new(obj_ref) };
+
+            Ok(ProfilerSkel {
+                maps: unsafe { ProfilerMaps::new(&self.skel_config, obj_ref.as_mut())? },
+                progs: ProfilerProgs::new(self.progs),
+                obj: obj_ref,
+                struct_ops: self.struct_ops,
+                skel_config: self.skel_config,
+                links: ProfilerLinks::default(),
+            })
+        }
+
+        fn open_object(&self) -> &libbpf_rs::OpenObject {
+            self.obj.as_ref()
+        }
+
+        fn open_object_mut(&mut self) -> &mut libbpf_rs::OpenObject {
+            self.obj.as_mut()
+        }
+    }
+    #[derive(Default)]
+    pub struct ProfilerLinks {
+        pub fentry_XXX: Option<libbpf_rs::Link>,
+        pub fexit_XXX: Option<libbpf_rs::Link>,
+    }
+    pub struct ProfilerSkel<'obj> {
+        obj: OwnedRef<'obj, libbpf_rs::Object>,
+        pub maps: ProfilerMaps<'obj>,
+        pub progs: ProfilerProgs<'obj>,
+        struct_ops: StructOps,
+        skel_config: libbpf_rs::__internal_skel::ObjectSkeletonConfig<'obj>,
+        pub links: ProfilerLinks,
+    }
+
+    unsafe impl Send for ProfilerSkel<'_> {}
+    unsafe impl Sync for ProfilerSkel<'_> {}
+
+    impl<'obj> Skel<'obj> for ProfilerSkel<'obj> {
+        fn object(&self) -> &libbpf_rs::Object {
+            self.obj.as_ref()
+        }
+
+        fn object_mut(&mut self) -> &mut libbpf_rs::Object {
+            self.obj.as_mut()
+        }
+        fn attach(&mut self) -> libbpf_rs::Result<()> {
+            let skel_ptr = self.skel_config.as_libbpf_object().as_ptr();
+            let ret = unsafe { libbpf_sys::bpf_object__attach_skeleton(skel_ptr) };
+            if ret != 0 {
+                return Err(libbpf_rs::Error::from_raw_os_error(-ret));
+            }
+
+            self.links = ProfilerLinks {
+                fentry_XXX: core::ptr::NonNull::new(self.skel_config.prog_link_ptr(0)?)
+                    .map(|ptr| unsafe { libbpf_rs::Link::from_ptr(ptr) }),
+                fexit_XXX: core::ptr::NonNull::new(self.skel_config.prog_link_ptr(1)?)
+                    .map(|ptr| unsafe { libbpf_rs::Link::from_ptr(ptr) }),
+            };
+
+            Ok(())
+        }
+    }
+    impl ProfilerSkel<'_> {
+        pub fn struct_ops_raw(&self) -> *const StructOps {
+            &self.struct_ops
+        }
+
+        pub fn struct_ops(&self) -> &StructOps {
+            &self.struct_ops
+        }
+    }
+    const DATA: &[u8] = &[
+        127, 69, 76, 70, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 247, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
+        0, 64, 0, 12, 0, 1, 0, 0, 46, 115, 116, 114, 116, 97, 98, 0, 46, 115, 121, 109, 116, 97,
+        98, 0, 102, 101, 110, 116, 114, 121, 47, 88, 88, 88, 0, 102, 101, 120, 105, 116, 47, 88,
+        88, 88, 0, 108, 105, 99, 101, 110, 115, 101, 0, 46, 109, 97, 112, 115, 0, 46, 114, 111,
+        100, 97, 116, 97, 0, 112, 114, 111, 102, 105, 108, 101, 114, 46, 108, 108, 0, 76, 66, 66,
+        48, 95, 53, 0, 76, 66, 66, 48, 95, 49, 0, 76, 66, 66, 49, 95, 50, 0, 76, 66, 66, 49, 95,
+        49, 48, 0, 76, 66, 66, 49, 95, 51, 0, 76, 66, 66, 49, 95, 57, 0, 102, 101, 110, 116, 114,
+        121, 95, 88, 88, 88, 0, 110, 117, 109, 95, 109, 101, 116, 114, 105, 99, 0, 102, 101, 110,
+        116, 114, 121, 95, 114, 101, 97, 100, 105, 110, 103, 115, 0, 101, 118, 101, 110, 116, 115,
+        0, 110, 117, 109, 95, 99, 112, 117, 0, 102, 101, 120, 105, 116, 95, 88, 88, 88, 0, 99, 111,
+        117, 110, 116, 115, 0, 97, 99, 99, 117, 109, 95, 114, 101, 97, 100, 105, 110, 103, 115, 0,
+        95, 108, 105, 99, 101, 110, 115, 101, 0, 46, 114, 101, 108, 102, 101, 110, 116, 114, 121,
+        47, 88, 88, 88, 0, 46, 114, 101, 108, 102, 101, 120, 105, 116, 47, 88, 88, 88, 0, 46, 66,
+        84, 70, 0, 46, 66, 84, 70, 46, 101, 120, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 4, 0, 241, 255, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 72, 0, 0, 0, 0, 0, 3, 0, 232, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 79, 0,
+        0, 0, 0, 0, 3, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 4, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 4, 0, 104, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 4, 0, 40, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 4, 0, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        108, 0, 0, 0, 0, 0, 4, 0, 16, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0,
+        18, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 17, 0, 7, 0,
+        4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 17, 0, 6, 0, 24, 0, 0, 0, 0,
+        0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 17, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0,
+        0, 0, 0, 0, 0, 0, 160, 0, 0, 0, 17, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0,
+        0, 168, 0, 0, 0, 18, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 2, 0, 0, 0, 0, 0, 0, 178, 0, 0,
+        0, 17, 0, 6, 0, 88, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 17, 0, 6,
+        0, 56, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 200, 0, 0, 0, 17, 0, 5, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 180, 7, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 8, 0, 0, 0,
+        188, 6, 0, 0, 0, 0, 0, 0, 38, 7, 25, 0, 3, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 97, 17, 0, 0, 0, 0, 0, 0, 62, 23, 21, 0, 0, 0, 0, 0, 99, 122, 252, 255, 0, 0, 0,
+        0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 252, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 14, 0, 0, 0, 0, 0, 188, 98, 0, 0, 0,
+        0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 180, 4,
+        0, 0, 24, 0, 0, 0, 133, 0, 0, 0, 55, 0, 0, 0, 85, 0, 7, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 97, 17, 0, 0, 0, 0, 0, 0, 12, 22, 0, 0, 0, 0, 0, 0, 4, 7, 0,
+        0, 1, 0, 0, 0, 38, 7, 1, 0, 3, 0, 0, 0, 5, 0, 231, 255, 0, 0, 0, 0, 180, 0, 0, 0, 0, 0, 0,
+        0, 149, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 8, 0, 0, 0, 188, 6, 0, 0, 0, 0, 0, 0, 180, 8, 0,
+        0, 0, 0, 0, 0, 99, 138, 228, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 228,
+        255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0,
+        21, 0, 3, 0, 0, 0, 0, 0, 121, 1, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0, 0, 123, 16, 0, 0,
+        0, 0, 0, 0, 38, 8, 55, 0, 3, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 97,
+        17, 0, 0, 0, 0, 0, 0, 62, 24, 51, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 97, 17, 0, 0, 0, 0, 0, 0, 188, 131, 0, 0, 0, 0, 0, 0, 44, 19, 0, 0, 0, 0, 0, 0, 188,
+        98, 0, 0, 0, 0, 0, 0, 12, 50, 0, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0,
+        232, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 180, 4, 0, 0, 24, 0,
+        0, 0, 133, 0, 0, 0, 55, 0, 0, 0, 85, 0, 37, 0, 0, 0, 0, 0, 99, 138, 224, 255, 0, 0, 0, 0,
+        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 224, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 191, 7, 0, 0, 0, 0, 0, 0, 21, 7, 26, 0, 0, 0,
+        0, 0, 121, 113, 0, 0, 0, 0, 0, 0, 21, 1, 24, 0, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7,
+        2, 0, 0, 224, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0,
+        1, 0, 0, 0, 21, 0, 18, 0, 0, 0, 0, 0, 121, 113, 8, 0, 0, 0, 0, 0, 121, 162, 240, 255, 0, 0,
+        0, 0, 31, 18, 0, 0, 0, 0, 0, 0, 121, 113, 16, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0,
+        0, 31, 19, 0, 0, 0, 0, 0, 0, 121, 1, 16, 0, 0, 0, 0, 0, 15, 49, 0, 0, 0, 0, 0, 0, 121, 115,
+        0, 0, 0, 0, 0, 0, 121, 164, 232, 255, 0, 0, 0, 0, 123, 16, 16, 0, 0, 0, 0, 0, 121, 1, 8, 0,
+        0, 0, 0, 0, 15, 33, 0, 0, 0, 0, 0, 0, 123, 16, 8, 0, 0, 0, 0, 0, 31, 52, 0, 0, 0, 0, 0, 0,
+        121, 1, 0, 0, 0, 0, 0, 0, 15, 65, 0, 0, 0, 0, 0, 0, 123, 16, 0, 0, 0, 0, 0, 0, 4, 8, 0, 0,
+        1, 0, 0, 0, 38, 8, 1, 0, 3, 0, 0, 0, 5, 0, 201, 255, 0, 0, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0,
+        149, 0, 0, 0, 0, 0, 0, 0, 71, 80, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 32, 0, 0, 0, 0,
+        0, 0, 0, 1, 0, 0, 0, 11, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 12, 0, 0, 0, 128, 0,
+        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 13, 0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 14, 0, 0,
+        0, 48, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 16, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+        11, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 14, 0, 0, 0, 216, 0, 0, 0, 0, 0, 0, 0,
+        1, 0, 0, 0, 13, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 12, 0, 0, 0, 96, 1, 0, 0, 0,
+        0, 0, 0, 1, 0, 0, 0, 17, 0, 0, 0, 159, 235, 1, 0, 24, 0, 0, 0, 0, 0, 0, 0, 248, 2, 0, 0,
+        248, 2, 0, 0, 85, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 4,
+        0, 0, 0, 32, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 4, 24, 0, 0, 0,
+        25, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 1, 0, 0, 0, 64, 0, 0, 0, 39, 0, 0, 0, 1,
+        0, 0, 0, 128, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 14, 5, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 2, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 6, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 2, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0,
+        0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 25, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0,
+        0, 30, 0, 0, 0, 1, 0, 0, 0, 64, 0, 0, 0, 39, 0, 0, 0, 9, 0, 0, 0, 128, 0, 0, 0, 57, 0, 0,
+        0, 1, 0, 0, 0, 192, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 14, 11, 0, 0, 0, 1, 0, 0, 0, 85, 0, 0,
+        0, 0, 0, 0, 14, 11, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 15, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 17, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+        4, 0, 0, 4, 32, 0, 0, 0, 25, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 1, 0, 0, 0, 64,
+        0, 0, 0, 39, 0, 0, 0, 14, 0, 0, 0, 128, 0, 0, 0, 57, 0, 0, 0, 16, 0, 0, 0, 192, 0, 0, 0,
+        100, 0, 0, 0, 0, 0, 0, 14, 18, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+        0, 0, 0, 1, 0, 0, 13, 2, 0, 0, 0, 107, 0, 0, 0, 20, 0, 0, 0, 111, 0, 0, 0, 1, 0, 0, 12, 21,
+        0, 0, 0, 122, 0, 0, 0, 1, 0, 0, 12, 21, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 8,
+        0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 24, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 137, 0,
+        0, 0, 0, 0, 0, 14, 25, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 28, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 9, 29, 0, 0, 0, 146, 0, 0, 0, 0, 0, 0, 8, 30, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0,
+        1, 4, 0, 0, 0, 32, 0, 0, 0, 165, 0, 0, 0, 0, 0, 0, 14, 27, 0, 0, 0, 1, 0, 0, 0, 173, 0, 0,
+        0, 0, 0, 0, 14, 27, 0, 0, 0, 1, 0, 0, 0, 42, 1, 0, 0, 1, 0, 0, 15, 4, 0, 0, 0, 26, 0, 0, 0,
+        0, 0, 0, 0, 4, 0, 0, 0, 50, 1, 0, 0, 4, 0, 0, 15, 120, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 24,
+        0, 0, 0, 12, 0, 0, 0, 24, 0, 0, 0, 32, 0, 0, 0, 13, 0, 0, 0, 56, 0, 0, 0, 32, 0, 0, 0, 19,
+        0, 0, 0, 88, 0, 0, 0, 32, 0, 0, 0, 56, 1, 0, 0, 2, 0, 0, 15, 8, 0, 0, 0, 31, 0, 0, 0, 0, 0,
+        0, 0, 4, 0, 0, 0, 32, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 105, 110, 116, 0, 95, 95, 65, 82,
+        82, 65, 89, 95, 83, 73, 90, 69, 95, 84, 89, 80, 69, 95, 95, 0, 116, 121, 112, 101, 0, 107,
+        101, 121, 95, 115, 105, 122, 101, 0, 118, 97, 108, 117, 101, 95, 115, 105, 122, 101, 0,
+        101, 118, 101, 110, 116, 115, 0, 109, 97, 120, 95, 101, 110, 116, 114, 105, 101, 115, 0,
+        102, 101, 110, 116, 114, 121, 95, 114, 101, 97, 100, 105, 110, 103, 115, 0, 97, 99, 99,
+        117, 109, 95, 114, 101, 97, 100, 105, 110, 103, 115, 0, 99, 111, 117, 110, 116, 115, 0, 99,
+        116, 120, 0, 102, 101, 110, 116, 114, 121, 95, 88, 88, 88, 0, 102, 101, 120, 105, 116, 95,
+        88, 88, 88, 0, 99, 104, 97, 114, 0, 95, 108, 105, 99, 101, 110, 115, 101, 0, 95, 95, 117,
+        51, 50, 0, 117, 110, 115, 105, 103, 110, 101, 100, 32, 105, 110, 116, 0, 110, 117, 109, 95,
+        99, 112, 117, 0, 110, 117, 109, 95, 109, 101, 116, 114, 105, 99, 0, 47, 114, 111, 111, 116,
+        47, 99, 114, 97, 116, 101, 47, 115, 114, 99, 47, 98, 112, 102, 47, 112, 114, 111, 102, 105,
+        108, 101, 114, 46, 98, 112, 102, 46, 99, 0, 105, 110, 116, 32, 102, 101, 110, 116, 114,
+        121, 95, 88, 88, 88, 40, 118, 111, 105, 100, 32, 42, 99, 116, 120, 41, 0, 9, 97, 99, 99,
+        117, 109, 45, 62, 114, 117, 110, 110, 105, 110, 103, 32, 43, 61, 32, 97, 102, 116, 101,
+        114, 45, 62, 114, 117, 110, 110, 105, 110, 103, 32, 45, 32, 98, 101, 102, 111, 114, 101,
+        45, 62, 114, 117, 110, 110, 105, 110, 103, 59, 0, 108, 105, 99, 101, 110, 115, 101, 0, 46,
+        109, 97, 112, 115, 0, 46, 114, 111, 100, 97, 116, 97, 0, 102, 101, 110, 116, 114, 121, 47,
+        88, 88, 88, 0, 102, 101, 120, 105, 116, 47, 88, 88, 88, 0, 0, 0, 0, 159, 235, 1, 0, 32, 0,
+        0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 36, 0, 0, 0, 52, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0,
+        0, 64, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 75, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+        23, 0, 0, 0, 16, 0, 0, 0, 64, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 219, 0, 0, 0,
+        0, 172, 0, 0, 75, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 245, 0, 0, 0, 0, 72, 1, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0,
+        0, 0, 0, 0, 0, 0, 252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 64, 1, 0, 0, 0, 0, 0, 0, 200, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0,
+        0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 8, 3, 0, 0, 0, 0, 0, 0, 248, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 56, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 1, 0, 0, 0, 3,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 6, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 1,
+        0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 6, 0, 0, 0, 0, 0, 0, 120, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        52, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 6, 0, 0, 0,
+        0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 209, 0, 0, 0, 9, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        192, 6, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 8, 0, 0, 0, 0,
+        0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 224, 0, 0, 0, 9, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0,
+        8, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 238, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 7, 0, 0, 0, 0, 0, 0, 101, 4, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 0, 0, 0, 1, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 11, 0, 0, 0, 0, 0, 0, 120, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+}
@@ -0,0 +1,532 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    map_ops::MapOps,
+    ringbuf::{RingBufStats, RingBufTracker},
+};
+use bpftop_core::{
+    bpf_program::Process,
+    helpers::{fdinfo_field, format_bytes, map_type_name},
+};
+use libbpf_rs::{MapCore, MapHandle, MapType};
+use std::{
+    collections::HashMap,
+    sync::{
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+// How often hash map entries are counted to estimate fill levels
+const FILL_LEVEL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Debug)]
+pub struct BpfMap {
+    pub id: u32,
+    pub name: String,
+    pub map_type: String,
+    pub key_size: u32,
+    pub value_size: u32,
+    pub max_entries: u32,
+    pub memlock: u64,
+    // Type specific: the user space address of arenas, the number of hash functions of
+    // bloom filters
+    pub map_extra: u64,
+    // Estimated number of entries, only counted for hash and LRU maps
+    pub entries: Option<u64>,
+    // Operations observed since the previous sample, only set while tracking is enabled
+    pub ops: Option<MapOps>,
+    // Throughput of ring buffer maps
+    pub ringbuf: Option<RingBufStats>,
+    // bpffs paths where this map is pinned
+    pub pinned_paths: Vec<String>,
+    // IDs of the BPF programs that reference this map
+    pub prog_ids: Vec<u32>,
+    // Processes holding an fd of this map, once per fd
+    pub owners: Vec<Process>,
+}
+
+impl PartialEq for BpfMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FillLevel {
+    Normal,
+    Warning,
+    Critical,
+}
+
+// Fill percentages at which a map is flagged as approaching exhaustion
+const FILL_WARNING_PERCENT: f64 = 75.0;
+const FILL_CRITICAL_PERCENT: f64 = 90.0;
+
+// Bloom filters use this many hash functions unless map_extra sets another number
+const DEFAULT_BLOOM_HASHES: u64 = 5;
+
+impl BpfMap {
+    // For ring buffers this is the share of the buffer not yet consumed
+    pub fn fill_percent(&self) -> Option<f64> {
+        if let Some(ringbuf) = &self.ringbuf {
+            return Some(ringbuf.pending_percent());
+        }
+        let entries = self.entries?;
+        if self.max_entries == 0 {
+            return None;
+        }
+        Some(entries as f64 / self.max_entries as f64 * 100.0)
+    }
+
+    // Keys inserted, deleted or given a new value per second, see MapOpsWorker
+    pub fn changed_keys_per_second(&self) -> Option<u64> {
+        self.ops.map(|ops| ops.changed_keys_per_second())
+    }
+
+    pub fn fill_level(&self) -> FillLevel {
+        match self.fill_percent() {
+            Some(percent) if percent >= FILL_CRITICAL_PERCENT => FillLevel::Critical,
+            Some(percent) if percent >= FILL_WARNING_PERCENT => FillLevel::Warning,
+            _ => FillLevel::Normal,
+        }
+    }
+
+    // What local storage maps keep a value for, as they are keyed by the fd of the object
+    // and have no max_entries
+    pub fn storage_owner(&self) -> Option<&'static str> {
+        match self.map_type.as_str() {
+            "TaskStorage" => Some("task"),
+            "CgrpStorage" => Some("cgroup"),
+            "InodeStorage" => Some("inode"),
+            "SkStorage" => Some("socket"),
+            _ => None,
+        }
+    }
+
+    // Max entries in the unit of the map type
+    pub fn capacity(&self) -> String {
+        if let Some(owner) = self.storage_owner() {
+            return format!("1 per {}", owner);
+        }
+        match self.map_type.as_str() {
+            "Arena" => format!("{} pages", self.max_entries),
+            _ => self.max_entries.to_string(),
+        }
+    }
+
+    // Attributes specific to the map type, for map types the inspector cannot list
+    pub fn attributes(&self) -> Vec<(&'static str, String)> {
+        if let Some(owner) = self.storage_owner() {
+            return vec![
+                ("Storage", format!("One value per {}", owner)),
+                ("Value size", format_bytes(self.value_size as u64)),
+            ];
+        }
+        match self.map_type.as_str() {
+            "Arena" => vec![
+                ("Pages", self.max_entries.to_string()),
+                (
+                    "Size",
+                    format_bytes(self.max_entries as u64 * procfs::page_size()),
+                ),
+                (
+                    "User address",
+                    match self.map_extra {
+                        0 => "Chosen at mmap".to_string(),
+                        addr => format!("{:#x}", addr),
+                    },
+                ),
+            ],
+            "BloomFilter" => vec![
+                ("Expected entries", self.max_entries.to_string()),
+                ("Value size", format_bytes(self.value_size as u64)),
+                (
+                    "Hash functions",
+                    match self.map_extra & 0xf {
+                        0 => DEFAULT_BLOOM_HASHES,
+                        hashes => hashes,
+                    }
+                    .to_string(),
+                ),
+            ],
+            _ => vec![],
+        }
+    }
+
+    pub fn pinned(&self) -> String {
+        self.pinned_paths.join(", ")
+    }
+
+    pub fn programs(&self) -> String {
+        self.prog_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    // Each process holding the map once, however many fds it holds
+    pub fn owners(&self) -> String {
+        let mut owners: Vec<String> = vec![];
+        for process in &self.owners {
+            let owner = format!("{} ({})", process.comm, process.pid);
+            if !owners.contains(&owner) {
+                owners.push(owner);
+            }
+        }
+        owners.join(", ")
+    }
+}
+
+// Number of maps and memory they use per map type, largest first, to tell where the BPF
+// memory of the host goes
+pub fn type_breakdown(maps: &[BpfMap]) -> Vec<(&str, usize, u64)> {
+    let mut types: HashMap<&str, (usize, u64)> = HashMap::new();
+    for map in maps {
+        let entry = types.entry(&map.map_type).or_default();
+        entry.0 += 1;
+        entry.1 += map.memlock;
+    }
+    let mut types: Vec<_> = types
+        .into_iter()
+        .map(|(map_type, (count, bytes))| (map_type, count, bytes))
+        .collect();
+    types.sort_unstable_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(b.0)));
+    types
+}
+
+fn supports_fill_level(map_type: MapType) -> bool {
+    matches!(
+        map_type,
+        MapType::Hash | MapType::LruHash | MapType::PercpuHash | MapType::LruPercpuHash
+    )
+}
+
+// Counts the entries of a map by walking its keys, bounded by max_entries in case the
+// map is being modified while it is iterated
+fn count_entries(handle: &MapHandle, max_entries: u32) -> u64 {
+    handle.keys().take(max_entries as usize).count() as u64
+}
+
+// Entry counts of the hash maps on the host, by map ID
+fn count_all_entries(stop: &mpsc::Receiver<()>) -> HashMap<u32, u64> {
+    let mut counts = HashMap::new();
+    let mut id = 0;
+    while unsafe { libbpf_sys::bpf_map_get_next_id(id, &mut id) } == 0 {
+        if stop.try_recv() != Err(mpsc::TryRecvError::Empty) {
+            break;
+        }
+        let Ok(handle) = MapHandle::from_map_id(id) else {
+            continue;
+        };
+        let Ok(info) = handle.info() else {
+            continue;
+        };
+        if supports_fill_level(MapType::from(info.info.type_)) {
+            counts.insert(id, count_entries(&handle, info.info.max_entries));
+        }
+    }
+    counts
+}
+
+// Counts the entries of hash maps every FILL_LEVEL_INTERVAL on its own thread, as walking
+// the keys of large maps one syscall at a time would stretch the collector's sample period.
+// The thread stops once the counter is dropped.
+pub struct FillCounter {
+    counts: Arc<Mutex<HashMap<u32, u64>>>,
+    _stop: Sender<()>,
+}
+
+impl FillCounter {
+    pub fn start() -> FillCounter {
+        let counts = Arc::new(Mutex::new(HashMap::new()));
+        let (stop, stopped) = mpsc::channel();
+        let shared = Arc::clone(&counts);
+        thread::spawn(move || loop {
+            let new_counts = count_all_entries(&stopped);
+            *shared.lock().unwrap() = new_counts;
+            if stopped.recv_timeout(FILL_LEVEL_INTERVAL) != Err(RecvTimeoutError::Timeout) {
+                return;
+            }
+        });
+        FillCounter {
+            counts,
+            _stop: stop,
+        }
+    }
+
+    // The latest counts, empty until the first round of counting finished
+    pub fn counts(&self) -> HashMap<u32, u64> {
+        self.counts.lock().unwrap().clone()
+    }
+}
+
+// Iterates over all BPF maps loaded on the host, with the entry counts of a FillCounter and
+// the changed keys of a MapOpsWorker, both empty when not collected.
+pub fn get_maps(
+    prog_ids: &HashMap<u32, Vec<u32>>,
+    pinned_paths: &HashMap<u32, Vec<String>>,
+    owners: &HashMap<u32, Vec<Process>>,
+    entry_counts: &HashMap<u32, u64>,
+    map_ops: &HashMap<u32, MapOps>,
+    ringbuf_tracker: &mut RingBufTracker,
+) -> Vec<BpfMap> {
+    let mut maps = vec![];
+    let mut id = 0;
+
+    loop {
+        if unsafe { libbpf_sys::bpf_map_get_next_id(id, &mut id) } != 0 {
+            break;
+        }
+
+        // The map may have been unloaded since its ID was returned
+        let Ok(handle) = MapHandle::from_map_id(id) else {
+            continue;
+        };
+        let Ok(info) = handle.info() else {
+            continue;
+        };
+
+        let map_type = MapType::from(info.info.type_);
+        let entries = entry_counts.get(&id).copied();
+        let ops = map_ops.get(&id).copied();
+        let map_prog_ids = prog_ids.get(&id).cloned().unwrap_or_default();
+        let ringbuf = match map_type {
+            MapType::RingBuf if !map_prog_ids.is_empty() => {
+                ringbuf_tracker.sample(id, info.info.max_entries)
+            }
+            _ => None,
+        };
+
+        maps.push(BpfMap {
+            id,
+            name: info.name().unwrap_or_default().to_string(),
+            map_type: map_type_name(info.info.type_),
+            key_size: info.info.key_size,
+            value_size: info.info.value_size,
+            max_entries: info.info.max_entries,
+            memlock: fdinfo_field(&handle, "memlock").unwrap_or_default(),
+            map_extra: info.info.map_extra,
+            entries,
+            ops,
+            ringbuf,
+            pinned_paths: pinned_paths.get(&id).cloned().unwrap_or_default(),
+            prog_ids: map_prog_ids,
+            owners: owners.get(&id).cloned().unwrap_or_default(),
+        });
+    }
+
+    let used: Vec<u32> = maps
+        .iter()
+        .filter(|map| !map.prog_ids.is_empty())
+        .map(|map| map.id)
+        .collect();
+    ringbuf_tracker.retain(&used);
+
+    maps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_programs() {
+        let map = BpfMap {
+            id: 1,
+            name: "test".to_string(),
+            map_type: "Hash".to_string(),
+            key_size: 4,
+            value_size: 8,
+            max_entries: 1024,
+            memlock: 4096,
+            map_extra: 0,
+            entries: None,
+            ops: None,
+            ringbuf: None,
+            pinned_paths: vec![],
+            prog_ids: vec![3, 7],
+            owners: vec![],
+        };
+        assert_eq!(map.programs(), "3, 7");
+        assert_eq!(map.pinned(), "");
+    }
+
+    #[test]
+    fn test_owners() {
+        let owner = |pid: i32, comm: &str| Process {
+            pid,
+            comm: comm.to_string(),
+            container: None,
+            cgroup_id: 0,
+            bpf_fds: 2,
+            user: None,
+        };
+        let mut map = BpfMap {
+            id: 1,
+            name: "test".to_string(),
+            map_type: "Hash".to_string(),
+            key_size: 4,
+            value_size: 8,
+            max_entries: 1024,
+            memlock: 4096,
+            map_extra: 0,
+            entries: None,
+            ops: None,
+            ringbuf: None,
+            pinned_paths: vec![],
+            prog_ids: vec![],
+            owners: vec![],
+        };
+        assert_eq!(map.owners(), "");
+
+        map.owners = vec![
+            owner(812, "agent"),
+            owner(812, "agent"),
+            owner(4021, "bpftool"),
+        ];
+        assert_eq!(map.owners(), "agent (812), bpftool (4021)");
+    }
+
+    #[test]
+    fn test_fill_level() {
+        let mut map = BpfMap {
+            id: 1,
+            name: "test".to_string(),
+            map_type: "Hash".to_string(),
+            key_size: 4,
+            value_size: 8,
+            max_entries: 1000,
+            memlock: 4096,
+            map_extra: 0,
+            entries: None,
+            ops: None,
+            ringbuf: None,
+            pinned_paths: vec![],
+            prog_ids: vec![],
+            owners: vec![],
+        };
+        assert_eq!(map.fill_percent(), None);
+        assert_eq!(map.fill_level(), FillLevel::Normal);
+
+        map.entries = Some(500);
+        assert_eq!(map.fill_percent(), Some(50.0));
+        assert_eq!(map.fill_level(), FillLevel::Normal);
+
+        map.entries = Some(800);
+        assert_eq!(map.fill_level(), FillLevel::Warning);
+
+        map.entries = Some(1000);
+        assert_eq!(map.fill_level(), FillLevel::Critical);
+
+        map.max_entries = 0;
+        assert_eq!(map.fill_percent(), None);
+
+        // Ring buffers report the pending share of the buffer
+        map.ringbuf = Some(RingBufStats {
+            capacity: 4096,
+            pending_bytes: 4000,
+            records_per_sec: 12,
+            ..Default::default()
+        });
+        assert_eq!(map.fill_level(), FillLevel::Critical);
+        // Records are shown in the inspector, they are not changed keys
+        assert_eq!(map.changed_keys_per_second(), None);
+    }
+
+    #[test]
+    fn test_type_breakdown() {
+        let map = |map_type: &str, memlock: u64| BpfMap {
+            id: 1,
+            name: "test".to_string(),
+            map_type: map_type.to_string(),
+            key_size: 4,
+            value_size: 8,
+            max_entries: 1024,
+            memlock,
+            map_extra: 0,
+            entries: None,
+            ops: None,
+            ringbuf: None,
+            pinned_paths: vec![],
+            prog_ids: vec![],
+            owners: vec![],
+        };
+        let maps = [
+            map("Hash", 4096),
+            map("RingBuf", 266240),
+            map("Array", 4096),
+            map("Hash", 8192),
+        ];
+        assert_eq!(
+            type_breakdown(&maps),
+            vec![
+                ("RingBuf", 1, 266240),
+                ("Hash", 2, 12288),
+                ("Array", 1, 4096)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_type_attributes() {
+        let mut map = BpfMap {
+            id: 1,
+            name: "test".to_string(),
+            map_type: "BloomFilter".to_string(),
+            key_size: 0,
+            value_size: 8,
+            max_entries: 10000,
+            memlock: 4096,
+            map_extra: 0,
+            entries: None,
+            ops: None,
+            ringbuf: None,
+            pinned_paths: vec![],
+            prog_ids: vec![],
+            owners: vec![],
+        };
+        assert_eq!(map.capacity(), "10000");
+        assert_eq!(map.attributes()[2], ("Hash functions", "5".to_string()));
+        map.map_extra = 3;
+        assert_eq!(map.attributes()[2], ("Hash functions", "3".to_string()));
+
+        map.map_type = "TaskStorage".to_string();
+        map.max_entries = 0;
+        assert_eq!(map.capacity(), "1 per task");
+        assert_eq!(
+            map.attributes()[0],
+            ("Storage", "One value per task".to_string())
+        );
+
+        map.map_type = "Arena".to_string();
+        map.max_entries = 256;
+        map.map_extra = 0x1_0000_0000;
+        assert_eq!(map.capacity(), "256 pages");
+        assert_eq!(
+            map.attributes()[2],
+            ("User address", "0x100000000".to_string())
+        );
+
+        map.map_type = "Hash".to_string();
+        assert!(map.attributes().is_empty());
+    }
+}
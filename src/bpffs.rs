@@ -0,0 +1,457 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::bpf_map::BpfMap;
+use anyhow::{anyhow, bail, Context, Result};
+use bpftop_core::{bpf_attachment::BpfAttachment, bpf_program::BpfProgram, helpers::fdinfo_field};
+use libbpf_rs::{
+    query::{ProgInfoIter, ProgInfoQueryOptions},
+    MapCore, MapHandle,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::CString,
+    fs,
+    os::{
+        fd::{AsRawFd, FromRawFd, OwnedFd},
+        unix::ffi::OsStrExt,
+    },
+    path::{Path, PathBuf},
+};
+
+const PROC_MOUNTS: &str = "/proc/mounts";
+
+// Where bpffs is conventionally mounted, for pinning when no mount is found
+pub const DEFAULT_BPFFS: &str = "/sys/fs/bpf";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PinnedKind {
+    Program,
+    Map,
+    Link,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PinnedObject {
+    pub path: PathBuf,
+    pub kind: PinnedKind,
+    pub id: u32,
+}
+
+impl PinnedKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PinnedKind::Program => "program",
+            PinnedKind::Map => "map",
+            PinnedKind::Link => "link",
+        }
+    }
+}
+
+// A pinned object cross-referenced with the live programs, maps and links
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pin {
+    pub path: String,
+    pub kind: PinnedKind,
+    pub id: u32,
+    pub name: String,
+    pub object_type: String,
+    // What keeps the object in use besides the pin: the processes holding a program and
+    // the links or hooks it is attached to, the programs using a map, or the processes
+    // holding the program of a link
+    pub users: Vec<String>,
+}
+
+impl Pin {
+    // Nothing but the pin keeps the object alive, which is often a leftover of a loader
+    // that exited without cleaning up
+    pub fn is_orphaned(&self) -> bool {
+        self.users.is_empty()
+    }
+
+    pub fn users(&self) -> String {
+        self.users.join(", ")
+    }
+
+    // What has to be typed to remove the pin, e.g. "map 37"
+    pub fn confirmation(&self) -> String {
+        format!("{} {}", self.kind.name(), self.id)
+    }
+}
+
+// Processes holding the program, once each
+fn holders(prog: Option<&BpfProgram>) -> Vec<String> {
+    let Some(prog) = prog else {
+        return vec![];
+    };
+    let mut holders: Vec<String> = prog
+        .processes
+        .iter()
+        .map(|process| process.to_string())
+        .collect();
+    holders.dedup();
+    holders
+}
+
+// Cross-references pinned objects with the live ones. `attached` holds the IDs of the
+// programs attached without a link, e.g. through the legacy tc, XDP or cgroup APIs.
+pub fn resolve_pins(
+    objects: &[PinnedObject],
+    programs: &[BpfProgram],
+    maps: &[BpfMap],
+    links: &[(u32, BpfAttachment)],
+    attached: &HashSet<u32>,
+) -> Vec<Pin> {
+    let programs: HashMap<u32, &BpfProgram> = programs.iter().map(|prog| (prog.id, prog)).collect();
+    let maps: HashMap<u32, &BpfMap> = maps.iter().map(|map| (map.id, map)).collect();
+
+    objects
+        .iter()
+        .map(|object| {
+            let (name, object_type, users) = match object.kind {
+                PinnedKind::Program => {
+                    let prog = programs.get(&object.id).copied();
+                    let mut users = holders(prog);
+                    users.extend(
+                        links
+                            .iter()
+                            .filter(|(prog_id, _)| *prog_id == object.id)
+                            .map(|(_, link)| format!("link {}", link.link_id)),
+                    );
+                    if attached.contains(&object.id) {
+                        users.push("attached".to_string());
+                    }
+                    (
+                        prog.map(|prog| prog.name.clone()).unwrap_or_default(),
+                        prog.map(|prog| prog.bpf_type.clone()).unwrap_or_default(),
+                        users,
+                    )
+                }
+                PinnedKind::Map => {
+                    let map = maps.get(&object.id);
+                    (
+                        map.map(|map| map.name.clone()).unwrap_or_default(),
+                        map.map(|map| map.map_type.clone()).unwrap_or_default(),
+                        map.map(|map| {
+                            map.prog_ids
+                                .iter()
+                                .map(|prog_id| format!("program {}", prog_id))
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    )
+                }
+                PinnedKind::Link => {
+                    let link = links.iter().find(|(_, link)| link.link_id == object.id);
+                    (
+                        link.map(|(_, link)| link.target.clone())
+                            .unwrap_or_default(),
+                        link.map(|(_, link)| link.link_type.clone())
+                            .unwrap_or_default(),
+                        link.map(|(prog_id, _)| holders(programs.get(prog_id).copied()))
+                            .unwrap_or_default(),
+                    )
+                }
+            };
+            Pin {
+                path: object.path.display().to_string(),
+                kind: object.kind,
+                id: object.id,
+                name,
+                object_type,
+                users,
+            }
+        })
+        .collect()
+}
+
+// Returns the mount points of all bpffs filesystems on the host
+pub fn bpffs_mounts() -> Vec<PathBuf> {
+    fs::read_to_string(PROC_MOUNTS)
+        .map(|mounts| parse_bpffs_mounts(&mounts))
+        .unwrap_or_default()
+}
+
+fn parse_bpffs_mounts(mounts: &str) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let mount_point = fields.nth(1)?;
+            let fs_type = fields.next()?;
+            (fs_type == "bpf").then(|| PathBuf::from(mount_point))
+        })
+        .collect();
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+// Walks every bpffs mount and resolves the pinned programs, maps and links it contains
+pub fn pinned_objects() -> Vec<PinnedObject> {
+    let mut objects = vec![];
+    for mount in bpffs_mounts() {
+        walk(&mount, &mut objects);
+    }
+    objects
+}
+
+// Groups pinned paths by object id for the given kind
+pub fn pinned_paths(objects: &[PinnedObject], kind: PinnedKind) -> HashMap<u32, Vec<String>> {
+    let mut paths: HashMap<u32, Vec<String>> = HashMap::new();
+    for object in objects.iter().filter(|object| object.kind == kind) {
+        paths
+            .entry(object.id)
+            .or_default()
+            .push(object.path.display().to_string());
+    }
+    paths
+}
+
+fn walk(dir: &Path, objects: &mut Vec<PinnedObject>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        let path = entry.path();
+        if file_type.is_dir() {
+            walk(&path, objects);
+        } else if file_type.is_file() {
+            if let Some(object) = resolve(&path) {
+                objects.push(object);
+            }
+        }
+    }
+}
+
+fn resolve(path: &Path) -> Option<PinnedObject> {
+    let c_path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let fd = unsafe { libbpf_sys::bpf_obj_get(c_path.as_ptr()) };
+    if fd < 0 {
+        return None;
+    }
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    let (kind, id) = if let Some(id) = fdinfo_field(&fd, "prog_id") {
+        (PinnedKind::Program, id)
+    } else if let Some(id) = fdinfo_field(&fd, "map_id") {
+        (PinnedKind::Map, id)
+    } else if let Some(id) = fdinfo_field(&fd, "link_id") {
+        (PinnedKind::Link, id)
+    } else {
+        return None;
+    };
+
+    Some(PinnedObject {
+        path: path.to_path_buf(),
+        kind,
+        id: id as u32,
+    })
+}
+
+// Pins only make sense below a bpffs mount, anywhere else bpf_obj_pin fails with EPERM
+fn in_bpffs(path: &Path, mounts: &[PathBuf]) -> bool {
+    path.is_absolute()
+        && !path
+            .components()
+            .any(|c| c == std::path::Component::ParentDir)
+        && mounts
+            .iter()
+            .any(|mount| path != mount && path.starts_with(mount))
+}
+
+fn check_in_bpffs(path: &Path) -> Result<()> {
+    if !in_bpffs(path, &bpffs_mounts()) {
+        bail!("{} is not below a bpffs mount", path.display());
+    }
+    Ok(())
+}
+
+// Pins the program to `path` so it outlives its loader. With `maps`, the maps it uses are
+// pinned next to it in `<path>_maps`, named after the map. Returns the paths pinned.
+pub fn pin_program(prog_id: u32, path: &Path, maps: bool) -> Result<Vec<PathBuf>> {
+    check_in_bpffs(path)?;
+    let fd = unsafe { libbpf_sys::bpf_prog_get_fd_by_id(prog_id) };
+    if fd < 0 {
+        return Err(anyhow!("Program {} no longer exists", prog_id));
+    }
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+    let c_path = CString::new(path.as_os_str().as_bytes())?;
+    let ret = unsafe { libbpf_sys::bpf_obj_pin(fd.as_raw_fd(), c_path.as_ptr()) };
+    if ret < 0 {
+        return Err(std::io::Error::from_raw_os_error(-ret))
+            .with_context(|| format!("Failed to pin program {} to {}", prog_id, path.display()));
+    }
+    let mut pinned = vec![path.to_path_buf()];
+    if !maps {
+        return Ok(pinned);
+    }
+
+    let map_ids =
+        ProgInfoIter::with_query_opts(ProgInfoQueryOptions::default().include_map_ids(true))
+            .find(|prog| prog.id == prog_id)
+            .map(|prog| prog.map_ids)
+            .unwrap_or_default();
+    if map_ids.is_empty() {
+        return Ok(pinned);
+    }
+    let mut dir = path.as_os_str().to_owned();
+    dir.push("_maps");
+    let dir = PathBuf::from(dir);
+    fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    for map_id in map_ids {
+        let mut map = MapHandle::from_map_id(map_id)
+            .with_context(|| format!("Map {} no longer exists", map_id))?;
+        let name = map.name().to_string_lossy().into_owned();
+        let map_path = if name.is_empty() {
+            dir.join(format!("map_{}", map_id))
+        } else {
+            dir.join(name)
+        };
+        map.pin(&map_path)
+            .with_context(|| format!("Failed to pin map {} to {}", map_id, map_path.display()))?;
+        pinned.push(map_path);
+    }
+    Ok(pinned)
+}
+
+// Removes a pin, which releases the object if nothing else holds it
+pub fn unpin(path: &Path) -> Result<()> {
+    check_in_bpffs(path)?;
+    fs::remove_file(path).with_context(|| format!("Failed to unpin {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bpffs_mounts() {
+        let mounts = "sysfs /sys sysfs rw,nosuid,nodev,noexec,relatime 0 0\n\
+                      bpf /sys/fs/bpf bpf rw,nosuid,nodev,noexec,relatime,mode=700 0 0\n\
+                      proc /proc proc rw,nosuid,nodev,noexec,relatime 0 0\n\
+                      none /run/cilium/bpffs bpf rw,relatime 0 0\n";
+        assert_eq!(
+            parse_bpffs_mounts(mounts),
+            vec![
+                PathBuf::from("/run/cilium/bpffs"),
+                PathBuf::from("/sys/fs/bpf")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_pins() {
+        let object = |path: &str, kind, id| PinnedObject {
+            path: PathBuf::from(path),
+            kind,
+            id,
+        };
+        let objects = vec![
+            object("/sys/fs/bpf/prog", PinnedKind::Program, 10),
+            object("/sys/fs/bpf/attached", PinnedKind::Program, 11),
+            object("/sys/fs/bpf/used", PinnedKind::Map, 20),
+            object("/sys/fs/bpf/unused", PinnedKind::Map, 21),
+        ];
+        let map = |id, prog_ids| BpfMap {
+            id,
+            name: "map".to_string(),
+            map_type: "Hash".to_string(),
+            key_size: 4,
+            value_size: 4,
+            max_entries: 16,
+            memlock: 0,
+            map_extra: 0,
+            entries: None,
+            ops: None,
+            ringbuf: None,
+            pinned_paths: vec![],
+            prog_ids,
+            owners: vec![],
+        };
+        let maps = [map(20, vec![11]), map(21, vec![])];
+        let links = [(
+            10,
+            BpfAttachment {
+                link_id: 5,
+                link_type: "xdp".to_string(),
+                target: "iface eth0".to_string(),
+                attach_type: String::new(),
+            },
+        )];
+
+        let pins = resolve_pins(&objects, &[], &maps, &links, &HashSet::from([11]));
+        assert_eq!(pins[0].users(), "link 5");
+        assert_eq!(pins[1].users(), "attached");
+        assert_eq!(pins[2].users(), "program 11");
+        assert_eq!(pins[2].object_type, "Hash");
+        assert!(pins[3].is_orphaned());
+    }
+
+    #[test]
+    fn test_pinned_paths() {
+        let objects = vec![
+            PinnedObject {
+                path: PathBuf::from("/sys/fs/bpf/a"),
+                kind: PinnedKind::Map,
+                id: 1,
+            },
+            PinnedObject {
+                path: PathBuf::from("/sys/fs/bpf/b"),
+                kind: PinnedKind::Program,
+                id: 1,
+            },
+            PinnedObject {
+                path: PathBuf::from("/sys/fs/bpf/c"),
+                kind: PinnedKind::Map,
+                id: 1,
+            },
+        ];
+        let paths = pinned_paths(&objects, PinnedKind::Map);
+        assert_eq!(
+            paths.get(&1),
+            Some(&vec![
+                "/sys/fs/bpf/a".to_string(),
+                "/sys/fs/bpf/c".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_in_bpffs() {
+        let mounts = [
+            PathBuf::from("/sys/fs/bpf"),
+            PathBuf::from("/run/cilium/bpffs"),
+        ];
+        assert!(in_bpffs(Path::new("/sys/fs/bpf/prog"), &mounts));
+        assert!(in_bpffs(
+            Path::new("/run/cilium/bpffs/tc/globals/prog"),
+            &mounts
+        ));
+        assert!(!in_bpffs(Path::new("/sys/fs/bpf"), &mounts));
+        assert!(!in_bpffs(
+            Path::new("/sys/fs/bpf/../../../etc/passwd"),
+            &mounts
+        ));
+        assert!(!in_bpffs(Path::new("/tmp/prog"), &mounts));
+        assert!(!in_bpffs(Path::new("sys/fs/bpf/prog"), &mounts));
+    }
+}
@@ -0,0 +1,426 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use anyhow::{anyhow, Result};
+use std::{
+    ffi::{c_char, c_int, c_void, CStr, CString},
+    mem::{size_of, size_of_val},
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+    ptr::{self, NonNull},
+    sync::OnceLock,
+};
+use tracing::error;
+
+pub const VMLINUX_BTF_PATH: &str = "/sys/kernel/btf/vmlinux";
+
+// vmlinux BTF is large, so load it once and only when it is first needed
+static VMLINUX_BTF: OnceLock<Option<Btf>> = OnceLock::new();
+
+const BTF_KIND_PTR: u32 = 2;
+const BTF_KIND_STRUCT: u32 = 4;
+const BTF_KIND_UNION: u32 = 5;
+const BTF_KIND_FUNC_PROTO: u32 = 13;
+
+// Size of the buffer each piece of btf_dump output is formatted into
+const FORMAT_BUF_LEN: usize = 1024;
+
+extern "C" {
+    fn vsnprintf(
+        buf: *mut c_char,
+        size: usize,
+        fmt: *const c_char,
+        args: *mut libbpf_sys::__va_list_tag,
+    ) -> c_int;
+}
+
+/// BPF Type Format information loaded by libbpf, used to decode raw map keys and values.
+pub struct Btf {
+    ptr: NonNull<libbpf_sys::btf>,
+    // Module BTF only holds the types it adds on top of vmlinux's, which must outlive it
+    _base: Option<Box<Btf>>,
+}
+
+// libbpf never modifies BTF it loaded from the kernel, it is only read afterwards
+unsafe impl Send for Btf {}
+unsafe impl Sync for Btf {}
+
+impl Drop for Btf {
+    fn drop(&mut self) {
+        unsafe { libbpf_sys::btf__free(self.ptr.as_ptr()) };
+    }
+}
+
+impl Btf {
+    fn new(ptr: *mut libbpf_sys::btf, base: Option<Box<Btf>>) -> Option<Btf> {
+        NonNull::new(ptr).map(|ptr| Btf { ptr, _base: base })
+    }
+
+    /// Loads the BTF object with the given id from the kernel, on top of vmlinux's for modules.
+    pub fn from_kernel_by_id(btf_id: u32) -> Result<Btf> {
+        let base = match object_info(btf_id) {
+            Some((name, true)) if name != "vmlinux" => Some(Box::new(Btf::vmlinux()?)),
+            _ => None,
+        };
+        let ptr = match &base {
+            Some(base) => unsafe {
+                libbpf_sys::btf__load_from_kernel_by_id_split(btf_id, base.ptr.as_ptr())
+            },
+            None => unsafe { libbpf_sys::btf__load_from_kernel_by_id(btf_id) },
+        };
+        Btf::new(ptr, base).ok_or_else(|| anyhow!("Failed to load BTF id {}", btf_id))
+    }
+
+    /// Loads the kernel's own type information.
+    pub fn vmlinux() -> Result<Btf> {
+        Btf::new(unsafe { libbpf_sys::btf__load_vmlinux_btf() }, None)
+            .ok_or_else(|| anyhow!("Failed to load {}", VMLINUX_BTF_PATH))
+    }
+
+    fn btf_type(&self, type_id: u32) -> Option<&libbpf_sys::btf_type> {
+        unsafe { libbpf_sys::btf__type_by_id(self.ptr.as_ptr(), type_id).as_ref() }
+    }
+
+    fn name_at(&self, offset: u32) -> &str {
+        let name = unsafe { libbpf_sys::btf__name_by_offset(self.ptr.as_ptr(), offset) };
+        if name.is_null() {
+            return "";
+        }
+        unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default()
+    }
+
+    // Skips typedefs and modifiers, e.g. `const __u32` resolves to `unsigned int`
+    fn resolve(&self, type_id: u32) -> Option<(u32, &libbpf_sys::btf_type)> {
+        let type_id = unsafe { libbpf_sys::btf__resolve_type(self.ptr.as_ptr(), type_id) };
+        let type_id = u32::try_from(type_id).ok()?;
+        Some((type_id, self.btf_type(type_id)?))
+    }
+
+    // The name, type and byte offset of each member of a struct or union
+    fn members(&self, type_id: u32) -> Vec<(&str, u32, usize)> {
+        let Some((_, ty)) = self.resolve(type_id) else {
+            return vec![];
+        };
+        let kind = kind(ty);
+        if kind != BTF_KIND_STRUCT && kind != BTF_KIND_UNION {
+            return vec![];
+        }
+        // The members directly follow the type, bitfields pack their size in the offset
+        let bitfields = ty.info >> 31 != 0;
+        let vlen = (ty.info & 0xffff) as usize;
+        let members = unsafe {
+            let first = (ty as *const libbpf_sys::btf_type).add(1) as *const libbpf_sys::btf_member;
+            std::slice::from_raw_parts(first, vlen)
+        };
+        members
+            .iter()
+            .map(|member| {
+                let bit_offset = if bitfields {
+                    member.offset & 0xffffff
+                } else {
+                    member.offset
+                };
+                (
+                    self.name_at(member.name_off),
+                    member.type_,
+                    (bit_offset / 8) as usize,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the size in bytes of the given type.
+    pub fn type_size(&self, type_id: u32) -> Option<usize> {
+        let size = unsafe { libbpf_sys::btf__resolve_size(self.ptr.as_ptr(), type_id) };
+        usize::try_from(size).ok()
+    }
+
+    /// Returns the name of the given type, empty for anonymous types.
+    pub fn type_name(&self, type_id: u32) -> &str {
+        self.btf_type(type_id)
+            .map(|ty| self.name_at(ty.name_off))
+            .unwrap_or_default()
+    }
+
+    /// Returns the id of the first type with the given name.
+    pub fn type_id(&self, name: &str) -> Option<u32> {
+        let name = CString::new(name).ok()?;
+        let type_id = unsafe { libbpf_sys::btf__find_by_name(self.ptr.as_ptr(), name.as_ptr()) };
+        u32::try_from(type_id).ok()
+    }
+
+    /// Looks up a struct or union member by name, returning its type and byte offset.
+    pub fn member(&self, type_id: u32, name: &str) -> Option<(u32, usize)> {
+        self.members(type_id)
+            .into_iter()
+            .find(|(member, _, _)| *member == name)
+            .map(|(_, member_type, offset)| (member_type, offset))
+    }
+
+    /// Returns the name and byte offset of every function pointer member of a struct, e.g.
+    /// the callbacks of a struct_ops type.
+    pub fn func_ptr_members(&self, type_id: u32) -> Vec<(String, usize)> {
+        self.members(type_id)
+            .into_iter()
+            .filter(|(_, member_type, _)| match self.resolve(*member_type) {
+                Some((_, ty)) if kind(ty) == BTF_KIND_PTR => {
+                    let target = unsafe { ty.__bindgen_anon_1.type_ };
+                    self.resolve(target)
+                        .is_some_and(|(_, target)| kind(target) == BTF_KIND_FUNC_PROTO)
+                }
+                _ => false,
+            })
+            .map(|(name, _, offset)| (name.to_string(), offset))
+            .collect()
+    }
+
+    /// Pretty-prints `data` as an instance of the given type with libbpf's BTF dumper,
+    /// falling back to hex for anything that cannot be decoded.
+    pub fn format(&self, type_id: u32, data: &[u8]) -> String {
+        let mut out = String::new();
+        let dump = unsafe {
+            libbpf_sys::btf_dump__new(
+                self.ptr.as_ptr(),
+                Some(append_formatted),
+                &mut out as *mut String as *mut c_void,
+                ptr::null(),
+            )
+        };
+        if dump.is_null() {
+            return format_hex(data);
+        }
+        let opts = libbpf_sys::btf_dump_type_data_opts {
+            sz: size_of::<libbpf_sys::btf_dump_type_data_opts>() as libbpf_sys::size_t,
+            compact: true,
+            emit_zeroes: true,
+            ..Default::default()
+        };
+        let ret = unsafe {
+            libbpf_sys::btf_dump__dump_type_data(
+                dump,
+                type_id,
+                data.as_ptr() as *const c_void,
+                data.len() as libbpf_sys::size_t,
+                &opts,
+            )
+        };
+        unsafe { libbpf_sys::btf_dump__free(dump) };
+        if ret < 0 {
+            return format_hex(data);
+        }
+        out
+    }
+}
+
+fn kind(ty: &libbpf_sys::btf_type) -> u32 {
+    (ty.info >> 24) & 0x1f
+}
+
+// btf_dump printf callback, appends each formatted piece to the String behind `ctx`
+unsafe extern "C" fn append_formatted(
+    ctx: *mut c_void,
+    fmt: *const c_char,
+    args: *mut libbpf_sys::__va_list_tag,
+) {
+    let mut buf = [0 as c_char; FORMAT_BUF_LEN];
+    if vsnprintf(buf.as_mut_ptr(), buf.len(), fmt, args) < 0 {
+        return;
+    }
+    let out = &mut *(ctx as *mut String);
+    out.push_str(&CStr::from_ptr(buf.as_ptr()).to_string_lossy());
+}
+
+/// Returns the name of a BTF object and whether it describes the kernel or a module.
+pub fn object_info(btf_id: u32) -> Option<(String, bool)> {
+    let fd = unsafe { libbpf_sys::bpf_btf_get_fd_by_id(btf_id) };
+    if fd < 0 {
+        return None;
+    }
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+    let mut name = [0u8; 64];
+    let mut info = libbpf_sys::bpf_btf_info {
+        name: name.as_mut_ptr() as u64,
+        name_len: name.len() as u32,
+        ..Default::default()
+    };
+    let mut len = size_of_val(&info) as u32;
+    let ret = unsafe {
+        libbpf_sys::bpf_obj_get_info_by_fd(
+            fd.as_raw_fd(),
+            &mut info as *mut _ as *mut c_void,
+            &mut len,
+        )
+    };
+    if ret != 0 {
+        return None;
+    }
+    let end = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+    Some((
+        String::from_utf8_lossy(&name[..end]).into_owned(),
+        info.kernel_btf != 0,
+    ))
+}
+
+/// Returns the kernel's own type information, loaded on first use.
+pub fn vmlinux_btf() -> Option<&'static Btf> {
+    VMLINUX_BTF
+        .get_or_init(|| match Btf::vmlinux() {
+            Ok(btf) => Some(btf),
+            Err(e) => {
+                error!("Failed to load vmlinux BTF: {}", e);
+                None
+            }
+        })
+        .as_ref()
+}
+
+/// Formats raw bytes as space separated hex, the same way bpftool does.
+pub fn format_hex(data: &[u8]) -> String {
+    data.iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BTF_MAGIC: u16 = 0xeb9f;
+    const BTF_HEADER_LEN: u32 = 24;
+    const BTF_KIND_INT: u32 = 1;
+    const BTF_KIND_ARRAY: u32 = 3;
+    const BTF_INT_SIGNED: u32 = 1 << 0;
+    const BTF_INT_CHAR: u32 = 1 << 1;
+
+    // Builds a raw BTF blob out of the given type section words and string section, and
+    // has libbpf parse it
+    fn build_btf(types: &[u32], strings: &[u8]) -> Btf {
+        let type_len = (types.len() * 4) as u32;
+        let mut data = vec![];
+        data.extend_from_slice(&BTF_MAGIC.to_le_bytes());
+        data.push(1); // version
+        data.push(0); // flags
+        data.extend_from_slice(&BTF_HEADER_LEN.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes()); // type_off
+        data.extend_from_slice(&type_len.to_le_bytes());
+        data.extend_from_slice(&type_len.to_le_bytes()); // str_off
+        data.extend_from_slice(&(strings.len() as u32).to_le_bytes());
+        for word in types {
+            data.extend_from_slice(&word.to_le_bytes());
+        }
+        data.extend_from_slice(strings);
+        let ptr =
+            unsafe { libbpf_sys::btf__new(data.as_ptr() as *const c_void, data.len() as u32) };
+        Btf::new(ptr, None).unwrap()
+    }
+
+    fn info(kind: u32, vlen: u32) -> u32 {
+        (kind << 24) | vlen
+    }
+
+    // Strings: 0 "", 1 "int", 5 "pid", 9 "comm", 14 "char", 19 "key", 23 "ops", 27 "init"
+    const STRINGS: &[u8] = b"\0int\0pid\0comm\0char\0key\0ops\0init\0";
+
+    fn test_btf() -> Btf {
+        let types = [
+            // [1] int, signed 32 bits
+            1,
+            info(BTF_KIND_INT, 0),
+            4,
+            (BTF_INT_SIGNED << 24) | 32,
+            // [2] char
+            14,
+            info(BTF_KIND_INT, 0),
+            1,
+            (BTF_INT_CHAR << 24) | 8,
+            // [3] char[4]
+            0,
+            info(BTF_KIND_ARRAY, 0),
+            0,
+            2,
+            1,
+            4,
+            // [4] struct key { int pid; char comm[4]; }
+            19,
+            info(BTF_KIND_STRUCT, 2),
+            8,
+            5,
+            1,
+            0,
+            9,
+            3,
+            32,
+            // [5] int (*)(void)
+            0,
+            info(BTF_KIND_FUNC_PROTO, 0),
+            1,
+            // [6] pointer to [5]
+            0,
+            info(BTF_KIND_PTR, 0),
+            5,
+            // [7] struct ops { int pid; int (*init)(void); }
+            23,
+            info(BTF_KIND_STRUCT, 2),
+            16,
+            5,
+            1,
+            0,
+            27,
+            6,
+            64,
+        ];
+        build_btf(&types, STRINGS)
+    }
+
+    #[test]
+    fn test_type_size() {
+        let btf = test_btf();
+        assert_eq!(btf.type_size(1), Some(4));
+        assert_eq!(btf.type_size(3), Some(4));
+        assert_eq!(btf.type_size(4), Some(8));
+    }
+
+    #[test]
+    fn test_format_struct() {
+        let btf = test_btf();
+        let mut data = (-5i32).to_le_bytes().to_vec();
+        data.extend_from_slice(b"ls\0\0");
+        assert_eq!(
+            btf.format(4, &data),
+            "(struct key){.pid = (int)-5,.comm = (char[4])['l','s',],}"
+        );
+        // Too short for the type
+        assert_eq!(btf.format(4, &data[..2]), "fb ff");
+    }
+
+    #[test]
+    fn test_members() {
+        let btf = test_btf();
+        assert_eq!(btf.type_name(4), "key");
+        assert_eq!(btf.type_id("ops"), Some(7));
+        assert_eq!(btf.type_id("missing"), None);
+        assert_eq!(btf.member(4, "comm"), Some((3, 4)));
+        assert_eq!(btf.member(4, "missing"), None);
+        assert_eq!(btf.func_ptr_members(7), vec![("init".to_string(), 8)]);
+        assert!(btf.func_ptr_members(4).is_empty());
+    }
+
+    #[test]
+    fn test_format_hex() {
+        assert_eq!(format_hex(&[0x01, 0xab, 0x00]), "01 ab 00");
+    }
+}
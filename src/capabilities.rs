@@ -0,0 +1,158 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use tracing::warn;
+
+const CAP_NET_ADMIN: u32 = 12;
+const CAP_SYS_ADMIN: u32 = 21;
+const CAP_PERFMON: u32 = 38;
+const CAP_BPF: u32 = 39;
+
+// Capabilities bpftop needs and what for. The kernel only accepts CAP_SYS_ADMIN for
+// walking BPF object IDs and enabling stats, even when CAP_BPF is held.
+const REQUIRED: [(u32, &str, &str); 2] = [
+    (
+        CAP_SYS_ADMIN,
+        "CAP_SYS_ADMIN",
+        "listing BPF objects by ID and enabling BPF stats",
+    ),
+    (CAP_BPF, "CAP_BPF", "loading bpftop's own BPF programs"),
+];
+
+const PERFMON_PURPOSE: &str = "Profiling, runtime histograms, per-CPU runtime and --audit";
+const NET_ADMIN_PURPOSE: &str = "The cgroups and interfaces views";
+
+// Why the features needing CAP_PERFMON fail when it is missing
+pub const PERFMON_MISSING: &str = "Needs CAP_PERFMON";
+
+// Capabilities that only some features need, which are disabled without them
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Capabilities {
+    pub perfmon: bool,
+    pub net_admin: bool,
+}
+
+// Assumed to be held until checked
+impl Default for Capabilities {
+    fn default() -> Self {
+        Capabilities {
+            perfmon: true,
+            net_admin: true,
+        }
+    }
+}
+
+impl Capabilities {
+    fn from_effective(effective: u64) -> Capabilities {
+        Capabilities {
+            perfmon: is_satisfied(effective, CAP_PERFMON),
+            net_admin: is_satisfied(effective, CAP_NET_ADMIN),
+        }
+    }
+
+    // Each optional capability, whether it is held and what depends on it
+    pub fn diagnostics(&self) -> [(&'static str, bool, &'static str); 2] {
+        [
+            ("CAP_PERFMON", self.perfmon, PERFMON_PURPOSE),
+            ("CAP_NET_ADMIN", self.net_admin, NET_ADMIN_PURPOSE),
+        ]
+    }
+}
+
+fn has(effective: u64, cap: u32) -> bool {
+    effective & (1 << cap) != 0
+}
+
+// CAP_BPF and CAP_PERFMON are implied by CAP_SYS_ADMIN, which is also how kernels
+// predating them grant those operations
+fn is_satisfied(effective: u64, cap: u32) -> bool {
+    has(effective, cap) || (matches!(cap, CAP_BPF | CAP_PERFMON) && has(effective, CAP_SYS_ADMIN))
+}
+
+fn effective_capabilities(status: &str) -> Option<u64> {
+    let caps = status
+        .lines()
+        .find_map(|line| line.strip_prefix("CapEff:"))?;
+    u64::from_str_radix(caps.trim(), 16).ok()
+}
+
+// Required capabilities missing from an effective set, with what each is needed for
+fn missing(effective: u64) -> Vec<String> {
+    REQUIRED
+        .iter()
+        .filter(|(cap, _, _)| !is_satisfied(effective, *cap))
+        .map(|(_, name, purpose)| format!("{} ({})", name, purpose))
+        .collect()
+}
+
+// Checks the capabilities of the current process rather than its UID, so bpftop can run
+// with file capabilities instead of as root. Only the required ones are fatal, the features
+// needing the others are disabled with a warning.
+pub fn check() -> Result<Capabilities> {
+    let status =
+        fs::read_to_string("/proc/self/status").context("Failed to read process status")?;
+    let effective = effective_capabilities(&status)
+        .ok_or_else(|| anyhow!("Failed to parse effective capabilities"))?;
+    let missing = missing(effective);
+    if !missing.is_empty() {
+        return Err(anyhow!(
+            "Missing capabilities: {}. Run as root or grant them, e.g. sudo setcap cap_sys_admin,cap_bpf,cap_perfmon,cap_net_admin+ep bpftop",
+            missing.join(", ")
+        ));
+    }
+    let capabilities = Capabilities::from_effective(effective);
+    for (name, held, purpose) in capabilities.diagnostics() {
+        if !held {
+            warn!("Missing {}, disabled: {}", name, purpose);
+        }
+    }
+    Ok(capabilities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_capabilities() {
+        let status = "Name:\tbpftop\nCapInh:\t0000000000000000\nCapEff:\t000001ffffffffff\n";
+        assert_eq!(effective_capabilities(status), Some(0x1ffffffffff));
+        assert_eq!(effective_capabilities("Name:\tbpftop\n"), None);
+    }
+
+    #[test]
+    fn test_missing() {
+        let root = 0x1ffffffffff;
+        assert!(missing(root).is_empty());
+
+        // CAP_SYS_ADMIN covers CAP_BPF and CAP_PERFMON, CAP_NET_ADMIN is optional
+        assert!(missing(1 << CAP_SYS_ADMIN).is_empty());
+        assert_eq!(
+            Capabilities::from_effective(1 << CAP_SYS_ADMIN),
+            Capabilities {
+                perfmon: true,
+                net_admin: false,
+            }
+        );
+
+        let missing_caps = missing(1 << CAP_BPF | 1 << CAP_PERFMON | 1 << CAP_NET_ADMIN);
+        assert_eq!(missing_caps.len(), 1);
+        assert!(missing_caps[0].starts_with("CAP_SYS_ADMIN"));
+    }
+}
@@ -0,0 +1,303 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{capabilities, kernel_features::procfs_bpf_stats_is_enabled};
+use anyhow::{anyhow, Result};
+use bpftop_core::sampler::{LiveSource, ProgramStatsSource, Sampler};
+use libbpf_sys::bpf_enable_stats;
+use std::{
+    os::fd::{FromRawFd, OwnedFd},
+    thread,
+    time::Duration,
+};
+
+// Service states of the Nagios plugin API, in increasing severity
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Ok,
+    Warning,
+    Critical,
+    Unknown,
+}
+
+impl Status {
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Status::Ok => 0,
+            Status::Warning => 1,
+            Status::Critical => 2,
+            Status::Unknown => 3,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Status::Ok => "OK",
+            Status::Warning => "WARNING",
+            Status::Critical => "CRITICAL",
+            Status::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Usage {
+    id: u32,
+    name: String,
+    bpf_type: String,
+    cpu_percent: f64,
+    events_per_second: f64,
+}
+
+// CPU budgets of a single program, in percent
+#[derive(Clone, Copy, Debug)]
+pub struct Thresholds {
+    pub warning: f64,
+    pub critical: f64,
+}
+
+impl Thresholds {
+    fn status(&self, cpu_percent: f64) -> Status {
+        if cpu_percent >= self.critical {
+            Status::Critical
+        } else if cpu_percent >= self.warning {
+            Status::Warning
+        } else {
+            Status::Ok
+        }
+    }
+}
+
+// Measures every program's usage between two samples taken `duration` apart. Programs
+// loaded or replaced during the check have no baseline yet and count as idle, as they do in
+// the first period of the UI.
+fn measure<S: ProgramStatsSource>(mut sampler: Sampler<S>, duration: Duration) -> Vec<Usage> {
+    sampler.sample();
+    thread::sleep(duration);
+    sampler
+        .sample()
+        .programs
+        .iter()
+        .map(|prog| Usage {
+            id: prog.id,
+            name: prog.name.clone(),
+            bpf_type: prog.bpf_type.clone(),
+            cpu_percent: prog.cpu_time_percent(),
+            events_per_second: prog.events_per_second() as f64,
+        })
+        .collect()
+}
+
+// Measures every program's CPU usage and event rate over `duration`. BPF stats are enabled
+// for the duration of the check if they are not already.
+fn sample(duration: Duration) -> Result<Vec<Usage>> {
+    capabilities::check()?;
+    let fd = unsafe { bpf_enable_stats(libbpf_sys::BPF_STATS_RUN_TIME) };
+    let _stats = if fd >= 0 {
+        Some(unsafe { OwnedFd::from_raw_fd(fd) })
+    } else if procfs_bpf_stats_is_enabled()? {
+        None
+    } else {
+        return Err(anyhow!("BPF stats are disabled and could not be enabled"));
+    };
+
+    // Holders are not reported, so they are found by scanning /proc rather than loading
+    // pid_iter
+    Ok(measure(Sampler::new(LiveSource::new(None)), duration))
+}
+
+// Builds the status line, followed by performance data after a | in the format of the
+// Nagios plugin API, and the programs over budget worst first
+fn evaluate(usage: &[Usage], thresholds: Thresholds) -> (Status, String, String, Vec<String>) {
+    let mut over: Vec<&Usage> = usage
+        .iter()
+        .filter(|prog| thresholds.status(prog.cpu_percent) != Status::Ok)
+        .collect();
+    over.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent));
+    let busiest = usage
+        .iter()
+        .max_by(|a, b| a.cpu_percent.total_cmp(&b.cpu_percent));
+    let max_cpu = busiest.map_or(0.0, |prog| prog.cpu_percent);
+    let status = thresholds.status(max_cpu);
+
+    let summary = match (over.first(), busiest) {
+        (Some(worst), _) => format!(
+            "{} ({}) at {:.2}% CPU, {} of {} programs over {}%",
+            worst.name,
+            worst.id,
+            worst.cpu_percent,
+            over.len(),
+            usage.len(),
+            thresholds.warning
+        ),
+        (None, Some(busiest)) => format!(
+            "{} programs, busiest {} ({}) at {:.2}% CPU",
+            usage.len(),
+            busiest.name,
+            busiest.id,
+            busiest.cpu_percent
+        ),
+        (None, None) => "No BPF programs loaded".to_string(),
+    };
+    let perfdata = format!(
+        "programs={} max_cpu={:.2}%;{};{};0 total_cpu={:.2}%;;;0 events_per_second={:.0};;;0",
+        usage.len(),
+        max_cpu,
+        thresholds.warning,
+        thresholds.critical,
+        usage.iter().fold(0.0, |sum, prog| sum + prog.cpu_percent),
+        usage
+            .iter()
+            .fold(0.0, |sum, prog| sum + prog.events_per_second),
+    );
+    let details = over
+        .iter()
+        .map(|prog| {
+            format!(
+                "{} {} ({}, {}): {:.2}% CPU, {:.0} events/s",
+                thresholds.status(prog.cpu_percent).label(),
+                prog.name,
+                prog.id,
+                prog.bpf_type,
+                prog.cpu_percent,
+                prog.events_per_second
+            )
+        })
+        .collect();
+    (status, summary, perfdata, details)
+}
+
+// Checks every program's CPU usage against the thresholds, prints the result and returns
+// the exit code. With `nagios`, the output is the single line with performance data that
+// Nagios, Icinga and NRPE expect.
+pub fn run(duration: Duration, thresholds: Thresholds, nagios: bool) -> i32 {
+    let usage = if thresholds.warning > thresholds.critical {
+        Err(anyhow!("--warning must not be above --critical"))
+    } else {
+        sample(duration)
+    };
+    let usage = match usage {
+        Ok(usage) => usage,
+        Err(e) => {
+            println!("BPFTOP {} - {:#}", Status::Unknown.label(), e);
+            return Status::Unknown.exit_code();
+        }
+    };
+    let (status, summary, perfdata, details) = evaluate(&usage, thresholds);
+    if nagios {
+        println!("BPFTOP {} - {} | {}", status.label(), summary, perfdata);
+    } else {
+        for line in details {
+            println!("{}", line);
+        }
+        println!("{} - {}", status.label(), summary);
+    }
+    status.exit_code()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bpftop_core::{bpf_program::BpfProgram, sampler::MockSource};
+    use std::time::Instant;
+
+    fn usage(id: u32, name: &str, cpu_percent: f64) -> Usage {
+        Usage {
+            id,
+            name: name.to_string(),
+            bpf_type: "Xdp".to_string(),
+            cpu_percent,
+            events_per_second: 1000.0,
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let thresholds = Thresholds {
+            warning: 5.0,
+            critical: 10.0,
+        };
+        let (status, summary, perfdata, details) =
+            evaluate(&[usage(1, "a", 1.0), usage(2, "b", 2.5)], thresholds);
+        assert_eq!(status, Status::Ok);
+        assert_eq!(summary, "2 programs, busiest b (2) at 2.50% CPU");
+        assert_eq!(
+            perfdata,
+            "programs=2 max_cpu=2.50%;5;10;0 total_cpu=3.50%;;;0 events_per_second=2000;;;0"
+        );
+        assert!(details.is_empty());
+
+        let (status, summary, _, details) = evaluate(
+            &[usage(1, "a", 6.0), usage(2, "b", 12.0), usage(3, "c", 0.0)],
+            thresholds,
+        );
+        assert_eq!(status, Status::Critical);
+        assert_eq!(summary, "b (2) at 12.00% CPU, 2 of 3 programs over 5%");
+        assert_eq!(
+            details,
+            [
+                "CRITICAL b (2, Xdp): 12.00% CPU, 1000 events/s",
+                "WARNING a (1, Xdp): 6.00% CPU, 1000 events/s"
+            ]
+        );
+
+        let (status, summary, perfdata, _) = evaluate(&[], thresholds);
+        assert_eq!(status, Status::Ok);
+        assert_eq!(summary, "No BPF programs loaded");
+        assert_eq!(
+            perfdata,
+            "programs=0 max_cpu=0.00%;5;10;0 total_cpu=0.00%;;;0 events_per_second=0;;;0"
+        );
+    }
+
+    #[test]
+    fn test_measure() {
+        let start = Instant::now();
+        let program = |id, tag: &str, run_time_ns, run_cnt, secs| BpfProgram {
+            id,
+            bpf_type: "Xdp".to_string(),
+            name: "xdp_prog".to_string(),
+            tag: tag.to_string(),
+            prev_runtime_ns: 0,
+            run_time_ns,
+            prev_run_cnt: 0,
+            run_cnt,
+            instant: start + Duration::from_secs(secs),
+            period_ns: 0,
+            processes: vec![],
+        };
+        let source = MockSource::new([
+            vec![
+                program(1, "aa", 0, 0, 0),
+                program(2, "bb", 50_000_000, 10, 0),
+            ],
+            vec![
+                program(1, "aa", 20_000_000, 1000, 2),
+                // ID 2 was handed to another program
+                program(2, "cc", 10_000_000, 10, 2),
+                program(3, "dd", 10_000_000, 10, 2),
+            ],
+        ]);
+
+        let usage = measure(Sampler::new(source), Duration::ZERO);
+        assert_eq!(usage.len(), 3);
+        assert_eq!(usage[0].cpu_percent, 1.0);
+        assert_eq!(usage[0].events_per_second, 500.0);
+        assert_eq!(usage[1].cpu_percent, 0.0);
+        assert_eq!(usage[2].cpu_percent, 0.0);
+    }
+}
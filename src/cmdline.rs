@@ -0,0 +1,90 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use std::{collections::HashMap, fs};
+
+// The kernel cuts comms to 15 bytes
+const COMM_LEN: usize = 15;
+
+// Comms of interpreters, runtimes and shells, which say little about what they run
+const GENERIC_COMMS: [&str; 12] = [
+    "python", "python3", "java", "node", "ruby", "perl", "php", "dotnet", "sh", "bash", "main",
+    "exe",
+];
+
+// Past this many processes the cache starts over, rather than tracking which have exited
+const CACHE_SIZE: usize = 4096;
+
+// Whether a comm may have been truncated, or names an interpreter rather than a program
+pub fn is_ambiguous(comm: &str) -> bool {
+    comm.len() >= COMM_LEN || GENERIC_COMMS.contains(&comm)
+}
+
+// The arguments of /proc/<pid>/cmdline joined by spaces. Kernel threads have none.
+fn parse_cmdline(cmdline: &[u8]) -> Option<String> {
+    let args: Vec<String> = cmdline
+        .split(|byte| *byte == 0)
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| String::from_utf8_lossy(arg).into_owned())
+        .collect();
+    (!args.is_empty()).then(|| args.join(" "))
+}
+
+// Command lines read from procfs for the processes on screen, once per process. Entries are
+// keyed by PID and comm, so a reused PID is read again.
+#[derive(Debug, Default)]
+pub struct CmdlineCache {
+    entries: HashMap<(i32, String), Option<String>>,
+}
+
+impl CmdlineCache {
+    pub fn get(&mut self, pid: i32, comm: &str) -> Option<&str> {
+        let key = (pid, comm.to_string());
+        if !self.entries.contains_key(&key) && self.entries.len() >= CACHE_SIZE {
+            self.entries.clear();
+        }
+        self.entries
+            .entry(key)
+            .or_insert_with(|| {
+                fs::read(format!("/proc/{}/cmdline", pid))
+                    .ok()
+                    .and_then(|cmdline| parse_cmdline(&cmdline))
+            })
+            .as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_ambiguous() {
+        assert!(is_ambiguous("containerd-shim"));
+        assert!(is_ambiguous("python3"));
+        assert!(!is_ambiguous("cilium-agent"));
+    }
+
+    #[test]
+    fn test_parse_cmdline() {
+        assert_eq!(
+            parse_cmdline(b"/usr/bin/containerd-shim-runc-v2\0-namespace\0k8s.io\0"),
+            Some("/usr/bin/containerd-shim-runc-v2 -namespace k8s.io".to_string())
+        );
+        assert_eq!(parse_cmdline(b""), None);
+    }
+}
@@ -0,0 +1,370 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+// The fields of a line written by --export-json that comparing needs
+#[derive(Debug, Deserialize)]
+struct RecordedLine {
+    timestamp_ms: u64,
+    name: String,
+    bpf_type: String,
+    events_per_second: i64,
+    cpu_time_percent: f64,
+    period_average_runtime_ns: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Metric {
+    Cpu,
+    Eps,
+    Runtime,
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Metric::Cpu => "cpu",
+            Metric::Eps => "eps",
+            Metric::Runtime => "runtime",
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Limit {
+    // Increase in percent of the value before
+    Relative(f64),
+    // Increase in the metric's own unit
+    Absolute(f64),
+}
+
+// A --fail-on threshold, the largest increase of a metric that is not a regression
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Threshold {
+    metric: Metric,
+    limit: Limit,
+}
+
+impl Threshold {
+    // Programs that did not run before have no ratio to compare, so relative thresholds
+    // only catch them through the total
+    fn exceeded(&self, before: f64, after: f64) -> bool {
+        match self.limit {
+            Limit::Relative(percent) => before > 0.0 && (after - before) / before * 100.0 > percent,
+            Limit::Absolute(increase) => after - before > increase,
+        }
+    }
+}
+
+// Parses a threshold such as "cpu:+20%" or "runtime:+500"
+pub fn parse_threshold(value: &str) -> Result<Threshold> {
+    let invalid = || anyhow!("Invalid threshold '{}', expected e.g. cpu:+20%", value);
+    let (metric, limit) = value.split_once(':').ok_or_else(invalid)?;
+    let metric = match metric.trim() {
+        "cpu" => Metric::Cpu,
+        "eps" => Metric::Eps,
+        "runtime" => Metric::Runtime,
+        _ => {
+            return Err(anyhow!(
+                "Unknown metric '{}', expected cpu, eps or runtime",
+                metric
+            ))
+        }
+    };
+    let limit = limit.trim();
+    let (number, relative) = match limit.strip_suffix('%') {
+        Some(number) => (number, true),
+        None => (limit, false),
+    };
+    let number: f64 = number.parse().map_err(|_| invalid())?;
+    if !(number >= 0.0 && number.is_finite()) {
+        return Err(anyhow!(
+            "Threshold '{}' must be a non-negative increase",
+            value
+        ));
+    }
+    let limit = if relative {
+        Limit::Relative(number)
+    } else {
+        Limit::Absolute(number)
+    };
+    Ok(Threshold { metric, limit })
+}
+
+// Average CPU %, events per second and runtime per event over a recording
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Stats {
+    cpu: f64,
+    eps: f64,
+    runtime_ns: f64,
+}
+
+impl Stats {
+    fn get(&self, metric: Metric) -> f64 {
+        match metric {
+            Metric::Cpu => self.cpu,
+            Metric::Eps => self.eps,
+            Metric::Runtime => self.runtime_ns,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Totals {
+    periods: f64,
+    // Lines are recorded in time order, so a new timestamp starts a new period
+    last_timestamp_ms: Option<u64>,
+    cpu: f64,
+    eps: f64,
+    // Runtime weighted by events, so busy periods count for more
+    weighted_runtime_ns: f64,
+}
+
+impl Totals {
+    fn add(&mut self, line: &RecordedLine) {
+        if self.last_timestamp_ms != Some(line.timestamp_ms) {
+            self.last_timestamp_ms = Some(line.timestamp_ms);
+            self.periods += 1.0;
+        }
+        self.cpu += line.cpu_time_percent;
+        self.eps += line.events_per_second as f64;
+        self.weighted_runtime_ns +=
+            line.period_average_runtime_ns as f64 * line.events_per_second as f64;
+    }
+
+    fn stats(&self) -> Stats {
+        Stats {
+            cpu: self.cpu / self.periods.max(1.0),
+            eps: self.eps / self.periods.max(1.0),
+            runtime_ns: if self.eps > 0.0 {
+                self.weighted_runtime_ns / self.eps
+            } else {
+                0.0
+            },
+        }
+    }
+}
+
+// Programs are matched by type and name, as their IDs change whenever they are reloaded,
+// and programs sharing both are added up
+type ProgramKey = (String, String);
+
+// What a recording made with --export-json says about each program, averaged over the
+// periods the program was recorded in, and about all of them together
+#[derive(Debug, Default)]
+struct Recording {
+    programs: BTreeMap<ProgramKey, Stats>,
+    total: Stats,
+}
+
+impl Recording {
+    fn parse(reader: impl BufRead) -> Result<Recording> {
+        let mut programs: BTreeMap<ProgramKey, Totals> = BTreeMap::new();
+        let mut total = Totals::default();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let line: RecordedLine =
+                serde_json::from_str(&line).with_context(|| format!("Line {}", i + 1))?;
+            total.add(&line);
+            programs
+                .entry((line.bpf_type.clone(), line.name.clone()))
+                .or_default()
+                .add(&line);
+        }
+        Ok(Recording {
+            programs: programs
+                .into_iter()
+                .map(|(key, totals)| (key, totals.stats()))
+                .collect(),
+            total: total.stats(),
+        })
+    }
+
+    fn load(path: &Path) -> Result<Recording> {
+        let file =
+            File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+        Recording::parse(BufReader::new(file))
+            .with_context(|| format!("Invalid recording {}", path.display()))
+    }
+}
+
+// Describes every threshold a program or the total exceeded
+fn regressions(before: &Recording, after: &Recording, thresholds: &[Threshold]) -> Vec<String> {
+    let programs = after.programs.iter().filter_map(|(key, after)| {
+        let before = before.programs.get(key)?;
+        Some((format!("{} {}", key.0, key.1), before, after))
+    });
+    let total = ("total".to_string(), &before.total, &after.total);
+    programs
+        .chain([total])
+        .flat_map(|(name, before, after)| {
+            thresholds
+                .iter()
+                .filter(|threshold| {
+                    threshold.exceeded(before.get(threshold.metric), after.get(threshold.metric))
+                })
+                .map(move |threshold| {
+                    format!(
+                        "{}: {} went from {:.2} to {:.2}",
+                        name,
+                        threshold.metric,
+                        before.get(threshold.metric),
+                        after.get(threshold.metric)
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn change(before: Option<f64>, after: Option<f64>) -> String {
+    match (before, after) {
+        (Some(before), Some(after)) if before > 0.0 => format!(
+            "{:.2} -> {:.2} ({:+.1}%)",
+            before,
+            after,
+            (after - before) / before * 100.0
+        ),
+        (Some(before), Some(after)) => format!("{:.2} -> {:.2}", before, after),
+        (Some(before), None) => format!("{:.2} -> removed", before),
+        (None, Some(after)) => format!("new -> {:.2}", after),
+        (None, None) => String::new(),
+    }
+}
+
+// Prints the per-program and total differences between two recordings, and fails if any
+// threshold was exceeded, so it can gate performance CI
+pub fn run(before: &Path, after: &Path, thresholds: &[Threshold]) -> Result<()> {
+    let before = Recording::load(before)?;
+    let after = Recording::load(after)?;
+
+    println!(
+        "{:<40} {:>30} {:>30} {:>30}",
+        "PROGRAM", "CPU %", "EVENTS/S", "RUNTIME NS"
+    );
+    let keys: BTreeSet<&ProgramKey> = before
+        .programs
+        .keys()
+        .chain(after.programs.keys())
+        .collect();
+    let rows = keys.into_iter().map(|key| {
+        (
+            format!("{} {}", key.0, key.1),
+            before.programs.get(key),
+            after.programs.get(key),
+        )
+    });
+    for (name, before, after) in
+        rows.chain([("total".to_string(), Some(&before.total), Some(&after.total))])
+    {
+        let cells = [Metric::Cpu, Metric::Eps, Metric::Runtime]
+            .map(|metric| change(before.map(|s| s.get(metric)), after.map(|s| s.get(metric))));
+        println!(
+            "{:<40} {:>30} {:>30} {:>30}",
+            name, cells[0], cells[1], cells[2]
+        );
+    }
+
+    let regressions = regressions(&before, &after, thresholds);
+    if regressions.is_empty() {
+        return Ok(());
+    }
+    println!();
+    for regression in &regressions {
+        println!("Regression: {}", regression);
+    }
+    Err(anyhow!("{} regression(s) found", regressions.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_threshold() {
+        assert_eq!(
+            parse_threshold("cpu:+20%").unwrap(),
+            Threshold {
+                metric: Metric::Cpu,
+                limit: Limit::Relative(20.0)
+            }
+        );
+        assert_eq!(
+            parse_threshold("runtime:500").unwrap(),
+            Threshold {
+                metric: Metric::Runtime,
+                limit: Limit::Absolute(500.0)
+            }
+        );
+        assert!(parse_threshold("cpu").is_err());
+        assert!(parse_threshold("memory:+1%").is_err());
+        assert!(parse_threshold("eps:-5%").is_err());
+    }
+
+    #[test]
+    fn test_regressions() {
+        let line = |timestamp_ms, name: &str, eps, cpu, runtime| {
+            format!(
+                r#"{{"timestamp_ms":{},"id":1,"name":"{}","bpf_type":"Xdp","run_time_ns":0,"run_cnt":0,"events_per_second":{},"cpu_time_percent":{},"period_average_runtime_ns":{},"total_average_runtime_ns":0}}"#,
+                timestamp_ms, name, eps, cpu, runtime
+            )
+        };
+        let before = [
+            line(1000, "xdp_lb", 1000, 1.0, 100),
+            line(1000, "xdp_fw", 1000, 1.0, 100),
+            line(2000, "xdp_lb", 1000, 3.0, 100),
+            line(2000, "xdp_fw", 1000, 1.0, 100),
+        ]
+        .join("\n");
+        let after = [
+            line(1000, "xdp_lb", 1000, 3.0, 100),
+            line(1000, "xdp_fw", 1000, 1.0, 100),
+            line(1000, "xdp_new", 0, 0.0, 0),
+        ]
+        .join("\n");
+        let before = Recording::parse(before.as_bytes()).unwrap();
+        let after = Recording::parse(after.as_bytes()).unwrap();
+        assert_eq!(before.total.cpu, 3.0);
+        assert_eq!(
+            before.programs[&("Xdp".to_string(), "xdp_lb".to_string())].cpu,
+            2.0
+        );
+        assert_eq!(after.total.runtime_ns, 100.0);
+
+        // xdp_lb went from 2% to 3%, the total from 3% to 4%
+        let thresholds = [parse_threshold("cpu:+40%").unwrap()];
+        assert_eq!(
+            regressions(&before, &after, &thresholds),
+            vec!["Xdp xdp_lb: cpu went from 2.00 to 3.00"]
+        );
+        let thresholds = [parse_threshold("cpu:+0.5").unwrap()];
+        assert_eq!(regressions(&before, &after, &thresholds).len(), 2);
+        assert!(regressions(&before, &after, &[parse_threshold("eps:+1%").unwrap()]).is_empty());
+    }
+}
@@ -0,0 +1,147 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    anomaly::DEFAULT_ANOMALY_SIGMA, idle::DEFAULT_IDLE_PERIODS, rules::RuleConfig,
+    script::ColumnConfig,
+};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+// Loaded when no --config is given, if it exists
+pub const DEFAULT_CONFIG_PATH: &str = "/etc/bpftop/config.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    // Named groups of program name patterns that rules can match on, e.g.
+    // `cilium = ["cil_*", "tail_*"]`
+    #[serde(default)]
+    pub labels: HashMap<String, Vec<String>>,
+    #[serde(default, rename = "rule")]
+    pub rules: Vec<RuleConfig>,
+    // Extra program list columns computed from the program's fields
+    #[serde(default, rename = "column")]
+    pub columns: Vec<ColumnConfig>,
+    // Directory of the config file, which the scripts of columns and rules are relative to
+    #[serde(skip)]
+    pub dir: PathBuf,
+    #[serde(default)]
+    pub idle: IdleConfig,
+    #[serde(default)]
+    pub anomaly: AnomalyConfig,
+}
+
+// Hiding of programs that ran no events recently, which can also be toggled from the UI
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct IdleConfig {
+    // Start with idle programs hidden
+    pub hide: bool,
+    // Consecutive one-second periods without events before a program counts as idle
+    pub periods: u32,
+}
+
+impl Default for IdleConfig {
+    fn default() -> Self {
+        IdleConfig {
+            hide: false,
+            periods: DEFAULT_IDLE_PERIODS,
+        }
+    }
+}
+
+// Highlighting of programs that moved far from their usual CPU% or events/sec
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct AnomalyConfig {
+    // Standard deviations from a program's rolling baseline before it is highlighted
+    pub sigma: f64,
+}
+
+impl Default for AnomalyConfig {
+    fn default() -> Self {
+        AnomalyConfig {
+            sigma: DEFAULT_ANOMALY_SIGMA,
+        }
+    }
+}
+
+impl Config {
+    pub fn parse(contents: &str) -> Result<Config> {
+        Ok(toml::from_str(contents)?)
+    }
+
+    // Loads the given config file, or the default one if it exists
+    pub fn load(path: Option<&Path>) -> Result<Config> {
+        let path = match path {
+            Some(path) => path,
+            None if Path::new(DEFAULT_CONFIG_PATH).exists() => Path::new(DEFAULT_CONFIG_PATH),
+            None => return Ok(Config::default()),
+        };
+        let dir = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        let contents = fs::read_to_string(path)
+            .context(format!("Failed to read config {}", path.display()))?;
+        let config = Config::parse(&contents)
+            .context(format!("Failed to parse config {}", path.display()))?;
+        Ok(Config { dir, ..config })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            r#"
+            [labels]
+            cilium = ["cil_*"]
+
+            [[rule]]
+            name = "hot-xdp"
+            type = "xdp"
+            condition = "cpu_percent > 3"
+            for = "10s"
+
+            [[column]]
+            name = "ns/event"
+            expr = "period_run_time_ns / period_run_cnt"
+
+            [idle]
+            hide = true
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.labels["cilium"], vec!["cil_*"]);
+        assert_eq!(config.rules.len(), 1);
+        assert_eq!(config.rules[0].name, "hot-xdp");
+        assert_eq!(config.columns[0].name, "ns/event");
+        assert!(config.idle.hide);
+        assert_eq!(config.idle.periods, DEFAULT_IDLE_PERIODS);
+        assert_eq!(config.anomaly.sigma, DEFAULT_ANOMALY_SIGMA);
+
+        assert!(Config::parse("unknown = 1").is_err());
+        assert!(Config::parse("").unwrap().rules.is_empty());
+    }
+}
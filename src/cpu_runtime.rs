@@ -0,0 +1,185 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{histogram::attach_latency, profile::read_value};
+use anyhow::Result;
+use libbpf_rs::{MapCore, MapFlags};
+use std::{
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+// Width of the bar column, in characters
+const BAR_WIDTH: usize = 40;
+
+// Runtime accumulated on one CPU, see struct runtime in latency.bpf.c
+#[repr(C)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CpuRuntime {
+    pub run_time_ns: u64,
+    pub run_cnt: u64,
+}
+
+impl CpuRuntime {
+    pub fn average_runtime_ns(&self) -> u64 {
+        self.run_time_ns
+            .checked_div(self.run_cnt)
+            .unwrap_or_default()
+    }
+}
+
+// Runtime of a program per CPU, indexed by CPU number
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CpuBreakdown {
+    pub cpus: Vec<CpuRuntime>,
+}
+
+impl CpuBreakdown {
+    pub fn total_runtime_ns(&self) -> u64 {
+        self.cpus.iter().map(|cpu| cpu.run_time_ns).sum()
+    }
+
+    // Renders one bar per CPU the program ran on, scaled to its share of the total runtime
+    pub fn lines(&self) -> Vec<String> {
+        let total = self.total_runtime_ns();
+        if total == 0 {
+            return vec![];
+        }
+
+        let mut lines = vec![format!(
+            "{:>5} {:<w$} {:>7} {:>10} {:>12}",
+            "cpu",
+            "runtime share",
+            "share",
+            "runs",
+            "avg (ns)",
+            w = BAR_WIDTH + 2
+        )];
+        for (cpu, runtime) in self.cpus.iter().enumerate() {
+            if runtime.run_cnt == 0 {
+                continue;
+            }
+            let share = runtime.run_time_ns as f64 / total as f64;
+            let bars = (share * BAR_WIDTH as f64).round() as usize;
+            lines.push(format!(
+                "{:>5} |{:<w$}| {:>6.1}% {:>10} {:>12}",
+                cpu,
+                "*".repeat(bars),
+                share * 100.0,
+                runtime.run_cnt,
+                runtime.average_runtime_ns(),
+                w = BAR_WIDTH
+            ));
+        }
+        lines
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CpuRuntimeState {
+    Collecting(u32, CpuBreakdown),
+    Failed(u32, String),
+}
+
+// Attaches the latency programs to `prog_id` and refreshes `state` with the runtime
+// accumulated on each CPU every second until `running` is cleared
+pub fn collect_cpu_runtime(
+    prog_id: u32,
+    running: Arc<AtomicBool>,
+    state: Arc<Mutex<Option<CpuRuntimeState>>>,
+) {
+    if let Err(e) = run_collector(prog_id, &running, &state) {
+        let mut state = state.lock().unwrap();
+        if running.load(Ordering::Relaxed) {
+            *state = Some(CpuRuntimeState::Failed(prog_id, e.to_string()));
+        }
+    }
+}
+
+fn run_collector(
+    prog_id: u32,
+    running: &AtomicBool,
+    state: &Mutex<Option<CpuRuntimeState>>,
+) -> Result<()> {
+    let mut open_object = MaybeUninit::uninit();
+    let skel = attach_latency(prog_id, &mut open_object)?;
+
+    loop {
+        thread::sleep(Duration::from_secs(1));
+
+        let cpus = skel
+            .maps
+            .cpu_runtime
+            .lookup_percpu(&0u32.to_ne_bytes(), MapFlags::ANY)?
+            .unwrap_or_default()
+            .iter()
+            .map(|bytes| read_value::<CpuRuntime>(bytes))
+            .collect();
+
+        let mut state = state.lock().unwrap();
+        if !running.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        *state = Some(CpuRuntimeState::Collecting(prog_id, CpuBreakdown { cpus }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        assert!(CpuBreakdown::default().lines().is_empty());
+
+        let breakdown = CpuBreakdown {
+            cpus: vec![
+                CpuRuntime {
+                    run_time_ns: 300,
+                    run_cnt: 3,
+                },
+                CpuRuntime::default(),
+                CpuRuntime {
+                    run_time_ns: 100,
+                    run_cnt: 4,
+                },
+            ],
+        };
+        let lines = breakdown.lines();
+        assert_eq!(breakdown.total_runtime_ns(), 400);
+        // Header plus the two CPUs the program ran on
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[1],
+            format!(
+                "{:>5} |{:<40}| {:>6.1}% {:>10} {:>12}",
+                0,
+                "*".repeat(30),
+                75.0,
+                3,
+                100
+            )
+        );
+        assert!(lines[2].starts_with("    2 |**********"));
+        assert!(lines[2].ends_with("25"));
+    }
+}
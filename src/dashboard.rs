@@ -0,0 +1,152 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::memory::MemoryUsage;
+use bpftop_core::{
+    bpf_program::BpfProgram,
+    helpers::{format_bytes, format_percent},
+};
+use std::collections::HashMap;
+
+// Programs listed in each quadrant of the dashboard
+pub const TOP_PROGRAMS: usize = 5;
+
+#[derive(Debug, PartialEq)]
+pub struct Entry {
+    pub id: u32,
+    pub name: String,
+    value: f64,
+    pub shown: String,
+}
+
+// One quadrant of the dashboard: the programs with the highest value of a metric
+#[derive(Debug, PartialEq)]
+pub struct Quadrant {
+    pub title: &'static str,
+    pub entries: Vec<Entry>,
+}
+
+impl Quadrant {
+    // Busiest first, leaving out programs at zero as there is nothing to single out
+    fn new(title: &'static str, mut entries: Vec<Entry>) -> Quadrant {
+        entries.retain(|entry| entry.value > 0.0);
+        entries.sort_by(|a, b| b.value.total_cmp(&a.value).then(a.id.cmp(&b.id)));
+        entries.truncate(TOP_PROGRAMS);
+        Quadrant { title, entries }
+    }
+}
+
+// The top programs by CPU %, events per second, average runtime and memory, in reading
+// order of the quadrants
+pub fn quadrants(programs: &[&BpfProgram], memory: &MemoryUsage) -> [Quadrant; 4] {
+    let entries = |value: &dyn Fn(&BpfProgram) -> (f64, String)| -> Vec<Entry> {
+        programs
+            .iter()
+            .map(|prog| {
+                let (value, shown) = value(prog);
+                Entry {
+                    id: prog.id,
+                    name: prog.name.clone(),
+                    value,
+                    shown,
+                }
+            })
+            .collect()
+    };
+    let bytes: HashMap<u32, u64> = memory
+        .consumers
+        .iter()
+        .filter(|consumer| consumer.kind == "program")
+        .map(|consumer| (consumer.id, consumer.bytes))
+        .collect();
+    [
+        Quadrant::new(
+            "CPU %",
+            entries(&|prog| {
+                let cpu = prog.cpu_time_percent();
+                (cpu, format_percent(cpu))
+            }),
+        ),
+        Quadrant::new(
+            "Events/s",
+            entries(&|prog| {
+                let eps = prog.events_per_second();
+                (eps as f64, eps.to_string())
+            }),
+        ),
+        Quadrant::new(
+            "Avg Runtime (ns)",
+            entries(&|prog| {
+                let runtime = prog.period_average_runtime_ns();
+                (runtime as f64, runtime.to_string())
+            }),
+        ),
+        Quadrant::new(
+            "Memory",
+            entries(&|prog| {
+                let bytes = bytes.get(&prog.id).copied().unwrap_or(0);
+                (bytes as f64, format_bytes(bytes))
+            }),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MemoryConsumer;
+    use std::time::Instant;
+
+    #[test]
+    fn test_quadrants() {
+        let prog = |id, run_time_ns, run_cnt| BpfProgram {
+            id,
+            bpf_type: "Xdp".to_string(),
+            name: format!("prog{}", id),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns,
+            prev_run_cnt: 0,
+            run_cnt,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        };
+        let programs: Vec<BpfProgram> = (1..=7)
+            .map(|id| prog(id, id as u64 * 1_000_000, 8 - id as u64))
+            .chain([prog(8, 0, 0)])
+            .collect();
+        let programs: Vec<&BpfProgram> = programs.iter().collect();
+        let memory = MemoryUsage::new(vec![MemoryConsumer {
+            kind: "program",
+            id: 3,
+            name: "prog3".to_string(),
+            type_name: "Xdp".to_string(),
+            bytes: 4096,
+        }]);
+
+        let [cpu, eps, runtime, memory] = quadrants(&programs, &memory);
+        let ids = |quadrant: &Quadrant| quadrant.entries.iter().map(|e| e.id).collect::<Vec<_>>();
+        assert_eq!(ids(&cpu), [7, 6, 5, 4, 3]);
+        assert_eq!(cpu.entries[0].shown, "0.7%");
+        assert_eq!(ids(&eps), [1, 2, 3, 4, 5]);
+        // Runtime rises and runs drop with the ID, so the average follows the ID too
+        assert_eq!(ids(&runtime), [7, 6, 5, 4, 3]);
+        assert_eq!(ids(&memory), [3]);
+        assert_eq!(memory.entries[0].shown, "4.0 KiB");
+    }
+}
@@ -0,0 +1,180 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use bpftop_core::bpf_program::BpfProgram;
+
+const BAR_WIDTH: usize = 30;
+
+// Lower bounds and labels of the buckets. The first holds the idle programs, whatever its
+// bound.
+const EVENTS_BUCKETS: [(f64, &str); 8] = [
+    (0.0, "0"),
+    (1.0, "1+"),
+    (10.0, "10+"),
+    (100.0, "100+"),
+    (1e3, "1k+"),
+    (1e4, "10k+"),
+    (1e5, "100k+"),
+    (1e6, "1M+"),
+];
+const CPU_BUCKETS: [(f64, &str); 6] = [
+    (0.0, "0%"),
+    (0.0, "<0.01%"),
+    (0.01, "0.01%+"),
+    (0.1, "0.1%+"),
+    (1.0, "1%+"),
+    (10.0, "10%+"),
+];
+
+// How a metric is spread over the programs: how many fall in each decade, and how much of
+// the total the busiest programs account for
+#[derive(Debug, PartialEq)]
+pub struct Distribution {
+    pub unit: &'static str,
+    buckets: Vec<(&'static str, usize)>,
+    // Shares of the total of the busiest program and the ten busiest, if anything ran
+    top_share: Option<(f64, f64)>,
+}
+
+impl Distribution {
+    fn new(
+        unit: &'static str,
+        bounds: &[(f64, &'static str)],
+        mut values: Vec<f64>,
+        idle: usize,
+    ) -> Distribution {
+        let mut buckets: Vec<(&str, usize)> = bounds.iter().map(|(_, label)| (*label, 0)).collect();
+        buckets[0].1 = idle;
+        for value in &values {
+            let bucket = if *value > 0.0 {
+                bounds
+                    .iter()
+                    .rposition(|(bound, _)| value >= bound)
+                    .unwrap_or(0)
+                    .max(1)
+            } else {
+                0
+            };
+            buckets[bucket].1 += 1;
+        }
+
+        values.sort_by(|a, b| b.total_cmp(a));
+        let total = values.iter().fold(0.0, |sum, value| sum + value);
+        let top_share = (total > 0.0).then(|| {
+            let top10 = values.iter().take(10).fold(0.0, |sum, value| sum + value);
+            (values[0] / total * 100.0, top10 / total * 100.0)
+        });
+        Distribution {
+            unit,
+            buckets,
+            top_share,
+        }
+    }
+
+    // Programs hidden for being idle still count towards the first bucket
+    pub fn events(programs: &[BpfProgram], idle: usize) -> Distribution {
+        let values = programs
+            .iter()
+            .map(|prog| prog.events_per_second() as f64)
+            .collect();
+        Distribution::new("events/s", &EVENTS_BUCKETS, values, idle)
+    }
+
+    pub fn cpu(programs: &[BpfProgram], idle: usize) -> Distribution {
+        let values = programs
+            .iter()
+            .map(|prog| prog.cpu_time_percent())
+            .collect();
+        Distribution::new("CPU %", &CPU_BUCKETS, values, idle)
+    }
+
+    pub fn concentration(&self) -> String {
+        match self.top_share {
+            Some((top, top10)) => format!("Busiest: {:.0}%, top 10: {:.0}%", top, top10),
+            None => "Nothing ran".to_string(),
+        }
+    }
+
+    // Renders the buckets the way the runtime histogram does
+    pub fn lines(&self) -> Vec<String> {
+        let max = self
+            .buckets
+            .iter()
+            .map(|(_, count)| *count)
+            .max()
+            .unwrap_or(0);
+        let mut lines = vec![format!(
+            "{:>10} : {:<8} {}",
+            self.unit, "programs", "distribution"
+        )];
+        for (label, count) in &self.buckets {
+            let stars = (count * BAR_WIDTH).checked_div(max).unwrap_or(0);
+            lines.push(format!(
+                "{:>10} : {:<8} |{:<width$}|",
+                label,
+                count,
+                "*".repeat(stars),
+                width = BAR_WIDTH
+            ));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distribution() {
+        let distribution = Distribution::new(
+            "events/s",
+            &EVENTS_BUCKETS,
+            vec![0.0, 5.0, 10.0, 85.0, 2e6],
+            3,
+        );
+        assert_eq!(
+            distribution.buckets,
+            [
+                ("0", 4),
+                ("1+", 1),
+                ("10+", 2),
+                ("100+", 0),
+                ("1k+", 0),
+                ("10k+", 0),
+                ("100k+", 0),
+                ("1M+", 1)
+            ]
+        );
+        assert_eq!(distribution.concentration(), "Busiest: 100%, top 10: 100%");
+
+        let distribution = Distribution::new("CPU %", &CPU_BUCKETS, vec![0.001, 0.5, 0.5], 0);
+        assert_eq!(distribution.buckets[1], ("<0.01%", 1));
+        assert_eq!(distribution.buckets[3], ("0.1%+", 2));
+        assert_eq!(distribution.concentration(), "Busiest: 50%, top 10: 100%");
+    }
+
+    #[test]
+    fn test_lines() {
+        let distribution = Distribution::new("events/s", &EVENTS_BUCKETS, vec![], 0);
+        assert_eq!(distribution.concentration(), "Nothing ran");
+        assert_eq!(
+            distribution.lines()[1],
+            format!("{:>10} : {:<8} |{}|", "0", 0, " ".repeat(BAR_WIDTH))
+        );
+    }
+}
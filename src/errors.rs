@@ -0,0 +1,132 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::{field::Field, field::Visit, Event, Level, Subscriber};
+use tracing_subscriber::{layer::Context, Layer};
+
+// How many distinct errors are kept
+const MAX_ERRORS: usize = 50;
+
+// Errors older than this no longer count towards the indicator
+const RECENT: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecentError {
+    pub message: String,
+    // How many times it was logged, as collection errors tend to repeat every period
+    pub count: u64,
+    pub last_seen: Instant,
+}
+
+// The latest errors logged while collecting, so they can be shown without tailing the
+// journal. Repeats of an error update it rather than pushing the others out.
+#[derive(Debug, Default)]
+pub struct ErrorLog {
+    errors: VecDeque<RecentError>,
+}
+
+impl ErrorLog {
+    pub fn record(&mut self, message: String, now: Instant) {
+        let mut error = match self.errors.iter().position(|e| e.message == message) {
+            Some(i) => self.errors.remove(i).unwrap(),
+            None => RecentError {
+                message,
+                count: 0,
+                last_seen: now,
+            },
+        };
+        error.count += 1;
+        error.last_seen = now;
+        self.errors.push_front(error);
+        self.errors.truncate(MAX_ERRORS);
+    }
+
+    // Most recent first
+    pub fn errors(&self) -> impl Iterator<Item = &RecentError> {
+        self.errors.iter()
+    }
+
+    // Distinct errors logged in the last minute
+    pub fn recent(&self, now: Instant) -> usize {
+        self.errors
+            .iter()
+            .take_while(|e| now.duration_since(e.last_seen) < RECENT)
+            .count()
+    }
+}
+
+// Feeds every error logged through tracing into an ErrorLog
+pub struct ErrorLayer {
+    log: Arc<Mutex<ErrorLog>>,
+}
+
+impl ErrorLayer {
+    pub fn new(log: Arc<Mutex<ErrorLog>>) -> ErrorLayer {
+        ErrorLayer { log }
+    }
+}
+
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{:?}", value);
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for ErrorLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if *event.metadata().level() != Level::ERROR {
+            return;
+        }
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        self.log.lock().unwrap().record(visitor.0, Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let mut log = ErrorLog::default();
+        let start = Instant::now();
+        log.record("Failed to read from iterator".to_string(), start);
+        log.record("Failed to open netlink socket".to_string(), start);
+        log.record(
+            "Failed to read from iterator".to_string(),
+            start + Duration::from_secs(90),
+        );
+
+        let errors: Vec<_> = log.errors().collect();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].message, "Failed to read from iterator");
+        assert_eq!(errors[0].count, 2);
+        assert_eq!(log.recent(start + Duration::from_secs(90)), 1);
+    }
+}
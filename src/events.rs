@@ -0,0 +1,337 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{exporter::Exporter, rules::Alert, snapshot::Sample};
+use anyhow::{anyhow, Context, Result};
+use bpftop_core::bpf_program::BpfProgram;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::{BufRead, BufReader, Write},
+    os::unix::{
+        fs::FileTypeExt,
+        net::{UnixListener, UnixStream},
+    },
+    path::Path,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+use tracing::{info, warn};
+
+// Subscribers that cannot take an event within this long are disconnected, so a stuck
+// client does not stall the collector
+const WRITE_TIMEOUT: Duration = Duration::from_millis(100);
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    Sample,
+    Load,
+    Unload,
+    Alert,
+}
+
+const ALL_EVENTS: [EventKind; 4] = [
+    EventKind::Sample,
+    EventKind::Load,
+    EventKind::Unload,
+    EventKind::Alert,
+];
+
+// The line a client sends to choose the events it receives, all of them until it does
+#[derive(Debug, Deserialize)]
+struct Subscribe {
+    subscribe: HashSet<EventKind>,
+}
+
+#[derive(Debug, Serialize)]
+struct ProgramStats<'a> {
+    id: u32,
+    name: &'a str,
+    bpf_type: &'a str,
+    run_time_ns: u64,
+    run_cnt: u64,
+    events_per_second: i64,
+    cpu_time_percent: f64,
+    period_average_runtime_ns: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum Event<'a> {
+    Sample {
+        timestamp_ms: u64,
+        programs: Vec<ProgramStats<'a>>,
+    },
+    Load {
+        timestamp_ms: u64,
+        id: u32,
+        name: &'a str,
+        bpf_type: &'a str,
+    },
+    Unload {
+        timestamp_ms: u64,
+        id: u32,
+        name: &'a str,
+        bpf_type: &'a str,
+    },
+    Alert {
+        timestamp_ms: u64,
+        #[serde(flatten)]
+        alert: &'a Alert,
+    },
+}
+
+struct Subscriber {
+    stream: UnixStream,
+    events: Arc<Mutex<HashSet<EventKind>>>,
+}
+
+// Streams events to the clients connected to a Unix socket, as one JSON object per line
+pub struct EventSocket {
+    name: String,
+    subscribers: Arc<Mutex<Vec<Subscriber>>>,
+    // Name and type of the programs in the previous sample, None before the first one
+    programs: Option<HashMap<u32, (String, String)>>,
+}
+
+// A socket left behind by a previous run would make the bind fail. Removed before the
+// sandbox is applied, which does not allow deleting files.
+pub fn remove_stale_socket(path: &Path) -> Result<()> {
+    if fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_socket()) {
+        fs::remove_file(path)
+            .with_context(|| format!("Failed to remove stale socket {}", path.display()))?;
+    }
+    Ok(())
+}
+
+impl EventSocket {
+    pub fn bind(path: &Path) -> Result<Self> {
+        let listener = UnixListener::bind(path)
+            .with_context(|| format!("Failed to bind {}", path.display()))?;
+        let subscribers = Arc::new(Mutex::new(vec![]));
+        {
+            let subscribers = Arc::clone(&subscribers);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    match stream.and_then(subscribe) {
+                        Ok(subscriber) => subscribers.lock().unwrap().push(subscriber),
+                        Err(e) => warn!("Failed to accept an event subscriber: {}", e),
+                    }
+                }
+            });
+        }
+        Ok(EventSocket {
+            name: path.display().to_string(),
+            subscribers,
+            programs: None,
+        })
+    }
+
+    // Load and unload events for the programs that appeared or disappeared since the
+    // previous sample. Programs loaded before the first sample are not reported.
+    fn diff(
+        &mut self,
+        programs: &[BpfProgram],
+        timestamp_ms: u64,
+    ) -> Result<Vec<(EventKind, String)>> {
+        let current: HashMap<u32, (String, String)> = programs
+            .iter()
+            .map(|prog| (prog.id, (prog.name.clone(), prog.bpf_type.clone())))
+            .collect();
+        let Some(previous) = self.programs.replace(current) else {
+            return Ok(vec![]);
+        };
+        let mut lines = vec![];
+        for prog in programs
+            .iter()
+            .filter(|prog| !previous.contains_key(&prog.id))
+        {
+            let event = Event::Load {
+                timestamp_ms,
+                id: prog.id,
+                name: &prog.name,
+                bpf_type: &prog.bpf_type,
+            };
+            lines.push((EventKind::Load, serde_json::to_string(&event)?));
+        }
+        let current = self.programs.as_ref().unwrap();
+        for (id, (name, bpf_type)) in &previous {
+            if !current.contains_key(id) {
+                let event = Event::Unload {
+                    timestamp_ms,
+                    id: *id,
+                    name,
+                    bpf_type,
+                };
+                lines.push((EventKind::Unload, serde_json::to_string(&event)?));
+            }
+        }
+        Ok(lines)
+    }
+
+    // Writes a line to every subscriber of its kind of event, dropping the ones that fail
+    fn send(&self, kind: EventKind, line: &str) {
+        self.subscribers.lock().unwrap().retain_mut(|subscriber| {
+            if !subscriber.events.lock().unwrap().contains(&kind) {
+                return true;
+            }
+            let sent = subscriber
+                .stream
+                .write_all(line.as_bytes())
+                .and_then(|_| subscriber.stream.write_all(b"\n"));
+            if let Err(e) = &sent {
+                info!("Dropping an event subscriber: {}", e);
+            }
+            sent.is_ok()
+        });
+    }
+}
+
+// Sets up a new client, whose subscription lines are read on a thread of its own
+fn subscribe(stream: UnixStream) -> std::io::Result<Subscriber> {
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let events = Arc::new(Mutex::new(HashSet::from(ALL_EVENTS)));
+    let reader = BufReader::new(stream.try_clone()?);
+    {
+        let events = Arc::clone(&events);
+        thread::spawn(move || {
+            for line in reader.lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                match parse_subscribe(&line) {
+                    Ok(subscribe) => *events.lock().unwrap() = subscribe,
+                    Err(e) => warn!("Ignoring event subscription: {:#}", e),
+                }
+            }
+        });
+    }
+    Ok(Subscriber { stream, events })
+}
+
+fn parse_subscribe(line: &str) -> Result<HashSet<EventKind>> {
+    let subscribe: Subscribe = serde_json::from_str(line)
+        .map_err(|e| anyhow!("Invalid subscription '{}': {}", line.trim(), e))?;
+    Ok(subscribe.subscribe)
+}
+
+impl Exporter for EventSocket {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn export(&mut self, sample: &Sample) -> Result<()> {
+        let timestamp_ms = sample.timestamp.wall_ms;
+        for (kind, line) in self.diff(&sample.programs, timestamp_ms)? {
+            self.send(kind, &line);
+        }
+        let event = Event::Sample {
+            timestamp_ms,
+            programs: sample
+                .programs
+                .iter()
+                .map(|prog| ProgramStats {
+                    id: prog.id,
+                    name: &prog.name,
+                    bpf_type: &prog.bpf_type,
+                    run_time_ns: prog.run_time_ns,
+                    run_cnt: prog.run_cnt,
+                    events_per_second: prog.events_per_second(),
+                    cpu_time_percent: prog.cpu_time_percent(),
+                    period_average_runtime_ns: prog.period_average_runtime_ns(),
+                })
+                .collect(),
+        };
+        self.send(EventKind::Sample, &serde_json::to_string(&event)?);
+        for alert in &sample.alerts {
+            let event = Event::Alert {
+                timestamp_ms,
+                alert,
+            };
+            self.send(EventKind::Alert, &serde_json::to_string(&event)?);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn program(id: u32) -> BpfProgram {
+        BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: format!("prog{}", id),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes: vec![],
+        }
+    }
+
+    fn sample(ids: &[u32]) -> Sample {
+        Sample {
+            programs: ids.iter().map(|id| program(*id)).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_export() {
+        let (server, client) = UnixStream::pair().unwrap();
+        let mut socket = EventSocket {
+            name: "test".to_string(),
+            subscribers: Arc::new(Mutex::new(vec![subscribe(server).unwrap()])),
+            programs: None,
+        };
+        socket.export(&sample(&[1, 2])).unwrap();
+        socket.export(&sample(&[2, 3])).unwrap();
+
+        let events: Vec<serde_json::Value> = BufReader::new(client)
+            .lines()
+            .take(4)
+            .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+            .collect();
+        // Nothing is reported as loaded in the first sample
+        assert_eq!(events[0]["event"], "sample");
+        assert_eq!(events[0]["programs"].as_array().unwrap().len(), 2);
+        assert_eq!(events[1]["event"], "load");
+        assert_eq!(events[1]["id"], 3);
+        assert_eq!(events[2]["event"], "unload");
+        assert_eq!(events[2]["name"], "prog1");
+        assert_eq!(events[3]["event"], "sample");
+    }
+
+    #[test]
+    fn test_parse_subscribe() {
+        assert_eq!(
+            parse_subscribe(r#"{"subscribe": ["alert", "load"]}"#).unwrap(),
+            HashSet::from([EventKind::Alert, EventKind::Load])
+        );
+        assert!(parse_subscribe(r#"{"subscribe": ["maps"]}"#).is_err());
+        assert!(parse_subscribe("alerts").is_err());
+    }
+}
@@ -0,0 +1,324 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::snapshot::Sample;
+use anyhow::{Context, Result};
+use bpftop_core::helpers::format_bytes;
+use serde::Serialize;
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Read, Seek, SeekFrom, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+use tracing::{info, warn};
+
+// An output backend fed every sample the collector takes, once per period. Exporters run on
+// the collector thread, so anything slow belongs on a thread of its own.
+pub trait Exporter: Send {
+    fn name(&self) -> &str;
+
+    fn export(&mut self, sample: &Sample) -> Result<()>;
+}
+
+struct Registered {
+    exporter: Box<dyn Exporter>,
+    // Set while the exporter is failing, so each outage is only logged once
+    failing: bool,
+}
+
+// The exporters the collector feeds, registered at startup
+#[derive(Default)]
+pub struct Exporters {
+    exporters: Vec<Registered>,
+}
+
+impl Exporters {
+    pub fn register(&mut self, exporter: Box<dyn Exporter>) {
+        info!("Exporting samples to {}", exporter.name());
+        self.exporters.push(Registered {
+            exporter,
+            failing: false,
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.exporters.is_empty()
+    }
+
+    // A failing exporter does not stop the others, nor the collector
+    pub fn export(&mut self, sample: &Sample) {
+        for registered in self.exporters.iter_mut() {
+            match registered.exporter.export(sample) {
+                Ok(()) if registered.failing => {
+                    info!("Exporting to {} recovered", registered.exporter.name());
+                    registered.failing = false;
+                }
+                Ok(()) => {}
+                Err(e) if !registered.failing => {
+                    warn!(
+                        "Failed to export to {}: {:#}",
+                        registered.exporter.name(),
+                        e
+                    );
+                    registered.failing = true;
+                }
+                Err(_) => {}
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ProgramLine<'a> {
+    timestamp_ms: u64,
+    id: u32,
+    name: &'a str,
+    bpf_type: &'a str,
+    // Cumulative counters, so recordings can be replayed with --replay
+    run_time_ns: u64,
+    run_cnt: u64,
+    events_per_second: i64,
+    cpu_time_percent: f64,
+    period_average_runtime_ns: u64,
+    total_average_runtime_ns: u64,
+}
+
+// Appends the stats of every program to a file as one JSON line per program and period.
+// Once the file grows past its cap, the oldest half is dropped so it stays replayable.
+pub struct JsonLinesExporter {
+    name: String,
+    writer: BufWriter<File>,
+    size: u64,
+    max_size: u64,
+    // Number of times the file was trimmed, shown in the UI
+    trims: Arc<AtomicUsize>,
+}
+
+impl JsonLinesExporter {
+    pub fn open(path: &Path, max_size: u64, trims: Arc<AtomicUsize>) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        let size = file.metadata()?.len();
+        Ok(JsonLinesExporter {
+            name: path.display().to_string(),
+            writer: BufWriter::new(file),
+            size,
+            max_size,
+            trims,
+        })
+    }
+
+    // Keeps the newest lines that fit in half the cap. The file is rewritten in place, as
+    // the sandbox only allows writing the file itself and not creating one next to it.
+    fn trim(&mut self) -> Result<()> {
+        let file = self.writer.get_mut();
+        file.seek(SeekFrom::Start(self.size - self.max_size / 2))?;
+        let mut tail = vec![];
+        file.read_to_end(&mut tail)?;
+        // The tail most likely starts halfway through a line
+        let kept = match tail.iter().position(|&b| b == b'\n') {
+            Some(newline) => &tail[newline + 1..],
+            None => &[],
+        };
+        file.set_len(0)?;
+        file.write_all(kept)?;
+        self.size = kept.len() as u64;
+        let trims = self.trims.fetch_add(1, Ordering::Relaxed) + 1;
+        if trims == 1 {
+            warn!(
+                "{} reached its {} cap, dropping its oldest periods",
+                self.name,
+                format_bytes(self.max_size)
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Exporter for JsonLinesExporter {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn export(&mut self, sample: &Sample) -> Result<()> {
+        let timestamp_ms = sample.timestamp.wall_ms;
+        // Programs without a full period of stats yet have nothing to report
+        for prog in sample.programs.iter().filter(|prog| prog.period_ns > 0) {
+            let line = ProgramLine {
+                timestamp_ms,
+                id: prog.id,
+                name: &prog.name,
+                bpf_type: &prog.bpf_type,
+                run_time_ns: prog.run_time_ns,
+                run_cnt: prog.run_cnt,
+                events_per_second: prog.events_per_second(),
+                cpu_time_percent: prog.cpu_time_percent(),
+                period_average_runtime_ns: prog.period_average_runtime_ns(),
+                total_average_runtime_ns: prog.total_average_runtime_ns(),
+            };
+            let mut json = serde_json::to_vec(&line)?;
+            json.push(b'\n');
+            self.writer.write_all(&json)?;
+            self.size += json.len() as u64;
+        }
+        self.writer.flush()?;
+        if self.size > self.max_size {
+            self.trim()?;
+        }
+        Ok(())
+    }
+}
+
+// Target of the per-program sample events, which only go to journald
+pub const SAMPLE_TARGET: &str = "bpftop::sample";
+
+// Logs the stats of every program to journald once per period, as structured fields that
+// journalctl can match on, e.g. journalctl BPFTOP_PROG_NAME=xdp_lb
+pub struct JournaldExporter;
+
+impl Exporter for JournaldExporter {
+    fn name(&self) -> &str {
+        "journald"
+    }
+
+    fn export(&mut self, sample: &Sample) -> Result<()> {
+        for prog in sample.programs.iter().filter(|prog| prog.period_ns > 0) {
+            let cpu = prog.cpu_time_percent();
+            info!(
+                target: SAMPLE_TARGET,
+                prog_id = prog.id,
+                prog_name = prog.name,
+                prog_type = prog.bpf_type,
+                cpu = %format_args!("{:.2}", cpu),
+                events_per_second = prog.events_per_second(),
+                avg_runtime_ns = prog.period_average_runtime_ns(),
+                run_cnt = prog.run_cnt,
+                run_time_ns = prog.run_time_ns,
+                "Program {} {} ({}): {:.2}% CPU, {} events/s, {} ns per event",
+                prog.id,
+                prog.name,
+                prog.bpf_type,
+                cpu,
+                prog.events_per_second(),
+                prog.period_average_runtime_ns(),
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use bpftop_core::{bpf_program::BpfProgram, sampler::Timestamp};
+    use std::{env, fs, sync::Mutex, time::Instant};
+
+    struct Recorder {
+        calls: Arc<Mutex<Vec<bool>>>,
+        fail: bool,
+    }
+
+    impl Exporter for Recorder {
+        fn name(&self) -> &str {
+            "recorder"
+        }
+
+        fn export(&mut self, _sample: &Sample) -> Result<()> {
+            self.calls.lock().unwrap().push(self.fail);
+            if self.fail {
+                return Err(anyhow!("unavailable"));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_export() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let mut exporters = Exporters::default();
+        assert!(exporters.is_empty());
+        exporters.register(Box::new(Recorder {
+            calls: Arc::clone(&calls),
+            fail: true,
+        }));
+        exporters.register(Box::new(Recorder {
+            calls: Arc::clone(&calls),
+            fail: false,
+        }));
+
+        exporters.export(&Sample::default());
+        exporters.export(&Sample::default());
+        // The failing exporter is retried every period and does not block the other one
+        assert_eq!(*calls.lock().unwrap(), vec![true, false, true, false]);
+        assert!(exporters.exporters[0].failing);
+        assert!(!exporters.exporters[1].failing);
+    }
+
+    #[test]
+    fn test_json_lines_cap() {
+        let path = env::temp_dir().join(format!("bpftop-export-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let trims = Arc::new(AtomicUsize::new(0));
+        let mut exporter = JsonLinesExporter::open(&path, 2048, Arc::clone(&trims)).unwrap();
+        let mut sample = Sample::default();
+        sample.programs.push(BpfProgram {
+            id: 1,
+            bpf_type: "Kprobe".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        });
+        for wall_ms in 0..100 {
+            sample.timestamp = Timestamp {
+                instant: Instant::now(),
+                wall_ms,
+            };
+            exporter.export(&sample).unwrap();
+        }
+
+        // Only whole lines of the newest periods are left
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(trims.load(Ordering::Relaxed) > 0);
+        assert!(contents.len() <= 2048);
+        let timestamps: Vec<u64> = contents
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<serde_json::Value>(line).unwrap()["timestamp_ms"]
+                    .as_u64()
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(timestamps.last(), Some(&99));
+        assert!(timestamps.windows(2).all(|pair| pair[1] == pair[0] + 1));
+    }
+}
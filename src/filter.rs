@@ -0,0 +1,77 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crossterm::event::Event;
+use tui_input::{backend::crossterm::EventHandler, Input};
+
+// The filter typed by the user, along with the needle it is matched with. The needle is
+// only lowercased again when the input is edited, not for every row it is matched against.
+#[derive(Clone, Debug, Default)]
+pub struct Filter {
+    input: Input,
+    needle: String,
+}
+
+impl Filter {
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    pub fn handle_event(&mut self, event: &Event) {
+        if self.input.handle_event(event).is_some() {
+            self.needle = self.input.value().to_lowercase();
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.needle.is_empty()
+    }
+
+    // Case insensitive substring match, without allocating a lowercase copy of the
+    // haystack. Only ASCII letters are folded, which covers program, map and pod names.
+    pub fn matches(&self, haystack: &str) -> bool {
+        let needle = self.needle.as_bytes();
+        needle.is_empty()
+            || haystack
+                .as_bytes()
+                .windows(needle.len())
+                .any(|window| window.eq_ignore_ascii_case(needle))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[test]
+    fn test_matches() {
+        let mut filter = Filter::default();
+        assert!(filter.matches("anything"));
+
+        for c in "TCP_".chars() {
+            filter.handle_event(&Event::Key(KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::NONE,
+            )));
+        }
+        assert_eq!(filter.input().value(), "TCP_");
+        assert!(filter.matches("trace_tcp_connect"));
+        assert!(!filter.matches("tcp"));
+        assert!(!filter.matches("udp_sendmsg"));
+    }
+}
@@ -0,0 +1,134 @@
+use anyhow::{Context, Result};
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use bpftop_core::{
+    bpf_attachment::{get_prog_attachments, BpfAttachment},
+    helpers::program_type_to_string,
+};
+use libbpf_rs::query::ProgInfoIter;
+use std::{collections::HashMap, fs, path::Path, thread, time::Duration};
+
+// Cumulative runtime of every named program, keyed by program ID
+fn runtimes() -> HashMap<u32, (String, String, u64)> {
+    ProgInfoIter::default()
+        .filter_map(|prog| {
+            let name = prog.name.to_str().ok()?.to_string();
+            if name.is_empty() {
+                return None;
+            }
+            Some((
+                prog.id,
+                (program_type_to_string(prog.ty), name, prog.run_time_ns),
+            ))
+        })
+        .collect()
+}
+
+// Frames may not contain the stack separator
+fn frame(name: &str) -> String {
+    name.replace(';', "_")
+}
+
+// Builds the folded stacks of one program, rooted at each point it is attached to. Runtime
+// is not accounted per link, so it is split evenly between the program's attachments.
+fn fold(bpf_type: &str, name: &str, attachments: &[BpfAttachment], runtime_ns: u64) -> Vec<String> {
+    let leaf = format!("{};{}", frame(bpf_type), frame(name));
+    if attachments.is_empty() {
+        return vec![format!("{} {}", leaf, runtime_ns)];
+    }
+
+    let share = runtime_ns / attachments.len() as u64;
+    let remainder = runtime_ns % attachments.len() as u64;
+    attachments
+        .iter()
+        .enumerate()
+        .map(|(i, attachment)| {
+            let root = if attachment.target.is_empty() {
+                &attachment.link_type
+            } else {
+                &attachment.target
+            };
+            let runtime_ns = if i == 0 { share + remainder } else { share };
+            format!("{};{} {}", frame(root), leaf, runtime_ns)
+        })
+        .collect()
+}
+
+// Samples program runtime for `duration` and writes it to `path` in the folded-stacks format
+// read by flamegraph.pl and speedscope, e.g. "iface eth0;xdp;xdp_prog 1234567". Returns the
+// number of stacks written.
+pub fn export_folded(path: &Path, duration: Duration) -> Result<usize> {
+    let before = runtimes();
+    thread::sleep(duration);
+
+    let mut stacks = vec![];
+    for (id, (bpf_type, name, run_time_ns)) in runtimes() {
+        // Programs loaded during the window ran entirely within it
+        let prev_runtime_ns = before.get(&id).map_or(0, |(_, _, runtime)| *runtime);
+        let runtime_ns = run_time_ns.saturating_sub(prev_runtime_ns);
+        if runtime_ns == 0 {
+            continue;
+        }
+        stacks.extend(fold(
+            &bpf_type,
+            &name,
+            &get_prog_attachments(id),
+            runtime_ns,
+        ));
+    }
+    stacks.sort();
+
+    let mut contents = stacks.join("\n");
+    if !contents.is_empty() {
+        contents.push('\n');
+    }
+    fs::write(path, contents).context(format!("Failed to write {}", path.display()))?;
+    Ok(stacks.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold() {
+        assert_eq!(
+            fold("kprobe", "trace;open", &[], 100),
+            vec!["kprobe;trace_open 100"]
+        );
+
+        let attachments = vec![
+            BpfAttachment {
+                link_id: 1,
+                link_type: "xdp".to_string(),
+                target: "iface eth0".to_string(),
+                attach_type: String::new(),
+            },
+            BpfAttachment {
+                link_id: 2,
+                link_type: "tracing".to_string(),
+                target: String::new(),
+                attach_type: String::new(),
+            },
+        ];
+        assert_eq!(
+            fold("xdp", "xdp_prog", &attachments, 101),
+            vec!["iface eth0;xdp;xdp_prog 51", "tracing;xdp;xdp_prog 50"]
+        );
+    }
+}
@@ -0,0 +1,321 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{exporter::Exporter, snapshot::Sample};
+use anyhow::{anyhow, Context, Result};
+use std::{
+    env, fs,
+    net::{TcpStream, ToSocketAddrs},
+    sync::{
+        mpsc::{self, Receiver, SyncSender, TrySendError},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+use tracing::info;
+use tungstenite::{
+    client::IntoClientRequest, client_tls_with_config, http::HeaderValue, stream::MaybeTlsStream,
+    Message, WebSocket,
+};
+
+// Grafana service account token sent with the handshake, read from the environment so it
+// does not show up in the process list
+pub const TOKEN_ENV: &str = "BPFTOP_GRAFANA_TOKEN";
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const WRITE_TIMEOUT: Duration = Duration::from_secs(2);
+// How long to wait after a failed connection before trying again
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(5);
+// Samples waiting for the connection. Older ones are dropped rather than queued, as a
+// live dashboard only cares about the latest.
+const QUEUED_SAMPLES: usize = 2;
+
+#[derive(Debug, PartialEq)]
+struct Endpoint {
+    url: String,
+    tls: bool,
+    host: String,
+    port: u16,
+    path: String,
+}
+
+// Parses ws://host[:port]/path and wss://host[:port]/path URLs
+fn parse_url(url: &str) -> Result<Endpoint> {
+    let (tls, rest) = if let Some(rest) = url.strip_prefix("wss://") {
+        (true, rest)
+    } else if let Some(rest) = url.strip_prefix("ws://") {
+        (false, rest)
+    } else {
+        return Err(anyhow!("Expected a ws:// or wss:// URL, got {}", url));
+    };
+    let (authority, path) = match rest.find('/') {
+        Some(slash) => rest.split_at(slash),
+        None => (rest, "/"),
+    };
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (
+            host,
+            port.parse()
+                .with_context(|| format!("Invalid port in {}", url))?,
+        ),
+        None => (authority, if tls { 443 } else { 80 }),
+    };
+    if host.is_empty() {
+        return Err(anyhow!("Missing host in {}", url));
+    }
+    Ok(Endpoint {
+        url: url.to_string(),
+        tls,
+        host: host.to_string(),
+        port,
+        path: path.to_string(),
+    })
+}
+
+// Opens the WebSocket, over TLS for wss:// URLs. The stream only goes one way, so nothing
+// is read after the handshake.
+fn connect(
+    endpoint: &Endpoint,
+    token: Option<&str>,
+) -> Result<WebSocket<MaybeTlsStream<TcpStream>>> {
+    let mut request = endpoint.url.as_str().into_client_request()?;
+    if let Some(token) = token {
+        request.headers_mut().insert(
+            "Authorization",
+            HeaderValue::from_str(&format!("Bearer {}", token))?,
+        );
+    }
+    let address = (endpoint.host.as_str(), endpoint.port)
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| anyhow!("{} did not resolve", endpoint.host))?;
+    let tcp = TcpStream::connect_timeout(&address, CONNECT_TIMEOUT)?;
+    tcp.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    tcp.set_read_timeout(Some(CONNECT_TIMEOUT))?;
+    let (socket, _) = client_tls_with_config(request, tcp, None, None)
+        .map_err(|e| anyhow!("Handshake failed: {}", e))?;
+    Ok(socket)
+}
+
+// Tag values may not contain unescaped commas, spaces or equal signs
+fn escape_tag(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, ',' | ' ' | '=') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// Formats a sample as Influx line protocol, which Grafana Live turns into one data frame
+// per program on the stream/<stream id>/bpftop channel
+fn line_protocol(sample: &Sample, host: &str) -> String {
+    let timestamp_ns = sample.timestamp.wall_ms * 1_000_000;
+    sample
+        .programs
+        .iter()
+        // Programs without a full period of stats yet have nothing to report
+        .filter(|prog| prog.period_ns > 0)
+        .map(|prog| {
+            format!(
+                "bpftop,host={},prog_id={},prog_name={},prog_type={} cpu_percent={},events_per_second={}i,avg_runtime_ns={}i {}\n",
+                escape_tag(host),
+                prog.id,
+                escape_tag(&prog.name),
+                escape_tag(&prog.bpf_type),
+                prog.cpu_time_percent(),
+                prog.events_per_second(),
+                prog.period_average_runtime_ns(),
+                timestamp_ns
+            )
+        })
+        .collect()
+}
+
+// Keeps the connection up and sends whatever the collector queued, recording the last
+// failure for the exporter to report
+fn send_loop(
+    endpoint: Endpoint,
+    token: Option<String>,
+    samples: Receiver<String>,
+    failure: Arc<Mutex<Option<String>>>,
+) {
+    let mut socket: Option<WebSocket<MaybeTlsStream<TcpStream>>> = None;
+    let mut last_attempt: Option<Instant> = None;
+    for lines in samples {
+        if socket.is_none() {
+            if last_attempt.is_some_and(|last| last.elapsed() < RECONNECT_INTERVAL) {
+                continue;
+            }
+            last_attempt = Some(Instant::now());
+            match connect(&endpoint, token.as_deref()) {
+                Ok(connected) => socket = Some(connected),
+                Err(e) => {
+                    *failure.lock().unwrap() = Some(format!("{:#}", e));
+                    continue;
+                }
+            }
+        }
+        if let Some(connected) = socket.as_mut() {
+            match connected.send(Message::text(lines)) {
+                Ok(()) => *failure.lock().unwrap() = None,
+                Err(e) => {
+                    *failure.lock().unwrap() = Some(format!("{:#}", e));
+                    socket = None;
+                }
+            }
+        }
+    }
+}
+
+// Streams every sample to a Grafana Live push endpoint over a WebSocket, so dashboards
+// update as samples are taken rather than on a scrape interval. Sending happens on a
+// thread of its own, so a slow or unreachable Grafana does not hold up the collector.
+pub struct GrafanaLive {
+    name: String,
+    host: String,
+    samples: SyncSender<String>,
+    failure: Arc<Mutex<Option<String>>>,
+}
+
+impl GrafanaLive {
+    pub fn connect(url: &str) -> Result<Self> {
+        let endpoint = parse_url(url)?;
+        let token = env::var(TOKEN_ENV).ok();
+        if token.is_none() {
+            info!(
+                "{} is not set, connecting to Grafana Live without a token",
+                TOKEN_ENV
+            );
+        }
+        let (samples, queued) = mpsc::sync_channel(QUEUED_SAMPLES);
+        let failure = Arc::new(Mutex::new(None));
+        {
+            let failure = Arc::clone(&failure);
+            thread::spawn(move || send_loop(endpoint, token, queued, failure));
+        }
+        Ok(GrafanaLive {
+            name: url.to_string(),
+            host: fs::read_to_string("/proc/sys/kernel/hostname")
+                .map(|hostname| hostname.trim().to_string())
+                .unwrap_or_default(),
+            samples,
+            failure,
+        })
+    }
+}
+
+impl Exporter for GrafanaLive {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn export(&mut self, sample: &Sample) -> Result<()> {
+        match self.samples.try_send(line_protocol(sample, &self.host)) {
+            Ok(()) | Err(TrySendError::Full(_)) => {}
+            Err(TrySendError::Disconnected(_)) => return Err(anyhow!("Sender thread exited")),
+        }
+        match self.failure.lock().unwrap().as_ref() {
+            Some(failure) => Err(anyhow!("{}", failure)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bpftop_core::{bpf_program::BpfProgram, sampler::Timestamp};
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(
+            parse_url("wss://grafana.example.com/api/live/push/bpftop").unwrap(),
+            Endpoint {
+                url: "wss://grafana.example.com/api/live/push/bpftop".to_string(),
+                tls: true,
+                host: "grafana.example.com".to_string(),
+                port: 443,
+                path: "/api/live/push/bpftop".to_string(),
+            }
+        );
+        assert_eq!(parse_url("ws://127.0.0.1:3000").unwrap().port, 3000);
+        assert_eq!(parse_url("ws://127.0.0.1:3000").unwrap().path, "/");
+        assert!(parse_url("http://grafana/api/live/push/bpftop").is_err());
+    }
+
+    #[test]
+    // The handshake callback's signature is tungstenite's
+    #[allow(clippy::result_large_err)]
+    fn test_connect() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!(
+            "ws://{}/api/live/push/bpftop",
+            listener.local_addr().unwrap()
+        );
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut authorization = None;
+            let mut socket = tungstenite::accept_hdr(
+                stream,
+                |request: &tungstenite::handshake::server::Request, response| {
+                    authorization = request.headers().get("Authorization").cloned();
+                    Ok(response)
+                },
+            )
+            .unwrap();
+            (authorization, socket.read().unwrap())
+        });
+
+        let mut socket = connect(&parse_url(&url).unwrap(), Some("token")).unwrap();
+        socket.send(Message::text("bpftop cpu_percent=1")).unwrap();
+        let (authorization, message) = server.join().unwrap();
+        assert_eq!(authorization.unwrap(), "Bearer token");
+        assert_eq!(message, Message::text("bpftop cpu_percent=1"));
+    }
+
+    #[test]
+    fn test_line_protocol() {
+        let mut sample = Sample {
+            timestamp: Timestamp {
+                instant: Instant::now(),
+                wall_ms: 1_700_000_000_000,
+            },
+            ..Default::default()
+        };
+        sample.programs.push(BpfProgram {
+            id: 42,
+            bpf_type: "Xdp".to_string(),
+            name: "xdp lb".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 25_000_000,
+            prev_run_cnt: 0,
+            run_cnt: 1000,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        });
+        assert_eq!(
+            line_protocol(&sample, "edge-1"),
+            "bpftop,host=edge-1,prog_id=42,prog_name=xdp\\ lb,prog_type=Xdp cpu_percent=2.5,events_per_second=1000i,avg_runtime_ns=25000i 1700000000000000000\n"
+        );
+    }
+}
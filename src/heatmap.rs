@@ -0,0 +1,99 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::app::{PeriodMeasure, HISTORY_PERIODS};
+use circular_buffer::CircularBuffer;
+
+// Shades of the heatmap above idle, from a trickle of CPU to the busiest cell shown
+pub const HEAT_LEVELS: usize = 5;
+
+// Average CPU % of a program in each of `count` time buckets covering the history, oldest
+// first and ending with the last period. Buckets from before the program was first sampled
+// are None.
+pub fn cpu_buckets(
+    periods: &CircularBuffer<HISTORY_PERIODS, PeriodMeasure>,
+    count: usize,
+) -> Vec<Option<f64>> {
+    let count = count.clamp(1, HISTORY_PERIODS);
+    let per_bucket = HISTORY_PERIODS.div_ceil(count);
+    // Periods are laid out against the right edge, so the newest lands in the last bucket
+    let first_slot = count * per_bucket - periods.len();
+    let mut sums = vec![(0.0, 0); count];
+    for (i, period) in periods.iter().enumerate() {
+        let (sum, n) = &mut sums[(first_slot + i) / per_bucket];
+        *sum += period.cpu_time_percent;
+        *n += 1;
+    }
+    sums.into_iter()
+        .map(|(sum, n)| (n > 0).then(|| sum / n as f64))
+        .collect()
+}
+
+// Shade of a cell relative to the busiest one shown: 0 when idle, otherwise 1 to
+// HEAT_LEVELS
+pub fn heat_level(cpu_percent: f64, max_cpu_percent: f64) -> usize {
+    if cpu_percent <= 0.0 || max_cpu_percent <= 0.0 {
+        return 0;
+    }
+    ((cpu_percent / max_cpu_percent * HEAT_LEVELS as f64).ceil() as usize).clamp(1, HEAT_LEVELS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bpftop_core::sampler::Timestamp;
+    use std::time::Instant;
+
+    fn periods(cpu: &[f64]) -> CircularBuffer<HISTORY_PERIODS, PeriodMeasure> {
+        let timestamp = Timestamp {
+            instant: Instant::now(),
+            wall_ms: 0,
+        };
+        cpu.iter()
+            .map(|cpu_time_percent| PeriodMeasure {
+                timestamp,
+                cpu_time_percent: *cpu_time_percent,
+                events_per_sec: 0,
+                average_runtime_ns: 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cpu_buckets() {
+        let full: Vec<f64> = (0..HISTORY_PERIODS).map(|i| i as f64).collect();
+        let buckets = cpu_buckets(&periods(&full), HISTORY_PERIODS);
+        assert_eq!(buckets.len(), HISTORY_PERIODS);
+        assert_eq!(buckets[59], Some(59.0));
+
+        // Two periods per bucket, with the program only sampled for the last three
+        let buckets = cpu_buckets(&periods(&[1.0, 2.0, 4.0]), 30);
+        assert_eq!(buckets.len(), 30);
+        assert_eq!(buckets[27], None);
+        assert_eq!(buckets[28], Some(1.0));
+        assert_eq!(buckets[29], Some(3.0));
+    }
+
+    #[test]
+    fn test_heat_level() {
+        assert_eq!(heat_level(0.0, 10.0), 0);
+        assert_eq!(heat_level(0.01, 10.0), 1);
+        assert_eq!(heat_level(5.0, 10.0), 3);
+        assert_eq!(heat_level(10.0, 10.0), HEAT_LEVELS);
+        assert_eq!(heat_level(1.0, 0.0), 0);
+    }
+}
@@ -0,0 +1,199 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    latency::{LatencySkel, LatencySkelBuilder},
+    profile::{attach_target, read_value},
+};
+use anyhow::Result;
+use libbpf_rs::{
+    skel::{OpenSkel, Skel, SkelBuilder},
+    MapCore, MapFlags, OpenObject,
+};
+use std::{
+    mem::MaybeUninit,
+    os::fd::AsRawFd,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+// Must match MAX_SLOTS in latency.bpf.c
+const MAX_SLOTS: usize = 64;
+
+// Width of the distribution column, in characters
+const BAR_WIDTH: usize = 40;
+
+// Number of runs per power-of-two runtime bucket, where bucket `i` holds
+// runtimes in [2^i, 2^(i+1)) ns and bucket 0 also holds 0 ns
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Histogram {
+    pub buckets: Vec<u64>,
+}
+
+impl Histogram {
+    pub fn total(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+
+    // Renders the buckets the way bcc tools print log2 histograms, skipping
+    // empty buckets above the highest populated one
+    pub fn lines(&self) -> Vec<String> {
+        let Some(last) = self.buckets.iter().rposition(|count| *count > 0) else {
+            return vec![];
+        };
+        let max = self.buckets.iter().copied().max().unwrap_or_default();
+
+        let mut lines = vec![format!(
+            "{:>20} : {:<8} {}",
+            "runtime (ns)", "count", "distribution"
+        )];
+        for (slot, count) in self.buckets.iter().take(last + 1).enumerate() {
+            let (low, high) = bucket_range(slot);
+            let stars = (*count as u128 * BAR_WIDTH as u128 / max as u128) as usize;
+            lines.push(format!(
+                "{:>9} -> {:<9} : {:<8} |{:<width$}|",
+                low,
+                high,
+                count,
+                "*".repeat(stars),
+                width = BAR_WIDTH
+            ));
+        }
+        lines
+    }
+}
+
+fn bucket_range(slot: usize) -> (u64, u64) {
+    if slot == 0 {
+        return (0, 1);
+    }
+    let low = 1u64 << slot;
+    (low, low.saturating_sub(1).saturating_add(low))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum HistogramState {
+    Collecting(u32, Histogram),
+    Failed(u32, String),
+}
+
+// Attaches the latency programs to `prog_id` and refreshes `state` every second until
+// `running` is cleared, at which point the programs are detached. `state` is only
+// written while `running` is set, so a stopped collector never overwrites a newer one.
+pub fn collect_histogram(
+    prog_id: u32,
+    running: Arc<AtomicBool>,
+    state: Arc<Mutex<Option<HistogramState>>>,
+) {
+    if let Err(e) = run_collector(prog_id, &running, &state) {
+        let mut state = state.lock().unwrap();
+        if running.load(Ordering::Relaxed) {
+            *state = Some(HistogramState::Failed(prog_id, e.to_string()));
+        }
+    }
+}
+
+// Loads the latency programs and attaches them to the entry function of `prog_id`
+pub fn attach_latency(
+    prog_id: u32,
+    open_object: &mut MaybeUninit<OpenObject>,
+) -> Result<LatencySkel<'_>> {
+    let (prog_fd, func_name) = attach_target(prog_id)?;
+
+    let skel_builder = LatencySkelBuilder::default();
+    let mut open_skel = skel_builder.open(open_object)?;
+    open_skel
+        .progs
+        .fentry_XXX
+        .set_attach_target(prog_fd.as_raw_fd(), Some(func_name.clone()))?;
+    open_skel
+        .progs
+        .fexit_XXX
+        .set_attach_target(prog_fd.as_raw_fd(), Some(func_name))?;
+    let mut skel = open_skel.load()?;
+    skel.attach()?;
+    Ok(skel)
+}
+
+fn run_collector(
+    prog_id: u32,
+    running: &AtomicBool,
+    state: &Mutex<Option<HistogramState>>,
+) -> Result<()> {
+    let mut open_object = MaybeUninit::uninit();
+    let skel = attach_latency(prog_id, &mut open_object)?;
+
+    loop {
+        thread::sleep(Duration::from_secs(1));
+
+        let mut buckets = vec![0; MAX_SLOTS];
+        for (slot, bucket) in buckets.iter_mut().enumerate() {
+            let key = (slot as u32).to_ne_bytes();
+            *bucket = skel
+                .maps
+                .hist
+                .lookup_percpu(&key, MapFlags::ANY)?
+                .unwrap_or_default()
+                .iter()
+                .map(|bytes| read_value::<u64>(bytes))
+                .sum();
+        }
+
+        let mut state = state.lock().unwrap();
+        if !running.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        *state = Some(HistogramState::Collecting(prog_id, Histogram { buckets }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_range() {
+        assert_eq!(bucket_range(0), (0, 1));
+        assert_eq!(bucket_range(1), (2, 3));
+        assert_eq!(bucket_range(10), (1024, 2047));
+        assert_eq!(bucket_range(63), (1 << 63, u64::MAX));
+    }
+
+    #[test]
+    fn test_lines() {
+        assert!(Histogram::default().lines().is_empty());
+
+        let histogram = Histogram {
+            buckets: vec![0, 2, 4, 0, 0],
+        };
+        let lines = histogram.lines();
+        assert_eq!(histogram.total(), 6);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[2],
+            format!("{:>9} -> {:<9} : {:<8} |{:<40}|", 2, 3, 2, "*".repeat(20))
+        );
+        assert_eq!(
+            lines[3],
+            format!("{:>9} -> {:<9} : {:<8} |{}|", 4, 7, 4, "*".repeat(40))
+        );
+    }
+}
@@ -0,0 +1,191 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    exporter::Exporter,
+    loaders::LoaderRegistry,
+    snapshot::{Sample, Snapshot},
+};
+use anyhow::{Context, Result};
+use bpftop_core::bpf_attachment::get_links;
+use serde::Serialize;
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+use tracing::{info, warn};
+
+// Clients that do not send their request or read the response within this long are dropped
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(2);
+
+// Serves the latest sample as JSON over HTTP. Requests are answered one at a time on a
+// thread of its own, as they only serialize what the collector already sampled.
+pub struct HttpApi {
+    name: String,
+    latest: Arc<Mutex<Sample>>,
+}
+
+impl HttpApi {
+    pub fn serve(address: &str, loaders: Arc<Mutex<LoaderRegistry>>) -> Result<Self> {
+        let listener =
+            TcpListener::bind(address).with_context(|| format!("Failed to bind {}", address))?;
+        info!("Serving the JSON API on http://{}", listener.local_addr()?);
+        let latest = Arc::new(Mutex::new(Sample::default()));
+        {
+            let latest = Arc::clone(&latest);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let handled = stream.and_then(|stream| handle(stream, &latest, &loaders));
+                    if let Err(e) = handled {
+                        warn!("Failed to answer an API request: {}", e);
+                    }
+                }
+            });
+        }
+        Ok(HttpApi {
+            name: format!("http://{}", address),
+            latest,
+        })
+    }
+}
+
+impl Exporter for HttpApi {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn export(&mut self, sample: &Sample) -> Result<()> {
+        *self.latest.lock().unwrap() = sample.clone();
+        Ok(())
+    }
+}
+
+fn handle(
+    mut stream: TcpStream,
+    latest: &Mutex<Sample>,
+    loaders: &Mutex<LoaderRegistry>,
+) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+
+    let mut parts = request_line.split_whitespace();
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some(path)) => {
+            let snapshot = Snapshot::new(
+                &latest.lock().unwrap(),
+                &get_links(),
+                &loaders.lock().unwrap(),
+            );
+            route(path, &snapshot)
+        }
+        (Some(_), Some(_)) => (405, error("Only GET is supported")),
+        _ => (400, error("Malformed request")),
+    };
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        _ => "Method Not Allowed",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    )
+}
+
+fn error(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+fn json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+// Answers a GET of path with a status and JSON body
+fn route(path: &str, snapshot: &Snapshot) -> (u16, String) {
+    // Query strings are not used by any endpoint
+    let path = path.split('?').next().unwrap_or_default();
+    match path.trim_end_matches('/') {
+        "/api/v1/programs" => (200, json(&snapshot.programs())),
+        "/api/v1/maps" => (200, json(&snapshot.maps())),
+        path => {
+            let program = path
+                .strip_prefix("/api/v1/programs/")
+                .and_then(|id| id.parse::<u32>().ok())
+                .map(|id| snapshot.programs().iter().find(|prog| prog.id == id));
+            match program {
+                Some(Some(program)) => (200, json(program)),
+                Some(None) => (404, error("No such program")),
+                None => (404, error("Not found")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bpftop_core::bpf_program::BpfProgram;
+    use std::time::Instant;
+
+    #[test]
+    fn test_route() {
+        let sample = Sample {
+            programs: vec![BpfProgram {
+                id: 7,
+                bpf_type: "Xdp".to_string(),
+                name: "xdp_prog".to_string(),
+                tag: String::new(),
+                prev_runtime_ns: 0,
+                run_time_ns: 100,
+                prev_run_cnt: 0,
+                run_cnt: 10,
+                instant: Instant::now(),
+                period_ns: 1_000_000_000,
+                processes: vec![],
+            }],
+            ..Default::default()
+        };
+        let snapshot = Snapshot::new(&sample, &[], &LoaderRegistry::default());
+
+        let (status, body) = route("/api/v1/programs", &snapshot);
+        assert_eq!(status, 200);
+        let programs: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(programs[0]["name"], "xdp_prog");
+
+        let (status, body) = route("/api/v1/programs/7/", &snapshot);
+        assert_eq!(status, 200);
+        let program: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(program["events_per_second"], 10);
+
+        assert_eq!(route("/api/v1/programs/8", &snapshot).0, 404);
+        assert_eq!(route("/api/v1/programs/abc", &snapshot).0, 404);
+        assert_eq!(
+            route("/api/v1/maps?x=1", &snapshot),
+            (200, "[]".to_string())
+        );
+    }
+}
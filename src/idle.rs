@@ -0,0 +1,108 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use bpftop_core::bpf_program::BpfProgram;
+use std::collections::HashMap;
+
+// Programs are considered idle after this many periods without events, unless configured
+pub const DEFAULT_IDLE_PERIODS: u32 = 5;
+
+// Counts the consecutive periods each program ran no events in, to hide the idle ones
+#[derive(Debug)]
+pub struct IdleTracker {
+    periods: u32,
+    idle: HashMap<u32, u32>,
+}
+
+impl Default for IdleTracker {
+    fn default() -> Self {
+        IdleTracker::new(DEFAULT_IDLE_PERIODS)
+    }
+}
+
+impl IdleTracker {
+    pub fn new(periods: u32) -> Self {
+        IdleTracker {
+            periods: periods.max(1),
+            idle: HashMap::new(),
+        }
+    }
+
+    // Programs sampled for the first time have no period yet and are not counted, and
+    // programs that are no longer loaded are forgotten
+    pub fn observe(&mut self, programs: &[BpfProgram]) {
+        let mut idle = HashMap::with_capacity(programs.len());
+        for prog in programs.iter().filter(|prog| prog.period_ns > 0) {
+            let periods = if prog.run_cnt_delta() == 0 {
+                self.idle.get(&prog.id).copied().unwrap_or(0) + 1
+            } else {
+                0
+            };
+            idle.insert(prog.id, periods);
+        }
+        self.idle = idle;
+    }
+
+    pub fn is_idle(&self, prog_id: u32) -> bool {
+        self.idle
+            .get(&prog_id)
+            .is_some_and(|periods| *periods >= self.periods)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn program(id: u32, prev_run_cnt: u64, run_cnt: u64, period_ns: u128) -> BpfProgram {
+        BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt,
+            run_cnt,
+            instant: Instant::now(),
+            period_ns,
+            processes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_observe() {
+        let mut tracker = IdleTracker::new(2);
+        tracker.observe(&[program(1, 0, 0, 0), program(2, 5, 5, 1)]);
+        // Not sampled for a full period yet
+        assert!(!tracker.is_idle(1));
+        assert!(!tracker.is_idle(2));
+
+        tracker.observe(&[program(1, 0, 0, 1), program(2, 5, 5, 1)]);
+        assert!(!tracker.is_idle(1));
+        assert!(tracker.is_idle(2));
+
+        // A single event makes a program active again
+        tracker.observe(&[program(1, 0, 0, 1), program(2, 5, 6, 1)]);
+        assert!(tracker.is_idle(1));
+        assert!(!tracker.is_idle(2));
+
+        tracker.observe(&[]);
+        assert!(!tracker.is_idle(1));
+    }
+}
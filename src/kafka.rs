@@ -0,0 +1,342 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{app::SAMPLE_INTERVAL, exporter::Exporter, snapshot::Sample};
+use anyhow::{anyhow, Context, Result};
+use rdkafka::{
+    config::{ClientConfig, RDKafkaLogLevel},
+    error::{KafkaError, RDKafkaErrorCode},
+    producer::{BaseRecord, DeliveryResult, Producer, ProducerContext, ThreadedProducer},
+    ClientContext,
+};
+use serde::Serialize;
+use std::{
+    env,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tracing::debug;
+
+// SASL credentials, read from the environment so they do not show up in the process list
+pub const USERNAME_ENV: &str = "BPFTOP_KAFKA_USERNAME";
+pub const PASSWORD_ENV: &str = "BPFTOP_KAFKA_PASSWORD";
+
+// Batches waiting to be delivered. Newer samples are dropped while Kafka is unreachable,
+// rather than growing without bound.
+const QUEUED_BATCHES: usize = 4;
+// The longest librdkafka waits to fill a batch
+const MAX_LINGER_MS: u128 = 900_000;
+// How long to wait for queued samples to be delivered on exit
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Compression {
+    None,
+    Gzip,
+    Snappy,
+    Lz4,
+}
+
+impl Compression {
+    fn codec(self) -> &'static str {
+        match self {
+            Compression::None => "none",
+            Compression::Gzip => "gzip",
+            Compression::Snappy => "snappy",
+            Compression::Lz4 => "lz4",
+        }
+    }
+}
+
+pub fn parse_compression(value: &str) -> Result<Compression> {
+    match value {
+        "none" => Ok(Compression::None),
+        "gzip" => Ok(Compression::Gzip),
+        "snappy" => Ok(Compression::Snappy),
+        "lz4" => Ok(Compression::Lz4),
+        _ => Err(anyhow!(
+            "Unknown compression '{}', expected none, gzip, snappy or lz4",
+            value
+        )),
+    }
+}
+
+// A librdkafka property given as KEY=VALUE, e.g. security.protocol=sasl_ssl
+pub fn parse_option(value: &str) -> Result<(String, String)> {
+    let (key, value) = value
+        .split_once('=')
+        .with_context(|| format!("Expected KEY=VALUE, got '{}'", value))?;
+    Ok((key.trim().to_string(), value.trim().to_string()))
+}
+
+// Records the outcome of the last delivery, and errors such as every broker being down,
+// which librdkafka retries by itself
+struct DeliveryContext {
+    failure: Arc<Mutex<Option<String>>>,
+}
+
+impl ClientContext for DeliveryContext {
+    // Connection failures are reported through error() as well, and would otherwise be
+    // logged as errors on every retry
+    fn log(&self, _level: RDKafkaLogLevel, facility: &str, message: &str) {
+        debug!("librdkafka {}: {}", facility, message);
+    }
+
+    fn error(&self, error: KafkaError, reason: &str) {
+        *self.failure.lock().unwrap() = Some(format!("{}: {}", error, reason));
+    }
+}
+
+impl ProducerContext for DeliveryContext {
+    type DeliveryOpaque = ();
+
+    fn delivery(&self, result: &DeliveryResult<'_>, _: ()) {
+        *self.failure.lock().unwrap() = result.as_ref().err().map(|(e, _)| e.to_string());
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ProgramStats<'a> {
+    id: u32,
+    name: &'a str,
+    bpf_type: &'a str,
+    run_time_ns: u64,
+    run_cnt: u64,
+    events_per_second: i64,
+    cpu_time_percent: f64,
+    period_average_runtime_ns: u64,
+}
+
+// One record per sample and host
+#[derive(Debug, Serialize)]
+struct HostSample<'a> {
+    host: &'a str,
+    timestamp_ms: u64,
+    programs: Vec<ProgramStats<'a>>,
+}
+
+pub struct KafkaConfig {
+    pub bootstrap: Vec<String>,
+    pub topic: String,
+    // Record key, which picks the partition. None spreads records over the partitions.
+    pub key: Option<String>,
+    pub compression: Compression,
+    // Samples per produce request
+    pub batch_size: usize,
+    // Further librdkafka properties, e.g. for TLS and SASL
+    pub options: Vec<(String, String)>,
+    pub host: String,
+}
+
+fn client_config(config: &KafkaConfig) -> ClientConfig {
+    let batch_size = config.batch_size.max(1);
+    // A batch is sent once it holds batch_size samples, or once they should have been taken
+    let linger_ms = (SAMPLE_INTERVAL.as_millis() * batch_size as u128).min(MAX_LINGER_MS);
+    let mut client = ClientConfig::new();
+    client
+        .set("bootstrap.servers", config.bootstrap.join(","))
+        .set("client.id", "bpftop")
+        // Logs are passed to the context rather than written to stderr, over the UI
+        .set("log.queue", "true")
+        .set("compression.codec", config.compression.codec())
+        // Keys land on the same partition as with the Java client
+        .set("partitioner", "murmur2_random")
+        .set("enable.idempotence", "true")
+        .set("batch.num.messages", batch_size.to_string())
+        .set("linger.ms", linger_ms.to_string())
+        .set(
+            "queue.buffering.max.messages",
+            (batch_size * QUEUED_BATCHES).to_string(),
+        );
+    if let (Ok(username), Ok(password)) = (env::var(USERNAME_ENV), env::var(PASSWORD_ENV)) {
+        client
+            .set("sasl.username", username)
+            .set("sasl.password", password);
+    }
+    for (key, value) in &config.options {
+        client.set(key, value);
+    }
+    client
+}
+
+// Produces every sample as a JSON record to a Kafka topic through librdkafka, which
+// batches several samples per request. Delivery happens on librdkafka's threads, so a slow
+// or unreachable cluster does not hold up the collector.
+pub struct KafkaExporter {
+    name: String,
+    topic: String,
+    host: String,
+    key: Option<Vec<u8>>,
+    producer: ThreadedProducer<DeliveryContext>,
+    failure: Arc<Mutex<Option<String>>>,
+}
+
+impl KafkaExporter {
+    pub fn new(config: KafkaConfig) -> Result<Self> {
+        if config.bootstrap.is_empty() || config.topic.is_empty() {
+            return Err(anyhow!("Kafka needs bootstrap brokers and a topic"));
+        }
+        let failure = Arc::new(Mutex::new(None));
+        let producer = client_config(&config)
+            .create_with_context(DeliveryContext {
+                failure: Arc::clone(&failure),
+            })
+            .context("Failed to create the Kafka producer")?;
+        Ok(KafkaExporter {
+            name: format!("kafka topic {}", config.topic),
+            topic: config.topic,
+            host: config.host,
+            key: config.key.map(String::into_bytes),
+            producer,
+            failure,
+        })
+    }
+}
+
+impl Exporter for KafkaExporter {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn export(&mut self, sample: &Sample) -> Result<()> {
+        let value = HostSample {
+            host: &self.host,
+            timestamp_ms: sample.timestamp.wall_ms,
+            programs: sample
+                .programs
+                .iter()
+                // Programs without a full period of stats yet have nothing to report
+                .filter(|prog| prog.period_ns > 0)
+                .map(|prog| ProgramStats {
+                    id: prog.id,
+                    name: &prog.name,
+                    bpf_type: &prog.bpf_type,
+                    run_time_ns: prog.run_time_ns,
+                    run_cnt: prog.run_cnt,
+                    events_per_second: prog.events_per_second(),
+                    cpu_time_percent: prog.cpu_time_percent(),
+                    period_average_runtime_ns: prog.period_average_runtime_ns(),
+                })
+                .collect(),
+        };
+        let payload = serde_json::to_vec(&value)?;
+        let mut record = BaseRecord::<[u8], [u8]>::to(&self.topic)
+            .payload(&payload)
+            .timestamp(sample.timestamp.wall_ms as i64);
+        if let Some(key) = &self.key {
+            record = record.key(key);
+        }
+        match self.producer.send(record) {
+            Ok(()) | Err((KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull), _)) => {}
+            Err((e, _)) => return Err(e.into()),
+        }
+        match self.failure.lock().unwrap().as_ref() {
+            Some(failure) => Err(anyhow!("{}", failure)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for KafkaExporter {
+    fn drop(&mut self) {
+        let _ = self.producer.flush(FLUSH_TIMEOUT);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bpftop_core::{bpf_program::BpfProgram, sampler::Timestamp};
+    use rdkafka::{
+        consumer::{BaseConsumer, Consumer},
+        mocking::MockCluster,
+        Message, Offset, TopicPartitionList,
+    };
+    use std::time::Instant;
+
+    fn config(bootstrap: String) -> KafkaConfig {
+        KafkaConfig {
+            bootstrap: vec![bootstrap],
+            topic: "bpftop".to_string(),
+            key: Some("edge-1".to_string()),
+            compression: Compression::Gzip,
+            batch_size: 5,
+            options: vec![parse_option("security.protocol = ssl").unwrap()],
+            host: "edge-1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_client_config() {
+        let client = client_config(&config("kafka-1:9092".to_string()));
+        assert_eq!(client.get("compression.codec"), Some("gzip"));
+        assert_eq!(client.get("linger.ms"), Some("5000"));
+        assert_eq!(client.get("queue.buffering.max.messages"), Some("20"));
+        assert_eq!(client.get("security.protocol"), Some("ssl"));
+        assert!(parse_option("security.protocol").is_err());
+        assert!(parse_compression("zstd").is_err());
+    }
+
+    #[test]
+    fn test_produce() {
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic("bpftop", 1, 1).unwrap();
+        let mut config = config(cluster.bootstrap_servers());
+        config.options.clear();
+        let mut exporter = KafkaExporter::new(config).unwrap();
+        let mut sample = Sample {
+            timestamp: Timestamp {
+                instant: Instant::now(),
+                wall_ms: 1_700_000_000_000,
+            },
+            ..Default::default()
+        };
+        sample.programs.push(BpfProgram {
+            id: 42,
+            bpf_type: "Xdp".to_string(),
+            name: "xdp_lb".to_string(),
+            run_time_ns: 25_000_000,
+            run_cnt: 1000,
+            period_ns: 1_000_000_000,
+            ..Default::default()
+        });
+        exporter.export(&sample).unwrap();
+        exporter.producer.flush(Duration::from_secs(10)).unwrap();
+        assert!(exporter.failure.lock().unwrap().is_none());
+
+        let consumer: BaseConsumer = ClientConfig::new()
+            .set("bootstrap.servers", cluster.bootstrap_servers())
+            .set("group.id", "test")
+            .set("auto.offset.reset", "earliest")
+            .create()
+            .unwrap();
+        let mut partitions = TopicPartitionList::new();
+        partitions
+            .add_partition_offset("bpftop", 0, Offset::Beginning)
+            .unwrap();
+        consumer.assign(&partitions).unwrap();
+        let message = consumer
+            .poll(Duration::from_secs(10))
+            .unwrap()
+            .unwrap()
+            .detach();
+        assert_eq!(message.key(), Some(&b"edge-1"[..]));
+        let value: serde_json::Value = serde_json::from_slice(message.payload().unwrap()).unwrap();
+        assert_eq!(value["host"], "edge-1");
+        assert_eq!(value["programs"][0]["events_per_second"], 1000);
+    }
+}
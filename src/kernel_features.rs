@@ -0,0 +1,345 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::btf::{vmlinux_btf, VMLINUX_BTF_PATH};
+use anyhow::{anyhow, Context, Result};
+use nix::libc;
+use std::{
+    fs,
+    os::fd::{FromRawFd, OwnedFd},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+use tracing::{error, info, warn};
+
+pub const PROCFS_BPF_STATS_ENABLED: &str = "/proc/sys/kernel/bpf_stats_enabled";
+
+// What the running kernel supports, probed at startup rather than inferred from its
+// version since distributions backport BPF features to older kernels
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct KernelFeatures {
+    pub enable_stats: bool,
+    pub stats_sysctl: bool,
+    pub btf: bool,
+    // fentry/fexit and iterator programs
+    pub tracing: bool,
+    pub task_file_iter: bool,
+    pub ringbuf: bool,
+    pub links: bool,
+    pub struct_ops: bool,
+}
+
+// The sysctl reports stats as enabled whether they were turned on through it or through
+// BPF_ENABLE_STATS, by any process
+pub fn procfs_bpf_stats_is_enabled() -> Result<bool> {
+    fs::read_to_string(PROCFS_BPF_STATS_ENABLED)
+        .context(format!("Failed to read from {}", PROCFS_BPF_STATS_ENABLED))
+        .map(|value| value.trim() == "1")
+}
+
+// How BPF stats came to be enabled at startup
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum StatsMechanism {
+    // bpftop holds a BPF_ENABLE_STATS file descriptor
+    Syscall,
+    // bpftop turned the sysctl on, and turns it back off on exit
+    Procfs,
+    // The sysctl was already on
+    Preenabled,
+    // --no-enable-stats, so whatever manages the sysctl decides
+    #[default]
+    External,
+}
+
+impl StatsMechanism {
+    pub fn describe(self) -> &'static str {
+        match self {
+            StatsMechanism::Syscall => "enabled by bpftop via BPF_ENABLE_STATS",
+            StatsMechanism::Procfs => "enabled by bpftop via kernel.bpf_stats_enabled",
+            StatsMechanism::Preenabled => "already enabled via kernel.bpf_stats_enabled",
+            StatsMechanism::External => "left to kernel.bpf_stats_enabled (--no-enable-stats)",
+        }
+    }
+}
+
+// BPF stats as bpftop enabled them. Stats bpftop turned on itself can be suspended, to take
+// its overhead out of A/B measurements, and resumed the same way.
+#[derive(Debug, Default)]
+pub struct StatsControl {
+    mechanism: StatsMechanism,
+    // Stats stay enabled only as long as the fd is open
+    fd: Option<OwnedFd>,
+    suspended: bool,
+}
+
+impl StatsControl {
+    pub fn new(mechanism: StatsMechanism, fd: Option<OwnedFd>) -> StatsControl {
+        StatsControl {
+            mechanism,
+            fd,
+            suspended: false,
+        }
+    }
+
+    pub fn mechanism(&self) -> StatsMechanism {
+        self.mechanism
+    }
+
+    // Stats bpftop did not turn on belong to someone else
+    pub fn can_suspend(&self) -> bool {
+        matches!(
+            self.mechanism,
+            StatsMechanism::Syscall | StatsMechanism::Procfs
+        )
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended
+    }
+
+    // Returns what became of the stats, as another holder of BPF_ENABLE_STATS or the
+    // sysctl keeps them on
+    pub fn suspend(&mut self) -> Result<String> {
+        match self.mechanism {
+            StatsMechanism::Syscall => self.fd = None,
+            StatsMechanism::Procfs => {
+                fs::write(PROCFS_BPF_STATS_ENABLED, b"0").context(format!(
+                    "Failed to disable BPF stats via {}",
+                    PROCFS_BPF_STATS_ENABLED
+                ))?
+            }
+            mechanism => {
+                return Err(anyhow!(
+                    "BPF stats are {}, so bpftop leaves them alone",
+                    mechanism.describe()
+                ))
+            }
+        }
+        self.suspended = true;
+        info!("Suspended BPF stats");
+        if procfs_bpf_stats_is_enabled().unwrap_or(false) {
+            Ok("Suspended bpftop's BPF stats, but another holder keeps them on".to_string())
+        } else {
+            Ok("BPF stats suspended".to_string())
+        }
+    }
+
+    pub fn resume(&mut self) -> Result<()> {
+        match self.mechanism {
+            StatsMechanism::Syscall => {
+                let fd = unsafe { libbpf_sys::bpf_enable_stats(libbpf_sys::BPF_STATS_RUN_TIME) };
+                if fd < 0 {
+                    return Err(anyhow!("Failed to enable BPF stats via syscall"));
+                }
+                self.fd = Some(unsafe { OwnedFd::from_raw_fd(fd) });
+            }
+            StatsMechanism::Procfs => {
+                fs::write(PROCFS_BPF_STATS_ENABLED, b"1").context(format!(
+                    "Failed to enable BPF stats via {}",
+                    PROCFS_BPF_STATS_ENABLED
+                ))?
+            }
+            _ => return Ok(()),
+        }
+        self.suspended = false;
+        info!("Resumed BPF stats");
+        Ok(())
+    }
+}
+
+// Keeps track of the sysctl, which another tool or an operator may flip at any time, as
+// every delta reads as zero while stats are off
+pub struct StatsWatcher {
+    control: Arc<Mutex<StatsControl>>,
+    enabled: bool,
+}
+
+impl StatsWatcher {
+    pub fn new(control: Arc<Mutex<StatsControl>>) -> StatsWatcher {
+        StatsWatcher {
+            control,
+            enabled: true,
+        }
+    }
+
+    // Returns whether stats are on, turning them back on first if bpftop turned them on
+    // through the sysctl and has not suspended them
+    pub fn check(&mut self) -> bool {
+        let Ok(mut enabled) = procfs_bpf_stats_is_enabled() else {
+            return self.enabled;
+        };
+        let (owned, suspended) = {
+            let control = self.control.lock().unwrap();
+            (
+                control.mechanism == StatsMechanism::Procfs && !control.suspended,
+                control.suspended,
+            )
+        };
+        if suspended {
+            // Expected to be off, which the UI already points out
+        } else if !enabled && owned {
+            match fs::write(PROCFS_BPF_STATS_ENABLED, b"1") {
+                Ok(()) => {
+                    warn!("BPF stats were turned off by another process, turned them back on");
+                    enabled = true;
+                }
+                Err(e) => error!(
+                    "Failed to turn BPF stats back on via {}: {}",
+                    PROCFS_BPF_STATS_ENABLED, e
+                ),
+            }
+        } else if enabled != self.enabled {
+            if enabled {
+                info!("BPF stats were turned back on");
+            } else {
+                warn!("BPF stats were turned off, runtime columns are blank until they are back");
+            }
+        }
+        self.enabled = enabled;
+        enabled
+    }
+}
+
+fn probe_enable_stats() -> bool {
+    let fd = unsafe { libbpf_sys::bpf_enable_stats(libbpf_sys::BPF_STATS_RUN_TIME) };
+    if fd < 0 {
+        return false;
+    }
+    // Stats stay enabled only as long as the fd is open
+    drop(unsafe { OwnedFd::from_raw_fd(fd) });
+    true
+}
+
+fn probe_prog_type(prog_type: libbpf_sys::bpf_prog_type) -> bool {
+    unsafe { libbpf_sys::libbpf_probe_bpf_prog_type(prog_type, std::ptr::null()) == 1 }
+}
+
+fn probe_map_type(map_type: libbpf_sys::bpf_map_type) -> bool {
+    unsafe { libbpf_sys::libbpf_probe_bpf_map_type(map_type, std::ptr::null()) == 1 }
+}
+
+// Kernels without links reject the command, while an empty list is reported as ENOENT
+fn probe_links() -> bool {
+    let mut id = 0;
+    let ret = unsafe { libbpf_sys::bpf_link_get_next_id(0, &mut id) };
+    ret == 0 || ret == -libc::ENOENT
+}
+
+impl KernelFeatures {
+    pub fn probe() -> KernelFeatures {
+        let btf = Path::new(VMLINUX_BTF_PATH).exists();
+        let tracing = btf && probe_prog_type(libbpf_sys::BPF_PROG_TYPE_TRACING);
+        KernelFeatures {
+            enable_stats: probe_enable_stats(),
+            stats_sysctl: Path::new(PROCFS_BPF_STATS_ENABLED).exists(),
+            btf,
+            tracing,
+            task_file_iter: tracing
+                && vmlinux_btf().is_some_and(|btf| btf.type_id("bpf_iter__task_file").is_some()),
+            ringbuf: probe_map_type(libbpf_sys::BPF_MAP_TYPE_RINGBUF),
+            links: probe_links(),
+            struct_ops: btf && probe_map_type(libbpf_sys::BPF_MAP_TYPE_STRUCT_OPS),
+        }
+    }
+
+    // Each feature, whether it is available and what depends on it
+    pub fn diagnostics(&self) -> [(&'static str, bool, &'static str); 8] {
+        [
+            (
+                "BPF_ENABLE_STATS",
+                self.enable_stats,
+                "Runtime stats, falling back to the kernel.bpf_stats_enabled sysctl",
+            ),
+            (
+                "kernel.bpf_stats_enabled",
+                self.stats_sysctl,
+                "Runtime stats on kernels without BPF_ENABLE_STATS",
+            ),
+            (
+                "Kernel BTF",
+                self.btf,
+                "struct_ops decoding and bpftop's own BPF programs",
+            ),
+            (
+                "fentry/fexit programs",
+                self.tracing,
+                "Profiling, runtime histograms, per-CPU runtime and --audit",
+            ),
+            (
+                "task_file iterator",
+                self.task_file_iter,
+                "Finding the processes holding programs and maps without scanning /proc",
+            ),
+            (
+                "Ring buffer maps",
+                self.ringbuf,
+                "Delivering --audit events",
+            ),
+            (
+                "BPF links",
+                self.links,
+                "Attachments in the graph view and pinned links",
+            ),
+            ("struct_ops maps", self.struct_ops, "The struct_ops view"),
+        ]
+    }
+
+    // Stats can be enabled either way
+    pub fn has_stats(&self) -> bool {
+        self.enable_stats || self.stats_sysctl
+    }
+
+    pub fn has_audit(&self) -> bool {
+        self.tracing && self.ringbuf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallbacks() {
+        let features = KernelFeatures {
+            stats_sysctl: true,
+            tracing: true,
+            ..Default::default()
+        };
+        assert!(features.has_stats());
+        assert!(!features.has_audit());
+        assert!(!KernelFeatures::default().has_stats());
+
+        let missing: Vec<&str> = features
+            .diagnostics()
+            .iter()
+            .filter(|(_, available, _)| !available)
+            .map(|(name, _, _)| *name)
+            .collect();
+        assert_eq!(missing.len(), 6);
+        assert_eq!(missing[0], "BPF_ENABLE_STATS");
+    }
+
+    #[test]
+    fn test_stats_control() {
+        let mut control = StatsControl::new(StatsMechanism::Preenabled, None);
+        assert!(!control.can_suspend());
+        assert!(control.suspend().is_err());
+        assert!(!control.is_suspended());
+        assert!(StatsControl::new(StatsMechanism::Syscall, None).can_suspend());
+        assert!(!StatsControl::default().can_suspend());
+    }
+}
@@ -0,0 +1,182 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use bpftop_core::bpf_program::BpfProgram;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LoaderSource {
+    // Reported by the audit programs as the process that loaded the program
+    Audit,
+    // First process seen holding the program, for programs loaded before bpftop started
+    // or without --audit
+    Observed,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Loader {
+    pub pid: i32,
+    pub comm: String,
+    pub source: LoaderSource,
+    // Whether the program has shown up in a sample yet
+    seen: bool,
+}
+
+impl Loader {
+    // The PID may have been reused, so the process only counts as alive if its comm matches
+    pub fn is_alive(&self) -> bool {
+        fs::read_to_string(format!("/proc/{}/comm", self.pid))
+            .is_ok_and(|comm| comm.trim_end() == self.comm)
+    }
+
+    pub fn describe(&self, alive: bool) -> String {
+        let loader = if alive {
+            format!("{} ({})", self.comm, self.pid)
+        } else {
+            format!("exited pid {}, comm {}", self.pid, self.comm)
+        };
+        match self.source {
+            LoaderSource::Audit => loader,
+            LoaderSource::Observed => format!("{}, first seen holding it", loader),
+        }
+    }
+}
+
+// Remembers which process loaded each program, so it can still be shown after that
+// process has exited and no longer holds the program
+#[derive(Debug, Default)]
+pub struct LoaderRegistry {
+    loaders: HashMap<u32, Loader>,
+}
+
+impl LoaderRegistry {
+    pub fn record_load(&mut self, prog_id: u32, pid: i32, comm: &str) {
+        self.loaders.insert(
+            prog_id,
+            Loader {
+                pid,
+                comm: comm.to_string(),
+                source: LoaderSource::Audit,
+                seen: false,
+            },
+        );
+    }
+
+    pub fn record_unload(&mut self, prog_id: u32) {
+        self.loaders.remove(&prog_id);
+    }
+
+    // Attributes programs without a known loader to the first process holding them, and
+    // forgets programs that are no longer loaded. Loads reported by the audit programs are
+    // kept until they have been sampled once, as they may race with the sample.
+    pub fn observe(&mut self, programs: &[BpfProgram]) {
+        for prog in programs {
+            if let Some(loader) = self.loaders.get_mut(&prog.id) {
+                loader.seen = true;
+            } else if let Some(process) = prog.processes.first() {
+                self.loaders.insert(
+                    prog.id,
+                    Loader {
+                        pid: process.pid,
+                        comm: process.comm.clone(),
+                        source: LoaderSource::Observed,
+                        seen: true,
+                    },
+                );
+            }
+        }
+        let ids: HashSet<u32> = programs.iter().map(|prog| prog.id).collect();
+        self.loaders
+            .retain(|id, loader| !loader.seen || ids.contains(id));
+    }
+
+    pub fn get(&self, prog_id: u32) -> Option<&Loader> {
+        self.loaders.get(&prog_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bpftop_core::bpf_program::Process;
+    use std::time::Instant;
+
+    fn program(id: u32, processes: Vec<Process>) -> BpfProgram {
+        BpfProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: "test".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes,
+        }
+    }
+
+    #[test]
+    fn test_observe() {
+        let mut registry = LoaderRegistry::default();
+        let holder = Process {
+            pid: 812,
+            comm: "malware".to_string(),
+            container: None,
+            cgroup_id: 0,
+            bpf_fds: 1,
+            user: None,
+        };
+        registry.record_load(2, 100, "bpftool");
+        registry.observe(&[program(1, vec![holder.clone()])]);
+        assert_eq!(registry.get(1).unwrap().source, LoaderSource::Observed);
+        assert_eq!(registry.get(1).unwrap().comm, "malware");
+        // Not sampled yet, so kept
+        assert_eq!(registry.get(2).unwrap().comm, "bpftool");
+
+        // Still attributed once the holder has exited
+        registry.observe(&[program(1, vec![]), program(2, vec![holder])]);
+        assert_eq!(registry.get(1).unwrap().pid, 812);
+        assert_eq!(registry.get(2).unwrap().source, LoaderSource::Audit);
+
+        registry.observe(&[program(2, vec![])]);
+        assert!(registry.get(1).is_none());
+        registry.record_unload(2);
+        assert!(registry.get(2).is_none());
+    }
+
+    #[test]
+    fn test_describe() {
+        let mut loader = Loader {
+            pid: 812,
+            comm: "malware".to_string(),
+            source: LoaderSource::Audit,
+            seen: true,
+        };
+        assert_eq!(loader.describe(true), "malware (812)");
+        assert_eq!(loader.describe(false), "exited pid 812, comm malware");
+        loader.source = LoaderSource::Observed;
+        assert_eq!(
+            loader.describe(true),
+            "malware (812), first seen holding it"
+        );
+    }
+}
@@ -15,77 +15,410 @@
  *  limitations under the License.
  *
  */
-use crate::helpers::format_percent;
+use allowlist::{Allowlist, DriftDetector};
 use anyhow::{anyhow, Context, Result};
 use app::SortColumn;
-use app::{App, Mode};
-use bpf_program::BpfProgram;
-use crossterm::event::{self, poll, Event, KeyCode, KeyModifiers};
+use app::{
+    App, AttachmentsState, FlagsState, Mode, PeriodMeasure, UiEvent, View, WatchTarget,
+    HISTORY_PERIODS, SAMPLE_INTERVAL,
+};
+use attach_target::AttachTarget;
+use baseline::{BaselineSnapshot, BASELINE_COLUMNS};
+use bpf_map::{type_breakdown, BpfMap, FillLevel};
+use bpffs::Pin;
+use bpftop_core::bpf_attachment::{Attachment, BpfAttachment};
+use bpftop_core::bpf_program::{BpfProgram, Process};
+use bpftop_core::bpf_token;
+use bpftop_core::cgroups::CgroupPrograms;
+use bpftop_core::cgroups::CgroupScope;
+use bpftop_core::helpers::{format_bytes, format_percent};
+use bpftop_core::interfaces::InterfacePrograms;
+use bpftop_core::processes::ProcessPrograms;
+use bpftop_core::sampler::{
+    load_map_iter, load_pid_iter, LiveSource, ProgramStatsSource, ScopedSource,
+};
+use clap::{Parser, Subcommand};
+use compare::{parse_threshold, Threshold};
+use config::Config;
+use cpu_runtime::CpuRuntimeState;
+use crossterm::cursor::Show;
+use crossterm::event::{self, Event, KeyCode, KeyModifiers};
 use crossterm::execute;
 use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
-use libbpf_rs::skel::{OpenSkel, Skel, SkelBuilder};
+use distribution::Distribution;
+use errors::{ErrorLayer, ErrorLog};
+use events::EventSocket;
+use exporter::{Exporters, JournaldExporter, JsonLinesExporter, SAMPLE_TARGET};
+use folded::export_folded;
+use grafana_live::GrafanaLive;
+use heatmap::{cpu_buckets, heat_level, HEAT_LEVELS};
+use histogram::HistogramState;
+use http_api::HttpApi;
+use kernel_features::{
+    procfs_bpf_stats_is_enabled, KernelFeatures, StatsControl, StatsMechanism,
+    PROCFS_BPF_STATS_ENABLED,
+};
 use libbpf_sys::bpf_enable_stats;
-use pid_iter::PidIterSkelBuilder;
+use loaders::LoaderRegistry;
 use procfs::KernelVersion;
+use profile::{ProfileState, PROFILE_DURATION};
+use prog_flags::ProgFlags;
 use ratatui::backend::{Backend, CrosstermBackend};
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::layout::{Constraint, Direction, Flex, Layout, Margin, Rect};
 use ratatui::style::{Color, Modifier, Style, Stylize};
-use ratatui::text::Line;
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{
-    Axis, Block, BorderType, Borders, Cell, Chart, Dataset, GraphType, Padding, Paragraph, Row,
-    Table,
+    Axis, Block, BorderType, Borders, Cell, Chart, Clear, Dataset, GraphType, Padding, Paragraph,
+    Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table, TableState, Wrap,
 };
 use ratatui::{symbols, Frame, Terminal};
+use replay::ReplaySource;
+use rules::RuleEngine;
+use sandbox::Sandbox;
+use script::CustomColumn;
+use session::Session;
+use status::HostInfo;
 use std::fs;
 use std::io::{self, Stdout};
-use std::mem::MaybeUninit;
+use std::ops::Range;
 use std::os::fd::{FromRawFd, OwnedFd};
 use std::panic;
-use std::time::Duration;
-use tracing::info;
-use tracing_subscriber::layer::SubscriberExt;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use struct_ops::StructOps;
+use tracing::{info, warn};
+use tracing_subscriber::layer::{Layer, SubscriberExt};
 use tracing_subscriber::util::SubscriberInitExt;
+use transient::TransientTracker;
 use tui_input::backend::crossterm::EventHandler;
+use watchdog::{parse_cpu_limit, watchdog_rule};
 
+mod allowlist;
+mod anomaly;
 mod app;
-mod bpf_program;
-mod helpers;
-mod pid_iter {
+mod attach_target;
+mod baseline;
+mod bpf_map;
+mod bpffs;
+mod btf;
+mod capabilities;
+mod check;
+mod cmdline;
+mod compare;
+mod config;
+mod cpu_runtime;
+mod dashboard;
+mod distribution;
+mod errors;
+mod events;
+mod exporter;
+mod filter;
+mod folded;
+mod grafana_live;
+mod heatmap;
+mod histogram;
+mod http_api;
+mod idle;
+#[cfg(feature = "kafka")]
+mod kafka;
+mod kernel_features;
+mod loaders;
+mod map_inspector;
+mod map_ops;
+mod memory;
+#[cfg(feature = "mqtt")]
+mod mqtt;
+mod process_info;
+mod profile;
+mod prog_audit;
+mod prog_flags;
+mod prog_types;
+mod replay;
+mod ringbuf;
+mod rules;
+mod sandbox;
+mod script;
+mod session;
+mod signals;
+mod snapshot;
+mod stars;
+mod status;
+mod struct_ops;
+mod text;
+mod transient;
+mod watchdog;
+mod profiler {
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/bpf/profiler.skel.rs"
+    ));
+}
+mod audit {
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/bpf/audit.skel.rs"
+    ));
+}
+mod latency {
     include!(concat!(
         env!("CARGO_MANIFEST_DIR"),
-        "/src/bpf/pid_iter.skel.rs"
+        "/src/bpf/latency.skel.rs"
     ));
 }
 
+// Smallest terminal the layout is readable in
+const MIN_WIDTH: u16 = 80;
+const MIN_HEIGHT: u16 = 21;
+
+// How long quitting waits for the collector to finish its current cycle
+const COLLECTOR_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+
+// The footers fit the minimum width, so they only list the core keys. The rest are in the
+// help.
 const TABLE_FOOTER: &str =
-    "(q) quit | (↑,k) move up | (↓,j) move down | (↵) show graphs | (f) filter | (s) sort";
-const GRAPHS_FOOTER: &str = "(q) quit | (↵) show program list";
+    "(q) quit | (↑,↓) move | (↵) show graphs | (f) filter | (s) sort | (?) help";
+const MAPS_FOOTER: &str =
+    "(q) quit | (↑,↓) move | (↵) show entries | (f) filter | (s) sort | (?) help";
+const STRUCT_OPS_FOOTER: &str =
+    "(q) quit | (↑,↓) move | (f) filter | (s) sort | (t) programs | (?) help";
+const PROCESSES_FOOTER: &str =
+    "(q) quit | (↑,↓) move | (↵) show programs | (f) filter | (s) sort | (?) help";
+const PINS_FOOTER: &str = "(q) quit | (↑,↓) move | (u) unpin | (s) sort | (b) programs | (?) help";
+const INTERFACES_FOOTER: &str =
+    "(q) quit | (↑,↓) move | (f) filter | (s) sort | (i) programs | (?) help";
+const CGROUPS_FOOTER: &str =
+    "(q) quit | (↑,↓) move | (f) filter | (s) sort | (g) programs | (?) help";
+const MAP_INSPECTOR_FOOTER: &str =
+    "(q) quit | (↑,↓) move | (←,→) page | (/) search | (r) refresh | (↵) map list";
+const MAP_SEARCH_FOOTER: &str = "(↵,Esc) back";
+const GRAPHS_FOOTER: &str =
+    "(q) quit | (p) profile | (h) histogram | (Esc) program list | (?) help";
+const DETACH_FOOTER: &str = "(↵) detach | (Esc) cancel";
+const PROCESS_INFO_FOOTER: &str = "(q) quit | (↵,Esc) back";
+const SIGNAL_FOOTER: &str = "(↵) send | (Tab) SIGTERM/SIGKILL | (Esc) cancel";
+const PIN_FOOTER: &str = "(↵) pin | (Tab) toggle maps | (Esc) cancel";
+const UNPIN_FOOTER: &str = "(↵) unpin | (Esc) cancel";
 const FILTER_FOOTER: &str = "(↵,Esc) back";
+const DIAGNOSTICS_FOOTER: &str = "(q) quit | (↵,Esc) back";
+const HEATMAP_FOOTER: &str = "(q) quit | (h,Esc) back";
+const DASHBOARD_FOOTER: &str = "(q) quit | (o,Esc) program list";
 const SORT_CONTROLS_FOOTER: &str =
     "(↑) asc | (↓) desc | (Backspace) clear | (←) move left | (→) move right";
 const SORT_INFO_FOOTER: &str = "(Esc) back";
+const HELP_FOOTER: &str = "(q) quit | (?,Esc) back";
+
+// Keys of every table view
+const TABLE_KEYS: &[(&str, &str)] = &[
+    ("q,Esc", "quit"),
+    ("↑,k", "move up"),
+    ("↓,j", "move down"),
+    ("f", "filter"),
+    ("s", "sort"),
+    ("w", "snapshot"),
+    ("z", "suspend stats"),
+    ("d", "diagnostics"),
+];
+// Each switches to its view, or back to the program list from it
+const VIEW_KEYS: &[(&str, View, &str)] = &[
+    ("m", View::Maps, "maps"),
+    ("t", View::StructOps, "struct_ops"),
+    ("g", View::Cgroups, "cgroups"),
+    ("i", View::Interfaces, "interfaces"),
+    ("b", View::Pins, "bpffs"),
+    ("p", View::Processes, "processes"),
+];
+const PROGRAMS_KEYS: &[(&str, &str)] = &[
+    ("↵", "show graphs"),
+    ("←,→,0-9", "type"),
+    ("*", "star"),
+    ("y", "starred only"),
+    ("F", "follow top"),
+    ("v", "split graph"),
+    ("h", "heatmap"),
+    ("l", "load spread"),
+    ("o", "top offenders"),
+    ("x", "transient"),
+    ("u", "memory"),
+    ("e", "errors"),
+    ("a", "hide idle"),
+];
+const MAPS_KEYS: &[(&str, &str)] = &[("↵", "show entries"), ("o", "track changed keys")];
+const PROCESSES_KEYS: &[(&str, &str)] = &[("↵", "show programs")];
+const PINS_KEYS: &[(&str, &str)] = &[("u", "unpin")];
+const GRAPHS_KEYS: &[(&str, &str)] = &[
+    ("q", "quit"),
+    ("Esc", "show program list"),
+    ("p", "profile"),
+    ("h", "histogram"),
+    ("c", "per-CPU runtime"),
+    ("↑,↓", "select attachment"),
+    ("d", "detach"),
+    ("←,→", "select process"),
+    ("↵", "process details"),
+    ("s", "signal"),
+    ("b", "pin"),
+];
+
+fn program_fields(bpf_program: &BpfProgram) -> Vec<String> {
+    vec![
+        bpf_program.id.to_string(),
+        bpf_program.bpf_type.to_string(),
+        bpf_program.name.to_string(),
+        bpf_program.period_average_runtime_ns().to_string(),
+        bpf_program.total_average_runtime_ns().to_string(),
+        bpf_program.events_per_second().to_string(),
+        format_percent(bpf_program.cpu_time_percent()),
+        bpf_program.containers(),
+        bpf_program.pods(),
+        bpf_program.holders().to_string(),
+    ]
+}
+
+// Widths ratatui will give each column of a bordered table, so cells can be truncated to
+// fit before they are handed to it
+fn column_widths(area: Rect, widths: &[Constraint], highlight_symbol: &str) -> Vec<usize> {
+    let [_, columns_area] = Layout::horizontal([
+        Constraint::Length(highlight_symbol.chars().count() as u16),
+        Constraint::Fill(0),
+    ])
+    .areas(area.inner(Margin::new(1, 1)));
+    Layout::horizontal(widths.iter().copied())
+        .flex(Flex::Start)
+        .spacing(1)
+        .split(columns_area)
+        .iter()
+        .map(|column| column.width as usize)
+        .collect()
+}
+
+impl From<&BpfMap> for Row<'_> {
+    fn from(bpf_map: &BpfMap) -> Self {
+        let height = 1;
+        let cells = vec![
+            Cell::from(bpf_map.id.to_string()),
+            Cell::from(bpf_map.name.to_string()),
+            Cell::from(bpf_map.map_type.to_string()),
+            Cell::from(bpf_map.key_size.to_string()),
+            Cell::from(bpf_map.value_size.to_string()),
+            Cell::from(bpf_map.capacity()),
+            Cell::from(
+                bpf_map
+                    .fill_percent()
+                    .map(format_percent)
+                    .unwrap_or_default(),
+            ),
+            Cell::from(
+                bpf_map
+                    .changed_keys_per_second()
+                    .map(|ops| ops.to_string())
+                    .unwrap_or_default(),
+            ),
+            Cell::from(format_bytes(bpf_map.memlock)),
+            Cell::from(bpf_map.pinned()),
+            Cell::from(bpf_map.programs()),
+            Cell::from(bpf_map.owners()),
+        ];
+
+        // Highlight maps approaching max_entries
+        let style = match bpf_map.fill_level() {
+            FillLevel::Normal => Style::default(),
+            FillLevel::Warning => Style::default().fg(Color::Yellow),
+            FillLevel::Critical => Style::default().fg(Color::Red),
+        };
+
+        Row::new(cells)
+            .style(style)
+            .height(height as u16)
+            .bottom_margin(1)
+    }
+}
+
+impl From<&StructOps> for Row<'_> {
+    fn from(struct_ops: &StructOps) -> Self {
+        let height = 1;
+        let cells = vec![
+            Cell::from(struct_ops.map_id.to_string()),
+            Cell::from(struct_ops.name.to_string()),
+            Cell::from(struct_ops.struct_name.to_string()),
+            Cell::from(struct_ops.state.to_string()),
+            Cell::from(struct_ops.programs()),
+            Cell::from(struct_ops.events_per_second.to_string()),
+            Cell::from(format_percent(struct_ops.cpu_time_percent)),
+        ];
+
+        Row::new(cells).height(height as u16).bottom_margin(1)
+    }
+}
+
+fn process_row(process: &ProcessPrograms, command: &str) -> Row<'static> {
+    let height = 1;
+    let cells = vec![
+        Cell::from(process.pid.to_string()),
+        Cell::from(command.to_string()),
+        Cell::from(process.container_name().to_string()),
+        Cell::from(process.prog_ids.len().to_string()),
+        Cell::from(process.events_per_second.to_string()),
+        Cell::from(format_percent(process.cpu_time_percent)),
+    ];
+
+    Row::new(cells).height(height as u16).bottom_margin(1)
+}
+
+fn interface_row(iface: &InterfacePrograms) -> Row<'static> {
+    let height = 1;
+    let cells = vec![
+        Cell::from(iface.ifindex.to_string()),
+        Cell::from(iface.name.to_string()),
+        Cell::from(iface.programs()),
+        Cell::from(iface.events_per_second.to_string()),
+        Cell::from(format_percent(iface.cpu_time_percent)),
+    ];
 
-const PROCFS_BPF_STATS_ENABLED: &str = "/proc/sys/kernel/bpf_stats_enabled";
+    Row::new(cells).height(height as u16).bottom_margin(1)
+}
 
-impl From<&BpfProgram> for Row<'_> {
-    fn from(bpf_program: &BpfProgram) -> Self {
+impl From<&Pin> for Row<'_> {
+    fn from(pin: &Pin) -> Self {
         let height = 1;
         let cells = vec![
-            Cell::from(bpf_program.id.to_string()),
-            Cell::from(bpf_program.bpf_type.to_string()),
-            Cell::from(bpf_program.name.to_string()),
-            Cell::from(bpf_program.period_average_runtime_ns().to_string()),
-            Cell::from(bpf_program.total_average_runtime_ns().to_string()),
-            Cell::from(bpf_program.events_per_second().to_string()),
-            Cell::from(format_percent(bpf_program.cpu_time_percent())),
+            Cell::from(pin.kind.name()),
+            Cell::from(pin.id.to_string()),
+            Cell::from(pin.path.to_string()),
+            Cell::from(pin.name.to_string()),
+            Cell::from(pin.object_type.to_string()),
+            Cell::from(if pin.is_orphaned() {
+                "pin only".to_string()
+            } else {
+                pin.users()
+            }),
         ];
 
         Row::new(cells).height(height as u16).bottom_margin(1)
     }
 }
 
+fn cgroup_row(cgroup: &CgroupPrograms) -> Row<'static> {
+    let height = 1;
+    let cells = vec![
+        Cell::from(cgroup.id.to_string()),
+        Cell::from(cgroup.path.to_string()),
+        Cell::from(cgroup.programs()),
+        Cell::from(cgroup.events_per_second.to_string()),
+        Cell::from(format_percent(cgroup.cpu_time_percent)),
+    ];
+
+    Row::new(cells).height(height as u16).bottom_margin(1)
+}
+
+// Whether the UI owns the terminal, so a termination signal knows to restore it
+static TERMINAL_ACTIVE: AtomicBool = AtomicBool::new(false);
+
 /// Responsible for managing the terminal state and cleaning up when the program exits
 struct TerminalManager {
     terminal: Terminal<CrosstermBackend<Stdout>>,
@@ -99,12 +432,14 @@ impl TerminalManager {
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
         terminal.hide_cursor()?;
+        TERMINAL_ACTIVE.store(true, Ordering::SeqCst);
         Ok(Self { terminal })
     }
 }
 
 impl Drop for TerminalManager {
     fn drop(&mut self) {
+        TERMINAL_ACTIVE.store(false, Ordering::SeqCst);
         execute!(self.terminal.backend_mut(), LeaveAlternateScreen)
             .unwrap_or_else(|e| eprintln!("Error leaving alternate screen: {:?}", e));
         disable_raw_mode().unwrap_or_else(|e| eprintln!("Error disabling raw mode: {:?}", e));
@@ -114,52 +449,419 @@ impl Drop for TerminalManager {
     }
 }
 
+// Restores the terminal from outside the draw loop, when a signal ends the program
+fn restore_terminal() {
+    if TERMINAL_ACTIVE.swap(false, Ordering::SeqCst) {
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, Show);
+        let _ = disable_raw_mode();
+    }
+}
+
+#[derive(Parser)]
+#[command(version, about, args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Config file with alert rules [default: /etc/bpftop/config.toml if it exists]
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+
+    /// Write the runtime of every program, folded under its attach points, to FILE for
+    /// flamegraph.pl or speedscope and exit instead of starting the UI
+    #[arg(long, value_name = "FILE")]
+    folded: Option<PathBuf>,
+
+    /// How long to sample program runtime for --folded
+    #[arg(long, value_name = "SECONDS", default_value_t = 5, requires = "folded")]
+    duration: u64,
+
+    /// Run without a UI and log every program that exceeds --cpu-limit or fires a rule from
+    /// the config file to journald and stderr
+    #[arg(long, conflicts_with = "folded")]
+    watchdog: bool,
+
+    /// CPU budget of each program in watchdog mode, e.g. 3%
+    #[arg(long, value_name = "PERCENT", value_parser = parse_cpu_limit, requires = "watchdog")]
+    cpu_limit: Option<f64>,
+
+    /// Consecutive one-second periods a program must exceed the budget before alerting
+    #[arg(long, value_name = "N", default_value_t = 3, requires = "watchdog")]
+    periods: u32,
+
+    /// URL to POST a JSON snapshot of the program's stats and attachments to on each alert
+    #[arg(long, value_name = "URL", requires = "watchdog")]
+    webhook: Option<String>,
+
+    /// Shell command to run on each alert, with the program details in BPFTOP_* env vars
+    #[arg(long, value_name = "COMMAND", requires = "watchdog")]
+    exec: Option<String>,
+
+    /// File of known-good "name tag" pairs; running programs missing from it are
+    /// highlighted, and logged in watchdog mode
+    #[arg(long, value_name = "FILE")]
+    allowlist: Option<PathBuf>,
+
+    /// Snapshot written with the w key to compare the running programs against, in ΔCPU %
+    /// and ΔEvents/s columns. Programs are matched by type and name
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["folded", "watchdog"])]
+    baseline: Option<PathBuf>,
+
+    /// Also log programs missing from the allowlist to journald while the UI is running
+    #[arg(long, requires = "allowlist")]
+    alert_unlisted: bool,
+
+    /// Log every BPF program load and unload, with the process responsible, to journald
+    #[arg(long, conflicts_with = "folded")]
+    audit: bool,
+
+    /// Also append each audited load and unload to FILE as a JSON line
+    #[arg(long, value_name = "FILE", requires = "audit")]
+    audit_log: Option<PathBuf>,
+
+    /// Restrict bpftop to the syscalls and paths it needs with seccomp and Landlock once it
+    /// has started. Commands cannot be run from --exec or rules in this mode
+    #[arg(long, conflicts_with = "exec")]
+    sandbox: bool,
+
+    /// Load pid_iter and map_iter with a BPF token from a bpffs mounted with delegation
+    /// options, e.g. in a user namespace (Linux 6.9+)
+    #[arg(long, value_name = "BPFFS")]
+    bpf_token: Option<PathBuf>,
+
+    /// Append the stats of every program to FILE as a JSON line per program, every second
+    #[arg(long, value_name = "FILE", conflicts_with = "folded")]
+    export_json: Option<PathBuf>,
+
+    /// Drop the oldest half of the --export-json file whenever it grows past this many MiB
+    #[arg(long, value_name = "MIB", default_value_t = 256, value_parser = clap::value_parser!(u64).range(1..))]
+    export_json_max_size: u64,
+
+    /// Log the stats of every program to journald every second, as structured fields such
+    /// as BPFTOP_PROG_ID, BPFTOP_PROG_NAME and BPFTOP_CPU
+    #[arg(long, conflicts_with = "folded")]
+    journald_samples: bool,
+
+    /// Stream samples, program loads and unloads, and alerts to the clients of a Unix
+    /// socket at PATH as JSON lines
+    #[arg(long, value_name = "PATH", conflicts_with = "folded")]
+    socket: Option<PathBuf>,
+
+    /// Serve the latest programs and maps as JSON at /api/v1/programs, /api/v1/programs/ID
+    /// and /api/v1/maps on ADDRESS, e.g. 127.0.0.1:9190
+    #[arg(long, value_name = "ADDRESS", conflicts_with = "folded")]
+    api: Option<String>,
+
+    /// Stream every sample to a Grafana Live push endpoint as Influx line protocol over a
+    /// WebSocket, e.g. wss://grafana.example.com/api/live/push/bpftop. A service account
+    /// token can be given in $BPFTOP_GRAFANA_TOKEN
+    #[arg(long, value_name = "URL", conflicts_with = "folded")]
+    grafana_live: Option<String>,
+
+    /// Publish every sample as compact JSON to an MQTT broker, e.g. mqtts://broker:8883 over
+    /// TLS or tcp://broker:1883. Credentials can be given in $BPFTOP_MQTT_USERNAME and
+    /// $BPFTOP_MQTT_PASSWORD
+    #[cfg(feature = "mqtt")]
+    #[arg(long, value_name = "BROKER", conflicts_with = "folded")]
+    mqtt: Option<String>,
+
+    /// Topic to publish samples to with --mqtt [default: bpftop/HOSTNAME]
+    #[cfg(feature = "mqtt")]
+    #[arg(long, alias = "topic", value_name = "TOPIC", requires = "mqtt")]
+    mqtt_topic: Option<String>,
+
+    /// Produce every sample as a JSON record to Kafka, e.g. kafka-1:9092,kafka-2:9092
+    #[cfg(feature = "kafka")]
+    #[arg(
+        long,
+        value_name = "BROKERS",
+        value_delimiter = ',',
+        conflicts_with = "folded"
+    )]
+    kafka: Vec<String>,
+
+    /// Topic to produce samples to with --kafka
+    #[cfg(feature = "kafka")]
+    #[arg(long, value_name = "TOPIC", default_value = "bpftop")]
+    kafka_topic: String,
+
+    /// Key of the records, which picks their partition [default: HOSTNAME]. "none" leaves
+    /// records without a key, spreading them over the partitions
+    #[cfg(feature = "kafka")]
+    #[arg(long, value_name = "KEY")]
+    kafka_key: Option<String>,
+
+    /// Compression of the produced batches: none, gzip, snappy or lz4
+    #[cfg(feature = "kafka")]
+    #[arg(long, value_name = "CODEC", default_value = "none", value_parser = kafka::parse_compression)]
+    kafka_compression: kafka::Compression,
+
+    /// Samples batched into each produce request with --kafka
+    #[cfg(feature = "kafka")]
+    #[arg(long, value_name = "SAMPLES", default_value_t = 5, value_parser = clap::value_parser!(u16).range(1..))]
+    kafka_batch: u16,
+
+    /// Further librdkafka property for --kafka as KEY=VALUE, e.g. security.protocol=sasl_ssl.
+    /// SASL credentials can be given in $BPFTOP_KAFKA_USERNAME and $BPFTOP_KAFKA_PASSWORD
+    #[cfg(feature = "kafka")]
+    #[arg(long, value_name = "KEY=VALUE", value_parser = kafka::parse_option)]
+    kafka_option: Vec<(String, String)>,
+
+    /// Directory that snapshots taken with the w key are written to
+    #[arg(long, value_name = "DIR", default_value = ".")]
+    snapshot_dir: PathBuf,
+
+    /// Start from the program list instead of resuming the view, selection and graphs of
+    /// the previous run, and do not save them for the next one
+    #[arg(long)]
+    no_session: bool,
+
+    /// Start on the dashboard of top programs by CPU %, events per second, runtime and
+    /// memory, e.g. for a wall monitor. Sessions are neither resumed nor saved
+    #[arg(long, conflicts_with_all = ["folded", "watchdog"])]
+    dashboard: bool,
+
+    /// Start in the graph view of the program with this name, waiting for it to be loaded.
+    /// Sessions are not resumed
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["watch_id", "dashboard", "folded", "watchdog"])]
+    watch_name: Option<String>,
+
+    /// Start in the graph view of the program with this ID, waiting for it to be loaded.
+    /// Sessions are not resumed
+    #[arg(long, value_name = "ID", conflicts_with_all = ["dashboard", "folded", "watchdog"])]
+    watch_id: Option<u32>,
+
+    /// Never enable BPF stats, for hosts where kernel.bpf_stats_enabled is managed
+    /// centrally. Runtime, event and CPU columns are only shown while something else has
+    /// them enabled
+    #[arg(long)]
+    no_enable_stats: bool,
+
+    /// Only show the programs attached to a cgroup within this cgroup2 directory, or held by
+    /// a process inside it, e.g. /sys/fs/cgroup/kubepods.slice/kubepods-pod1.slice
+    #[arg(long, value_name = "DIR")]
+    cgroup: Option<PathBuf>,
+
+    /// Show the programs recorded with --export-json in FILE, one period per second,
+    /// instead of the running ones. Other views still show the host
+    #[arg(long, value_name = "FILE", conflicts_with = "folded")]
+    replay: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Compare two recordings made with --export-json, per program and in total, and exit
+    /// with an error if a --fail-on threshold is exceeded, e.g. in performance CI
+    Compare {
+        before: PathBuf,
+        after: PathBuf,
+
+        /// Largest allowed increase of cpu, eps or runtime, relative (cpu:+20%) or in the
+        /// metric's unit (runtime:+500). May be repeated
+        #[arg(long, value_name = "METRIC:INCREASE", value_parser = parse_threshold)]
+        fail_on: Vec<Threshold>,
+    },
+
+    /// Sample every program's CPU usage once and exit with the Nagios plugin status: 0 when
+    /// all are within --warning, 1 above it, 2 above --critical and 3 when sampling failed
+    Check {
+        /// Print a single status line with performance data, for Nagios, Icinga and NRPE
+        #[arg(long)]
+        nagios: bool,
+
+        /// CPU budget of each program above which the check warns, e.g. 5%
+        #[arg(long, value_name = "PERCENT", default_value = "5", value_parser = parse_cpu_limit)]
+        warning: f64,
+
+        /// CPU budget of each program above which the check is critical, e.g. 10%
+        #[arg(long, value_name = "PERCENT", default_value = "10", value_parser = parse_cpu_limit)]
+        critical: f64,
+
+        /// How long to sample programs for
+        #[arg(long, value_name = "SECONDS", default_value_t = 1)]
+        duration: u64,
+    },
+}
+
 fn main() -> Result<()> {
-    if !nix::unistd::Uid::current().is_root() {
-        return Err(anyhow!("This program must be run as root"));
+    let args = Args::parse();
+    if let Some(Command::Compare {
+        before,
+        after,
+        fail_on,
+    }) = &args.command
+    {
+        return compare::run(before, after, fail_on);
+    }
+    if let Some(Command::Check {
+        nagios,
+        warning,
+        critical,
+        duration,
+    }) = args.command
+    {
+        let thresholds = check::Thresholds { warning, critical };
+        process::exit(check::run(
+            Duration::from_secs(duration),
+            thresholds,
+            nagios,
+        ));
+    }
+    let config = Config::load(args.config.as_deref())?;
+    let mut rules = RuleEngine::from_config(&config)?;
+    let custom_columns = config
+        .columns
+        .iter()
+        .map(|column| CustomColumn::from_config(column, &config.dir))
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(cpu_limit) = args.cpu_limit {
+        rules.add_rule(watchdog_rule(
+            cpu_limit,
+            args.periods,
+            args.webhook.clone(),
+            args.exec.clone(),
+        )?);
+    }
+    let allowlist = args.allowlist.as_deref().map(Allowlist::load).transpose()?;
+    let baseline = args
+        .baseline
+        .as_deref()
+        .map(BaselineSnapshot::load)
+        .transpose()?;
+    if let Some(allowlist) = &allowlist {
+        if args.watchdog || args.alert_unlisted {
+            rules.set_drift_detector(DriftDetector::new(allowlist.clone()));
+        }
     }
 
-    // Initialize the journald layer or ignore if not available
-    let journald_layer = match tracing_journald::layer() {
-        Ok(layer) => Some(layer),
-        Err(_) => None,
-    };
+    if args.sandbox && rules.runs_commands() {
+        return Err(anyhow!(
+            "--sandbox cannot be combined with rules that exec commands"
+        ));
+    }
+
+    // Initialize the journald layer or ignore if not available. Fields are named BPFTOP_*,
+    // so they can be matched on with journalctl
+    let journald_layer = tracing_journald::layer()
+        .ok()
+        .map(|layer| layer.with_field_prefix(Some("BPFTOP".to_string())));
+
+    // Watchdog mode has no UI, so its alerts also go to stderr. Per-program samples would
+    // drown them out there, so those only go to journald.
+    let stderr_layer = args.watchdog.then(|| {
+        tracing_subscriber::fmt::layer()
+            .with_writer(io::stderr)
+            .with_filter(tracing_subscriber::filter::filter_fn(|metadata| {
+                metadata.target() != SAMPLE_TARGET
+            }))
+    });
+
+    // Errors are also kept for the error panel
+    let errors = Arc::new(Mutex::new(ErrorLog::default()));
+    let error_layer = ErrorLayer::new(Arc::clone(&errors));
 
     // Initialize the tracing subscriber with the journald layer
     let registry = tracing_subscriber::registry()
         .with(journald_layer)
-        .with(tracing_subscriber::filter::LevelFilter::INFO);
+        .with(stderr_layer)
+        .with(error_layer)
+        .with(tracing_subscriber::filter::LevelFilter::INFO)
+        // rdkafka warns again on every poll about the errors the Kafka exporter reports
+        .with(tracing_subscriber::filter::filter_fn(|metadata| {
+            metadata.target() != "rdkafka::producer::base_producer"
+        }));
     // Try to set this subscriber as the global default
     registry.try_init()?;
 
+    let capabilities = capabilities::check()?;
+
+    let loaders = Arc::new(Mutex::new(LoaderRegistry::default()));
     let kernel_version = KernelVersion::current()?;
-    let _owned_fd: OwnedFd;
+    let stats_mechanism;
+    let mut stats_fd = None;
     let mut stats_enabled_via_procfs = false;
-    let mut iter_link = None;
+    let mut pid_iter = None;
+    let mut map_iter = None;
 
     info!("Starting bpftop...");
     info!("Kernel: {:?}", kernel_version);
+    let features = KernelFeatures::probe();
+    info!("Kernel features: {:?}", features);
+
+    if !features.has_stats() {
+        return Err(anyhow!(
+            "This kernel supports neither BPF_ENABLE_STATS nor {}",
+            PROCFS_BPF_STATS_ENABLED
+        ));
+    }
+    if args.audit && !features.has_audit() {
+        return Err(anyhow!(
+            "--audit needs fentry programs and ring buffer maps, which this kernel lacks"
+        ));
+    }
+    if args.audit && !capabilities.perfmon {
+        return Err(anyhow!("--audit needs CAP_PERFMON"));
+    }
+
+    // fail early with a precise error when the mount cannot provide a token
+    if let Some(bpffs) = &args.bpf_token {
+        bpf_token::create(bpffs)?;
+        info!(
+            "Loading the BPF iterators with a BPF token from {}",
+            bpffs.display()
+        );
+    }
+
+    // load and attach the pid_iter and map_iter BPF programs to get process information, or
+    // run without them. The feature probes run without the token, so with one they may have
+    // failed.
+    if features.task_file_iter || args.bpf_token.is_some() {
+        match load_pid_iter(args.bpf_token.as_deref()) {
+            Ok(iter) => pid_iter = iter,
+            Err(e) => warn!(
+                "Failed to load pid_iter, scanning /proc for process information: {}",
+                e
+            ),
+        }
+        match load_map_iter(args.bpf_token.as_deref()) {
+            Ok(link) => map_iter = link,
+            Err(e) => warn!(
+                "Failed to load map_iter, scanning /proc for map holders: {}",
+                e
+            ),
+        }
+    } else {
+        info!("task_file iterator not supported, scanning /proc for process information");
+    }
 
-    // enable BPF stats via syscall if kernel version >= 5.8
-    if kernel_version >= KernelVersion::new(5, 8, 0) {
+    if args.no_enable_stats {
+        let enabled = procfs_bpf_stats_is_enabled()?;
+        info!(
+            "Not enabling BPF stats, which are currently {}",
+            if enabled { "enabled" } else { "disabled" }
+        );
+        if !enabled && args.folded.is_some() {
+            return Err(anyhow!(
+                "--folded needs BPF stats, which are disabled and --no-enable-stats leaves off"
+            ));
+        }
+        if !enabled && args.watchdog {
+            warn!("BPF stats are disabled, so runtime rules stay quiet until they are enabled");
+        }
+        stats_mechanism = StatsMechanism::External;
+    } else if features.enable_stats {
         let fd = unsafe { bpf_enable_stats(libbpf_sys::BPF_STATS_RUN_TIME) };
         if fd < 0 {
             return Err(anyhow!("Failed to enable BPF stats via syscall"));
         }
-        _owned_fd = unsafe { OwnedFd::from_raw_fd(fd) };
+        stats_fd = Some(unsafe { OwnedFd::from_raw_fd(fd) });
+        stats_mechanism = StatsMechanism::Syscall;
         info!("Enabled BPF stats via syscall");
-
-        // load and attach pid_iter BPF program to get process information
-        let skel_builder = PidIterSkelBuilder::default();
-        let mut open_object = MaybeUninit::uninit();
-        let open_skel = skel_builder.open(&mut open_object)?;
-        let mut skel = open_skel.load()?;
-        skel.attach()?;
-        iter_link = skel.links.bpftop_iter;
     } else {
         // otherwise, enable via procfs
         // but first check if procfs bpf stats were already enabled
         if procfs_bpf_stats_is_enabled()? {
+            stats_mechanism = StatsMechanism::Preenabled;
             info!("BPF stats already enabled via procfs");
         } else {
             fs::write(PROCFS_BPF_STATS_ENABLED, b"1").context(format!(
@@ -167,9 +869,73 @@ fn main() -> Result<()> {
                 PROCFS_BPF_STATS_ENABLED
             ))?;
             stats_enabled_via_procfs = true;
+            stats_mechanism = StatsMechanism::Procfs;
             info!("Enabled BPF stats via procfs");
         }
     }
+    // Held until exit, also by the modes without a UI
+    let stats = Arc::new(Mutex::new(StatsControl::new(stats_mechanism, stats_fd)));
+
+    // the recording is opened before sandboxing, which would deny reading it
+    let mut source: Box<dyn ProgramStatsSource> = match &args.replay {
+        Some(path) => Box::new(ReplaySource::open(path)?),
+        None => Box::new(LiveSource::new(pid_iter)),
+    };
+    let scope = args.cgroup.as_deref().map(CgroupScope::new).transpose()?;
+    let scope_path = scope.as_ref().map(|scope| scope.path.clone());
+    if let Some(scope) = scope {
+        info!("Only showing programs within cgroup {}", scope.path);
+        source = Box::new(ScopedSource::new(source, scope));
+    }
+
+    // the session is only kept for the UI, and its directory must exist before sandboxing
+    let session_path =
+        (!args.no_session && !args.dashboard && args.folded.is_none() && !args.watchdog)
+            .then(session::default_path)
+            .flatten()
+            .filter(|path| {
+                path.parent()
+                    .is_some_and(|dir| fs::create_dir_all(dir).is_ok())
+            });
+
+    if let Some(path) = &args.socket {
+        events::remove_stale_socket(path)?;
+    }
+
+    // from here on, bpftop only reads BPF objects and the files it needs
+    if args.sandbox {
+        let mut sandbox = Sandbox::new(bpffs::bpffs_mounts());
+        for path in [&args.folded, &args.audit_log, &args.export_json]
+            .into_iter()
+            .flatten()
+        {
+            sandbox = sandbox.allow_write(path);
+        }
+        if args.folded.is_none() && !args.watchdog {
+            sandbox = sandbox.allow_write(&args.snapshot_dir);
+        }
+        if let Some(path) = &session_path {
+            sandbox = sandbox.allow_write(path);
+        }
+        if let Some(path) = &args.socket {
+            sandbox = sandbox.allow_socket(path);
+        }
+        if stats_enabled_via_procfs {
+            sandbox = sandbox.allow_write(Path::new(PROCFS_BPF_STATS_ENABLED));
+        }
+        sandbox.apply()?;
+    }
+
+    // restore the procfs stats toggle and the terminal when terminated by a signal, e.g.
+    // by systemd or a closing tmux pane
+    signals::handle_termination(move || {
+        restore_terminal();
+        if stats_enabled_via_procfs {
+            if let Err(err) = procs_bfs_stats_disable() {
+                eprintln!("Failed to disable BPF stats via procfs: {:?}", err);
+            }
+        }
+    })?;
 
     // capture panic to disable BPF stats via procfs
     let previous_hook = panic::take_hook();
@@ -183,13 +949,87 @@ fn main() -> Result<()> {
         previous_hook(panic_info);
     }));
 
-    // setup terminal
-    let mut terminal_manager = TerminalManager::new()?;
+    let recording_trims = Arc::new(AtomicUsize::new(0));
+    let exporters = match start_exporters(&args, &loaders, &recording_trims) {
+        Ok(exporters) => exporters,
+        Err(e) => {
+            if stats_enabled_via_procfs {
+                procs_bfs_stats_disable()?;
+            }
+            return Err(e);
+        }
+    };
+
+    let transients = Arc::new(Mutex::new(TransientTracker::default()));
+    if args.audit {
+        let loaders = Arc::clone(&loaders);
+        let transients = Arc::clone(&transients);
+        prog_audit::start_audit(args.audit_log.clone(), move |event| {
+            let mut loaders = loaders.lock().unwrap();
+            if event.is_load() {
+                loaders.record_load(event.prog_id, event.pid as i32, &event.comm);
+            } else {
+                loaders.record_unload(event.prog_id);
+            }
+            drop(loaders);
+            transients
+                .lock()
+                .unwrap()
+                .record_audit_event(event, Instant::now());
+        })?;
+    }
+
+    let res = if let Some(path) = &args.folded {
+        export_folded(path, Duration::from_secs(args.duration)).map(|stacks| {
+            println!("Wrote {} stacks to {}", stacks, path.display());
+        })
+    } else if args.watchdog {
+        watchdog::run(rules, exporters, source, map_iter, args.api.is_some())
+    } else {
+        // setup terminal
+        let mut terminal_manager = TerminalManager::new()?;
 
-    // create app and run the draw loop
-    let app = App::new();
-    app.start_background_thread(iter_link);
-    let res = run_draw_loop(&mut terminal_manager.terminal, app);
+        // create app and run the draw loop
+        let mut app = App::new();
+        app.features = features;
+        app.capabilities = capabilities;
+        app.stats = Arc::clone(&stats);
+        app.hide_idle.store(config.idle.hide, Ordering::Relaxed);
+        app.idle_periods = config.idle.periods;
+        app.anomaly_sigma = config.anomaly.sigma;
+        app.snapshot_dir = args.snapshot_dir.clone();
+        app.custom_columns = custom_columns;
+        app.cgroup_scope = scope_path;
+        app.allowlist = allowlist;
+        app.baseline = baseline;
+        app.loaders = loaders;
+        app.transients = transients;
+        app.audited = args.audit;
+        app.errors = errors;
+        app.recording_trims = recording_trims;
+        app.host = HostInfo::current();
+        if args.dashboard {
+            app.toggle_dashboard();
+        }
+        let watch = match (args.watch_name, args.watch_id) {
+            (Some(name), _) => Some(WatchTarget::Name(name)),
+            (_, Some(id)) => Some(WatchTarget::Id(id)),
+            (None, None) => None,
+        };
+        if let Some(path) = &session_path {
+            app.restore_session(Session::load(path));
+        }
+        if let Some(target) = watch {
+            app.watch(target);
+        }
+        let (ui_tx, ui_rx) = mpsc::channel();
+        start_input_thread(ui_tx.clone());
+        app.update_fast_scan();
+        let collector = app.start_background_thread(source, map_iter, rules, exporters, ui_tx);
+        let res = run_draw_loop(&mut terminal_manager.terminal, app, session_path, ui_rx);
+        collector.shutdown(COLLECTOR_SHUTDOWN_TIMEOUT);
+        res
+    };
 
     // disable BPF stats via procfs if needed
     if stats_enabled_via_procfs {
@@ -204,6 +1044,62 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+// Exporters spawn threads, which only inherit the blocked termination signals and the
+// sandbox when started after both
+fn start_exporters(
+    args: &Args,
+    loaders: &Arc<Mutex<LoaderRegistry>>,
+    recording_trims: &Arc<AtomicUsize>,
+) -> Result<Exporters> {
+    let mut exporters = Exporters::default();
+    if let Some(path) = &args.export_json {
+        exporters.register(Box::new(JsonLinesExporter::open(
+            path,
+            args.export_json_max_size << 20,
+            Arc::clone(recording_trims),
+        )?));
+    }
+    if args.journald_samples {
+        exporters.register(Box::new(JournaldExporter));
+    }
+    if let Some(path) = &args.socket {
+        exporters.register(Box::new(EventSocket::bind(path)?));
+    }
+    if let Some(address) = &args.api {
+        exporters.register(Box::new(HttpApi::serve(address, Arc::clone(loaders))?));
+    }
+    if let Some(url) = &args.grafana_live {
+        exporters.register(Box::new(GrafanaLive::connect(url)?));
+    }
+    #[cfg(feature = "mqtt")]
+    if let Some(broker) = &args.mqtt {
+        let topic = args.mqtt_topic.clone().unwrap_or_else(|| {
+            let hostname = fs::read_to_string("/proc/sys/kernel/hostname").unwrap_or_default();
+            format!("bpftop/{}", hostname.trim())
+        });
+        exporters.register(Box::new(mqtt::MqttPublisher::connect(broker, &topic)?));
+    }
+    #[cfg(feature = "kafka")]
+    if !args.kafka.is_empty() {
+        let hostname = fs::read_to_string("/proc/sys/kernel/hostname").unwrap_or_default();
+        let key = match args.kafka_key.as_deref() {
+            Some("none") => None,
+            Some(key) => Some(key.to_string()),
+            None => Some(hostname.trim().to_string()),
+        };
+        exporters.register(Box::new(kafka::KafkaExporter::new(kafka::KafkaConfig {
+            bootstrap: args.kafka.clone(),
+            topic: args.kafka_topic.clone(),
+            key,
+            compression: args.kafka_compression,
+            batch_size: args.kafka_batch as usize,
+            options: args.kafka_option.clone(),
+            host: hostname.trim().to_string(),
+        })?));
+    }
+    Ok(exporters)
+}
+
 fn procs_bfs_stats_disable() -> Result<()> {
     fs::write(PROCFS_BPF_STATS_ENABLED, b"0").context(format!(
         "Failed to disable BPF stats via {}",
@@ -212,74 +1108,519 @@ fn procs_bfs_stats_disable() -> Result<()> {
     Ok(())
 }
 
-fn procfs_bpf_stats_is_enabled() -> Result<bool> {
-    fs::read_to_string(PROCFS_BPF_STATS_ENABLED)
-        .context(format!("Failed to read from {}", PROCFS_BPF_STATS_ENABLED))
-        .map(|value| value.trim() == "1")
+// Forwards terminal events to the draw loop, so it can block instead of polling for them
+fn start_input_thread(ui_events: mpsc::Sender<UiEvent>) {
+    thread::spawn(move || {
+        while let Ok(event) = event::read() {
+            if ui_events.send(UiEvent::Input(event)).is_err() {
+                return;
+            }
+        }
+    });
 }
 
-fn run_draw_loop<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result<()> {
+fn run_draw_loop<B: Backend>(
+    terminal: &mut Terminal<B>,
+    mut app: App,
+    session_path: Option<PathBuf>,
+    ui_events: mpsc::Receiver<UiEvent>,
+) -> Result<()> {
+    let mut saved_session = None;
+    let mut drawn_generation = None;
+    let mut redraw = true;
     loop {
-        terminal.draw(|f| ui(f, &mut app))?;
-
-        // wait up to 100ms for a keyboard event
-        if poll(Duration::from_millis(50))? {
-            if let Event::Key(key) = event::read()? {
-                match app.mode {
-                    Mode::Table => match key.code {
-                        KeyCode::Down | KeyCode::Char('j') => app.next_program(),
-                        KeyCode::Up | KeyCode::Char('k') => app.previous_program(),
-                        KeyCode::Enter => app.show_graphs(),
-                        KeyCode::Char('f') => app.toggle_filter(),
-                        KeyCode::Char('s') => app.toggle_sort(),
-                        KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
-                        _ => {}
-                    },
-                    Mode::Graph => match key.code {
-                        KeyCode::Enter | KeyCode::Esc => app.show_table(),
-                        KeyCode::Char('q') => return Ok(()),
-                        _ => {}
-                    },
-                    Mode::Filter => match key.code {
-                        KeyCode::Enter | KeyCode::Esc => app.toggle_filter(),
+        app.apply_pending_session();
+        app.apply_watch();
+        app.follow_top_program();
+        // ratatui only writes the cells that differ from the previous frame, but building
+        // a frame still costs more than bpftop should spend on a quiet host
+        if redraw {
+            let generation = app.generation();
+            terminal.draw(|f| ui(f, &mut app))?;
+            drawn_generation = Some(generation);
+        }
+
+        // save the session whenever it changes, as the connection may drop at any time
+        if let Some(path) = &session_path {
+            let session = app.session();
+            if saved_session.as_ref() != Some(&session) {
+                if let Err(e) = session.save(path) {
+                    warn!("{:#}", e);
+                }
+                saved_session = Some(session);
+            }
+        }
+
+        // Sleep until a key is pressed, the terminal is resized or a new sample is in.
+        // Samples that piled up behind a frame which already showed their data are skipped.
+        let event = ui_events.recv()?;
+        redraw = match &event {
+            UiEvent::Sample => {
+                drawn_generation != Some(app.generation())
+                    || app.map_inspector.as_ref().is_some_and(|i| i.loading())
+            }
+            UiEvent::Input(Event::Key(_) | Event::Resize(..)) => true,
+            UiEvent::Input(_) => false,
+        };
+        if let UiEvent::Input(Event::Key(key)) = event {
+            match app.mode {
+                Mode::Table => match (app.view, key.code) {
+                    (View::Programs, KeyCode::Down | KeyCode::Char('j')) => app.next_program(),
+                    (View::Programs, KeyCode::Up | KeyCode::Char('k')) => app.previous_program(),
+                    (View::Programs, KeyCode::Enter) => app.show_graphs(),
+                    (View::Programs, KeyCode::Char('x')) => app.toggle_transients(),
+                    (View::Programs, KeyCode::Char('v')) => app.toggle_split(),
+                    (View::Programs, KeyCode::Char('h')) => app.toggle_heatmap(),
+                    (View::Programs, KeyCode::Char('l')) => app.toggle_distribution(),
+                    (View::Programs, KeyCode::Char('o')) => app.toggle_dashboard(),
+                    (View::Programs, KeyCode::Char('u')) => app.toggle_memory(),
+                    (View::Programs, KeyCode::Char('e')) => app.toggle_errors(),
+                    (View::Programs, KeyCode::Char('a')) => app.toggle_hide_idle(),
+                    (View::Programs, KeyCode::Char('*')) => app.toggle_star(),
+                    (View::Programs, KeyCode::Char('y')) => app.toggle_starred_only(),
+                    (View::Programs, KeyCode::Char('F')) => app.toggle_follow(),
+                    (View::Programs, KeyCode::Right) => app.next_type_filter(),
+                    (View::Programs, KeyCode::Left) => app.previous_type_filter(),
+                    (View::Programs, KeyCode::Char(c @ '0'..='9')) => {
+                        app.quick_type_filter(c as usize - '0' as usize)
+                    }
+                    (View::Maps, KeyCode::Down | KeyCode::Char('j')) => app.next_map(),
+                    (View::Maps, KeyCode::Up | KeyCode::Char('k')) => app.previous_map(),
+                    (View::Maps, KeyCode::Enter) => app.show_map_inspector(),
+                    (View::Maps, KeyCode::Char('o')) => app.toggle_map_ops_tracking(),
+                    (View::StructOps, KeyCode::Down | KeyCode::Char('j')) => app.next_struct_ops(),
+                    (View::StructOps, KeyCode::Up | KeyCode::Char('k')) => {
+                        app.previous_struct_ops()
+                    }
+                    (_, KeyCode::Char('m')) => app.toggle_view(View::Maps),
+                    (View::Cgroups, KeyCode::Down | KeyCode::Char('j')) => app.next_cgroup(),
+                    (View::Cgroups, KeyCode::Up | KeyCode::Char('k')) => app.previous_cgroup(),
+                    (_, KeyCode::Char('t')) => app.toggle_view(View::StructOps),
+                    (View::Processes, KeyCode::Down | KeyCode::Char('j')) => app.next_process(),
+                    (View::Processes, KeyCode::Up | KeyCode::Char('k')) => app.previous_process(),
+                    (View::Processes, KeyCode::Enter) => app.show_process_programs(),
+                    (View::Interfaces, KeyCode::Down | KeyCode::Char('j')) => app.next_interface(),
+                    (View::Interfaces, KeyCode::Up | KeyCode::Char('k')) => {
+                        app.previous_interface()
+                    }
+                    (View::Pins, KeyCode::Down | KeyCode::Char('j')) => app.next_pin(),
+                    (View::Pins, KeyCode::Up | KeyCode::Char('k')) => app.previous_pin(),
+                    (View::Pins, KeyCode::Char('u')) => app.start_unpin(),
+                    (_, KeyCode::Char('g')) => app.toggle_view(View::Cgroups),
+                    (_, KeyCode::Char('b')) => app.toggle_view(View::Pins),
+                    (_, KeyCode::Char('i')) => app.toggle_view(View::Interfaces),
+                    (_, KeyCode::Char('p')) => app.toggle_view(View::Processes),
+                    (_, KeyCode::Char('d')) => app.toggle_diagnostics(),
+                    (_, KeyCode::Char('f')) => app.toggle_filter(),
+                    (_, KeyCode::Char('s')) => app.toggle_sort(),
+                    (_, KeyCode::Char('w')) => app.write_snapshot(),
+                    (_, KeyCode::Char('z')) => app.toggle_stats(),
+                    (_, KeyCode::Char('?')) => app.toggle_help(),
+                    (_, KeyCode::Char('q') | KeyCode::Esc) => return Ok(()),
+                    _ => {}
+                },
+                Mode::Graph => match key.code {
+                    KeyCode::Enter => app.show_process_info(),
+                    KeyCode::Esc => app.show_table(),
+                    KeyCode::Char('p') => app.start_profile(),
+                    KeyCode::Char('h') => app.toggle_histogram(),
+                    KeyCode::Char('c') => app.toggle_cpu_runtime(),
+                    KeyCode::Down | KeyCode::Char('j') => app.next_attachment(),
+                    KeyCode::Up | KeyCode::Char('k') => app.previous_attachment(),
+                    KeyCode::Char('d') => app.start_detach(),
+                    KeyCode::Right => app.next_holder(),
+                    KeyCode::Left => app.previous_holder(),
+                    KeyCode::Char('s') => app.start_signal(),
+                    KeyCode::Char('b') => app.start_pin(),
+                    KeyCode::Char('?') => app.toggle_help(),
+                    KeyCode::Char('q') => return Ok(()),
+                    _ => {}
+                },
+                Mode::Help => match key.code {
+                    KeyCode::Char('?') | KeyCode::Esc => app.toggle_help(),
+                    KeyCode::Char('q') => return Ok(()),
+                    _ => {}
+                },
+                Mode::Detach | Mode::Signal | Mode::Pin | Mode::Unpin => {
+                    match (&app.mode, key.code) {
+                        (Mode::Detach, KeyCode::Enter) => app.confirm_detach(),
+                        (Mode::Signal, KeyCode::Enter) => app.confirm_signal(),
+                        (Mode::Signal, KeyCode::Tab) => app.toggle_signal_kill(),
+                        (Mode::Pin, KeyCode::Enter) => app.confirm_pin(),
+                        (Mode::Unpin, KeyCode::Enter) => app.confirm_unpin(),
+                        (Mode::Pin, KeyCode::Tab) => app.toggle_pin_maps(),
+                        (_, KeyCode::Esc) => app.cancel_prompt(),
                         _ => {
-                            app.filter_input
-                                .lock()
-                                .unwrap()
-                                .handle_event(&Event::Key(key));
+                            app.prompt_input.handle_event(&Event::Key(key));
                         }
-                    },
-                    Mode::Sort => match key.code {
-                        KeyCode::Esc => app.toggle_sort(),
-                        KeyCode::Up => app.sort_column(SortColumn::Ascending(
-                            app.selected_column.unwrap_or_default(),
-                        )),
-                        KeyCode::Down => app.sort_column(SortColumn::Descending(
-                            app.selected_column.unwrap_or_default(),
-                        )),
-                        KeyCode::Backspace => app.sort_column(SortColumn::NoOrder),
-                        KeyCode::Left => app.previous_column(),
-                        KeyCode::Right => app.next_column(),
-                        KeyCode::Enter => app.cycle_sort_exit(),
-                        _ => {}
-                    },
+                    }
                 }
-                if let (KeyModifiers::CONTROL, KeyCode::Char('c')) = (key.modifiers, key.code) {
-                    return Ok(());
+                Mode::ProcessInfo => match key.code {
+                    KeyCode::Enter | KeyCode::Esc => app.hide_process_info(),
+                    KeyCode::Char('q') => return Ok(()),
+                    _ => {}
+                },
+                Mode::Diagnostics => match key.code {
+                    KeyCode::Enter | KeyCode::Esc => app.toggle_diagnostics(),
+                    KeyCode::Char('q') => return Ok(()),
+                    _ => {}
+                },
+                Mode::Heatmap => match key.code {
+                    KeyCode::Char('h') | KeyCode::Esc => app.toggle_heatmap(),
+                    KeyCode::Char('q') => return Ok(()),
+                    _ => {}
+                },
+                Mode::Dashboard => match key.code {
+                    KeyCode::Char('o') | KeyCode::Esc => app.toggle_dashboard(),
+                    KeyCode::Char('q') => return Ok(()),
+                    _ => {}
+                },
+                Mode::Filter => match key.code {
+                    KeyCode::Enter | KeyCode::Esc => app.toggle_filter(),
+                    _ => {
+                        app.filter.lock().unwrap().handle_event(&Event::Key(key));
+                    }
+                },
+                Mode::MapInspector => {
+                    let Some(inspector) = app.map_inspector.as_mut() else {
+                        app.hide_map_inspector();
+                        continue;
+                    };
+                    match key.code {
+                        KeyCode::Down | KeyCode::Char('j') => inspector.next_entry(),
+                        KeyCode::Up | KeyCode::Char('k') => inspector.previous_entry(),
+                        KeyCode::Right | KeyCode::PageDown => inspector.next_page(),
+                        KeyCode::Left | KeyCode::PageUp => inspector.previous_page(),
+                        KeyCode::Char('r') => inspector.refresh(),
+                        KeyCode::Char('/') => app.toggle_map_search(),
+                        KeyCode::Enter | KeyCode::Esc => app.hide_map_inspector(),
+                        KeyCode::Char('q') => return Ok(()),
+                        _ => {}
+                    }
                 }
+                Mode::MapSearch => match key.code {
+                    KeyCode::Enter | KeyCode::Esc => app.toggle_map_search(),
+                    _ => {
+                        if let Some(inspector) = app.map_inspector.as_mut() {
+                            inspector.search_input.handle_event(&Event::Key(key));
+                            inspector.reset_search_position();
+                        }
+                    }
+                },
+                Mode::Sort => match key.code {
+                    KeyCode::Esc => app.toggle_sort(),
+                    KeyCode::Up => app.sort_column(SortColumn::Ascending(
+                        app.selected_column.unwrap_or_default(),
+                    )),
+                    KeyCode::Down => app.sort_column(SortColumn::Descending(
+                        app.selected_column.unwrap_or_default(),
+                    )),
+                    KeyCode::Backspace => app.sort_column(SortColumn::NoOrder),
+                    KeyCode::Left => app.previous_column(),
+                    KeyCode::Right => app.next_column(),
+                    KeyCode::Enter => app.cycle_sort_exit(),
+                    _ => {}
+                },
+            }
+            if let (KeyModifiers::CONTROL, KeyCode::Char('c')) = (key.modifiers, key.code) {
+                return Ok(());
             }
         }
     }
 }
 
 fn ui(f: &mut Frame, app: &mut App) {
-    let rects = Layout::vertical([Constraint::Min(5), Constraint::Length(3)]).split(f.area());
+    let area = f.area();
+    if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+        render_too_small(f, area);
+        return;
+    }
 
-    match app.mode {
-        Mode::Table | Mode::Filter | Mode::Sort => render_table(f, app, rects[0]),
-        Mode::Graph => render_graphs(f, app, rects[0]),
+    let rects = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Min(5),
+        Constraint::Length(3),
+    ])
+    .split(area);
+
+    // Where and when, so screenshots can be told apart
+    f.render_widget(
+        Paragraph::new(app.host.status_line(SAMPLE_INTERVAL)).reversed(),
+        rects[0],
+    );
+    let rects = &rects[1..];
+
+    // The outcome of the last snapshot or unpin follows the memory summary in the tables
+    let mut summary = format!(" {}", app.memory.lock().unwrap().summary());
+    if let (Mode::Table | Mode::Filter | Mode::Sort, Some(status)) = (&app.mode, &app.action_status)
+    {
+        summary += &format!(" | {}", status);
+    }
+    // Point out degraded data, which would otherwise only show in the journal
+    let mut summary = vec![Span::raw(summary)];
+    if app.stats_suspended() {
+        summary.push(Span::raw(" | "));
+        summary.push("BPF stats suspended (z to resume)".yellow());
+    } else if !app.runtime_stats() {
+        summary.push(Span::raw(" | "));
+        summary.push("BPF stats are off, so runtime stats are unavailable".yellow());
+    }
+    if app.recording_trims.load(Ordering::Relaxed) > 0 {
+        summary.push(Span::raw(" | "));
+        summary.push("Recording at its size cap, oldest periods dropped".yellow());
+    }
+    let recent_errors = app.errors.lock().unwrap().recent(Instant::now());
+    if recent_errors > 0 {
+        summary.push(Span::raw(" | "));
+        summary.push(format!("{} errors in the last minute (e)", recent_errors).red());
+    }
+    f.render_widget(Paragraph::new(Line::from(summary)), rects[0]);
+
+    match (&app.mode, app.view) {
+        (Mode::Graph | Mode::Detach | Mode::Signal | Mode::Pin, _) => {
+            render_graphs(f, app, rects[1])
+        }
+        (Mode::ProcessInfo, _) => {
+            render_graphs(f, app, rects[1]);
+            render_process_info(f, app, rects[1]);
+        }
+        (Mode::Help, _) if app.mode_before_help == Mode::Graph => render_graphs(f, app, rects[1]),
+        (Mode::Diagnostics, _) => render_diagnostics(f, app, rects[1]),
+        (Mode::Heatmap, _) => render_heatmap(f, app, rects[1]),
+        (Mode::Dashboard, _) => render_dashboard(f, app, rects[1]),
+        (Mode::MapInspector | Mode::MapSearch, _) => render_map_inspector(f, app, rects[1]),
+        (_, View::Programs)
+            if app.split()
+                || app.show_transients
+                || app.show_memory
+                || app.show_errors
+                || app.show_distribution =>
+        {
+            let panels = [
+                app.show_transients,
+                app.show_memory,
+                app.show_errors,
+                app.show_distribution,
+            ];
+            let mut constraints = vec![Constraint::Min(5)];
+            // The graph of the selection takes the lower third, right below the table
+            if app.split() {
+                constraints.push(Constraint::Percentage(33));
+            }
+            constraints.extend(
+                panels
+                    .iter()
+                    .filter(|shown| **shown)
+                    .map(|_| Constraint::Percentage(35)),
+            );
+            let chunks = Layout::vertical(constraints).split(rects[1]);
+            render_table(f, app, chunks[0]);
+            let mut panel_areas = chunks.iter().skip(1);
+            if app.split() {
+                render_split_graph(f, app, *panel_areas.next().unwrap());
+            }
+            if app.show_transients {
+                render_transients(f, app, *panel_areas.next().unwrap());
+            }
+            if app.show_memory {
+                render_memory(f, app, *panel_areas.next().unwrap());
+            }
+            if app.show_errors {
+                render_errors(f, app, *panel_areas.next().unwrap());
+            }
+            if app.show_distribution {
+                render_distribution(f, app, *panel_areas.next().unwrap());
+            }
+        }
+        (_, View::Programs) => render_table(f, app, rects[1]),
+        (_, View::Maps) => render_maps_table(f, app, rects[1]),
+        (_, View::StructOps) => render_struct_ops_table(f, app, rects[1]),
+        (_, View::Cgroups) => render_cgroups_table(f, app, rects[1]),
+        (_, View::Processes) => render_processes_table(f, app, rects[1]),
+        (_, View::Interfaces) => render_interfaces_table(f, app, rects[1]),
+        (_, View::Pins) => render_pins_table(f, app, rects[1]),
+    }
+    if app.mode == Mode::Help {
+        render_help(f, app, rects[1]);
+    }
+    render_footer(f, app, rects[2]);
+}
+
+// The keys of the program list or graph view the help was opened from
+fn help_keys(app: &App) -> Vec<(&'static str, &'static str)> {
+    if app.mode_before_help == Mode::Graph {
+        return GRAPHS_KEYS.to_vec();
+    }
+    let view_keys = match app.view {
+        View::Programs => PROGRAMS_KEYS,
+        View::Maps => MAPS_KEYS,
+        View::Processes => PROCESSES_KEYS,
+        View::Pins => PINS_KEYS,
+        View::StructOps | View::Cgroups | View::Interfaces => &[],
+    };
+    let switch_keys = VIEW_KEYS.iter().map(|(key, view, name)| {
+        let name = if *view == app.view { "programs" } else { name };
+        (*key, name)
+    });
+    TABLE_KEYS
+        .iter()
+        .chain(view_keys)
+        .copied()
+        .chain(switch_keys)
+        .collect()
+}
+
+// Popup with every key, in two columns so the program list's fit the minimum height
+fn render_help(f: &mut Frame, app: &App, area: Rect) {
+    let keys = help_keys(app);
+    let half = keys.len().div_ceil(2);
+    let rows: Vec<Row> = (0..half)
+        .map(|i| {
+            let mut cells = Vec::new();
+            for (key, action) in [keys.get(i), keys.get(half + i)].into_iter().flatten() {
+                cells.push(Cell::from(format!("({})", key).bold()));
+                cells.push(Cell::from(*action));
+            }
+            Row::new(cells)
+        })
+        .collect();
+    let [popup] = Layout::vertical([Constraint::Length(rows.len() as u16 + 2)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [popup] = Layout::horizontal([Constraint::Length(64)])
+        .flex(Flex::Center)
+        .areas(popup);
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Fill(1),
+            Constraint::Length(10),
+            Constraint::Fill(1),
+        ],
+    )
+    .block(Block::default().borders(Borders::ALL).title(" Keys "));
+    f.render_widget(Clear, popup);
+    f.render_widget(table, popup);
+}
+
+// Shown instead of the layout, which cannot fit its tables and graphs below the minimum size
+fn render_too_small(f: &mut Frame, area: Rect) {
+    let message = Paragraph::new(vec![
+        Line::from(format!(
+            "Terminal too small ({}x{}, need {}x{})",
+            area.width, area.height, MIN_WIDTH, MIN_HEIGHT
+        )),
+        Line::from("(q) quit"),
+    ])
+    .centered()
+    .wrap(Wrap { trim: true });
+    let [middle] = Layout::vertical([Constraint::Length(2)])
+        .flex(Flex::Center)
+        .areas(area);
+    f.render_widget(message, middle);
+}
+
+// Popup over the graph view with the details of the selected process
+fn render_process_info(f: &mut Frame, app: &App, area: Rect) {
+    let Some(info) = &app.process_info else {
+        return;
+    };
+    let rows: Vec<Row> = info
+        .lines()
+        .into_iter()
+        .map(|(label, value)| Row::new(vec![Cell::from(label.bold()), Cell::from(value)]))
+        .collect();
+    let [popup] = Layout::vertical([Constraint::Length(rows.len() as u16 + 2)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [popup] = Layout::horizontal([Constraint::Percentage(80)])
+        .flex(Flex::Center)
+        .areas(popup);
+    let table = Table::new(rows, [Constraint::Length(13), Constraint::Fill(1)]).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Process {} ({}) ", info.comm, info.pid)),
+    );
+    f.render_widget(Clear, popup);
+    f.render_widget(table, popup);
+}
+
+// The user and full command line of a process, which its comm may be too short to tell apart
+fn describe_holder(holder: &Process, cmdline: Option<&str>) -> String {
+    format!(
+        "{} ({}) as {}: {}",
+        holder.comm,
+        holder.pid,
+        holder.user.as_deref().unwrap_or("unknown user"),
+        cmdline.unwrap_or(&holder.comm)
+    )
+}
+
+// The processes holding the program in the graph view, with the selected one highlighted
+fn holders_line(app: &App, bpf_program: &BpfProgram) -> Line<'static> {
+    let mut spans = vec![];
+    for (i, holder) in bpf_program.processes.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw(", "));
+        }
+        let span = Span::raw(holder.to_string());
+        spans.push(if i == app.selected_holder {
+            span.reversed()
+        } else {
+            span
+        });
+    }
+    Line::from(spans)
+}
+
+// The attachments of the program in the graph view, with the one to detach highlighted
+fn attachments_line(app: &App) -> Line<'static> {
+    let attachments = match &*app.graphs_attachments.lock().unwrap() {
+        Some(AttachmentsState::Resolved(_, attachments)) => attachments.clone(),
+        Some(AttachmentsState::Resolving(_)) => return Line::from("Resolving...".italic()),
+        None => return Line::default(),
+    };
+    // Tracing links only tell how they hook the program's attach target, which is resolved
+    // along with its flags
+    let attach_target = match &*app.graphs_flags.lock().unwrap() {
+        Some(FlagsState::Resolved(_, flags)) => flags.attach_target.clone(),
+        _ => None,
+    };
+    let mut spans = vec![];
+    for (i, attachment) in attachments.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw(", "));
+        }
+        let description = match (attachment, &attach_target) {
+            (Attachment::Link(link), Some(target)) if link.link_type == "tracing" => {
+                BpfAttachment {
+                    target: target.describe(),
+                    ..link.clone()
+                }
+                .describe()
+            }
+            _ => attachment.describe(),
+        };
+        let span = Span::raw(description);
+        spans.push(if i == app.selected_attachment {
+            span.reversed()
+        } else {
+            span
+        });
+    }
+    Line::from(spans)
+}
+
+// Part of the flags of the program in the graph view, once they have been read
+fn flags_line(app: &App, width: usize, describe: impl Fn(&ProgFlags) -> String) -> Line<'static> {
+    match &*app.graphs_flags.lock().unwrap() {
+        Some(FlagsState::Resolved(_, flags)) => Line::from(text::truncate(&describe(flags), width)),
+        Some(FlagsState::Failed(_, error)) => Line::from(text::truncate(error, width)),
+        Some(FlagsState::Resolving(_)) => Line::from("Resolving...".italic()),
+        None => Line::default(),
     }
-    render_footer(f, app, rects[1]);
 }
 
 fn render_graphs(f: &mut Frame, app: &mut App, area: Rect) {
@@ -296,10 +1637,20 @@ fn render_graphs(f: &mut Frame, app: &mut App, area: Rect) {
     let mut moving_max_eps = 0;
     let mut moving_max_runtime = 0;
 
+    // Points are placed by when they were sampled, so late samples do not squash the graph
+    let first = data_buf.front().map(|val| val.timestamp.instant);
+    let elapsed =
+        |instant: Instant| first.map_or(0.0, |first| instant.duration_since(first).as_secs_f64());
+    let x_max = data_buf
+        .back()
+        .map_or(0.0, |val| elapsed(val.timestamp.instant))
+        .max(1.0);
+
     for (i, val) in data_buf.iter().enumerate() {
-        cpu_data[i] = (i as f64, val.cpu_time_percent);
-        eps_data[i] = (i as f64, val.events_per_sec as f64);
-        runtime_data[i] = (i as f64, val.average_runtime_ns as f64);
+        let x = elapsed(val.timestamp.instant);
+        cpu_data[i] = (x, val.cpu_time_percent);
+        eps_data[i] = (x, val.events_per_sec as f64);
+        runtime_data[i] = (x, val.average_runtime_ns as f64);
 
         if val.cpu_time_percent > app.max_cpu {
             app.max_cpu = val.cpu_time_percent;
@@ -334,7 +1685,7 @@ fn render_graphs(f: &mut Frame, app: &mut App, area: Rect) {
     let mut avg_cpu = 0.0;
     let mut avg_eps = 0.0;
     let mut avg_runtime = 0.0;
-    if data_buf.len() > 0 {
+    if !data_buf.is_empty() {
         avg_cpu = total_cpu / data_buf.len() as f64;
         avg_eps = total_eps as f64 / data_buf.len() as f64;
         avg_runtime = total_runtime as f64 / data_buf.len() as f64;
@@ -351,9 +1702,7 @@ fn render_graphs(f: &mut Frame, app: &mut App, area: Rect) {
         .style(Style::default().green())
         .data(&cpu_data);
     let cpu_datasets = vec![cpu_dataset];
-    let x_axis = Axis::default()
-        .style(Style::default())
-        .bounds([0.0, cpu_data.len() as f64]);
+    let x_axis = Axis::default().style(Style::default()).bounds([0.0, x_max]);
     let y_axis = Axis::default()
         .style(Style::default())
         .bounds([0.0, cpu_y_max])
@@ -382,9 +1731,7 @@ fn render_graphs(f: &mut Frame, app: &mut App, area: Rect) {
         .style(Style::default().cyan())
         .data(&eps_data);
     let eps_datasets = vec![eps_dataset];
-    let x_axis = Axis::default()
-        .style(Style::default())
-        .bounds([0.0, eps_data.len() as f64]);
+    let x_axis = Axis::default().style(Style::default()).bounds([0.0, x_max]);
     let y_axis = Axis::default()
         .style(Style::default())
         .bounds([0.0, eps_y_max])
@@ -413,9 +1760,7 @@ fn render_graphs(f: &mut Frame, app: &mut App, area: Rect) {
         .style(Style::default().magenta())
         .data(&runtime_data);
     let runtime_datasets = vec![runtime_dataset];
-    let x_axis = Axis::default()
-        .style(Style::default())
-        .bounds([0.0, runtime_data.len() as f64]);
+    let x_axis = Axis::default().style(Style::default()).bounds([0.0, x_max]);
     let y_axis = Axis::default()
         .style(Style::default())
         .bounds([0.0, runtime_y_max])
@@ -437,6 +1782,25 @@ fn render_graphs(f: &mut Frame, app: &mut App, area: Rect) {
         .x_axis(x_axis)
         .y_axis(y_axis);
 
+    // Give the runtime histogram and per-CPU breakdown a panel below the graphs while
+    // they are shown, side by side when both are
+    let histogram = app.histogram.lock().unwrap().clone();
+    let cpu_runtime = app.cpu_runtime.lock().unwrap().clone();
+    let panels = histogram.is_some() as usize + cpu_runtime.is_some() as usize;
+    let (area, panel_areas) = if panels > 0 {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)].as_ref())
+            .split(area);
+        let panel_areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, panels as u32); panels])
+            .split(chunks[1]);
+        (chunks[0], panel_areas.to_vec())
+    } else {
+        (area, vec![])
+    };
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
@@ -457,7 +1821,9 @@ fn render_graphs(f: &mut Frame, app: &mut App, area: Rect) {
         Row::new(vec![Cell::from("Program Type"), Cell::from("Unknown")]),
         Row::new(vec![Cell::from("Program Name"), Cell::from("Unknown")]),
     ];
-    let widths = [Constraint::Length(15), Constraint::Min(0)];
+    let widths = [Constraint::Length(17), Constraint::Min(0)];
+    // Room left for values once the borders, padding and label column are taken out
+    let value_width = sub_chunks[0][0].width.saturating_sub(2 + 3 + 17 + 1) as usize;
 
     if let Some(bpf_program) = app.graphs_bpf_program.lock().unwrap().clone() {
         items = vec![
@@ -468,27 +1834,118 @@ fn render_graphs(f: &mut Frame, app: &mut App, area: Rect) {
             .height(2),
             Row::new(vec![
                 Cell::from("Program Type".bold()),
-                Cell::from(bpf_program.bpf_type),
+                Cell::from(text::truncate(&bpf_program.bpf_type, value_width)),
             ])
             .height(2),
             Row::new(vec![
                 Cell::from("Program Name".bold()),
-                Cell::from(bpf_program.name),
+                Cell::from(text::truncate(&bpf_program.name, value_width)),
+            ])
+            .height(2),
+            Row::new(vec![
+                Cell::from("Flags".bold()),
+                Cell::from(flags_line(app, value_width, ProgFlags::describe)),
+            ])
+            .height(2),
+            Row::new(vec![
+                Cell::from("Attach type".bold()),
+                Cell::from(flags_line(app, value_width, |flags| {
+                    flags
+                        .expected_attach_type
+                        .clone()
+                        .unwrap_or_else(|| "Unknown, not attached".to_string())
+                })),
+            ])
+            .height(2),
+            Row::new(vec![
+                Cell::from("Attach target".bold()),
+                Cell::from(flags_line(app, value_width, |flags| {
+                    flags
+                        .attach_target
+                        .as_ref()
+                        .map(AttachTarget::describe)
+                        .unwrap_or_else(|| "None".to_string())
+                })),
             ])
             .height(2),
             Row::new(vec![
                 Cell::from("PIDs".bold()),
+                Cell::from(holders_line(app, &bpf_program)),
+            ])
+            .height(2),
+            Row::new(vec![
+                Cell::from("Process".bold()),
+                Cell::from(text::truncate(
+                    &app.holder_to_signal()
+                        .map(|holder| {
+                            let cmdline = app.cmdlines.get(holder.pid, &holder.comm);
+                            describe_holder(&holder, cmdline)
+                        })
+                        .unwrap_or_default(),
+                    value_width,
+                )),
+            ])
+            .height(2),
+            Row::new(vec![
+                Cell::from("Loaded by".bold()),
                 Cell::from(
-                    bpf_program
-                        .processes
-                        .iter()
-                        .map(|pid| pid.to_string())
-                        .collect::<Vec<String>>()
-                        .join(", "),
+                    app.loaders
+                        .lock()
+                        .unwrap()
+                        .get(bpf_program.id)
+                        .map(|loader| {
+                            text::truncate(&loader.describe(loader.is_alive()), value_width)
+                        })
+                        .unwrap_or_else(|| "Unknown".to_string()),
                 ),
             ])
             .height(2),
+            Row::new(vec![
+                Cell::from("Attachments".bold()),
+                Cell::from(attachments_line(app)),
+            ])
+            .height(2),
         ];
+
+        if let Some(status) = &app.action_status {
+            items.push(
+                Row::new(vec![
+                    Cell::from("Action".bold()),
+                    Cell::from(status.to_string()),
+                ])
+                .height(2),
+            );
+        }
+
+        match app.profile.lock().unwrap().as_ref() {
+            Some(ProfileState::Running(_)) => items.push(Row::new(vec![
+                Cell::from("Profile".bold()),
+                Cell::from(format!("Profiling for {}s...", PROFILE_DURATION.as_secs())),
+            ])),
+            Some(ProfileState::Failed(_, error)) => items.push(Row::new(vec![
+                Cell::from("Profile".bold()),
+                Cell::from(error.to_string()),
+            ])),
+            Some(ProfileState::Done(_, report)) => {
+                items.push(Row::new(vec![
+                    Cell::from("Profiled runs".bold()),
+                    Cell::from(report.run_cnt.to_string()),
+                ]));
+                for reading in &report.metrics {
+                    items.push(Row::new(vec![
+                        Cell::from(format!("{}/run", reading.name).bold()),
+                        Cell::from(format!("{:.1}", report.per_run(reading))),
+                    ]));
+                }
+                if let Some(ipc) = report.instructions_per_cycle() {
+                    items.push(Row::new(vec![
+                        Cell::from("IPC".bold()),
+                        Cell::from(format!("{:.2}", ipc)),
+                    ]));
+                }
+            }
+            None => {}
+        }
     }
 
     let table = Table::new(items, widths)
@@ -504,18 +1961,161 @@ fn render_graphs(f: &mut Frame, app: &mut App, area: Rect) {
     f.render_widget(cpu_chart.clone(), sub_chunks[0][1]); // Top right
     f.render_widget(eps_chart, sub_chunks[1][0]); // Bottom left
     f.render_widget(runtime_chart, sub_chunks[1][1]); // Bottom right
+
+    let mut panel_areas = panel_areas.into_iter();
+    if let (Some(state), Some(histogram_area)) = (histogram, panel_areas.next()) {
+        render_histogram(f, &state, histogram_area);
+    }
+    if let (Some(state), Some(cpu_runtime_area)) = (cpu_runtime, panel_areas.next()) {
+        render_cpu_runtime(f, &state, cpu_runtime_area);
+    }
+}
+
+// Compact CPU % and events per second graphs of the program selected in the table, which
+// follow the cursor
+fn render_split_graph(f: &mut Frame, app: &App, area: Rect) {
+    let chunks =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area);
+    let Some(prog) = app.selected_program() else {
+        let block = Block::default()
+            .title(" No program selected ")
+            .borders(Borders::ALL);
+        f.render_widget(block, area);
+        return;
+    };
+    let history = app.history.lock().unwrap();
+    let periods = history.get(prog.id);
+    let first = periods.and_then(|periods| periods.front().map(|val| val.timestamp.instant));
+    let elapsed =
+        |instant: Instant| first.map_or(0.0, |first| instant.duration_since(first).as_secs_f64());
+    let points = |value: fn(&PeriodMeasure) -> f64| -> Vec<(f64, f64)> {
+        periods
+            .into_iter()
+            .flatten()
+            .map(|val| (elapsed(val.timestamp.instant), value(val)))
+            .collect()
+    };
+    let cpu_data = points(|val| val.cpu_time_percent);
+    let eps_data = points(|val| val.events_per_sec as f64);
+    let x_max = cpu_data.last().map_or(0.0, |(x, _)| *x).max(1.0);
+    let name = text::truncate(&prog.name, chunks[0].width.saturating_sub(30) as usize);
+
+    let charts = [
+        (
+            format!(
+                " CPU % of {} | Now: {} ",
+                name,
+                format_percent(prog.cpu_time_percent())
+            ),
+            &cpu_data,
+            Color::Green,
+            "%",
+        ),
+        (
+            format!(" Events per second | Now: {} ", prog.events_per_second()),
+            &eps_data,
+            Color::Cyan,
+            "",
+        ),
+    ];
+    for ((title, data, color, unit), chunk) in charts.into_iter().zip(chunks.iter()) {
+        let y_max = data
+            .iter()
+            .fold(0.0, |max: f64, (_, y)| max.max(*y))
+            .ceil()
+            .max(1.0);
+        let dataset = Dataset::default()
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(color))
+            .data(data);
+        let chart = Chart::new(vec![dataset])
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .x_axis(Axis::default().bounds([0.0, x_max]))
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, y_max])
+                    .labels(vec![format!("0{}", unit), format!("{}{}", y_max, unit)]),
+            );
+        f.render_widget(chart, *chunk);
+    }
+}
+
+fn render_cpu_runtime(f: &mut Frame, state: &CpuRuntimeState, area: Rect) {
+    let (title, lines) = match state {
+        CpuRuntimeState::Collecting(_, breakdown) if breakdown.total_runtime_ns() == 0 => (
+            " Runtime per CPU ".to_string(),
+            vec![Line::from("Waiting for the program to run...")],
+        ),
+        CpuRuntimeState::Collecting(_, breakdown) => (
+            format!(
+                " Runtime per CPU | Total: {} ns ",
+                breakdown.total_runtime_ns()
+            ),
+            breakdown.lines().into_iter().map(Line::from).collect(),
+        ),
+        CpuRuntimeState::Failed(_, error) => (
+            " Runtime per CPU ".to_string(),
+            vec![Line::from(error.to_string())],
+        ),
+    };
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .padding(Padding::new(3, 0, 1, 0))
+            .borders(Borders::ALL),
+    );
+    f.render_widget(paragraph, area);
+}
+
+fn render_histogram(f: &mut Frame, state: &HistogramState, area: Rect) {
+    let (title, lines) = match state {
+        HistogramState::Collecting(_, histogram) if histogram.total() == 0 => (
+            " Runtime Histogram ".to_string(),
+            vec![Line::from("Waiting for the program to run...")],
+        ),
+        HistogramState::Collecting(_, histogram) => (
+            format!(" Runtime Histogram | Runs: {} ", histogram.total()),
+            histogram.lines().into_iter().map(Line::from).collect(),
+        ),
+        HistogramState::Failed(_, error) => (
+            " Runtime Histogram ".to_string(),
+            vec![Line::from(error.to_string())],
+        ),
+    };
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .padding(Padding::new(3, 0, 1, 0))
+            .borders(Borders::ALL),
+    );
+    f.render_widget(paragraph, area);
 }
 
 fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let [types_area, area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+    render_type_breakdown(f, app, types_area);
+
     let selected_style = Style::default().add_modifier(Modifier::REVERSED);
     let normal_style = Style::default().bg(Color::Blue);
 
+    // Without BPF stats the columns derived from them would only show zeros
+    let shown = app.program_columns();
+    let baseline_columns = match app.baseline {
+        Some(_) => &BASELINE_COLUMNS[..],
+        None => &[],
+    };
     let columns: Vec<Cell<'_>> = app
         .header_columns
         .iter()
+        .chain(app.custom_columns.iter().map(|column| &column.name))
+        .map(String::as_str)
+        .chain(baseline_columns.iter().copied())
         .enumerate()
+        .filter(|(i, _)| shown.contains(i))
         .map(|(i, col)| {
-            Cell::new(&**col).style(
+            Cell::new(col).style(
                 if app.selected_column.is_some_and(|selected| selected == i) {
                     selected_style
                 } else {
@@ -529,17 +2129,758 @@ fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
         .height(1)
         .bottom_margin(1);
 
+    let widths: Vec<Constraint> = [5, 10, 14, 11, 11, 10, 9, 11, 12, 7]
+        .into_iter()
+        .chain(app.custom_columns.iter().map(|_| 8))
+        .chain(baseline_columns.iter().map(|_| 8))
+        .enumerate()
+        .filter(|(i, _)| shown.contains(i))
+        .map(|(_, width)| Constraint::Percentage(width))
+        .collect();
+    let highlight_symbol = ">> ";
+    let cell_widths = column_widths(area, &widths, highlight_symbol);
+
     let items = app.items.lock().unwrap();
 
-    let rows: Vec<Row> = items.iter().map(|item| item.into()).collect();
+    // Highlight programs missing from the allowlist
+    let is_unlisted = |item: &BpfProgram| {
+        app.allowlist
+            .as_ref()
+            .is_some_and(|allowlist| !allowlist.allows(item))
+    };
+    let unlisted = items.iter().filter(|item| is_unlisted(item)).count();
+    // Highlight programs behaving unlike their baseline
+    let anomalies = app.anomalies.lock().unwrap().clone();
+    let rows: Vec<Row> = app
+        .shown_programs(&items)
+        .into_iter()
+        .map(|item| {
+            let mut fields = program_fields(item);
+            if app.stars.contains(item) {
+                fields[2] = format!("★ {}", fields[2]);
+            }
+            fields.extend(app.custom_columns.iter().map(|column| column.value(item)));
+            if let Some(baseline) = &app.baseline {
+                fields.extend(baseline.deltas(item));
+            }
+            let row = Row::new(
+                fields
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(i, _)| shown.contains(i))
+                    .zip(&cell_widths)
+                    .map(|((_, field), width)| Cell::from(text::truncate(&field, *width))),
+            )
+            .bottom_margin(1);
+            if is_unlisted(item) {
+                row.style(Style::default().fg(Color::Red))
+            } else if anomalies.contains(&item.id) {
+                row.style(Style::default().fg(Color::Magenta))
+            } else {
+                row
+            }
+        })
+        .collect();
+    let mut title = match app.selected_process.lock().unwrap().as_ref() {
+        // Aggregate stats of the process the list is narrowed down to
+        Some((pid, comm)) => format!(
+            " eBPF programs held by {} ({}): {} events/sec, {} CPU ",
+            comm,
+            pid,
+            items
+                .iter()
+                .map(|item| item.events_per_second())
+                .sum::<i64>(),
+            format_percent(items.iter().map(|item| item.cpu_time_percent()).sum()),
+        ),
+        None => " eBPF programs ".to_string(),
+    };
+    let transient = app.transients.lock().unwrap().count();
+    if transient > 0 {
+        title += &format!("| {} transient ", transient);
+    }
+    if unlisted > 0 {
+        title += &format!("| {} not on allowlist ", unlisted);
+    }
+    if !anomalies.is_empty() {
+        title += &format!("| {} unusual ", anomalies.len());
+    }
+    if app.follow {
+        title += "| following top ";
+    }
+    if app.starred_only {
+        title += &format!("| {} starred only ", app.stars.len());
+    }
+    if let Some(scope) = &app.cgroup_scope {
+        title += &format!("| cgroup {} ", scope);
+    }
+    if !app.runtime_stats() {
+        title += "| BPF stats disabled ";
+    }
+    if app.hide_idle.load(Ordering::Relaxed) {
+        title += &format!(
+            "| {} idle for {}s hidden ",
+            app.hidden_idle(),
+            app.idle_periods
+        );
+    }
+
+    let row_count = rows.len();
+    let t = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(selected_style)
+        .highlight_symbol(highlight_symbol);
+    f.render_stateful_widget(t, area, &mut app.table_state);
+    // Header and program rows both take two lines, with their bottom margin
+    render_scrollbar(f, area, &app.table_state, row_count, 2, 2);
+}
+
+// Number of programs of each type family, with the one the list is narrowed down to
+// highlighted
+fn render_type_breakdown(f: &mut Frame, app: &App, area: Rect) {
+    let mut counts = prog_types::counts(app.items.lock().unwrap().iter());
+    if let Some(family) = &app.type_filter {
+        if !counts.iter().any(|(other, _)| other == family) {
+            counts.push((family.clone(), 0));
+        }
+    }
+    let mut spans = vec![Span::raw(" ")];
+    for (family, count) in counts {
+        let span = Span::raw(format!("{}:{}", family, count));
+        spans.push(if app.type_filter.as_ref() == Some(&family) {
+            span.reversed()
+        } else {
+            span
+        });
+        spans.push(Span::raw(" "));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+// Rows of a bordered table that will be on screen once it is rendered, as ratatui scrolls
+// just enough to keep the selected row visible
+fn visible_rows(
+    table_state: &TableState,
+    row_count: usize,
+    area: Rect,
+    row_height: u16,
+    header_height: u16,
+) -> Range<usize> {
+    let viewport = area
+        .height
+        .saturating_sub(2 + header_height)
+        .checked_div(row_height)
+        .unwrap_or_default() as usize;
+    let mut start = table_state.offset().min(row_count.saturating_sub(viewport));
+    if let Some(selected) = table_state.selected() {
+        start = start.clamp(
+            selected.saturating_sub(viewport.saturating_sub(1)),
+            selected,
+        );
+    }
+    start..(start + viewport).min(row_count)
+}
+
+// Draws a scrollbar over the right border of a table, once it has been rendered and its
+// offset points at the first visible row
+fn render_scrollbar(
+    f: &mut Frame,
+    area: Rect,
+    table_state: &TableState,
+    row_count: usize,
+    row_height: u16,
+    header_height: u16,
+) {
+    let viewport = area
+        .height
+        .saturating_sub(2 + header_height)
+        .checked_div(row_height)
+        .unwrap_or_default() as usize;
+    if row_count <= viewport {
+        return;
+    }
+    // One position per possible offset, so the thumb reaches the end with the last row
+    let max_offset = row_count - viewport;
+    let mut state = ScrollbarState::new(max_offset + 1)
+        .position(table_state.offset().min(max_offset))
+        .viewport_content_length(viewport);
+    f.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight),
+        area.inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut state,
+    );
+}
+
+fn render_transients(f: &mut Frame, app: &App, area: Rect) {
+    let header = Row::new(vec![
+        "ID",
+        "Type",
+        "Name",
+        "Lifetime (ms)",
+        "Runtime (ns)",
+        "Runs",
+        "Loaded by",
+        "Unloaded",
+    ])
+    .style(Style::default().bg(Color::Blue))
+    .height(1);
+
+    let transients = app.transients.lock().unwrap();
+    let unknown = || "-".to_string();
+    let rows: Vec<Row> = transients
+        .recent()
+        .map(|prog| {
+            Row::new(vec![
+                prog.id.to_string(),
+                prog.bpf_type.clone(),
+                prog.name.clone(),
+                prog.lifetime.as_millis().to_string(),
+                prog.run_time_ns.map_or_else(unknown, |ns| ns.to_string()),
+                prog.run_cnt.map_or_else(unknown, |cnt| cnt.to_string()),
+                prog.loader.clone().unwrap_or_else(unknown),
+                format!("{}s ago", prog.unloaded_at.elapsed().as_secs()),
+            ])
+        })
+        .collect();
 
     let widths = [
         Constraint::Percentage(5),
+        Constraint::Percentage(12),
+        Constraint::Percentage(15),
+        Constraint::Percentage(10),
+        Constraint::Percentage(12),
+        Constraint::Percentage(8),
+        Constraint::Percentage(25),
+        Constraint::Percentage(13),
+    ];
+    let table = Table::new(rows, widths).header(header).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Transient programs (loaded and unloaded within a second) "),
+    );
+    f.render_widget(table, area);
+}
+
+fn render_errors(f: &mut Frame, app: &App, area: Rect) {
+    let header = Row::new(vec!["Last seen", "Count", "Error"])
+        .style(Style::default().bg(Color::Blue))
+        .height(1);
+    let errors = app.errors.lock().unwrap();
+    let rows: Vec<Row> = errors
+        .errors()
+        .map(|error| {
+            Row::new(vec![
+                format!("{}s ago", error.last_seen.elapsed().as_secs()),
+                error.count.to_string(),
+                error.message.clone(),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Percentage(10),
+        Constraint::Percentage(8),
+        Constraint::Percentage(82),
+    ];
+    let table = Table::new(rows, widths).header(header).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Recent errors "),
+    );
+    f.render_widget(table, area);
+}
+
+// How events and CPU are spread over all programs, including the idle ones that are hidden,
+// side by side
+fn render_distribution(f: &mut Frame, app: &App, area: Rect) {
+    let chunks =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area);
+    let items = app.items.lock().unwrap();
+    let idle = app.hidden_idle();
+    for (distribution, chunk) in [
+        Distribution::events(&items, idle),
+        Distribution::cpu(&items, idle),
+    ]
+    .into_iter()
+    .zip(chunks.iter())
+    {
+        let lines: Vec<Line> = distribution.lines().into_iter().map(Line::from).collect();
+        let title = format!(
+            " Programs by {} | {} ",
+            distribution.unit,
+            distribution.concentration()
+        );
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .title(title)
+                .padding(Padding::new(1, 0, 0, 0))
+                .borders(Borders::ALL),
+        );
+        f.render_widget(paragraph, *chunk);
+    }
+}
+
+fn render_memory(f: &mut Frame, app: &App, area: Rect) {
+    let chunks =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).split(area);
+    let header_style = Style::default().bg(Color::Blue);
+    let memory = app.memory.lock().unwrap();
+
+    let rows: Vec<Row> = memory
+        .by_type()
+        .into_iter()
+        .map(|(kind, type_name, count, bytes)| {
+            Row::new(vec![
+                kind.to_string(),
+                type_name.to_string(),
+                count.to_string(),
+                format_bytes(bytes),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Percentage(20),
+        Constraint::Percentage(40),
+        Constraint::Percentage(15),
+        Constraint::Percentage(25),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Kind", "Type", "Count", "Memory"]).style(header_style))
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            " BPF memory by type ({}) ",
+            format_bytes(memory.total())
+        )));
+    f.render_widget(table, chunks[0]);
+
+    let rows: Vec<Row> = memory
+        .consumers
+        .iter()
+        .map(|consumer| {
+            Row::new(vec![
+                consumer.kind.to_string(),
+                consumer.id.to_string(),
+                consumer.name.clone(),
+                consumer.type_name.clone(),
+                format_bytes(consumer.bytes),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Percentage(12),
+        Constraint::Percentage(10),
+        Constraint::Percentage(35),
+        Constraint::Percentage(23),
+        Constraint::Percentage(20),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Kind", "ID", "Name", "Type", "Memory"]).style(header_style))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Top BPF memory consumers "),
+        );
+    f.render_widget(table, chunks[1]);
+}
+
+fn render_diagnostics(f: &mut Frame, app: &App, area: Rect) {
+    let header = Row::new(vec!["Feature", "Available", "Used for"])
+        .style(Style::default().bg(Color::Blue))
+        .height(1)
+        .bottom_margin(1);
+    let rows: Vec<Row> = app
+        .features
+        .diagnostics()
+        .into_iter()
+        .chain(app.capabilities.diagnostics())
+        .map(|(feature, available, used_for)| {
+            let row = Row::new(vec![
+                feature,
+                if available { "yes" } else { "no" },
+                used_for,
+            ]);
+            if available {
+                row
+            } else {
+                row.style(Style::default().fg(Color::Yellow))
+            }
+        })
+        .collect();
+    let widths = [
+        Constraint::Percentage(25),
+        Constraint::Percentage(10),
+        Constraint::Percentage(65),
+    ];
+    // How stats were enabled decides whether z can suspend them
+    let stats = {
+        let stats = app.stats.lock().unwrap();
+        let suspended = if stats.is_suspended() {
+            ", suspended"
+        } else {
+            ""
+        };
+        format!("{}{}", stats.mechanism().describe(), suspended)
+    };
+    let table = Table::new(rows, widths).header(header).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Kernel feature probes | BPF stats {} ", stats)),
+    );
+    f.render_widget(table, area);
+}
+
+// One row per program and one column per time bucket, shaded by CPU % relative to the
+// busiest cell shown. The busiest programs over the window come first.
+fn render_heatmap(f: &mut Frame, app: &App, area: Rect) {
+    const NAME_WIDTH: usize = 20;
+    const HEAT_COLORS: [Color; HEAT_LEVELS + 1] = [
+        Color::DarkGray,
+        Color::Blue,
+        Color::Cyan,
+        Color::Green,
+        Color::Yellow,
+        Color::Red,
+    ];
+    let inner = area.inner(Margin::new(1, 1));
+    let columns = (inner.width as usize).saturating_sub(NAME_WIDTH + 1);
+    let bucket_count = columns.clamp(1, HISTORY_PERIODS);
+    let cell_width = (columns / bucket_count).max(1);
+
+    let items = app.items.lock().unwrap();
+    let history = app.history.lock().unwrap();
+    let mut rows: Vec<(&BpfProgram, Vec<Option<f64>>)> = app
+        .shown_programs(&items)
+        .into_iter()
+        .map(|prog| {
+            let buckets = history.get(prog.id).map_or_else(
+                || vec![None; bucket_count],
+                |periods| cpu_buckets(periods, bucket_count),
+            );
+            (prog, buckets)
+        })
+        .collect();
+    let total = |buckets: &[Option<f64>]| buckets.iter().flatten().fold(0.0, |sum, cpu| sum + cpu);
+    rows.sort_by(|a, b| {
+        total(&b.1)
+            .total_cmp(&total(&a.1))
+            .then(a.0.id.cmp(&b.0.id))
+    });
+    rows.truncate(inner.height as usize);
+    let max_cpu = rows
+        .iter()
+        .flat_map(|(_, buckets)| buckets.iter().flatten())
+        .fold(0.0, |max: f64, cpu| max.max(*cpu));
+
+    let lines: Vec<Line> = rows
+        .iter()
+        .map(|(prog, buckets)| {
+            let name = text::truncate(&prog.name, NAME_WIDTH);
+            let mut spans = vec![Span::raw(format!("{:<width$} ", name, width = NAME_WIDTH))];
+            spans.extend(buckets.iter().map(|bucket| match bucket {
+                Some(cpu) => Span::styled(
+                    " ".repeat(cell_width),
+                    Style::default().bg(HEAT_COLORS[heat_level(*cpu, max_cpu)]),
+                ),
+                None => Span::raw(" ".repeat(cell_width)),
+            }));
+            Line::from(spans)
+        })
+        .collect();
+    let seconds = HISTORY_PERIODS.div_ceil(bucket_count) * bucket_count;
+    let title = format!(
+        " CPU % over the last {}s | Busiest: {} | {} of {} programs ",
+        seconds,
+        format_percent(max_cpu),
+        rows.len(),
+        app.shown_programs(&items).len()
+    );
+    let paragraph =
+        Paragraph::new(lines).block(Block::default().title(title).borders(Borders::ALL));
+    f.render_widget(paragraph, area);
+}
+
+// Top programs in four quadrants, refreshed with every sample
+fn render_dashboard(f: &mut Frame, app: &App, area: Rect) {
+    let rows =
+        Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area);
+    let areas = rows.iter().flat_map(|row| {
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(*row)
+            .to_vec()
+    });
+    let items = app.items.lock().unwrap();
+    let quadrants = dashboard::quadrants(&app.shown_programs(&items), &app.memory.lock().unwrap());
+    for (quadrant, area) in quadrants.into_iter().zip(areas) {
+        let header = Row::new(vec!["#", "ID", "Name", quadrant.title])
+            .style(Style::default().bg(Color::Blue))
+            .height(1)
+            .bottom_margin(1);
+        let rows: Vec<Row> = quadrant
+            .entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                Row::new(vec![
+                    (i + 1).to_string(),
+                    entry.id.to_string(),
+                    entry.name,
+                    entry.shown,
+                ])
+            })
+            .collect();
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Length(8),
+            Constraint::Fill(1),
+            Constraint::Length(16),
+        ];
+        let table = Table::new(rows, widths).header(header).block(
+            Block::default().borders(Borders::ALL).title(format!(
+                " Top {} by {} ",
+                dashboard::TOP_PROGRAMS,
+                quadrant.title
+            )),
+        );
+        f.render_widget(table, area);
+    }
+}
+
+fn render_maps_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let selected_style = Style::default().add_modifier(Modifier::REVERSED);
+    let normal_style = Style::default().bg(Color::Blue);
+
+    let columns: Vec<Cell<'_>> = app
+        .map_header_columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| {
+            Cell::new(&**col).style(
+                if app.selected_column.is_some_and(|selected| selected == i) {
+                    selected_style
+                } else {
+                    normal_style
+                },
+            )
+        })
+        .collect();
+    let header = Row::new(columns)
+        .style(normal_style)
+        .height(1)
+        .bottom_margin(1);
+
+    let maps = app.maps.lock().unwrap();
+
+    // Count and memory per map type below the list, as tall as the types need up to a third
+    let types = type_breakdown(&maps);
+    let [area, types_area] = Layout::vertical([
+        Constraint::Min(0),
+        Constraint::Length((types.len() as u16 + 3).min(area.height / 3)),
+    ])
+    .areas(area);
+    render_map_types(f, &types, types_area);
+
+    let rows: Vec<Row> = maps.iter().map(|map| map.into()).collect();
+
+    let widths = [
+        Constraint::Percentage(5),
+        Constraint::Percentage(11),
+        Constraint::Percentage(8),
+        Constraint::Percentage(6),
+        Constraint::Percentage(6),
+        Constraint::Percentage(7),
+        Constraint::Percentage(6),
+        Constraint::Percentage(8),
+        Constraint::Percentage(7),
+        Constraint::Percentage(12),
+        Constraint::Percentage(10),
+        Constraint::Percentage(14),
+    ];
+
+    let t = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(
+            if app.track_map_ops.load(Ordering::Relaxed) {
+                " BPF maps | Tracking changed keys "
+            } else {
+                " BPF maps "
+            },
+        ))
+        .highlight_style(selected_style)
+        .highlight_symbol(">> ");
+    f.render_stateful_widget(t, area, &mut app.map_table_state);
+}
+
+fn render_map_types(f: &mut Frame, types: &[(&str, usize, u64)], area: Rect) {
+    let header_style = Style::default().bg(Color::Blue);
+    let rows: Vec<Row> = types
+        .iter()
+        .map(|(map_type, count, bytes)| {
+            Row::new(vec![
+                map_type.to_string(),
+                count.to_string(),
+                format_bytes(*bytes),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Percentage(40),
+        Constraint::Percentage(20),
+        Constraint::Percentage(40),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Type", "Count", "Memory"]).style(header_style))
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            " Maps by type ({}) ",
+            format_bytes(types.iter().map(|(_, _, bytes)| bytes).sum())
+        )));
+    f.render_widget(table, area);
+}
+
+fn render_struct_ops_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let selected_style = Style::default().add_modifier(Modifier::REVERSED);
+    let normal_style = Style::default().bg(Color::Blue);
+
+    let columns: Vec<Cell<'_>> = app
+        .struct_ops_header_columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| {
+            Cell::new(&**col).style(
+                if app.selected_column.is_some_and(|selected| selected == i) {
+                    selected_style
+                } else {
+                    normal_style
+                },
+            )
+        })
+        .collect();
+    let header = Row::new(columns)
+        .style(normal_style)
+        .height(1)
+        .bottom_margin(1);
+
+    let struct_ops = app.struct_ops.lock().unwrap();
+
+    let rows: Vec<Row> = struct_ops.iter().map(|ops| ops.into()).collect();
+
+    let widths = [
+        Constraint::Percentage(5),
+        Constraint::Percentage(15),
         Constraint::Percentage(17),
+        Constraint::Percentage(8),
+        Constraint::Percentage(35),
+        Constraint::Percentage(10),
+        Constraint::Percentage(10),
+    ];
+
+    let t = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" BPF struct_ops "),
+        )
+        .highlight_style(selected_style)
+        .highlight_symbol(">> ");
+    f.render_stateful_widget(t, area, &mut app.struct_ops_table_state);
+}
+
+fn render_processes_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let selected_style = Style::default().add_modifier(Modifier::REVERSED);
+    let normal_style = Style::default().bg(Color::Blue);
+
+    let columns: Vec<Cell<'_>> = app
+        .processes_header_columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| {
+            Cell::new(&**col).style(
+                if app.selected_column.is_some_and(|selected| selected == i) {
+                    selected_style
+                } else {
+                    normal_style
+                },
+            )
+        })
+        .collect();
+    let header = Row::new(columns)
+        .style(normal_style)
+        .height(1)
+        .bottom_margin(1);
+
+    let processes = app.processes.lock().unwrap();
+
+    // Comms that do not tell the processes apart are replaced with their command line, only
+    // read for the rows on screen
+    let visible = visible_rows(&app.processes_table_state, processes.len(), area, 2, 2);
+    let rows: Vec<Row> = processes
+        .iter()
+        .enumerate()
+        .map(|(i, process)| {
+            let command = if visible.contains(&i) && cmdline::is_ambiguous(&process.comm) {
+                app.cmdlines.get(process.pid, &process.comm)
+            } else {
+                None
+            };
+            process_row(process, command.unwrap_or(&process.comm))
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Percentage(10),
+        Constraint::Percentage(20),
+        Constraint::Percentage(25),
+        Constraint::Percentage(15),
+        Constraint::Percentage(15),
+        Constraint::Percentage(15),
+    ];
+
+    let t = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Processes holding eBPF programs "),
+        )
+        .highlight_style(selected_style)
+        .highlight_symbol(">> ");
+    f.render_stateful_widget(t, area, &mut app.processes_table_state);
+}
+
+fn render_interfaces_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let selected_style = Style::default().add_modifier(Modifier::REVERSED);
+    let normal_style = Style::default().bg(Color::Blue);
+
+    let columns: Vec<Cell<'_>> = app
+        .interfaces_header_columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| {
+            Cell::new(&**col).style(
+                if app.selected_column.is_some_and(|selected| selected == i) {
+                    selected_style
+                } else {
+                    normal_style
+                },
+            )
+        })
+        .collect();
+    let header = Row::new(columns)
+        .style(normal_style)
+        .height(1)
+        .bottom_margin(1);
+
+    let interfaces = app.interfaces.lock().unwrap();
+
+    let rows: Vec<Row> = interfaces.iter().map(interface_row).collect();
+
+    let widths = [
+        Constraint::Percentage(8),
         Constraint::Percentage(17),
-        Constraint::Percentage(17),
-        Constraint::Percentage(17),
-        Constraint::Percentage(17),
+        Constraint::Percentage(55),
+        Constraint::Percentage(10),
         Constraint::Percentage(10),
     ];
 
@@ -548,19 +2889,256 @@ fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" eBPF programs "),
+                .title(" BPF network interfaces "),
         )
         .highlight_style(selected_style)
         .highlight_symbol(">> ");
-    f.render_stateful_widget(t, area, &mut app.table_state);
+    f.render_stateful_widget(t, area, &mut app.interfaces_table_state);
+}
+
+fn render_pins_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let selected_style = Style::default().add_modifier(Modifier::REVERSED);
+    let normal_style = Style::default().bg(Color::Blue);
+
+    let columns: Vec<Cell<'_>> = app
+        .pins_header_columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| {
+            Cell::new(&**col).style(
+                if app.selected_column.is_some_and(|selected| selected == i) {
+                    selected_style
+                } else {
+                    normal_style
+                },
+            )
+        })
+        .collect();
+    let header = Row::new(columns)
+        .style(normal_style)
+        .height(1)
+        .bottom_margin(1);
+
+    let pins = app.pins.lock().unwrap();
+
+    // Highlight pins that are the only thing keeping their object alive
+    let orphaned = pins.iter().filter(|pin| pin.is_orphaned()).count();
+    let rows: Vec<Row> = pins
+        .iter()
+        .map(|pin| {
+            let row = Row::from(pin);
+            if pin.is_orphaned() {
+                row.style(Style::default().fg(Color::Yellow))
+            } else {
+                row
+            }
+        })
+        .collect();
+    let mut title = " BPF pinned objects ".to_string();
+    if orphaned > 0 {
+        title += &format!("| {} pinned only ", orphaned);
+    }
+
+    let widths = [
+        Constraint::Percentage(7),
+        Constraint::Percentage(6),
+        Constraint::Percentage(35),
+        Constraint::Percentage(15),
+        Constraint::Percentage(12),
+        Constraint::Percentage(25),
+    ];
+
+    let t = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(selected_style)
+        .highlight_symbol(">> ");
+    f.render_stateful_widget(t, area, &mut app.pins_table_state);
+}
+
+fn render_cgroups_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let selected_style = Style::default().add_modifier(Modifier::REVERSED);
+    let normal_style = Style::default().bg(Color::Blue);
+
+    let columns: Vec<Cell<'_>> = app
+        .cgroups_header_columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| {
+            Cell::new(&**col).style(
+                if app.selected_column.is_some_and(|selected| selected == i) {
+                    selected_style
+                } else {
+                    normal_style
+                },
+            )
+        })
+        .collect();
+    let header = Row::new(columns)
+        .style(normal_style)
+        .height(1)
+        .bottom_margin(1);
+
+    let cgroups = app.cgroups.lock().unwrap();
+
+    let rows: Vec<Row> = cgroups.iter().map(cgroup_row).collect();
+
+    let widths = [
+        Constraint::Percentage(8),
+        Constraint::Percentage(37),
+        Constraint::Percentage(35),
+        Constraint::Percentage(10),
+        Constraint::Percentage(10),
+    ];
+
+    let t = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" BPF cgroups "),
+        )
+        .highlight_style(selected_style)
+        .highlight_symbol(">> ");
+    f.render_stateful_widget(t, area, &mut app.cgroups_table_state);
+}
+
+fn render_map_inspector(f: &mut Frame, app: &mut App, area: Rect) {
+    let Some(inspector) = app.map_inspector.as_mut() else {
+        return;
+    };
+    inspector.poll();
+    let selected_style = Style::default().add_modifier(Modifier::REVERSED);
+    let normal_style = Style::default().bg(Color::Blue);
+
+    let header = Row::new(vec![Cell::from("Key"), Cell::from("Value")])
+        .style(normal_style)
+        .height(1)
+        .bottom_margin(1);
+
+    let mut title = format!(
+        " Map {} ({}) | {} entries | Page {}/{} ",
+        inspector.map.id,
+        inspector.map.name,
+        inspector.matching_entries().len(),
+        inspector.page + 1,
+        inspector.page_count()
+    );
+    if inspector.truncated {
+        title.push_str("| Truncated ");
+    }
+    if inspector.loading() {
+        title.push_str("| Loading ");
+    }
+
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    // Ring buffers can't be dumped, show their live throughput instead
+    let ringbuf = app
+        .maps
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|map| map.id == inspector.map.id)
+        .and_then(|map| map.ringbuf);
+    if let Some(stats) = ringbuf {
+        let rows = vec![
+            Row::new(vec![
+                Cell::from("Capacity".bold()),
+                Cell::from(format_bytes(stats.capacity)),
+            ]),
+            Row::new(vec![
+                Cell::from("Pending".bold()),
+                Cell::from(format!(
+                    "{} ({})",
+                    format_bytes(stats.pending_bytes),
+                    format_percent(stats.pending_percent())
+                )),
+            ]),
+            Row::new(vec![
+                Cell::from("Produced/sec".bold()),
+                Cell::from(format_bytes(stats.produced_bytes_per_sec)),
+            ]),
+            Row::new(vec![
+                Cell::from("Consumed/sec".bold()),
+                Cell::from(format_bytes(stats.consumed_bytes_per_sec)),
+            ]),
+            Row::new(vec![
+                Cell::from("Records/sec".bold()),
+                Cell::from(stats.records_per_sec.to_string()),
+            ]),
+        ];
+        let widths = [Constraint::Length(15), Constraint::Min(0)];
+        let table = Table::new(rows, widths).block(block.padding(Padding::new(3, 0, 1, 0)));
+        f.render_widget(table, area);
+        return;
+    }
+
+    if let Some(error) = &inspector.error {
+        // Map types without entries to list still have attributes worth showing
+        let attributes = inspector.map.attributes();
+        if attributes.is_empty() {
+            let paragraph = Paragraph::new(Line::from(error.as_str())).block(block);
+            f.render_widget(paragraph, area);
+            return;
+        }
+        let rows: Vec<Row> = attributes
+            .into_iter()
+            .chain([("Entries", error.clone())])
+            .map(|(label, value)| Row::new(vec![Cell::from(label.bold()), Cell::from(value)]))
+            .collect();
+        let widths = [Constraint::Length(18), Constraint::Min(0)];
+        let table = Table::new(rows, widths).block(block.padding(Padding::new(3, 0, 1, 0)));
+        f.render_widget(table, area);
+        return;
+    }
+
+    let rows: Vec<Row> = inspector
+        .page_entries()
+        .into_iter()
+        .map(|entry| {
+            Row::new(vec![
+                Cell::from(entry.key.clone()),
+                Cell::from(entry.value.clone()),
+            ])
+        })
+        .collect();
+
+    let widths = [Constraint::Percentage(35), Constraint::Percentage(65)];
+
+    let t = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .highlight_style(selected_style)
+        .highlight_symbol(">> ");
+    f.render_stateful_widget(t, area, &mut inspector.table_state);
 }
 
 fn render_footer(f: &mut Frame, app: &mut App, area: Rect) {
     let info_text = match app.mode {
-        Mode::Table => TABLE_FOOTER,
+        Mode::Table => match app.view {
+            View::Programs => TABLE_FOOTER,
+            View::Maps => MAPS_FOOTER,
+            View::StructOps => STRUCT_OPS_FOOTER,
+            View::Cgroups => CGROUPS_FOOTER,
+            View::Processes => PROCESSES_FOOTER,
+            View::Interfaces => INTERFACES_FOOTER,
+            View::Pins => PINS_FOOTER,
+        },
         Mode::Graph => GRAPHS_FOOTER,
+        Mode::Detach => DETACH_FOOTER,
+        Mode::Signal => SIGNAL_FOOTER,
+        Mode::ProcessInfo => PROCESS_INFO_FOOTER,
+        Mode::Pin => PIN_FOOTER,
+        Mode::Unpin => UNPIN_FOOTER,
+        Mode::Diagnostics => DIAGNOSTICS_FOOTER,
+        Mode::Heatmap => HEATMAP_FOOTER,
+        Mode::Dashboard => DASHBOARD_FOOTER,
         Mode::Filter => FILTER_FOOTER,
         Mode::Sort => SORT_INFO_FOOTER,
+        Mode::MapInspector => MAP_INSPECTOR_FOOTER,
+        Mode::MapSearch => MAP_SEARCH_FOOTER,
+        Mode::Help => HELP_FOOTER,
     };
     let info_footer = Paragraph::new(Line::from(info_text)).centered().block(
         Block::default()
@@ -568,13 +3146,22 @@ fn render_footer(f: &mut Frame, app: &mut App, area: Rect) {
             .border_type(BorderType::Double),
     );
 
-    // Only single footer in table and graph mode
-    if let Mode::Table | Mode::Graph = app.mode {
+    // Only single footer in table, graph, process details, diagnostics, heatmap, dashboard,
+    // map inspector and help mode
+    if let Mode::Table
+    | Mode::Graph
+    | Mode::Help
+    | Mode::ProcessInfo
+    | Mode::Diagnostics
+    | Mode::Heatmap
+    | Mode::Dashboard
+    | Mode::MapInspector = app.mode
+    {
         f.render_widget(info_footer, area);
         return;
     }
 
-    // Two footers in filter and sort mode
+    // Two footers in filter, sort, map search, detach, signal, pin and unpin mode
     let split_area = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(75), Constraint::Percentage(25)].as_ref())
@@ -586,7 +3173,8 @@ fn render_footer(f: &mut Frame, app: &mut App, area: Rect) {
     // Left footer
     match app.mode {
         Mode::Filter => {
-            let filter_input = app.filter_input.lock().unwrap();
+            let filter = app.filter.lock().unwrap();
+            let filter_input = filter.input();
             let filter_footer = Paragraph::new(filter_input.value()).block(
                 Block::default()
                     .padding(Padding::horizontal(1))
@@ -602,7 +3190,7 @@ fn render_footer(f: &mut Frame, app: &mut App, area: Rect) {
                 split_area[0].x + filter_input.visual_cursor() as u16 + 2,
                 split_area[0].y + 1,
             ));
-            drop(filter_input);
+            drop(filter);
         }
         Mode::Sort => {
             let sort_footer = Paragraph::new(Line::from(SORT_CONTROLS_FOOTER))
@@ -616,6 +3204,93 @@ fn render_footer(f: &mut Frame, app: &mut App, area: Rect) {
 
             f.render_widget(sort_footer, split_area[0]);
         }
+        Mode::Detach | Mode::Signal | Mode::Pin | Mode::Unpin => {
+            let title = match app.mode {
+                Mode::Detach => {
+                    let Some(attachment) = app.attachment_to_detach() else {
+                        return;
+                    };
+                    format!(
+                        " Type \"{}\" to detach {} ",
+                        attachment.confirmation(),
+                        attachment.describe()
+                    )
+                }
+                Mode::Signal => {
+                    let Some(holder) = app.holder_to_signal() else {
+                        return;
+                    };
+                    format!(
+                        " Type \"{}\" to send {} to {} ({}) ",
+                        holder.pid,
+                        app.signal(),
+                        holder.comm,
+                        holder.pid
+                    )
+                }
+                Mode::Pin => {
+                    let Some(prog) = app.graphs_bpf_program.lock().unwrap().clone() else {
+                        return;
+                    };
+                    format!(
+                        " Pin program {} ({}){} to ",
+                        prog.name,
+                        prog.id,
+                        if app.pin_maps { " and its maps" } else { "" }
+                    )
+                }
+                _ => {
+                    let Some(pin) = app.pin_to_unpin() else {
+                        return;
+                    };
+                    format!(
+                        " Type \"{}\" to unpin {}{} ",
+                        pin.confirmation(),
+                        pin.path,
+                        if pin.is_orphaned() {
+                            ", releasing the object"
+                        } else {
+                            ""
+                        }
+                    )
+                }
+            };
+            let prompt_footer = Paragraph::new(app.prompt_input.value()).block(
+                Block::default()
+                    .padding(Padding::horizontal(1))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Double)
+                    .title(title),
+            );
+
+            f.render_widget(prompt_footer, split_area[0]);
+
+            // Displays cursor when inputting
+            f.set_cursor_position((
+                split_area[0].x + app.prompt_input.visual_cursor() as u16 + 2,
+                split_area[0].y + 1,
+            ));
+        }
+        Mode::MapSearch => {
+            let Some(inspector) = app.map_inspector.as_ref() else {
+                return;
+            };
+            let search_footer = Paragraph::new(inspector.search_input.value()).block(
+                Block::default()
+                    .padding(Padding::horizontal(1))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Double)
+                    .title(" Search Key "),
+            );
+
+            f.render_widget(search_footer, split_area[0]);
+
+            // Displays cursor when inputting
+            f.set_cursor_position((
+                split_area[0].x + inspector.search_input.visual_cursor() as u16 + 2,
+                split_area[0].y + 1,
+            ));
+        }
         _ => {}
     }
 }
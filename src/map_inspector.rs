@@ -0,0 +1,360 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    bpf_map::BpfMap,
+    btf::{format_hex, Btf},
+};
+use anyhow::{anyhow, Result};
+use libbpf_rs::{MapCore, MapFlags, MapHandle, MapType};
+use ratatui::widgets::TableState;
+use std::{
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+};
+use tui_input::Input;
+
+// Number of entries shown per page
+pub const PAGE_SIZE: usize = 50;
+// Upper bound on the number of entries read from a single map
+const MAX_DUMP_ENTRIES: usize = 10_000;
+
+type Dump = Result<(Vec<MapEntry>, bool), String>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MapEntry {
+    pub key: String,
+    pub value: String,
+}
+
+pub struct MapInspector {
+    pub map: BpfMap,
+    pub entries: Vec<MapEntry>,
+    // Set when the map holds more than MAX_DUMP_ENTRIES entries
+    pub truncated: bool,
+    pub error: Option<String>,
+    pub page: usize,
+    pub table_state: TableState,
+    pub search_input: Input,
+    // Dumps can take a while on large maps, so they are read on a worker thread
+    pending: Option<Receiver<Dump>>,
+}
+
+impl MapInspector {
+    pub fn new(map: BpfMap) -> MapInspector {
+        let mut inspector = MapInspector {
+            map,
+            entries: vec![],
+            truncated: false,
+            error: None,
+            page: 0,
+            table_state: TableState::default(),
+            search_input: Input::default(),
+            pending: None,
+        };
+        inspector.refresh();
+        inspector
+    }
+
+    // Starts reading the map's entries, replacing any dump still in flight
+    pub fn refresh(&mut self) {
+        let (tx, rx) = mpsc::channel();
+        let map_id = self.map.id;
+        thread::spawn(move || {
+            // The receiver is gone if the inspector was closed or refreshed again
+            let _ = tx.send(dump_map(map_id).map_err(|e| e.to_string()));
+        });
+        self.pending = Some(rx);
+    }
+
+    pub fn loading(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    // Takes in the entries once the worker thread read them
+    pub fn poll(&mut self) {
+        let Some(pending) = &self.pending else {
+            return;
+        };
+        let dump = match pending.try_recv() {
+            Ok(dump) => dump,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => Err("Failed to read the map".to_string()),
+        };
+        self.pending = None;
+        match dump {
+            Ok((entries, truncated)) => {
+                self.entries = entries;
+                self.truncated = truncated;
+                self.error = None;
+            }
+            Err(e) => {
+                self.entries.clear();
+                self.truncated = false;
+                self.error = Some(e);
+            }
+        }
+        self.page = self.page.min(self.page_count() - 1);
+        self.table_state.select(None);
+    }
+
+    // Entries whose key matches the search input
+    pub fn matching_entries(&self) -> Vec<&MapEntry> {
+        let search = self.search_input.value().to_lowercase();
+        self.entries
+            .iter()
+            .filter(|entry| search.is_empty() || entry.key.to_lowercase().contains(&search))
+            .collect()
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.matching_entries().len().div_ceil(PAGE_SIZE).max(1)
+    }
+
+    pub fn page_entries(&self) -> Vec<&MapEntry> {
+        self.matching_entries()
+            .into_iter()
+            .skip(self.page * PAGE_SIZE)
+            .take(PAGE_SIZE)
+            .collect()
+    }
+
+    pub fn next_page(&mut self) {
+        if self.page + 1 < self.page_count() {
+            self.page += 1;
+            self.table_state.select(None);
+        }
+    }
+
+    pub fn previous_page(&mut self) {
+        if self.page > 0 {
+            self.page -= 1;
+            self.table_state.select(None);
+        }
+    }
+
+    pub fn next_entry(&mut self) {
+        let len = self.page_entries().len();
+        if len > 0 {
+            let i = match self.table_state.selected() {
+                Some(i) if i < len - 1 => i + 1,
+                _ => 0,
+            };
+            self.table_state.select(Some(i));
+        }
+    }
+
+    pub fn previous_entry(&mut self) {
+        let len = self.page_entries().len();
+        if len > 0 {
+            let i = match self.table_state.selected() {
+                Some(i) if i > 0 && i < len => i - 1,
+                _ => len - 1,
+            };
+            self.table_state.select(Some(i));
+        }
+    }
+
+    // Jumps back to the first page after the search input changed
+    pub fn reset_search_position(&mut self) {
+        self.page = 0;
+        self.table_state.select(None);
+    }
+}
+
+// Why the entries of a map type cannot be listed, for the types that have no keys to iterate
+fn unlistable(map_type: u32) -> Option<&'static str> {
+    match map_type {
+        libbpf_sys::BPF_MAP_TYPE_ARENA => {
+            Some("Arenas are pages shared with user space, not entries")
+        }
+        libbpf_sys::BPF_MAP_TYPE_BLOOM_FILTER => {
+            Some("Bloom filters can only be queried for membership")
+        }
+        libbpf_sys::BPF_MAP_TYPE_QUEUE | libbpf_sys::BPF_MAP_TYPE_STACK => {
+            Some("Queues and stacks can only be read by popping their entries")
+        }
+        libbpf_sys::BPF_MAP_TYPE_TASK_STORAGE
+        | libbpf_sys::BPF_MAP_TYPE_CGRP_STORAGE
+        | libbpf_sys::BPF_MAP_TYPE_INODE_STORAGE
+        | libbpf_sys::BPF_MAP_TYPE_SK_STORAGE => {
+            Some("Local storage is keyed by the fd of its owner and cannot be listed")
+        }
+        _ => None,
+    }
+}
+
+// Reads up to MAX_DUMP_ENTRIES entries from the map, decoding keys and values with the
+// map's BTF when available. Returns the entries and whether the dump was truncated.
+fn dump_map(id: u32) -> Result<(Vec<MapEntry>, bool)> {
+    let handle = MapHandle::from_map_id(id)?;
+    let info = handle.info()?;
+    let map_type = handle.map_type();
+
+    if let Some(reason) = unlistable(info.info.type_) {
+        return Err(anyhow!(reason));
+    }
+    if matches!(
+        map_type,
+        MapType::RingBuf | MapType::UserRingBuf | MapType::PerfEventArray
+    ) {
+        return Err(anyhow!("Dumping {:?} maps is not supported", map_type));
+    }
+
+    let btf = match info.info.btf_id {
+        0 => None,
+        btf_id => Btf::from_kernel_by_id(btf_id).ok(),
+    };
+    let format = |type_id: u32, data: &[u8]| match &btf {
+        Some(btf) if type_id != 0 => btf.format(type_id, data),
+        _ => format_hex(data),
+    };
+
+    let mut entries = vec![];
+    let mut truncated = false;
+    for key in handle.keys() {
+        if entries.len() == MAX_DUMP_ENTRIES {
+            truncated = true;
+            break;
+        }
+
+        let value = if map_type.is_percpu() {
+            handle.lookup_percpu(&key, MapFlags::ANY).map(|values| {
+                values.map(|values| {
+                    values
+                        .iter()
+                        .enumerate()
+                        .map(|(cpu, value)| {
+                            format!("cpu{}: {}", cpu, format(info.info.btf_value_type_id, value))
+                        })
+                        .collect::<Vec<String>>()
+                        .join(" | ")
+                })
+            })
+        } else {
+            handle
+                .lookup(&key, MapFlags::ANY)
+                .map(|value| value.map(|value| format(info.info.btf_value_type_id, &value)))
+        };
+
+        let value = match value {
+            Ok(Some(value)) => value,
+            // The entry was deleted between reading its key and looking it up
+            Ok(None) => continue,
+            Err(e) => format!("<{}>", e),
+        };
+
+        entries.push(MapEntry {
+            key: format(info.info.btf_key_type_id, &key),
+            value,
+        });
+    }
+
+    Ok((entries, truncated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inspector_with_entries(count: usize) -> MapInspector {
+        MapInspector {
+            map: BpfMap {
+                id: 1,
+                name: "test".to_string(),
+                map_type: "Hash".to_string(),
+                key_size: 4,
+                value_size: 4,
+                max_entries: 1024,
+                memlock: 4096,
+                map_extra: 0,
+                entries: None,
+                ops: None,
+                ringbuf: None,
+                pinned_paths: vec![],
+                prog_ids: vec![],
+                owners: vec![],
+            },
+            entries: (0..count)
+                .map(|i| MapEntry {
+                    key: format!("key{}", i),
+                    value: i.to_string(),
+                })
+                .collect(),
+            truncated: false,
+            error: None,
+            page: 0,
+            table_state: TableState::default(),
+            search_input: Input::default(),
+            pending: None,
+        }
+    }
+
+    #[test]
+    fn test_paging() {
+        let mut inspector = inspector_with_entries(PAGE_SIZE + 10);
+        assert_eq!(inspector.page_count(), 2);
+        assert_eq!(inspector.page_entries().len(), PAGE_SIZE);
+
+        inspector.next_page();
+        assert_eq!(inspector.page, 1);
+        assert_eq!(inspector.page_entries().len(), 10);
+
+        // Can't move past the last page
+        inspector.next_page();
+        assert_eq!(inspector.page, 1);
+
+        inspector.previous_page();
+        inspector.previous_page();
+        assert_eq!(inspector.page, 0);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut inspector = inspector_with_entries(20);
+        inspector.search_input = Input::new("key1".to_string());
+        // key1 and key10..key19
+        assert_eq!(inspector.matching_entries().len(), 11);
+        assert_eq!(inspector.page_count(), 1);
+    }
+
+    #[test]
+    fn test_poll() {
+        let mut inspector = inspector_with_entries(0);
+        let (tx, rx) = mpsc::channel();
+        inspector.pending = Some(rx);
+        inspector.poll();
+        assert!(inspector.loading());
+
+        let entries = vec![MapEntry {
+            key: "1".to_string(),
+            value: "2".to_string(),
+        }];
+        tx.send(Ok((entries.clone(), true))).unwrap();
+        inspector.poll();
+        assert!(!inspector.loading());
+        assert_eq!(inspector.entries, entries);
+        assert!(inspector.truncated);
+    }
+
+    #[test]
+    fn test_empty_page_count() {
+        let inspector = inspector_with_entries(0);
+        assert_eq!(inspector.page_count(), 1);
+        assert!(inspector.page_entries().is_empty());
+    }
+}
@@ -0,0 +1,254 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use libbpf_rs::{MapCore, MapFlags, MapHandle, MapType};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+// Maps larger than this are not tracked to bound the memory used by fingerprints
+const MAX_TRACKED_ENTRIES: u32 = 65_536;
+// How often the tracked maps are diffed
+const OPS_INTERVAL: Duration = Duration::from_secs(1);
+
+// Keys that changed in a map between two samples
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MapOps {
+    pub inserts: u64,
+    pub updates: u64,
+    pub deletes: u64,
+    pub period_ns: u128,
+}
+
+impl MapOps {
+    pub fn changed_keys_per_second(&self) -> u64 {
+        if self.period_ns == 0 {
+            return 0;
+        }
+        let ops = self.inserts + self.updates + self.deletes;
+        (ops as f64 / self.period_ns as f64 * 1_000_000_000.0).round() as u64
+    }
+}
+
+struct MapSnapshot {
+    // Key to a hash of its value
+    fingerprints: HashMap<Vec<u8>, u64>,
+    instant: Instant,
+}
+
+/// Counts the keys inserted, deleted or given a new value in each map by diffing its
+/// contents between samples. This is not an operation rate: lookups, writes that leave a
+/// value unchanged and repeated updates of a key within a sample are invisible. Each sample
+/// reads every entry of every tracked map, which is why tracking is opt-in and limited to
+/// maps of up to MAX_TRACKED_ENTRIES entries.
+#[derive(Default)]
+struct MapOpsTracker {
+    snapshots: HashMap<u32, MapSnapshot>,
+}
+
+impl MapOpsTracker {
+    fn is_tracked(map_type: MapType, max_entries: u32) -> bool {
+        max_entries <= MAX_TRACKED_ENTRIES
+            && matches!(
+                map_type,
+                MapType::Hash
+                    | MapType::LruHash
+                    | MapType::PercpuHash
+                    | MapType::LruPercpuHash
+                    | MapType::Array
+                    | MapType::PercpuArray
+                    | MapType::LpmTrie
+            )
+    }
+
+    /// Samples the given map and returns the operations seen since the previous sample,
+    /// or None if this is the first time the map is sampled.
+    fn sample(&mut self, id: u32, handle: &MapHandle) -> Option<MapOps> {
+        let snapshot = MapSnapshot {
+            fingerprints: fingerprints(handle),
+            instant: Instant::now(),
+        };
+        let ops = self
+            .snapshots
+            .get(&id)
+            .map(|prev| diff(&prev.fingerprints, &snapshot.fingerprints, prev.instant));
+        self.snapshots.insert(id, snapshot);
+        ops
+    }
+
+    // Samples every tracked map on the host, dropping the snapshots of maps that no longer
+    // exist
+    fn sample_all(&mut self, stop: &mpsc::Receiver<()>) -> HashMap<u32, MapOps> {
+        let mut ops = HashMap::new();
+        let mut ids = vec![];
+        let mut id = 0;
+        while unsafe { libbpf_sys::bpf_map_get_next_id(id, &mut id) } == 0 {
+            if stop.try_recv() != Err(mpsc::TryRecvError::Empty) {
+                break;
+            }
+            let Ok(handle) = MapHandle::from_map_id(id) else {
+                continue;
+            };
+            let Ok(info) = handle.info() else {
+                continue;
+            };
+            if !MapOpsTracker::is_tracked(MapType::from(info.info.type_), info.info.max_entries) {
+                continue;
+            }
+            ids.push(id);
+            if let Some(map_ops) = self.sample(id, &handle) {
+                ops.insert(id, map_ops);
+            }
+        }
+        self.snapshots.retain(|id, _| ids.contains(id));
+        ops
+    }
+}
+
+// Diffs the tracked maps every OPS_INTERVAL on its own thread while tracking is enabled, as
+// looking up every key one syscall at a time would stretch the collector's sample period.
+// The thread stops once the worker is dropped.
+pub struct MapOpsWorker {
+    ops: Arc<Mutex<HashMap<u32, MapOps>>>,
+    _stop: Sender<()>,
+}
+
+impl MapOpsWorker {
+    pub fn start(enabled: Arc<AtomicBool>) -> MapOpsWorker {
+        let ops = Arc::new(Mutex::new(HashMap::new()));
+        let (stop, stopped) = mpsc::channel();
+        let shared = Arc::clone(&ops);
+        thread::spawn(move || {
+            let mut tracker = MapOpsTracker::default();
+            loop {
+                let new_ops = if enabled.load(Ordering::Relaxed) {
+                    tracker.sample_all(&stopped)
+                } else {
+                    // Tracking starts over from fresh snapshots once enabled again
+                    tracker.snapshots.clear();
+                    HashMap::new()
+                };
+                *shared.lock().unwrap() = new_ops;
+                if stopped.recv_timeout(OPS_INTERVAL) != Err(RecvTimeoutError::Timeout) {
+                    return;
+                }
+            }
+        });
+        MapOpsWorker { ops, _stop: stop }
+    }
+
+    // The changed keys of the latest round, empty until a map was diffed twice
+    pub fn ops(&self) -> HashMap<u32, MapOps> {
+        self.ops.lock().unwrap().clone()
+    }
+}
+
+fn fingerprints(handle: &MapHandle) -> HashMap<Vec<u8>, u64> {
+    let percpu = handle.map_type().is_percpu();
+    let mut fingerprints = HashMap::new();
+
+    for key in handle.keys().take(MAX_TRACKED_ENTRIES as usize) {
+        let mut hasher = DefaultHasher::new();
+        let found = if percpu {
+            match handle.lookup_percpu(&key, MapFlags::ANY) {
+                Ok(Some(values)) => {
+                    values.hash(&mut hasher);
+                    true
+                }
+                _ => false,
+            }
+        } else {
+            match handle.lookup(&key, MapFlags::ANY) {
+                Ok(Some(value)) => {
+                    value.hash(&mut hasher);
+                    true
+                }
+                _ => false,
+            }
+        };
+        if found {
+            fingerprints.insert(key, hasher.finish());
+        }
+    }
+
+    fingerprints
+}
+
+fn diff(prev: &HashMap<Vec<u8>, u64>, curr: &HashMap<Vec<u8>, u64>, since: Instant) -> MapOps {
+    let mut ops = MapOps {
+        period_ns: since.elapsed().as_nanos(),
+        ..Default::default()
+    };
+
+    for (key, hash) in curr {
+        match prev.get(key) {
+            None => ops.inserts += 1,
+            Some(prev_hash) if prev_hash != hash => ops.updates += 1,
+            Some(_) => {}
+        }
+    }
+    ops.deletes = prev.keys().filter(|key| !curr.contains_key(*key)).count() as u64;
+
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let prev: HashMap<Vec<u8>, u64> =
+            HashMap::from([(vec![1], 10), (vec![2], 20), (vec![3], 30)]);
+        let curr: HashMap<Vec<u8>, u64> =
+            HashMap::from([(vec![1], 10), (vec![2], 21), (vec![4], 40), (vec![5], 50)]);
+
+        let ops = diff(&prev, &curr, Instant::now());
+        assert_eq!(ops.inserts, 2);
+        assert_eq!(ops.updates, 1);
+        assert_eq!(ops.deletes, 1);
+    }
+
+    #[test]
+    fn test_changed_keys_per_second() {
+        let ops = MapOps {
+            inserts: 10,
+            updates: 5,
+            deletes: 5,
+            period_ns: 2_000_000_000,
+        };
+        assert_eq!(ops.changed_keys_per_second(), 10);
+
+        let ops = MapOps::default();
+        assert_eq!(ops.changed_keys_per_second(), 0);
+    }
+
+    #[test]
+    fn test_is_tracked() {
+        assert!(MapOpsTracker::is_tracked(MapType::Hash, 1024));
+        assert!(!MapOpsTracker::is_tracked(MapType::Hash, 1 << 20));
+        assert!(!MapOpsTracker::is_tracked(MapType::RingBuf, 1024));
+    }
+}
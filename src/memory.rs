@@ -0,0 +1,214 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::bpf_map::BpfMap;
+use bpftop_core::{
+    bpf_program::BpfProgram,
+    helpers::{fdinfo_field, format_bytes},
+};
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    mem::size_of_val,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+};
+
+// A program, map or BTF object and the memory charged for it
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryConsumer {
+    pub kind: &'static str,
+    pub id: u32,
+    pub name: String,
+    pub type_name: String,
+    pub bytes: u64,
+}
+
+// Memory used by BPF objects across the host
+#[derive(Clone, Debug, Default)]
+pub struct MemoryUsage {
+    pub programs: u64,
+    pub maps: u64,
+    pub btf: u64,
+    // Largest first
+    pub consumers: Vec<MemoryConsumer>,
+}
+
+impl MemoryUsage {
+    pub fn new(mut consumers: Vec<MemoryConsumer>) -> MemoryUsage {
+        let total = |kind| {
+            consumers
+                .iter()
+                .filter(|consumer| consumer.kind == kind)
+                .map(|consumer| consumer.bytes)
+                .sum()
+        };
+        let (programs, maps, btf) = (total("program"), total("map"), total("btf"));
+        consumers.sort_unstable_by(|a, b| b.bytes.cmp(&a.bytes).then(a.id.cmp(&b.id)));
+        MemoryUsage {
+            programs,
+            maps,
+            btf,
+            consumers,
+        }
+    }
+
+    pub fn total(&self) -> u64 {
+        self.programs + self.maps + self.btf
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "BPF memory: {} | programs {} | maps {} | BTF {}",
+            format_bytes(self.total()),
+            format_bytes(self.programs),
+            format_bytes(self.maps),
+            format_bytes(self.btf)
+        )
+    }
+
+    // Number of objects and memory used per kind and type, largest first
+    pub fn by_type(&self) -> Vec<(&'static str, &str, usize, u64)> {
+        let mut types: HashMap<(&'static str, &str), (usize, u64)> = HashMap::new();
+        for consumer in &self.consumers {
+            let entry = types
+                .entry((consumer.kind, &consumer.type_name))
+                .or_default();
+            entry.0 += 1;
+            entry.1 += consumer.bytes;
+        }
+        let mut types: Vec<_> = types
+            .into_iter()
+            .map(|((kind, type_name), (count, bytes))| (kind, type_name, count, bytes))
+            .collect();
+        types.sort_unstable_by(|a, b| b.3.cmp(&a.3).then(a.1.cmp(b.1)));
+        types
+    }
+}
+
+// Programs report the memory charged for them in their fdinfo, like maps do
+pub fn program_memory(programs: &[BpfProgram]) -> Vec<MemoryConsumer> {
+    programs
+        .iter()
+        .filter_map(|prog| {
+            let fd = unsafe { libbpf_sys::bpf_prog_get_fd_by_id(prog.id) };
+            if fd < 0 {
+                return None;
+            }
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+            Some(MemoryConsumer {
+                kind: "program",
+                id: prog.id,
+                name: prog.name.clone(),
+                type_name: prog.bpf_type.clone(),
+                bytes: fdinfo_field(&fd, "memlock")?,
+            })
+        })
+        .collect()
+}
+
+pub fn map_memory(maps: &[BpfMap]) -> Vec<MemoryConsumer> {
+    maps.iter()
+        .map(|map| MemoryConsumer {
+            kind: "map",
+            id: map.id,
+            name: map.name.clone(),
+            type_name: map.map_type.clone(),
+            bytes: map.memlock,
+        })
+        .collect()
+}
+
+// BTF loaded along with programs and maps. The kernel's own BTF for vmlinux and modules is
+// not counted, as it is there whether BPF is used or not.
+pub fn btf_memory() -> Vec<MemoryConsumer> {
+    let mut consumers = vec![];
+    let mut id = 0;
+    loop {
+        if unsafe { libbpf_sys::bpf_btf_get_next_id(id, &mut id) } != 0 {
+            break;
+        }
+        // The BTF may have been released since its ID was returned
+        let fd = unsafe { libbpf_sys::bpf_btf_get_fd_by_id(id) };
+        if fd < 0 {
+            continue;
+        }
+        let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+        let mut info = libbpf_sys::bpf_btf_info::default();
+        let mut len = size_of_val(&info) as u32;
+        let ret = unsafe {
+            libbpf_sys::bpf_obj_get_info_by_fd(
+                fd.as_raw_fd(),
+                &mut info as *mut _ as *mut c_void,
+                &mut len,
+            )
+        };
+        if ret != 0 || info.kernel_btf != 0 {
+            continue;
+        }
+        consumers.push(MemoryConsumer {
+            kind: "btf",
+            id,
+            name: String::new(),
+            type_name: "BTF".to_string(),
+            bytes: info.btf_size as u64,
+        });
+    }
+    consumers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn consumer(kind: &'static str, id: u32, type_name: &str, bytes: u64) -> MemoryConsumer {
+        MemoryConsumer {
+            kind,
+            id,
+            name: String::new(),
+            type_name: type_name.to_string(),
+            bytes,
+        }
+    }
+
+    #[test]
+    fn test_memory_usage() {
+        let usage = MemoryUsage::new(vec![
+            consumer("program", 1, "Xdp", 4096),
+            consumer("map", 2, "Hash", 1 << 20),
+            consumer("map", 3, "Hash", 8192),
+            consumer("map", 4, "RingBuf", 1 << 16),
+            consumer("btf", 5, "BTF", 2048),
+        ]);
+        assert_eq!(usage.maps, (1 << 20) + 8192 + (1 << 16));
+        assert_eq!(usage.total(), usage.maps + 4096 + 2048);
+        assert_eq!(usage.consumers[0].id, 2);
+        assert_eq!(usage.consumers[4].id, 5);
+        assert_eq!(
+            usage.by_type(),
+            vec![
+                ("map", "Hash", 2, (1 << 20) + 8192),
+                ("map", "RingBuf", 1, 1 << 16),
+                ("program", "Xdp", 1, 4096),
+                ("btf", "BTF", 1, 2048),
+            ]
+        );
+        assert_eq!(
+            MemoryUsage::default().summary(),
+            "BPF memory: 0 B | programs 0 B | maps 0 B | BTF 0 B"
+        );
+    }
+}
@@ -0,0 +1,276 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{exporter::Exporter, snapshot::Sample};
+use anyhow::{anyhow, Context, Result};
+use rumqttc::{Client, ClientError, Connection, Event, MqttOptions, Packet, QoS, Transport};
+use serde::Serialize;
+use std::{
+    env, process,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+use tracing::warn;
+
+// Broker credentials, read from the environment so they do not show up in the process list
+pub const USERNAME_ENV: &str = "BPFTOP_MQTT_USERNAME";
+pub const PASSWORD_ENV: &str = "BPFTOP_MQTT_PASSWORD";
+
+const DEFAULT_PORT: u16 = 1883;
+const DEFAULT_TLS_PORT: u16 = 8883;
+// Samples are published every second, which keeps the connection alive well within this
+const KEEP_ALIVE: Duration = Duration::from_secs(60);
+// How long to wait after a failed connection before trying again
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(5);
+// Samples waiting for the connection. Newer ones are dropped rather than queued, as edge
+// links are often slow and a backlog would only grow.
+const QUEUED_SAMPLES: usize = 2;
+
+#[derive(Debug, PartialEq)]
+struct Broker {
+    tls: bool,
+    host: String,
+    port: u16,
+}
+
+// Parses tcp://host[:port] and mqtt://host[:port] broker addresses, and ssl://host[:port]
+// and mqtts://host[:port] for TLS
+fn parse_broker(url: &str) -> Result<Broker> {
+    let (scheme, authority) = url
+        .split_once("://")
+        .ok_or_else(|| anyhow!("Expected a broker URL such as mqtts://broker, got {}", url))?;
+    let tls = match scheme {
+        "tcp" | "mqtt" => false,
+        "ssl" | "mqtts" => true,
+        _ => return Err(anyhow!("Unsupported broker scheme in {}", url)),
+    };
+    let authority = authority.trim_end_matches('/');
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (
+            host,
+            port.parse()
+                .with_context(|| format!("Invalid port in {}", url))?,
+        ),
+        None => (authority, if tls { DEFAULT_TLS_PORT } else { DEFAULT_PORT }),
+    };
+    if host.is_empty() {
+        return Err(anyhow!("Missing host in {}", url));
+    }
+    Ok(Broker {
+        tls,
+        host: host.to_string(),
+        port,
+    })
+}
+
+fn mqtt_options(broker: &Broker) -> MqttOptions {
+    let mut options = MqttOptions::new(
+        format!("bpftop-{}", process::id()),
+        broker.host.clone(),
+        broker.port,
+    );
+    // Nothing is kept between sessions
+    options.set_keep_alive(KEEP_ALIVE).set_clean_session(true);
+    if broker.tls {
+        // Verified against the system's trusted certificates
+        options.set_transport(Transport::tls_with_default_config());
+    }
+    if let (Ok(username), Ok(password)) = (env::var(USERNAME_ENV), env::var(PASSWORD_ENV)) {
+        if !broker.tls {
+            warn!("Sending MQTT credentials unencrypted, use an mqtts:// broker to avoid it");
+        }
+        options.set_credentials(username, password);
+    }
+    options
+}
+
+#[derive(Debug, Serialize)]
+struct CompactProgram<'a> {
+    id: u32,
+    n: &'a str,
+    t: &'a str,
+    cpu: f64,
+    eps: i64,
+    ns: u64,
+}
+
+// A sample with short keys, to keep what is sent over metered edge links small
+#[derive(Debug, Serialize)]
+struct CompactSample<'a> {
+    ts: u64,
+    p: Vec<CompactProgram<'a>>,
+}
+
+fn compact_sample(sample: &Sample) -> Result<Vec<u8>> {
+    let compact = CompactSample {
+        ts: sample.timestamp.wall_ms,
+        p: sample
+            .programs
+            .iter()
+            // Programs without a full period of stats yet have nothing to report
+            .filter(|prog| prog.period_ns > 0)
+            .map(|prog| CompactProgram {
+                id: prog.id,
+                n: &prog.name,
+                t: &prog.bpf_type,
+                cpu: prog.cpu_time_percent(),
+                eps: prog.events_per_second(),
+                ns: prog.period_average_runtime_ns(),
+            })
+            .collect(),
+    };
+    Ok(serde_json::to_vec(&compact)?)
+}
+
+// Drives the connection, which reconnects on the next iteration after a failure, recording
+// the last failure for the exporter to report
+fn event_loop(mut connection: Connection, failure: Arc<Mutex<Option<String>>>) {
+    for event in connection.iter() {
+        match event {
+            Ok(Event::Incoming(Packet::ConnAck(_))) => *failure.lock().unwrap() = None,
+            Ok(_) => {}
+            Err(e) => {
+                *failure.lock().unwrap() = Some(e.to_string());
+                thread::sleep(RECONNECT_INTERVAL);
+            }
+        }
+    }
+}
+
+// Publishes every sample as compact JSON to a topic on an MQTT broker, for edge gateways
+// that report through MQTT. The connection is kept up on a thread of its own, so a slow or
+// unreachable broker does not hold up the collector.
+pub struct MqttPublisher {
+    name: String,
+    topic: String,
+    client: Client,
+    failure: Arc<Mutex<Option<String>>>,
+}
+
+impl MqttPublisher {
+    pub fn connect(broker: &str, topic: &str) -> Result<Self> {
+        let options = mqtt_options(&parse_broker(broker)?);
+        if topic.is_empty() || topic.contains(['+', '#']) {
+            return Err(anyhow!("Invalid MQTT topic '{}'", topic));
+        }
+        let (client, connection) = Client::new(options, QUEUED_SAMPLES);
+        let failure = Arc::new(Mutex::new(None));
+        {
+            let failure = Arc::clone(&failure);
+            thread::spawn(move || event_loop(connection, failure));
+        }
+        Ok(MqttPublisher {
+            name: format!("{} {}", broker, topic),
+            topic: topic.to_string(),
+            client,
+            failure,
+        })
+    }
+}
+
+impl Exporter for MqttPublisher {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn export(&mut self, sample: &Sample) -> Result<()> {
+        // Samples are published with QoS 0, as the next one follows a second later anyway
+        match self
+            .client
+            .try_publish(&self.topic, QoS::AtMostOnce, false, compact_sample(sample)?)
+        {
+            Ok(()) | Err(ClientError::TryRequest(_)) => {}
+            Err(ClientError::Request(_)) => return Err(anyhow!("Publisher thread exited")),
+        }
+        match self.failure.lock().unwrap().as_ref() {
+            Some(failure) => Err(anyhow!("{}", failure)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bpftop_core::sampler::Timestamp;
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        time::Instant,
+    };
+
+    #[test]
+    fn test_parse_broker() {
+        assert_eq!(
+            parse_broker("tcp://broker:1884").unwrap(),
+            Broker {
+                tls: false,
+                host: "broker".to_string(),
+                port: 1884,
+            }
+        );
+        assert_eq!(parse_broker("mqtt://10.0.0.1").unwrap().port, DEFAULT_PORT);
+        assert!(parse_broker("mqtts://broker").unwrap().tls);
+        assert_eq!(parse_broker("ssl://broker").unwrap().port, DEFAULT_TLS_PORT);
+        assert!(parse_broker("http://broker:8883").is_err());
+        assert!(parse_broker("broker:1883").is_err());
+    }
+
+    // Reads one packet, returning its type and body
+    fn read_packet(stream: &mut TcpStream) -> (u8, Vec<u8>) {
+        let mut byte = [0];
+        stream.read_exact(&mut byte).unwrap();
+        let header = byte[0];
+        let (mut len, mut shift) = (0, 0);
+        loop {
+            stream.read_exact(&mut byte).unwrap();
+            len |= ((byte[0] & 0x7f) as usize) << shift;
+            shift += 7;
+            if byte[0] & 0x80 == 0 {
+                break;
+            }
+        }
+        let mut body = vec![0; len];
+        stream.read_exact(&mut body).unwrap();
+        (header >> 4, body)
+    }
+
+    #[test]
+    fn test_publish() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let broker = format!("tcp://{}", listener.local_addr().unwrap());
+        let mut publisher = MqttPublisher::connect(&broker, "bpftop/edge-1").unwrap();
+
+        let (mut stream, _) = listener.accept().unwrap();
+        assert_eq!(read_packet(&mut stream).0, 1);
+        // CONNACK accepting the session
+        stream.write_all(&[0x20, 0x02, 0, 0]).unwrap();
+        let sample = Sample {
+            timestamp: Timestamp {
+                instant: Instant::now(),
+                wall_ms: 1_700_000_000_000,
+            },
+            ..Default::default()
+        };
+        publisher.export(&sample).unwrap();
+        let (packet_type, body) = read_packet(&mut stream);
+        assert_eq!(packet_type, 3);
+        assert_eq!(&body[..15], b"\x00\x0dbpftop/edge-1");
+        assert_eq!(&body[15..], br#"{"ts":1700000000000,"p":[]}"#);
+    }
+}
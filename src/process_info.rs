@@ -0,0 +1,153 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::status::format_utc;
+use anyhow::{bail, Context, Result};
+use bpftop_core::{bpf_program::Process, container::parse_cgroup};
+use nix::unistd::{Uid, User};
+use std::fs;
+
+// Details of a process holding a program, read from procfs for the popup of the graph view
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessInfo {
+    pub pid: i32,
+    pub comm: String,
+    pub cmdline: String,
+    pub user: String,
+    pub started: String,
+    pub cgroup: String,
+    pub container: String,
+}
+
+impl ProcessInfo {
+    // Fails if the process has exited, or its PID has been reused since it was sampled
+    pub fn read(holder: &Process) -> Result<ProcessInfo> {
+        let comm = holder.comm.clone();
+        let process = procfs::process::Process::new(holder.pid)
+            .with_context(|| format!("Process {} ({}) has exited", comm, holder.pid))?;
+        let stat = process
+            .stat()
+            .with_context(|| format!("Failed to read the stat of process {}", holder.pid))?;
+        if stat.comm != comm {
+            bail!(
+                "PID {} now belongs to {}, not {}",
+                holder.pid,
+                stat.comm,
+                comm
+            );
+        }
+
+        let cmdline = process.cmdline().unwrap_or_default();
+        let user = process
+            .uid()
+            .map(|uid| match User::from_uid(Uid::from_raw(uid)) {
+                Ok(Some(user)) => format!("{} ({})", user.name, uid),
+                _ => uid.to_string(),
+            })
+            .unwrap_or_else(|_| "unknown".to_string());
+        // The start time is in clock ticks since boot
+        let started = procfs::boot_time_secs()
+            .map(|boot| {
+                let ms = boot * 1000 + stat.starttime * 1000 / procfs::ticks_per_second();
+                format_utc(ms)
+            })
+            .unwrap_or_else(|_| "unknown".to_string());
+        let cgroups =
+            fs::read_to_string(format!("/proc/{}/cgroup", holder.pid)).unwrap_or_default();
+        let container = parse_cgroup(&cgroups)
+            .map(|container| format!("{} ({})", container.display_name(), container.runtime))
+            .unwrap_or_else(|| "none".to_string());
+
+        Ok(ProcessInfo {
+            pid: holder.pid,
+            cmdline: format_cmdline(&cmdline, &comm),
+            comm,
+            user,
+            started,
+            cgroup: unified_cgroup(&cgroups).unwrap_or("unknown").to_string(),
+            container,
+        })
+    }
+
+    pub fn lines(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("Command line", &self.cmdline),
+            ("User", &self.user),
+            ("Started", &self.started),
+            ("Cgroup", &self.cgroup),
+            ("Container", &self.container),
+        ]
+    }
+}
+
+// Kernel threads have no command line, and are shown by their comm in brackets like ps does
+fn format_cmdline(args: &[String], comm: &str) -> String {
+    if args.is_empty() {
+        format!("[{}]", comm)
+    } else {
+        args.join(" ")
+    }
+}
+
+// The path of the process in the cgroup v2 hierarchy, from the contents of /proc/<pid>/cgroup
+fn unified_cgroup(contents: &str) -> Option<&str> {
+    contents.lines().find_map(|line| line.strip_prefix("0::"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read() {
+        let comm = fs::read_to_string("/proc/self/comm").unwrap();
+        let mut holder = Process {
+            pid: std::process::id() as i32,
+            comm: comm.trim_end().to_string(),
+            container: None,
+            cgroup_id: 0,
+            bpf_fds: 1,
+            user: None,
+        };
+        let info = ProcessInfo::read(&holder).unwrap();
+        assert_eq!(info.comm, comm.trim_end());
+        assert!(info.started.ends_with(" UTC"));
+
+        holder.comm = "not-bpftop".to_string();
+        assert!(ProcessInfo::read(&holder).is_err());
+    }
+
+    #[test]
+    fn test_format_cmdline() {
+        let args = ["/usr/bin/parca-agent".to_string(), "--node=a".to_string()];
+        assert_eq!(
+            format_cmdline(&args, "parca-agent"),
+            "/usr/bin/parca-agent --node=a"
+        );
+        assert_eq!(format_cmdline(&[], "kworker/0:1"), "[kworker/0:1]");
+    }
+
+    #[test]
+    fn test_unified_cgroup() {
+        let contents = "12:pids:/system.slice\n0::/system.slice/parca-agent.service\n";
+        assert_eq!(
+            unified_cgroup(contents),
+            Some("/system.slice/parca-agent.service")
+        );
+        assert_eq!(unified_cgroup("12:pids:/\n"), None);
+    }
+}
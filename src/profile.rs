@@ -0,0 +1,300 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{btf::Btf, profiler::ProfilerSkelBuilder};
+use anyhow::{anyhow, Result};
+use libbpf_rs::{
+    num_possible_cpus,
+    query::{ProgInfoIter, ProgInfoQueryOptions},
+    skel::{OpenSkel, Skel, SkelBuilder},
+    MapCore, MapFlags,
+};
+use nix::libc;
+use std::{
+    mem::{size_of, MaybeUninit},
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+    thread,
+    time::Duration,
+};
+
+// How long the profiler stays attached to the selected program
+pub const PROFILE_DURATION: Duration = Duration::from_secs(5);
+
+// Must not exceed MAX_NUM_METRICS in profiler.bpf.c
+const METRICS: [Metric; 3] = [
+    Metric {
+        name: "cycles",
+        type_: libbpf_sys::PERF_TYPE_HARDWARE,
+        config: libbpf_sys::PERF_COUNT_HW_CPU_CYCLES as u64,
+    },
+    Metric {
+        name: "instructions",
+        type_: libbpf_sys::PERF_TYPE_HARDWARE,
+        config: libbpf_sys::PERF_COUNT_HW_INSTRUCTIONS as u64,
+    },
+    Metric {
+        name: "llc_misses",
+        type_: libbpf_sys::PERF_TYPE_HW_CACHE,
+        config: libbpf_sys::PERF_COUNT_HW_CACHE_LL as u64
+            | (libbpf_sys::PERF_COUNT_HW_CACHE_OP_READ as u64) << 8
+            | (libbpf_sys::PERF_COUNT_HW_CACHE_RESULT_MISS as u64) << 16,
+    },
+];
+
+struct Metric {
+    name: &'static str,
+    type_: u32,
+    config: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricReading {
+    pub name: &'static str,
+    pub value: u64,
+}
+
+// Hardware counter totals collected while the profiler was attached
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileReport {
+    pub run_cnt: u64,
+    pub metrics: Vec<MetricReading>,
+}
+
+impl ProfileReport {
+    pub fn per_run(&self, reading: &MetricReading) -> f64 {
+        if self.run_cnt == 0 {
+            return 0.0;
+        }
+        reading.value as f64 / self.run_cnt as f64
+    }
+
+    fn metric(&self, name: &str) -> Option<u64> {
+        self.metrics
+            .iter()
+            .find(|reading| reading.name == name)
+            .map(|reading| reading.value)
+    }
+
+    pub fn instructions_per_cycle(&self) -> Option<f64> {
+        let cycles = self.metric("cycles").filter(|cycles| *cycles > 0)?;
+        Some(self.metric("instructions")? as f64 / cycles as f64)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProfileState {
+    Running(u32),
+    Done(u32, ProfileReport),
+    Failed(u32, String),
+}
+
+// Accumulated counter value of one metric for a single CPU, see bpf_perf_event_value
+#[repr(C)]
+#[derive(Default)]
+struct PerfEventValue {
+    counter: u64,
+    enabled: u64,
+    running: u64,
+}
+
+// Returns the name of the program's entry function, which fentry/fexit attach to
+fn prog_func_name(prog_id: u32) -> Result<String> {
+    let prog =
+        ProgInfoIter::with_query_opts(ProgInfoQueryOptions::default().include_func_info(true))
+            .find(|prog| prog.id == prog_id)
+            .ok_or_else(|| anyhow!("Program {} no longer exists", prog_id))?;
+    let func_info = prog
+        .func_info
+        .first()
+        .filter(|_| prog.btf_id != 0)
+        .ok_or_else(|| anyhow!("Program {} has no BTF and cannot be profiled", prog_id))?;
+    let btf = Btf::from_kernel_by_id(prog.btf_id)?;
+    Ok(btf.type_name(func_info.type_id).to_string())
+}
+
+// Returns an fd and entry function name for pointing fentry/fexit programs at `prog_id`
+pub fn attach_target(prog_id: u32) -> Result<(OwnedFd, String)> {
+    let func_name = prog_func_name(prog_id)?;
+    let prog_fd = unsafe { libbpf_sys::bpf_prog_get_fd_by_id(prog_id) };
+    if prog_fd < 0 {
+        return Err(anyhow!("Failed to get fd for program {}", prog_id));
+    }
+    Ok((unsafe { OwnedFd::from_raw_fd(prog_fd) }, func_name))
+}
+
+fn perf_event_open(metric: &Metric, cpu: i32) -> Option<OwnedFd> {
+    let mut attr: libbpf_sys::perf_event_attr = unsafe { std::mem::zeroed() };
+    attr.type_ = metric.type_;
+    attr.size = size_of::<libbpf_sys::perf_event_attr>() as u32;
+    attr.config = metric.config;
+
+    let fd = unsafe {
+        libc::syscall(
+            libc::SYS_perf_event_open,
+            &attr as *const libbpf_sys::perf_event_attr,
+            -1,
+            cpu,
+            -1,
+            0,
+        )
+    };
+    (fd >= 0).then(|| unsafe { OwnedFd::from_raw_fd(fd as i32) })
+}
+
+// Scales a counter up when the PMU was multiplexed between events
+fn scale(value: &PerfEventValue) -> u64 {
+    if value.running == 0 || value.running >= value.enabled {
+        return value.counter;
+    }
+    (value.counter as f64 * value.enabled as f64 / value.running as f64) as u64
+}
+
+pub fn read_value<T: Default>(bytes: &[u8]) -> T {
+    let mut value = T::default();
+    let len = bytes.len().min(size_of::<T>());
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), &mut value as *mut T as *mut u8, len);
+    }
+    value
+}
+
+// Attaches fentry/fexit programs to the given program and reports the hardware counters
+// it consumed per invocation over `duration`, the same way `bpftool prog profile` does.
+// Blocks for the whole sampling window.
+pub fn profile_program(prog_id: u32, duration: Duration) -> Result<ProfileReport> {
+    let (prog_fd, func_name) = attach_target(prog_id)?;
+    let num_cpu = num_possible_cpus()?;
+
+    let skel_builder = ProfilerSkelBuilder::default();
+    let mut open_object = MaybeUninit::uninit();
+    let mut open_skel = skel_builder.open(&mut open_object)?;
+    open_skel.maps.rodata_data.num_cpu = num_cpu as u32;
+    open_skel.maps.rodata_data.num_metric = METRICS.len() as u32;
+    open_skel
+        .maps
+        .events
+        .set_max_entries((num_cpu * METRICS.len()) as u32)?;
+    open_skel
+        .progs
+        .fentry_XXX
+        .set_attach_target(prog_fd.as_raw_fd(), Some(func_name.clone()))?;
+    open_skel
+        .progs
+        .fexit_XXX
+        .set_attach_target(prog_fd.as_raw_fd(), Some(func_name))?;
+    let mut skel = open_skel.load()?;
+
+    // Offline CPUs cannot be opened, but the metric needs at least one counter
+    let mut perf_fds = vec![];
+    for (i, metric) in METRICS.iter().enumerate() {
+        let opened = perf_fds.len();
+        for cpu in 0..num_cpu {
+            let Some(fd) = perf_event_open(metric, cpu as i32) else {
+                continue;
+            };
+            let key = ((i * num_cpu + cpu) as u32).to_ne_bytes();
+            skel.maps
+                .events
+                .update(&key, &fd.as_raw_fd().to_ne_bytes(), MapFlags::ANY)?;
+            perf_fds.push(fd);
+        }
+        if perf_fds.len() == opened {
+            return Err(anyhow!(
+                "Failed to open {} counter, hardware counters may be unavailable",
+                metric.name
+            ));
+        }
+    }
+
+    skel.attach()?;
+    thread::sleep(duration);
+
+    let mut metrics = vec![];
+    for (i, metric) in METRICS.iter().enumerate() {
+        let key = (i as u32).to_ne_bytes();
+        let per_cpu = skel
+            .maps
+            .accum_readings
+            .lookup_percpu(&key, MapFlags::ANY)?
+            .unwrap_or_default();
+        let value = per_cpu
+            .iter()
+            .map(|bytes| scale(&read_value::<PerfEventValue>(bytes)))
+            .sum();
+        metrics.push(MetricReading {
+            name: metric.name,
+            value,
+        });
+    }
+    let run_cnt = skel
+        .maps
+        .counts
+        .lookup_percpu(&0u32.to_ne_bytes(), MapFlags::ANY)?
+        .unwrap_or_default()
+        .iter()
+        .map(|bytes| read_value::<u64>(bytes))
+        .sum();
+
+    Ok(ProfileReport { run_cnt, metrics })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale() {
+        let value = PerfEventValue {
+            counter: 100,
+            enabled: 10,
+            running: 5,
+        };
+        assert_eq!(scale(&value), 200);
+
+        let value = PerfEventValue {
+            counter: 100,
+            enabled: 10,
+            running: 10,
+        };
+        assert_eq!(scale(&value), 100);
+    }
+
+    #[test]
+    fn test_report() {
+        let report = ProfileReport {
+            run_cnt: 4,
+            metrics: vec![
+                MetricReading {
+                    name: "cycles",
+                    value: 400,
+                },
+                MetricReading {
+                    name: "instructions",
+                    value: 200,
+                },
+            ],
+        };
+        assert_eq!(report.per_run(&report.metrics[0]), 100.0);
+        assert_eq!(report.instructions_per_cycle(), Some(0.5));
+
+        let report = ProfileReport {
+            run_cnt: 0,
+            metrics: vec![],
+        };
+        assert_eq!(report.instructions_per_cycle(), None);
+    }
+}
@@ -0,0 +1,262 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{audit::AuditSkelBuilder, profile::read_value};
+use anyhow::{anyhow, Context, Result};
+use bpftop_core::helpers::{now_ms, program_type_to_string};
+use libbpf_rs::{
+    skel::{OpenSkel, Skel, SkelBuilder},
+    ErrorKind, ProgramType, RingBufferBuilder,
+};
+use serde::Serialize;
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    mem::MaybeUninit,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+use tracing::{info, warn};
+
+// Event kinds, see src/bpf/audit.bpf.c
+const AUDIT_PROG_LOAD: u32 = 1;
+const AUDIT_PROG_UNLOAD: u32 = 2;
+
+const TASK_COMM_LEN: usize = 16;
+const BPF_OBJ_NAME_LEN: usize = 16;
+
+// Layout of struct audit_event in src/bpf/audit.bpf.c
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+struct RawAuditEvent {
+    event: u32,
+    prog_id: u32,
+    prog_type: u32,
+    pid: u32,
+    uid: u32,
+    comm: [u8; TASK_COMM_LEN],
+    prog_name: [u8; BPF_OBJ_NAME_LEN],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AuditEvent {
+    pub timestamp_ms: u64,
+    pub event: &'static str,
+    pub prog_id: u32,
+    pub prog_type: String,
+    pub prog_name: String,
+    // For unloads this is the process that dropped the last reference to the program
+    pub pid: u32,
+    pub uid: u32,
+    pub comm: String,
+}
+
+fn c_string(bytes: &[u8]) -> String {
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..len]).into_owned()
+}
+
+impl AuditEvent {
+    fn parse(data: &[u8], timestamp_ms: u64) -> Option<AuditEvent> {
+        if data.len() < size_of::<RawAuditEvent>() {
+            return None;
+        }
+        let raw: RawAuditEvent = read_value(data);
+        let event = match raw.event {
+            AUDIT_PROG_LOAD => "load",
+            AUDIT_PROG_UNLOAD => "unload",
+            _ => return None,
+        };
+        // Programs freed before they were assigned an ID were never visible to anyone
+        if raw.prog_id == 0 {
+            return None;
+        }
+        Some(AuditEvent {
+            timestamp_ms,
+            event,
+            prog_id: raw.prog_id,
+            prog_type: program_type_to_string(ProgramType::from(raw.prog_type)),
+            prog_name: c_string(&raw.prog_name),
+            pid: raw.pid,
+            uid: raw.uid,
+            comm: c_string(&raw.comm),
+        })
+    }
+
+    pub fn is_load(&self) -> bool {
+        self.event == "load"
+    }
+
+    // Writes the event to journald as structured fields
+    fn log(&self) {
+        info!(
+            audit_event = self.event,
+            prog_id = self.prog_id,
+            prog_type = self.prog_type,
+            prog_name = self.prog_name,
+            pid = self.pid,
+            uid = self.uid,
+            comm = self.comm,
+            "BPF program {} {} ({}) {} by {} (pid {}, uid {})",
+            self.prog_id,
+            self.prog_name,
+            self.prog_type,
+            if self.is_load() { "loaded" } else { "unloaded" },
+            self.comm,
+            self.pid,
+            self.uid,
+        );
+    }
+}
+
+// Attaches the audit programs and logs every program load and unload to journald, and as
+// JSON lines to `record_path` if given, from a background thread until the process exits.
+// Each event is also passed to `on_event`. Returns once the programs are attached so that
+// failures are reported to the caller.
+pub fn start_audit(
+    record_path: Option<PathBuf>,
+    on_event: impl FnMut(&AuditEvent) + Send + 'static,
+) -> Result<()> {
+    let record = match &record_path {
+        Some(path) => Some(open_record(path)?),
+        None => None,
+    };
+
+    let (ready_tx, ready_rx) = mpsc::channel();
+    thread::spawn(move || {
+        if let Err(e) = run_audit(record, on_event, &ready_tx) {
+            let _ = ready_tx.send(Err(e));
+        }
+    });
+
+    ready_rx
+        .recv()
+        .map_err(|_| anyhow!("Audit thread exited"))?
+        .context("Failed to attach the BPF program audit")?;
+    info!("Auditing BPF program loads and unloads");
+    Ok(())
+}
+
+fn run_audit(
+    mut record: Option<File>,
+    mut on_event: impl FnMut(&AuditEvent),
+    ready_tx: &mpsc::Sender<Result<()>>,
+) -> Result<()> {
+    let mut open_object = MaybeUninit::uninit();
+    let open_skel = AuditSkelBuilder::default().open(&mut open_object)?;
+    let mut skel = open_skel.load()?;
+    skel.attach()?;
+
+    let mut builder = RingBufferBuilder::new();
+    builder.add(&skel.maps.events, |data: &[u8]| {
+        if let Some(event) = AuditEvent::parse(data, now_ms()) {
+            event.log();
+            on_event(&event);
+            if let Some(file) = &mut record {
+                if let Err(e) = write_record(file, &event) {
+                    warn!("Failed to write audit record: {:?}", e);
+                }
+            }
+        }
+        0
+    })?;
+    let ringbuf = builder.build()?;
+    let _ = ready_tx.send(Ok(()));
+
+    loop {
+        match ringbuf.poll(Duration::from_millis(500)) {
+            Err(e) if e.kind() != ErrorKind::Interrupted => {
+                warn!("Stopped auditing BPF programs: {:?}", e);
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+}
+
+fn open_record(path: &Path) -> Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .context(format!("Failed to open audit log {}", path.display()))
+}
+
+fn write_record(writer: &mut impl Write, event: &AuditEvent) -> Result<()> {
+    let mut line = serde_json::to_vec(event)?;
+    line.push(b'\n');
+    writer.write_all(&line)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_bytes(raw: &RawAuditEvent) -> Vec<u8> {
+        let ptr = raw as *const RawAuditEvent as *const u8;
+        unsafe { std::slice::from_raw_parts(ptr, size_of::<RawAuditEvent>()) }.to_vec()
+    }
+
+    #[test]
+    fn test_parse() {
+        let mut raw = RawAuditEvent {
+            event: AUDIT_PROG_LOAD,
+            prog_id: 42,
+            prog_type: 6,
+            pid: 1234,
+            uid: 0,
+            ..Default::default()
+        };
+        raw.comm[..7].copy_from_slice(b"systemd");
+        raw.prog_name[..8].copy_from_slice(b"xdp_prog");
+
+        let event = AuditEvent::parse(&raw_bytes(&raw), 1000).unwrap();
+        assert_eq!(event.event, "load");
+        assert_eq!(event.prog_id, 42);
+        assert_eq!(event.prog_type, "Xdp");
+        assert_eq!(event.prog_name, "xdp_prog");
+        assert_eq!(event.comm, "systemd");
+
+        raw.prog_id = 0;
+        assert!(AuditEvent::parse(&raw_bytes(&raw), 1000).is_none());
+        assert!(AuditEvent::parse(&[0; 4], 1000).is_none());
+    }
+
+    #[test]
+    fn test_write_record() {
+        let event = AuditEvent {
+            timestamp_ms: 1000,
+            event: "unload",
+            prog_id: 7,
+            prog_type: "Kprobe".to_string(),
+            prog_name: "trace".to_string(),
+            pid: 1,
+            uid: 0,
+            comm: "init".to_string(),
+        };
+        let mut out = vec![];
+        write_record(&mut out, &event).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"timestamp_ms\":1000,\"event\":\"unload\",\"prog_id\":7,\"prog_type\":\"Kprobe\",\
+             \"prog_name\":\"trace\",\"pid\":1,\"uid\":0,\"comm\":\"init\"}\n"
+        );
+    }
+}
@@ -0,0 +1,298 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{attach_target::AttachTarget, struct_ops::StructOps};
+use anyhow::{anyhow, Result};
+use bpftop_core::{
+    bpf_attachment::{expected_attach_type, get_links},
+    cgroups::CgroupPrograms,
+};
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    fs,
+    mem::size_of_val,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+};
+
+// Helpers and kfuncs the verifier only allows in sleepable programs
+const SLEEPABLE_CALLS: [&str; 8] = [
+    "bpf_copy_from_user",
+    "bpf_copy_from_user_task",
+    "bpf_copy_from_user_str",
+    "bpf_copy_from_user_task_str",
+    "bpf_ima_inode_hash",
+    "bpf_ima_file_hash",
+    "bpf_lookup_user_key",
+    "bpf_verify_pkcs7_signature",
+];
+
+// Helpers that reach past the first buffer of a multi-buffer packet, which programs loaded
+// with BPF_F_XDP_HAS_FRAGS use. Other XDP programs may call them too.
+const XDP_FRAGS_CALLS: [&str; 3] = [
+    "bpf_xdp_get_buff_len",
+    "bpf_xdp_load_bytes",
+    "bpf_xdp_store_bytes",
+];
+
+const BPF_CALL: u8 = 0x85;
+const BPF_PSEUDO_KFUNC_CALL: u8 = 2;
+
+// Load flags of a program. The kernel does not report most of them, so they are inferred
+// from the program type and the kernel functions it calls.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProgFlags {
+    // Why the program is known to be sleepable
+    pub sleepable: Option<String>,
+    // Why the program is thought to handle XDP frags
+    pub xdp_frags: Option<String>,
+    // Function or hook the program had to be loaded against
+    pub attach_target: Option<AttachTarget>,
+    // As found from how the program is attached, None while it is not
+    pub expected_attach_type: Option<String>,
+}
+
+impl ProgFlags {
+    pub fn read(
+        prog_id: u32,
+        struct_ops: &[StructOps],
+        cgroups: &[CgroupPrograms],
+    ) -> Result<ProgFlags> {
+        let (info, insns) = prog_info(prog_id)?;
+        let calls = match kallsyms() {
+            Ok((call_base, symbols)) => called_functions(&insns, call_base, &symbols),
+            Err(_) => vec![],
+        };
+        let expected_attach_type = expected_attach_type(prog_id, &get_links(), cgroups);
+        // The attach type of a tracing link is how it hooks the target, e.g. fentry
+        let attach_target = (info.attach_btf_id != 0).then(|| {
+            AttachTarget::resolve(
+                prog_id,
+                info.type_,
+                info.attach_btf_obj_id,
+                info.attach_btf_id,
+                expected_attach_type.clone(),
+                struct_ops,
+            )
+        });
+        Ok(ProgFlags {
+            expected_attach_type,
+            ..ProgFlags::infer(info.type_, &calls, attach_target)
+        })
+    }
+
+    fn infer(prog_type: u32, calls: &[&str], attach_target: Option<AttachTarget>) -> ProgFlags {
+        let calling = |names: &[&str]| {
+            calls
+                .iter()
+                .find(|call| names.contains(call))
+                .map(|call| format!("calls {}", call))
+        };
+        let sleepable = if prog_type == libbpf_sys::BPF_PROG_TYPE_SYSCALL {
+            Some("syscall program".to_string())
+        } else {
+            calling(&SLEEPABLE_CALLS)
+        };
+        let xdp_frags = (prog_type == libbpf_sys::BPF_PROG_TYPE_XDP)
+            .then(|| calling(&XDP_FRAGS_CALLS))
+            .flatten();
+        ProgFlags {
+            sleepable,
+            xdp_frags,
+            attach_target,
+            expected_attach_type: None,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        let mut flags = vec![];
+        if let Some(reason) = &self.sleepable {
+            flags.push(format!("sleepable ({})", reason));
+        }
+        if let Some(reason) = &self.xdp_frags {
+            flags.push(format!("xdp frags likely ({})", reason));
+        }
+        if self.attach_target.is_some() {
+            flags.push("attach target required".to_string());
+        }
+        if flags.is_empty() {
+            return "None".to_string();
+        }
+        flags.join(", ")
+    }
+}
+
+// The first call returns the size of the translated instructions, the second one fills them in
+fn prog_info(prog_id: u32) -> Result<(libbpf_sys::bpf_prog_info, Vec<u8>)> {
+    let fd = unsafe { libbpf_sys::bpf_prog_get_fd_by_id(prog_id) };
+    if fd < 0 {
+        return Err(anyhow!("Program {} no longer exists", prog_id));
+    }
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+    let mut info = libbpf_sys::bpf_prog_info::default();
+    let mut len = size_of_val(&info) as u32;
+    let ret = unsafe {
+        libbpf_sys::bpf_obj_get_info_by_fd(
+            fd.as_raw_fd(),
+            &mut info as *mut _ as *mut c_void,
+            &mut len,
+        )
+    };
+    if ret != 0 {
+        return Err(anyhow!("Failed to get info for program {}", prog_id));
+    }
+
+    let mut insns = vec![0u8; info.xlated_prog_len as usize];
+    let mut info = libbpf_sys::bpf_prog_info {
+        xlated_prog_insns: insns.as_mut_ptr() as u64,
+        xlated_prog_len: insns.len() as u32,
+        ..Default::default()
+    };
+    let mut len = size_of_val(&info) as u32;
+    let ret = unsafe {
+        libbpf_sys::bpf_obj_get_info_by_fd(
+            fd.as_raw_fd(),
+            &mut info as *mut _ as *mut c_void,
+            &mut len,
+        )
+    };
+    if ret != 0 {
+        return Err(anyhow!("Failed to dump program {}", prog_id));
+    }
+    Ok((info, insns))
+}
+
+fn kallsyms() -> Result<(u64, HashMap<u64, &'static str>)> {
+    parse_kallsyms(&fs::read_to_string("/proc/kallsyms")?)
+        .ok_or_else(|| anyhow!("Kernel symbol addresses are hidden"))
+}
+
+// Addresses of the functions that reveal a flag, and of __bpf_call_base, which the calls of
+// dumped programs are relative to. The addresses read as zeros without CAP_SYSLOG.
+fn parse_kallsyms(kallsyms: &str) -> Option<(u64, HashMap<u64, &'static str>)> {
+    let mut call_base = None;
+    let mut symbols = HashMap::new();
+    for line in kallsyms.lines() {
+        let mut fields = line.split_whitespace();
+        let (Some(addr), Some(_), Some(name)) = (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let Ok(addr) = u64::from_str_radix(addr, 16) else {
+            continue;
+        };
+        if name == "__bpf_call_base" {
+            call_base = Some(addr);
+        } else if let Some(name) = SLEEPABLE_CALLS
+            .iter()
+            .chain(&XDP_FRAGS_CALLS)
+            .find(|known| **known == name)
+        {
+            symbols.insert(addr, *name);
+        }
+    }
+    call_base
+        .filter(|addr| *addr != 0)
+        .map(|addr| (addr, symbols))
+}
+
+// Known functions called by the translated instructions of a program. The kernel dumps helper
+// and kfunc calls as offsets from __bpf_call_base.
+fn called_functions(
+    insns: &[u8],
+    call_base: u64,
+    symbols: &HashMap<u64, &'static str>,
+) -> Vec<&'static str> {
+    let mut calls = vec![];
+    for insn in insns.chunks_exact(8) {
+        let src_reg = insn[1] >> 4;
+        if insn[0] != BPF_CALL || !(src_reg == 0 || src_reg == BPF_PSEUDO_KFUNC_CALL) {
+            continue;
+        }
+        let imm = i32::from_le_bytes(insn[4..8].try_into().unwrap());
+        if let Some(name) = symbols.get(&call_base.wrapping_add(imm as i64 as u64)) {
+            if !calls.contains(name) {
+                calls.push(*name);
+            }
+        }
+    }
+    calls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_called_functions() {
+        let (call_base, symbols) = parse_kallsyms(
+            "ffffffff81200000 T __bpf_call_base\n\
+             ffffffff81200100 T bpf_copy_from_user\n\
+             ffffffff811fff00 T bpf_xdp_load_bytes\n\
+             ffffffff81300000 T tcp_v4_connect\n",
+        )
+        .unwrap();
+        assert_eq!(symbols.len(), 2);
+
+        let insn = |code: u8, src_reg: u8, imm: i32| {
+            let mut insn = vec![code, src_reg << 4, 0, 0];
+            insn.extend(imm.to_le_bytes());
+            insn
+        };
+        let insns = [
+            insn(BPF_CALL, 0, 0x100),
+            // Exit, with an immediate that happens to match
+            insn(0x95, 0, 0x100),
+            insn(BPF_CALL, BPF_PSEUDO_KFUNC_CALL, -0x100),
+            insn(BPF_CALL, 0, 0x100),
+            // Call of a BPF subprogram, relative to the instruction
+            insn(BPF_CALL, 1, 0x100),
+        ]
+        .concat();
+        assert_eq!(
+            called_functions(&insns, call_base, &symbols),
+            vec!["bpf_copy_from_user", "bpf_xdp_load_bytes"]
+        );
+
+        // Addresses hidden without CAP_SYSLOG
+        assert!(parse_kallsyms("0000000000000000 T __bpf_call_base\n").is_none());
+    }
+
+    #[test]
+    fn test_infer() {
+        let flags = ProgFlags::infer(
+            libbpf_sys::BPF_PROG_TYPE_TRACING,
+            &["bpf_xdp_load_bytes", "bpf_copy_from_user"],
+            Some(AttachTarget {
+                kind: "lsm".to_string(),
+                name: "bpf_lsm_file_open".to_string(),
+            }),
+        );
+        assert_eq!(
+            flags.describe(),
+            "sleepable (calls bpf_copy_from_user), attach target required"
+        );
+        let flags = ProgFlags::infer(libbpf_sys::BPF_PROG_TYPE_XDP, &["bpf_xdp_load_bytes"], None);
+        assert_eq!(
+            flags.describe(),
+            "xdp frags likely (calls bpf_xdp_load_bytes)"
+        );
+        let flags = ProgFlags::infer(libbpf_sys::BPF_PROG_TYPE_SYSCALL, &[], None);
+        assert_eq!(flags.describe(), "sleepable (syscall program)");
+        assert_eq!(ProgFlags::default().describe(), "None");
+    }
+}
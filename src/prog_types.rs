@@ -0,0 +1,108 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use bpftop_core::bpf_program::BpfProgram;
+use std::collections::HashMap;
+
+// Families filtered on by the number keys, from 1 up. 0 clears the filter.
+pub const QUICK_FILTERS: [&str; 9] = [
+    "kprobe",
+    "tracepoint",
+    "xdp",
+    "tc",
+    "cgroup",
+    "tracing",
+    "raw_tracepoint",
+    "lsm",
+    "socket_filter",
+];
+
+// The family a program type is counted under in the type breakdown. The cgroup, lwt, tc and
+// raw tracepoint variants are grouped together, and the other types are snake cased.
+pub fn family(bpf_type: &str) -> String {
+    match bpf_type {
+        t if t.starts_with("Cgroup") => "cgroup".to_string(),
+        t if t.starts_with("Lwt") => "lwt".to_string(),
+        "SchedCls" | "SchedAct" => "tc".to_string(),
+        "RawTracepoint" | "RawTracepointWritable" => "raw_tracepoint".to_string(),
+        t => {
+            let mut family = String::new();
+            for (i, c) in t.chars().enumerate() {
+                if c.is_ascii_uppercase() && i > 0 {
+                    family.push('_');
+                }
+                family.push(c.to_ascii_lowercase());
+            }
+            family
+        }
+    }
+}
+
+// Number of programs of each family, the most common first
+pub fn counts<'a>(programs: impl IntoIterator<Item = &'a BpfProgram>) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for prog in programs {
+        *counts.entry(family(&prog.bpf_type)).or_default() += 1;
+    }
+    let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn test_family() {
+        assert_eq!(family("Kprobe"), "kprobe");
+        assert_eq!(family("SocketFilter"), "socket_filter");
+        assert_eq!(family("CgroupSockAddr"), "cgroup");
+        assert_eq!(family("SchedCls"), "tc");
+        assert_eq!(family("RawTracepointWritable"), "raw_tracepoint");
+    }
+
+    #[test]
+    fn test_counts() {
+        let programs: Vec<BpfProgram> = ["Xdp", "CgroupSkb", "Kprobe", "CgroupSock", "Kprobe"]
+            .iter()
+            .enumerate()
+            .map(|(id, bpf_type)| BpfProgram {
+                id: id as u32,
+                bpf_type: bpf_type.to_string(),
+                name: "test".to_string(),
+                tag: String::new(),
+                prev_runtime_ns: 0,
+                run_time_ns: 0,
+                prev_run_cnt: 0,
+                run_cnt: 0,
+                instant: Instant::now(),
+                period_ns: 0,
+                processes: vec![],
+            })
+            .collect();
+        assert_eq!(
+            counts(&programs),
+            vec![
+                ("cgroup".to_string(), 2),
+                ("kprobe".to_string(), 2),
+                ("xdp".to_string(), 1),
+            ]
+        );
+    }
+}
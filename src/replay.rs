@@ -0,0 +1,172 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use anyhow::{Context, Result};
+use bpftop_core::{
+    bpf_program::BpfProgram,
+    sampler::{ProgramSample, ProgramStatsSource, Timestamp},
+};
+use serde::Deserialize;
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+
+// The fields of a line written by --export-json that replaying needs
+#[derive(Debug, Deserialize)]
+struct ReplayLine {
+    timestamp_ms: u64,
+    id: u32,
+    name: String,
+    bpf_type: String,
+    // Missing from recordings made before the counters were exported
+    #[serde(default)]
+    run_time_ns: u64,
+    #[serde(default)]
+    run_cnt: u64,
+}
+
+// Replays a recording made with --export-json, one period per read. Lines written in the
+// same period share a timestamp, which is turned back into the sample time of the programs.
+pub struct ReplaySource<R> {
+    reader: R,
+    // First line of the next period, read while looking for the end of the current one
+    pending: Option<ReplayLine>,
+    start: Instant,
+    first_ms: Option<u64>,
+    finished: bool,
+}
+
+impl ReplaySource<BufReader<File>> {
+    pub fn open(path: &Path) -> Result<Self> {
+        let file =
+            File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+        info!("Replaying programs from {}", path.display());
+        Ok(ReplaySource::new(BufReader::new(file)))
+    }
+}
+
+impl<R: BufRead> ReplaySource<R> {
+    pub fn new(reader: R) -> Self {
+        ReplaySource {
+            reader,
+            pending: None,
+            start: Instant::now(),
+            first_ms: None,
+            finished: false,
+        }
+    }
+
+    fn next_line(&mut self) -> Option<ReplayLine> {
+        let mut buf = String::new();
+        loop {
+            buf.clear();
+            match self.reader.read_line(&mut buf) {
+                Ok(0) => return None,
+                Ok(_) if buf.trim().is_empty() => continue,
+                Ok(_) => match serde_json::from_str(&buf) {
+                    Ok(line) => return Some(line),
+                    Err(e) => warn!("Skipping malformed replay line: {}", e),
+                },
+                Err(e) => {
+                    warn!("Failed to read the replay file: {}", e);
+                    return None;
+                }
+            }
+        }
+    }
+
+    fn program(&mut self, line: ReplayLine) -> BpfProgram {
+        let first_ms = *self.first_ms.get_or_insert(line.timestamp_ms);
+        BpfProgram {
+            id: line.id,
+            bpf_type: line.bpf_type,
+            name: line.name,
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: line.run_time_ns,
+            prev_run_cnt: 0,
+            run_cnt: line.run_cnt,
+            instant: self.start + Duration::from_millis(line.timestamp_ms.saturating_sub(first_ms)),
+            period_ns: 0,
+            processes: vec![],
+        }
+    }
+}
+
+impl<R: BufRead + Send> ProgramStatsSource for ReplaySource<R> {
+    fn read(&mut self) -> Option<ProgramSample> {
+        let Some(first) = self.pending.take().or_else(|| self.next_line()) else {
+            if !self.finished {
+                info!("Replay finished, showing the last period");
+                self.finished = true;
+            }
+            return None;
+        };
+        let timestamp_ms = first.timestamp_ms;
+        let first = self.program(first);
+        // Replayed samples keep the wall clock time they were recorded at
+        let mut sample = ProgramSample {
+            timestamp: Timestamp {
+                instant: first.instant,
+                wall_ms: timestamp_ms,
+            },
+            ..Default::default()
+        };
+        sample.programs.push(first);
+        while let Some(line) = self.next_line() {
+            if line.timestamp_ms != timestamp_ms {
+                self.pending = Some(line);
+                break;
+            }
+            let program = self.program(line);
+            sample.programs.push(program);
+        }
+        Some(sample)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bpftop_core::sampler::Sampler;
+
+    #[test]
+    fn test_replay() {
+        let recording = r#"{"timestamp_ms":1000,"id":1,"name":"a","bpf_type":"Kprobe","run_time_ns":100,"run_cnt":1}
+{"timestamp_ms":1000,"id":2,"name":"b","bpf_type":"Xdp","run_time_ns":0,"run_cnt":0}
+not json
+{"timestamp_ms":3000,"id":1,"name":"a","bpf_type":"Kprobe","run_time_ns":20000100,"run_cnt":11}
+"#;
+        let mut sampler = Sampler::new(ReplaySource::new(recording.as_bytes()));
+        assert_eq!(sampler.sample().programs.len(), 2);
+
+        let sample = sampler.sample();
+        assert_eq!(sample.timestamp.wall_ms, 3000);
+        let programs = sample.programs;
+        assert_eq!(programs.len(), 1);
+        assert_eq!(programs[0].period_ns, 2_000_000_000);
+        assert_eq!(programs[0].events_per_second(), 5);
+        assert_eq!(programs[0].cpu_time_percent(), 1.0);
+
+        // The last period stays once the recording ends
+        assert_eq!(sampler.sample().programs[0].events_per_second(), 5);
+    }
+}
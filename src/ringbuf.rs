@@ -0,0 +1,240 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use anyhow::Result;
+use libbpf_rs::MapHandle;
+use nix::sys::mman::{mmap, munmap, MapFlags, ProtFlags};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    ffi::c_void,
+    num::NonZeroUsize,
+    ptr::NonNull,
+    sync::atomic::{AtomicU32, AtomicU64, Ordering},
+    time::Instant,
+};
+
+// Record header flags, see include/uapi/linux/bpf.h
+const BPF_RINGBUF_BUSY_BIT: u32 = 1 << 31;
+const BPF_RINGBUF_DISCARD_BIT: u32 = 1 << 30;
+const BPF_RINGBUF_HDR_SZ: u64 = 8;
+
+// Upper bound on the number of record headers walked per sample
+const MAX_WALKED_RECORDS: usize = 1_000_000;
+
+/// Throughput of a BPF ring buffer between two samples. The kernel does not count failed
+/// reservations, so the pending percentage is the best available signal that producers are
+/// about to drop events.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RingBufStats {
+    pub capacity: u64,
+    pub pending_bytes: u64,
+    pub produced_bytes_per_sec: u64,
+    pub consumed_bytes_per_sec: u64,
+    pub records_per_sec: u64,
+}
+
+impl RingBufStats {
+    pub fn pending_percent(&self) -> f64 {
+        if self.capacity == 0 {
+            return 0.0;
+        }
+        self.pending_bytes as f64 / self.capacity as f64 * 100.0
+    }
+}
+
+struct Sample {
+    consumer_pos: u64,
+    producer_pos: u64,
+    instant: Instant,
+}
+
+// Read-only mapping of a ring buffer's consumer page, producer page and data area
+struct MappedRingBuf {
+    _handle: MapHandle,
+    ptr: NonNull<c_void>,
+    len: usize,
+    page_size: usize,
+    capacity: u64,
+    prev: Option<Sample>,
+}
+
+// SAFETY: the mapping is only ever read and is owned by a single tracker
+unsafe impl Send for MappedRingBuf {}
+
+impl MappedRingBuf {
+    fn new(handle: MapHandle, capacity: u32) -> Result<MappedRingBuf> {
+        let page_size = procfs::page_size() as usize;
+        // The kernel maps the data area twice so records that wrap around are contiguous
+        let len = 2 * page_size + 2 * capacity as usize;
+        let ptr = unsafe {
+            mmap(
+                None,
+                NonZeroUsize::new(len).unwrap(),
+                ProtFlags::PROT_READ,
+                MapFlags::MAP_SHARED,
+                &handle,
+                0,
+            )?
+        };
+        Ok(MappedRingBuf {
+            _handle: handle,
+            ptr,
+            len,
+            page_size,
+            capacity: capacity as u64,
+            prev: None,
+        })
+    }
+
+    fn load_u64(&self, offset: usize) -> u64 {
+        let ptr = unsafe { self.ptr.as_ptr().byte_add(offset) } as *const AtomicU64;
+        unsafe { (*ptr).load(Ordering::Acquire) }
+    }
+
+    fn consumer_pos(&self) -> u64 {
+        self.load_u64(0)
+    }
+
+    fn producer_pos(&self) -> u64 {
+        self.load_u64(self.page_size)
+    }
+
+    fn record_len(&self, pos: u64) -> u32 {
+        let offset = 2 * self.page_size + (pos & (self.capacity - 1)) as usize;
+        let ptr = unsafe { self.ptr.as_ptr().byte_add(offset) } as *const AtomicU32;
+        unsafe { (*ptr).load(Ordering::Acquire) }
+    }
+
+    // Counts the records between two record boundaries
+    fn count_records(&self, start: u64, end: u64) -> u64 {
+        count_records(start, end, |pos| self.record_len(pos))
+    }
+
+    fn sample(&mut self) -> RingBufStats {
+        let curr = Sample {
+            consumer_pos: self.consumer_pos(),
+            producer_pos: self.producer_pos(),
+            instant: Instant::now(),
+        };
+        let mut stats = RingBufStats {
+            capacity: self.capacity,
+            pending_bytes: curr.producer_pos.saturating_sub(curr.consumer_pos),
+            ..Default::default()
+        };
+
+        if let Some(prev) = &self.prev {
+            let period_ns = prev.instant.elapsed().as_nanos() as f64;
+            let produced = curr.producer_pos.saturating_sub(prev.producer_pos);
+            let consumed = curr.consumer_pos.saturating_sub(prev.consumer_pos);
+
+            // Records that were already overwritten can't be walked, so extrapolate from
+            // the average size of the records still in the buffer
+            let records = if produced <= self.capacity {
+                self.count_records(prev.producer_pos, curr.producer_pos)
+            } else {
+                let pending = self.count_records(curr.consumer_pos, curr.producer_pos);
+                match pending {
+                    0 => 0,
+                    _ => produced / (stats.pending_bytes / pending).max(1),
+                }
+            };
+
+            if period_ns > 0.0 {
+                let per_sec = |value: u64| (value as f64 / period_ns * 1_000_000_000.0) as u64;
+                stats.produced_bytes_per_sec = per_sec(produced);
+                stats.consumed_bytes_per_sec = per_sec(consumed);
+                stats.records_per_sec = per_sec(records);
+            }
+        }
+
+        self.prev = Some(curr);
+        stats
+    }
+}
+
+impl Drop for MappedRingBuf {
+    fn drop(&mut self) {
+        let _ = unsafe { munmap(self.ptr, self.len) };
+    }
+}
+
+fn count_records(start: u64, end: u64, record_len: impl Fn(u64) -> u32) -> u64 {
+    let mut pos = start;
+    let mut records = 0;
+    while pos < end && records < MAX_WALKED_RECORDS as u64 {
+        let len = record_len(pos) & !(BPF_RINGBUF_BUSY_BIT | BPF_RINGBUF_DISCARD_BIT);
+        pos += (len as u64 + BPF_RINGBUF_HDR_SZ).next_multiple_of(8);
+        records += 1;
+    }
+    records
+}
+
+/// Keeps ring buffers mapped between samples so their positions can be diffed
+#[derive(Default)]
+pub struct RingBufTracker {
+    rings: HashMap<u32, MappedRingBuf>,
+}
+
+impl RingBufTracker {
+    pub fn sample(&mut self, id: u32, max_entries: u32) -> Option<RingBufStats> {
+        let ring = match self.rings.entry(id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let handle = MapHandle::from_map_id(id).ok()?;
+                entry.insert(MappedRingBuf::new(handle, max_entries).ok()?)
+            }
+        };
+        Some(ring.sample())
+    }
+
+    // Unmaps the ring buffers that are gone or no longer used by any program. A mapping
+    // holds a reference to the ring, so keeping it would keep the ring alive after its
+    // owner exited.
+    pub fn retain(&mut self, ids: &[u32]) {
+        self.rings.retain(|id, _| ids.contains(id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_records() {
+        // Records of 8, 20 (padded to 24) and 1 (padded to 8) bytes, the second one discarded
+        let records: HashMap<u64, u32> = HashMap::from([
+            (0, 8),
+            (16, 20 | BPF_RINGBUF_DISCARD_BIT),
+            (48, 1 | BPF_RINGBUF_BUSY_BIT),
+        ]);
+        let record_len = |pos| records[&pos];
+        assert_eq!(count_records(0, 64, record_len), 3);
+        assert_eq!(count_records(16, 48, record_len), 1);
+        assert_eq!(count_records(0, 0, record_len), 0);
+    }
+
+    #[test]
+    fn test_pending_percent() {
+        let stats = RingBufStats {
+            capacity: 4096,
+            pending_bytes: 1024,
+            ..Default::default()
+        };
+        assert_eq!(stats.pending_percent(), 25.0);
+        assert_eq!(RingBufStats::default().pending_percent(), 0.0);
+    }
+}
@@ -0,0 +1,837 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    allowlist::DriftDetector,
+    config::Config,
+    script::{self, Script},
+};
+use anyhow::{anyhow, bail, Context, Result};
+use bpftop_core::{bpf_attachment::get_prog_attachments, bpf_program::BpfProgram};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    process::{Command, Stdio},
+    thread,
+    time::Duration,
+};
+use tracing::{error, warn};
+
+// Rules are evaluated once per one-second sample period
+const PERIOD: Duration = Duration::from_secs(1);
+
+// Rule as written in the config file, see Rule for the compiled form
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleConfig {
+    pub name: String,
+    // Glob on the program name
+    pub program: Option<String>,
+    #[serde(rename = "type")]
+    pub bpf_type: Option<String>,
+    // Name of a label defined in the [labels] table
+    pub label: Option<String>,
+    pub condition: Option<String>,
+    // Script in the config directory returning whether the rule's condition holds, for
+    // conditions that do not fit a single comparison
+    pub script: Option<String>,
+    // How long the condition must hold before the rule fires, e.g. "10s" or "2m"
+    #[serde(rename = "for")]
+    pub for_duration: Option<String>,
+    // URL to POST the alert to as JSON when the rule fires
+    pub webhook: Option<String>,
+    // Shell command to run when the rule fires, with the alert in BPFTOP_* env vars
+    pub exec: Option<String>,
+    // Deprecated, setting the webhook is enough. Kept so older configs still load.
+    pub action: Option<Action>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Action {
+    // Log to journald only, ignoring the webhook
+    Log,
+    // Log and POST the alert as JSON to the rule's webhook
+    Webhook,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operator {
+    Greater,
+    GreaterEqual,
+    Less,
+    LessEqual,
+    Equal,
+    NotEqual,
+}
+
+impl Operator {
+    // Longer operators first so ">=" is not read as ">"
+    const ALL: [(&'static str, Operator); 6] = [
+        (">=", Operator::GreaterEqual),
+        ("<=", Operator::LessEqual),
+        ("==", Operator::Equal),
+        ("!=", Operator::NotEqual),
+        (">", Operator::Greater),
+        ("<", Operator::Less),
+    ];
+
+    fn symbol(&self) -> &'static str {
+        Operator::ALL
+            .iter()
+            .find(|(_, op)| op == self)
+            .map(|(symbol, _)| *symbol)
+            .unwrap_or_default()
+    }
+
+    fn apply(&self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Operator::Greater => lhs > rhs,
+            Operator::GreaterEqual => lhs >= rhs,
+            Operator::Less => lhs < rhs,
+            Operator::LessEqual => lhs <= rhs,
+            Operator::Equal => lhs == rhs,
+            Operator::NotEqual => lhs != rhs,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Condition {
+    // Two expressions compared with an operator, the left-hand one being what alerts report
+    Compare {
+        lhs: Script,
+        operator: Operator,
+        rhs: Script,
+    },
+    // A script returning true while the condition holds
+    Script(Script),
+}
+
+// Finds the comparison operator outside of parentheses and string literals
+fn split_comparison(condition: &str) -> Option<(&str, Operator, &str)> {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut prev = None;
+    for (i, c) in condition.char_indices() {
+        match c {
+            '"' if prev != Some('\\') => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth -= 1,
+            _ if in_string || depth > 0 => {}
+            _ => {
+                let rest = &condition[i..];
+                // Shifts are arithmetic, not comparisons
+                let shift = rest.starts_with("<<")
+                    || rest.starts_with(">>")
+                    || matches!((prev, c), (Some('<'), '<') | (Some('>'), '>'));
+                let found = Operator::ALL
+                    .iter()
+                    .find(|(symbol, _)| !shift && rest.starts_with(symbol));
+                if let Some((symbol, operator)) = found {
+                    return Some((&condition[..i], *operator, &rest[symbol.len()..]));
+                }
+            }
+        }
+        prev = Some(c);
+    }
+    None
+}
+
+impl Condition {
+    // Condition that holds while the field is above the threshold
+    pub fn above(field: &str, threshold: f64) -> Result<Self> {
+        Ok(Condition::Compare {
+            lhs: Script::expression(field)?,
+            operator: Operator::Greater,
+            rhs: Script::expression(&threshold.to_string())?,
+        })
+    }
+
+    // Parses conditions of the form "<expression> <operator> <expression>", e.g.
+    // "cpu_percent > 3" or "avg_runtime_ns > 2 * total_avg_runtime_ns". Both sides are
+    // evaluated once against an empty program so that unknown fields are reported here.
+    pub fn parse(condition: &str) -> Result<Condition> {
+        let (lhs, operator, rhs) = split_comparison(condition)
+            .ok_or_else(|| anyhow!("Missing comparison operator in '{}'", condition))?;
+        let parsed = Condition::Compare {
+            lhs: Script::expression(lhs).context(format!("Invalid condition '{}'", condition))?,
+            operator,
+            rhs: Script::expression(rhs).context(format!("Invalid condition '{}'", condition))?,
+        };
+        parsed
+            .holds(&script::empty_program())
+            .context(format!("Invalid condition '{}'", condition))?;
+        Ok(parsed)
+    }
+
+    // Value of the left-hand side, which is what alerts report. Scripts have none.
+    pub fn value(&self, prog: &BpfProgram) -> Option<f64> {
+        match self {
+            Condition::Compare { lhs, .. } => lhs.eval_number(prog).ok(),
+            Condition::Script(_) => None,
+        }
+    }
+
+    fn holds(&self, prog: &BpfProgram) -> Result<bool> {
+        match self {
+            Condition::Compare { lhs, operator, rhs } => {
+                Ok(operator.apply(lhs.eval_number(prog)?, rhs.eval_number(prog)?))
+            }
+            Condition::Script(script) => script.eval_bool(prog),
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Condition::Compare { lhs, operator, rhs } => {
+                write!(f, "{} {} {}", lhs, operator.symbol(), rhs)
+            }
+            Condition::Script(script) => write!(f, "{}", script),
+        }
+    }
+}
+
+// Parses durations such as "30s", "2m", "1h" or a plain number of seconds
+fn parse_duration(value: &str) -> Result<Duration> {
+    let value = value.trim();
+    let (number, unit) = value
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or((value, ""), |i| value.split_at(i));
+    let number: u64 = number
+        .parse()
+        .map_err(|_| anyhow!("Invalid duration '{}'", value))?;
+    let multiplier = match unit {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        _ => return Err(anyhow!("Invalid duration unit in '{}'", value)),
+    };
+    let seconds = number
+        .checked_mul(multiplier)
+        .ok_or_else(|| anyhow!("Duration '{}' is too long", value))?;
+    Ok(Duration::from_secs(seconds))
+}
+
+// Matches `text` against a pattern where `*` matches any run of characters
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // No wildcard, so the whole text must match
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+#[derive(Clone, Debug)]
+pub struct Rule {
+    pub name: String,
+    program: Option<String>,
+    bpf_type: Option<String>,
+    // Program name patterns of the rule's label
+    label: Option<Vec<String>>,
+    pub condition: Condition,
+    // Consecutive sample periods the condition must hold before the rule fires
+    periods: u32,
+    webhook: Option<String>,
+    exec: Option<String>,
+}
+
+impl Rule {
+    // Rule that matches every program
+    pub fn new(name: &str, condition: Condition, periods: u32) -> Self {
+        Rule {
+            name: name.to_string(),
+            program: None,
+            bpf_type: None,
+            label: None,
+            condition,
+            periods: periods.max(1),
+            webhook: None,
+            exec: None,
+        }
+    }
+
+    pub fn with_actions(mut self, webhook: Option<String>, exec: Option<String>) -> Self {
+        self.webhook = webhook;
+        self.exec = exec;
+        self
+    }
+
+    fn from_config(rule: &RuleConfig, config: &Config) -> Result<Self> {
+        let label = rule
+            .label
+            .as_ref()
+            .map(|label| {
+                config
+                    .labels
+                    .get(label)
+                    .cloned()
+                    .ok_or_else(|| anyhow!("Unknown label '{}'", label))
+            })
+            .transpose()?;
+        let webhook = match (rule.action, &rule.webhook) {
+            (Some(Action::Webhook), None) => {
+                return Err(anyhow!("Webhook action without a webhook"))
+            }
+            (Some(Action::Log), _) => None,
+            (_, webhook) => webhook.clone(),
+        };
+        if rule.action.is_some() {
+            warn!(
+                "The action of rule '{}' is deprecated, set or remove its webhook instead",
+                rule.name
+            );
+        }
+        let duration = rule
+            .for_duration
+            .as_deref()
+            .map(parse_duration)
+            .transpose()?
+            .unwrap_or_default();
+        let periods = u32::try_from(duration.as_secs().div_ceil(PERIOD.as_secs()))
+            .map_err(|_| anyhow!("Duration of rule '{}' is too long", rule.name))?;
+
+        let condition = match (&rule.condition, &rule.script) {
+            (Some(condition), None) => Condition::parse(condition)?,
+            (None, Some(file)) => Condition::Script(Script::load(&config.dir.join(file))?),
+            _ => bail!("Rule needs either a condition or a script"),
+        };
+
+        Ok(Rule {
+            name: rule.name.clone(),
+            program: rule.program.clone(),
+            bpf_type: rule.bpf_type.clone(),
+            label,
+            condition,
+            periods: periods.max(1),
+            webhook,
+            exec: rule.exec.clone(),
+        })
+    }
+
+    fn matches(&self, prog: &BpfProgram) -> bool {
+        self.program
+            .as_ref()
+            .is_none_or(|pattern| glob_match(pattern, &prog.name))
+            && self
+                .bpf_type
+                .as_ref()
+                .is_none_or(|bpf_type| bpf_type.eq_ignore_ascii_case(&prog.bpf_type))
+            && self.label.as_ref().is_none_or(|patterns| {
+                patterns
+                    .iter()
+                    .any(|pattern| glob_match(pattern, &prog.name))
+            })
+    }
+}
+
+// Stats and attachments of a program at the time a rule fired for it
+#[derive(Clone, Debug, Serialize)]
+pub struct Alert {
+    pub rule: String,
+    pub condition: String,
+    // Value of the condition's left-hand side, none for script conditions
+    pub value: Option<f64>,
+    pub duration_secs: u64,
+    pub id: u32,
+    pub name: String,
+    pub bpf_type: String,
+    pub cpu_time_percent: f64,
+    pub events_per_second: i64,
+    pub period_average_runtime_ns: u64,
+    pub total_average_runtime_ns: u64,
+    pub processes: Vec<String>,
+    pub attachments: Vec<String>,
+}
+
+impl Alert {
+    fn new(rule: &Rule, prog: &BpfProgram) -> Self {
+        Alert {
+            rule: rule.name.clone(),
+            condition: rule.condition.to_string(),
+            value: rule.condition.value(prog),
+            duration_secs: rule.periods as u64 * PERIOD.as_secs(),
+            id: prog.id,
+            name: prog.name.clone(),
+            bpf_type: prog.bpf_type.clone(),
+            cpu_time_percent: prog.cpu_time_percent(),
+            events_per_second: prog.events_per_second(),
+            period_average_runtime_ns: prog.period_average_runtime_ns(),
+            total_average_runtime_ns: prog.total_average_runtime_ns(),
+            processes: prog.processes.iter().map(|p| p.to_string()).collect(),
+            attachments: get_prog_attachments(prog.id)
+                .iter()
+                .map(|attachment| attachment.describe())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RuleEngine {
+    rules: Vec<Rule>,
+    // Consecutive periods each (rule, program) pair has met the rule's condition
+    pending: HashMap<(usize, u32), u32>,
+    // Alerts on programs missing from the allowlist
+    drift: Option<DriftDetector>,
+    // Rules whose condition failed to evaluate, so each failure is only logged once
+    failing: HashSet<usize>,
+}
+
+impl RuleEngine {
+    pub fn new(rules: Vec<Rule>) -> Self {
+        RuleEngine {
+            rules,
+            pending: HashMap::new(),
+            drift: None,
+            failing: HashSet::new(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let rules = config
+            .rules
+            .iter()
+            .map(|rule| {
+                Rule::from_config(rule, config).context(format!("Invalid rule '{}'", rule.name))
+            })
+            .collect::<Result<_>>()?;
+        Ok(RuleEngine::new(rules))
+    }
+
+    pub fn add_rule(&mut self, rule: Rule) {
+        self.rules.push(rule);
+    }
+
+    pub fn set_drift_detector(&mut self, drift: DriftDetector) {
+        self.drift = Some(drift);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty() && self.drift.is_none()
+    }
+
+    // Whether any rule runs a shell command when it fires
+    pub fn runs_commands(&self) -> bool {
+        self.rules.iter().any(|rule| rule.exec.is_some())
+    }
+
+    // Evaluates every rule against one period of a program's stats. Returns the rules that
+    // fire, which happens once per excursion: on the period the condition has held for the
+    // rule's full duration.
+    fn observe(&mut self, prog: &BpfProgram) -> Vec<usize> {
+        let mut fired = vec![];
+        for (i, rule) in self.rules.iter().enumerate() {
+            if !rule.matches(prog) {
+                self.pending.remove(&(i, prog.id));
+                continue;
+            }
+            let holds = match rule.condition.holds(prog) {
+                Ok(holds) => {
+                    self.failing.remove(&i);
+                    holds
+                }
+                Err(e) => {
+                    if self.failing.insert(i) {
+                        warn!("Failed to evaluate rule '{}': {:#}", rule.name, e);
+                    }
+                    false
+                }
+            };
+            if !holds {
+                self.pending.remove(&(i, prog.id));
+                continue;
+            }
+            let count = self.pending.entry((i, prog.id)).or_default();
+            *count += 1;
+            if *count == rule.periods {
+                fired.push(i);
+            }
+        }
+        fired
+    }
+
+    // Evaluates the rules against the programs sampled this period, fires their actions
+    // and returns the alerts fired. Programs without a full period of stats yet are skipped.
+    pub fn evaluate(&mut self, programs: &[BpfProgram]) -> Vec<Alert> {
+        if let Some(drift) = &mut self.drift {
+            drift.check(programs);
+        }
+        let mut alerts = vec![];
+        if self.rules.is_empty() {
+            return alerts;
+        }
+        for prog in programs.iter().filter(|prog| prog.period_ns > 0) {
+            for i in self.observe(prog) {
+                let alert = Alert::new(&self.rules[i], prog);
+                fire(&self.rules[i], &alert);
+                alerts.push(alert);
+            }
+        }
+
+        // Forget unloaded programs so a reused ID starts from scratch
+        let ids: HashSet<u32> = programs.iter().map(|prog| prog.id).collect();
+        self.pending.retain(|(_, id), _| ids.contains(id));
+        alerts
+    }
+}
+
+fn fire(rule: &Rule, alert: &Alert) {
+    let held = match alert.value {
+        Some(value) => format!("{} was {:.2}", alert.condition, value),
+        None => format!("{} held", alert.condition),
+    };
+    warn!(
+        rule = alert.rule.as_str(),
+        prog_id = alert.id,
+        prog_name = alert.name.as_str(),
+        prog_type = alert.bpf_type.as_str(),
+        cpu_time_percent = alert.cpu_time_percent,
+        events_per_second = alert.events_per_second,
+        period_average_runtime_ns = alert.period_average_runtime_ns,
+        attachments = alert.attachments.join(", ").as_str(),
+        processes = alert.processes.join(", ").as_str(),
+        "Rule {} fired for program {} ({}): {} for {}s",
+        alert.rule,
+        alert.name,
+        alert.id,
+        held,
+        alert.duration_secs
+    );
+
+    // Post from a separate thread so a slow endpoint does not stall the collector
+    if let Some(url) = rule.webhook.clone() {
+        match serde_json::to_value(alert) {
+            Ok(payload) => {
+                thread::spawn(move || {
+                    if let Err(e) = ureq::post(&url)
+                        .timeout(Duration::from_secs(5))
+                        .send_json(payload)
+                    {
+                        error!("Failed to send alert to {}: {}", url, e);
+                    }
+                });
+            }
+            Err(e) => error!("Failed to serialize alert: {}", e),
+        }
+    }
+
+    if let Some(command) = &rule.exec {
+        let child = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .envs(alert_env(alert))
+            .stdin(Stdio::null())
+            .spawn();
+        match child {
+            // Reap the command from a separate thread for the same reason
+            Ok(mut child) => {
+                let command = command.clone();
+                thread::spawn(move || match child.wait() {
+                    Ok(status) if !status.success() => {
+                        error!("Alert command '{}' failed with {}", command, status)
+                    }
+                    Err(e) => error!("Failed to wait for alert command '{}': {}", command, e),
+                    Ok(_) => {}
+                });
+            }
+            Err(e) => error!("Failed to run alert command '{}': {}", command, e),
+        }
+    }
+}
+
+// Environment of alert commands. Lists are comma separated and BPFTOP_ALERT holds the
+// same JSON document that is posted to webhooks.
+fn alert_env(alert: &Alert) -> Vec<(&'static str, String)> {
+    vec![
+        ("BPFTOP_RULE", alert.rule.clone()),
+        ("BPFTOP_CONDITION", alert.condition.clone()),
+        (
+            "BPFTOP_VALUE",
+            alert
+                .value
+                .map(|value| value.to_string())
+                .unwrap_or_default(),
+        ),
+        ("BPFTOP_DURATION_SECS", alert.duration_secs.to_string()),
+        ("BPFTOP_PROG_ID", alert.id.to_string()),
+        ("BPFTOP_PROG_NAME", alert.name.clone()),
+        ("BPFTOP_PROG_TYPE", alert.bpf_type.clone()),
+        ("BPFTOP_CPU_PERCENT", alert.cpu_time_percent.to_string()),
+        (
+            "BPFTOP_EVENTS_PER_SECOND",
+            alert.events_per_second.to_string(),
+        ),
+        (
+            "BPFTOP_AVG_RUNTIME_NS",
+            alert.period_average_runtime_ns.to_string(),
+        ),
+        (
+            "BPFTOP_TOTAL_AVG_RUNTIME_NS",
+            alert.total_average_runtime_ns.to_string(),
+        ),
+        ("BPFTOP_PROCESSES", alert.processes.join(",")),
+        ("BPFTOP_ATTACHMENTS", alert.attachments.join(",")),
+        (
+            "BPFTOP_ALERT",
+            serde_json::to_string(alert).unwrap_or_default(),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, time::Instant};
+
+    fn program(id: u32, name: &str, bpf_type: &str, cpu_time_percent: u64) -> BpfProgram {
+        BpfProgram {
+            id,
+            bpf_type: bpf_type.to_string(),
+            name: name.to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 0,
+            run_time_ns: cpu_time_percent * 10_000_000,
+            prev_run_cnt: 0,
+            run_cnt: 100,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_parse_condition() {
+        let condition = Condition::parse("cpu_percent >= 2.5").unwrap();
+        assert_eq!(condition.to_string(), "cpu_percent >= 2.5");
+        assert_eq!(
+            Condition::parse("events_per_second<10")
+                .unwrap()
+                .to_string(),
+            "events_per_second < 10"
+        );
+        assert!(Condition::parse("cpu_percent 3").is_err());
+        assert!(Condition::parse("memory > 3").is_err());
+        assert!(Condition::parse("cpu_percent > x").is_err());
+        let condition =
+            Condition::parse("period_run_time_ns/period_run_cnt > max(2.0, total_avg_runtime_ns)")
+                .unwrap();
+        assert_eq!(
+            condition.to_string(),
+            "period_run_time_ns/period_run_cnt > max(2.0, total_avg_runtime_ns)"
+        );
+        // Comparisons inside strings and parentheses are not the condition's operator
+        let condition = Condition::parse("(1 << 2) + name.len() >= \"a<b\".len()").unwrap();
+        assert_eq!(
+            condition.to_string(),
+            "(1 << 2) + name.len() >= \"a<b\".len()"
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("30").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_duration("10s").unwrap(), Duration::from_secs(10));
+        assert_eq!(parse_duration("2m").unwrap(), Duration::from_secs(120));
+        assert_eq!(parse_duration("1h").unwrap(), Duration::from_secs(3600));
+        assert!(parse_duration("5d").is_err());
+        assert!(parse_duration("s").is_err());
+        assert!(parse_duration(&format!("{}h", u64::MAX / 60)).is_err());
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("cil_*", "cil_from_netdev"));
+        assert!(glob_match("*_xdp_*", "cilium_xdp_entry"));
+        assert!(glob_match("tail", "tail"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("tail", "tail_call"));
+        assert!(!glob_match("cil_*_in", "cil_from_netdev"));
+    }
+
+    #[test]
+    fn test_matches() {
+        let config = Config::parse(
+            r#"
+            [labels]
+            cilium = ["cil_*", "tail_*"]
+
+            [[rule]]
+            name = "cilium-xdp"
+            type = "xdp"
+            label = "cilium"
+            condition = "cpu_percent > 3"
+            "#,
+        )
+        .unwrap();
+        let rule = Rule::from_config(&config.rules[0], &config).unwrap();
+        assert!(rule.matches(&program(1, "cil_xdp_entry", "XDP", 0)));
+        assert!(!rule.matches(&program(1, "cil_from_netdev", "sched_cls", 0)));
+        assert!(!rule.matches(&program(1, "other", "xdp", 0)));
+
+        let config = Config::parse(
+            r#"
+            [[rule]]
+            name = "missing"
+            label = "missing"
+            condition = "cpu_percent > 3"
+            "#,
+        )
+        .unwrap();
+        assert!(RuleEngine::from_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_deprecated_action() {
+        let config = Config::parse(
+            r#"
+            [[rule]]
+            name = "posted"
+            condition = "cpu_percent > 3"
+            action = "webhook"
+            webhook = "http://localhost/alert"
+
+            [[rule]]
+            name = "logged"
+            condition = "cpu_percent > 3"
+            action = "log"
+            webhook = "http://localhost/alert"
+            "#,
+        )
+        .unwrap();
+        let posted = Rule::from_config(&config.rules[0], &config).unwrap();
+        assert_eq!(posted.webhook.as_deref(), Some("http://localhost/alert"));
+        let logged = Rule::from_config(&config.rules[1], &config).unwrap();
+        assert_eq!(logged.webhook, None);
+
+        let config = Config::parse(
+            r#"
+            [[rule]]
+            name = "missing"
+            condition = "cpu_percent > 3"
+            action = "webhook"
+            "#,
+        )
+        .unwrap();
+        assert!(RuleEngine::from_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_script_condition() {
+        let dir = std::env::temp_dir().join(format!("bpftop-rules-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("cilium.rhai"),
+            "name.starts_with(\"cil_\") && cpu_percent > 3",
+        )
+        .unwrap();
+        let mut config = Config::parse(
+            r#"
+            [[rule]]
+            name = "cilium"
+            script = "cilium.rhai"
+            "#,
+        )
+        .unwrap();
+        config.dir = dir.clone();
+        let rule = Rule::from_config(&config.rules[0], &config).unwrap();
+        assert!(rule
+            .condition
+            .holds(&program(1, "cil_xdp", "xdp", 5))
+            .unwrap());
+        assert!(!rule
+            .condition
+            .holds(&program(1, "other", "xdp", 5))
+            .unwrap());
+        assert_eq!(rule.condition.value(&program(1, "cil_xdp", "xdp", 5)), None);
+        fs::remove_dir_all(&dir).unwrap();
+
+        // A rule needs exactly one of the two
+        let config = Config::parse(
+            r#"
+            [[rule]]
+            name = "both"
+            condition = "cpu_percent > 3"
+            script = "cilium.rhai"
+            "#,
+        )
+        .unwrap();
+        assert!(RuleEngine::from_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_alert_env() {
+        let alert = Alert {
+            rule: "hot".to_string(),
+            condition: "cpu_percent > 3".to_string(),
+            value: Some(5.0),
+            duration_secs: 10,
+            id: 7,
+            name: "prog".to_string(),
+            bpf_type: "xdp".to_string(),
+            cpu_time_percent: 5.0,
+            events_per_second: 100,
+            period_average_runtime_ns: 500,
+            total_average_runtime_ns: 400,
+            processes: vec!["agent (1)".to_string(), "agent (2)".to_string()],
+            attachments: vec![],
+        };
+        let env: HashMap<_, _> = alert_env(&alert).into_iter().collect();
+        assert_eq!(env["BPFTOP_RULE"], "hot");
+        assert_eq!(env["BPFTOP_PROG_ID"], "7");
+        assert_eq!(env["BPFTOP_PROCESSES"], "agent (1),agent (2)");
+        assert_eq!(env["BPFTOP_ATTACHMENTS"], "");
+        let json: serde_json::Value = serde_json::from_str(&env["BPFTOP_ALERT"]).unwrap();
+        assert_eq!(json["name"], "prog");
+    }
+
+    #[test]
+    fn test_observe() {
+        let mut engine = RuleEngine::new(vec![Rule::new(
+            "cpu",
+            Condition::above("cpu_percent", 3.0).unwrap(),
+            2,
+        )]);
+        let hot = program(1, "prog", "xdp", 5);
+        let cold = program(1, "prog", "xdp", 1);
+        assert!(engine.observe(&hot).is_empty());
+        assert_eq!(engine.observe(&hot), vec![0]);
+        // Fires once per excursion
+        assert!(engine.observe(&hot).is_empty());
+        assert!(engine.observe(&cold).is_empty());
+        assert!(engine.observe(&hot).is_empty());
+        assert_eq!(engine.observe(&hot), vec![0]);
+        // Programs are tracked independently
+        assert!(engine.observe(&program(2, "prog", "xdp", 3)).is_empty());
+        assert!(engine.observe(&program(2, "prog", "xdp", 4)).is_empty());
+    }
+}
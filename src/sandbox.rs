@@ -0,0 +1,438 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use anyhow::{anyhow, Context, Result};
+use nix::libc;
+use std::{
+    fs::File,
+    io,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+    path::{Path, PathBuf},
+};
+use tracing::{info, warn};
+
+// Filesystem access rights of the first Landlock ABI, supported by every kernel with Landlock
+const ACCESS_FS_EXECUTE: u64 = 1 << 0;
+const ACCESS_FS_WRITE_FILE: u64 = 1 << 1;
+const ACCESS_FS_READ_FILE: u64 = 1 << 2;
+const ACCESS_FS_READ_DIR: u64 = 1 << 3;
+const ACCESS_FS_MAKE_REG: u64 = 1 << 8;
+const ACCESS_FS_MAKE_SOCK: u64 = 1 << 9;
+// All of them, including removing and creating every other kind of file
+const ACCESS_FS_ALL: u64 = (1 << 13) - 1;
+const RULE_PATH_BENEATH: libc::c_int = 1;
+
+const READ_ACCESS: u64 = ACCESS_FS_READ_FILE | ACCESS_FS_READ_DIR;
+const WRITE_ACCESS: u64 = READ_ACCESS | ACCESS_FS_WRITE_FILE | ACCESS_FS_MAKE_REG;
+// The terminal and /dev/null can be written, but nothing can be created under /dev
+const DEVICE_ACCESS: u64 = READ_ACCESS | ACCESS_FS_WRITE_FILE;
+// The event socket is created in its directory, once a stale one was removed before sandboxing
+const SOCKET_ACCESS: u64 = READ_ACCESS | ACCESS_FS_MAKE_SOCK;
+// Rights that apply to files rather than directories
+const FILE_ACCESS: u64 = ACCESS_FS_EXECUTE | ACCESS_FS_WRITE_FILE | ACCESS_FS_READ_FILE;
+
+// Everything bpftop reads once it has started: procfs and sysfs for BPF objects, cgroups and
+// interfaces, /etc for webhook name resolution, shared libraries loaded on demand, the
+// terminal, and container metadata
+const READ_PATHS: [&str; 7] = [
+    "/proc",
+    "/sys",
+    "/etc",
+    "/usr",
+    "/lib",
+    "/lib64",
+    "/var/lib/docker/containers",
+];
+
+#[repr(C)]
+struct RulesetAttr {
+    handled_access_fs: u64,
+}
+
+#[repr(C, packed)]
+struct PathBeneathAttr {
+    allowed_access: u64,
+    parent_fd: i32,
+}
+
+#[cfg(target_arch = "x86_64")]
+const AUDIT_ARCH: u32 = 0xc000_003e;
+#[cfg(target_arch = "aarch64")]
+const AUDIT_ARCH: u32 = 0xc000_00b7;
+
+// Syscalls made once the sandbox is applied, which happens before the signal thread, the
+// exporters, the audit and the collector start: by bpftop, libbpf, the UI, journald logging,
+// the webhook client and the exporters. Anything else fails with EPERM, notably execve, so
+// rules and --exec cannot run commands, and kill, so no process can be signalled.
+const SYSCALLS: &[libc::c_long] = &[
+    libc::SYS_read,
+    libc::SYS_write,
+    libc::SYS_readv,
+    libc::SYS_writev,
+    libc::SYS_pread64,
+    libc::SYS_pwrite64,
+    libc::SYS_openat,
+    libc::SYS_close,
+    libc::SYS_fstat,
+    libc::SYS_newfstatat,
+    libc::SYS_statx,
+    libc::SYS_statfs,
+    libc::SYS_fstatfs,
+    libc::SYS_lseek,
+    libc::SYS_getdents64,
+    libc::SYS_readlinkat,
+    libc::SYS_faccessat,
+    libc::SYS_faccessat2,
+    libc::SYS_fcntl,
+    libc::SYS_ioctl,
+    libc::SYS_dup,
+    libc::SYS_dup3,
+    libc::SYS_pipe2,
+    libc::SYS_getcwd,
+    libc::SYS_mmap,
+    libc::SYS_munmap,
+    libc::SYS_mprotect,
+    libc::SYS_mremap,
+    libc::SYS_madvise,
+    libc::SYS_brk,
+    libc::SYS_rt_sigaction,
+    libc::SYS_rt_sigprocmask,
+    libc::SYS_rt_sigreturn,
+    libc::SYS_rt_sigtimedwait,
+    libc::SYS_sigaltstack,
+    libc::SYS_restart_syscall,
+    libc::SYS_futex,
+    libc::SYS_set_robust_list,
+    libc::SYS_rseq,
+    libc::SYS_membarrier,
+    libc::SYS_clone,
+    libc::SYS_clone3,
+    libc::SYS_exit,
+    libc::SYS_exit_group,
+    libc::SYS_gettid,
+    libc::SYS_getpid,
+    libc::SYS_getppid,
+    libc::SYS_getuid,
+    libc::SYS_geteuid,
+    libc::SYS_getgid,
+    libc::SYS_getegid,
+    libc::SYS_sched_yield,
+    libc::SYS_sched_getaffinity,
+    libc::SYS_nanosleep,
+    libc::SYS_clock_nanosleep,
+    libc::SYS_clock_gettime,
+    libc::SYS_clock_getres,
+    libc::SYS_gettimeofday,
+    libc::SYS_getrandom,
+    libc::SYS_prlimit64,
+    libc::SYS_uname,
+    libc::SYS_sysinfo,
+    libc::SYS_prctl,
+    libc::SYS_bpf,
+    libc::SYS_perf_event_open,
+    libc::SYS_socket,
+    libc::SYS_bind,
+    libc::SYS_listen,
+    libc::SYS_accept4,
+    libc::SYS_connect,
+    libc::SYS_sendto,
+    libc::SYS_recvfrom,
+    libc::SYS_sendmsg,
+    libc::SYS_recvmsg,
+    libc::SYS_sendmmsg,
+    libc::SYS_recvmmsg,
+    libc::SYS_getsockname,
+    libc::SYS_getpeername,
+    libc::SYS_setsockopt,
+    libc::SYS_getsockopt,
+    libc::SYS_shutdown,
+    libc::SYS_ppoll,
+    libc::SYS_pselect6,
+    libc::SYS_epoll_create1,
+    libc::SYS_epoll_ctl,
+    libc::SYS_epoll_pwait,
+    libc::SYS_eventfd2,
+    libc::SYS_memfd_create,
+    libc::SYS_ftruncate,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_open,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_stat,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_lstat,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_access,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_readlink,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_poll,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_select,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_pipe,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_dup2,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_epoll_wait,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_arch_prctl,
+    #[cfg(target_arch = "x86_64")]
+    libc::SYS_getrlimit,
+];
+
+// Restricts the filesystem paths and syscalls available to bpftop once it has started.
+// Landlock only applies to the calling thread and the threads it spawns later, so this
+// has to run before any other thread is spawned.
+pub struct Sandbox {
+    read: Vec<PathBuf>,
+    write: Vec<PathBuf>,
+    sockets: Vec<PathBuf>,
+}
+
+impl Sandbox {
+    pub fn new(bpffs_mounts: Vec<PathBuf>) -> Self {
+        let mut read: Vec<PathBuf> = READ_PATHS.iter().map(PathBuf::from).collect();
+        read.extend(bpffs_mounts);
+        Sandbox {
+            read,
+            write: Vec::new(),
+            sockets: Vec::new(),
+        }
+    }
+
+    // Allows writing the file at path, or creating it in its directory if it does not exist
+    pub fn allow_write(mut self, path: &Path) -> Self {
+        let path = if path.exists() {
+            path
+        } else {
+            match path.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir,
+                _ => Path::new("."),
+            }
+        };
+        self.write.push(path.to_path_buf());
+        self
+    }
+
+    // Allows binding a Unix socket at path
+    pub fn allow_socket(mut self, path: &Path) -> Self {
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        self.sockets.push(dir.to_path_buf());
+        self
+    }
+
+    pub fn apply(self) -> Result<()> {
+        if unsafe { libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) } != 0 {
+            return Err(io::Error::last_os_error()).context("Failed to set no_new_privs");
+        }
+        // Landlock may be missing or disabled, in which case the seccomp filter still applies
+        match self.restrict_paths() {
+            Ok(()) => info!("Restricted filesystem access with Landlock"),
+            Err(e) => warn!("Filesystem access not restricted: {:?}", e),
+        }
+        restrict_syscalls().context("Failed to install the seccomp filter")?;
+        info!("Restricted syscalls with seccomp");
+        Ok(())
+    }
+
+    fn restrict_paths(&self) -> Result<()> {
+        let attr = RulesetAttr {
+            handled_access_fs: ACCESS_FS_ALL,
+        };
+        let fd = unsafe {
+            libc::syscall(
+                libc::SYS_landlock_create_ruleset,
+                &attr as *const RulesetAttr,
+                std::mem::size_of::<RulesetAttr>(),
+                0,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error()).context("Landlock is not available");
+        }
+        let ruleset = unsafe { OwnedFd::from_raw_fd(fd as i32) };
+
+        let rules = self
+            .read
+            .iter()
+            .map(|path| (path.as_path(), READ_ACCESS))
+            .chain([(Path::new("/dev"), DEVICE_ACCESS)])
+            .chain(self.write.iter().map(|path| (path.as_path(), WRITE_ACCESS)))
+            .chain(
+                self.sockets
+                    .iter()
+                    .map(|path| (path.as_path(), SOCKET_ACCESS)),
+            );
+        for (path, access) in rules {
+            // Optional paths such as the container directories may not exist
+            let Ok(file) = File::open(path) else {
+                continue;
+            };
+            let access = if file.metadata()?.is_dir() {
+                access
+            } else {
+                access & FILE_ACCESS
+            };
+            add_rule(&ruleset, &file, access)
+                .context(format!("Failed to allow access to {}", path.display()))?;
+        }
+
+        if unsafe { libc::syscall(libc::SYS_landlock_restrict_self, ruleset.as_raw_fd(), 0) } != 0 {
+            return Err(io::Error::last_os_error())
+                .context("Failed to enforce the Landlock ruleset");
+        }
+        Ok(())
+    }
+}
+
+fn add_rule(ruleset: &OwnedFd, file: &File, access: u64) -> io::Result<()> {
+    let attr = PathBeneathAttr {
+        allowed_access: access,
+        parent_fd: file.as_raw_fd(),
+    };
+    let ret = unsafe {
+        libc::syscall(
+            libc::SYS_landlock_add_rule,
+            ruleset.as_raw_fd(),
+            RULE_PATH_BENEATH,
+            &attr as *const PathBeneathAttr,
+            0,
+        )
+    };
+    if ret != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+fn bpf_stmt(code: u32, k: u32) -> libc::sock_filter {
+    libc::sock_filter {
+        code: code as u16,
+        jt: 0,
+        jf: 0,
+        k,
+    }
+}
+
+fn bpf_jump(code: u32, k: u32, jt: u8, jf: u8) -> libc::sock_filter {
+    libc::sock_filter {
+        code: code as u16,
+        jt,
+        jf,
+        k,
+    }
+}
+
+// Classic BPF program allowing the syscalls in `syscalls` and failing any other with EPERM.
+// Syscalls of a foreign architecture kill the process, as their numbers mean something else.
+fn seccomp_filter(syscalls: &[libc::c_long]) -> Vec<libc::sock_filter> {
+    let arch_offset = std::mem::offset_of!(libc::seccomp_data, arch) as u32;
+    let nr_offset = std::mem::offset_of!(libc::seccomp_data, nr) as u32;
+    let count = syscalls.len();
+
+    let mut filter = vec![
+        bpf_stmt(libc::BPF_LD | libc::BPF_W | libc::BPF_ABS, arch_offset),
+        bpf_jump(
+            libc::BPF_JMP | libc::BPF_JEQ | libc::BPF_K,
+            AUDIT_ARCH,
+            1,
+            0,
+        ),
+        bpf_stmt(libc::BPF_RET | libc::BPF_K, libc::SECCOMP_RET_KILL_PROCESS),
+        bpf_stmt(libc::BPF_LD | libc::BPF_W | libc::BPF_ABS, nr_offset),
+    ];
+    // Each match jumps over the remaining comparisons and the EPERM return to the allow
+    for (i, nr) in syscalls.iter().enumerate() {
+        let to_allow = (count - i) as u8;
+        filter.push(bpf_jump(
+            libc::BPF_JMP | libc::BPF_JEQ | libc::BPF_K,
+            *nr as u32,
+            to_allow,
+            0,
+        ));
+    }
+    filter.push(bpf_stmt(
+        libc::BPF_RET | libc::BPF_K,
+        libc::SECCOMP_RET_ERRNO | libc::EPERM as u32,
+    ));
+    filter.push(bpf_stmt(
+        libc::BPF_RET | libc::BPF_K,
+        libc::SECCOMP_RET_ALLOW,
+    ));
+    filter
+}
+
+// Installs the filter on every thread of the process
+fn restrict_syscalls() -> Result<()> {
+    let filter = seccomp_filter(SYSCALLS);
+    if filter.len() > u8::MAX as usize {
+        return Err(anyhow!("Too many syscalls for the seccomp filter"));
+    }
+    let prog = libc::sock_fprog {
+        len: filter.len() as u16,
+        filter: filter.as_ptr() as *mut libc::sock_filter,
+    };
+    let ret = unsafe {
+        libc::syscall(
+            libc::SYS_seccomp,
+            libc::SECCOMP_SET_MODE_FILTER,
+            libc::SECCOMP_FILTER_FLAG_TSYNC,
+            &prog as *const libc::sock_fprog,
+        )
+    };
+    if ret != 0 {
+        return Err(io::Error::last_os_error().into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seccomp_filter() {
+        let filter = seccomp_filter(&[libc::SYS_read, libc::SYS_bpf]);
+        assert_eq!(filter.len(), 8);
+        // Both comparisons land on the final allow
+        assert_eq!((filter[4].k, filter[4].jt), (libc::SYS_read as u32, 2));
+        assert_eq!((filter[5].k, filter[5].jt), (libc::SYS_bpf as u32, 1));
+        assert_eq!(filter[6].k, libc::SECCOMP_RET_ERRNO | libc::EPERM as u32);
+        assert_eq!(filter[7].k, libc::SECCOMP_RET_ALLOW);
+        assert!(seccomp_filter(SYSCALLS).len() <= u8::MAX as usize);
+    }
+
+    #[test]
+    fn test_apply() {
+        // The filter applies to every thread, so it is installed in a child process
+        let pid = unsafe { libc::fork() };
+        assert!(pid >= 0);
+        if pid == 0 {
+            let blocked = Sandbox::new(vec![]).apply().is_ok()
+                && unsafe { libc::kill(libc::getppid(), 0) } == -1
+                && io::Error::last_os_error().raw_os_error() == Some(libc::EPERM);
+            unsafe { libc::_exit(if blocked { 0 } else { 1 }) };
+        }
+        let mut status = 0;
+        assert_eq!(unsafe { libc::waitpid(pid, &mut status, 0) }, pid);
+        assert!(libc::WIFEXITED(status));
+        assert_eq!(libc::WEXITSTATUS(status), 0);
+    }
+}
@@ -0,0 +1,278 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use anyhow::{anyhow, bail, Context, Result};
+use bpftop_core::bpf_program::BpfProgram;
+use rhai::{Dynamic, Engine, Scope, AST};
+use serde::Deserialize;
+use std::{
+    fmt, fs,
+    path::Path,
+    sync::{Arc, OnceLock},
+    time::Instant,
+};
+use tracing::debug;
+
+// Bounds the work of one evaluation, so a runaway script cannot stall the collector or UI
+const MAX_OPERATIONS: u64 = 100_000;
+
+static ENGINE: OnceLock<Engine> = OnceLock::new();
+
+fn engine() -> &'static Engine {
+    ENGINE.get_or_init(|| {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        // print() and debug() would otherwise write over the terminal UI
+        engine.on_print(|text| debug!("script: {}", text));
+        engine.on_debug(|text, _, _| debug!("script: {}", text));
+        engine
+    })
+}
+
+// The program fields scripts can read. Numbers are floats, so dividing two of them never
+// truncates.
+fn program_scope(prog: &BpfProgram) -> Scope<'static> {
+    let mut scope = Scope::new();
+    scope.push_constant("id", prog.id as i64);
+    scope.push_constant("name", prog.name.clone());
+    scope.push_constant("bpf_type", prog.bpf_type.clone());
+    scope.push_constant("tag", prog.tag.clone());
+    scope.push_constant("cpu_percent", prog.cpu_time_percent());
+    scope.push_constant("events_per_second", prog.events_per_second() as f64);
+    scope.push_constant("avg_runtime_ns", prog.period_average_runtime_ns() as f64);
+    scope.push_constant(
+        "total_avg_runtime_ns",
+        prog.total_average_runtime_ns() as f64,
+    );
+    // Cumulative since stats were enabled
+    scope.push_constant("run_time_ns", prog.run_time_ns as f64);
+    scope.push_constant("run_cnt", prog.run_cnt as f64);
+    // Within the last period
+    scope.push_constant("period_run_time_ns", prog.runtime_delta() as f64);
+    scope.push_constant("period_run_cnt", prog.run_cnt_delta() as f64);
+    scope.push_constant("processes", prog.processes.len() as f64);
+    scope
+}
+
+// A program with every field zeroed, to check that an expression only reads known fields
+pub fn empty_program() -> BpfProgram {
+    BpfProgram {
+        id: 0,
+        bpf_type: String::new(),
+        name: String::new(),
+        tag: String::new(),
+        prev_runtime_ns: 0,
+        run_time_ns: 0,
+        prev_run_cnt: 0,
+        run_cnt: 0,
+        instant: Instant::now(),
+        period_ns: 0,
+        processes: vec![],
+    }
+}
+
+// A rhai expression written inline in the config file, or a script file next to it,
+// evaluated against one program at a time
+#[derive(Clone)]
+pub struct Script {
+    // The expression, or the path of the script file
+    source: String,
+    ast: Arc<AST>,
+}
+
+impl Script {
+    pub fn expression(source: &str) -> Result<Script> {
+        let ast = engine()
+            .compile_expression(source)
+            .map_err(|e| anyhow!("Invalid expression '{}': {}", source.trim(), e))?;
+        Ok(Script {
+            source: source.trim().to_string(),
+            ast: Arc::new(ast),
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Script> {
+        let source = fs::read_to_string(path)
+            .context(format!("Failed to read script {}", path.display()))?;
+        let ast = engine()
+            .compile(&source)
+            .map_err(|e| anyhow!("Invalid script {}: {}", path.display(), e))?;
+        Ok(Script {
+            source: path.display().to_string(),
+            ast: Arc::new(ast),
+        })
+    }
+
+    pub fn eval(&self, prog: &BpfProgram) -> Result<Dynamic> {
+        engine()
+            .eval_ast_with_scope(&mut program_scope(prog), &self.ast)
+            .map_err(|e| anyhow!("{} failed: {}", self.source, e))
+    }
+
+    // Division by zero yields zero, as for programs that did not run in the period
+    pub fn eval_number(&self, prog: &BpfProgram) -> Result<f64> {
+        let value = self.eval(prog)?;
+        let number = match value.as_float() {
+            Ok(number) => number,
+            Err(_) => value.as_int().map_err(|type_name| {
+                anyhow!("{} returned a {}, not a number", self.source, type_name)
+            })? as f64,
+        };
+        Ok(if number.is_finite() { number } else { 0.0 })
+    }
+
+    pub fn eval_bool(&self, prog: &BpfProgram) -> Result<bool> {
+        self.eval(prog)?
+            .as_bool()
+            .map_err(|type_name| anyhow!("{} returned a {}, not a boolean", self.source, type_name))
+    }
+}
+
+impl fmt::Debug for Script {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Script({})", self.source)
+    }
+}
+
+impl fmt::Display for Script {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+// Extra program list column as written in the config file, computed by an inline
+// expression or by a script in the config directory
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ColumnConfig {
+    pub name: String,
+    pub expr: Option<String>,
+    pub script: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CustomColumn {
+    pub name: String,
+    script: Script,
+}
+
+impl CustomColumn {
+    pub fn from_config(column: &ColumnConfig, config_dir: &Path) -> Result<Self> {
+        let script = match (&column.expr, &column.script) {
+            // Evaluated once against an empty program so that unknown fields are reported here
+            (Some(expr), None) => Script::expression(expr)
+                .and_then(|script| script.eval(&empty_program()).map(|_| script)),
+            (None, Some(file)) => Script::load(&config_dir.join(file)),
+            _ => bail!("Column '{}' needs either an expr or a script", column.name),
+        };
+        Ok(CustomColumn {
+            name: column.name.clone(),
+            script: script.context(format!("Invalid column '{}'", column.name))?,
+        })
+    }
+
+    // Whole numbers are shown as such, other numbers with two decimals and anything else,
+    // such as strings, as the script returned it
+    pub fn value(&self, prog: &BpfProgram) -> String {
+        let value = match self.script.eval(prog) {
+            Ok(value) => value,
+            Err(e) => {
+                debug!("Column '{}': {:#}", self.name, e);
+                return "error".to_string();
+            }
+        };
+        let number = value
+            .as_float()
+            .ok()
+            .or_else(|| value.as_int().ok().map(|value| value as f64));
+        match number {
+            Some(number) if !number.is_finite() => "0".to_string(),
+            Some(number) if number.fract() == 0.0 => format!("{}", number),
+            Some(number) => format!("{:.2}", number),
+            None => value.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program() -> BpfProgram {
+        BpfProgram {
+            id: 1,
+            bpf_type: "Kprobe".to_string(),
+            name: "cil_from_netdev".to_string(),
+            tag: String::new(),
+            prev_runtime_ns: 1000,
+            run_time_ns: 5000,
+            prev_run_cnt: 10,
+            run_cnt: 30,
+            instant: Instant::now(),
+            period_ns: 1_000_000_000,
+            processes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_expression() {
+        let prog = program();
+        let eval = |source: &str| Script::expression(source).unwrap().eval_number(&prog);
+
+        assert_eq!(eval("period_run_time_ns / period_run_cnt").unwrap(), 200.0);
+        assert_eq!(eval("1 + 2 * 3").unwrap(), 7.0);
+        assert_eq!(eval("(1 + 2) * -3").unwrap(), -9.0);
+        assert_eq!(eval("run_cnt / processes").unwrap(), 0.0);
+        assert!(eval("name").is_err());
+        assert!(Script::expression("name.starts_with(\"cil_\")")
+            .unwrap()
+            .eval_bool(&prog)
+            .unwrap());
+
+        assert!(Script::expression("(1 + 2").is_err());
+        assert!(Script::expression("let x = 1; x").is_err());
+        assert!(Script::expression("memory * 2")
+            .unwrap()
+            .eval(&prog)
+            .is_err());
+    }
+
+    #[test]
+    fn test_script_column() {
+        let dir = std::env::temp_dir().join(format!("bpftop-script-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("kind.rhai"),
+            "let prefix = name.split(\"_\")[0];\nif prefix == \"cil\" { \"cilium\" } else { prefix }\n",
+        )
+        .unwrap();
+        fs::write(dir.join("spin.rhai"), "loop {}").unwrap();
+        let config = |script: &str| ColumnConfig {
+            name: "kind".to_string(),
+            expr: None,
+            script: Some(script.to_string()),
+        };
+
+        let column = CustomColumn::from_config(&config("kind.rhai"), &dir).unwrap();
+        assert_eq!(column.value(&program()), "cilium");
+        // Stopped once it runs out of operations
+        let column = CustomColumn::from_config(&config("spin.rhai"), &dir).unwrap();
+        assert_eq!(column.value(&program()), "error");
+        assert!(CustomColumn::from_config(&config("missing.rhai"), &dir).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
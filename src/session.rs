@@ -0,0 +1,90 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{app::View, stars::Stars};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+// Where the UI state is kept between runs on the same host
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    pub view: View,
+    // Program selected in the program list, or shown in the graph view
+    pub program_id: Option<u32>,
+    // Selected row and scroll offset of the table of the view
+    pub selected: Option<usize>,
+    pub offset: usize,
+    // Whether the graph view of the program was open
+    pub graphs: bool,
+    pub stars: Stars,
+}
+
+// $XDG_STATE_HOME/bpftop/session.json, falling back to ~/.local/state
+pub fn default_path() -> Option<PathBuf> {
+    let state_home = env::var_os("XDG_STATE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local/state")))?;
+    Some(state_home.join("bpftop").join("session.json"))
+}
+
+impl Session {
+    // A missing or unreadable session starts bpftop from scratch
+    pub fn load(path: &Path) -> Session {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string(self)?;
+        fs::write(path, json)
+            .with_context(|| format!("Failed to save session to {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_load() {
+        let path = env::temp_dir().join(format!("bpftop-session-{}.json", std::process::id()));
+        assert_eq!(Session::load(&path), Session::default());
+
+        let session = Session {
+            view: View::Maps,
+            program_id: Some(42),
+            selected: Some(3),
+            offset: 1,
+            graphs: true,
+            stars: Stars::default(),
+        };
+        session.save(&path).unwrap();
+        assert_eq!(Session::load(&path), session);
+
+        fs::write(&path, "{\"view\":\"Pins\",\"unknown\":1}").unwrap();
+        assert_eq!(Session::load(&path).view, View::Pins);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,102 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use anyhow::{bail, Context, Result};
+use bpftop_core::bpf_program::Process;
+use nix::{
+    sys::signal::{kill, SigSet, Signal},
+    unistd::Pid,
+};
+use std::{fs, process, thread};
+use tracing::info;
+
+// Signals that would otherwise kill bpftop without restoring what it changed
+const TERMINATION_SIGNALS: [Signal; 3] = [Signal::SIGTERM, Signal::SIGHUP, Signal::SIGINT];
+
+fn termination_signals() -> SigSet {
+    let mut signals = SigSet::empty();
+    for signal in TERMINATION_SIGNALS {
+        signals.add(signal);
+    }
+    signals
+}
+
+// Blocks the termination signals and waits for them on a dedicated thread, which runs
+// `cleanup` before exiting. Threads inherit the signal mask, so this has to be called
+// before any other thread is spawned.
+pub fn handle_termination<F>(cleanup: F) -> Result<()>
+where
+    F: FnOnce() + Send + 'static,
+{
+    let signals = termination_signals();
+    signals.thread_block()?;
+    thread::spawn(move || {
+        let Ok(signal) = signals.wait() else {
+            return;
+        };
+        info!("Received {}, exiting", signal);
+        cleanup();
+        // Conventional exit status of a process terminated by a signal
+        process::exit(128 + signal as i32);
+    });
+    Ok(())
+}
+
+// Sends a signal to a process holding a program, unless its PID has been reused by another
+// process since it was sampled
+pub fn signal_process(holder: &Process, signal: Signal) -> Result<()> {
+    let comm = &holder.comm;
+    let current = fs::read_to_string(format!("/proc/{}/comm", holder.pid))
+        .with_context(|| format!("Process {} ({}) has exited", comm, holder.pid))?;
+    if current.trim_end() != comm {
+        bail!(
+            "PID {} now belongs to {}, not {}, nothing sent",
+            holder.pid,
+            current.trim_end(),
+            comm
+        );
+    }
+    kill(Pid::from_raw(holder.pid), signal)
+        .with_context(|| format!("Failed to send {} to {} ({})", signal, comm, holder.pid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_termination_signals() {
+        let signals = termination_signals();
+        assert!(signals.contains(Signal::SIGTERM));
+        assert!(signals.contains(Signal::SIGHUP));
+        assert!(!signals.contains(Signal::SIGKILL));
+    }
+
+    #[test]
+    fn test_signal_reused_pid() {
+        let holder = Process {
+            pid: process::id() as i32,
+            comm: "not-bpftop".to_string(),
+            container: None,
+            cgroup_id: 0,
+            bpf_fds: 1,
+            user: None,
+        };
+        let e = signal_process(&holder, Signal::SIGKILL).unwrap_err();
+        assert!(e.to_string().ends_with("not not-bpftop, nothing sent"));
+    }
+}
@@ -0,0 +1,352 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{bpf_map::BpfMap, bpffs::Pin, loaders::LoaderRegistry, rules::Alert};
+use anyhow::{Context, Result};
+use bpftop_core::{
+    bpf_attachment::{expected_attach_type, BpfAttachment},
+    bpf_program::{BpfProgram, Process},
+    cgroups::CgroupPrograms,
+    interfaces::InterfacePrograms,
+    sampler::Timestamp,
+};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+// Everything collected in one period, before the filter is applied
+#[derive(Clone, Debug, Default)]
+pub struct Sample {
+    // When the programs were sampled
+    pub timestamp: Timestamp,
+    pub programs: Vec<BpfProgram>,
+    pub maps: Vec<BpfMap>,
+    pub cgroups: Vec<CgroupPrograms>,
+    pub interfaces: Vec<InterfacePrograms>,
+    pub pins: Vec<Pin>,
+    // Alerts the rules fired on this period's programs
+    pub alerts: Vec<Alert>,
+}
+
+// Only resolved on Kubernetes nodes, with the kubernetes feature
+#[derive(Debug, Serialize)]
+struct PodSnapshot {
+    namespace: String,
+    name: String,
+    labels: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ProcessSnapshot {
+    pid: i32,
+    comm: String,
+    container: Option<String>,
+    pod: Option<PodSnapshot>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProgramSnapshot {
+    pub id: u32,
+    name: String,
+    bpf_type: String,
+    tag: String,
+    run_time_ns: u64,
+    run_cnt: u64,
+    events_per_second: i64,
+    cpu_time_percent: f64,
+    period_average_runtime_ns: u64,
+    total_average_runtime_ns: u64,
+    processes: Vec<ProcessSnapshot>,
+    loader: Option<String>,
+    expected_attach_type: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MapSnapshot {
+    id: u32,
+    name: String,
+    map_type: String,
+    key_size: u32,
+    value_size: u32,
+    max_entries: u32,
+    memlock: u64,
+    entries: Option<u64>,
+    prog_ids: Vec<u32>,
+    pinned_paths: Vec<String>,
+    owners: Vec<ProcessSnapshot>,
+}
+
+#[derive(Debug, Serialize)]
+struct LinkSnapshot {
+    id: u32,
+    link_type: String,
+    target: String,
+    attach_type: String,
+    prog_id: u32,
+}
+
+// A program attached without a link, through the legacy cgroup, XDP or tc APIs
+#[derive(Debug, Serialize)]
+struct AttachmentSnapshot {
+    target: String,
+    hook: &'static str,
+    prog_id: u32,
+}
+
+#[derive(Debug, Serialize)]
+struct PinSnapshot {
+    path: String,
+    kind: &'static str,
+    id: u32,
+}
+
+// Every program, map, link and attachment on the host at one point in time
+#[derive(Debug, Serialize)]
+pub struct Snapshot {
+    timestamp_ms: u64,
+    hostname: String,
+    kernel: String,
+    programs: Vec<ProgramSnapshot>,
+    maps: Vec<MapSnapshot>,
+    links: Vec<LinkSnapshot>,
+    attachments: Vec<AttachmentSnapshot>,
+    pins: Vec<PinSnapshot>,
+}
+
+fn processes(processes: &[Process]) -> Vec<ProcessSnapshot> {
+    processes
+        .iter()
+        .map(|process| ProcessSnapshot {
+            pid: process.pid,
+            comm: process.comm.clone(),
+            container: process
+                .container
+                .as_ref()
+                .map(|container| container.display_name().to_string()),
+            pod: process
+                .container
+                .as_ref()
+                .and_then(|container| container.pod.as_ref())
+                .map(|pod| PodSnapshot {
+                    namespace: pod.namespace.clone(),
+                    name: pod.name.clone(),
+                    labels: pod.labels.clone(),
+                }),
+        })
+        .collect()
+}
+
+fn read_proc(path: &str) -> String {
+    fs::read_to_string(path)
+        .map(|value| value.trim().to_string())
+        .unwrap_or_default()
+}
+
+impl Snapshot {
+    pub fn new(sample: &Sample, links: &[(u32, BpfAttachment)], loaders: &LoaderRegistry) -> Self {
+        let programs = sample
+            .programs
+            .iter()
+            .map(|prog| ProgramSnapshot {
+                id: prog.id,
+                name: prog.name.clone(),
+                bpf_type: prog.bpf_type.clone(),
+                tag: prog.tag.clone(),
+                run_time_ns: prog.run_time_ns,
+                run_cnt: prog.run_cnt,
+                events_per_second: prog.events_per_second(),
+                cpu_time_percent: prog.cpu_time_percent(),
+                period_average_runtime_ns: prog.period_average_runtime_ns(),
+                total_average_runtime_ns: prog.total_average_runtime_ns(),
+                processes: processes(&prog.processes),
+                loader: loaders
+                    .get(prog.id)
+                    .map(|loader| loader.describe(loader.is_alive())),
+                expected_attach_type: expected_attach_type(prog.id, links, &sample.cgroups),
+            })
+            .collect();
+        let maps = sample
+            .maps
+            .iter()
+            .map(|map| MapSnapshot {
+                id: map.id,
+                name: map.name.clone(),
+                map_type: map.map_type.clone(),
+                key_size: map.key_size,
+                value_size: map.value_size,
+                max_entries: map.max_entries,
+                memlock: map.memlock,
+                entries: map.entries,
+                prog_ids: map.prog_ids.clone(),
+                pinned_paths: map.pinned_paths.clone(),
+                owners: processes(&map.owners),
+            })
+            .collect();
+        let links = links
+            .iter()
+            .map(|(prog_id, link)| LinkSnapshot {
+                id: link.link_id,
+                link_type: link.link_type.clone(),
+                target: link.target.clone(),
+                attach_type: link.attach_type.clone(),
+                prog_id: *prog_id,
+            })
+            .collect();
+        let cgroups = sample.cgroups.iter().flat_map(|cgroup| {
+            cgroup
+                .attached
+                .iter()
+                .map(|(hook, prog_id)| AttachmentSnapshot {
+                    target: format!("cgroup {}", cgroup.path),
+                    hook,
+                    prog_id: *prog_id,
+                })
+        });
+        let interfaces = sample.interfaces.iter().flat_map(|iface| {
+            iface
+                .attached
+                .iter()
+                .map(|(hook, prog_id)| AttachmentSnapshot {
+                    target: format!("iface {}", iface.name),
+                    hook,
+                    prog_id: *prog_id,
+                })
+        });
+        let pins = sample
+            .pins
+            .iter()
+            .map(|pin| PinSnapshot {
+                path: pin.path.clone(),
+                kind: pin.kind.name(),
+                id: pin.id,
+            })
+            .collect();
+
+        Snapshot {
+            timestamp_ms: sample.timestamp.wall_ms,
+            hostname: read_proc("/proc/sys/kernel/hostname"),
+            kernel: read_proc("/proc/sys/kernel/osrelease"),
+            programs,
+            maps,
+            links,
+            attachments: cgroups.chain(interfaces).collect(),
+            pins,
+        }
+    }
+
+    pub fn programs(&self) -> &[ProgramSnapshot] {
+        &self.programs
+    }
+
+    pub fn maps(&self) -> &[MapSnapshot] {
+        &self.maps
+    }
+
+    // Writes the snapshot to a file in dir named after the host and time it was taken
+    pub fn write(&self, dir: &Path) -> Result<PathBuf> {
+        let path = dir.join(format!(
+            "bpftop-{}-{}.json",
+            self.hostname, self.timestamp_ms
+        ));
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(&path, json)
+            .with_context(|| format!("Failed to write snapshot to {}", path.display()))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bpftop_core::{container::Container, kubernetes::Pod};
+    use std::time::Instant;
+
+    #[test]
+    fn test_snapshot() {
+        let sample = Sample {
+            timestamp: Timestamp {
+                instant: Instant::now(),
+                wall_ms: 1000,
+            },
+            programs: vec![BpfProgram {
+                id: 7,
+                bpf_type: "Xdp".to_string(),
+                name: "xdp_prog".to_string(),
+                tag: "0011223344556677".to_string(),
+                prev_runtime_ns: 0,
+                run_time_ns: 100,
+                prev_run_cnt: 0,
+                run_cnt: 10,
+                instant: Instant::now(),
+                period_ns: 1_000_000_000,
+                processes: vec![Process {
+                    pid: 42,
+                    comm: "loader".to_string(),
+                    container: Some(Container {
+                        runtime: "containerd",
+                        id: "3b185f0a00ff129c".to_string(),
+                        name: Some("loader".to_string()),
+                        pod: Some(Pod {
+                            namespace: "kube-system".to_string(),
+                            name: "cilium-x8k2p".to_string(),
+                            container: "loader".to_string(),
+                            labels: BTreeMap::from([("k8s-app".to_string(), "cilium".to_string())]),
+                        }),
+                    }),
+                    cgroup_id: 0,
+                    bpf_fds: 2,
+                    user: None,
+                }],
+            }],
+            interfaces: vec![InterfacePrograms {
+                ifindex: 1,
+                name: "lo".to_string(),
+                attached: vec![("tc ingress", 7)],
+                events_per_second: 0,
+                cpu_time_percent: 0.0,
+            }],
+            ..Default::default()
+        };
+        let links = [(
+            7,
+            BpfAttachment {
+                link_id: 3,
+                link_type: "xdp".to_string(),
+                target: "iface eth0".to_string(),
+                attach_type: String::new(),
+            },
+        )];
+
+        let snapshot = Snapshot::new(&sample, &links, &LoaderRegistry::default());
+        let json = serde_json::to_value(&snapshot).unwrap();
+        assert_eq!(json["timestamp_ms"], 1000);
+        assert_eq!(json["programs"][0]["events_per_second"], 10);
+        assert_eq!(json["programs"][0]["processes"][0]["comm"], "loader");
+        let pod = &json["programs"][0]["processes"][0]["pod"];
+        assert_eq!(pod["name"], "cilium-x8k2p");
+        assert_eq!(pod["labels"]["k8s-app"], "cilium");
+        // XDP links have no attach type of their own
+        assert!(json["programs"][0]["expected_attach_type"].is_null());
+        assert_eq!(json["links"][0]["target"], "iface eth0");
+        assert_eq!(json["attachments"][0]["target"], "iface lo");
+        assert_eq!(json["attachments"][0]["hook"], "tc ingress");
+        assert!(json["maps"].as_array().unwrap().is_empty());
+    }
+}
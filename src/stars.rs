@@ -0,0 +1,104 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use bpftop_core::bpf_program::BpfProgram;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+// A program starred by the user. Programs are remembered by name and tag, as their IDs
+// change when they are reloaded.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Star {
+    pub name: String,
+    pub tag: String,
+}
+
+impl Star {
+    fn of(prog: &BpfProgram) -> Star {
+        Star {
+            name: prog.name.clone(),
+            tag: prog.tag.clone(),
+        }
+    }
+}
+
+// The starred programs, kept in the session so they survive restarts
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Stars(BTreeSet<Star>);
+
+impl Stars {
+    // Stars the program, or unstars it if it was starred, and returns whether it is now
+    pub fn toggle(&mut self, prog: &BpfProgram) -> bool {
+        let star = Star::of(prog);
+        if self.0.remove(&star) {
+            false
+        } else {
+            self.0.insert(star)
+        }
+    }
+
+    pub fn contains(&self, prog: &BpfProgram) -> bool {
+        self.0.contains(&Star::of(prog))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn test_toggle() {
+        let mut prog = BpfProgram {
+            id: 1,
+            bpf_type: "Xdp".to_string(),
+            name: "xdp_lb".to_string(),
+            tag: "a04f5eef06a7f555".to_string(),
+            prev_runtime_ns: 0,
+            run_time_ns: 0,
+            prev_run_cnt: 0,
+            run_cnt: 0,
+            instant: Instant::now(),
+            period_ns: 0,
+            processes: vec![],
+        };
+        let mut stars = Stars::default();
+        assert!(stars.toggle(&prog));
+        // Still starred once reloaded with a new ID
+        prog.id = 2;
+        assert!(stars.contains(&prog));
+        assert_eq!(
+            serde_json::to_string(&stars).unwrap(),
+            "[{\"name\":\"xdp_lb\",\"tag\":\"a04f5eef06a7f555\"}]"
+        );
+
+        prog.tag = "0000000000000000".to_string();
+        assert!(!stars.contains(&prog));
+        prog.tag = "a04f5eef06a7f555".to_string();
+        assert!(!stars.toggle(&prog));
+        assert!(stars.is_empty());
+    }
+}
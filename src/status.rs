@@ -0,0 +1,94 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use bpftop_core::helpers::now_ms;
+use libbpf_rs::num_possible_cpus;
+use std::{fs, time::Duration};
+
+// Where and how bpftop is collecting, shown on every screen so screenshots carry it
+#[derive(Clone, Debug, Default)]
+pub struct HostInfo {
+    pub hostname: String,
+    pub kernel: String,
+    pub cpus: usize,
+}
+
+impl HostInfo {
+    pub fn current() -> HostInfo {
+        let read = |path| {
+            fs::read_to_string(path)
+                .map(|value| value.trim().to_string())
+                .unwrap_or_else(|_| "unknown".to_string())
+        };
+        HostInfo {
+            hostname: read("/proc/sys/kernel/hostname"),
+            kernel: read("/proc/sys/kernel/osrelease"),
+            cpus: num_possible_cpus().unwrap_or(0),
+        }
+    }
+
+    pub fn status_line(&self, interval: Duration) -> String {
+        format!(
+            " {} | Linux {} | {} CPUs | sampled every {}s | {}",
+            self.hostname,
+            self.kernel,
+            self.cpus,
+            interval.as_secs_f64(),
+            format_utc(now_ms())
+        )
+    }
+}
+
+// Formats milliseconds since the Unix epoch as a UTC date and time, e.g.
+// 2024-03-01 12:00:00 UTC, which reads the same from whatever timezone a ticket is opened in
+pub fn format_utc(ms: u64) -> String {
+    let secs = ms / 1000;
+    let days = (secs / 86400) as i64;
+    let time = secs % 86400;
+    // Days to civil date, from Howard Hinnant's date algorithms
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_utc() {
+        assert_eq!(format_utc(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(format_utc(951_782_400_000), "2000-02-29 00:00:00 UTC");
+        assert_eq!(format_utc(1_709_294_400_999), "2024-03-01 12:00:00 UTC");
+        assert_eq!(format_utc(4_102_444_799_000), "2099-12-31 23:59:59 UTC");
+    }
+}
@@ -0,0 +1,196 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    bpf_map::BpfMap,
+    btf::{vmlinux_btf, Btf},
+};
+use bpftop_core::{bpf_program::BpfProgram, helpers::map_type_to_string};
+use libbpf_rs::{MapCore, MapFlags, MapHandle, MapType};
+use std::collections::HashSet;
+
+// The kernel wraps every struct_ops type in a `bpf_struct_ops_<name>` value type
+const VALUE_TYPE_PREFIX: &str = "bpf_struct_ops_";
+const STATE_PREFIX: &str = "BPF_STRUCT_OPS_STATE_";
+
+#[derive(Clone, Debug)]
+pub struct StructOps {
+    pub map_id: u32,
+    pub name: String,
+    pub struct_name: String,
+    pub state: String,
+    // Callback member names and the ID of the program implementing each of them
+    pub callbacks: Vec<(String, u32)>,
+    pub events_per_second: i64,
+    pub cpu_time_percent: f64,
+}
+
+impl PartialEq for StructOps {
+    fn eq(&self, other: &Self) -> bool {
+        self.map_id == other.map_id
+    }
+}
+
+impl StructOps {
+    pub fn programs(&self) -> String {
+        self.callbacks
+            .iter()
+            .map(|(member, prog_id)| format!("{}:{}", member, prog_id))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+fn format_state(state: &str) -> String {
+    // libbpf prefixes values with their type, e.g. "(enum bpf_struct_ops_state)"
+    let state = match state.strip_prefix('(') {
+        Some(rest) => rest.split_once(')').map_or(state, |(_, value)| value),
+        None => state,
+    };
+    state
+        .strip_prefix(STATE_PREFIX)
+        .unwrap_or(state)
+        .to_lowercase()
+}
+
+// Reads a struct_ops map's single value and decodes it with the vmlinux type it was
+// registered against. The kernel replaces every callback pointer with the ID of the
+// program implementing it, which is what links a struct_ops to its programs.
+fn decode(map: &BpfMap, btf: &Btf, programs: &[BpfProgram]) -> Option<StructOps> {
+    let handle = MapHandle::from_map_id(map.id).ok()?;
+    let info = handle.info().ok()?;
+    let value_type_id = info.info.btf_vmlinux_value_type_id;
+    let value = handle
+        .lookup(&0u32.to_ne_bytes(), MapFlags::ANY)
+        .ok()
+        .flatten()?;
+
+    let value_type_name = btf.type_name(value_type_id);
+    let struct_name = value_type_name
+        .strip_prefix(VALUE_TYPE_PREFIX)
+        .unwrap_or(value_type_name)
+        .to_string();
+
+    // Newer kernels nest the state inside a `common` member
+    let state = btf
+        .member(value_type_id, "state")
+        .or_else(|| {
+            let (common_type, common_off) = btf.member(value_type_id, "common")?;
+            let (state_type, state_off) = btf.member(common_type, "state")?;
+            Some((state_type, common_off + state_off))
+        })
+        .and_then(|(state_type, off)| {
+            let size = btf.type_size(state_type)?;
+            let bytes = value.get(off..off + size)?;
+            Some(format_state(&btf.format(state_type, bytes)))
+        })
+        .unwrap_or_default();
+
+    let mut name = map.name.clone();
+    let mut callbacks = vec![];
+    if let Some((data_type, data_off)) = btf.member(value_type_id, "data") {
+        // Congestion control algorithms and sched_ext schedulers carry their own name
+        if let Some((name_type, name_off)) = btf.member(data_type, "name") {
+            let size = btf.type_size(name_type).unwrap_or(0);
+            if let Some(bytes) = value.get(data_off + name_off..data_off + name_off + size) {
+                let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+                if end > 0 {
+                    name = String::from_utf8_lossy(&bytes[..end]).into_owned();
+                }
+            }
+        }
+
+        for (member, off) in btf.func_ptr_members(data_type) {
+            let start = data_off + off;
+            let Some(bytes) = value.get(start..start + 8) else {
+                continue;
+            };
+            let prog_id = u64::from_ne_bytes(bytes.try_into().unwrap()) as u32;
+            if prog_id != 0 {
+                callbacks.push((member, prog_id));
+            }
+        }
+    }
+
+    // The same program may implement several callbacks, only count it once
+    let prog_ids: HashSet<u32> = callbacks.iter().map(|(_, prog_id)| *prog_id).collect();
+    let implementing = programs.iter().filter(|prog| prog_ids.contains(&prog.id));
+    let (events_per_second, cpu_time_percent) = implementing.fold((0, 0.0), |(eps, cpu), prog| {
+        (
+            eps + prog.events_per_second(),
+            cpu + prog.cpu_time_percent(),
+        )
+    });
+
+    Some(StructOps {
+        map_id: map.id,
+        name,
+        struct_name,
+        state,
+        callbacks,
+        events_per_second,
+        cpu_time_percent,
+    })
+}
+
+// Collects every registered struct_ops (e.g. TCP congestion control algorithms or
+// sched_ext schedulers) out of the given maps, aggregating the stats of the programs
+// implementing their callbacks.
+pub fn get_struct_ops(maps: &[BpfMap], programs: &[BpfProgram]) -> Vec<StructOps> {
+    let struct_ops_type = map_type_to_string(MapType::StructOps);
+    let mut maps = maps
+        .iter()
+        .filter(|map| map.map_type == struct_ops_type)
+        .peekable();
+    if maps.peek().is_none() {
+        return vec![];
+    }
+    let Some(btf) = vmlinux_btf() else {
+        return vec![];
+    };
+
+    maps.filter_map(|map| decode(map, btf, programs)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_programs() {
+        let struct_ops = StructOps {
+            map_id: 1,
+            name: "bpf_cubic".to_string(),
+            struct_name: "tcp_congestion_ops".to_string(),
+            state: "inuse".to_string(),
+            callbacks: vec![("init".to_string(), 10), ("cong_avoid".to_string(), 11)],
+            events_per_second: 0,
+            cpu_time_percent: 0.0,
+        };
+        assert_eq!(struct_ops.programs(), "init:10, cong_avoid:11");
+    }
+
+    #[test]
+    fn test_format_state() {
+        assert_eq!(format_state("BPF_STRUCT_OPS_STATE_INUSE"), "inuse");
+        assert_eq!(
+            format_state("(enum bpf_struct_ops_state)BPF_STRUCT_OPS_STATE_INUSE"),
+            "inuse"
+        );
+        assert_eq!(format_state("3"), "3");
+    }
+}
@@ -0,0 +1,83 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+const ELLIPSIS: char = '…';
+
+// Fits text into the given number of terminal columns, ending it with an ellipsis when it
+// has to be cut. Widths are measured the same way ratatui does, so wide and combining
+// characters don't push the following columns out of alignment. Control characters are
+// dropped as they would corrupt the terminal.
+pub fn truncate(text: &str, width: usize) -> String {
+    let text: String = text.chars().filter(|c| !c.is_control()).collect();
+    if text.width() <= width {
+        return text;
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut truncated = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        // Leave room for the ellipsis; a wide character that doesn't fit is dropped whole
+        if used + char_width > width - 1 {
+            break;
+        }
+        used += char_width;
+        truncated.push(c);
+    }
+    truncated.push(ELLIPSIS);
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("xdp_prog", 8), "xdp_prog");
+        assert_eq!(truncate("xdp_prog", 5), "xdp_…");
+        assert_eq!(truncate("xdp_prog", 0), "");
+        assert_eq!(truncate("curl\0\0\0", 4), "curl");
+        // Each CJK character takes two columns
+        assert_eq!(truncate("程序名称", 8), "程序名称");
+        assert_eq!(truncate("程序名称", 6), "程序…");
+        assert_eq!(truncate("程序名称", 5), "程序…");
+        assert_eq!(truncate("程序名称", 4), "程…");
+        // Combining marks take no columns and stay with their base character
+        assert_eq!(truncate("cafe\u{301}s", 5), "cafe\u{301}s");
+        assert_eq!(truncate("cafe\u{301}s", 4), "caf…");
+    }
+
+    #[test]
+    fn test_truncate_fits() {
+        for text in [
+            "bpf_prog_6deef7357e7b4530",
+            "程序名称",
+            "cafe\u{301}",
+            "a\u{200b}b",
+        ] {
+            for width in 0..12 {
+                assert!(truncate(text, width).width() <= width);
+            }
+        }
+    }
+}
@@ -0,0 +1,340 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::prog_audit::AuditEvent;
+use bpftop_core::helpers::program_type_to_string;
+use libbpf_rs::query::ProgInfoIter;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+use tracing::error;
+
+// Programs are scanned this often to catch the ones that live less than a sample period
+pub const FAST_SCAN_INTERVAL: Duration = Duration::from_millis(100);
+
+// Programs that lived shorter than this may never have shown up in the program list
+const SAMPLE_PERIOD: Duration = Duration::from_secs(1);
+
+// Number of transient programs kept for display
+const MAX_RECENT: usize = 100;
+
+// Audited loads no scan has seen are dropped after this, in case their unload was lost
+const AUDIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScannedProgram {
+    pub id: u32,
+    pub bpf_type: String,
+    pub name: String,
+    pub run_time_ns: u64,
+    pub run_cnt: u64,
+}
+
+// A program that was loaded and unloaded within a sample period
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransientProgram {
+    pub id: u32,
+    pub bpf_type: String,
+    pub name: String,
+    pub lifetime: Duration,
+    // Unknown for programs that only the audit programs saw
+    pub run_time_ns: Option<u64>,
+    pub run_cnt: Option<u64>,
+    pub loader: Option<String>,
+    pub unloaded_at: Instant,
+}
+
+struct LiveProgram {
+    program: ScannedProgram,
+    // Unknown for programs that were already loaded at the first scan
+    loaded_at: Option<Instant>,
+    last_seen: Instant,
+    loader: Option<String>,
+}
+
+struct AuditedLoad {
+    bpf_type: String,
+    name: String,
+    loaded_at: Instant,
+    loader: String,
+}
+
+#[derive(Default)]
+pub struct TransientTracker {
+    live: HashMap<u32, LiveProgram>,
+    // Loads reported by the audit programs that no scan has seen yet
+    audited: HashMap<u32, AuditedLoad>,
+    recent: VecDeque<TransientProgram>,
+    count: u64,
+    scanned: bool,
+}
+
+impl TransientTracker {
+    // Total number of transient programs seen since startup
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    // Most recent first
+    pub fn recent(&self) -> impl Iterator<Item = &TransientProgram> {
+        self.recent.iter().rev()
+    }
+
+    pub fn scan(&mut self, programs: Vec<ScannedProgram>, now: Instant) {
+        let mut live = HashMap::with_capacity(programs.len());
+        for program in programs {
+            let id = program.id;
+            let entry = match self.live.remove(&id) {
+                Some(prev) => LiveProgram {
+                    program,
+                    last_seen: now,
+                    ..prev
+                },
+                None => {
+                    let audited = self.audited.remove(&id);
+                    let loaded_at = match &audited {
+                        Some(load) => Some(load.loaded_at),
+                        None => self.scanned.then_some(now),
+                    };
+                    LiveProgram {
+                        program,
+                        loaded_at,
+                        last_seen: now,
+                        loader: audited.map(|load| load.loader),
+                    }
+                }
+            };
+            live.insert(id, entry);
+        }
+
+        // Whatever is left was unloaded since the previous scan
+        for (_, gone) in std::mem::replace(&mut self.live, live) {
+            let Some(loaded_at) = gone.loaded_at else {
+                continue;
+            };
+            let lifetime = gone.last_seen.duration_since(loaded_at);
+            if lifetime < SAMPLE_PERIOD {
+                self.push(TransientProgram {
+                    id: gone.program.id,
+                    bpf_type: gone.program.bpf_type,
+                    name: gone.program.name,
+                    lifetime,
+                    run_time_ns: Some(gone.program.run_time_ns),
+                    run_cnt: Some(gone.program.run_cnt),
+                    loader: gone.loader,
+                    unloaded_at: now,
+                });
+            }
+        }
+
+        self.audited
+            .retain(|_, load| now.duration_since(load.loaded_at) < AUDIT_TIMEOUT);
+        self.scanned = true;
+    }
+
+    pub fn record_audit_event(&mut self, event: &AuditEvent, now: Instant) {
+        if event.is_load() {
+            self.audited.insert(
+                event.prog_id,
+                AuditedLoad {
+                    bpf_type: event.prog_type.clone(),
+                    name: event.prog_name.clone(),
+                    loaded_at: now,
+                    loader: format!("{} ({})", event.comm, event.pid),
+                },
+            );
+            return;
+        }
+
+        // Programs the scans saw are handled once they disappear from them
+        if let Some(load) = self.audited.remove(&event.prog_id) {
+            self.push(TransientProgram {
+                id: event.prog_id,
+                bpf_type: load.bpf_type,
+                name: load.name,
+                lifetime: now.duration_since(load.loaded_at),
+                run_time_ns: None,
+                run_cnt: None,
+                loader: Some(load.loader),
+                unloaded_at: now,
+            });
+        }
+    }
+
+    // Forgets what the scans saw, so that programs unloaded while no scan ran are not taken
+    // for transient ones once they resume
+    pub fn pause(&mut self) {
+        self.live.clear();
+        self.scanned = false;
+    }
+
+    fn push(&mut self, program: TransientProgram) {
+        if self.recent.len() == MAX_RECENT {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(program);
+        self.count += 1;
+    }
+}
+
+fn scan_programs() -> Vec<ScannedProgram> {
+    ProgInfoIter::default()
+        .map(|prog| ScannedProgram {
+            id: prog.id,
+            bpf_type: program_type_to_string(prog.ty),
+            name: prog.name.to_string_lossy().into_owned(),
+            run_time_ns: prog.run_time_ns,
+            run_cnt: prog.run_cnt,
+        })
+        .collect()
+}
+
+// The thread scanning the loaded programs every FAST_SCAN_INTERVAL, stopped and joined
+// when dropped
+pub struct FastScan {
+    handle: Option<JoinHandle<()>>,
+    stop: Option<Sender<()>>,
+}
+
+impl FastScan {
+    pub fn start(tracker: Arc<Mutex<TransientTracker>>) -> FastScan {
+        let (stop, stopped) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            loop {
+                let loop_start = Instant::now();
+                let programs = scan_programs();
+                tracker.lock().unwrap().scan(programs, Instant::now());
+                let sleep = FAST_SCAN_INTERVAL.saturating_sub(loop_start.elapsed());
+                if stopped.recv_timeout(sleep) != Err(RecvTimeoutError::Timeout) {
+                    break;
+                }
+            }
+            tracker.lock().unwrap().pause();
+        });
+        FastScan {
+            handle: Some(handle),
+            stop: Some(stop),
+        }
+    }
+}
+
+impl Drop for FastScan {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(handle) = self.handle.take() {
+            if handle.join().is_err() {
+                error!("Transient program scan panicked");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scanned(id: u32, run_time_ns: u64) -> ScannedProgram {
+        ScannedProgram {
+            id,
+            bpf_type: "Kprobe".to_string(),
+            name: format!("prog_{}", id),
+            run_time_ns,
+            run_cnt: 1,
+        }
+    }
+
+    fn audit_event(event: &'static str, prog_id: u32) -> AuditEvent {
+        AuditEvent {
+            timestamp_ms: 0,
+            event,
+            prog_id,
+            prog_type: "Kprobe".to_string(),
+            prog_name: "short".to_string(),
+            pid: 812,
+            uid: 0,
+            comm: "loader".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_scan() {
+        let mut tracker = TransientTracker::default();
+        let start = Instant::now();
+        let at = |scans: u32| start + scans * FAST_SCAN_INTERVAL;
+        // Already loaded at startup, so never transient
+        tracker.scan(vec![scanned(1, 10)], at(0));
+        tracker.scan(vec![scanned(2, 10)], at(1));
+        tracker.scan(vec![scanned(2, 500)], at(15));
+        tracker.scan(vec![scanned(3, 0)], at(16));
+        // Lived for at least a sample period
+        assert_eq!(tracker.count(), 0);
+
+        tracker.scan(vec![scanned(3, 700)], at(17));
+        tracker.scan(vec![], at(18));
+        assert_eq!(tracker.count(), 1);
+        let program = tracker.recent().next().unwrap();
+        assert_eq!(program.id, 3);
+        assert_eq!(program.run_time_ns, Some(700));
+        assert_eq!(program.lifetime, FAST_SCAN_INTERVAL);
+    }
+
+    #[test]
+    fn test_pause() {
+        let mut tracker = TransientTracker::default();
+        let start = Instant::now();
+        let at = |scans: u32| start + scans * FAST_SCAN_INTERVAL;
+        tracker.scan(vec![scanned(1, 10)], at(0));
+        tracker.scan(vec![scanned(1, 10), scanned(2, 10)], at(1));
+        // Program 2 was unloaded while paused, and program 3 loaded
+        tracker.pause();
+        tracker.scan(vec![scanned(1, 10), scanned(3, 10)], at(5));
+        tracker.scan(vec![scanned(1, 10)], at(6));
+        assert_eq!(tracker.count(), 0);
+    }
+
+    #[test]
+    fn test_record_audit_event() {
+        let mut tracker = TransientTracker::default();
+        let start = Instant::now();
+
+        // Unloaded before any scan saw it
+        tracker.record_audit_event(&audit_event("load", 5), start);
+        tracker.record_audit_event(&audit_event("unload", 5), start + Duration::from_millis(20));
+        assert_eq!(tracker.count(), 1);
+        let program = tracker.recent().next().unwrap();
+        assert_eq!(program.lifetime, Duration::from_millis(20));
+        assert_eq!(program.run_time_ns, None);
+        assert_eq!(program.loader.as_deref(), Some("loader (812)"));
+
+        // Seen by a scan, so its stats come from the scan once it is gone
+        tracker.record_audit_event(&audit_event("load", 6), start);
+        tracker.scan(vec![scanned(6, 40)], start + FAST_SCAN_INTERVAL);
+        tracker.record_audit_event(&audit_event("unload", 6), start + 2 * FAST_SCAN_INTERVAL);
+        tracker.scan(vec![], start + 2 * FAST_SCAN_INTERVAL);
+        assert_eq!(tracker.count(), 2);
+        let program = tracker.recent().next().unwrap();
+        assert_eq!(program.run_time_ns, Some(40));
+        assert_eq!(program.lifetime, FAST_SCAN_INTERVAL);
+        assert_eq!(program.loader.as_deref(), Some("loader (812)"));
+    }
+}
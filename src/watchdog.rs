@@ -0,0 +1,136 @@
+/**
+ *
+ *  Copyright 2024 Netflix, Inc.
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ *
+ */
+use crate::{
+    bpf_map::get_maps,
+    bpffs::{pinned_objects, pinned_paths, PinnedKind},
+    exporter::Exporters,
+    ringbuf::RingBufTracker,
+    rules::{Condition, Rule, RuleEngine},
+    snapshot::Sample,
+};
+use anyhow::{anyhow, Result};
+use bpftop_core::sampler::{get_map_owners, ProgramSample, ProgramStatsSource, Sampler};
+use libbpf_rs::Link;
+use std::{
+    collections::HashMap,
+    thread,
+    time::{Duration, Instant},
+};
+use tracing::info;
+
+// Parses a CPU budget such as "3%" or "3" into a percentage
+pub fn parse_cpu_limit(value: &str) -> Result<f64> {
+    let limit: f64 = value
+        .trim()
+        .trim_end_matches('%')
+        .parse()
+        .map_err(|_| anyhow!("Invalid CPU limit '{}', expected e.g. 3%", value))?;
+    if !(limit > 0.0 && limit <= 100.0) {
+        return Err(anyhow!("CPU limit must be within (0, 100]%, got {}", value));
+    }
+    Ok(limit)
+}
+
+// Rule behind --cpu-limit, applied to every program
+pub fn watchdog_rule(
+    cpu_limit: f64,
+    periods: u32,
+    webhook: Option<String>,
+    exec: Option<String>,
+) -> Result<Rule> {
+    Ok(Rule::new(
+        "watchdog",
+        Condition::above("cpu_percent", cpu_limit)?,
+        periods,
+    )
+    .with_actions(webhook, exec))
+}
+
+// Samples every program once per second without a UI, evaluates the rules against them
+// and feeds the exporters until the process is terminated
+pub fn run(
+    mut rules: RuleEngine,
+    mut exporters: Exporters,
+    source: impl ProgramStatsSource,
+    map_iter: Option<Link>,
+    collect_maps: bool,
+) -> Result<()> {
+    if rules.is_empty() && exporters.is_empty() {
+        return Err(anyhow!(
+            "Watchdog mode needs --cpu-limit, --allowlist, --export-json, --socket, --api or rules in the config file"
+        ));
+    }
+    info!("Watchdog started");
+    let mut sampler = Sampler::new(source);
+    let mut ringbuf_tracker = RingBufTracker::default();
+
+    loop {
+        let loop_start = Instant::now();
+        let ProgramSample {
+            timestamp,
+            programs,
+            map_prog_ids,
+        } = sampler.sample();
+        // Only the API serves maps, and without their entry counts
+        let maps = if collect_maps {
+            get_maps(
+                &map_prog_ids,
+                &pinned_paths(&pinned_objects(), PinnedKind::Map),
+                &get_map_owners(&map_iter),
+                &HashMap::new(),
+                &HashMap::new(),
+                &mut ringbuf_tracker,
+            )
+        } else {
+            vec![]
+        };
+
+        let alerts = rules.evaluate(&programs);
+        let sample = Sample {
+            timestamp,
+            programs,
+            maps,
+            alerts,
+            ..Default::default()
+        };
+        exporters.export(&sample);
+
+        thread::sleep(Duration::from_secs(1).saturating_sub(loop_start.elapsed()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_limit() {
+        assert_eq!(parse_cpu_limit("3%").unwrap(), 3.0);
+        assert_eq!(parse_cpu_limit(" 0.5 ").unwrap(), 0.5);
+        assert!(parse_cpu_limit("0%").is_err());
+        assert!(parse_cpu_limit("101").is_err());
+        assert!(parse_cpu_limit("abc").is_err());
+    }
+
+    #[test]
+    fn test_watchdog_rule() {
+        let rule = watchdog_rule(3.0, 2, None, None).unwrap();
+        assert_eq!(rule.name, "watchdog");
+        assert_eq!(rule.condition.to_string(), "cpu_percent > 3");
+    }
+}